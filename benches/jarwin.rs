@@ -28,6 +28,17 @@ pub fn jarwin_benchmark(cr: &mut Criterion) {
             }
         );
 
+        group.bench_with_input(format!("eddie unicode size={}", size),
+            size,
+            |bench, _| {
+                let mut gen = Generator::with_alphabet(*size, 2, CYRILLIC);
+                bench.iter(|| {
+                    let (s1, s2) = &gen.next();
+                    jarwin.similarity(s1, s2)
+                });
+            }
+        );
+
         group.bench_with_input(format!("strsim size={}", size),
             size,
             |bench, _| {
@@ -69,6 +80,7 @@ criterion_main!(benches);
 
 
 const GEN_SAMPLE_SIZE: usize = 1000;
+const CYRILLIC: &str = "абвгдежзийклмнопрстуфхцчшщъыьэюя";
 
 
 struct Generator {
@@ -83,7 +95,11 @@ struct Generator {
 
 impl Generator {
     pub fn new(len: usize, edits: usize) -> Generator {
-        let chars = "abcdefghijklmnopqrstuvwxyz".chars().collect();
+        Generator::with_alphabet(len, edits, "abcdefghijklmnopqrstuvwxyz")
+    }
+
+    pub fn with_alphabet(len: usize, edits: usize, alphabet: &str) -> Generator {
+        let chars = alphabet.chars().collect();
         let rng = rand::thread_rng();
         let sample = Vec::with_capacity(GEN_SAMPLE_SIZE);
         let i = 0;
@@ -0,0 +1,54 @@
+#!/usr/bin/env perl
+#
+# Generates src/utils/grapheme/tables.rs with Grapheme_Cluster_Break and
+# Extended_Pictographic properties from the Unicode database bundled with Perl.
+#
+# Usage: perl scripts/grapheme_tables.pl > src/utils/grapheme/tables.rs
+
+use strict;
+use warnings;
+use Unicode::UCD qw(prop_invmap);
+
+my %names = (
+    'CR'                 => 'CR',
+    'LF'                 => 'LF',
+    'Control'            => 'Control',
+    'Extend'             => 'Extend',
+    'ZWJ'                => 'Zwj',
+    'Regional_Indicator' => 'RegionalIndicator',
+    'Prepend'            => 'Prepend',
+    'SpacingMark'        => 'SpacingMark',
+    'L'                  => 'L',
+    'V'                  => 'V',
+    'T'                  => 'T',
+    'ExtPict_XX'         => 'Pictographic',
+);
+
+my ($list, $map) = prop_invmap('Grapheme_Cluster_Break');
+my @ranges;
+
+for my $i (0 .. $#$list) {
+    my $value = $map->[$i];
+    my $lo = $list->[$i];
+    my $hi = $i < $#$list ? $list->[$i + 1] - 1 : 0x10FFFF;
+    next if $value eq 'Other';
+    if ($value eq 'LV' || $value eq 'LVT') {
+        # Hangul syllables are classified arithmetically.
+        die "Unexpected $value at $lo" if $lo < 0xAC00 || $hi > 0xD7A3;
+        next;
+    }
+    my $name = $names{$value} or die "Unknown category $value";
+    if (@ranges && $ranges[-1][1] + 1 == $lo && $ranges[-1][2] eq $name) {
+        $ranges[-1][1] = $hi;
+    } else {
+        push @ranges, [$lo, $hi, $name];
+    }
+}
+
+my $version = Unicode::UCD::UnicodeVersion();
+
+print "// Generated by scripts/grapheme_tables.pl from Unicode $version, do not edit manually.\n\n";
+print "use super::Category::{self, *};\n\n\n";
+print "pub const CATEGORIES: &[(u32, u32, Category)] = &[\n";
+printf "    (0x%04X, 0x%04X, %s),\n", @$_ for @ranges;
+print "];\n";
@@ -0,0 +1,141 @@
+#!/usr/bin/env perl
+#
+# Generates src/normalize/tables.rs with decompositions, compositions,
+# combining classes, case foldings, punctuation and white space
+# from the Unicode database bundled with Perl.
+#
+# Usage: perl scripts/normalize_tables.pl > src/normalize/tables.rs
+
+use strict;
+use warnings;
+use feature qw(fc unicode_strings);
+use Unicode::UCD qw(prop_invmap prop_invlist);
+use Unicode::Normalize qw(getCanon getCompat getCombinClass NFC);
+
+my $HANGUL_FIRST = 0xAC00;
+my $HANGUL_LAST = 0xD7A3;
+my $LAST = 0x10FFFF;
+
+sub is_char { my $cp = shift; return !($cp >= 0xD800 && $cp <= 0xDFFF); }
+
+sub ranges {
+    my ($value_of) = @_;
+    my @ranges;
+    for my $cp (0 .. $LAST) {
+        next unless is_char($cp);
+        my $value = $value_of->($cp);
+        next unless $value;
+        if (@ranges && $ranges[-1][1] + 1 == $cp && $ranges[-1][2] eq $value) {
+            $ranges[-1][1] = $cp;
+        } else {
+            push @ranges, [$cp, $cp, $value];
+        }
+    }
+    return @ranges;
+}
+
+sub mappings {
+    my ($mapping_of, $flat) = @_;
+    my @entries;
+    for my $cp (0 .. $LAST) {
+        next unless is_char($cp);
+        my $mapped = $mapping_of->($cp);
+        next unless defined $mapped;
+        my @chars = map { ord } split //, $mapped;
+        push @entries, [$cp, scalar(@$flat), scalar(@chars)];
+        push @$flat, @chars;
+    }
+    die "Mapped characters don't fit into u16 offsets" if @$flat > 0xFFFF;
+    return @entries;
+}
+
+sub print_list {
+    my ($name, $type, $doc, $per_line, @items) = @_;
+    print "/// $doc\n";
+    print "pub const $name: &[$type] = &[\n";
+    while (my @line = splice @items, 0, $per_line) {
+        print "    ", join(" ", @line), "\n";
+    }
+    print "];\n\n\n";
+}
+
+sub char_literal { sprintf "'\\u{%X}',", shift }
+
+my @combining = ranges(sub { getCombinClass(shift) });
+
+my @decomposed;
+my @canonical = mappings(sub {
+    my $cp = shift;
+    return undef if $cp >= $HANGUL_FIRST && $cp <= $HANGUL_LAST;
+    my $canon = getCanon($cp);
+    return defined $canon && $canon ne chr($cp) ? $canon : undef;
+}, \@decomposed);
+my @compatibility = mappings(sub {
+    my $cp = shift;
+    return undef if $cp >= $HANGUL_FIRST && $cp <= $HANGUL_LAST;
+    my $compat = getCompat($cp);
+    my $canon = getCanon($cp) // chr($cp);
+    return defined $compat && $compat ne $canon ? $compat : undef;
+}, \@decomposed);
+
+my @compositions;
+my ($dm_list, $dm_map) = prop_invmap('Decomposition_Mapping');
+my ($dt_list, $dt_map) = prop_invmap('Decomposition_Type');
+my %canonical_type;
+for my $i (0 .. $#$dt_list - 1) {
+    next unless $dt_map->[$i] eq 'Canonical';
+    $canonical_type{$_} = 1 for $dt_list->[$i] .. $dt_list->[$i + 1] - 1;
+}
+for my $i (0 .. $#$dm_list) {
+    my $mapping = $dm_map->[$i];
+    next unless ref $mapping && @$mapping == 2;
+    my $cp = $dm_list->[$i];
+    next unless $canonical_type{$cp};
+    next unless NFC(chr($mapping->[0]) . chr($mapping->[1])) eq chr($cp);
+    push @compositions, [@$mapping, $cp];
+}
+@compositions = sort { $a->[0] <=> $b->[0] || $a->[1] <=> $b->[1] } @compositions;
+
+my @folded;
+my @folding = mappings(sub {
+    my $cp = shift;
+    my $folded = fc(chr($cp));
+    return $folded ne chr($cp) ? $folded : undef;
+}, \@folded);
+
+my ($gc_list, $gc_map) = prop_invmap('General_Category');
+my @punctuation;
+for my $i (0 .. $#$gc_list - 1) {
+    next unless $gc_map->[$i] =~ /^P/;
+    my ($lo, $hi) = ($gc_list->[$i], $gc_list->[$i + 1] - 1);
+    if (@punctuation && $punctuation[-1][1] + 1 == $lo) { $punctuation[-1][1] = $hi; }
+    else { push @punctuation, [$lo, $hi]; }
+}
+
+my @white_list = prop_invlist('White_Space');
+my @white_space;
+while (my ($lo, $hi) = splice @white_list, 0, 2) {
+    push @white_space, [$lo, $hi - 1];
+}
+
+my $version = Unicode::UCD::UnicodeVersion();
+print "// Generated by scripts/normalize_tables.pl from Unicode $version, do not edit manually.\n\n\n";
+
+print_list('COMBINING_CLASSES', '(u32, u32, u8)', 'Nonzero canonical combining classes.', 4,
+    map { sprintf "(0x%04X, 0x%04X, %d),", @$_ } @combining);
+print_list('CANONICAL', '(u32, u16, u8)', 'Full canonical decompositions, as offsets and lengths in `DECOMPOSED`.', 4,
+    map { sprintf "(0x%04X, %d, %d),", @$_ } @canonical);
+print_list('COMPATIBILITY', '(u32, u16, u8)', 'Full compatibility decompositions that differ from the canonical ones.', 4,
+    map { sprintf "(0x%04X, %d, %d),", @$_ } @compatibility);
+print_list('DECOMPOSED', 'char', 'Characters of decompositions.', 8,
+    map { char_literal($_) } @decomposed);
+print_list('COMPOSITIONS', '(u32, u32, u32)', 'Primary composites of pairs of characters, sorted by the pairs.', 3,
+    map { sprintf "(0x%04X, 0x%04X, 0x%04X),", @$_ } @compositions);
+print_list('CASE_FOLDING', '(u32, u16, u8)', 'Full case foldings, as offsets and lengths in `FOLDED`.', 4,
+    map { sprintf "(0x%04X, %d, %d),", @$_ } @folding);
+print_list('FOLDED', 'char', 'Characters of case foldings.', 8,
+    map { char_literal($_) } @folded);
+print_list('PUNCTUATION', '(u32, u32)', 'Characters of the punctuation general categories.', 4,
+    map { sprintf "(0x%04X, 0x%04X),", @$_ } @punctuation);
+print_list('WHITE_SPACE', '(u32, u32)', 'Characters with the White_Space property.', 4,
+    map { sprintf "(0x%04X, 0x%04X),", @$_ } @white_space);
@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use super::parametric::ParametricDfa;
+
+
+const NOT_FINAL: u8 = u8::MAX;
+
+
+/// # Levenshtein DFA for a single query.
+///
+/// Deterministic automaton that accepts every word within
+/// the maximum edit distance from the query.
+/// Created by [`LevenshteinAutomaton`][1].
+///
+/// Walk it item by item with [`transition`][2] and check the final state with
+/// [`distance`][3], or use [`eval`][4] to check a whole word at once.
+///
+/// [1]: struct.LevenshteinAutomaton.html
+/// [2]: #method.transition
+/// [3]: #method.distance
+/// [4]: #method.eval
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::automaton::LevenshteinAutomaton;
+///
+/// let lev = LevenshteinAutomaton::new(2, false);
+/// let dfa = lev.dfa("martha");
+///
+/// let mut state = dfa.initial();
+/// for ch in "marhta".chars() {
+///     state = dfa.transition(state, ch);
+/// }
+/// assert_eq!(dfa.distance(state), Some(2));
+/// ```
+pub struct Dfa<T> {
+    alphabet: Vec<T>,
+    transitions: Vec<usize>,
+    distances: Vec<u8>,
+}
+
+
+impl<T: Copy + Ord> Dfa<T> {
+    /// Id of the rejecting state, that never leads to a match.
+    pub const DEAD: usize = 0;
+
+    pub(super) fn new(parametric: &ParametricDfa, query: &[T]) -> Self {
+        let mut alphabet = query.to_vec();
+        alphabet.sort();
+        alphabet.dedup();
+
+        let columns = alphabet.len() + 1;
+        let mut dfa = Self { alphabet, transitions: Vec::new(), distances: Vec::new() };
+
+        let mut ids: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut states: Vec<(usize, usize)> = Vec::new();
+        let mut queue = VecDeque::new();
+
+        for &state in &[(ParametricDfa::DEAD, 0), (ParametricDfa::INITIAL, 0)] {
+            ids.insert(state, states.len());
+            queue.push_back(states.len());
+            states.push(state);
+        }
+
+        while let Some(id) = queue.pop_front() {
+            let (param, base) = states[id];
+            let dist = parametric.distance(param, query.len().saturating_sub(base));
+            dfa.distances.push(dist.map_or(NOT_FINAL, |d| d as u8));
+            debug_assert_eq!(dfa.transitions.len(), id * columns);
+
+            let width = parametric.width(param);
+            let symbols = dfa.alphabet.iter().map(Some).chain(Some(None));
+            for symbol in symbols {
+                let chi = match symbol {
+                    None => 0,
+                    Some(x) => (0..width)
+                        .filter(|&i| query.get(base + i) == Some(x))
+                        .fold(0, |chi, i| chi | 1 << i),
+                };
+                let (next_param, shift) = parametric.transition(param, chi);
+                let next = if next_param == ParametricDfa::DEAD { (next_param, 0) } else { (next_param, base + shift) };
+                let next_id = match ids.get(&next) {
+                    Some(&next_id) => next_id,
+                    None => {
+                        let next_id = states.len();
+                        ids.insert(next, next_id);
+                        queue.push_back(next_id);
+                        states.push(next);
+                        next_id
+                    }
+                };
+                dfa.transitions.push(next_id);
+            }
+        }
+
+        dfa
+    }
+
+    /// Returns the initial state.
+    pub fn initial(&self) -> usize {
+        1
+    }
+
+    /// Returns a number of states, including the dead one.
+    pub fn len(&self) -> usize {
+        self.distances.len()
+    }
+
+    /// Returns `true` if the automaton never matches anything.
+    /// Never happens since any automaton matches at least its own query.
+    pub fn is_empty(&self) -> bool {
+        self.len() <= 1
+    }
+
+    /// Returns the next state after reading an item.
+    pub fn transition(&self, state: usize, item: T) -> usize {
+        let columns = self.alphabet.len() + 1;
+        let column = self.alphabet.binary_search(&item).unwrap_or(self.alphabet.len());
+        self.transitions[state * columns + column]
+    }
+
+    /// Returns the distance between the query and a word that ends in the state,
+    /// or `None` if it is greater than the maximum distance.
+    pub fn distance(&self, state: usize) -> Option<usize> {
+        match self.distances[state] {
+            NOT_FINAL => None,
+            dist => Some(dist as usize),
+        }
+    }
+
+    /// Returns `false` if no word that has the current prefix can match.
+    pub fn can_match(&self, state: usize) -> bool {
+        state != Self::DEAD
+    }
+
+    /// Returns the distance between the query and the word,
+    /// or `None` if it is greater than the maximum distance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::automaton::LevenshteinAutomaton;
+    /// let lev = LevenshteinAutomaton::new(1, true);
+    /// let dfa = lev.dfa("martha");
+    /// assert_eq!(dfa.eval("marhta".chars()), Some(1));
+    /// assert_eq!(dfa.eval("mailbox".chars()), None);
+    /// ```
+    pub fn eval<I: IntoIterator<Item=T>>(&self, word: I) -> Option<usize> {
+        let mut state = self.initial();
+        for item in word {
+            state = self.transition(state, item);
+            if state == Self::DEAD { return None; }
+        }
+        self.distance(state)
+    }
+}
@@ -0,0 +1,162 @@
+use super::Dfa;
+
+
+/// A trie-like structure that can be traversed by a [`Dfa`][1],
+/// e.g. a trie, a DAWG, or an FST.
+///
+/// [1]: struct.Dfa.html
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+/// use eddie::automaton::{LevenshteinAutomaton, Trie};
+///
+/// #[derive(Default)]
+/// struct Node {
+///     children: BTreeMap<char, Node>,
+///     is_final: bool,
+/// }
+///
+/// impl<'a> Trie for &'a Node {
+///     type Symbol = char;
+///     type Node = &'a Node;
+///
+///     fn root(&self) -> &'a Node { self }
+///     fn is_final(&self, node: &&'a Node) -> bool { node.is_final }
+///     fn for_each_child(&self, node: &&'a Node, f: &mut dyn FnMut(char, &'a Node)) {
+///         for (&ch, child) in &node.children { f(ch, child); }
+///     }
+/// }
+///
+/// let mut root = Node::default();
+/// for word in &["mailbox", "mail", "martha"] {
+///     let mut node = &mut root;
+///     for ch in word.chars() {
+///         node = node.children.entry(ch).or_default();
+///     }
+///     node.is_final = true;
+/// }
+///
+/// let dfa = LevenshteinAutomaton::new(1, false).dfa("mall");
+/// let found: Vec<(usize, String)> = dfa.search_trie(&&root)
+///     .into_iter()
+///     .map(|(dist, word, _)| (dist, word.into_iter().collect()))
+///     .collect();
+/// assert_eq!(found, vec![(1, "mail".to_string())]);
+/// ```
+pub trait Trie {
+    /// Type of edge labels.
+    type Symbol;
+
+    /// Type of node handles.
+    type Node: Clone;
+
+    /// Returns the root node.
+    fn root(&self) -> Self::Node;
+
+    /// Returns `true` if the node ends a word.
+    fn is_final(&self, node: &Self::Node) -> bool;
+
+    /// Calls the function for every outgoing edge of the node.
+    fn for_each_child(&self, node: &Self::Node, f: &mut dyn FnMut(Self::Symbol, Self::Node));
+}
+
+
+impl Dfa<char> {
+    /// Finds all matching words in a dictionary sorted in lexicographic order.
+    /// Returns pairs of distances and words, in dictionary order.
+    ///
+    /// Automaton states are reused for common prefixes of consecutive words,
+    /// and all the words starting with a rejected prefix are skipped with a binary search,
+    /// so only a small part of the dictionary is actually read.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::automaton::LevenshteinAutomaton;
+    /// let dict = ["mail", "mailbox", "martha", "marty", "moth"];
+    /// let dfa = LevenshteinAutomaton::new(1, false).dfa("marta");
+    /// assert_eq!(dfa.search_sorted(&dict), vec![(1, &"martha"), (1, &"marty")]);
+    /// ```
+    pub fn search_sorted<'a, S: AsRef<str>>(&self, words: &'a [S]) -> Vec<(usize, &'a S)> {
+        let mut found = Vec::new();
+        // States after each character of the previous word, and its characters.
+        let mut states = vec![self.initial()];
+        let mut prev: Vec<char> = Vec::new();
+        let mut i = 0;
+
+        while i < words.len() {
+            let word = words[i].as_ref();
+            let common = prev.iter().zip(word.chars()).take_while(|(x1, x2)| *x1 == x2).count();
+            states.truncate(common + 1);
+            prev.truncate(common);
+
+            let mut dead = false;
+            for ch in word.chars().skip(common) {
+                let state = self.transition(states[states.len() - 1], ch);
+                states.push(state);
+                prev.push(ch);
+                if state == Self::DEAD { dead = true; break; }
+            }
+
+            if dead {
+                // Skip all the following words having the same rejected prefix.
+                let prefix: String = prev.iter().collect();
+                let rest = &words[i + 1 ..];
+                i += 1 + rest.partition_point(|w| w.as_ref().starts_with(&prefix[..]));
+                continue;
+            }
+
+            if let Some(dist) = self.distance(states[states.len() - 1]) {
+                found.push((dist, &words[i]));
+            }
+            i += 1;
+        }
+
+        found
+    }
+}
+
+
+impl<T: Copy + Ord> Dfa<T> {
+    /// Finds all matching words in a trie-like structure.
+    /// Returns triples of distances, words and their final nodes,
+    /// in depth-first order. Subtrees under rejected prefixes are not visited.
+    ///
+    /// See [`Trie`][1] for an example.
+    ///
+    /// [1]: trait.Trie.html
+    pub fn search_trie<Tr>(&self, trie: &Tr) -> Vec<(usize, Vec<T>, Tr::Node)>
+    where
+        Tr: Trie<Symbol=T>,
+    {
+        let mut found = Vec::new();
+        let mut word = Vec::new();
+        let mut stack = vec![(trie.root(), self.initial(), None)];
+        let mut children = Vec::new();
+
+        while let Some((node, state, edge)) = stack.pop() {
+            if let Some((depth, symbol)) = edge {
+                word.truncate(depth);
+                word.push(symbol);
+            }
+
+            if trie.is_final(&node) {
+                if let Some(dist) = self.distance(state) {
+                    found.push((dist, word.clone(), node.clone()));
+                }
+            }
+
+            trie.for_each_child(&node, &mut |symbol, child| children.push((symbol, child)));
+            for (symbol, child) in children.drain(..).rev() {
+                let next = self.transition(state, symbol);
+                if next != Self::DEAD {
+                    stack.push((child, next, Some((word.len(), symbol))));
+                }
+            }
+        }
+
+        found
+    }
+}
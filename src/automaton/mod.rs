@@ -0,0 +1,281 @@
+//! Levenshtein automata for fast dictionary lookups.
+//!
+//! A Levenshtein automaton for a query is a deterministic finite automaton that
+//! accepts exactly the words within a given edit distance from the query.
+//! Intersecting it with a sorted dictionary or a trie finds all such words
+//! without computing the distance for every candidate.
+//!
+//! Automata are built in two stages, following [Schulz and Mihov][1]:
+//! - a query-independent parametric automaton is built once for the maximum distance,
+//!   see [`LevenshteinAutomaton`][2];
+//! - a concrete [`Dfa`][3] is built from it for every query in linear time.
+//!
+//! [1]: https://doi.org/10.1007/s10032-002-0082-8
+//! [2]: struct.LevenshteinAutomaton.html
+//! [3]: struct.Dfa.html
+//!
+//!
+//! # Basic Usage
+//!
+//! ```rust
+//! use eddie::automaton::LevenshteinAutomaton;
+//!
+//! let lev = LevenshteinAutomaton::new(1, true);
+//! let dict = ["mailbox", "marhta", "martha", "marty"];
+//!
+//! let dfa = lev.dfa("martha");
+//! assert_eq!(dfa.search_sorted(&dict), vec![(1, &"marhta"), (0, &"martha")]);
+//! ```
+
+
+mod parametric;
+mod dfa;
+mod intersect;
+
+pub use dfa::Dfa;
+pub use intersect::Trie;
+
+use parametric::ParametricDfa;
+
+
+/// # Levenshtein automaton builder.
+///
+/// Holds the precomputed query-independent part of Levenshtein automata
+/// for the given maximum distance (up to 3),
+/// and builds a [`Dfa`][1] for every query.
+///
+/// With transpositions enabled the automata compute the optimal string alignment distance,
+/// i.e. Damerau-Levenshtein distance where no substring is edited more than once.
+///
+/// [1]: struct.Dfa.html
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::automaton::LevenshteinAutomaton;
+///
+/// let lev = LevenshteinAutomaton::new(2, false);
+/// let dfa = lev.dfa("martha");
+/// assert_eq!(dfa.eval("marhta".chars()), Some(2));
+/// assert_eq!(dfa.eval("mailbox".chars()), None);
+///
+/// let damlev = LevenshteinAutomaton::new(2, true);
+/// let dfa = damlev.dfa("martha");
+/// assert_eq!(dfa.eval("marhta".chars()), Some(1));
+/// ```
+pub struct LevenshteinAutomaton {
+    parametric: ParametricDfa,
+}
+
+
+impl LevenshteinAutomaton {
+    /// Creates a new automaton builder for the given maximum distance,
+    /// with or without transpositions.
+    /// Panics if the maximum distance is greater than 3.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::automaton::LevenshteinAutomaton;
+    ///
+    /// let lev = LevenshteinAutomaton::new(2, true);
+    /// ```
+    pub fn new(max_dist: usize, transpositions: bool) -> Self {
+        Self { parametric: ParametricDfa::new(max_dist, transpositions) }
+    }
+
+    /// Returns the maximum distance.
+    pub fn max_dist(&self) -> usize {
+        self.parametric.max_dist()
+    }
+
+    /// Builds an automaton accepting the strings within the maximum distance from the query.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::automaton::LevenshteinAutomaton;
+    /// let lev = LevenshteinAutomaton::new(1, false);
+    /// let dfa = lev.dfa("もしもし");
+    /// assert_eq!(dfa.eval("もしまし".chars()), Some(1));
+    /// ```
+    pub fn dfa(&self, query: &str) -> Dfa<char> {
+        let query: Vec<char> = query.chars().collect();
+        Dfa::new(&self.parametric, &query)
+    }
+
+    /// Builds an automaton accepting the slices within the maximum distance from the query.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::automaton::LevenshteinAutomaton;
+    /// let lev = LevenshteinAutomaton::new(1, true);
+    /// let dfa = lev.dfa_slice(&[1, 2, 3, 4, 5]);
+    /// assert_eq!(dfa.eval(vec![1, 3, 2, 4, 5]), Some(1));
+    /// ```
+    pub fn dfa_slice<T: Copy + Ord>(&self, query: &[T]) -> Dfa<T> {
+        Dfa::new(&self.parametric, query)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::LevenshteinAutomaton;
+    use crate::slice::Levenshtein;
+
+    /// Optimal string alignment distance.
+    fn osa(s1: &[u8], s2: &[u8]) -> usize {
+        let mut d = vec![vec![0; s2.len() + 1]; s1.len() + 1];
+        for (i, row) in d.iter_mut().enumerate() { row[0] = i; }
+        for (j, cell) in d[0].iter_mut().enumerate() { *cell = j; }
+        for i in 1 ..= s1.len() {
+            for j in 1 ..= s2.len() {
+                let cost = (s1[i - 1] != s2[j - 1]) as usize;
+                d[i][j] = min!(d[i - 1][j] + 1, d[i][j - 1] + 1, d[i - 1][j - 1] + cost);
+                if i > 1 && j > 1 && s1[i - 1] == s2[j - 2] && s1[i - 2] == s2[j - 1] {
+                    d[i][j] = min!(d[i][j], d[i - 2][j - 2] + 1);
+                }
+            }
+        }
+        d[s1.len()][s2.len()]
+    }
+
+    /// All words up to the given length over the alphabet.
+    fn words(alphabet: &[u8], max_len: usize) -> Vec<Vec<u8>> {
+        let mut words = vec![vec![]];
+        let mut last = vec![vec![]];
+        for _ in 0 .. max_len {
+            last = last.iter()
+                .flat_map(|w| alphabet.iter().map(move |&x| { let mut w = w.clone(); w.push(x); w }))
+                .collect();
+            words.extend(last.iter().cloned());
+        }
+        words
+    }
+
+    #[test]
+    fn exhaustive_levenshtein() {
+        let lev = Levenshtein::new();
+        let queries = words(b"abc", 4);
+        let candidates = words(b"abcd", 5);
+        for max_dist in 0..4 {
+            let automaton = LevenshteinAutomaton::new(max_dist, false);
+            for query in queries.iter().step_by(3) {
+                let dfa = automaton.dfa_slice(query);
+                for word in &candidates {
+                    let dist = lev.distance(query, word);
+                    let expected = if dist <= max_dist { Some(dist) } else { None };
+                    assert_eq!(dfa.eval(word.iter().copied()), expected, "{:?} {:?}", query, word);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn exhaustive_transpositions() {
+        let queries = words(b"abc", 4);
+        let candidates = words(b"abcd", 5);
+        for max_dist in 0..4 {
+            let automaton = LevenshteinAutomaton::new(max_dist, true);
+            for query in queries.iter().step_by(3) {
+                let dfa = automaton.dfa_slice(query);
+                for word in &candidates {
+                    let dist = osa(query, word);
+                    let expected = if dist <= max_dist { Some(dist) } else { None };
+                    assert_eq!(dfa.eval(word.iter().copied()), expected, "{:?} {:?}", query, word);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn long_query() {
+        let lev = Levenshtein::new();
+        let automaton = LevenshteinAutomaton::new(2, false);
+        let query: Vec<u8> = b"mailbox".iter().cycle().take(100).copied().collect();
+        let dfa = automaton.dfa_slice(&query);
+        let mut word = query.clone();
+        word[10] = b'_';
+        word.remove(50);
+        assert_eq!(dfa.eval(word.iter().copied()), Some(lev.distance(&query, &word)));
+        word.insert(90, b'_');
+        assert_eq!(dfa.eval(word.iter().copied()), None);
+    }
+
+    #[test]
+    fn search_sorted() {
+        let lev = crate::Levenshtein::new();
+        let mut dict: Vec<String> = words(b"abc", 5).into_iter()
+            .map(|w| String::from_utf8(w).unwrap())
+            .collect();
+        dict.sort();
+        for max_dist in 0..3 {
+            let automaton = LevenshteinAutomaton::new(max_dist, false);
+            for query in &["", "a", "abc", "cabca", "abcabc", "xyz"] {
+                let dfa = automaton.dfa(query);
+                let expected: Vec<(usize, &String)> = dict.iter()
+                    .map(|w| (lev.distance(query, w), w))
+                    .filter(|&(d, _)| d <= max_dist)
+                    .collect();
+                assert_eq!(dfa.search_sorted(&dict), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn search_trie() {
+        use std::collections::BTreeMap;
+        use super::Trie;
+
+        #[derive(Default)]
+        struct Node {
+            children: BTreeMap<char, Node>,
+            id: Option<usize>,
+        }
+
+        impl<'a> Trie for &'a Node {
+            type Symbol = char;
+            type Node = &'a Node;
+            fn root(&self) -> &'a Node { self }
+            fn is_final(&self, node: &&'a Node) -> bool { node.id.is_some() }
+            fn for_each_child(&self, node: &&'a Node, f: &mut dyn FnMut(char, &'a Node)) {
+                for (&ch, child) in &node.children { f(ch, child); }
+            }
+        }
+
+        let lev = crate::Levenshtein::new();
+        let dict: Vec<String> = words(b"abc", 5).into_iter()
+            .map(|w| String::from_utf8(w).unwrap())
+            .collect();
+        let mut root = Node::default();
+        for (id, word) in dict.iter().enumerate() {
+            let mut node = &mut root;
+            for ch in word.chars() {
+                node = node.children.entry(ch).or_default();
+            }
+            node.id = Some(id);
+        }
+
+        let automaton = LevenshteinAutomaton::new(2, false);
+        for query in &["", "a", "abc", "cabca", "abcabc", "xyz"] {
+            let dfa = automaton.dfa(query);
+            let mut found: Vec<(usize, String)> = dfa.search_trie(&&root)
+                .into_iter()
+                .map(|(dist, word, node)| {
+                    let word: String = word.into_iter().collect();
+                    assert_eq!(dict[node.id.unwrap()], word);
+                    (dist, word)
+                })
+                .collect();
+            found.sort_by(|(_, w1), (_, w2)| w1.cmp(w2));
+            let mut expected: Vec<(usize, String)> = dict.iter()
+                .map(|w| (lev.distance(query, w), w.clone()))
+                .filter(|&(d, _)| d <= 2)
+                .collect();
+            expected.sort_by(|(_, w1), (_, w2)| w1.cmp(w2));
+            assert_eq!(found, expected);
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+
+/// Maximum supported edit distance.
+pub const MAX_DISTANCE: usize = 3;
+
+
+/// A position of the nondeterministic Levenshtein automaton:
+/// a number of query items consumed (relative to the state base),
+/// a number of edits made, and whether a transposition is in progress.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Position {
+    offset: usize,
+    errors: usize,
+    transposing: bool,
+}
+
+
+impl Position {
+    fn new(offset: usize, errors: usize, transposing: bool) -> Self {
+        Self { offset, errors, transposing }
+    }
+
+    /// Tells whether every word accepted from the other position
+    /// is accepted from this one with no more edits.
+    fn subsumes(&self, other: &Position) -> bool {
+        if self.transposing || other.transposing { return false; }
+        let shift = max!(self.offset, other.offset) - min!(self.offset, other.offset);
+        self.errors < other.errors && shift <= other.errors - self.errors
+    }
+
+    /// A number of characteristic vector bits the position depends on.
+    fn width(&self, max_dist: usize) -> usize {
+        if self.transposing { self.offset + 1 } else { self.offset + max_dist - self.errors + 1 }
+    }
+}
+
+
+/// Query-independent part of a Levenshtein automaton.
+///
+/// A state is a set of positions normalized so that the minimal offset is zero.
+/// Transitions depend only on the characteristic vector of an input item,
+/// i.e. on the set of query positions (relative to the state base) where the item occurs.
+pub struct ParametricDfa {
+    max_dist: usize,
+    states: Vec<Vec<Position>>,
+    widths: Vec<usize>,
+    offsets: Vec<usize>,
+    transitions: Vec<(usize, usize)>,
+}
+
+
+impl ParametricDfa {
+    /// Id of the state without positions, i.e. of the rejecting state.
+    pub const DEAD: usize = 0;
+
+    /// Id of the initial state.
+    pub const INITIAL: usize = 1;
+
+    pub fn new(max_dist: usize, transpositions: bool) -> Self {
+        assert!(max_dist <= MAX_DISTANCE, "Maximum distance should not be greater than {}", MAX_DISTANCE);
+
+        let mut dfa = Self {
+            max_dist,
+            states: Vec::new(),
+            widths: Vec::new(),
+            offsets: Vec::new(),
+            transitions: Vec::new(),
+        };
+
+        let mut ids: HashMap<Vec<Position>, usize> = HashMap::new();
+        let mut queue = VecDeque::new();
+        for state in [vec![], vec![Position::new(0, 0, false)]].iter().cloned() {
+            ids.insert(state.clone(), dfa.states.len());
+            queue.push_back(dfa.states.len());
+            dfa.push_state(state);
+        }
+
+        while let Some(id) = queue.pop_front() {
+            let offset = dfa.transitions.len();
+            dfa.offsets[id] = offset;
+            for chi in 0 .. 1usize << dfa.widths[id] {
+                let (next, shift) = step(&dfa.states[id], chi, max_dist, transpositions);
+                let next_id = match ids.get(&next) {
+                    Some(&next_id) => next_id,
+                    None => {
+                        let next_id = dfa.states.len();
+                        ids.insert(next.clone(), next_id);
+                        queue.push_back(next_id);
+                        dfa.push_state(next);
+                        next_id
+                    }
+                };
+                dfa.transitions.push((next_id, shift));
+            }
+        }
+
+        dfa
+    }
+
+    fn push_state(&mut self, state: Vec<Position>) {
+        let width = state.iter().map(|p| p.width(self.max_dist)).max().unwrap_or(0);
+        self.states.push(state);
+        self.widths.push(width);
+        self.offsets.push(0);
+    }
+
+    pub fn max_dist(&self) -> usize {
+        self.max_dist
+    }
+
+    /// A number of characteristic vector bits the state transitions depend on.
+    pub fn width(&self, state: usize) -> usize {
+        self.widths[state]
+    }
+
+    /// Returns the next state and the shift of the state base.
+    pub fn transition(&self, state: usize, chi: usize) -> (usize, usize) {
+        self.transitions[self.offsets[state] + chi]
+    }
+
+    /// Returns the minimal number of edits for a word that ends in the state,
+    /// given a number of query items left after the state base.
+    pub fn distance(&self, state: usize, remaining: usize) -> Option<usize> {
+        self.states[state].iter()
+            .filter(|p| !p.transposing)
+            .map(|p| p.errors + remaining.saturating_sub(p.offset))
+            .filter(|&dist| dist <= self.max_dist)
+            .min()
+    }
+
+    #[cfg(test)]
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+}
+
+
+/// Computes a set of positions reachable from the state
+/// by an item with the given characteristic vector.
+fn step(state: &[Position], chi: usize, max_dist: usize, transpositions: bool) -> (Vec<Position>, usize) {
+    let is_set = |i: usize| chi >> i & 1 == 1;
+    let mut next = Vec::new();
+
+    for &Position { offset, errors, transposing } in state {
+        if transposing {
+            if is_set(offset) { next.push(Position::new(offset + 2, errors, false)); }
+            continue;
+        }
+        if is_set(offset) {
+            next.push(Position::new(offset + 1, errors, false));
+            continue;
+        }
+        if errors == max_dist { continue; }
+        next.push(Position::new(offset, errors + 1, false));
+        next.push(Position::new(offset + 1, errors + 1, false));
+        for skip in 1 ..= max_dist - errors {
+            if is_set(offset + skip) {
+                next.push(Position::new(offset + skip + 1, errors + skip, false));
+            }
+        }
+        if transpositions && is_set(offset + 1) {
+            next.push(Position::new(offset, errors + 1, true));
+        }
+    }
+
+    next.sort();
+    next.dedup();
+    let mut reduced: Vec<Position> = next.iter()
+        .filter(|p| !next.iter().any(|q| q.subsumes(p)))
+        .copied()
+        .collect();
+
+    let shift = reduced.iter().map(|p| p.offset).min().unwrap_or(0);
+    for p in &mut reduced { p.offset -= shift; }
+    (reduced, shift)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::ParametricDfa;
+
+    #[test]
+    fn sizes() {
+        // Numbers of states without transpositions match the known values
+        // (including the dead state).
+        assert_eq!(ParametricDfa::new(0, false).len(), 2);
+        assert_eq!(ParametricDfa::new(1, false).len(), 6);
+        assert_eq!(ParametricDfa::new(2, false).len(), 31);
+        assert_eq!(ParametricDfa::new(3, false).len(), 197);
+        assert_eq!(ParametricDfa::new(3, true).len(), 575);
+    }
+
+    #[test]
+    #[should_panic]
+    fn max_dist_too_large() {
+        ParametricDfa::new(4, false);
+    }
+}
@@ -0,0 +1,629 @@
+use std::borrow::Borrow;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap};
+use std::collections::btree_map::Entry;
+use std::slice;
+use crate::metric::Metric;
+
+
+/// # Burkhard-Keller tree.
+///
+/// An index for fast fuzzy lookups in a metric space,
+/// generic over any integer-valued [metric][1] from this crate:
+/// `Levenshtein`, `DamerauLevenshtein`, `Hamming`, `Eudex`,
+/// and their counterparts from `eddie::slice`.
+///
+/// The tree owns the metric instance, so its internal buffers
+/// are reused between all the comparisons made by the tree.
+///
+/// Distinct keys at distance zero from each other, e.g. words that sound alike for `Eudex`,
+/// are all kept in the same node. Keys are told apart by equality (`==`).
+///
+/// See [the detailed description][2].
+///
+/// [1]: ../metric/trait.Metric.html
+/// [2]: https://en.wikipedia.org/wiki/BK-tree
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::Levenshtein;
+/// use eddie::index::BKTree;
+///
+/// let mut tree = BKTree::new(Levenshtein::new());
+/// tree.extend(vec!["mailbox", "boxmail", "mail", "martha"]);
+///
+/// let found = tree.find_within("mailbx", 1);
+/// assert_eq!(found, vec![(1, &"mailbox")]);
+///
+/// let nearest = tree.find_nearest("marta", 2);
+/// assert_eq!(nearest, vec![(1, &"martha"), (3, &"mail")]);
+/// ```
+///
+/// Keys can be queried by any type they can be borrowed as:
+/// ```rust
+/// # use eddie::Levenshtein;
+/// # use eddie::index::BKTree;
+/// let mut tree: BKTree<String, _> = BKTree::new(Levenshtein::new());
+/// tree.insert("mailbox".to_string());
+/// assert!(tree.contains("mailbox"));
+/// ```
+pub struct BKTree<K, M> {
+    metric: M,
+    root: Option<Node<K>>,
+    len: usize,
+}
+
+
+struct Node<K> {
+    key: K,
+    removed: bool,
+    /// Other keys at distance zero from the key, never present if it is removed.
+    twins: Vec<K>,
+    children: BTreeMap<usize, Node<K>>,
+}
+
+
+impl<K> Node<K> {
+    fn new(key: K) -> Self {
+        Self { key, removed: false, twins: Vec::new(), children: BTreeMap::new() }
+    }
+
+    /// Returns all the keys of the node that are not removed.
+    fn keys(&self) -> impl Iterator<Item=&K> {
+        let key = if self.removed { None } else { Some(&self.key) };
+        key.into_iter().chain(self.twins.iter())
+    }
+}
+
+
+impl<K, M> BKTree<K, M> {
+    /// Creates a new empty tree using the given metric.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::DamerauLevenshtein;
+    /// use eddie::index::BKTree;
+    ///
+    /// let tree: BKTree<String, _> = BKTree::new(DamerauLevenshtein::new());
+    /// ```
+    pub fn new(metric: M) -> Self {
+        Self { metric, root: None, len: 0 }
+    }
+
+    /// Returns a number of keys in the tree.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the tree contains no keys.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the metric used by the tree.
+    pub fn metric(&self) -> &M {
+        &self.metric
+    }
+
+    /// Returns an iterator over the keys in the tree, in no particular order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// # use eddie::index::BKTree;
+    /// let mut tree = BKTree::new(Levenshtein::new());
+    /// tree.extend(vec!["mailbox", "boxmail"]);
+    ///
+    /// let mut keys: Vec<_> = tree.iter().collect();
+    /// keys.sort();
+    /// assert_eq!(keys, vec![&"boxmail", &"mailbox"]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K> {
+        Iter { stack: self.root.iter().collect(), twins: [].iter() }
+    }
+}
+
+
+impl<K: PartialEq, M: Metric<K>> BKTree<K, M> {
+    /// Inserts a key into the tree.
+    /// Returns `false` if an equal key is already present.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// # use eddie::index::BKTree;
+    /// let mut tree = BKTree::new(Levenshtein::new());
+    /// assert!(tree.insert("mailbox"));
+    /// assert!(!tree.insert("mailbox"));
+    /// assert_eq!(tree.len(), 1);
+    /// ```
+    ///
+    /// Distinct keys are kept even if the metric cannot tell them apart:
+    /// ```rust
+    /// # use eddie::index::BKTree;
+    /// use eddie::phonetic::Eudex;
+    ///
+    /// let mut tree = BKTree::new(Eudex::new());
+    /// assert!(tree.insert("jon"));
+    /// assert!(tree.insert("john"));
+    /// assert_eq!(tree.find_within("jonn", 0), vec![(0, &"jon"), (0, &"john")]);
+    /// ```
+    pub fn insert(&mut self, key: K) -> bool {
+        let Self { metric, root, len } = self;
+
+        let mut node = match root {
+            Some(node) => node,
+            None => {
+                *root = Some(Node::new(key));
+                *len += 1;
+                return true;
+            }
+        };
+
+        loop {
+            let dist = metric.distance(&key, &node.key);
+            if dist == 0 {
+                if node.removed {
+                    node.key = key;
+                    node.removed = false;
+                } else if node.key == key || node.twins.contains(&key) {
+                    return false;
+                } else {
+                    node.twins.push(key);
+                }
+                *len += 1;
+                return true;
+            }
+            match node.children.entry(dist) {
+                Entry::Occupied(entry) => {
+                    node = entry.into_mut();
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(Node::new(key));
+                    *len += 1;
+                    return true;
+                }
+            }
+        }
+    }
+}
+
+
+impl<K: PartialEq, M: Metric<K>> Extend<K> for BKTree<K, M> {
+    fn extend<I: IntoIterator<Item=K>>(&mut self, keys: I) {
+        for key in keys {
+            self.insert(key);
+        }
+    }
+}
+
+
+impl<K, M> BKTree<K, M> {
+    /// Removes a key from the tree.
+    /// Returns `false` if there was no such key.
+    ///
+    /// Removed keys are kept in the tree as tombstones to route the searches,
+    /// so removal does not free any memory.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// # use eddie::index::BKTree;
+    /// let mut tree = BKTree::new(Levenshtein::new());
+    /// tree.extend(vec!["mailbox", "boxmail"]);
+    ///
+    /// assert!(tree.remove("mailbox"));
+    /// assert!(!tree.remove("mailbox"));
+    /// assert_eq!(tree.find_within("mailbox", 1), vec![]);
+    /// ```
+    pub fn remove<Q>(&mut self, key: &Q) -> bool
+    where
+        Q: ?Sized + PartialEq,
+        K: Borrow<Q>,
+        M: Metric<Q>,
+    {
+        let Self { metric, root, len } = self;
+        let mut node = match root {
+            Some(node) => node,
+            None => return false,
+        };
+        loop {
+            let dist = metric.distance(key, node.key.borrow());
+            if dist == 0 {
+                if node.removed { return false; }
+                if node.key.borrow() == key {
+                    match node.twins.pop() {
+                        Some(twin) => node.key = twin,
+                        None => node.removed = true,
+                    }
+                } else {
+                    match node.twins.iter().position(|twin| twin.borrow() == key) {
+                        Some(i) => { node.twins.swap_remove(i); }
+                        None => return false,
+                    }
+                }
+                *len -= 1;
+                return true;
+            }
+            node = match node.children.get_mut(&dist) {
+                Some(child) => child,
+                None => return false,
+            };
+        }
+    }
+
+    /// Returns `true` if the tree contains a key equal to the given one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// # use eddie::index::BKTree;
+    /// let mut tree = BKTree::new(Levenshtein::new());
+    /// tree.insert("mailbox");
+    /// assert!(tree.contains("mailbox"));
+    /// assert!(!tree.contains("mailbx"));
+    /// ```
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        Q: ?Sized + PartialEq,
+        K: Borrow<Q>,
+        M: Metric<Q>,
+    {
+        let mut node = match &self.root {
+            Some(node) => node,
+            None => return false,
+        };
+        loop {
+            let dist = self.metric.distance(key, node.key.borrow());
+            if dist == 0 { return node.keys().any(|k| k.borrow() == key); }
+            node = match node.children.get(&dist) {
+                Some(child) => child,
+                None => return false,
+            };
+        }
+    }
+
+    /// Finds all keys within the given distance from the query.
+    /// Returns pairs of distances and keys, sorted by distance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// # use eddie::index::BKTree;
+    /// let mut tree = BKTree::new(Levenshtein::new());
+    /// tree.extend(vec!["mailbox", "boxmail", "mail", "martha"]);
+    ///
+    /// let found = tree.find_within("mal", 1);
+    /// assert_eq!(found, vec![(1, &"mail")]);
+    /// ```
+    pub fn find_within<Q>(&self, query: &Q, max_dist: usize) -> Vec<(usize, &K)>
+    where
+        Q: ?Sized,
+        K: Borrow<Q>,
+        M: Metric<Q>,
+    {
+        let mut found = Vec::new();
+        let mut stack: Vec<&Node<K>> = self.root.iter().collect();
+
+        while let Some(node) = stack.pop() {
+            let dist = self.metric.distance(query, node.key.borrow());
+            if dist <= max_dist {
+                found.extend(node.keys().map(|key| (dist, key)));
+            }
+            let lo = dist.saturating_sub(max_dist);
+            let hi = dist.saturating_add(max_dist);
+            stack.extend(node.children.range(lo ..= hi).map(|(_, child)| child));
+        }
+
+        found.sort_by_key(|&(dist, _)| dist);
+        found
+    }
+
+    /// Finds `k` keys nearest to the query.
+    /// Returns pairs of distances and keys, sorted by distance.
+    /// Keys at equal distances are returned in order of discovery.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// # use eddie::index::BKTree;
+    /// let mut tree = BKTree::new(Levenshtein::new());
+    /// tree.extend(vec!["mailbox", "boxmail", "mail", "martha"]);
+    ///
+    /// let found = tree.find_nearest("mailbx", 2);
+    /// assert_eq!(found, vec![(1, &"mailbox"), (2, &"mail")]);
+    /// ```
+    pub fn find_nearest<Q>(&self, query: &Q, k: usize) -> Vec<(usize, &K)>
+    where
+        Q: ?Sized,
+        K: Borrow<Q>,
+        M: Metric<Q>,
+    {
+        if k == 0 { return Vec::new(); }
+
+        // Nodes to visit, ordered by the lower bound of their distance to the query.
+        let mut nodes: Vec<&Node<K>> = self.root.iter().collect();
+        let mut queue: BinaryHeap<Reverse<(usize, usize)>> = BinaryHeap::new();
+        if !nodes.is_empty() { queue.push(Reverse((0, 0))); }
+
+        // Best keys found so far, the farthest one on top.
+        let mut keys: Vec<(usize, &K)> = Vec::new();
+        let mut best: BinaryHeap<(usize, usize)> = BinaryHeap::new();
+
+        while let Some(Reverse((bound, i))) = queue.pop() {
+            if best.len() == k && bound > best.peek().map_or(usize::MAX, |b| b.0) {
+                break;
+            }
+
+            let node = nodes[i];
+            let dist = self.metric.distance(query, node.key.borrow());
+
+            for key in node.keys() {
+                let worst = best.peek().map_or(usize::MAX, |b| b.0);
+                if best.len() < k || dist < worst {
+                    if best.len() == k { best.pop(); }
+                    best.push((dist, keys.len()));
+                    keys.push((dist, key));
+                }
+            }
+
+            let radius = if best.len() == k { best.peek().map_or(usize::MAX, |b| b.0) } else { usize::MAX };
+            let lo = dist.saturating_sub(radius);
+            let hi = dist.saturating_add(radius);
+            for (&edge, child) in node.children.range(lo ..= hi) {
+                let bound = max!(edge, dist) - min!(edge, dist);
+                queue.push(Reverse((bound, nodes.len())));
+                nodes.push(child);
+            }
+        }
+
+        let mut found: Vec<(usize, &K)> = best.into_sorted_vec()
+            .into_iter()
+            .map(|(_, i)| keys[i])
+            .collect();
+        found.sort_by_key(|&(dist, _)| dist);
+        found
+    }
+}
+
+
+/// An iterator over the keys of a [`BKTree`][1].
+///
+/// [1]: struct.BKTree.html
+pub struct Iter<'a, K> {
+    stack: Vec<&'a Node<K>>,
+    twins: slice::Iter<'a, K>,
+}
+
+
+impl<'a, K> Iterator for Iter<'a, K> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(twin) = self.twins.next() { return Some(twin); }
+        loop {
+            let node = self.stack.pop()?;
+            self.stack.extend(node.children.values());
+            self.twins = node.twins.iter();
+            if !node.removed { return Some(&node.key); }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::BKTree;
+    use crate::metric::Distance;
+    use crate::{Levenshtein, DamerauLevenshtein, Hamming};
+    use crate::slice;
+    use crate::phonetic::Eudex;
+
+    fn words() -> Vec<String> {
+        let syllables = ["ma", "il", "bo", "x", "mar", "tha", "ta", ""];
+        let mut words = Vec::new();
+        for s1 in &syllables {
+            for s2 in &syllables {
+                for s3 in &syllables {
+                    words.push(format!("{}{}{}", s1, s2, s3));
+                }
+            }
+        }
+        words.sort();
+        words.dedup();
+        words
+    }
+
+    fn scan<'a, M: Distance<str>>(metric: &M, words: &'a [String], query: &str, max_dist: usize) -> Vec<(usize, &'a String)> {
+        let mut found: Vec<_> = words.iter()
+            .map(|w| (metric.distance(query, w), w))
+            .filter(|&(d, _)| d <= max_dist)
+            .collect();
+        found.sort();
+        found
+    }
+
+    fn sorted(mut found: Vec<(usize, &String)>) -> Vec<(usize, &String)> {
+        found.sort();
+        found
+    }
+
+    #[test]
+    fn empty() {
+        let tree: BKTree<String, _> = BKTree::new(Levenshtein::new());
+        assert_eq!(tree.len(), 0);
+        assert!(tree.is_empty());
+        assert!(!tree.contains("mailbox"));
+        assert_eq!(tree.find_within("mailbox", 10), vec![]);
+        assert_eq!(tree.find_nearest("mailbox", 10), vec![]);
+        assert_eq!(tree.iter().count(), 0);
+    }
+
+    #[test]
+    fn insert() {
+        let words = words();
+        let mut tree = BKTree::new(Levenshtein::new());
+        for w in &words {
+            assert!(tree.insert(w.clone()));
+        }
+        for w in &words {
+            assert!(!tree.insert(w.clone()));
+            assert!(tree.contains(w.as_str()));
+        }
+        assert_eq!(tree.len(), words.len());
+        let mut keys: Vec<_> = tree.iter().cloned().collect();
+        keys.sort();
+        assert_eq!(keys, words);
+    }
+
+    #[test]
+    fn find_within() {
+        let words = words();
+        let lev = Levenshtein::new();
+        let mut tree = BKTree::new(Levenshtein::new());
+        tree.extend(words.iter().cloned());
+        for query in &["", "mailbox", "mailbx", "marta", "xxxx", "もしもし"] {
+            for max_dist in 0..4 {
+                let expected = scan(&lev, &words, query, max_dist);
+                let found = tree.find_within(*query, max_dist);
+                assert!(found.windows(2).all(|w| w[0].0 <= w[1].0));
+                assert_eq!(sorted(found), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn find_nearest() {
+        let words = words();
+        let damlev = DamerauLevenshtein::new();
+        let mut tree = BKTree::new(DamerauLevenshtein::new());
+        tree.extend(words.iter().cloned());
+        for query in &["", "mailbox", "mailbx", "marta", "xxxx", "もしもし"] {
+            for k in 0..20 {
+                let found = tree.find_nearest(*query, k);
+                let all = scan(&damlev, &words, query, usize::MAX);
+                assert_eq!(found.len(), k);
+                assert!(found.windows(2).all(|w| w[0].0 <= w[1].0));
+                let dists: Vec<usize> = found.iter().map(|&(d, _)| d).collect();
+                let expected: Vec<usize> = all.iter().take(k).map(|&(d, _)| d).collect();
+                assert_eq!(dists, expected);
+                for (d, w) in found {
+                    assert_eq!(damlev.distance(query, w), d);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn remove() {
+        let words = words();
+        let lev = Levenshtein::new();
+        let mut tree = BKTree::new(Levenshtein::new());
+        tree.extend(words.iter().cloned());
+
+        let (removed, kept): (Vec<String>, Vec<String>) = words.iter()
+            .cloned()
+            .partition(|w| w.len() % 2 == 0);
+        for w in &removed {
+            assert!(tree.remove(w.as_str()));
+            assert!(!tree.remove(w.as_str()));
+            assert!(!tree.contains(w.as_str()));
+        }
+        assert_eq!(tree.len(), kept.len());
+        assert!(!tree.remove("もしもし"));
+
+        for query in &["mailbox", "marta"] {
+            let expected = scan(&lev, &kept, query, 2);
+            assert_eq!(sorted(tree.find_within(*query, 2)), expected);
+            assert!(tree.find_nearest(*query, 5).iter().all(|(_, w)| w.len() % 2 == 1));
+        }
+
+        for w in &removed {
+            assert!(tree.insert(w.clone()));
+        }
+        assert_eq!(tree.len(), words.len());
+        assert_eq!(tree.iter().count(), words.len());
+    }
+
+    #[test]
+    fn hamming() {
+        let mut tree = BKTree::new(Hamming::new());
+        tree.extend(vec!["martha", "marhta", "mar", "march"]);
+        assert_eq!(tree.find_within("martha", 1), vec![(0, &"martha")]);
+        let mut found = tree.find_within("marc", 1);
+        found.sort();
+        assert_eq!(found, vec![(1, &"mar"), (1, &"march")]);
+    }
+
+    #[test]
+    fn same_distance() {
+        let mut tree = BKTree::new(Eudex::new());
+        assert!(tree.insert("jon"));
+        assert!(tree.insert("john"));
+        assert!(tree.insert("jone"));
+        assert!(!tree.insert("john"));
+        assert_eq!(tree.len(), 3);
+        assert!(tree.contains("john"));
+        assert!(!tree.contains("jonn"));
+        assert_eq!(tree.iter().count(), 3);
+        assert_eq!(tree.find_within("jonn", 0).len(), 3);
+        assert_eq!(tree.find_nearest("jonn", 2).len(), 2);
+
+        assert!(tree.remove("jon"));
+        assert!(!tree.remove("jon"));
+        assert!(!tree.remove("jonn"));
+        assert!(tree.contains("john"));
+        assert!(tree.remove("jone"));
+        assert!(tree.remove("john"));
+        assert!(tree.is_empty());
+        assert_eq!(tree.find_within("jon", 0), vec![]);
+        assert!(tree.insert("jon"));
+        assert_eq!(tree.find_within("john", 0), vec![(0, &"jon")]);
+    }
+
+    #[test]
+    fn grapheme_mode() {
+        let mut lev = Levenshtein::new();
+        lev.set_grapheme_mode(true);
+        let mut tree = BKTree::new(lev);
+        tree.extend(vec!["cafe\u{301}s", "cafés", "cafe\u{301}", "cafe"]);
+        assert_eq!(tree.len(), 4);
+        assert_eq!(tree.find_within("cafés", 1), vec![(0, &"cafés"), (1, &"cafe\u{301}s")]);
+        assert_eq!(tree.find_nearest("cafe\u{301}", 1), vec![(0, &"cafe\u{301}")]);
+        assert_eq!(tree.find_within("cafe", 0), vec![(0, &"cafe")]);
+    }
+
+    #[test]
+    fn normalizer() {
+        use crate::normalize::{Normalizer, CaseFolding};
+        let mut normalizer = Normalizer::new();
+        normalizer.set_case_folding(CaseFolding::Full);
+        let mut tree = BKTree::new(Levenshtein::with_normalizer(normalizer));
+        assert!(tree.insert("Mail"));
+        assert!(tree.insert("mail"));
+        assert!(tree.insert("MAILBOX"));
+        assert!(!tree.insert("mail"));
+        assert_eq!(tree.len(), 3);
+        assert_eq!(tree.find_within("MAIL", 0), vec![(0, &"Mail"), (0, &"mail")]);
+        assert_eq!(tree.find_within("mailbx", 1), vec![(1, &"MAILBOX")]);
+        assert!(tree.remove("Mail"));
+        assert!(tree.contains("mail"));
+        assert!(!tree.contains("MAIL"));
+    }
+
+    #[test]
+    fn slices() {
+        let mut tree: BKTree<Vec<u8>, _> = BKTree::new(slice::Levenshtein::new());
+        tree.extend(vec![vec![1, 2, 3], vec![1, 3, 2], vec![4, 5, 6]]);
+        assert_eq!(tree.find_within(&[4, 5][..], 1), vec![(1, &vec![4, 5, 6])]);
+        assert!(tree.contains(&[4, 5, 6][..]));
+    }
+}
@@ -0,0 +1,334 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use crate::utils::{Buffer, BoundedOsa};
+
+
+const DEFAULT_CAPACITY: usize = 20;
+
+
+/// # Symmetric deletion index.
+///
+/// A dictionary of words with frequencies for fast spelling suggestions,
+/// following the [SymSpell][1] approach.
+///
+/// For every word, all the strings obtained by deleting up to `max_dist` characters
+/// are precomputed and stored in the index. At query time, the same deletions are
+/// generated for the query, and the words sharing any of them are verified
+/// with the bounded optimal string alignment distance
+/// (Damerau-Levenshtein distance where no substring is edited more than once).
+///
+/// Lookups are very fast for small distances, at the cost of memory:
+/// a word of length `n` produces `O(n^max_dist)` deletions.
+///
+/// [1]: https://github.com/wolfgarbe/SymSpell
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::index::DeletionIndex;
+///
+/// let mut index = DeletionIndex::new(2);
+/// index.insert("martha", 10);
+/// index.insert("marta", 50);
+/// index.insert("mailbox", 30);
+///
+/// let found: Vec<_> = index.lookup("marhta", 2)
+///     .iter()
+///     .map(|s| (s.word, s.distance, s.frequency))
+///     .collect();
+/// assert_eq!(found, vec![("marta", 1, 50), ("martha", 1, 10)]);
+/// ```
+pub struct DeletionIndex {
+    max_dist: usize,
+    words: Vec<(String, u64)>,
+    ids: HashMap<String, usize>,
+    deletions: HashMap<String, Vec<usize>>,
+    osa: BoundedOsa,
+    buffer1: Buffer<char>,
+    buffer2: Buffer<char>,
+    seen: RefCell<HashSet<usize>>,
+}
+
+
+/// A word found by [`DeletionIndex::lookup`][1].
+///
+/// [1]: struct.DeletionIndex.html#method.lookup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Suggestion<'a> {
+    /// The word from the dictionary.
+    pub word: &'a str,
+    /// Its distance to the query.
+    pub distance: usize,
+    /// Its frequency in the dictionary.
+    pub frequency: u64,
+}
+
+
+impl DeletionIndex {
+    /// Creates a new empty index supporting lookups within the given maximum distance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::index::DeletionIndex;
+    ///
+    /// let index = DeletionIndex::new(2);
+    /// ```
+    pub fn new(max_dist: usize) -> Self {
+        Self {
+            max_dist,
+            words: Vec::new(),
+            ids: HashMap::new(),
+            deletions: HashMap::new(),
+            osa: BoundedOsa::new(),
+            buffer1: Buffer::with_capacity(DEFAULT_CAPACITY),
+            buffer2: Buffer::with_capacity(DEFAULT_CAPACITY),
+            seen: RefCell::new(HashSet::new()),
+        }
+    }
+
+    /// Returns the maximum distance supported by lookups.
+    pub fn max_dist(&self) -> usize {
+        self.max_dist
+    }
+
+    /// Returns a number of distinct words in the index.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Returns `true` if the index contains no words.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Returns the frequency of the word, or `None` if it is not in the index.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::index::DeletionIndex;
+    /// let mut index = DeletionIndex::new(2);
+    /// index.insert("martha", 10);
+    /// assert_eq!(index.frequency("martha"), Some(10));
+    /// assert_eq!(index.frequency("marta"), None);
+    /// ```
+    pub fn frequency(&self, word: &str) -> Option<u64> {
+        self.ids.get(word).map(|&id| self.words[id].1)
+    }
+
+    /// Adds a word with the given frequency to the index.
+    /// If the word is already present, its frequency is increased.
+    /// Returns `true` if the word was not present.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::index::DeletionIndex;
+    /// let mut index = DeletionIndex::new(2);
+    /// assert!(index.insert("martha", 10));
+    /// assert!(!index.insert("martha", 5));
+    /// assert_eq!(index.frequency("martha"), Some(15));
+    /// ```
+    pub fn insert(&mut self, word: &str, frequency: u64) -> bool {
+        if let Some(&id) = self.ids.get(word) {
+            let freq = &mut self.words[id].1;
+            *freq = freq.saturating_add(frequency);
+            return false;
+        }
+
+        let id = self.words.len();
+        self.words.push((word.to_string(), frequency));
+        self.ids.insert(word.to_string(), id);
+
+        let chars = &*self.buffer1.store(word.chars()).borrow();
+        for deletion in deletions(chars, self.max_dist) {
+            self.deletions.entry(deletion).or_default().push(id);
+        }
+        true
+    }
+
+    /// Finds all words within the given distance from the query
+    /// (which should not exceed the maximum distance of the index).
+    /// Returns the words sorted by distance, then by frequency (descending),
+    /// then alphabetically.
+    ///
+    /// Panics if `max_dist` is greater than the maximum distance of the index.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::index::DeletionIndex;
+    /// let mut index = DeletionIndex::new(2);
+    /// index.insert("martha", 10);
+    /// index.insert("mailbox", 50);
+    ///
+    /// let found = index.lookup("marhta", 1);
+    /// assert_eq!(found.len(), 1);
+    /// assert_eq!(found[0].word, "martha");
+    /// assert_eq!(found[0].distance, 1);
+    /// ```
+    pub fn lookup(&self, query: &str, max_dist: usize) -> Vec<Suggestion<'_>> {
+        assert!(max_dist <= self.max_dist, "Lookup distance should not be greater than {}", self.max_dist);
+
+        let query = &*self.buffer1.store(query.chars()).borrow();
+        let seen = &mut *self.seen.borrow_mut();
+        seen.clear();
+
+        let mut found = Vec::new();
+        for deletion in deletions(query, max_dist) {
+            let ids = match self.deletions.get(&deletion) {
+                Some(ids) => ids,
+                None => continue,
+            };
+            for &id in ids {
+                if !seen.insert(id) { continue; }
+                let (word, frequency) = &self.words[id];
+                let candidate = &*self.buffer2.store(word.chars()).borrow();
+                if let Some(distance) = self.osa.distance(query, candidate, max_dist) {
+                    found.push(Suggestion { word, distance, frequency: *frequency });
+                }
+            }
+        }
+
+        found.sort_by(|s1, s2| {
+            s1.distance.cmp(&s2.distance)
+                .then(s2.frequency.cmp(&s1.frequency))
+                .then(s1.word.cmp(s2.word))
+        });
+        found
+    }
+}
+
+
+/// Returns all distinct strings obtained by deleting up to `max_dist` characters,
+/// including the original string.
+fn deletions(chars: &[char], max_dist: usize) -> HashSet<String> {
+    let mut result = HashSet::new();
+    result.insert(chars.iter().collect::<String>());
+    let mut last: Vec<Vec<char>> = vec![chars.to_vec()];
+
+    for _ in 0 .. max_dist {
+        let mut next = Vec::new();
+        for word in &last {
+            for i in 0 .. word.len() {
+                let mut deleted = word.clone();
+                deleted.remove(i);
+                if result.insert(deleted.iter().collect()) {
+                    next.push(deleted);
+                }
+            }
+        }
+        last = next;
+    }
+
+    result
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{DeletionIndex, deletions};
+    use crate::utils::BoundedOsa;
+
+    fn words() -> Vec<String> {
+        let syllables = ["ma", "il", "bo", "x", "mar", "tha", "ta", "も", ""];
+        let mut words = Vec::new();
+        for s1 in &syllables {
+            for s2 in &syllables {
+                for s3 in &syllables {
+                    words.push(format!("{}{}{}", s1, s2, s3));
+                }
+            }
+        }
+        words.sort();
+        words.dedup();
+        words
+    }
+
+    fn vec(s: &str) -> Vec<char> { s.chars().collect() }
+
+    #[test]
+    fn deletions_count() {
+        let sample = [
+            (1, 0, ""),
+            (1, 3, ""),
+            (1, 0, "abc"),
+            (4, 1, "abc"),
+            (7, 2, "abc"),
+            (8, 3, "abc"),
+            (8, 4, "abc"),
+            (3, 1, "aab"),
+            (3, 3, "aa"),
+        ];
+        for &(count, max_dist, s) in &sample {
+            assert_eq!(deletions(&vec(s), max_dist).len(), count);
+        }
+    }
+
+    #[test]
+    fn insert() {
+        let mut index = DeletionIndex::new(1);
+        assert!(index.is_empty());
+        assert!(index.insert("mailbox", 1));
+        assert!(index.insert("martha", 2));
+        assert!(!index.insert("mailbox", 3));
+        assert_eq!(index.len(), 2);
+        assert_eq!(index.frequency("mailbox"), Some(4));
+        assert_eq!(index.frequency("martha"), Some(2));
+        assert_eq!(index.frequency("marta"), None);
+    }
+
+    #[test]
+    fn lookup() {
+        let words = words();
+        let osa = BoundedOsa::new();
+        let mut index = DeletionIndex::new(2);
+        for (i, w) in words.iter().enumerate() {
+            index.insert(w, (i % 7) as u64);
+        }
+        for query in &["", "mailbox", "mailbx", "marhta", "boxmail", "もしもし", "xxxx"] {
+            for max_dist in 0..3 {
+                let found: Vec<(&str, usize)> = index.lookup(query, max_dist)
+                    .iter()
+                    .map(|s| (s.word, s.distance))
+                    .collect();
+                let mut expected: Vec<(&str, usize)> = words.iter()
+                    .filter_map(|w| osa.distance(&vec(query), &vec(w), max_dist).map(|d| (&w[..], d)))
+                    .collect();
+                let mut found_sorted = found.clone();
+                found_sorted.sort();
+                expected.sort();
+                assert_eq!(found_sorted, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn ranking() {
+        let mut index = DeletionIndex::new(2);
+        index.insert("marta", 5);
+        index.insert("martha", 1);
+        index.insert("marty", 10);
+        index.insert("marti", 10);
+        index.insert("mart", 3);
+        let found: Vec<(&str, usize, u64)> = index.lookup("marth", 2)
+            .iter()
+            .map(|s| (s.word, s.distance, s.frequency))
+            .collect();
+        assert_eq!(found, vec![
+            ("marti",  1, 10),
+            ("marty",  1, 10),
+            ("marta",  1, 5),
+            ("mart",   1, 3),
+            ("martha", 1, 1),
+        ]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn lookup_too_far() {
+        let index = DeletionIndex::new(1);
+        index.lookup("mailbox", 2);
+    }
+}
@@ -0,0 +1,70 @@
+//! Indexes for fast fuzzy lookups in large collections.
+//!
+//!
+//! # Basic Usage
+//!
+//! BK-tree:
+//! ```rust
+//! use eddie::Levenshtein;
+//! use eddie::index::BKTree;
+//!
+//! let mut tree = BKTree::new(Levenshtein::new());
+//! tree.extend(vec!["mailbox", "boxmail", "mail"]);
+//! let found = tree.find_within("mailbx", 1);
+//! assert_eq!(found, vec![(1, &"mailbox")]);
+//! ```
+//!
+//! Symmetric deletion index:
+//! ```rust
+//! use eddie::index::DeletionIndex;
+//!
+//! let mut index = DeletionIndex::new(1);
+//! index.insert("mailbox", 10);
+//! index.insert("mail", 20);
+//! let found = index.lookup("mailbx", 1);
+//! assert_eq!(found[0].word, "mailbox");
+//! ```
+//!
+//! Q-gram index:
+//! ```rust
+//! use eddie::Levenshtein;
+//! use eddie::index::QGramIndex;
+//!
+//! let mut index = QGramIndex::new(2, Levenshtein::new());
+//! let id = index.insert("mailbox");
+//! index.insert("boxmail");
+//! assert_eq!(index.find_within("mailbx", 1), vec![(1, id)]);
+//! ```
+//!
+//! Vantage-point tree:
+//! ```rust
+//! use eddie::JaroWinkler;
+//! use eddie::index::VPTree;
+//!
+//! let tree = VPTree::new(JaroWinkler::new(), vec!["mailbox", "boxmail", "martha"]);
+//! let nearest = tree.find_nearest("marhta", 1);
+//! assert_eq!(nearest[0].1, &"martha");
+//! ```
+//!
+//! Multi-pattern search:
+//! ```rust
+//! use eddie::index::PatternIndex;
+//!
+//! let index = PatternIndex::new(1, &["mailbox", "martha"]);
+//! let found = index.find_all("Marhta's malbox");
+//! assert_eq!(index.get(found[0].id), "mailbox");
+//! ```
+
+
+mod bktree;
+mod deletion;
+mod qgram;
+mod pattern;
+mod vptree;
+
+pub use bktree::{BKTree, Iter};
+pub use deletion::{DeletionIndex, Suggestion};
+pub use qgram::{QGramIndex, QGramFilter};
+pub(crate) use qgram::{PAD, PAD_SYMBOL, symbols};
+pub use pattern::{PatternIndex, PatternMatch};
+pub use vptree::VPTree;
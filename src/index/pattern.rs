@@ -0,0 +1,371 @@
+use std::collections::VecDeque;
+use std::ops::Range;
+use crate::slice::Searcher;
+
+
+/// Id of the absent node.
+const NONE: usize = usize::MAX;
+
+
+/// # Multi-pattern approximate search.
+///
+/// Finds all occurrences of many patterns in a text within the given Levenshtein distance,
+/// much faster than searching for every pattern separately.
+///
+/// Every pattern is split into `max_dist + 1` pieces. As a single edit can break
+/// at most one piece, an occurrence within distance `max_dist` contains
+/// at least one of the pieces exactly (the pigeonhole principle).
+/// The pieces of all patterns are found at once with an [Aho-Corasick automaton][1],
+/// and only the text around them is verified with the bounded [`Sellers`][2] search.
+/// Patterns shorter than `max_dist + 1` can not be split, so they are searched one by one.
+///
+/// Patterns are identified by their indices in the list passed to the constructor.
+/// The index is built at once and does not support insertions.
+///
+/// [1]: https://en.wikipedia.org/wiki/Aho%E2%80%93Corasick_algorithm
+/// [2]: ../struct.Sellers.html
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::index::{PatternIndex, PatternMatch};
+///
+/// let watchlist = vec!["Martha Jones", "John Smith", "Jane Doe"];
+/// let index = PatternIndex::new(2, &watchlist);
+///
+/// let text = "Payment from Jon Smith to Marta Jnes.";
+/// let found: Vec<(&str, &str, usize)> = index.find_all(text).iter()
+///     .map(|m| (index.get(m.id), &text[m.start .. m.end], m.distance))
+///     .collect();
+/// assert_eq!(found, vec![
+///     ("John Smith", "Jon Smith", 1),
+///     ("Martha Jones", "Marta Jnes", 2),
+/// ]);
+/// ```
+pub struct PatternIndex {
+    max_dist: usize,
+    patterns: Vec<Vec<char>>,
+    sources: Vec<String>,
+    pieces: Vec<Piece>,
+    nodes: Vec<Node>,
+    // Patterns too short to be split into pieces.
+    short: Vec<usize>,
+}
+
+
+/// An occurrence of a pattern found by [`PatternIndex::find_all`][1].
+///
+/// [1]: struct.PatternIndex.html#method.find_all
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PatternMatch {
+    /// Pattern id.
+    pub id: usize,
+    /// Byte offset of the start of the occurrence in the text.
+    pub start: usize,
+    /// Byte offset of the end of the occurrence in the text.
+    pub end: usize,
+    /// Levenshtein distance between the pattern and the occurrence.
+    pub distance: usize,
+}
+
+
+/// A part of a pattern that must occur exactly.
+struct Piece {
+    pattern: usize,
+    // Offset and length in characters.
+    offset: usize,
+    len: usize,
+}
+
+
+/// A node of the Aho-Corasick automaton.
+struct Node {
+    // Sorted by characters.
+    edges: Vec<(char, usize)>,
+    fail: usize,
+    // Nearest node on the chain of failure links that has pieces ending in it.
+    output: usize,
+    pieces: Vec<usize>,
+}
+
+
+impl Node {
+    fn new() -> Self {
+        Node { edges: Vec::new(), fail: 0, output: NONE, pieces: Vec::new() }
+    }
+
+    fn child(&self, ch: char) -> Option<usize> {
+        self.edges
+            .binary_search_by_key(&ch, |&(ch, _)| ch)
+            .ok()
+            .map(|i| self.edges[i].1)
+    }
+}
+
+
+impl PatternIndex {
+    /// Builds an index of the patterns for the given maximum distance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::index::PatternIndex;
+    ///
+    /// let index = PatternIndex::new(1, &["mailbox", "martha"]);
+    /// assert_eq!(index.len(), 2);
+    /// ```
+    pub fn new<I, S>(max_dist: usize, patterns: I) -> Self
+    where
+        I: IntoIterator<Item=S>,
+        S: AsRef<str>,
+    {
+        let sources: Vec<String> = patterns.into_iter().map(|s| s.as_ref().to_string()).collect();
+        let patterns: Vec<Vec<char>> = sources.iter().map(|s| s.chars().collect()).collect();
+        let mut index = Self {
+            max_dist,
+            patterns: Vec::new(),
+            sources,
+            pieces: Vec::new(),
+            nodes: vec![Node::new()],
+            short: Vec::new(),
+        };
+
+        let parts = max_dist + 1;
+        for (id, pattern) in patterns.iter().enumerate() {
+            let len = pattern.len();
+            if len < parts {
+                index.short.push(id);
+                continue;
+            }
+            for i in 0 .. parts {
+                let offset = i * len / parts;
+                let end = (i + 1) * len / parts;
+                index.insert_piece(&pattern[offset .. end], Piece { pattern: id, offset, len: end - offset });
+            }
+        }
+        index.patterns = patterns;
+        index.link();
+        index
+    }
+
+    /// Returns the maximum distance.
+    pub fn max_dist(&self) -> usize {
+        self.max_dist
+    }
+
+    /// Returns a number of patterns in the index.
+    pub fn len(&self) -> usize {
+        self.patterns.len()
+    }
+
+    /// Returns `true` if the index contains no patterns.
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// Returns the pattern with the given id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no pattern with such id.
+    pub fn get(&self, id: usize) -> &str {
+        &self.sources[id]
+    }
+
+    /// Finds all occurrences of the patterns in the text.
+    /// Returns them sorted by their start positions (byte offsets in the text),
+    /// then by pattern ids.
+    ///
+    /// Overlapping occurrences of the same pattern are reported once, as in [`Sellers`][1].
+    /// Empty patterns do not match anything.
+    ///
+    /// [1]: ../struct.Sellers.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::index::{PatternIndex, PatternMatch};
+    ///
+    /// let index = PatternIndex::new(1, &["mailbox", "box"]);
+    /// assert_eq!(index.find_all("a malbox"), vec![
+    ///     PatternMatch { id: 0, start: 2, end: 8, distance: 1 },
+    ///     PatternMatch { id: 1, start: 5, end: 8, distance: 0 },
+    /// ]);
+    /// ```
+    pub fn find_all(&self, text: &str) -> Vec<PatternMatch> {
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
+        let k = self.max_dist;
+
+        // Windows of the text around exact occurrences of the pieces.
+        let mut windows: Vec<(usize, usize, usize)> = Vec::new();
+        let mut node = 0;
+        for (i, &(_, ch)) in chars.iter().enumerate() {
+            node = self.next(node, ch);
+            let mut out = if self.nodes[node].pieces.is_empty() { self.nodes[node].output } else { node };
+            while out != NONE {
+                for &p in &self.nodes[out].pieces {
+                    let piece = &self.pieces[p];
+                    let m = self.patterns[piece.pattern].len();
+                    let end = i + 1;
+                    let lo = (end - piece.len).saturating_sub(piece.offset + k);
+                    let hi = min!(chars.len(), end + m - piece.offset - piece.len + k);
+                    windows.push((piece.pattern, lo, hi));
+                }
+                out = self.nodes[out].output;
+            }
+        }
+        windows.extend(self.short.iter().map(|&id| (id, 0, chars.len())));
+
+        windows.sort_unstable();
+        let mut found = Vec::new();
+        let mut i = 0;
+        while i < windows.len() {
+            let (id, lo, mut hi) = windows[i];
+            i += 1;
+            while i < windows.len() && windows[i].0 == id && windows[i].1 <= hi {
+                hi = max!(hi, windows[i].2);
+                i += 1;
+            }
+            self.verify(id, &chars, lo .. hi, text.len(), &mut found);
+        }
+
+        found.sort_unstable_by_key(|m| (m.start, m.id, m.end));
+        found
+    }
+
+    /// Searches for the pattern in a part of the text.
+    fn verify(&self, id: usize, chars: &[(usize, char)], range: Range<usize>, text_len: usize, found: &mut Vec<PatternMatch>) {
+        let mut searcher = Searcher::new(&self.patterns[id], self.max_dist);
+        let mut matches = Vec::new();
+        for i in range {
+            let (start, ch) = chars[i];
+            let end = chars.get(i + 1).map_or(text_len, |&(end, _)| end);
+            matches.extend(searcher.push(start, end, ch));
+        }
+        matches.extend(searcher.finish());
+        found.extend(matches.into_iter().map(|m| PatternMatch {
+            id,
+            start: m.start,
+            end: m.end,
+            distance: m.distance,
+        }));
+    }
+
+    /// Follows the automaton transition, falling back along the failure links.
+    fn next(&self, mut node: usize, ch: char) -> usize {
+        loop {
+            if let Some(child) = self.nodes[node].child(ch) { return child; }
+            if node == 0 { return 0; }
+            node = self.nodes[node].fail;
+        }
+    }
+
+    fn insert_piece(&mut self, chars: &[char], piece: Piece) {
+        let mut node = 0;
+        for &ch in chars {
+            node = match self.nodes[node].edges.binary_search_by_key(&ch, |&(ch, _)| ch) {
+                Ok(i) => self.nodes[node].edges[i].1,
+                Err(i) => {
+                    let child = self.nodes.len();
+                    self.nodes.push(Node::new());
+                    self.nodes[node].edges.insert(i, (ch, child));
+                    child
+                }
+            };
+        }
+        self.nodes[node].pieces.push(self.pieces.len());
+        self.pieces.push(piece);
+    }
+
+    /// Sets failure and output links in breadth-first order.
+    fn link(&mut self) {
+        let mut queue: VecDeque<usize> = self.nodes[0].edges.iter().map(|&(_, child)| child).collect();
+        while let Some(node) = queue.pop_front() {
+            for i in 0 .. self.nodes[node].edges.len() {
+                let (ch, child) = self.nodes[node].edges[i];
+                let fail = if node == 0 { 0 } else { self.next(self.nodes[node].fail, ch) };
+                let fail = if fail == child { 0 } else { fail };
+                let output = if self.nodes[fail].pieces.is_empty() { self.nodes[fail].output } else { fail };
+                self.nodes[child].fail = fail;
+                self.nodes[child].output = output;
+                queue.push_back(child);
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{PatternIndex, PatternMatch};
+    use crate::Sellers;
+
+    fn naive(patterns: &[&str], max_dist: usize, text: &str) -> Vec<PatternMatch> {
+        let mut found: Vec<PatternMatch> = patterns.iter().enumerate()
+            .flat_map(|(id, pattern)| Sellers::new(max_dist).find_iter(pattern, text)
+                .map(move |m| PatternMatch { id, start: m.start, end: m.end, distance: m.distance }))
+            .collect();
+        found.sort_unstable_by_key(|m| (m.start, m.id, m.end));
+        found
+    }
+
+    #[test]
+    fn matches_sellers() {
+        let patterns = [
+            "mailbox", "boxmail", "mail", "box", "martha", "marhta", "もしもし",
+            "ail", "a", "ab", "", "mailboxes", "ox, m", "aaaa",
+        ];
+        let texts = [
+            "",
+            "mailbox",
+            "mailbox boxmail mail box mialbox, malbox もしもし mailboxes",
+            "martha and marhta met at the mlbx",
+            "aaaaaaaaaa baaab aabaa",
+            "もしまし, もし, しもしも",
+        ];
+        for max_dist in 0..4 {
+            let index = PatternIndex::new(max_dist, patterns);
+            for text in &texts {
+                assert_eq!(index.find_all(text), naive(&patterns, max_dist, text), "{} {}", max_dist, text);
+            }
+        }
+    }
+
+    #[test]
+    fn shared_pieces() {
+        // Pieces shared by patterns and pieces that are suffixes of other pieces.
+        let patterns = ["abcdef", "abcxyz", "bcdefg", "cdef", "ef", "f"];
+        let text = "xxabcdefgxx abcxyy zbcdef";
+        for max_dist in 0..3 {
+            let index = PatternIndex::new(max_dist, patterns);
+            assert_eq!(index.find_all(text), naive(&patterns, max_dist, text));
+        }
+    }
+
+    #[test]
+    fn accessors() {
+        let index = PatternIndex::new(2, vec![String::from("mailbox"), String::from("ab")]);
+        assert_eq!(index.len(), 2);
+        assert!(!index.is_empty());
+        assert_eq!(index.max_dist(), 2);
+        assert_eq!(index.get(0), "mailbox");
+        assert_eq!(index.get(1), "ab");
+        let index = PatternIndex::new(1, Vec::<&str>::new());
+        assert!(index.is_empty());
+        assert_eq!(index.find_all("mailbox"), vec![]);
+    }
+
+    #[test]
+    fn many_patterns() {
+        let words = ["mail", "box", "mart", "ha", "sel", "lers", "tree", "ind", "ex"];
+        let patterns: Vec<String> = words.iter()
+            .flat_map(|w1| words.iter().map(move |w2| format!("{}{}", w1, w2)))
+            .collect();
+        let patterns: Vec<&str> = patterns.iter().map(|s| s.as_str()).collect();
+        let text = "the mailbox of martha, near the sellers tree index, mialbox and indextree";
+        for max_dist in 0..3 {
+            let index = PatternIndex::new(max_dist, &patterns);
+            assert_eq!(index.find_all(text), naive(&patterns, max_dist, text));
+        }
+    }
+}
@@ -0,0 +1,549 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::{BinaryHeap, HashMap};
+use crate::metric::Distance;
+use crate::normalize::Normalizer;
+use crate::utils::graphemes;
+use crate::{Levenshtein, DamerauLevenshtein, Hamming};
+use crate::sync;
+
+
+/// Character used to pad strings before splitting them into q-grams.
+/// It is a Unicode noncharacter, so it does not normally occur in text.
+pub(crate) const PAD: char = '\u{FFFF}';
+
+/// The padding character as a symbol of a string split by [`symbols`].
+pub(crate) const PAD_SYMBOL: &str = "\u{FFFF}";
+
+
+/// Edit distances that can be filtered by q-gram counts.
+///
+/// A single edit operation changes at most `grams_per_edit(q)` q-grams of a string,
+/// so strings within distance `k` share at least `|grams| - k * grams_per_edit(q)` q-grams.
+/// Every edit also changes the length by at most one, so strings within distance `k`
+/// differ in length by at most `k`.
+///
+/// Implemented by `Levenshtein` and `Hamming` (`q` grams per edit)
+/// and `DamerauLevenshtein` (`q + 1` grams per edit, as a transposition touches two characters),
+/// as well as their thread-safe counterparts from `eddie::sync`.
+///
+/// Edits, q-grams and lengths are counted in the symbols the metric compares:
+/// characters, or grapheme clusters for metrics in the grapheme mode,
+/// of the strings normalized by the metric's normalizer, if any.
+pub trait QGramFilter: Distance<str> {
+    /// Returns the maximum number of q-grams changed by a single edit.
+    fn grams_per_edit(&self, q: usize) -> usize;
+
+    /// Returns `true` if the metric edits grapheme clusters instead of characters.
+    fn edits_graphemes(&self) -> bool { false }
+
+    /// Returns the string as compared by the metric, i.e. normalized if it has a normalizer.
+    fn normalize<'a>(&self, string: &'a str) -> Cow<'a, str> { Cow::Borrowed(string) }
+}
+
+
+impl<const N: usize> QGramFilter for Levenshtein<N> {
+    fn grams_per_edit(&self, q: usize) -> usize { q }
+    fn edits_graphemes(&self) -> bool { self.grapheme_mode() }
+    fn normalize<'a>(&self, string: &'a str) -> Cow<'a, str> { normalize(self.normalizer(), string) }
+}
+
+impl QGramFilter for Hamming {
+    fn grams_per_edit(&self, q: usize) -> usize { q }
+    fn edits_graphemes(&self) -> bool { self.grapheme_mode() }
+    fn normalize<'a>(&self, string: &'a str) -> Cow<'a, str> { normalize(self.normalizer(), string) }
+}
+
+impl<const N: usize> QGramFilter for DamerauLevenshtein<N> {
+    fn grams_per_edit(&self, q: usize) -> usize { q + 1 }
+    fn edits_graphemes(&self) -> bool { self.grapheme_mode() }
+    fn normalize<'a>(&self, string: &'a str) -> Cow<'a, str> { normalize(self.normalizer(), string) }
+}
+
+impl QGramFilter for sync::Levenshtein {
+    fn grams_per_edit(&self, q: usize) -> usize { q }
+}
+
+impl QGramFilter for sync::DamerauLevenshtein {
+    fn grams_per_edit(&self, q: usize) -> usize { q + 1 }
+}
+
+
+/// # Q-gram inverted index.
+///
+/// An index for approximate search over large collections of short strings.
+/// It maps every q-gram (substring of `q` characters, or grapheme clusters for metrics
+/// in the grapheme mode, with the strings normalized by the metric and padded at both ends)
+/// to the ids of the strings containing it.
+///
+/// A query first collects candidates using the count filter (strings within distance `k`
+/// share many q-grams with the query) and the length filter, and only the candidates
+/// are verified with the exact [metric][1]. See [the detailed description][2].
+///
+/// Strings are identified by ids returned on insertion. Ids are never reused.
+///
+/// [1]: trait.QGramFilter.html
+/// [2]: http://www.vldb.org/conf/2001/P491.pdf
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::Levenshtein;
+/// use eddie::index::QGramIndex;
+///
+/// let mut index = QGramIndex::new(2, Levenshtein::new());
+/// let mailbox = index.insert("mailbox");
+/// let boxmail = index.insert("boxmail");
+/// let martha = index.insert("martha");
+///
+/// assert_eq!(index.find_within("mailbx", 1), vec![(1, mailbox)]);
+/// assert_eq!(index.find_nearest("marta", 1, 2), vec![(1, martha)]);
+/// assert_eq!(index.get(boxmail), Some("boxmail"));
+/// ```
+pub struct QGramIndex<M> {
+    q: usize,
+    metric: M,
+    strings: Vec<Option<String>>,
+    lengths: Vec<usize>,
+    postings: HashMap<String, Vec<(usize, usize)>>,
+    len: usize,
+    counts: RefCell<HashMap<usize, usize>>,
+}
+
+
+impl<M> QGramIndex<M> {
+    /// Creates a new empty index of q-grams of the given size, using the given metric.
+    /// Panics if `q` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::DamerauLevenshtein;
+    /// use eddie::index::QGramIndex;
+    ///
+    /// let index = QGramIndex::new(3, DamerauLevenshtein::new());
+    /// ```
+    pub fn new(q: usize, metric: M) -> Self {
+        assert!(q > 0, "Q-gram size should be positive");
+        Self {
+            q,
+            metric,
+            strings: Vec::new(),
+            lengths: Vec::new(),
+            postings: HashMap::new(),
+            len: 0,
+            counts: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the q-gram size.
+    pub fn q(&self) -> usize {
+        self.q
+    }
+
+    /// Returns a number of strings in the index.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the index contains no strings.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the metric used by the index.
+    pub fn metric(&self) -> &M {
+        &self.metric
+    }
+
+    /// Returns the string with the given id, or `None` if it was removed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// # use eddie::index::QGramIndex;
+    /// let mut index = QGramIndex::new(2, Levenshtein::new());
+    /// let id = index.insert("mailbox");
+    /// assert_eq!(index.get(id), Some("mailbox"));
+    /// ```
+    pub fn get(&self, id: usize) -> Option<&str> {
+        self.strings.get(id)?.as_deref()
+    }
+}
+
+
+impl<M: QGramFilter> QGramIndex<M> {
+    /// Inserts a string into the index and returns its id.
+    /// Equal strings inserted several times get different ids.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// # use eddie::index::QGramIndex;
+    /// let mut index = QGramIndex::new(2, Levenshtein::new());
+    /// assert_eq!(index.insert("mailbox"), 0);
+    /// assert_eq!(index.insert("mailbox"), 1);
+    /// assert_eq!(index.len(), 2);
+    /// ```
+    pub fn insert(&mut self, string: &str) -> usize {
+        let id = self.strings.len();
+        let text = self.metric.normalize(string);
+        let symbols = symbols(&self.metric, &text);
+        for (gram, count) in grams(&symbols, self.q) {
+            self.postings.entry(gram).or_default().push((id, count));
+        }
+        self.strings.push(Some(string.to_string()));
+        self.lengths.push(symbols.len());
+        self.len += 1;
+        id
+    }
+
+    /// Removes the string with the given id from the index.
+    /// Returns the string, or `None` if there was no such id.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// # use eddie::index::QGramIndex;
+    /// let mut index = QGramIndex::new(2, Levenshtein::new());
+    /// let id = index.insert("mailbox");
+    ///
+    /// assert_eq!(index.remove(id), Some("mailbox".to_string()));
+    /// assert_eq!(index.remove(id), None);
+    /// assert_eq!(index.find_within("mailbox", 1), vec![]);
+    /// ```
+    pub fn remove(&mut self, id: usize) -> Option<String> {
+        let string = self.strings.get_mut(id)?.take()?;
+        let text = self.metric.normalize(&string);
+        for (gram, _) in grams(&symbols(&self.metric, &text), self.q) {
+            let ids = self.postings.get_mut(&gram).unwrap();
+            ids.retain(|&(other, _)| other != id);
+            if ids.is_empty() { self.postings.remove(&gram); }
+        }
+        self.len -= 1;
+        Some(string)
+    }
+
+    /// Finds all strings within the given distance from the query.
+    /// Returns pairs of distances and ids, sorted by distance, then by id.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// # use eddie::index::QGramIndex;
+    /// let mut index = QGramIndex::new(2, Levenshtein::new());
+    /// for word in &["mailbox", "boxmail", "mail", "martha"] {
+    ///     index.insert(word);
+    /// }
+    /// assert_eq!(index.find_within("mal", 1), vec![(1, 2)]);
+    /// ```
+    pub fn find_within(&self, query: &str, max_dist: usize) -> Vec<(usize, usize)> {
+        let text = self.metric.normalize(query);
+        let mut found: Vec<(usize, usize)> = self.candidates(&symbols(&self.metric, &text), max_dist)
+            .into_iter()
+            .filter_map(|(_, id)| {
+                let dist = self.verify(query, id);
+                if dist <= max_dist { Some((dist, id)) } else { None }
+            })
+            .collect();
+        found.sort();
+        found
+    }
+
+    /// Finds `k` strings nearest to the query among those within the given distance.
+    /// Returns pairs of distances and ids, sorted by distance, then by id.
+    ///
+    /// Candidates sharing more q-grams with the query are verified first,
+    /// so that the filter gets tighter as the best results are found.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// # use eddie::index::QGramIndex;
+    /// let mut index = QGramIndex::new(2, Levenshtein::new());
+    /// for word in &["mailbox", "boxmail", "mail", "martha"] {
+    ///     index.insert(word);
+    /// }
+    /// assert_eq!(index.find_nearest("mailbx", 2, 3), vec![(1, 0), (2, 2)]);
+    /// ```
+    pub fn find_nearest(&self, query: &str, k: usize, max_dist: usize) -> Vec<(usize, usize)> {
+        if k == 0 { return Vec::new(); }
+
+        let text = self.metric.normalize(query);
+        let symbols = symbols(&self.metric, &text);
+        let query_len = symbols.len();
+        let per_edit = self.metric.grams_per_edit(self.q);
+        let mut candidates = self.candidates(&symbols, max_dist);
+        candidates.sort_by(|(shared1, id1), (shared2, id2)| shared2.cmp(shared1).then(id1.cmp(id2)));
+
+        // Best strings found so far, the farthest one on top.
+        let mut best: BinaryHeap<(usize, usize)> = BinaryHeap::new();
+
+        for (shared, id) in candidates {
+            let radius = match best.peek() {
+                Some(&(worst, _)) if best.len() == k => {
+                    if worst == 0 { break; }
+                    worst - 1
+                }
+                _ => max_dist,
+            };
+            let len = self.lengths[id];
+            if max!(len, query_len) - min!(len, query_len) > radius { continue; }
+            if shared < self.min_shared(query_len, len, radius, per_edit) { continue; }
+
+            let dist = self.verify(query, id);
+            if dist <= radius {
+                if best.len() == k { best.pop(); }
+                best.push((dist, id));
+            }
+        }
+
+        best.into_sorted_vec()
+    }
+
+    /// Collects the strings passing the count and length filters,
+    /// with numbers of q-grams they share with the query split into symbols.
+    fn candidates(&self, query: &[&str], max_dist: usize) -> Vec<(usize, usize)> {
+        let query_len = query.len();
+        let per_edit = self.metric.grams_per_edit(self.q);
+        let length_ok = |len: usize| max!(len, query_len) - min!(len, query_len) <= max_dist;
+
+        let counts = &mut *self.counts.borrow_mut();
+        counts.clear();
+        for (gram, query_count) in grams(query, self.q) {
+            if let Some(ids) = self.postings.get(&gram) {
+                for &(id, count) in ids {
+                    *counts.entry(id).or_insert(0) += min!(count, query_count);
+                }
+            }
+        }
+
+        // Short strings may need to share no q-grams at all,
+        // and then all the strings of suitable lengths are candidates.
+        let min_len = query_len.saturating_sub(max_dist);
+        if self.min_shared(query_len, min_len, max_dist, per_edit) == 0 {
+            return self.strings.iter()
+                .enumerate()
+                .filter(|(id, string)| string.is_some() && length_ok(self.lengths[*id]))
+                .map(|(id, _)| (counts.get(&id).copied().unwrap_or(0), id))
+                .collect();
+        }
+
+        counts.iter()
+            .filter(|&(&id, &shared)| {
+                let len = self.lengths[id];
+                length_ok(len) && shared >= self.min_shared(query_len, len, max_dist, per_edit)
+            })
+            .map(|(&id, &shared)| (shared, id))
+            .collect()
+    }
+
+    /// Returns the minimal number of q-grams shared by strings of the given lengths
+    /// within the given distance.
+    fn min_shared(&self, len1: usize, len2: usize, max_dist: usize, per_edit: usize) -> usize {
+        let grams = max!(len1, len2) + self.q - 1;
+        grams.saturating_sub(max_dist.saturating_mul(per_edit))
+    }
+
+    fn verify(&self, query: &str, id: usize) -> usize {
+        let string = self.strings[id].as_ref().unwrap();
+        self.metric.distance(query, string)
+    }
+}
+
+
+/// Normalizes the string with the normalizer, if any.
+fn normalize(normalizer: Option<Normalizer>, string: &str) -> Cow<'_, str> {
+    match normalizer {
+        Some(normalizer) => Cow::Owned(normalizer.normalize(string)),
+        None => Cow::Borrowed(string),
+    }
+}
+
+
+/// Splits the string normalized by the metric into the symbols it edits:
+/// characters, or grapheme clusters in the grapheme mode.
+pub(crate) fn symbols<'a, M: QGramFilter>(metric: &M, string: &'a str) -> Vec<&'a str> {
+    if metric.edits_graphemes() {
+        graphemes(string).collect()
+    } else {
+        string.char_indices().map(|(i, ch)| &string[i .. i + ch.len_utf8()]).collect()
+    }
+}
+
+
+/// Splits the symbols padded with `q - 1` padding symbols at both ends into q-grams,
+/// and counts the occurrences of each one.
+fn grams(symbols: &[&str], q: usize) -> HashMap<String, usize> {
+    let padding = vec![PAD_SYMBOL; q - 1];
+    let padded: Vec<&str> = padding.iter()
+        .chain(symbols)
+        .chain(&padding)
+        .copied()
+        .collect();
+    let mut grams = HashMap::new();
+    for window in padded.windows(q) {
+        *grams.entry(window.concat()).or_insert(0) += 1;
+    }
+    grams
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{QGramIndex, QGramFilter, grams, symbols};
+    use crate::{Levenshtein, DamerauLevenshtein, Hamming};
+
+    fn words() -> Vec<String> {
+        let syllables = ["ma", "il", "bo", "x", "mar", "tha", "ta", "も", "e\u{301}", ""];
+        let mut words = Vec::new();
+        for s1 in &syllables {
+            for s2 in &syllables {
+                for s3 in &syllables {
+                    words.push(format!("{}{}{}", s1, s2, s3));
+                }
+            }
+        }
+        words.sort();
+        words.dedup();
+        words
+    }
+
+    const QUERIES: [&str; 10] = ["", "m", "mailbox", "mailbx", "marhta", "boxmail", "もしもし", "xxxx", "ilmarbo", "mé\u{302}il"];
+
+    fn check<M: QGramFilter>(new: impl Fn() -> M) {
+        let words = words();
+        let metric = new();
+        for q in 1..4 {
+            let mut index = QGramIndex::new(q, new());
+            for word in &words {
+                index.insert(word);
+            }
+            for query in &QUERIES {
+                for max_dist in 0..4 {
+                    let expected: Vec<(usize, usize)> = {
+                        let mut expected: Vec<(usize, usize)> = words.iter()
+                            .enumerate()
+                            .map(|(id, w)| (metric.distance(query, w), id))
+                            .filter(|&(dist, _)| dist <= max_dist)
+                            .collect();
+                        expected.sort();
+                        expected
+                    };
+                    assert_eq!(index.find_within(query, max_dist), expected);
+                    for &k in &[1, 3, 10] {
+                        let nearest = index.find_nearest(query, k, max_dist);
+                        let dists: Vec<usize> = nearest.iter().map(|&(d, _)| d).collect();
+                        let expected_dists: Vec<usize> = expected.iter().take(k).map(|&(d, _)| d).collect();
+                        assert_eq!(dists, expected_dists);
+                        for &(dist, id) in &nearest {
+                            assert_eq!(metric.distance(query, &words[id]), dist);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn levenshtein() {
+        check(Levenshtein::new);
+    }
+
+    #[test]
+    fn damerau_levenshtein() {
+        check(DamerauLevenshtein::new);
+    }
+
+    #[test]
+    fn hamming() {
+        check(Hamming::new);
+    }
+
+    #[test]
+    fn grapheme_mode() {
+        check(|| { let mut lev = Levenshtein::new(); lev.set_grapheme_mode(true); lev });
+        check(|| { let mut damlev = DamerauLevenshtein::new(); damlev.set_grapheme_mode(true); damlev });
+        check(|| { let mut hamming = Hamming::new(); hamming.set_grapheme_mode(true); hamming });
+
+        let mut lev = Levenshtein::new();
+        lev.set_grapheme_mode(true);
+        let mut index = QGramIndex::new(2, lev);
+        let id = index.insert("cafe\u{301}s");
+        assert_eq!(index.find_within("cafés", 1), vec![(1, id)]);
+        assert_eq!(index.find_nearest("cafés", 1, 1), vec![(1, id)]);
+    }
+
+    #[test]
+    fn normalizer() {
+        use crate::normalize::{Normalizer, CaseFolding};
+        let mut normalizer = Normalizer::new();
+        normalizer
+            .set_case_folding(CaseFolding::Full)
+            .set_strip_diacritics(true);
+        check(|| Levenshtein::with_normalizer(normalizer));
+        check(|| DamerauLevenshtein::with_normalizer(normalizer));
+        check(|| { let mut lev = Levenshtein::with_normalizer(normalizer); lev.set_grapheme_mode(true); lev });
+
+        let mut index = QGramIndex::new(2, Levenshtein::with_normalizer(normalizer));
+        let id = index.insert("MAILBOX");
+        let strasse = index.insert("Straße");
+        assert_eq!(index.find_within("mailbox", 0), vec![(0, id)]);
+        assert_eq!(index.find_nearest("mäilbox", 1, 0), vec![(0, id)]);
+        assert_eq!(index.find_within("STRASSE", 0), vec![(0, strasse)]);
+        assert_eq!(index.remove(strasse), Some("Straße".to_string()));
+        assert_eq!(index.find_within("strasse", 1), vec![]);
+    }
+
+    #[test]
+    fn grams_count() {
+        let sample = [
+            (0, 1, ""),
+            (1, 1, "a"),
+            (2, 1, "ab"),
+            (2, 2, "a"),
+            (4, 2, "abc"),
+            (3, 2, "aa"),
+            (6, 3, "abcd"),
+        ];
+        for &(count, q, s) in &sample {
+            assert_eq!(grams(&symbols(&Levenshtein::new(), s), q).len(), count, "{} {}", q, s);
+        }
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut index = QGramIndex::new(2, Levenshtein::new());
+        assert!(index.is_empty());
+        let mailbox = index.insert("mailbox");
+        let boxmail = index.insert("boxmail");
+        let mailbox2 = index.insert("mailbox");
+        assert_eq!(index.len(), 3);
+        assert_eq!(index.find_within("mailbox", 0), vec![(0, mailbox), (0, mailbox2)]);
+
+        assert_eq!(index.remove(mailbox), Some("mailbox".to_string()));
+        assert_eq!(index.remove(mailbox), None);
+        assert_eq!(index.remove(100), None);
+        assert_eq!(index.len(), 2);
+        assert_eq!(index.get(mailbox), None);
+        assert_eq!(index.find_within("mailbox", 0), vec![(0, mailbox2)]);
+        assert_eq!(index.find_nearest("mailbox", 5, 10), vec![(0, mailbox2), (6, boxmail)]);
+
+        assert_eq!(index.remove(mailbox2), Some("mailbox".to_string()));
+        assert_eq!(index.remove(boxmail), Some("boxmail".to_string()));
+        assert!(index.is_empty());
+        assert!(index.postings.is_empty());
+        assert_eq!(index.insert("mail"), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_q() {
+        QGramIndex::new(0, Levenshtein::new());
+    }
+}
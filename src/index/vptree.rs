@@ -0,0 +1,348 @@
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use crate::metric::RelDistance;
+
+
+/// # Vantage-point tree.
+///
+/// An index for fast nearest neighbour lookups in a metric space,
+/// generic over any [distance returning `f64`][1]: relative distances of the metrics
+/// from this crate, or any function.
+///
+/// Every node keeps a vantage point and the median distance from it to the keys in its subtree,
+/// splitting them into the inner and the outer half. Searches prune the halves
+/// that can not contain any results according to the triangle inequality,
+/// so the results are exact only if the distance is a true metric.
+/// `Levenshtein`, `DamerauLevenshtein`, `Hamming` and `Eudex` distances (converted to `f64`)
+/// are true metrics, while `rel_dist` of `Levenshtein`, `DamerauLevenshtein`,
+/// `Jaro` and `JaroWinkler` are not: with them the tree may miss some results,
+/// though it usually finds most of them.
+///
+/// The tree is built at once from all the keys and does not support insertions.
+///
+/// See [the detailed description][2].
+///
+/// [1]: ../metric/trait.RelDistance.html
+/// [2]: https://en.wikipedia.org/wiki/Vantage-point_tree
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::Levenshtein;
+/// use eddie::index::VPTree;
+///
+/// let tree = VPTree::new(Levenshtein::new(), vec!["mailbox", "boxmail", "mail", "martha"]);
+///
+/// let found = tree.find_within("mailbx", 0.2);
+/// assert_eq!(found, vec![(1.0 / 7.0, &"mailbox")]);
+///
+/// let nearest = tree.find_nearest("marta", 1);
+/// assert_eq!(nearest, vec![(1.0 / 6.0, &"martha")]);
+/// ```
+///
+/// Any function on keys can be used as a distance:
+/// ```rust
+/// use eddie::Levenshtein;
+/// use eddie::index::VPTree;
+///
+/// let lev = Levenshtein::new();
+/// let dist = |s1: &&str, s2: &&str| lev.distance(s1, s2) as f64;
+/// let tree = VPTree::new(dist, vec!["mailbox", "boxmail", "mail", "martha"]);
+///
+/// let nearest = tree.find_nearest(&"marta", 2);
+/// assert_eq!(nearest, vec![(1.0, &"martha"), (3.0, &"mail")]);
+/// ```
+pub struct VPTree<K, M> {
+    metric: M,
+    // Nodes in preorder: the node at index `i` has its inner subtree at `i + 1 .. mids[i]`
+    // and its outer subtree at `mids[i] .. end`, where `end` is the end of its own subtree.
+    keys: Vec<K>,
+    radii: Vec<f64>,
+    mids: Vec<usize>,
+}
+
+
+impl<K, M> VPTree<K, M> {
+    /// Returns a number of keys in the tree.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if the tree contains no keys.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns a reference to the metric used by the tree.
+    pub fn metric(&self) -> &M {
+        &self.metric
+    }
+
+    /// Returns an iterator over the keys in the tree, in no particular order.
+    pub fn iter(&self) -> std::slice::Iter<'_, K> {
+        self.keys.iter()
+    }
+}
+
+
+impl<K, M: RelDistance<K>> VPTree<K, M> {
+    /// Builds a tree from the given keys, using the given metric.
+    ///
+    /// Takes `O(n log n)` distance computations.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::Jaro;
+    /// use eddie::index::VPTree;
+    ///
+    /// let tree = VPTree::new(Jaro::new(), vec!["mailbox".to_string(), "martha".to_string()]);
+    /// assert_eq!(tree.len(), 2);
+    /// ```
+    pub fn new<I: IntoIterator<Item=K>>(metric: M, keys: I) -> Self {
+        let mut items: Vec<(f64, K)> = keys.into_iter().map(|key| (0.0, key)).collect();
+        let len = items.len();
+        let mut radii = vec![0.0; len];
+        let mut mids = vec![0; len];
+
+        let mut stack = vec![(0, len)];
+        while let Some((lo, hi)) = stack.pop() {
+            if hi - lo < 2 {
+                if lo < hi { mids[lo] = hi; }
+                continue;
+            }
+            let (vantage, rest) = items[lo .. hi].split_first_mut().unwrap();
+            for (dist, key) in rest.iter_mut() {
+                *dist = metric.rel_dist(&vantage.1, key);
+            }
+            // The inner half (including the median) gets the keys not farther than the median.
+            let median = (rest.len() - 1) / 2;
+            rest.select_nth_unstable_by(median, |(d1, _), (d2, _)| cmp(*d1, *d2));
+            radii[lo] = rest[median].0;
+            let mid = lo + 1 + median + 1;
+            mids[lo] = mid;
+            stack.push((lo + 1, mid));
+            stack.push((mid, hi));
+        }
+
+        let keys = items.into_iter().map(|(_, key)| key).collect();
+        Self { metric, keys, radii, mids }
+    }
+}
+
+
+impl<K, M> VPTree<K, M> {
+    /// Finds all keys within the given distance from the query.
+    /// Returns pairs of distances and keys, sorted by distance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::JaroWinkler;
+    /// # use eddie::index::VPTree;
+    /// let tree = VPTree::new(JaroWinkler::new(), vec!["mailbox", "boxmail", "mail", "martha"]);
+    ///
+    /// let found: Vec<_> = tree.find_within("mailbx", 0.1)
+    ///     .into_iter()
+    ///     .map(|(_, key)| key)
+    ///     .collect();
+    /// assert_eq!(found, vec![&"mailbox", &"mail"]);
+    /// ```
+    pub fn find_within<Q>(&self, query: &Q, max_dist: f64) -> Vec<(f64, &K)>
+    where
+        Q: ?Sized,
+        K: Borrow<Q>,
+        M: RelDistance<Q>,
+    {
+        let mut found = Vec::new();
+        let mut stack = vec![(0, self.keys.len())];
+
+        while let Some((lo, hi)) = stack.pop() {
+            if lo >= hi { continue; }
+            let dist = self.metric.rel_dist(query, self.keys[lo].borrow());
+            if dist <= max_dist {
+                found.push((dist, &self.keys[lo]));
+            }
+            let (radius, mid) = (self.radii[lo], self.mids[lo]);
+            if dist - max_dist <= radius { stack.push((lo + 1, mid)); }
+            if dist + max_dist >= radius { stack.push((mid, hi)); }
+        }
+
+        found.sort_by(|(d1, _), (d2, _)| cmp(*d1, *d2));
+        found
+    }
+
+    /// Finds `k` keys nearest to the query.
+    /// Returns pairs of distances and keys, sorted by distance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// # use eddie::index::VPTree;
+    /// let lev = Levenshtein::new();
+    /// let dist = |s1: &&str, s2: &&str| lev.distance(s1, s2) as f64;
+    /// let tree = VPTree::new(dist, vec!["mailbox", "boxmail", "mail", "martha"]);
+    ///
+    /// let found = tree.find_nearest(&"mailbx", 2);
+    /// assert_eq!(found, vec![(1.0, &"mailbox"), (2.0, &"mail")]);
+    /// ```
+    pub fn find_nearest<Q>(&self, query: &Q, k: usize) -> Vec<(f64, &K)>
+    where
+        Q: ?Sized,
+        K: Borrow<Q>,
+        M: RelDistance<Q>,
+    {
+        if k == 0 { return Vec::new(); }
+
+        // Best keys found so far, sorted by distance.
+        let mut best: Vec<(f64, usize)> = Vec::with_capacity(k + 1);
+        // Subtrees to visit, with lower bounds of their distances to the query.
+        let mut stack = vec![(0.0, 0, self.keys.len())];
+
+        while let Some((bound, lo, hi)) = stack.pop() {
+            if lo >= hi { continue; }
+            let tau = if best.len() == k { best[k - 1].0 } else { f64::INFINITY };
+            if bound > tau { continue; }
+
+            let dist = self.metric.rel_dist(query, self.keys[lo].borrow());
+            if dist < tau {
+                let i = best.partition_point(|&(d, _)| d <= dist);
+                best.insert(i, (dist, lo));
+                best.truncate(k);
+            }
+
+            let tau = if best.len() == k { best[k - 1].0 } else { f64::INFINITY };
+            let (radius, mid) = (self.radii[lo], self.mids[lo]);
+            let inner = (max_f64(bound, dist - radius), lo + 1, mid);
+            let outer = (max_f64(bound, radius - dist), mid, hi);
+            // Visit the half containing the query first.
+            let (near, far) = if dist <= radius { (inner, outer) } else { (outer, inner) };
+            if far.0 <= tau { stack.push(far); }
+            if near.0 <= tau { stack.push(near); }
+        }
+
+        best.into_iter().map(|(dist, i)| (dist, &self.keys[i])).collect()
+    }
+}
+
+
+fn cmp(x1: f64, x2: f64) -> Ordering {
+    x1.partial_cmp(&x2).unwrap_or(Ordering::Equal)
+}
+
+
+fn max_f64(x1: f64, x2: f64) -> f64 {
+    if x1 > x2 { x1 } else { x2 }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::VPTree;
+    use crate::metric::{Distance, RelDistance};
+    use crate::{Levenshtein, DamerauLevenshtein, Hamming, Jaro, JaroWinkler};
+    use crate::phonetic::Eudex;
+
+    fn words() -> Vec<String> {
+        let syllables = ["ma", "il", "bo", "x", "mar", "tha", "ta", "も", ""];
+        let mut words = Vec::new();
+        for s1 in &syllables {
+            for s2 in &syllables {
+                for s3 in &syllables {
+                    words.push(format!("{}{}{}", s1, s2, s3));
+                }
+            }
+        }
+        words.sort();
+        words.dedup();
+        words
+    }
+
+    const QUERIES: [&str; 8] = ["", "m", "mailbox", "mailbx", "marhta", "boxmail", "もしもし", "ilmarbo"];
+
+    fn sorted<'a>(mut found: Vec<(f64, &'a &'a str)>) -> Vec<(f64, &'a &'a str)> {
+        found.sort_by(|(d1, w1), (d2, w2)| d1.partial_cmp(d2).unwrap().then(w1.cmp(w2)));
+        found
+    }
+
+    fn check<'a, M: RelDistance<&'a str>>(new: impl Fn() -> M, radii: &[f64], words: &'a [&'a str]) {
+        let metric = new();
+        let tree = VPTree::new(new(), words.to_vec());
+        assert_eq!(tree.len(), words.len());
+
+        for query in &QUERIES {
+            let expected = sorted(words.iter().map(|w| (metric.rel_dist(query, w), w)).collect());
+
+            for &max_dist in radii {
+                let within: Vec<(f64, &&str)> = expected.iter()
+                    .filter(|&&(d, _)| d <= max_dist)
+                    .copied()
+                    .collect();
+                assert_eq!(sorted(tree.find_within(query, max_dist)), within);
+            }
+
+            for &k in &[1, 3, 10, 1000] {
+                let found = tree.find_nearest(query, k);
+                let dists: Vec<f64> = found.iter().map(|&(d, _)| d).collect();
+                let expected_dists: Vec<f64> = expected.iter().take(k).map(|&(d, _)| d).collect();
+                assert_eq!(dists, expected_dists);
+                for &(dist, word) in &found {
+                    assert_eq!(metric.rel_dist(query, word), dist);
+                }
+            }
+        }
+    }
+
+    fn int_metric<'a, M: Distance<str>>(metric: M) -> impl Fn(&&'a str, &&'a str) -> f64 {
+        move |s1, s2| metric.distance(*s1, *s2) as f64
+    }
+
+    #[test]
+    fn metrics() {
+        let words = words();
+        let words: Vec<&str> = words.iter().map(|w| &w[..]).collect();
+        let radii = [0.0, 1.0, 2.0, 3.0];
+        check(|| int_metric(Levenshtein::new()), &radii, &words);
+        check(|| int_metric(DamerauLevenshtein::new()), &radii, &words);
+        check(|| int_metric(Hamming::new()), &radii, &words);
+        check(|| int_metric(Eudex::new()), &[0.0, 1.0, 10.0, 50.0], &words);
+    }
+
+    #[test]
+    fn not_metrics() {
+        // Results are not guaranteed to be exact, but must be correct.
+        let words = words();
+        let words: Vec<&str> = words.iter().map(|w| &w[..]).collect();
+        fn check_subset<M: RelDistance<str> + for<'a> RelDistance<&'a str>>(new: impl Fn() -> M, words: &[&str]) {
+            let metric = new();
+            let tree = VPTree::new(new(), words.to_vec());
+            for query in &QUERIES {
+                for &(dist, word) in &tree.find_within(*query, 0.3) {
+                    assert!(dist <= 0.3);
+                    assert_eq!(RelDistance::<str>::rel_dist(&metric, query, word), dist);
+                }
+                assert_eq!(tree.find_nearest(*query, 5).len(), 5);
+            }
+        }
+        check_subset(Levenshtein::new, &words);
+        check_subset(Jaro::new, &words);
+        check_subset(JaroWinkler::new, &words);
+    }
+
+    #[test]
+    fn small() {
+        let lev = Levenshtein::new();
+        let dist = |s1: &&str, s2: &&str| lev.distance(s1, s2) as f64;
+        let tree: VPTree<&str, _> = VPTree::new(&dist, vec![]);
+        assert!(tree.is_empty());
+        assert_eq!(tree.find_within(&"mailbox", 10.0), vec![]);
+        assert_eq!(tree.find_nearest(&"mailbox", 1), vec![]);
+
+        let tree = VPTree::new(&dist, vec!["mailbox"]);
+        assert_eq!(tree.find_within(&"mailbx", 1.0), vec![(1.0, &"mailbox")]);
+        assert_eq!(tree.find_nearest(&"mailbx", 0), vec![]);
+        assert_eq!(tree.find_nearest(&"mailbx", 2), vec![(1.0, &"mailbox")]);
+        assert_eq!(tree.iter().collect::<Vec<_>>(), vec![&"mailbox"]);
+    }
+}
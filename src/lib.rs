@@ -81,6 +81,7 @@
 //! - `eddie::slice` for comparing generic slices `&[T]`.
 //!   Implementations in this module are significantly faster than those from `eddie::str`,
 //!   but will produce incorrect results for UTF-8 and other variable width character encodings.
+//!   For pure ASCII strings `eddie::str` dispatches to them automatically.
 //!
 //! Usage example:
 //!
@@ -95,6 +96,231 @@
 //! [6]: https://doc.rust-lang.org/std/primitive.char.html
 //!
 //!
+//! ## Grapheme clusters
+//!
+//! Metrics from `eddie::str` compare Unicode scalar values,
+//! so "é" written as "e" followed by a combining accent counts as two characters.
+//! In the grapheme mode they compare [extended grapheme clusters][27] instead,
+//! and relative metrics count lengths in clusters:
+//! ```rust
+//! use eddie::Levenshtein;
+//!
+//! let mut lev = Levenshtein::new();
+//! lev.set_grapheme_mode(true);
+//! assert_eq!(lev.distance("cafe\u{301}", "café"), 1);
+//! assert_eq!(lev.rel_dist("cafe\u{301}", "cafe"), 0.25);
+//! ```
+//!
+//! [27]: https://www.unicode.org/reports/tr29/
+//!
+//!
+//! ## Text normalization
+//!
+//! The `eddie::normalize` module contains a configurable [`Normalizer`][28]:
+//! Unicode normalization forms, full case folding (optionally Turkic),
+//! transliteration of Cyrillic and Greek, folding to ASCII
+//! and removal of diacritics, punctuation and extra white space.
+//! Every metric from `eddie::str` can apply it to both strings before comparison:
+//! ```rust
+//! use eddie::Levenshtein;
+//! use eddie::normalize::{Normalizer, CaseFolding};
+//!
+//! let mut normalizer = Normalizer::new();
+//! normalizer
+//!     .set_case_folding(CaseFolding::Full)
+//!     .set_strip_diacritics(true)
+//!     .set_collapse_whitespace(true);
+//!
+//! let lev = Levenshtein::with_normalizer(normalizer);
+//! assert_eq!(lev.distance("Crème  Brûlée", "creme brulee"), 0);
+//! ```
+//!
+//! Transliteration with ASCII folding matches names written in Cyrillic or Greek
+//! against their Latin spelling:
+//! ```rust
+//! use eddie::Levenshtein;
+//! use eddie::normalize::{Normalizer, Transliteration};
+//!
+//! let mut normalizer = Normalizer::new();
+//! normalizer
+//!     .set_transliteration(Transliteration::BgnPcgn)
+//!     .set_ascii_folding(true);
+//!
+//! let lev = Levenshtein::with_normalizer(normalizer);
+//! assert_eq!(lev.distance("Щербаков", "Shcherbakov"), 0);
+//! ```
+//!
+//! [28]: normalize/struct.Normalizer.html
+//!
+//!
+//! # Phonetic hashing
+//!
+//! The `eddie::phonetic` module contains [Eudex][8], a 64-bit phonetic hash
+//! whose bitwise Hamming distance reflects how differently two words sound.
+//!
+//! ```rust
+//! use eddie::phonetic::Eudex;
+//!
+//! let eudex = Eudex::new();
+//! let dist = eudex.distance("Maier", "Meyer");
+//! assert_eq!(dist, 0);
+//! ```
+//!
+//! [8]: phonetic/struct.Eudex.html
+//!
+//!
+//! # Fuzzy lookups
+//!
+//! The `eddie::index` module contains indexes for fast fuzzy lookups in large collections,
+//! e.g. [BK-tree][9], generic over the metrics from this crate
+//! via the traits from `eddie::metric`.
+//!
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! use eddie::Levenshtein;
+//! use eddie::index::BKTree;
+//!
+//! let mut tree = BKTree::new(Levenshtein::new());
+//! tree.extend(vec!["mailbox", "boxmail", "mail"]);
+//! let found = tree.find_within("mailbx", 1);
+//! assert_eq!(found, vec![(1, &"mailbox")]);
+//! # }
+//! ```
+//!
+//! For fuzzy lookups in sorted dictionaries and tries, the `eddie::automaton` module
+//! provides [Levenshtein automata][10].
+//! For spelling suggestions within small distances, see the [symmetric deletion index][11],
+//! and for approximate search over millions of short strings, the [q-gram index][12].
+//! Nearest neighbours by relative distances (e.g. `JaroWinkler`) can be found with a [VP-tree][13].
+//!
+//! [9]: index/struct.BKTree.html
+//! [10]: automaton/struct.LevenshteinAutomaton.html
+//! [11]: index/struct.DeletionIndex.html
+//! [12]: index/struct.QGramIndex.html
+//! [13]: index/struct.VPTree.html
+//!
+//!
+//! # Approximate search
+//!
+//! [`Sellers`][15] finds all occurrences of a pattern in a long text
+//! within the given edit distance.
+//!
+//! ```rust
+//! use eddie::Sellers;
+//!
+//! let sellers = Sellers::new(1);
+//! let text = "Martha's mailbox and a malbox.";
+//! let found: Vec<&str> = sellers.find_iter("mailbox", text)
+//!     .map(|m| &text[m.start .. m.end])
+//!     .collect();
+//! assert_eq!(found, vec!["mailbox", "malbox"]);
+//! ```
+//!
+//! To search for many patterns at once (e.g. names from a watchlist), use the [pattern index][17].
+//!
+//! [`Bitap`][16] supports agrep-like patterns with character classes and wildcards:
+//!
+//! ```rust
+//! use eddie::Bitap;
+//!
+//! let bitap = Bitap::new("time#out after [0-9]", 1).unwrap();
+//! assert!(bitap.is_match("request timed out after 30s"));
+//! assert!(bitap.is_match("request timeuot after 30s"));
+//! ```
+//!
+//! [15]: struct.Sellers.html
+//! [16]: struct.Bitap.html
+//! [17]: index/struct.PatternIndex.html
+//!
+//!
+//! # Spelling correction
+//!
+//! The `eddie::speller` module contains a [spelling corrector][14]
+//! based on a frequency dictionary.
+//!
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! use eddie::speller::Speller;
+//!
+//! let mut speller = Speller::new(2);
+//! speller.load("the 1000\nmailbox 10\nis 500\nfull 50".as_bytes()).unwrap();
+//! assert_eq!(speller.correct("Teh mailbx is ful!"), "The mailbox is full!");
+//! # }
+//! ```
+//!
+//! [14]: speller/struct.Speller.html
+//!
+//!
+//! # Fuzzy extraction
+//!
+//! The `eddie::process` module finds the [best matches][18] for a query among a list of choices.
+//!
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! use eddie::JaroWinkler;
+//! use eddie::process::Extractor;
+//!
+//! let extractor = Extractor::new(JaroWinkler::new());
+//! let best = extractor.extract_one("marhta", &["mailbox", "martha"]).unwrap();
+//! assert_eq!(best.index, 1);
+//! # }
+//! ```
+//!
+//! Distance and similarity matrices are computed in parallel with [`Pairwise`][19].
+//!
+//! [18]: process/struct.Extractor.html
+//! [19]: process/struct.Pairwise.html
+//!
+//!
+//! # Record linkage
+//!
+//! The `eddie::linkage` module finds all pairs of similar strings in large collections
+//! with a [similarity join][20].
+//!
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! use eddie::Levenshtein;
+//! use eddie::linkage::SimilarityJoin;
+//!
+//! let join = SimilarityJoin::new(2, Levenshtein::new());
+//! let found = join.join_within(&["mailbox", "martha"], &["marhta", "malbox"], 1);
+//! assert_eq!(found, vec![(0, 1, 1)]);
+//! # }
+//! ```
+//!
+//! Near-duplicates are grouped by [threshold][21] and [agglomerative][22] clustering,
+//! or in linear time by [key collisions][23] (fingerprints, n-grams, phonetic keys).
+//! Multi-field records are compared with Fellegi-Sunter weights by the [record comparator][24],
+//! and candidate pairs are produced by blocking, e.g. [sorted neighbourhood][25].
+//!
+//! [20]: linkage/struct.SimilarityJoin.html
+//! [21]: linkage/struct.ThresholdClustering.html
+//! [22]: linkage/struct.Agglomerative.html
+//! [23]: linkage/struct.KeyCollision.html
+//! [24]: linkage/struct.RecordComparator.html
+//! [25]: linkage/struct.SortedNeighbourhood.html
+//!
+//!
+//! # Thread safety
+//!
+//! Metrics from `eddie::str` and `eddie::slice` reuse internal buffers, so they are not `Sync`.
+//! The `eddie::sync` module contains [stateless versions][26] of them
+//! that can be shared across threads or stored in a `static`.
+//!
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! use eddie::sync::Levenshtein;
+//!
+//! static LEV: Levenshtein = Levenshtein::new();
+//!
+//! let dist = std::thread::spawn(|| LEV.distance("martha", "marhta")).join().unwrap();
+//! assert_eq!(dist, 2);
+//! # }
+//! ```
+//!
+//! [26]: sync/index.html
+//!
+//!
 //! # Performance
 //!
 //! At the moment Eddie has the fastest implementations among the alternatives from crates.io
@@ -106,14 +332,67 @@
 //! For the detailed measurements tables see [Benchmarks][7] page.
 //!
 //! [7]: http://github.com/thaumant/eddie/tree/master/benchmarks.md
+//!
+//!
+//! # `no_std` support
+//!
+//! The default `std` feature can be disabled to use the crate in `no_std` environments:
+//! ```toml
+//! [dependencies]
+//! eddie = { version = "0.4", default-features = false, features = ["alloc"] }
+//! ```
+//!
+//! With the `alloc` feature all the metrics are available,
+//! while fuzzy lookups, approximate search in collections, spelling correction,
+//! fuzzy extraction and record linkage require `std`.
+//! Without `std` the thread-safe metrics only accept an explicit workspace.
+//!
+//! Without `alloc` the crate doesn't allocate at all:
+//! Levenshtein, Hamming, Jaro, Jaro-Winkler, and Eudex are available,
+//! the metrics with stack buffers panic on inputs longer than the buffers,
+//! and their size is chosen explicitly with `with_stack`, as `new` requires `alloc`.
+//! ```rust
+//! use eddie::Levenshtein;
+//!
+//! let lev = Levenshtein::<64>::with_stack();
+//! assert_eq!(lev.distance("martha", "marhta"), 2);
+//! ```
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 mod utils;
 
 pub mod slice;
 pub mod str;
+pub mod phonetic;
+pub mod metric;
+#[cfg(feature = "std")]
+pub mod index;
+#[cfg(feature = "std")]
+pub mod automaton;
+#[cfg(feature = "std")]
+pub mod speller;
+#[cfg(feature = "std")]
+pub mod process;
+#[cfg(feature = "std")]
+pub mod linkage;
+#[cfg(feature = "alloc")]
+pub mod sync;
+#[cfg(feature = "alloc")]
+pub mod normalize;
 
 pub use crate::str::Levenshtein;
+#[cfg(feature = "alloc")]
 pub use crate::str::DamerauLevenshtein;
 pub use crate::str::Hamming;
 pub use crate::str::Jaro;
 pub use crate::str::JaroWinkler;
+#[cfg(feature = "alloc")]
+pub use crate::str::Sellers;
+#[cfg(feature = "alloc")]
+pub use crate::str::Match;
+#[cfg(feature = "alloc")]
+pub use crate::str::Bitap;
@@ -0,0 +1,572 @@
+use std::collections::HashMap;
+use crate::index::PAD;
+use crate::phonetic::Eudex;
+use super::keys::{Keyer, normalize};
+
+
+/// # Standard key blocking.
+///
+/// Strings with equal keys, as computed by the [`Keyer`][1], form a block,
+/// and all pairs within every block are candidates.
+/// Strings with empty keys (e.g. missing values) are not paired with anything.
+///
+/// [1]: trait.Keyer.html
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::linkage::{KeyBlocking, Fingerprint};
+///
+/// let blocking = KeyBlocking::new(Fingerprint::new());
+/// let names = ["Smith, John", "Jones", "john smith", "JONES", ""];
+/// let pairs: Vec<(usize, usize)> = blocking.pairs(&names).collect();
+///
+/// assert_eq!(pairs, vec![(0, 2), (1, 3)]);
+/// ```
+pub struct KeyBlocking<K> {
+    keyer: K,
+}
+
+
+impl<K: Keyer> KeyBlocking<K> {
+    /// Creates a new blocking by the keys of the keyer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::linkage::{KeyBlocking, PhoneticFingerprint};
+    ///
+    /// let blocking = KeyBlocking::new(PhoneticFingerprint::new());
+    /// ```
+    pub fn new(keyer: K) -> Self {
+        Self { keyer }
+    }
+
+    /// Returns a reference to the keyer.
+    pub fn keyer(&self) -> &K {
+        &self.keyer
+    }
+
+    /// Returns candidate pairs of distinct items of the list, `(i, j)` with `i < j`.
+    pub fn pairs<S: AsRef<str>>(&self, items: &[S]) -> Candidates {
+        let keys = items.iter().map(|item| self.key(item.as_ref())).collect();
+        Candidates::new(keys, None, 1)
+    }
+
+    /// Returns candidate pairs of items of the two lists, `(i, j)` for `left[i]` and `right[j]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::linkage::{KeyBlocking, Fingerprint};
+    ///
+    /// let blocking = KeyBlocking::new(Fingerprint::new());
+    /// let pairs: Vec<(usize, usize)> = blocking.link(&["Smith, John", "Jones"], &["jones", "john smith"]).collect();
+    /// assert_eq!(pairs, vec![(0, 1), (1, 0)]);
+    /// ```
+    pub fn link<S1: AsRef<str>, S2: AsRef<str>>(&self, left: &[S1], right: &[S2]) -> Candidates {
+        let keys = left.iter().map(|item| self.key(item.as_ref()))
+            .chain(right.iter().map(|item| self.key(item.as_ref())))
+            .collect();
+        Candidates::new(keys, Some(left.len()), 1)
+    }
+
+    fn key(&self, s: &str) -> Vec<String> {
+        let key = self.keyer.key(s);
+        if key.is_empty() { Vec::new() } else { vec![key] }
+    }
+}
+
+
+/// # Sorted neighbourhood blocking.
+///
+/// Strings are sorted by their keys, as computed by the [`Keyer`][1],
+/// and a window of the given size slides over the sorted list:
+/// all pairs within the window are candidates.
+/// Unlike standard blocking, strings with slightly different keys
+/// (e.g. `smith` and `smyth`) may still be paired.
+/// Strings with empty keys are not paired with anything.
+///
+/// [1]: trait.Keyer.html
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::linkage::SortedNeighbourhood;
+///
+/// let blocking = SortedNeighbourhood::new(|s: &str| s.to_lowercase(), 2);
+/// let names = ["smyth", "jones", "Smith", "johns"];
+/// let pairs: Vec<(usize, usize)> = blocking.pairs(&names).collect();
+///
+/// // Sorted: johns, jones, smith, smyth.
+/// assert_eq!(pairs, vec![(0, 2), (1, 2), (1, 3)]);
+/// ```
+pub struct SortedNeighbourhood<K> {
+    keyer: K,
+    window: usize,
+}
+
+
+impl<K: Keyer> SortedNeighbourhood<K> {
+    /// Creates a new blocking with the given keyer and window size.
+    /// Panics if the window is smaller than 2.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::linkage::{SortedNeighbourhood, Fingerprint};
+    ///
+    /// let blocking = SortedNeighbourhood::new(Fingerprint::new(), 10);
+    /// ```
+    pub fn new(keyer: K, window: usize) -> Self {
+        assert!(window >= 2, "Window should contain at least two items");
+        Self { keyer, window }
+    }
+
+    /// Returns a reference to the keyer.
+    pub fn keyer(&self) -> &K {
+        &self.keyer
+    }
+
+    /// Returns the window size.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Returns candidate pairs of distinct items of the list, `(i, j)` with `i < j`.
+    pub fn pairs<S: AsRef<str>>(&self, items: &[S]) -> Candidates {
+        let keys: Vec<String> = items.iter().map(|item| self.keyer.key(item.as_ref())).collect();
+        Candidates::new(self.windows(&keys), None, 1)
+    }
+
+    /// Returns candidate pairs of items of the two lists, `(i, j)` for `left[i]` and `right[j]`.
+    /// Both lists are sorted together, so the window should be large enough
+    /// to cover the items from both lists with close keys.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::linkage::SortedNeighbourhood;
+    ///
+    /// let blocking = SortedNeighbourhood::new(|s: &str| s.to_lowercase(), 2);
+    /// let pairs: Vec<(usize, usize)> = blocking.link(&["smith", "jones"], &["Smyth", "Johns"]).collect();
+    /// assert_eq!(pairs, vec![(0, 0), (1, 1)]);
+    /// ```
+    pub fn link<S1: AsRef<str>, S2: AsRef<str>>(&self, left: &[S1], right: &[S2]) -> Candidates {
+        let keys: Vec<String> = left.iter().map(|item| self.keyer.key(item.as_ref()))
+            .chain(right.iter().map(|item| self.keyer.key(item.as_ref())))
+            .collect();
+        Candidates::new(self.windows(&keys), Some(left.len()), 1)
+    }
+
+    /// Assigns every item to the windows it falls into,
+    /// identified by their first positions in the sorted list.
+    fn windows(&self, keys: &[String]) -> Vec<Vec<usize>> {
+        let mut sorted: Vec<usize> = (0..keys.len()).filter(|&i| !keys[i].is_empty()).collect();
+        sorted.sort_by(|&i, &j| keys[i].cmp(&keys[j]).then(i.cmp(&j)));
+        let mut windows = vec![Vec::new(); keys.len()];
+        for (pos, &i) in sorted.iter().enumerate() {
+            let first = pos.saturating_sub(self.window - 1);
+            windows[i] = (first ..= pos).collect();
+        }
+        windows
+    }
+}
+
+
+/// # Q-gram blocking.
+///
+/// Every padded q-gram of a string is a block key,
+/// and strings sharing at least the given number of distinct q-grams are candidates.
+/// Catches typos anywhere in the string, at the cost of more candidates
+/// than the key-based blockings.
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::linkage::QGramBlocking;
+///
+/// let blocking = QGramBlocking::new(2, 3);
+/// let names = ["martha", "marhta", "mary", "boxmail"];
+/// let pairs: Vec<(usize, usize)> = blocking.pairs(&names).collect();
+///
+/// assert_eq!(pairs, vec![(0, 1), (0, 2), (1, 2)]);
+/// ```
+pub struct QGramBlocking {
+    q: usize,
+    min_shared: usize,
+}
+
+
+impl QGramBlocking {
+    /// Creates a new blocking by q-grams of the given size,
+    /// pairing strings with at least `min_shared` common q-grams.
+    /// Panics if `q` or `min_shared` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::linkage::QGramBlocking;
+    ///
+    /// let blocking = QGramBlocking::new(3, 2);
+    /// ```
+    pub fn new(q: usize, min_shared: usize) -> Self {
+        assert!(q > 0, "Q-gram size should be positive");
+        assert!(min_shared > 0, "Minimum number of shared q-grams should be positive");
+        Self { q, min_shared }
+    }
+
+    /// Returns the q-gram size.
+    pub fn q(&self) -> usize {
+        self.q
+    }
+
+    /// Returns the minimum number of shared q-grams.
+    pub fn min_shared(&self) -> usize {
+        self.min_shared
+    }
+
+    /// Returns candidate pairs of distinct items of the list, `(i, j)` with `i < j`.
+    pub fn pairs<S: AsRef<str>>(&self, items: &[S]) -> Candidates {
+        let keys = items.iter().map(|item| self.grams(item.as_ref())).collect();
+        Candidates::new(keys, None, self.min_shared)
+    }
+
+    /// Returns candidate pairs of items of the two lists, `(i, j)` for `left[i]` and `right[j]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::linkage::QGramBlocking;
+    ///
+    /// let blocking = QGramBlocking::new(2, 4);
+    /// let pairs: Vec<(usize, usize)> = blocking.link(&["mailbox", "martha"], &["marhta", "malbox"]).collect();
+    /// assert_eq!(pairs, vec![(0, 1), (1, 0)]);
+    /// ```
+    pub fn link<S1: AsRef<str>, S2: AsRef<str>>(&self, left: &[S1], right: &[S2]) -> Candidates {
+        let keys = left.iter().map(|item| self.grams(item.as_ref()))
+            .chain(right.iter().map(|item| self.grams(item.as_ref())))
+            .collect();
+        Candidates::new(keys, Some(left.len()), self.min_shared)
+    }
+
+    /// Returns distinct q-grams of the string padded with `q - 1` characters at both ends.
+    fn grams(&self, s: &str) -> Vec<String> {
+        if s.is_empty() { return Vec::new(); }
+        let padding = vec![PAD; self.q - 1];
+        let chars: Vec<char> = padding.iter().copied()
+            .chain(s.chars())
+            .chain(padding.iter().copied())
+            .collect();
+        let mut grams: Vec<String> = chars.windows(self.q).map(|w| w.iter().collect()).collect();
+        grams.sort_unstable();
+        grams.dedup();
+        grams
+    }
+}
+
+
+/// # Phonetic blocking.
+///
+/// Words of strings are normalized as in the [`Fingerprint`][1] and encoded by [`Eudex`][2].
+/// Every code is a block key, so strings with any pair of words that sound alike are candidates,
+/// e.g. `John Smith` and `Smythe, Jon`.
+///
+/// [1]: struct.Fingerprint.html
+/// [2]: ../phonetic/struct.Eudex.html
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::linkage::PhoneticBlocking;
+///
+/// let blocking = PhoneticBlocking::new();
+/// let names = ["John Smith", "Mary Brown", "Smythe"];
+/// let pairs: Vec<(usize, usize)> = blocking.pairs(&names).collect();
+///
+/// assert_eq!(pairs, vec![(0, 2)]);
+/// ```
+pub struct PhoneticBlocking {
+    eudex: Eudex,
+}
+
+
+impl PhoneticBlocking {
+    /// Creates a new phonetic blocking.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::linkage::PhoneticBlocking;
+    ///
+    /// let blocking = PhoneticBlocking::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { eudex: Eudex::new() }
+    }
+
+    /// Returns candidate pairs of distinct items of the list, `(i, j)` with `i < j`.
+    pub fn pairs<S: AsRef<str>>(&self, items: &[S]) -> Candidates {
+        let keys = items.iter().map(|item| self.codes(item.as_ref())).collect();
+        Candidates::new(keys, None, 1)
+    }
+
+    /// Returns candidate pairs of items of the two lists, `(i, j)` for `left[i]` and `right[j]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::linkage::PhoneticBlocking;
+    ///
+    /// let blocking = PhoneticBlocking::new();
+    /// let pairs: Vec<(usize, usize)> = blocking.link(&["Smith", "Jones"], &["Smythe", "Brown"]).collect();
+    /// assert_eq!(pairs, vec![(0, 0)]);
+    /// ```
+    pub fn link<S1: AsRef<str>, S2: AsRef<str>>(&self, left: &[S1], right: &[S2]) -> Candidates {
+        let keys = left.iter().map(|item| self.codes(item.as_ref()))
+            .chain(right.iter().map(|item| self.codes(item.as_ref())))
+            .collect();
+        Candidates::new(keys, Some(left.len()), 1)
+    }
+
+    fn codes(&self, s: &str) -> Vec<u64> {
+        let mut codes: Vec<u64> = normalize(s).split_whitespace()
+            .map(|word| self.eudex.hash(word))
+            .collect();
+        codes.sort_unstable();
+        codes.dedup();
+        codes
+    }
+}
+
+
+impl Default for PhoneticBlocking {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+/// An iterator over candidate pairs produced by blocking.
+///
+/// Pairs are ordered by indices, and every pair is produced once,
+/// even if it shares several blocks.
+pub struct Candidates {
+    // Sorted items of every block.
+    blocks: Vec<Vec<usize>>,
+    // Blocks of every item.
+    memberships: Vec<Vec<usize>>,
+    // Number of items in the left list when linking two lists.
+    split: Option<usize>,
+    min_shared: usize,
+    // Current item, and its candidates not yet produced.
+    item: usize,
+    found: Vec<usize>,
+    pos: usize,
+    // Number of blocks shared with the current item.
+    shared: Vec<usize>,
+    stamps: Vec<usize>,
+}
+
+
+impl Candidates {
+    /// Builds blocks from the keys of items (the left list followed by the right one when linking).
+    fn new<K: std::hash::Hash + Eq>(keys: Vec<Vec<K>>, split: Option<usize>, min_shared: usize) -> Self {
+        let mut ids: HashMap<K, usize> = HashMap::new();
+        let mut blocks: Vec<Vec<usize>> = Vec::new();
+        let mut memberships = Vec::with_capacity(keys.len());
+        for (i, item_keys) in keys.into_iter().enumerate() {
+            let mut item_blocks = Vec::with_capacity(item_keys.len());
+            for key in item_keys {
+                let next = blocks.len();
+                let id = *ids.entry(key).or_insert(next);
+                if id == next { blocks.push(Vec::new()); }
+                blocks[id].push(i);
+                item_blocks.push(id);
+            }
+            memberships.push(item_blocks);
+        }
+        let n = memberships.len();
+        Self {
+            blocks,
+            memberships,
+            split,
+            min_shared,
+            item: 0,
+            found: Vec::new(),
+            pos: 0,
+            shared: vec![0; n],
+            stamps: vec![usize::MAX; n],
+        }
+    }
+
+    /// Collects candidates of the current item.
+    fn collect(&mut self) {
+        let i = self.item;
+        let from = self.split.unwrap_or(i + 1);
+        self.found.clear();
+        self.pos = 0;
+        for &block in &self.memberships[i] {
+            let members = &self.blocks[block];
+            let start = members.partition_point(|&j| j < from);
+            for &j in &members[start ..] {
+                if self.stamps[j] != i {
+                    self.stamps[j] = i;
+                    self.shared[j] = 0;
+                }
+                self.shared[j] += 1;
+                if self.shared[j] == self.min_shared {
+                    self.found.push(j);
+                }
+            }
+        }
+        self.found.sort_unstable();
+    }
+}
+
+
+impl Iterator for Candidates {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        let last = self.split.unwrap_or(self.memberships.len());
+        while self.pos == self.found.len() {
+            if self.item >= last { return None; }
+            self.collect();
+            self.item += 1;
+        }
+        let j = self.found[self.pos];
+        self.pos += 1;
+        Some((self.item - 1, j - self.split.unwrap_or(0)))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{KeyBlocking, SortedNeighbourhood, QGramBlocking, PhoneticBlocking};
+    use crate::linkage::Fingerprint;
+    use crate::Levenshtein;
+    use rand::prelude::*;
+
+    fn random_words(rng: &mut StdRng, count: usize) -> Vec<String> {
+        let alphabet = ['a', 'b', 'c', 'ж'];
+        (0..count)
+            .map(|_| {
+                let len = rng.gen_range(0, 6);
+                (0..len).map(|_| *alphabet.choose(rng).unwrap()).collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn key_blocking() {
+        let blocking = KeyBlocking::new(|s: &str| s.chars().take(1).collect());
+        let mut rng = StdRng::seed_from_u64(3);
+        let words = random_words(&mut rng, 100);
+        let mut expected = Vec::new();
+        for i in 0..words.len() {
+            for j in i + 1 .. words.len() {
+                let (k1, k2) = (words[i].chars().next(), words[j].chars().next());
+                if k1.is_some() && k1 == k2 { expected.push((i, j)); }
+            }
+        }
+        assert_eq!(blocking.pairs(&words).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn key_blocking_link() {
+        let blocking = KeyBlocking::new(Fingerprint::new());
+        let left = ["John Smith", "Mary Jones", "", "jones mary"];
+        let right = ["smith, john", "", "Mary Jones", "Brown"];
+        let pairs: Vec<(usize, usize)> = blocking.link(&left, &right).collect();
+        assert_eq!(pairs, vec![(0, 0), (1, 2), (3, 2)]);
+        let none: [&str; 0] = [];
+        assert_eq!(blocking.link(&none, &right).count(), 0);
+        assert_eq!(blocking.link(&left, &none).count(), 0);
+        assert_eq!(blocking.pairs(&none).count(), 0);
+    }
+
+    #[test]
+    fn sorted_neighbourhood() {
+        let mut rng = StdRng::seed_from_u64(4);
+        let words = random_words(&mut rng, 100);
+        for window in 2..6 {
+            let blocking = SortedNeighbourhood::new(|s: &str| s.to_string(), window);
+            let mut sorted: Vec<usize> = (0..words.len()).filter(|&i| !words[i].is_empty()).collect();
+            sorted.sort_by_key(|&i| (&words[i], i));
+            let mut expected = Vec::new();
+            for (p, &i) in sorted.iter().enumerate() {
+                for &j in &sorted[p + 1 .. min!(p + window, sorted.len())] {
+                    expected.push((min!(i, j), max!(i, j)));
+                }
+            }
+            expected.sort_unstable();
+            assert_eq!(blocking.pairs(&words).collect::<Vec<_>>(), expected, "{}", window);
+        }
+    }
+
+    #[test]
+    fn sorted_neighbourhood_link() {
+        let blocking = SortedNeighbourhood::new(|s: &str| s.to_lowercase(), 3);
+        let pairs: Vec<(usize, usize)> = blocking.link(&["smith", "jones", "brown"], &["Smyth", "Johns", "Browne"]).collect();
+        // Sorted: brown, browne, johns, jones, smith, smyth.
+        assert_eq!(pairs, vec![(0, 0), (0, 1), (1, 0), (1, 1), (1, 2), (2, 1), (2, 2)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sorted_neighbourhood_window() {
+        SortedNeighbourhood::new(Fingerprint::new(), 1);
+    }
+
+    #[test]
+    fn qgram_blocking() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let words = random_words(&mut rng, 100);
+        for q in 1..4 {
+            for min_shared in 1..4 {
+                let blocking = QGramBlocking::new(q, min_shared);
+                let mut expected = Vec::new();
+                for i in 0..words.len() {
+                    for j in i + 1 .. words.len() {
+                        let g1 = blocking.grams(&words[i]);
+                        let shared = blocking.grams(&words[j]).iter().filter(|g| g1.contains(g)).count();
+                        if shared >= min_shared { expected.push((i, j)); }
+                    }
+                }
+                assert_eq!(blocking.pairs(&words).collect::<Vec<_>>(), expected, "{} {}", q, min_shared);
+            }
+        }
+    }
+
+    #[test]
+    fn qgram_blocking_recall() {
+        // Strings within distance k share at least |grams| - k * q q-grams.
+        let blocking = QGramBlocking::new(2, 3);
+        let lev = Levenshtein::new();
+        let words = ["mailbox", "malbox", "mailbx", "boxmail", "martha", "marhta", "mrtha"];
+        let pairs: Vec<(usize, usize)> = blocking.pairs(&words).collect();
+        for i in 0..words.len() {
+            for j in i + 1 .. words.len() {
+                if lev.distance(words[i], words[j]) <= 1 {
+                    assert!(pairs.contains(&(i, j)), "{} {}", words[i], words[j]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn phonetic_blocking() {
+        let blocking = PhoneticBlocking::new();
+        let names = ["John Smith", "Smythe, Jon", "Mary Jones", "", "JONES", "Smith Smith"];
+        let pairs: Vec<(usize, usize)> = blocking.pairs(&names).collect();
+        assert!(pairs.contains(&(0, 1)));
+        assert!(pairs.contains(&(0, 5)));
+        assert!(pairs.contains(&(2, 4)));
+        assert!(!pairs.iter().any(|&(i, j)| i == 3 || j == 3));
+        let mut deduped = pairs.clone();
+        deduped.dedup();
+        assert_eq!(pairs, deduped);
+    }
+}
@@ -0,0 +1,544 @@
+use crate::index::QGramFilter;
+use crate::metric::RelDistance;
+use super::SimilarityJoin;
+
+
+/// Result of clustering: a cluster label for every item, and a representative of every cluster.
+///
+/// Clusters are numbered in the order of their first items,
+/// so the first item always belongs to the cluster `0`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Clusters {
+    labels: Vec<usize>,
+    medoids: Vec<usize>,
+}
+
+
+impl Clusters {
+    /// Builds clusters from disjoint sets of items,
+    /// choosing the medoid of every cluster by the given distance.
+    pub(crate) fn new<D: FnMut(usize, usize) -> f64>(sets: &mut DisjointSets, mut dist: D) -> Self {
+        let n = sets.len();
+        let mut labels = vec![0; n];
+        let mut root_labels = vec![usize::MAX; n];
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        for (i, label) in labels.iter_mut().enumerate() {
+            let root = sets.find(i);
+            if root_labels[root] == usize::MAX {
+                root_labels[root] = groups.len();
+                groups.push(Vec::new());
+            }
+            *label = root_labels[root];
+            groups[*label].push(i);
+        }
+        let medoids = groups.iter().map(|group| medoid(group, &mut dist)).collect();
+        Self { labels, medoids }
+    }
+
+    /// Returns a number of clusters.
+    pub fn len(&self) -> usize {
+        self.medoids.len()
+    }
+
+    /// Returns `true` if there are no clusters, i.e. nothing was clustered.
+    pub fn is_empty(&self) -> bool {
+        self.medoids.is_empty()
+    }
+
+    /// Returns cluster labels of all items.
+    pub fn labels(&self) -> &[usize] {
+        &self.labels
+    }
+
+    /// Returns the cluster label of the given item.
+    pub fn label(&self, item: usize) -> usize {
+        self.labels[item]
+    }
+
+    /// Returns medoids of all clusters, i.e. the items with the least total distance
+    /// to the other items of their clusters.
+    pub fn medoids(&self) -> &[usize] {
+        &self.medoids
+    }
+
+    /// Returns the medoid of the given cluster.
+    pub fn medoid(&self, cluster: usize) -> usize {
+        self.medoids[cluster]
+    }
+
+    /// Returns items of every cluster, in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::Levenshtein;
+    /// use eddie::linkage::ThresholdClustering;
+    ///
+    /// let clustering = ThresholdClustering::new(2, Levenshtein::new());
+    /// let clusters = clustering.cluster_within(&["mailbox", "martha", "malbox", "marhta"], 2);
+    /// assert_eq!(clusters.groups(), vec![vec![0, 2], vec![1, 3]]);
+    /// ```
+    pub fn groups(&self) -> Vec<Vec<usize>> {
+        let mut groups = vec![Vec::new(); self.len()];
+        for (i, &label) in self.labels.iter().enumerate() {
+            groups[label].push(i);
+        }
+        groups
+    }
+}
+
+
+/// Returns the item of the group with the least total distance to the others,
+/// the first one in case of ties.
+fn medoid<D: FnMut(usize, usize) -> f64>(group: &[usize], dist: &mut D) -> usize {
+    if group.len() <= 2 { return group[0]; }
+    let mut totals = vec![0.0; group.len()];
+    for (a, &i) in group.iter().enumerate() {
+        for (b, &j) in group.iter().enumerate().skip(a + 1) {
+            let d = dist(i, j);
+            totals[a] += d;
+            totals[b] += d;
+        }
+    }
+    let mut best = 0;
+    for (a, &total) in totals.iter().enumerate() {
+        if total < totals[best] { best = a; }
+    }
+    group[best]
+}
+
+
+/// Union-find over item indices, with path halving and union by size.
+pub(crate) struct DisjointSets {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+
+impl DisjointSets {
+    pub fn new(n: usize) -> Self {
+        Self { parents: (0..n).collect(), sizes: vec![1; n] }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn find(&mut self, mut i: usize) -> usize {
+        while self.parents[i] != i {
+            self.parents[i] = self.parents[self.parents[i]];
+            i = self.parents[i];
+        }
+        i
+    }
+
+    pub fn union(&mut self, i: usize, j: usize) {
+        let (mut i, mut j) = (self.find(i), self.find(j));
+        if i == j { return; }
+        if self.sizes[i] < self.sizes[j] { std::mem::swap(&mut i, &mut j); }
+        self.parents[j] = i;
+        self.sizes[i] += self.sizes[j];
+    }
+}
+
+
+/// # Threshold clustering.
+///
+/// Groups strings into connected components of the graph where two strings are connected
+/// if they are within the given distance (or have at least the given similarity).
+/// This is the same as single linkage clustering cut at the threshold,
+/// so chains of close strings may join quite different strings.
+///
+/// The edges are found with a [`SimilarityJoin`][1], without comparing every pair of strings.
+/// Medoids are found by comparing all pairs within every cluster.
+///
+/// [1]: struct.SimilarityJoin.html
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::Levenshtein;
+/// use eddie::linkage::ThresholdClustering;
+///
+/// let clustering = ThresholdClustering::new(2, Levenshtein::new());
+/// let titles = ["mailbox", "martha", "malbox", "mailbx", "marhta"];
+/// let clusters = clustering.cluster_within(&titles, 1);
+///
+/// assert_eq!(clusters.labels(), &[0, 1, 0, 0, 2]);
+/// assert_eq!(titles[clusters.medoid(0)], "mailbox");
+/// ```
+pub struct ThresholdClustering<M> {
+    join: SimilarityJoin<M>,
+}
+
+
+impl<M: QGramFilter> ThresholdClustering<M> {
+    /// Creates a new clustering using q-grams of the given size for the similarity join.
+    /// Panics if `q` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::DamerauLevenshtein;
+    /// use eddie::linkage::ThresholdClustering;
+    ///
+    /// let clustering = ThresholdClustering::new(2, DamerauLevenshtein::new());
+    /// ```
+    pub fn new(q: usize, metric: M) -> Self {
+        Self { join: SimilarityJoin::new(q, metric) }
+    }
+
+    /// Returns a reference to the metric used by the clustering.
+    pub fn metric(&self) -> &M {
+        self.join.metric()
+    }
+
+    /// Clusters items connected by pairs within the given distance.
+    /// Medoids minimize the total distance within clusters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// # use eddie::linkage::ThresholdClustering;
+    /// let clustering = ThresholdClustering::new(2, Levenshtein::new());
+    /// let clusters = clustering.cluster_within(&["mailbox", "mailbx", "malbx"], 1);
+    /// assert_eq!(clusters.labels(), &[0, 0, 0]);
+    /// assert_eq!(clusters.medoid(0), 1);
+    /// ```
+    pub fn cluster_within<S: AsRef<str>>(&self, items: &[S], max_dist: usize) -> Clusters {
+        let mut sets = DisjointSets::new(items.len());
+        for (i, j, _) in self.join.self_join_within(items, max_dist) {
+            sets.union(i, j);
+        }
+        let metric = self.metric();
+        Clusters::new(&mut sets, |i, j| metric.distance(items[i].as_ref(), items[j].as_ref()) as f64)
+    }
+
+    /// Clusters items connected by pairs with similarity (`1 - rel_dist`) of at least `min_sim`.
+    /// Medoids minimize the total relative distance within clusters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// # use eddie::linkage::ThresholdClustering;
+    /// let clustering = ThresholdClustering::new(2, Levenshtein::new());
+    /// let clusters = clustering.cluster_similar(&["mailbox", "martha", "mailbx"], 0.8);
+    /// assert_eq!(clusters.groups(), vec![vec![0, 2], vec![1]]);
+    /// ```
+    pub fn cluster_similar<S: AsRef<str>>(&self, items: &[S], min_sim: f64) -> Clusters
+    where M: RelDistance<str>
+    {
+        let mut sets = DisjointSets::new(items.len());
+        for (i, j, _) in self.join.self_join_similar(items, min_sim) {
+            sets.union(i, j);
+        }
+        let metric = self.metric();
+        Clusters::new(&mut sets, |i, j| metric.rel_dist(items[i].as_ref(), items[j].as_ref()))
+    }
+}
+
+
+/// Distance between clusters used by agglomerative clustering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Linkage {
+    /// Distance between the closest items of two clusters.
+    Single,
+    /// Distance between the farthest items of two clusters.
+    Complete,
+    /// Mean distance between items of two clusters (UPGMA).
+    Average,
+}
+
+
+/// # Agglomerative clustering.
+///
+/// Starts with every string in its own cluster, and repeatedly merges the two closest clusters
+/// until the distance between them (by the chosen [`Linkage`][1]) exceeds the threshold.
+/// Distances are relative (`rel_dist`), so any metric from `eddie`
+/// (including `Jaro` and `JaroWinkler`) can be used.
+///
+/// The full distance matrix is computed, so clustering takes `O(n²)` time and memory
+/// (merges are found with the nearest-neighbour chain algorithm).
+/// For large collections, consider [`ThresholdClustering`][2]
+/// or splitting the collection into blocks first.
+///
+/// [1]: enum.Linkage.html
+/// [2]: struct.ThresholdClustering.html
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::JaroWinkler;
+/// use eddie::linkage::{Agglomerative, Linkage};
+///
+/// let clustering = Agglomerative::new(JaroWinkler::new(), Linkage::Average);
+/// let titles = ["mailbox", "martha", "mailbx", "marhta", "boxmail"];
+/// let clusters = clustering.cluster(&titles, 0.1);
+///
+/// assert_eq!(clusters.groups(), vec![vec![0, 2], vec![1, 3], vec![4]]);
+/// ```
+pub struct Agglomerative<M> {
+    metric: M,
+    linkage: Linkage,
+}
+
+
+impl<M: RelDistance<str>> Agglomerative<M> {
+    /// Creates a new clustering with the given metric and linkage.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::Levenshtein;
+    /// use eddie::linkage::{Agglomerative, Linkage};
+    ///
+    /// let clustering = Agglomerative::new(Levenshtein::new(), Linkage::Complete);
+    /// ```
+    pub fn new(metric: M, linkage: Linkage) -> Self {
+        Self { metric, linkage }
+    }
+
+    /// Returns a reference to the metric used by the clustering.
+    pub fn metric(&self) -> &M {
+        &self.metric
+    }
+
+    /// Returns the linkage.
+    pub fn linkage(&self) -> Linkage {
+        self.linkage
+    }
+
+    /// Clusters items, merging clusters within the given relative distance.
+    /// Medoids minimize the total relative distance within clusters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// # use eddie::linkage::{Agglomerative, Linkage};
+    /// let items = ["mailbox", "mailbx", "mailb", "mail"];
+    ///
+    /// let single = Agglomerative::new(Levenshtein::new(), Linkage::Single);
+    /// assert_eq!(single.cluster(&items, 0.2).len(), 1);
+    ///
+    /// let complete = Agglomerative::new(Levenshtein::new(), Linkage::Complete);
+    /// assert_eq!(complete.cluster(&items, 0.2).groups(), vec![vec![0, 1], vec![2, 3]]);
+    /// ```
+    pub fn cluster<S: AsRef<str>>(&self, items: &[S], max_dist: f64) -> Clusters {
+        let n = items.len();
+        let mut dists = Vec::with_capacity(n * n.saturating_sub(1) / 2);
+        for i in 0..n {
+            for j in i + 1 .. n {
+                dists.push(self.metric.rel_dist(items[i].as_ref(), items[j].as_ref()));
+            }
+        }
+
+        let mut sets = DisjointSets::new(n);
+        for (i, j, dist) in self.merges(n, dists.clone()) {
+            if dist <= max_dist { sets.union(i, j); }
+        }
+        Clusters::new(&mut sets, |i, j| dists[condensed(n, i, j)])
+    }
+
+    /// Builds the dendrogram with the nearest-neighbour chain algorithm,
+    /// and returns merges as pairs of cluster representatives with linkage distances.
+    ///
+    /// Merges come out of order, but all three linkages are monotone,
+    /// so merges within a threshold form exactly the clusters cut at that threshold.
+    fn merges(&self, n: usize, mut dists: Vec<f64>) -> Vec<(usize, usize, f64)> {
+        let mut active = vec![true; n];
+        let mut sizes = vec![1; n];
+        let mut chain: Vec<usize> = Vec::new();
+        let mut merges = Vec::with_capacity(n.saturating_sub(1));
+
+        while merges.len() + 1 < n {
+            if chain.is_empty() {
+                chain.push(active.iter().position(|&a| a).unwrap());
+            }
+            let a = chain[chain.len() - 1];
+            let prev = if chain.len() >= 2 { Some(chain[chain.len() - 2]) } else { None };
+
+            // Nearest active cluster, preferring the previous one in the chain on ties.
+            let mut nearest = prev;
+            let mut nearest_dist = prev.map_or(f64::INFINITY, |p| dists[condensed(n, a, p)]);
+            for c in (0..n).filter(|&c| c != a && active[c]) {
+                let d = dists[condensed(n, a, c)];
+                if d < nearest_dist || nearest.is_none() {
+                    nearest = Some(c);
+                    nearest_dist = d;
+                }
+            }
+            let b = nearest.unwrap();
+
+            if Some(b) != prev {
+                chain.push(b);
+                continue;
+            }
+
+            chain.truncate(chain.len() - 2);
+            merges.push((a, b, nearest_dist));
+            active[a] = false;
+            let (size_a, size_b) = (sizes[a] as f64, sizes[b] as f64);
+            for c in (0..n).filter(|&c| c != b && active[c]) {
+                let da = dists[condensed(n, a, c)];
+                let db = dists[condensed(n, b, c)];
+                dists[condensed(n, b, c)] = match self.linkage {
+                    Linkage::Single => da.min(db),
+                    Linkage::Complete => da.max(db),
+                    Linkage::Average => (size_a * da + size_b * db) / (size_a + size_b),
+                };
+            }
+            sizes[b] += sizes[a];
+        }
+
+        merges
+    }
+}
+
+
+/// Index of the pair of distinct items in a condensed distance matrix.
+fn condensed(n: usize, i: usize, j: usize) -> usize {
+    let (i, j) = (min!(i, j), max!(i, j));
+    n * i - i * (i + 1) / 2 + j - i - 1
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{Agglomerative, Clusters, DisjointSets, Linkage, ThresholdClustering};
+    use crate::{Levenshtein, DamerauLevenshtein};
+    use rand::prelude::*;
+
+    #[test]
+    fn disjoint_sets() {
+        let mut sets = DisjointSets::new(6);
+        sets.union(0, 3);
+        sets.union(4, 3);
+        sets.union(1, 5);
+        assert_eq!(sets.find(0), sets.find(4));
+        assert_eq!(sets.find(1), sets.find(5));
+        assert_ne!(sets.find(0), sets.find(1));
+        assert_ne!(sets.find(2), sets.find(0));
+        let clusters = Clusters::new(&mut sets, |i, j| (i as f64 - j as f64).abs());
+        assert_eq!(clusters.labels(), &[0, 1, 2, 0, 0, 1]);
+        assert_eq!(clusters.medoids(), &[3, 1, 2]);
+    }
+
+    #[test]
+    fn threshold_within() {
+        let items = ["mailbox", "malbox", "malbx", "malb", "martha", "marhta", "", "m"];
+        let clustering = ThresholdClustering::new(2, Levenshtein::new());
+        let clusters = clustering.cluster_within(&items, 1);
+        assert_eq!(clusters.groups(), vec![vec![0, 1, 2, 3], vec![4], vec![5], vec![6, 7]]);
+        assert_eq!(clusters.medoids(), &[1, 4, 5, 6]);
+
+        let clustering = ThresholdClustering::new(2, DamerauLevenshtein::new());
+        let clusters = clustering.cluster_within(&items, 1);
+        assert_eq!(clusters.groups(), vec![vec![0, 1, 2, 3], vec![4, 5], vec![6, 7]]);
+        assert_eq!(clusters.len(), 3);
+        assert_eq!(clusters.label(5), 1);
+    }
+
+    #[test]
+    fn threshold_similar() {
+        let items = ["mailbox", "mailbx", "martha", "marhta", "boxmail"];
+        let clustering = ThresholdClustering::new(2, DamerauLevenshtein::new());
+        let clusters = clustering.cluster_similar(&items, 0.8);
+        assert_eq!(clusters.groups(), vec![vec![0, 1], vec![2, 3], vec![4]]);
+        assert_eq!(clustering.cluster_similar(&items, 0.0).len(), 1);
+        assert_eq!(clustering.cluster_similar(&items, 1.0).len(), 5);
+    }
+
+    #[test]
+    fn threshold_grapheme_mode() {
+        let items = ["cafe\u{301}s", "cafés", "cafe\u{301}"];
+        let mut lev = Levenshtein::new();
+        lev.set_grapheme_mode(true);
+        let clustering = ThresholdClustering::new(2, lev);
+        assert_eq!(clustering.cluster_within(&items, 1).groups(), vec![vec![0, 1, 2]]);
+        assert_eq!(clustering.cluster_similar(&items, 0.75).groups(), vec![vec![0, 1, 2]]);
+    }
+
+    #[test]
+    fn threshold_empty() {
+        let clustering = ThresholdClustering::new(2, Levenshtein::new());
+        let items: [&str; 0] = [];
+        assert!(clustering.cluster_within(&items, 1).is_empty());
+    }
+
+    /// Clusters by merging the closest pair of clusters at every step.
+    fn naive(items: &[String], linkage: Linkage, max_dist: f64) -> Vec<Vec<usize>> {
+        let dist = |i: usize, j: usize| {
+            let x: f64 = items[i].parse().unwrap();
+            let y: f64 = items[j].parse().unwrap();
+            (x - y).abs()
+        };
+        let mut clusters: Vec<Vec<usize>> = (0..items.len()).map(|i| vec![i]).collect();
+        loop {
+            let mut best: Option<(f64, usize, usize)> = None;
+            for a in 0..clusters.len() {
+                for b in a + 1 .. clusters.len() {
+                    let pairs = clusters[a].iter().flat_map(|&i| clusters[b].iter().map(move |&j| (i, j)));
+                    let d = match linkage {
+                        Linkage::Single => pairs.map(|(i, j)| dist(i, j)).fold(f64::INFINITY, f64::min),
+                        Linkage::Complete => pairs.map(|(i, j)| dist(i, j)).fold(0.0, f64::max),
+                        Linkage::Average => {
+                            let total: f64 = pairs.map(|(i, j)| dist(i, j)).sum();
+                            total / (clusters[a].len() * clusters[b].len()) as f64
+                        },
+                    };
+                    if best.map_or(true, |(bd, _, _)| d < bd) { best = Some((d, a, b)); }
+                }
+            }
+            match best {
+                Some((d, a, b)) if d <= max_dist => {
+                    let merged = clusters.remove(b);
+                    clusters[a].extend(merged);
+                    clusters[a].sort_unstable();
+                },
+                _ => break,
+            }
+        }
+        clusters.sort_unstable();
+        clusters
+    }
+
+    #[test]
+    fn agglomerative_matches_naive() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let items: Vec<String> = (0..40).map(|_| format!("{}", rng.gen::<f64>())).collect();
+        let metric = |s1: &str, s2: &str| {
+            let x: f64 = s1.parse().unwrap();
+            let y: f64 = s2.parse().unwrap();
+            (x - y).abs()
+        };
+        for &linkage in &[Linkage::Single, Linkage::Complete, Linkage::Average] {
+            let clustering = Agglomerative::new(metric, linkage);
+            for &max_dist in &[0.0, 0.01, 0.05, 0.1, 0.3, 1.0] {
+                let clusters = clustering.cluster(&items, max_dist);
+                assert_eq!(clusters.groups(), naive(&items, linkage, max_dist), "{:?} {}", linkage, max_dist);
+            }
+        }
+    }
+
+    #[test]
+    fn agglomerative_medoids() {
+        let items = ["mailbx", "mailbox", "malbox", "martha"];
+        let clustering = Agglomerative::new(Levenshtein::new(), Linkage::Average);
+        let clusters = clustering.cluster(&items, 0.3);
+        assert_eq!(clusters.groups(), vec![vec![0, 1, 2], vec![3]]);
+        assert_eq!(clusters.medoids(), &[1, 3]);
+    }
+
+    #[test]
+    fn agglomerative_edge_cases() {
+        let clustering = Agglomerative::new(Levenshtein::new(), Linkage::Single);
+        let none: [&str; 0] = [];
+        assert!(clustering.cluster(&none, 1.0).is_empty());
+        assert_eq!(clustering.cluster(&["mailbox"], 1.0).labels(), &[0]);
+        assert_eq!(clustering.cluster(&["mailbox", "mailbox"], 0.0).labels(), &[0, 0]);
+        assert_eq!(clustering.linkage(), Linkage::Single);
+    }
+}
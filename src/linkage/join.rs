@@ -0,0 +1,484 @@
+use std::collections::HashMap;
+use crate::index::{QGramFilter, PAD_SYMBOL, symbols};
+use crate::metric::RelDistance;
+
+
+/// Tolerance for rounding errors when converting similarity thresholds to distances.
+const EPSILON: f64 = 1e-9;
+
+
+/// # Similarity join.
+///
+/// Finds all pairs of strings from two lists (or all pairs within one list)
+/// that are within the given edit distance, or have at least the given similarity.
+///
+/// Instead of comparing every pair, candidates are generated by an inverted index
+/// of padded q-grams in the style of AllPairs and Ed-Join:
+///
+/// * length filter: strings within distance `k` differ in length by at most `k`;
+/// * prefix filter: q-grams of every string are ordered by their global frequency,
+///   rarest first, and two strings within distance `k` must share a q-gram
+///   among the first `k * grams_per_edit(q) + 1` ones;
+/// * positional filter: the shared q-gram must occur at positions at most `k` apart.
+///
+/// The remaining candidates are verified with the metric.
+/// Strings too short to have such a prefix are compared with all strings of suitable lengths,
+/// so larger `q` filters better with small distances, and smaller `q` with larger ones.
+///
+/// See [`QGramFilter`][1] for the supported metrics.
+///
+/// [1]: ../index/trait.QGramFilter.html
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::Levenshtein;
+/// use eddie::linkage::SimilarityJoin;
+///
+/// let join = SimilarityJoin::new(2, Levenshtein::new());
+/// let left = ["mailbox", "martha", "boxmail"];
+/// let right = ["malbox", "marhta", "mail"];
+///
+/// assert_eq!(join.join_within(&left, &right, 1), vec![(0, 0, 1)]);
+/// assert_eq!(join.join_within(&left, &right, 2), vec![(0, 0, 1), (1, 1, 2)]);
+/// ```
+pub struct SimilarityJoin<M> {
+    q: usize,
+    metric: M,
+}
+
+
+/// Q-grams of a single string.
+struct Grams {
+    // Length of the string in the symbols edited by the metric.
+    len: usize,
+    // Q-gram ids and positions, ordered for prefix filtering.
+    grams: Vec<(usize, usize)>,
+}
+
+
+impl<M: QGramFilter> SimilarityJoin<M> {
+    /// Creates a new join of q-grams of the given size, using the given metric.
+    /// Panics if `q` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::DamerauLevenshtein;
+    /// use eddie::linkage::SimilarityJoin;
+    ///
+    /// let join = SimilarityJoin::new(2, DamerauLevenshtein::new());
+    /// ```
+    pub fn new(q: usize, metric: M) -> Self {
+        assert!(q > 0, "Q-gram size should be positive");
+        Self { q, metric }
+    }
+
+    /// Returns the q-gram size.
+    pub fn q(&self) -> usize {
+        self.q
+    }
+
+    /// Returns a reference to the metric used by the join.
+    pub fn metric(&self) -> &M {
+        &self.metric
+    }
+
+    /// Finds all pairs of strings from the two lists within the given distance.
+    /// Returns tuples of indices in the left and the right lists and distances,
+    /// sorted by indices.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::DamerauLevenshtein;
+    /// # use eddie::linkage::SimilarityJoin;
+    /// let join = SimilarityJoin::new(2, DamerauLevenshtein::new());
+    /// let found = join.join_within(&["martha", "mailbox"], &["marhta", "mailbx"], 1);
+    /// assert_eq!(found, vec![(0, 0, 1), (1, 1, 1)]);
+    /// ```
+    pub fn join_within<S1, S2>(&self, left: &[S1], right: &[S2], max_dist: usize) -> Vec<(usize, usize, usize)>
+    where S1: AsRef<str>, S2: AsRef<str>
+    {
+        let left: Vec<&str> = left.iter().map(AsRef::as_ref).collect();
+        let right: Vec<&str> = right.iter().map(AsRef::as_ref).collect();
+        self.within(&left, &right, false, max_dist)
+    }
+
+    /// Finds all pairs of distinct items of the list within the given distance.
+    /// Returns tuples of indices `i < j` and distances, sorted by indices.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// # use eddie::linkage::SimilarityJoin;
+    /// let join = SimilarityJoin::new(2, Levenshtein::new());
+    /// let found = join.self_join_within(&["mailbox", "martha", "malbox", "mailbx"], 1);
+    /// assert_eq!(found, vec![(0, 2, 1), (0, 3, 1)]);
+    /// ```
+    pub fn self_join_within<S: AsRef<str>>(&self, items: &[S], max_dist: usize) -> Vec<(usize, usize, usize)> {
+        let items: Vec<&str> = items.iter().map(AsRef::as_ref).collect();
+        self.within(&items, &items, true, max_dist)
+    }
+
+    /// Finds all pairs of strings from the two lists with similarity (`1 - rel_dist`)
+    /// of at least `min_sim`. Returns tuples of indices in the left and the right lists
+    /// and similarities, sorted by indices.
+    ///
+    /// The threshold is converted to the maximum distance `(1 - min_sim) * max(len1, len2)`
+    /// for every pair of lengths, so no pair is missed by the filters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// # use eddie::linkage::SimilarityJoin;
+    /// let join = SimilarityJoin::new(2, Levenshtein::new());
+    /// let found = join.join_similar(&["mailbox", "martha"], &["malbox", "marhta"], 0.8);
+    /// assert_eq!(found.len(), 1);
+    /// assert_eq!((found[0].0, found[0].1), (0, 0));
+    /// assert!((found[0].2 - 0.857).abs() < 0.001);
+    /// ```
+    pub fn join_similar<S1, S2>(&self, left: &[S1], right: &[S2], min_sim: f64) -> Vec<(usize, usize, f64)>
+    where S1: AsRef<str>, S2: AsRef<str>, M: RelDistance<str>
+    {
+        let left: Vec<&str> = left.iter().map(AsRef::as_ref).collect();
+        let right: Vec<&str> = right.iter().map(AsRef::as_ref).collect();
+        self.similar(&left, &right, false, min_sim)
+    }
+
+    /// Finds all pairs of distinct items of the list with similarity of at least `min_sim`.
+    /// Returns tuples of indices `i < j` and similarities, sorted by indices.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// # use eddie::linkage::SimilarityJoin;
+    /// let join = SimilarityJoin::new(2, Levenshtein::new());
+    /// let found = join.self_join_similar(&["mailbox", "martha", "malbox"], 0.8);
+    /// let pairs: Vec<(usize, usize)> = found.iter().map(|p| (p.0, p.1)).collect();
+    /// assert_eq!(pairs, vec![(0, 2)]);
+    /// ```
+    pub fn self_join_similar<S: AsRef<str>>(&self, items: &[S], min_sim: f64) -> Vec<(usize, usize, f64)>
+    where M: RelDistance<str>
+    {
+        let items: Vec<&str> = items.iter().map(AsRef::as_ref).collect();
+        self.similar(&items, &items, true, min_sim)
+    }
+
+    fn within(&self, left: &[&str], right: &[&str], distinct: bool, max_dist: usize) -> Vec<(usize, usize, usize)> {
+        let mut found = Vec::new();
+        let bound = |_, _| max_dist;
+        self.join(left, right, distinct, |_| max_dist, bound, |i, j| {
+            let dist = self.metric.distance(left[i], right[j]);
+            if dist <= max_dist { found.push((i, j, dist)); }
+        });
+        found
+    }
+
+    fn similar(&self, left: &[&str], right: &[&str], distinct: bool, min_sim: f64) -> Vec<(usize, usize, f64)>
+    where M: RelDistance<str>
+    {
+        let mut found = Vec::new();
+        let reach = |len| {
+            if min_sim <= 0.0 { return usize::MAX; }
+            ((1.0 - min_sim) * max!(len, 1) as f64 / min_sim + EPSILON) as usize
+        };
+        let bound = |len1, len2| ((1.0 - min_sim) * max!(len1, len2, 1) as f64 + EPSILON) as usize;
+        self.join(left, right, distinct, reach, bound, |i, j| {
+            let sim = 1.0 - self.metric.rel_dist(left[i], right[j]);
+            if sim >= min_sim { found.push((i, j, sim)); }
+        });
+        found
+    }
+
+    /// Generates candidate pairs and passes them to `verify` ordered by indices.
+    /// With `distinct`, the lists are the same and only pairs `i < j` are generated.
+    ///
+    /// `reach(len)` bounds the distance from a string of the given length
+    /// to any string it may match, and determines the length of its prefix.
+    /// `bound(len1, len2)` bounds the distance between strings of the given lengths,
+    /// and should never exceed the reach of either of them.
+    fn join<R, B, V>(&self, left: &[&str], right: &[&str], distinct: bool, reach: R, bound: B, mut verify: V)
+    where
+        R: Fn(usize) -> usize,
+        B: Fn(usize, usize) -> usize,
+        V: FnMut(usize, usize),
+    {
+        let per_edit = self.metric.grams_per_edit(self.q);
+        let prefix_len = |grams: &Grams| match reach(grams.len).checked_mul(per_edit) {
+            Some(n) if n < grams.grams.len() => Some(n + 1),
+            _ => None,
+        };
+
+        let right = if distinct { &[] } else { right };
+        let (left, right) = self.grams(left, right);
+        let right = if distinct { &left } else { &right };
+
+        // Inverted index of prefixes of the right strings,
+        // and the right strings too short to be filtered.
+        let mut postings: HashMap<usize, Vec<(usize, usize)>> = HashMap::new();
+        let mut unfiltered = Vec::new();
+        for (j, grams) in right.iter().enumerate() {
+            match prefix_len(grams) {
+                Some(n) => for &(gram, pos) in &grams.grams[.. n] {
+                    postings.entry(gram).or_default().push((j, pos));
+                },
+                None => unfiltered.push(j),
+            }
+        }
+        let mut by_len: Vec<(usize, usize)> = right.iter()
+            .enumerate()
+            .map(|(j, grams)| (grams.len, j))
+            .collect();
+        by_len.sort_unstable();
+
+        let mut seen = vec![usize::MAX; right.len()];
+        let mut candidates = Vec::new();
+
+        for (i, grams1) in left.iter().enumerate() {
+            let len1 = grams1.len;
+            let mut consider = |j: usize, pos_diff: usize| {
+                if distinct && j <= i || seen[j] == i { return; }
+                let len2 = right[j].len;
+                let k = bound(len1, len2);
+                if max!(len1, len2) - min!(len1, len2) > k || pos_diff > k { return; }
+                seen[j] = i;
+                candidates.push(j);
+            };
+
+            match prefix_len(grams1) {
+                Some(n) => {
+                    for &(gram, pos1) in &grams1.grams[.. n] {
+                        for &(j, pos2) in postings.get(&gram).into_iter().flatten() {
+                            consider(j, max!(pos1, pos2) - min!(pos1, pos2));
+                        }
+                    }
+                    for &j in &unfiltered {
+                        consider(j, 0);
+                    }
+                },
+                None => {
+                    let k = reach(len1);
+                    let from = by_len.partition_point(|&(len2, _)| len2.saturating_add(k) < len1);
+                    for &(len2, j) in &by_len[from ..] {
+                        if len2 > len1.saturating_add(k) { break; }
+                        consider(j, 0);
+                    }
+                },
+            }
+
+            candidates.sort_unstable();
+            for &j in &candidates {
+                verify(i, j);
+            }
+            candidates.clear();
+        }
+    }
+
+    /// Splits the strings of both lists into padded q-grams,
+    /// and orders the q-grams of every string by their total frequency, rarest first.
+    fn grams(&self, left: &[&str], right: &[&str]) -> (Vec<Grams>, Vec<Grams>) {
+        let padding = vec![PAD_SYMBOL; self.q - 1];
+        let mut ids: HashMap<String, usize> = HashMap::new();
+        let mut freqs: Vec<usize> = Vec::new();
+        let mut split = |string: &str| {
+            let text = self.metric.normalize(string);
+            let symbols = symbols(&self.metric, &text);
+            let padded: Vec<&str> = padding.iter()
+                .chain(&symbols)
+                .chain(&padding)
+                .copied()
+                .collect();
+            let grams = padded.windows(self.q)
+                .enumerate()
+                .map(|(pos, window)| {
+                    let next = ids.len();
+                    let id = *ids.entry(window.concat()).or_insert(next);
+                    if id == freqs.len() { freqs.push(0); }
+                    freqs[id] += 1;
+                    (id, pos)
+                })
+                .collect();
+            Grams { len: symbols.len(), grams }
+        };
+        let mut left: Vec<Grams> = left.iter().map(|s| split(s)).collect();
+        let mut right: Vec<Grams> = right.iter().map(|s| split(s)).collect();
+        for grams in left.iter_mut().chain(right.iter_mut()) {
+            grams.grams.sort_unstable_by_key(|&(id, pos)| (freqs[id], id, pos));
+        }
+        (left, right)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::SimilarityJoin;
+    use crate::{Levenshtein, DamerauLevenshtein, Hamming};
+    use crate::index::QGramFilter;
+    use rand::prelude::*;
+
+    fn random_words(rng: &mut StdRng, count: usize, alphabet: &[char], max_len: usize) -> Vec<String> {
+        (0..count)
+            .map(|_| {
+                let len = rng.gen_range(0, max_len + 1);
+                (0..len).map(|_| *alphabet.choose(rng).unwrap()).collect()
+            })
+            .collect()
+    }
+
+    fn brute_within<M: QGramFilter>(metric: &M, left: &[String], right: &[String], k: usize) -> Vec<(usize, usize, usize)> {
+        let mut found = Vec::new();
+        for (i, s1) in left.iter().enumerate() {
+            for (j, s2) in right.iter().enumerate() {
+                let dist = metric.distance(s1.as_str(), s2.as_str());
+                if dist <= k { found.push((i, j, dist)); }
+            }
+        }
+        found
+    }
+
+    #[test]
+    fn within_matches_brute_force() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let alphabet = ['a', 'b', 'c', 'ж'];
+        let left = random_words(&mut rng, 150, &alphabet, 10);
+        let right = random_words(&mut rng, 150, &alphabet, 10);
+        for q in 1..4 {
+            for k in 0..4 {
+                let join = SimilarityJoin::new(q, Levenshtein::new());
+                let expected = brute_within(join.metric(), &left, &right, k);
+                assert_eq!(join.join_within(&left, &right, k), expected, "q={} k={}", q, k);
+                let join = SimilarityJoin::new(q, DamerauLevenshtein::new());
+                let expected = brute_within(join.metric(), &left, &right, k);
+                assert_eq!(join.join_within(&left, &right, k), expected, "q={} k={}", q, k);
+                let join = SimilarityJoin::new(q, Hamming::new());
+                let expected = brute_within(join.metric(), &left, &right, k);
+                assert_eq!(join.join_within(&left, &right, k), expected, "q={} k={}", q, k);
+            }
+        }
+    }
+
+    #[test]
+    fn self_join_within() {
+        let mut rng = StdRng::seed_from_u64(8);
+        let items = random_words(&mut rng, 200, &['x', 'y', 'z'], 8);
+        let join = SimilarityJoin::new(2, Levenshtein::new());
+        for k in 0..3 {
+            let expected: Vec<(usize, usize, usize)> = brute_within(join.metric(), &items, &items, k)
+                .into_iter()
+                .filter(|&(i, j, _)| i < j)
+                .collect();
+            assert_eq!(join.self_join_within(&items, k), expected);
+        }
+    }
+
+    #[test]
+    fn similar_matches_brute_force() {
+        let mut rng = StdRng::seed_from_u64(9);
+        let alphabet = ['a', 'b', 'c', 'd'];
+        let left = random_words(&mut rng, 150, &alphabet, 12);
+        let right = random_words(&mut rng, 150, &alphabet, 12);
+        let lev = Levenshtein::new();
+        for &min_sim in &[0.0, 0.5, 0.6, 0.75, 0.8, 0.9, 1.0] {
+            let mut expected = Vec::new();
+            for (i, s1) in left.iter().enumerate() {
+                for (j, s2) in right.iter().enumerate() {
+                    let sim = 1.0 - lev.rel_dist(s1.as_str(), s2.as_str());
+                    if sim >= min_sim { expected.push((i, j, sim)); }
+                }
+            }
+            for q in 1..4 {
+                let join = SimilarityJoin::new(q, Levenshtein::new());
+                assert_eq!(join.join_similar(&left, &right, min_sim), expected, "q={} t={}", q, min_sim);
+            }
+        }
+    }
+
+    #[test]
+    fn grapheme_mode() {
+        let mut rng = StdRng::seed_from_u64(10);
+        let alphabet = ['a', 'e', '\u{301}', '\u{302}'];
+        let left = random_words(&mut rng, 150, &alphabet, 10);
+        let right = random_words(&mut rng, 150, &alphabet, 10);
+        let lev = || { let mut lev = Levenshtein::new(); lev.set_grapheme_mode(true); lev };
+        let damlev = || { let mut damlev = DamerauLevenshtein::new(); damlev.set_grapheme_mode(true); damlev };
+        for q in 1..4 {
+            for k in 0..3 {
+                let join = SimilarityJoin::new(q, lev());
+                let expected = brute_within(join.metric(), &left, &right, k);
+                assert_eq!(join.join_within(&left, &right, k), expected, "q={} k={}", q, k);
+                let join = SimilarityJoin::new(q, damlev());
+                let expected = brute_within(join.metric(), &left, &right, k);
+                assert_eq!(join.join_within(&left, &right, k), expected, "q={} k={}", q, k);
+            }
+            for &min_sim in &[0.5, 0.8] {
+                let join = SimilarityJoin::new(q, lev());
+                let mut expected = Vec::new();
+                for (i, s1) in left.iter().enumerate() {
+                    for (j, s2) in right.iter().enumerate() {
+                        let sim = 1.0 - join.metric().rel_dist(s1.as_str(), s2.as_str());
+                        if sim >= min_sim { expected.push((i, j, sim)); }
+                    }
+                }
+                assert_eq!(join.join_similar(&left, &right, min_sim), expected, "q={} t={}", q, min_sim);
+            }
+        }
+    }
+
+    #[test]
+    fn normalizer() {
+        use crate::normalize::{Normalizer, CaseFolding};
+        let mut normalizer = Normalizer::new();
+        normalizer.set_case_folding(CaseFolding::Full);
+        let mut rng = StdRng::seed_from_u64(11);
+        let alphabet = ['a', 'A', 'ß', 's', 'S'];
+        let left = random_words(&mut rng, 150, &alphabet, 8);
+        let right = random_words(&mut rng, 150, &alphabet, 8);
+        for q in 1..4 {
+            for k in 0..3 {
+                let join = SimilarityJoin::new(q, Levenshtein::with_normalizer(normalizer));
+                let expected = brute_within(join.metric(), &left, &right, k);
+                assert_eq!(join.join_within(&left, &right, k), expected, "q={} k={}", q, k);
+            }
+            let join = SimilarityJoin::new(q, Levenshtein::with_normalizer(normalizer));
+            let mut expected = Vec::new();
+            for (i, s1) in left.iter().enumerate() {
+                for (j, s2) in right.iter().enumerate() {
+                    let sim = 1.0 - join.metric().rel_dist(s1.as_str(), s2.as_str());
+                    if sim >= 0.75 { expected.push((i, j, sim)); }
+                }
+            }
+            assert_eq!(join.join_similar(&left, &right, 0.75), expected, "q={}", q);
+        }
+    }
+
+    #[test]
+    fn self_join_similar() {
+        let items = ["mailbox", "mailbx", "malbox", "boxmail", "", ""];
+        let join = SimilarityJoin::new(3, DamerauLevenshtein::new());
+        let pairs: Vec<(usize, usize)> = join.self_join_similar(&items, 0.8).iter()
+            .map(|p| (p.0, p.1))
+            .collect();
+        assert_eq!(pairs, vec![(0, 1), (0, 2), (4, 5)]);
+    }
+
+    #[test]
+    fn empty() {
+        let join = SimilarityJoin::new(2, Levenshtein::new());
+        let none: [&str; 0] = [];
+        assert_eq!(join.join_within(&none, &["mailbox"], 1), vec![]);
+        assert_eq!(join.join_within(&["mailbox"], &none, 1), vec![]);
+        assert_eq!(join.self_join_within(&none, 1), vec![]);
+        assert_eq!(join.join_within(&[""], &["", "m", "ma"], 1), vec![(0, 0, 0), (0, 1, 1)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_q() {
+        SimilarityJoin::new(0, Levenshtein::new());
+    }
+}
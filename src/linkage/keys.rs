@@ -0,0 +1,458 @@
+use std::collections::HashMap;
+use crate::JaroWinkler;
+use crate::phonetic::Eudex;
+use crate::utils::fold_latin;
+use super::cluster::{Clusters, DisjointSets};
+
+
+/// Functions computing keys of strings for key-collision clustering:
+/// strings with equal keys are considered duplicates.
+///
+/// Implemented by [`Fingerprint`][1], [`NGramFingerprint`][2], [`PhoneticFingerprint`][3],
+/// and by functions (and closures) `Fn(&str) -> String`.
+///
+/// [1]: struct.Fingerprint.html
+/// [2]: struct.NGramFingerprint.html
+/// [3]: struct.PhoneticFingerprint.html
+pub trait Keyer {
+    /// Returns the key of the string.
+    fn key(&self, s: &str) -> String;
+}
+
+
+impl<F: Fn(&str) -> String> Keyer for F {
+    fn key(&self, s: &str) -> String {
+        self(s)
+    }
+}
+
+
+/// # Fingerprint key.
+///
+/// The key is built the same way as the OpenRefine fingerprint:
+/// the string is trimmed, lower cased, Latin-1 and Latin Extended-A letters
+/// are folded to ASCII, punctuation and control characters are removed,
+/// and the remaining words are sorted and deduplicated.
+///
+/// Catches differences in case, word order, diacritics and punctuation.
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::linkage::{Keyer, Fingerprint};
+///
+/// let fingerprint = Fingerprint::new();
+/// assert_eq!(fingerprint.key("Tom Waits"), "tom waits");
+/// assert_eq!(fingerprint.key("Waits, Tom"), "tom waits");
+/// assert_eq!(fingerprint.key("  TOM  wáits!"), "tom waits");
+/// ```
+pub struct Fingerprint { }
+
+
+impl Fingerprint {
+    /// Creates a new fingerprint keyer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::linkage::Fingerprint;
+    ///
+    /// let fingerprint = Fingerprint::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { }
+    }
+}
+
+
+impl Default for Fingerprint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+impl Keyer for Fingerprint {
+    fn key(&self, s: &str) -> String {
+        let normal = normalize(s);
+        let mut words: Vec<&str> = normal.split_whitespace().collect();
+        words.sort_unstable();
+        words.dedup();
+        words.join(" ")
+    }
+}
+
+
+/// # N-gram fingerprint key.
+///
+/// The string is lower cased, Latin-1 and Latin Extended-A letters
+/// are folded to ASCII, whitespace, punctuation and control characters are removed,
+/// and the key is made of sorted and deduplicated character n-grams.
+/// Strings shorter than `n` are keyed by themselves.
+///
+/// Catches everything the [`Fingerprint`][1] does, and also differences in spacing,
+/// e.g. `mail box` and `mailbox`, but it also brings together more unrelated strings,
+/// especially with small `n`.
+///
+/// [1]: struct.Fingerprint.html
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::linkage::{Keyer, NGramFingerprint};
+///
+/// let ngrams = NGramFingerprint::new(2);
+/// assert_eq!(ngrams.key("Paris"), "arispari");
+/// assert_eq!(ngrams.key("pa ris"), ngrams.key("Paris"));
+/// ```
+pub struct NGramFingerprint {
+    n: usize,
+}
+
+
+impl NGramFingerprint {
+    /// Creates a new n-gram fingerprint keyer. Panics if `n` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::linkage::NGramFingerprint;
+    ///
+    /// let ngrams = NGramFingerprint::new(2);
+    /// ```
+    pub fn new(n: usize) -> Self {
+        assert!(n > 0, "N-gram size should be positive");
+        Self { n }
+    }
+
+    /// Returns the n-gram size.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+}
+
+
+impl Keyer for NGramFingerprint {
+    fn key(&self, s: &str) -> String {
+        let chars: Vec<char> = normalize(s).chars()
+            .filter(|ch| !ch.is_whitespace())
+            .collect();
+        if chars.len() < self.n {
+            return chars.into_iter().collect();
+        }
+        let mut grams: Vec<&[char]> = chars.windows(self.n).collect();
+        grams.sort_unstable();
+        grams.dedup();
+        grams.into_iter().flatten().collect()
+    }
+}
+
+
+/// # Phonetic fingerprint key.
+///
+/// Words of the [`Fingerprint`][1] are encoded by [`Eudex`][2],
+/// and the key is made of sorted and deduplicated hashes.
+///
+/// Catches differences in spelling of words that sound alike, e.g. `Smith` and `Smythe`.
+///
+/// [1]: struct.Fingerprint.html
+/// [2]: ../phonetic/struct.Eudex.html
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::linkage::{Keyer, PhoneticFingerprint};
+///
+/// let phonetic = PhoneticFingerprint::new();
+/// assert_eq!(phonetic.key("John Smith"), phonetic.key("Smythe, John"));
+/// assert_ne!(phonetic.key("John Smith"), phonetic.key("John Doe"));
+/// ```
+pub struct PhoneticFingerprint {
+    eudex: Eudex,
+}
+
+
+impl PhoneticFingerprint {
+    /// Creates a new phonetic fingerprint keyer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::linkage::PhoneticFingerprint;
+    ///
+    /// let phonetic = PhoneticFingerprint::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { eudex: Eudex::new() }
+    }
+}
+
+
+impl Default for PhoneticFingerprint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+impl Keyer for PhoneticFingerprint {
+    fn key(&self, s: &str) -> String {
+        let normal = normalize(s);
+        let mut hashes: Vec<u64> = normal.split_whitespace()
+            .map(|word| self.eudex.hash(word))
+            .collect();
+        hashes.sort_unstable();
+        hashes.dedup();
+        let hashes: Vec<String> = hashes.iter().map(|hash| format!("{:016x}", hash)).collect();
+        hashes.join(" ")
+    }
+}
+
+
+/// Lower cases the string, folds Latin-1 and Latin Extended-A letters to ASCII,
+/// and removes everything except letters, digits and whitespace.
+pub(super) fn normalize(s: &str) -> String {
+    let mut normal = String::with_capacity(s.len());
+    for ch in s.trim().chars().flat_map(char::to_lowercase) {
+        match fold_latin(ch) {
+            Some(folded) => normal.push_str(folded),
+            None if ch.is_alphanumeric() || ch.is_whitespace() => normal.push(ch),
+            None => { },
+        }
+    }
+    normal
+}
+
+
+/// # Key-collision clustering.
+///
+/// Groups strings with equal keys, as computed by the [`Keyer`][1].
+/// Takes linear time, so it is a cheap first pass before any pairwise comparisons.
+///
+/// Optionally, every group is refined with `JaroWinkler`:
+/// it is split into connected components of pairs with at least the given similarity,
+/// which guards against coarse keys (e.g. n-grams) joining unrelated strings.
+///
+/// Medoids minimize the total `JaroWinkler` relative distance within clusters.
+///
+/// [1]: trait.Keyer.html
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::linkage::{KeyCollision, Fingerprint};
+///
+/// let clustering = KeyCollision::new(Fingerprint::new());
+/// let names = ["Tom Waits", "Waits, Tom", "tom waits", "Tom Jones"];
+/// let clusters = clustering.cluster(&names);
+///
+/// assert_eq!(clusters.groups(), vec![vec![0, 1, 2], vec![3]]);
+/// ```
+pub struct KeyCollision<K> {
+    keyer: K,
+    refinement: Option<f64>,
+    jarwin: JaroWinkler,
+}
+
+
+impl<K: Keyer> KeyCollision<K> {
+    /// Creates a new clustering with the given keyer and no refinement.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::linkage::{KeyCollision, NGramFingerprint};
+    ///
+    /// let clustering = KeyCollision::new(NGramFingerprint::new(2));
+    /// ```
+    pub fn new(keyer: K) -> Self {
+        Self { keyer, refinement: None, jarwin: JaroWinkler::new() }
+    }
+
+    /// Returns a reference to the keyer.
+    pub fn keyer(&self) -> &K {
+        &self.keyer
+    }
+
+    /// Returns the minimum `JaroWinkler` similarity within refined groups,
+    /// or `None` if groups are not refined.
+    pub fn refinement(&self) -> Option<f64> {
+        self.refinement
+    }
+
+    /// Enables refinement of groups with the given minimum `JaroWinkler` similarity,
+    /// or disables it with `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::linkage::{KeyCollision, NGramFingerprint};
+    ///
+    /// let mut clustering = KeyCollision::new(NGramFingerprint::new(1));
+    /// assert_eq!(clustering.cluster(&["listen", "silent"]).len(), 1);
+    ///
+    /// clustering.set_refinement(Some(0.9));
+    /// assert_eq!(clustering.cluster(&["listen", "silent"]).len(), 2);
+    /// ```
+    pub fn set_refinement(&mut self, min_sim: Option<f64>) -> &mut Self {
+        self.refinement = min_sim;
+        self
+    }
+
+    /// Groups items by their keys, without refinement.
+    /// Returns keys and items of every group, in the order of their first items.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::linkage::{KeyCollision, Fingerprint};
+    ///
+    /// let clustering = KeyCollision::new(Fingerprint::new());
+    /// let buckets = clustering.buckets(&["Tom Waits", "Tom Jones", "waits tom"]);
+    /// assert_eq!(buckets, vec![
+    ///     ("tom waits".to_string(), vec![0, 2]),
+    ///     ("jones tom".to_string(), vec![1]),
+    /// ]);
+    /// ```
+    pub fn buckets<S: AsRef<str>>(&self, items: &[S]) -> Vec<(String, Vec<usize>)> {
+        let mut ids: HashMap<String, usize> = HashMap::new();
+        let mut buckets: Vec<(String, Vec<usize>)> = Vec::new();
+        for (i, item) in items.iter().enumerate() {
+            let key = self.keyer.key(item.as_ref());
+            match ids.get(&key) {
+                Some(&id) => buckets[id].1.push(i),
+                None => {
+                    ids.insert(key.clone(), buckets.len());
+                    buckets.push((key, vec![i]));
+                },
+            }
+        }
+        buckets
+    }
+
+    /// Clusters items by their keys, refining groups if enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::linkage::{KeyCollision, PhoneticFingerprint};
+    ///
+    /// let clustering = KeyCollision::new(PhoneticFingerprint::new());
+    /// let clusters = clustering.cluster(&["Smith", "Jones", "Smythe"]);
+    /// assert_eq!(clusters.labels(), &[0, 1, 0]);
+    /// ```
+    pub fn cluster<S: AsRef<str>>(&self, items: &[S]) -> Clusters {
+        let mut sets = DisjointSets::new(items.len());
+        for (_, bucket) in self.buckets(items) {
+            match self.refinement {
+                None => for &i in &bucket[1 ..] {
+                    sets.union(bucket[0], i);
+                },
+                Some(min_sim) => for (a, &i) in bucket.iter().enumerate() {
+                    for &j in &bucket[a + 1 ..] {
+                        if self.jarwin.similarity(items[i].as_ref(), items[j].as_ref()) >= min_sim {
+                            sets.union(i, j);
+                        }
+                    }
+                },
+            }
+        }
+        Clusters::new(&mut sets, |i, j| self.jarwin.rel_dist(items[i].as_ref(), items[j].as_ref()))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{Keyer, Fingerprint, NGramFingerprint, PhoneticFingerprint, KeyCollision};
+
+    #[test]
+    fn fingerprint() {
+        let fingerprint = Fingerprint::new();
+        let sample = [
+            ("", ""),
+            ("   ", ""),
+            ("...", ""),
+            ("mailbox", "mailbox"),
+            ("Mailbox Mailbox", "mailbox"),
+            ("Martha's mailbox", "mailbox marthas"),
+            ("  Crème\tBrûlée ", "brulee creme"),
+            ("Łódź, Kraków", "krakow lodz"),
+            ("Straße-Æsir", "strasseaesir"),
+            ("もしもし はい", "はい もしもし"),
+            ("Ärger ärger ÄRGER", "arger"),
+        ];
+        for (s, expected) in sample.iter() {
+            assert_eq!(fingerprint.key(s), *expected, "{}", s);
+        }
+    }
+
+    #[test]
+    fn ngram_fingerprint() {
+        let sample = [
+            (1, "", ""),
+            (1, "banana", "abn"),
+            (2, "banana", "anbana"),
+            (2, "Ba nana!", "anbana"),
+            (2, "m", "m"),
+            (3, "mailbox", "ailboxilblbomai"),
+            (2, "mañana", "anmana"),
+        ];
+        for (n, s, expected) in sample.iter() {
+            assert_eq!(NGramFingerprint::new(*n).key(s), *expected, "{} {}", n, s);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn ngram_zero() {
+        NGramFingerprint::new(0);
+    }
+
+    #[test]
+    fn phonetic_fingerprint() {
+        let phonetic = PhoneticFingerprint::new();
+        assert_eq!(phonetic.key(""), "");
+        assert_eq!(phonetic.key("Smith"), phonetic.key("smythe"));
+        assert_eq!(phonetic.key("John Smith"), phonetic.key("Smith, John John"));
+        assert_ne!(phonetic.key("Smith"), phonetic.key("Jones"));
+        assert_eq!(phonetic.key("Smith Jones").split(' ').count(), 2);
+    }
+
+    #[test]
+    fn closure_keyer() {
+        let clustering = KeyCollision::new(|s: &str| s.len().to_string());
+        let clusters = clustering.cluster(&["mail", "box", "mailbox", "bolt", "tab"]);
+        assert_eq!(clusters.groups(), vec![vec![0, 3], vec![1, 4], vec![2]]);
+    }
+
+    #[test]
+    fn cluster_refined() {
+        let items = ["mailbox", "box mail", "mailbox!", "listen", "silent", "enlist"];
+        let mut clustering = KeyCollision::new(NGramFingerprint::new(1));
+        assert_eq!(clustering.cluster(&items).groups(), vec![vec![0, 1, 2], vec![3, 4, 5]]);
+
+        clustering.set_refinement(Some(0.9));
+        assert_eq!(clustering.refinement(), Some(0.9));
+        let clusters = clustering.cluster(&items);
+        assert_eq!(clusters.groups(), vec![vec![0, 2], vec![1], vec![3], vec![4], vec![5]]);
+        assert_eq!(clusters.medoids(), &[0, 1, 3, 4, 5]);
+    }
+
+    #[test]
+    fn cluster_single_key() {
+        let items = ["Tom Waits", "tom waits", "Waits, Tom", "TOM WAITS"];
+        let clustering = KeyCollision::new(Fingerprint::new());
+        let clusters = clustering.cluster(&items);
+        assert_eq!(clusters.labels(), &[0, 0, 0, 0]);
+        assert_eq!(clusters.len(), 1);
+    }
+
+    #[test]
+    fn cluster_empty() {
+        let clustering = KeyCollision::new(Fingerprint::new());
+        let items: [&str; 0] = [];
+        assert!(clustering.cluster(&items).is_empty());
+        assert!(clustering.buckets(&items).is_empty());
+    }
+}
@@ -0,0 +1,77 @@
+//! Record linkage: finding and grouping similar strings and records in large collections.
+//!
+//!
+//! # Basic Usage
+//!
+//! Similarity join:
+//! ```rust
+//! use eddie::Levenshtein;
+//! use eddie::linkage::SimilarityJoin;
+//!
+//! let join = SimilarityJoin::new(2, Levenshtein::new());
+//! let found = join.join_within(&["mailbox", "martha"], &["mailbx", "marhta", "boxmail"], 1);
+//! assert_eq!(found, vec![(0, 0, 1)]);
+//! ```
+//!
+//! Clustering:
+//! ```rust
+//! use eddie::JaroWinkler;
+//! use eddie::linkage::{Agglomerative, Linkage};
+//!
+//! let clustering = Agglomerative::new(JaroWinkler::new(), Linkage::Complete);
+//! let clusters = clustering.cluster(&["mailbox", "martha", "mailbx"], 0.1);
+//! assert_eq!(clusters.labels(), &[0, 1, 0]);
+//! assert_eq!(clusters.medoid(1), 1);
+//! ```
+//!
+//! Key collisions:
+//! ```rust
+//! use eddie::linkage::{KeyCollision, Fingerprint};
+//!
+//! let clustering = KeyCollision::new(Fingerprint::new());
+//! let clusters = clustering.cluster(&["Tom Waits", "Tom Jones", "Waits, Tom"]);
+//! assert_eq!(clusters.labels(), &[0, 1, 0]);
+//! ```
+//!
+//! Record comparison:
+//! ```rust
+//! use eddie::{JaroWinkler, Levenshtein};
+//! use eddie::linkage::{RecordComparator, Decision};
+//!
+//! let mut comparator = RecordComparator::new();
+//! comparator
+//!     .add_field("name", JaroWinkler::new(), &[0.9])
+//!     .add_field("city", Levenshtein::new(), &[0.8]);
+//! let decision = comparator.decide(&["Martha", "London"], &["Marhta", "Londn"]);
+//! assert_eq!(decision, Decision::Match);
+//! ```
+//!
+//! Blocking, feeding candidate pairs to the comparator:
+//! ```rust
+//! use eddie::JaroWinkler;
+//! use eddie::linkage::{PhoneticBlocking, RecordComparator, Decision};
+//!
+//! let mut comparator = RecordComparator::new();
+//! comparator.add_field("name", JaroWinkler::new(), &[0.9]);
+//!
+//! let left = [["John Smith"], ["Mary Jones"]];
+//! let right = [["Jon Smyth"], ["Mary Brown"]];
+//! let matches: Vec<(usize, usize)> = PhoneticBlocking::new()
+//!     .link(&["John Smith", "Mary Jones"], &["Jon Smyth", "Mary Brown"])
+//!     .filter(|&(i, j)| comparator.decide(&left[i], &right[j]) == Decision::Match)
+//!     .collect();
+//! assert_eq!(matches, vec![(0, 0)]);
+//! ```
+
+
+mod join;
+mod cluster;
+mod keys;
+mod record;
+mod blocking;
+
+pub use join::SimilarityJoin;
+pub use cluster::{Clusters, ThresholdClustering, Agglomerative, Linkage};
+pub use keys::{Keyer, Fingerprint, NGramFingerprint, PhoneticFingerprint, KeyCollision};
+pub use record::{RecordComparator, Decision};
+pub use blocking::{KeyBlocking, SortedNeighbourhood, QGramBlocking, PhoneticBlocking, Candidates};
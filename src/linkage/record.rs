@@ -0,0 +1,555 @@
+use crate::process::Scorer;
+
+
+/// Additive smoothing of estimated probabilities, so that no level gets zero weight.
+const SMOOTHING: f64 = 1e-6;
+
+/// Estimation stops when no probability changes by more than this.
+const TOLERANCE: f64 = 1e-9;
+
+
+/// Decision about a pair of records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    /// The records refer to the same entity.
+    Match,
+    /// The pair should be reviewed manually.
+    Possible,
+    /// The records refer to different entities.
+    NonMatch,
+}
+
+
+/// A field of records compared by the [`RecordComparator`][1].
+///
+/// [1]: struct.RecordComparator.html
+struct Field {
+    name: String,
+    scorer: Box<dyn Scorer>,
+    // Descending similarity thresholds of agreement levels.
+    levels: Vec<f64>,
+    // Probabilities of every level (and of disagreement, the last one)
+    // among matching and non-matching pairs.
+    m: Vec<f64>,
+    u: Vec<f64>,
+}
+
+
+impl Field {
+    /// Returns the agreement level of two values: the first one with the similarity threshold
+    /// not above their similarity, or `levels.len()` for disagreement.
+    /// Returns `None` if any value is missing, i.e. empty or whitespace.
+    fn level(&self, value1: &str, value2: &str) -> Option<usize> {
+        let (value1, value2) = (value1.trim(), value2.trim());
+        if value1.is_empty() || value2.is_empty() { return None; }
+        let score = self.scorer.score(value1, value2);
+        Some(self.levels.iter().position(|&min| score >= min).unwrap_or(self.levels.len()))
+    }
+
+    fn weight(&self, level: usize) -> f64 {
+        (self.m[level] / self.u[level]).log2()
+    }
+}
+
+
+/// # Record comparator.
+///
+/// Compares records (slices of field values) field by field,
+/// and computes the Fellegi-Sunter match weight of the pair.
+///
+/// Every field has its own metric (any [`Scorer`][1]) and descending similarity thresholds,
+/// splitting pairs of values into agreement levels, from the closest to disagreement.
+/// Every level has probabilities `m` (among matching pairs) and `u` (among non-matching pairs),
+/// and contributes `log2(m / u)` to the weight. Missing (empty) values contribute nothing.
+///
+/// Pairs with weights above the upper threshold are matches,
+/// below the lower threshold are non-matches, and possible matches in between.
+/// Both thresholds are zero by default.
+///
+/// The probabilities can be set manually, or estimated from unlabeled pairs
+/// with the expectation-maximization algorithm.
+///
+/// [1]: ../process/trait.Scorer.html
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::{JaroWinkler, Levenshtein};
+/// use eddie::linkage::{RecordComparator, Decision};
+///
+/// let mut comparator = RecordComparator::new();
+/// comparator
+///     .add_field("name", JaroWinkler::new(), &[0.95, 0.85])
+///     .add_field("city", Levenshtein::new(), &[0.8])
+///     .add_field("birth", |d1: &str, d2: &str| if d1 == d2 { 1.0 } else { 0.0 }, &[1.0])
+///     .set_thresholds(-1.0, 6.0);
+///
+/// let rec1 = ["Martha Smith", "London", "1970-01-02"];
+/// let rec2 = ["Marhta Smith", "Londn", "1970-01-02"];
+/// let rec3 = ["Martin Smith", "Leeds", "1981-11-20"];
+/// let rec4 = ["Martha Smith", "Leeds", ""];
+///
+/// assert_eq!(comparator.decide(&rec1, &rec2), Decision::Match);
+/// assert_eq!(comparator.decide(&rec1, &rec3), Decision::NonMatch);
+/// assert_eq!(comparator.decide(&rec1, &rec4), Decision::Possible);
+/// ```
+pub struct RecordComparator {
+    fields: Vec<Field>,
+    lower: f64,
+    upper: f64,
+}
+
+
+impl RecordComparator {
+    /// Creates a new comparator without fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::linkage::RecordComparator;
+    ///
+    /// let comparator = RecordComparator::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { fields: Vec::new(), lower: 0.0, upper: 0.0 }
+    }
+
+    /// Returns a number of fields.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Returns `true` if the comparator has no fields.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Returns names of the fields.
+    pub fn names(&self) -> Vec<&str> {
+        self.fields.iter().map(|field| field.name.as_str()).collect()
+    }
+
+    /// Returns the lower and the upper thresholds.
+    pub fn thresholds(&self) -> (f64, f64) {
+        (self.lower, self.upper)
+    }
+
+    /// Returns `m` probabilities of agreement levels of the field.
+    pub fn m_probs(&self, field: usize) -> &[f64] {
+        &self.fields[field].m
+    }
+
+    /// Returns `u` probabilities of agreement levels of the field.
+    pub fn u_probs(&self, field: usize) -> &[f64] {
+        &self.fields[field].u
+    }
+
+    /// Adds a field compared by the scorer, with agreement levels split
+    /// by the given descending similarity thresholds.
+    /// Panics if there are no thresholds, or they are not descending.
+    ///
+    /// The probabilities are initialized with `m` of 0.9 and `u` of 0.1 for disagreement
+    /// (and vice versa), spread evenly over the agreement levels.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::JaroWinkler;
+    /// use eddie::linkage::RecordComparator;
+    ///
+    /// let mut comparator = RecordComparator::new();
+    /// comparator.add_field("name", JaroWinkler::new(), &[0.9]);
+    /// assert_eq!(comparator.m_probs(0), &[0.9, 0.1]);
+    /// assert_eq!(comparator.u_probs(0), &[0.1, 0.9]);
+    /// ```
+    pub fn add_field<S: Scorer + 'static>(&mut self, name: &str, scorer: S, levels: &[f64]) -> &mut Self {
+        assert!(!levels.is_empty(), "Field should have at least one agreement level");
+        assert!(levels.windows(2).all(|w| w[0] > w[1]), "Level thresholds should be descending");
+        let agree = levels.len() as f64;
+        let mut m = vec![0.9 / agree; levels.len()];
+        let mut u = vec![0.1 / agree; levels.len()];
+        m.push(0.1);
+        u.push(0.9);
+        self.fields.push(Field {
+            name: name.to_string(),
+            scorer: Box::new(scorer),
+            levels: levels.to_vec(),
+            m,
+            u,
+        });
+        self
+    }
+
+    /// Sets `m` and `u` probabilities of agreement levels of the field,
+    /// the last ones being probabilities of disagreement.
+    /// Panics if the number of probabilities does not match the number of levels,
+    /// or any of them is not positive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::JaroWinkler;
+    /// use eddie::linkage::RecordComparator;
+    ///
+    /// let mut comparator = RecordComparator::new();
+    /// comparator
+    ///     .add_field("name", JaroWinkler::new(), &[0.95, 0.85])
+    ///     .set_probs(0, &[0.8, 0.15, 0.05], &[0.01, 0.04, 0.95]);
+    /// ```
+    pub fn set_probs(&mut self, field: usize, m: &[f64], u: &[f64]) -> &mut Self {
+        let field = &mut self.fields[field];
+        assert_eq!(m.len(), field.levels.len() + 1, "Wrong number of m probabilities");
+        assert_eq!(u.len(), field.levels.len() + 1, "Wrong number of u probabilities");
+        assert!(m.iter().chain(u).all(|&p| p > 0.0), "Probabilities should be positive");
+        field.m = m.to_vec();
+        field.u = u.to_vec();
+        self
+    }
+
+    /// Sets the lower and the upper weight thresholds.
+    /// Panics if the lower threshold is above the upper one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::linkage::RecordComparator;
+    ///
+    /// let mut comparator = RecordComparator::new();
+    /// comparator.set_thresholds(-2.0, 8.0);
+    /// assert_eq!(comparator.thresholds(), (-2.0, 8.0));
+    /// ```
+    pub fn set_thresholds(&mut self, lower: f64, upper: f64) -> &mut Self {
+        assert!(lower <= upper, "Lower threshold should not exceed the upper one");
+        self.lower = lower;
+        self.upper = upper;
+        self
+    }
+
+    /// Compares two records field by field, and returns the comparison vector:
+    /// agreement levels of fields (0 is the closest, the number of thresholds is disagreement),
+    /// or `None` for missing values.
+    /// Panics if the records have fewer values than the comparator has fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::JaroWinkler;
+    /// use eddie::linkage::RecordComparator;
+    ///
+    /// let mut comparator = RecordComparator::new();
+    /// comparator
+    ///     .add_field("first", JaroWinkler::new(), &[0.97, 0.9])
+    ///     .add_field("last", JaroWinkler::new(), &[0.97, 0.9]);
+    ///
+    /// assert_eq!(comparator.compare(&["Martha", "Smith"], &["Marhta", "Smith"]), vec![Some(1), Some(0)]);
+    /// assert_eq!(comparator.compare(&["Martha", "Smith"], &["Mary", ""]), vec![Some(2), None]);
+    /// ```
+    pub fn compare<S1: AsRef<str>, S2: AsRef<str>>(&self, rec1: &[S1], rec2: &[S2]) -> Vec<Option<usize>> {
+        assert!(rec1.len() >= self.len() && rec2.len() >= self.len(), "Records should have all fields");
+        self.fields.iter()
+            .zip(rec1.iter().zip(rec2))
+            .map(|(field, (value1, value2))| field.level(value1.as_ref(), value2.as_ref()))
+            .collect()
+    }
+
+    /// Returns the match weight of the comparison vector:
+    /// the sum of `log2(m / u)` over fields with values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::JaroWinkler;
+    /// use eddie::linkage::RecordComparator;
+    ///
+    /// let mut comparator = RecordComparator::new();
+    /// comparator
+    ///     .add_field("name", JaroWinkler::new(), &[0.9])
+    ///     .set_probs(0, &[0.8, 0.2], &[0.1, 0.4]);
+    ///
+    /// assert_eq!(comparator.weight(&[Some(0)]), 3.0);
+    /// assert_eq!(comparator.weight(&[Some(1)]), -1.0);
+    /// assert_eq!(comparator.weight(&[None]), 0.0);
+    /// ```
+    pub fn weight(&self, levels: &[Option<usize>]) -> f64 {
+        self.fields.iter()
+            .zip(levels)
+            .filter_map(|(field, level)| level.map(|level| field.weight(level)))
+            .sum()
+    }
+
+    /// Classifies a pair by its match weight.
+    pub fn classify(&self, weight: f64) -> Decision {
+        if weight > self.upper { return Decision::Match; }
+        if weight < self.lower { return Decision::NonMatch; }
+        Decision::Possible
+    }
+
+    /// Returns the match weight of two records.
+    pub fn match_weight<S1: AsRef<str>, S2: AsRef<str>>(&self, rec1: &[S1], rec2: &[S2]) -> f64 {
+        self.weight(&self.compare(rec1, rec2))
+    }
+
+    /// Compares two records and classifies the pair by its match weight.
+    pub fn decide<S1: AsRef<str>, S2: AsRef<str>>(&self, rec1: &[S1], rec2: &[S2]) -> Decision {
+        self.classify(self.match_weight(rec1, rec2))
+    }
+
+    /// Estimates `m` and `u` probabilities of all fields from comparison vectors
+    /// of unlabeled pairs with the expectation-maximization algorithm,
+    /// starting from the current probabilities.
+    /// Returns the estimated proportion of matches among the pairs.
+    ///
+    /// Comparison vectors shorter than the number of fields miss the levels of the remaining fields,
+    /// as in the [match weight][1].
+    ///
+    /// [1]: #method.weight
+    ///
+    /// Pairs should contain enough matches (e.g. pairs from blocking, not random pairs),
+    /// and the initial probabilities should favor agreement among matches,
+    /// otherwise the estimates may swap the roles of `m` and `u`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::JaroWinkler;
+    /// use eddie::linkage::RecordComparator;
+    ///
+    /// let mut comparator = RecordComparator::new();
+    /// comparator
+    ///     .add_field("first", JaroWinkler::new(), &[0.9])
+    ///     .add_field("last", JaroWinkler::new(), &[0.9]);
+    ///
+    /// let records = [
+    ///     ["Martha", "Smith"], ["Marhta", "Smith"], ["John", "Jones"], ["Jon", "Jones"],
+    ///     ["Mary", "Brown"], ["Ann", "Smyth"],
+    /// ];
+    /// let mut pairs = Vec::new();
+    /// for i in 0..records.len() {
+    ///     for j in i + 1 .. records.len() {
+    ///         pairs.push(comparator.compare(&records[i], &records[j]));
+    ///     }
+    /// }
+    /// let proportion = comparator.estimate(&pairs, 100);
+    /// assert!(proportion > 0.0 && proportion < 0.5);
+    /// assert!(comparator.m_probs(1)[0] > comparator.u_probs(1)[0]);
+    /// ```
+    pub fn estimate(&mut self, pairs: &[Vec<Option<usize>>], max_iter: usize) -> f64 {
+        let mut proportion = 0.1;
+        if pairs.is_empty() { return proportion; }
+        let mut posteriors = vec![0.0; pairs.len()];
+
+        for _ in 0..max_iter {
+            // Expectation: posterior probabilities of pairs being matches.
+            for (posterior, levels) in posteriors.iter_mut().zip(pairs) {
+                let (mut m, mut u) = (proportion, 1.0 - proportion);
+                for (field, level) in self.fields.iter().zip(levels) {
+                    if let Some(level) = *level {
+                        m *= field.m[level];
+                        u *= field.u[level];
+                    }
+                }
+                *posterior = if m + u > 0.0 { m / (m + u) } else { proportion };
+            }
+
+            // Maximization: probabilities of levels weighted by posteriors.
+            let mut change: f64 = 0.0;
+            for (f, field) in self.fields.iter_mut().enumerate() {
+                let outcomes = field.m.len();
+                let mut m = vec![SMOOTHING; outcomes];
+                let mut u = vec![SMOOTHING; outcomes];
+                for (posterior, levels) in posteriors.iter().zip(pairs) {
+                    if let Some(level) = levels.get(f).copied().flatten() {
+                        m[level] += posterior;
+                        u[level] += 1.0 - posterior;
+                    }
+                }
+                let (m_total, u_total): (f64, f64) = (m.iter().sum(), u.iter().sum());
+                for level in 0..outcomes {
+                    let (m, u) = (m[level] / m_total, u[level] / u_total);
+                    change = change.max((field.m[level] - m).abs()).max((field.u[level] - u).abs());
+                    field.m[level] = m;
+                    field.u[level] = u;
+                }
+            }
+
+            let total: f64 = posteriors.iter().sum();
+            let next = total / pairs.len() as f64;
+            change = change.max((next - proportion).abs());
+            proportion = next;
+            if change < TOLERANCE { break; }
+        }
+
+        proportion
+    }
+}
+
+
+impl Default for RecordComparator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{RecordComparator, Decision};
+    use crate::{Levenshtein, JaroWinkler};
+    use rand::prelude::*;
+
+    fn exact(s1: &str, s2: &str) -> f64 {
+        if s1 == s2 { 1.0 } else { 0.0 }
+    }
+
+    fn comparator() -> RecordComparator {
+        let mut comparator = RecordComparator::new();
+        comparator
+            .add_field("name", JaroWinkler::new(), &[0.97, 0.9])
+            .add_field("city", Levenshtein::new(), &[0.8])
+            .add_field("birth", exact, &[1.0])
+            .set_probs(0, &[0.8, 0.15, 0.05], &[0.01, 0.04, 0.95])
+            .set_probs(1, &[0.9, 0.1], &[0.2, 0.8])
+            .set_probs(2, &[0.95, 0.05], &[0.001, 0.999])
+            .set_thresholds(0.0, 8.0);
+        comparator
+    }
+
+    #[test]
+    fn fields() {
+        let comparator = comparator();
+        assert_eq!(comparator.len(), 3);
+        assert!(!comparator.is_empty());
+        assert!(RecordComparator::new().is_empty());
+        assert_eq!(comparator.names(), vec!["name", "city", "birth"]);
+        assert_eq!(comparator.m_probs(1), &[0.9, 0.1]);
+        assert_eq!(comparator.u_probs(2), &[0.001, 0.999]);
+    }
+
+    #[test]
+    fn default_probs() {
+        let mut comparator = RecordComparator::new();
+        comparator.add_field("name", exact, &[0.9, 0.8, 0.7]);
+        assert_eq!(comparator.m_probs(0), &[0.3, 0.3, 0.3, 0.1]);
+        assert_eq!(comparator.u_probs(0), &[0.1 / 3.0, 0.1 / 3.0, 0.1 / 3.0, 0.9]);
+    }
+
+    type Sample<'a> = (&'a [&'a str], &'a [&'a str], Vec<Option<usize>>);
+
+    #[test]
+    fn compare() {
+        let comparator = comparator();
+        let sample: [Sample; 5] = [
+            (&["Martha", "London", "1970"], &["Martha", "London", "1970"], vec![Some(0), Some(0), Some(0)]),
+            (&["Martha", "London", "1970"], &["Marhta", "Londn", "1971"], vec![Some(1), Some(0), Some(1)]),
+            (&["Martha", "London", "1970"], &["John", "Leeds", "1970"], vec![Some(2), Some(1), Some(0)]),
+            (&["Martha", "  ", "1970"], &["Martha", "London", ""], vec![Some(0), None, None]),
+            (&["", "", ""], &["", "", ""], vec![None, None, None]),
+        ];
+        for (rec1, rec2, expected) in sample.iter() {
+            assert_eq!(&comparator.compare(rec1, rec2), expected);
+        }
+    }
+
+    #[test]
+    fn weights() {
+        let comparator = comparator();
+        let expected = (0.8f64 / 0.01).log2() + (0.9f64 / 0.2).log2() + (0.95f64 / 0.001).log2();
+        assert!((comparator.weight(&[Some(0), Some(0), Some(0)]) - expected).abs() < 1e-9);
+        let expected = (0.05f64 / 0.95).log2() + (0.1f64 / 0.8).log2();
+        assert!((comparator.weight(&[Some(2), Some(1), None]) - expected).abs() < 1e-9);
+        assert_eq!(comparator.weight(&[None, None, None]), 0.0);
+        let rec = ["Martha", "London", "1970"];
+        assert_eq!(comparator.match_weight(&rec, &rec), comparator.weight(&[Some(0), Some(0), Some(0)]));
+    }
+
+    #[test]
+    fn decide() {
+        let comparator = comparator();
+        assert_eq!(comparator.classify(8.5), Decision::Match);
+        assert_eq!(comparator.classify(8.0), Decision::Possible);
+        assert_eq!(comparator.classify(0.0), Decision::Possible);
+        assert_eq!(comparator.classify(-0.5), Decision::NonMatch);
+        let rec = ["Martha", "London", "1970"];
+        assert_eq!(comparator.decide(&rec, &["Marhta", "London", "1970"]), Decision::Match);
+        assert_eq!(comparator.decide(&rec, &["Martha", "", ""]), Decision::Possible);
+        assert_eq!(comparator.decide(&rec, &["John", "Leeds", "1980"]), Decision::NonMatch);
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_levels() {
+        RecordComparator::new().add_field("name", exact, &[]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ascending_levels() {
+        RecordComparator::new().add_field("name", exact, &[0.8, 0.9]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn wrong_probs() {
+        RecordComparator::new()
+            .add_field("name", exact, &[0.9])
+            .set_probs(0, &[0.9, 0.05, 0.05], &[0.1, 0.9]);
+    }
+
+    #[test]
+    fn estimate_synthetic() {
+        // Comparison vectors sampled from known probabilities, with 20% of matches.
+        let m = [[0.85, 0.1, 0.05], [0.9, 0.05, 0.05], [0.7, 0.2, 0.1]];
+        let u = [[0.05, 0.15, 0.8], [0.1, 0.1, 0.8], [0.02, 0.08, 0.9]];
+        let mut rng = StdRng::seed_from_u64(42);
+        let sample = |rng: &mut StdRng, probs: &[f64; 3]| {
+            let x: f64 = rng.gen();
+            if x < probs[0] { 0 } else if x < probs[0] + probs[1] { 1 } else { 2 }
+        };
+        let pairs: Vec<Vec<Option<usize>>> = (0..20000)
+            .map(|_| {
+                let probs = if rng.gen::<f64>() < 0.2 { &m } else { &u };
+                probs.iter().map(|p| {
+                    if rng.gen::<f64>() < 0.05 { None } else { Some(sample(&mut rng, p)) }
+                }).collect()
+            })
+            .collect();
+
+        let mut comparator = RecordComparator::new();
+        for name in &["first", "last", "city"] {
+            comparator.add_field(name, exact, &[0.9, 0.8]);
+        }
+        let proportion = comparator.estimate(&pairs, 500);
+        assert!((proportion - 0.2).abs() < 0.02, "{}", proportion);
+        for field in 0..3 {
+            for level in 0..3 {
+                assert!((comparator.m_probs(field)[level] - m[field][level]).abs() < 0.03);
+                assert!((comparator.u_probs(field)[level] - u[field][level]).abs() < 0.03);
+            }
+        }
+    }
+
+    #[test]
+    fn estimate_short() {
+        let short = vec![vec![Some(0)], vec![Some(2), Some(1)], vec![], vec![Some(0), Some(0), Some(0)]];
+        let padded: Vec<Vec<Option<usize>>> = short.iter()
+            .map(|levels| {
+                let mut levels = levels.clone();
+                levels.resize(3, None);
+                levels
+            })
+            .collect();
+        let (mut comparator1, mut comparator2) = (comparator(), comparator());
+        assert_eq!(comparator1.estimate(&short, 10), comparator2.estimate(&padded, 10));
+        for field in 0..3 {
+            assert_eq!(comparator1.m_probs(field), comparator2.m_probs(field));
+            assert_eq!(comparator1.u_probs(field), comparator2.u_probs(field));
+        }
+    }
+
+    #[test]
+    fn estimate_empty() {
+        let mut comparator = comparator();
+        assert_eq!(comparator.estimate(&[], 10), 0.1);
+        assert_eq!(comparator.m_probs(1), &[0.9, 0.1]);
+    }
+}
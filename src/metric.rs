@@ -0,0 +1,369 @@
+//! Traits for writing code that is generic over the metrics from this crate.
+//!
+//! Implementations in `eddie::str` accept anything that can be viewed
+//! as `&str` (`str`, `String`, `&str`, ...), and implementations in `eddie::slice`
+//! accept `[T]` and `Vec<T>`.
+//!
+//!
+//! # Basic Usage
+//!
+//! ```rust
+//! use eddie::Levenshtein;
+//! use eddie::metric::Distance;
+//!
+//! fn closest<'a, M: Distance<str>>(metric: &M, query: &str, words: &[&'a str]) -> Option<&'a str> {
+//!     words.iter()
+//!         .min_by_key(|word| metric.distance(query, word))
+//!         .copied()
+//! }
+//!
+//! let lev = Levenshtein::new();
+//! assert_eq!(closest(&lev, "mailbx", &["boxmail", "mailbox"]), Some("mailbox"));
+//! ```
+
+use crate::{Levenshtein, Hamming, Jaro, JaroWinkler};
+#[cfg(feature = "alloc")]
+use crate::DamerauLevenshtein;
+use crate::slice;
+#[cfg(feature = "std")]
+use crate::sync;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use crate::phonetic::Eudex;
+
+
+/// Integer-valued distance between two items.
+pub trait Distance<T: ?Sized> {
+    /// Returns a distance between two items, zero meaning equality.
+    fn distance(&self, item1: &T, item2: &T) -> usize;
+}
+
+
+/// Marker for distances that are true metrics, i.e. symmetric
+/// and satisfying the triangle inequality:
+/// `d(a, c) <= d(a, b) + d(b, c)`.
+///
+/// Metric space indexes, e.g. [`BKTree`][1], rely on it to prune the search.
+///
+/// Implemented by `Levenshtein`, `DamerauLevenshtein`, `Hamming` and `Eudex`.
+/// Distinct items may be at distance zero, e.g. words that sound alike for `Eudex`,
+/// so indexes tell keys apart by equality rather than by distance.
+/// Hamming distance between items of different lengths is defined here as if the
+/// shorter item was padded with a value that is not equal to anything,
+/// so that it stays a metric over items of any length.
+///
+/// [1]: ../index/struct.BKTree.html
+pub trait Metric<T: ?Sized>: Distance<T> { }
+
+
+/// Relative distance between two items, from 0.0 (equality) to 1.0.
+///
+/// Implemented by `Levenshtein`, `DamerauLevenshtein`, `Jaro`, `JaroWinkler`
+/// and their counterparts from `eddie::slice` (via their `rel_dist` methods),
+/// and by any function `Fn(&T, &T) -> f64`.
+///
+/// None of the relative distances above is a true metric.
+/// `Jaro` and `JaroWinkler` are not, and normalizing by the length
+/// breaks the triangle inequality even for edit distances:
+/// `rel_dist("ab", "ba") = 1.0`, while `rel_dist("ab", "aba") = rel_dist("aba", "ba") = 0.33`.
+/// Metric space indexes, e.g. [`VPTree`][1], may miss some results with them.
+/// Integer-valued [metrics][2] converted to `f64` are true metrics.
+///
+/// [1]: ../index/struct.VPTree.html
+/// [2]: trait.Metric.html
+pub trait RelDistance<T: ?Sized> {
+    /// Returns a relative distance between two items.
+    fn rel_dist(&self, item1: &T, item2: &T) -> f64;
+}
+
+
+impl<T: ?Sized, F: Fn(&T, &T) -> f64> RelDistance<T> for F {
+    fn rel_dist(&self, item1: &T, item2: &T) -> f64 {
+        self(item1, item2)
+    }
+}
+
+
+impl<S: AsRef<str> + ?Sized, const N: usize> Distance<S> for Levenshtein<N> {
+    fn distance(&self, item1: &S, item2: &S) -> usize {
+        Levenshtein::distance(self, item1.as_ref(), item2.as_ref())
+    }
+}
+
+impl<S: AsRef<str> + ?Sized, const N: usize> Metric<S> for Levenshtein<N> { }
+
+
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str> + ?Sized, const N: usize> Distance<S> for DamerauLevenshtein<N> {
+    fn distance(&self, item1: &S, item2: &S) -> usize {
+        DamerauLevenshtein::distance(self, item1.as_ref(), item2.as_ref())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str> + ?Sized, const N: usize> Metric<S> for DamerauLevenshtein<N> { }
+
+
+impl<S: AsRef<str> + ?Sized> Distance<S> for Hamming {
+    fn distance(&self, item1: &S, item2: &S) -> usize {
+        self.padded_distance(item1.as_ref(), item2.as_ref())
+    }
+}
+
+impl<S: AsRef<str> + ?Sized> Metric<S> for Hamming { }
+
+
+impl<S: AsRef<str> + ?Sized> Distance<S> for Eudex {
+    fn distance(&self, item1: &S, item2: &S) -> usize {
+        Eudex::distance(self, item1.as_ref(), item2.as_ref())
+    }
+}
+
+impl<S: AsRef<str> + ?Sized> Metric<S> for Eudex { }
+
+
+macro_rules! impl_str_rel_dist {
+    ($([$($params: tt)*] $metric: ty),*) => {
+        $(
+            impl<S: AsRef<str> + ?Sized, $($params)*> RelDistance<S> for $metric {
+                fn rel_dist(&self, item1: &S, item2: &S) -> f64 {
+                    <$metric>::rel_dist(self, item1.as_ref(), item2.as_ref())
+                }
+            }
+        )*
+    };
+}
+
+impl_str_rel_dist!(
+    [const N: usize] Levenshtein<N>,
+    [const N: usize] Jaro<N>,
+    [const N: usize] JaroWinkler<N>
+);
+
+#[cfg(feature = "alloc")]
+impl_str_rel_dist!([const N: usize] DamerauLevenshtein<N>);
+
+
+#[cfg(feature = "std")]
+impl<S: AsRef<str> + ?Sized> Distance<S> for sync::Levenshtein {
+    fn distance(&self, item1: &S, item2: &S) -> usize {
+        sync::Levenshtein::distance(self, item1.as_ref(), item2.as_ref())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S: AsRef<str> + ?Sized> Metric<S> for sync::Levenshtein { }
+
+
+#[cfg(feature = "std")]
+impl<S: AsRef<str> + ?Sized> Distance<S> for sync::DamerauLevenshtein {
+    fn distance(&self, item1: &S, item2: &S) -> usize {
+        sync::DamerauLevenshtein::distance(self, item1.as_ref(), item2.as_ref())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S: AsRef<str> + ?Sized> Metric<S> for sync::DamerauLevenshtein { }
+
+#[cfg(feature = "std")]
+impl_str_rel_dist!([] sync::Levenshtein, [] sync::DamerauLevenshtein, [] sync::Jaro, [] sync::JaroWinkler);
+
+
+impl<T: PartialEq + Copy> Distance<[T]> for slice::Levenshtein {
+    fn distance(&self, item1: &[T], item2: &[T]) -> usize {
+        slice::Levenshtein::distance(self, item1, item2)
+    }
+}
+
+impl<T: PartialEq + Copy> Metric<[T]> for slice::Levenshtein { }
+
+
+#[cfg(feature = "alloc")]
+impl<T: PartialEq + Copy + Ord> Distance<[T]> for slice::DamerauLevenshtein<T> {
+    fn distance(&self, item1: &[T], item2: &[T]) -> usize {
+        slice::DamerauLevenshtein::distance(self, item1, item2)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: PartialEq + Copy + Ord> Metric<[T]> for slice::DamerauLevenshtein<T> { }
+
+
+impl<T: PartialEq + Copy> Distance<[T]> for slice::Hamming {
+    fn distance(&self, item1: &[T], item2: &[T]) -> usize {
+        let dist = item1.iter().zip(item2.iter()).filter(|(x1, x2)| x1 != x2).count();
+        let len1 = item1.len();
+        let len2 = item2.len();
+        dist + max!(len1, len2) - min!(len1, len2)
+    }
+}
+
+impl<T: PartialEq + Copy> Metric<[T]> for slice::Hamming { }
+
+
+#[cfg(feature = "alloc")]
+macro_rules! impl_vec_metric {
+    ($metric: ty, $($bounds: tt)*) => {
+        impl<T: $($bounds)*> Distance<Vec<T>> for $metric {
+            fn distance(&self, item1: &Vec<T>, item2: &Vec<T>) -> usize {
+                Distance::<[T]>::distance(self, item1, item2)
+            }
+        }
+
+        impl<T: $($bounds)*> Metric<Vec<T>> for $metric { }
+    };
+}
+
+#[cfg(feature = "alloc")]
+impl_vec_metric!(slice::Levenshtein, PartialEq + Copy);
+#[cfg(feature = "alloc")]
+impl_vec_metric!(slice::DamerauLevenshtein<T>, PartialEq + Copy + Ord);
+#[cfg(feature = "alloc")]
+impl_vec_metric!(slice::Hamming, PartialEq + Copy);
+
+
+macro_rules! impl_slice_rel_dist {
+    ($metric: ty, $($bounds: tt)*) => {
+        impl<T: $($bounds)*> RelDistance<[T]> for $metric {
+            fn rel_dist(&self, item1: &[T], item2: &[T]) -> f64 {
+                <$metric>::rel_dist(self, item1, item2)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<T: $($bounds)*> RelDistance<Vec<T>> for $metric {
+            fn rel_dist(&self, item1: &Vec<T>, item2: &Vec<T>) -> f64 {
+                <$metric>::rel_dist(self, item1, item2)
+            }
+        }
+    };
+}
+
+impl_slice_rel_dist!(slice::Levenshtein, PartialEq + Copy);
+#[cfg(feature = "alloc")]
+impl_slice_rel_dist!(slice::DamerauLevenshtein<T>, PartialEq + Copy + Ord);
+impl_slice_rel_dist!(slice::Jaro, PartialEq + Copy);
+impl_slice_rel_dist!(slice::JaroWinkler, PartialEq + Copy);
+
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{Distance, RelDistance};
+    use crate::{Levenshtein, DamerauLevenshtein, Hamming, Jaro, JaroWinkler};
+    use crate::{slice, sync};
+    use crate::phonetic::Eudex;
+
+    const WORDS: [&str; 10] = [
+        "", "m", "ma", "mail", "mailbox", "boxmail", "amliobx", "martha", "marhta", "もしもし",
+    ];
+
+    fn check_triangle<S: ?Sized, M: Distance<S>>(metric: &M, items: &[&S]) {
+        for &x1 in items {
+            for &x2 in items {
+                assert_eq!(metric.distance(x1, x2), metric.distance(x2, x1));
+                assert_eq!(metric.distance(x1, x2) == 0, metric.distance(x2, x1) == 0);
+                for &x3 in items {
+                    let d12 = metric.distance(x1, x2);
+                    let d23 = metric.distance(x2, x3);
+                    let d13 = metric.distance(x1, x3);
+                    assert!(d13 <= d12 + d23);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn str_metrics() {
+        check_triangle(&Levenshtein::new(), &WORDS);
+        check_triangle(&DamerauLevenshtein::new(), &WORDS);
+        check_triangle(&Hamming::new(), &WORDS);
+        check_triangle(&Eudex::new(), &WORDS);
+    }
+
+    #[test]
+    fn sync_metrics() {
+        check_triangle(&sync::Levenshtein::new(), &WORDS);
+        check_triangle(&sync::DamerauLevenshtein::new(), &WORDS);
+        let jarwin = sync::JaroWinkler::new();
+        assert_eq!(RelDistance::rel_dist(&jarwin, "martha", "marhta"), jarwin.rel_dist("martha", "marhta"));
+    }
+
+    #[test]
+    fn slice_metrics() {
+        let vecs: Vec<Vec<char>> = WORDS.iter().map(|w| w.chars().collect()).collect();
+        let slices: Vec<&[char]> = vecs.iter().map(|v| &v[..]).collect();
+        let vec_refs: Vec<&Vec<char>> = vecs.iter().collect();
+        check_triangle(&slice::Levenshtein::new(), &slices);
+        check_triangle(&slice::DamerauLevenshtein::new(), &slices);
+        check_triangle(&slice::Hamming::new(), &slices);
+        check_triangle(&slice::Levenshtein::new(), &vec_refs);
+    }
+
+    #[test]
+    fn hamming_lengths() {
+        let hamming = Hamming::new();
+        let sample = [
+            (0, "",        ""),
+            (2, "martha",  "marhta"),
+            (4, "martha",  "marhtaxx"),
+            (3, "martha",  "mar"),
+            (6, "martha",  ""),
+            (5, "もしもし", "march"),
+        ];
+        for &(d, s1, s2) in &sample {
+            assert_eq!(Distance::<str>::distance(&hamming, s1, s2), d);
+            assert_eq!(Distance::<str>::distance(&hamming, s2, s1), d);
+        }
+        let hamming = slice::Hamming::new();
+        assert_eq!(Distance::<[u8]>::distance(&hamming, b"martha", b"mar"), 3);
+    }
+
+    #[test]
+    fn matches_inherent() {
+        let lev = Levenshtein::new();
+        let damlev = DamerauLevenshtein::new();
+        for s1 in &WORDS {
+            for s2 in &WORDS {
+                assert_eq!(Distance::distance(&lev, *s1, *s2), lev.distance(s1, s2));
+                assert_eq!(Distance::distance(&lev, &s1.to_string(), &s2.to_string()), lev.distance(s1, s2));
+                assert_eq!(Distance::distance(&damlev, *s1, *s2), damlev.distance(s1, s2));
+            }
+        }
+    }
+
+    fn check_rel_dist<S: ?Sized, M: RelDistance<S>>(metric: &M, items: &[&S]) {
+        for &x1 in items {
+            assert_eq!(metric.rel_dist(x1, x1), 0.0);
+            for &x2 in items {
+                let d = metric.rel_dist(x1, x2);
+                assert!((0.0 ..= 1.0).contains(&d));
+                assert_eq!(d, metric.rel_dist(x2, x1));
+            }
+        }
+    }
+
+    #[test]
+    fn rel_dist() {
+        check_rel_dist(&Levenshtein::new(), &WORDS);
+        check_rel_dist(&DamerauLevenshtein::new(), &WORDS);
+        check_rel_dist(&Jaro::new(), &WORDS);
+        check_rel_dist(&JaroWinkler::new(), &WORDS);
+        let vecs: Vec<Vec<char>> = WORDS.iter().map(|w| w.chars().collect()).collect();
+        let slices: Vec<&[char]> = vecs.iter().map(|v| &v[..]).collect();
+        check_rel_dist(&slice::Levenshtein::new(), &slices);
+        check_rel_dist(&slice::Jaro::new(), &slices);
+
+        let lev = Levenshtein::new();
+        let closure = |s1: &str, s2: &str| lev.distance(s1, s2) as f64;
+        assert_eq!(closure.rel_dist("mailbox", "mailbx"), 1.0);
+    }
+
+    #[test]
+    fn rel_dist_not_metric() {
+        let lev = Levenshtein::new();
+        let d13 = RelDistance::<str>::rel_dist(&lev, "ab", "ba");
+        let d12 = RelDistance::<str>::rel_dist(&lev, "ab", "aba");
+        let d23 = RelDistance::<str>::rel_dist(&lev, "aba", "ba");
+        assert!(d13 > d12 + d23);
+    }
+}
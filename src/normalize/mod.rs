@@ -0,0 +1,845 @@
+//! Text normalization applied to strings before comparison:
+//! Unicode normalization forms, case folding, transliteration of Cyrillic and Greek,
+//! folding to ASCII and removal of diacritics, punctuation and extra white space.
+//!
+//!
+//! # Usage
+//!
+//! ```rust
+//! use eddie::normalize::{Normalizer, CaseFolding};
+//!
+//! let mut normalizer = Normalizer::new();
+//! normalizer
+//!     .set_case_folding(CaseFolding::Full)
+//!     .set_strip_diacritics(true)
+//!     .set_strip_punctuation(true)
+//!     .set_collapse_whitespace(true);
+//! assert_eq!(normalizer.normalize("  Crème  Brûlée! "), "creme brulee");
+//! ```
+//!
+//! Every metric from `eddie::str` accepts a normalizer,
+//! which is then applied to both compared strings:
+//! ```rust
+//! use eddie::Levenshtein;
+//! use eddie::normalize::{Normalizer, CaseFolding};
+//!
+//! let mut normalizer = Normalizer::new();
+//! normalizer.set_case_folding(CaseFolding::Full);
+//! let lev = Levenshtein::with_normalizer(normalizer);
+//! assert_eq!(lev.distance("STRASSE", "Straße"), 0);
+//! ```
+//!
+//! Transliteration and ASCII folding let names written in different scripts match:
+//! ```rust
+//! use eddie::Levenshtein;
+//! use eddie::normalize::{Normalizer, Transliteration};
+//!
+//! let mut normalizer = Normalizer::new();
+//! normalizer
+//!     .set_transliteration(Transliteration::BgnPcgn)
+//!     .set_ascii_folding(true);
+//! let lev = Levenshtein::with_normalizer(normalizer);
+//! assert_eq!(lev.distance("Щербаков", "Shcherbakov"), 0);
+//! ```
+//!
+//! The tables are generated from the Unicode 14.0.0 character database
+//! by `scripts/normalize_tables.pl`.
+
+mod tables;
+mod translit;
+
+pub use translit::Transliteration;
+
+use core::cell::{Ref, RefCell};
+use core::mem;
+use core::ops::Deref;
+use alloc::string::String;
+use alloc::vec::Vec;
+use tables::*;
+use translit::transliterate;
+use crate::utils::fold_latin;
+
+
+/// Unicode normalization form, see [UAX #15][1].
+///
+/// [1]: https://www.unicode.org/reports/tr15/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Form {
+    /// Canonical decomposition followed by canonical composition.
+    Nfc,
+    /// Canonical decomposition.
+    Nfd,
+    /// Compatibility decomposition followed by canonical composition.
+    Nfkc,
+    /// Compatibility decomposition.
+    Nfkd,
+}
+
+
+/// Case folding mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseFolding {
+    /// Keeps the letter case.
+    Off,
+    /// Full Unicode case folding, e.g. "ß" becomes "ss".
+    Full,
+    /// Full Unicode case folding with the Turkic mappings,
+    /// where "I" becomes dotless "ı" and "İ" becomes "i".
+    Turkic,
+}
+
+
+const HANGUL_FIRST: u32 = 0xAC00;
+const HANGUL_LAST: u32 = 0xD7A3;
+const HANGUL_L_FIRST: u32 = 0x1100;
+const HANGUL_V_FIRST: u32 = 0x1161;
+const HANGUL_T_FIRST: u32 = 0x11A7;
+const HANGUL_L_COUNT: u32 = 19;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_COUNT: u32 = 28;
+
+
+/// # Text normalizer.
+///
+/// Converts strings to a Unicode normalization form (NFC by default)
+/// and optionally folds case, strips diacritics and punctuation,
+/// and collapses runs of white space into single spaces.
+///
+/// The normalizer holds only its settings, so it is `Copy` and `Sync`.
+/// Metrics from `eddie::str` normalize into internal buffers reused between calls.
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::normalize::{Normalizer, Form};
+///
+/// let mut normalizer = Normalizer::new();
+/// assert_eq!(normalizer.normalize("cafe\u{301}"), "café");
+///
+/// normalizer.set_form(Form::Nfkc);
+/// assert_eq!(normalizer.normalize("ﬁle №1"), "file No1");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Normalizer {
+    form: Form,
+    case_folding: CaseFolding,
+    transliteration: Transliteration,
+    ascii_folding: bool,
+    strip_diacritics: bool,
+    strip_punctuation: bool,
+    collapse_whitespace: bool,
+}
+
+
+impl Normalizer {
+    /// Creates a new normalizer converting strings to NFC,
+    /// with the other transformations disabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::normalize::Normalizer;
+    ///
+    /// let normalizer = Normalizer::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            form: Form::Nfc,
+            case_folding: CaseFolding::Off,
+            transliteration: Transliteration::Off,
+            ascii_folding: false,
+            strip_diacritics: false,
+            strip_punctuation: false,
+            collapse_whitespace: false,
+        }
+    }
+
+    /// Sets the Unicode normalization form, NFC by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::normalize::{Normalizer, Form};
+    /// let mut normalizer = Normalizer::new();
+    /// normalizer.set_form(Form::Nfd);
+    /// assert_eq!(normalizer.normalize("café"), "cafe\u{301}");
+    /// ```
+    pub fn set_form(&mut self, form: Form) -> &mut Self {
+        self.form = form;
+        self
+    }
+
+    /// Sets the case folding mode, disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::normalize::{Normalizer, CaseFolding};
+    /// let mut normalizer = Normalizer::new();
+    /// normalizer.set_case_folding(CaseFolding::Full);
+    /// assert_eq!(normalizer.normalize("Straße ΣΟΦΊΑ"), "strasse σοφία");
+    ///
+    /// normalizer.set_case_folding(CaseFolding::Turkic);
+    /// assert_eq!(normalizer.normalize("DİYARBAKIR"), "diyarbakır");
+    /// ```
+    pub fn set_case_folding(&mut self, case_folding: CaseFolding) -> &mut Self {
+        self.case_folding = case_folding;
+        self
+    }
+
+    /// Sets the transliteration of Cyrillic and Greek into Latin, disabled by default.
+    /// Letters of the other scripts are kept as they are.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::normalize::{Normalizer, Transliteration};
+    /// let mut normalizer = Normalizer::new();
+    /// normalizer.set_transliteration(Transliteration::BgnPcgn);
+    /// assert_eq!(normalizer.normalize("Щербаков"), "Shcherbakov");
+    /// assert_eq!(normalizer.normalize("Θεσσαλονίκη"), "Thessaloníki");
+    ///
+    /// normalizer.set_transliteration(Transliteration::Iso);
+    /// assert_eq!(normalizer.normalize("Щербаков"), "Ŝerbakov");
+    /// ```
+    pub fn set_transliteration(&mut self, transliteration: Transliteration) -> &mut Self {
+        self.transliteration = transliteration;
+        self
+    }
+
+    /// Enables or disables folding of Latin letters to ASCII, disabled by default.
+    /// Removes diacritics from Latin letters and replaces letters such as "ø", "ł" or "æ"
+    /// with their ASCII counterparts, while the other scripts are kept as they are.
+    /// Typographic quotes and the modifier primes of ISO 9, e.g. "’" or "ʺ",
+    /// become ASCII quotes.
+    ///
+    /// Precisely, the folded characters are the Latin-1 Supplement and Latin Extended-A letters
+    /// (U+00C0 to U+017F), combining marks following an ASCII letter, the quotes above,
+    /// and characters whose compatibility decomposition is made of these and ASCII characters,
+    /// such as the ligature "ﬁ", the digraph "ǅ", fullwidth letters or superscript digits.
+    /// Spacing accents such as "´" and Latin letters without a decomposition,
+    /// such as "ƒ" or "ɨ", are kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::normalize::Normalizer;
+    /// let mut normalizer = Normalizer::new();
+    /// normalizer.set_ascii_folding(true);
+    /// assert_eq!(normalizer.normalize("Ærøskøbing, Łódź"), "AEroskobing, Lodz");
+    /// assert_eq!(normalizer.normalize("ǅurđevac, ﬁord"), "Dzurdevac, fiord");
+    /// ```
+    pub fn set_ascii_folding(&mut self, enabled: bool) -> &mut Self {
+        self.ascii_folding = enabled;
+        self
+    }
+
+    /// Enables or disables removal of diacritics, disabled by default.
+    /// Removes all combining marks after the canonical decomposition,
+    /// while letters without decomposition such as "ø" or "ł" are kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::normalize::Normalizer;
+    /// let mut normalizer = Normalizer::new();
+    /// normalizer.set_strip_diacritics(true);
+    /// assert_eq!(normalizer.normalize("Ångström, Dvořák"), "Angstrom, Dvorak");
+    /// ```
+    pub fn set_strip_diacritics(&mut self, enabled: bool) -> &mut Self {
+        self.strip_diacritics = enabled;
+        self
+    }
+
+    /// Enables or disables removal of punctuation, disabled by default.
+    /// Removes characters of the Unicode punctuation categories,
+    /// while symbols such as "+" or "$" are kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::normalize::Normalizer;
+    /// let mut normalizer = Normalizer::new();
+    /// normalizer.set_strip_punctuation(true);
+    /// assert_eq!(normalizer.normalize("«O'Brien», $5!"), "OBrien $5");
+    /// ```
+    pub fn set_strip_punctuation(&mut self, enabled: bool) -> &mut Self {
+        self.strip_punctuation = enabled;
+        self
+    }
+
+    /// Enables or disables collapsing of white space, disabled by default.
+    /// Replaces every run of white space characters with a single space
+    /// and trims white space at both ends.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::normalize::Normalizer;
+    /// let mut normalizer = Normalizer::new();
+    /// normalizer.set_collapse_whitespace(true);
+    /// assert_eq!(normalizer.normalize(" martha \t\u{A0}marhta\n"), "martha marhta");
+    /// ```
+    pub fn set_collapse_whitespace(&mut self, enabled: bool) -> &mut Self {
+        self.collapse_whitespace = enabled;
+        self
+    }
+
+    /// Returns the normalized text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::normalize::Normalizer;
+    /// let normalizer = Normalizer::new();
+    /// assert_eq!(normalizer.normalize("A\u{30A}"), "Å");
+    /// ```
+    pub fn normalize(&self, text: &str) -> String {
+        let mut normalized = String::with_capacity(text.len());
+        self.normalize_into(text, &mut normalized);
+        normalized
+    }
+
+    /// Replaces the contents of `out` with the normalized text,
+    /// reusing its allocation. Pure ASCII text is normalized without intermediate buffers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::normalize::Normalizer;
+    /// let normalizer = Normalizer::new();
+    /// let mut out = String::new();
+    /// for text in &["A\u{30A}", "a\u{30A}"] {
+    ///     normalizer.normalize_into(text, &mut out);
+    ///     assert_eq!(out.chars().count(), 1);
+    /// }
+    /// ```
+    pub fn normalize_into(&self, text: &str, out: &mut String) {
+        self.normalize_with(text, &mut Vec::new(), &mut Vec::new(), out);
+    }
+
+    /// Normalizes the text using the given scratch buffers.
+    fn normalize_with(&self, text: &str, chars: &mut Vec<char>, folded: &mut Vec<char>, out: &mut String) {
+        out.clear();
+        if text.is_ascii() && self.case_folding != CaseFolding::Turkic {
+            let fold = self.case_folding != CaseFolding::Off;
+            self.write(text.chars().map(|ch| if fold { ch.to_ascii_lowercase() } else { ch }), out);
+        } else {
+            let compatibility = self.compatibility();
+            chars.clear();
+            if self.transliteration == Transliteration::Off {
+                for ch in text.chars() {
+                    decompose(ch, compatibility, chars);
+                }
+            } else {
+                // Transliteration reads composed letters, e.g. "й" rather than "и" with a breve.
+                for ch in text.chars() {
+                    decompose(ch, false, chars);
+                }
+                reorder(chars);
+                compose(chars);
+                transliterate(self.transliteration, chars, folded);
+                chars.clear();
+                for &ch in folded.iter() {
+                    decompose(ch, compatibility, chars);
+                }
+            }
+            reorder(chars);
+            if self.case_folding != CaseFolding::Off {
+                self.fold(chars, folded);
+                reorder(chars);
+            }
+            if self.ascii_folding {
+                fold_ascii(chars, folded);
+            }
+            if self.strip_diacritics {
+                chars.retain(|&ch| combining_class(ch) == 0);
+            }
+            if let Form::Nfc | Form::Nfkc = self.form {
+                compose(chars);
+            }
+            self.write(chars.iter().copied(), out);
+        }
+    }
+
+    /// Whether the normalization form uses compatibility decompositions.
+    fn compatibility(&self) -> bool {
+        match self.form {
+            Form::Nfkc | Form::Nfkd => true,
+            Form::Nfc | Form::Nfd => false,
+        }
+    }
+
+    /// Folds case of the decomposed characters, decomposing the results again.
+    fn fold(&self, chars: &mut Vec<char>, folded: &mut Vec<char>) {
+        folded.clear();
+        let turkic = self.case_folding == CaseFolding::Turkic;
+        // Position of the last dotless "ı" folded from "I", which becomes "i" if followed by a dot above.
+        let mut dotless = None;
+
+        for &ch in chars.iter() {
+            if turkic {
+                if ch == 'I' {
+                    dotless = Some(folded.len());
+                    folded.push('ı');
+                    continue;
+                }
+                if ch == '\u{307}' {
+                    if let Some(i) = dotless.take() {
+                        folded[i] = 'i';
+                        continue;
+                    }
+                }
+                if let 0 | 230 = combining_class(ch) {
+                    dotless = None;
+                }
+            }
+            if ch.is_ascii() {
+                folded.push(ch.to_ascii_lowercase());
+                continue;
+            }
+            match lookup(CASE_FOLDING, FOLDED, ch as u32) {
+                Some(mapped) => for &ch in mapped { decompose(ch, self.compatibility(), folded); },
+                None => folded.push(ch),
+            }
+        }
+
+        mem::swap(chars, folded);
+    }
+
+    /// Pushes the characters to the output, removing punctuation and collapsing white space.
+    fn write(&self, chars: impl Iterator<Item=char>, out: &mut String) {
+        let mut space = false;
+        for ch in chars {
+            if self.strip_punctuation && is_punctuation(ch) {
+                continue;
+            }
+            if self.collapse_whitespace && is_white_space(ch) {
+                space = !out.is_empty();
+                continue;
+            }
+            if space {
+                out.push(' ');
+                space = false;
+            }
+            out.push(ch);
+        }
+    }
+}
+
+
+impl Default for Normalizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+/// A string either as it is or normalized into the buffer of a normalizer.
+pub(crate) enum Normalized<'a> {
+    Original(&'a str),
+    Buffered(Ref<'a, String>),
+}
+
+
+impl<'a> Deref for Normalized<'a> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match self {
+            Normalized::Original(s) => s,
+            Normalized::Buffered(s) => s,
+        }
+    }
+}
+
+
+/// Buffers for normalizing pairs of strings, reused between calls.
+pub(crate) struct NormalizeBuffer {
+    chars: RefCell<Vec<char>>,
+    folded: RefCell<Vec<char>>,
+    text1: RefCell<String>,
+    text2: RefCell<String>,
+}
+
+
+impl NormalizeBuffer {
+    pub const fn new() -> Self {
+        Self {
+            chars: RefCell::new(Vec::new()),
+            folded: RefCell::new(Vec::new()),
+            text1: RefCell::new(String::new()),
+            text2: RefCell::new(String::new()),
+        }
+    }
+
+    /// Normalizes both strings if there is a normalizer, otherwise keeps them as they are.
+    pub fn store<'a>(
+        &'a self,
+        normalizer: &Option<Normalizer>,
+        str1: &'a str,
+        str2: &'a str,
+    ) -> (Normalized<'a>, Normalized<'a>) {
+        let normalizer = match normalizer {
+            Some(normalizer) => normalizer,
+            None => return (Normalized::Original(str1), Normalized::Original(str2)),
+        };
+        let chars = &mut *self.chars.borrow_mut();
+        let folded = &mut *self.folded.borrow_mut();
+        normalizer.normalize_with(str1, chars, folded, &mut self.text1.borrow_mut());
+        normalizer.normalize_with(str2, chars, folded, &mut self.text2.borrow_mut());
+        (Normalized::Buffered(self.text1.borrow()), Normalized::Buffered(self.text2.borrow()))
+    }
+}
+
+
+/// Pushes the full canonical or compatibility decomposition of the character.
+fn decompose(ch: char, compatibility: bool, out: &mut Vec<char>) {
+    let code = ch as u32;
+    if code < 0xA0 {
+        out.push(ch);
+        return;
+    }
+    if (HANGUL_FIRST ..= HANGUL_LAST).contains(&code) {
+        let index = code - HANGUL_FIRST;
+        let l = HANGUL_L_FIRST + index / (HANGUL_V_COUNT * HANGUL_T_COUNT);
+        let v = HANGUL_V_FIRST + index % (HANGUL_V_COUNT * HANGUL_T_COUNT) / HANGUL_T_COUNT;
+        let t = HANGUL_T_FIRST + index % HANGUL_T_COUNT;
+        out.extend([l, v, t].iter().filter(|&&jamo| jamo != HANGUL_T_FIRST).filter_map(|&jamo| char::from_u32(jamo)));
+        return;
+    }
+    let decomposed = if compatibility { lookup(COMPATIBILITY, DECOMPOSED, code) } else { None };
+    match decomposed.or_else(|| lookup(CANONICAL, DECOMPOSED, code)) {
+        Some(decomposed) => out.extend_from_slice(decomposed),
+        None => out.push(ch),
+    }
+}
+
+
+/// Replaces Latin letters with their ASCII counterparts and removes diacritics
+/// from ASCII letters in the decomposed characters.
+fn fold_ascii(chars: &mut Vec<char>, folded: &mut Vec<char>) {
+    folded.clear();
+    let mut ascii_base = false;
+    for &ch in chars.iter() {
+        if ch.is_ascii() {
+            folded.push(ch);
+            ascii_base = true;
+        } else if combining_class(ch) != 0 {
+            if !ascii_base { folded.push(ch); }
+        } else if let Some(ascii) = fold_latin(ch) {
+            folded.extend(ascii.chars());
+            ascii_base = true;
+        } else if let Some(decomposed) = lookup(COMPATIBILITY, DECOMPOSED, ch as u32).filter(|d| folds_to_ascii(d)) {
+            // Ligatures, digraphs, fullwidth and superscript forms, e.g. "ﬁ" or "ǅ".
+            for &ch in decomposed {
+                if ch.is_ascii() {
+                    folded.push(ch);
+                } else if let Some(ascii) = fold_latin(ch) {
+                    folded.extend(ascii.chars());
+                }
+            }
+            ascii_base = true;
+        } else {
+            // Soft and hard signs of ISO 9 and BGN/PCGN, and the other typographic quotes.
+            let quote = match ch {
+                'ʹ' | '‘' | '’' => Some('\''),
+                'ʺ' | '“' | '”' => Some('"'),
+                _ => None,
+            };
+            folded.push(quote.unwrap_or(ch));
+            ascii_base = quote.is_some();
+        }
+    }
+    mem::swap(chars, folded);
+}
+
+
+/// Whether the compatibility decomposition consists of ASCII characters, Latin letters
+/// with an ASCII replacement and combining marks, other than a spacing accent such as "´".
+fn folds_to_ascii(decomposed: &[char]) -> bool {
+    decomposed[0] != ' ' && decomposed.iter()
+        .all(|&ch| ch.is_ascii() || fold_latin(ch).is_some() || combining_class(ch) != 0)
+}
+
+
+fn lookup(table: &[(u32, u16, u8)], mapped: &'static [char], code: u32) -> Option<&'static [char]> {
+    let i = table.binary_search_by_key(&code, |&(key, _, _)| key).ok()?;
+    let (_, offset, len) = table[i];
+    Some(&mapped[offset as usize .. offset as usize + len as usize])
+}
+
+
+fn in_ranges(table: &[(u32, u32)], code: u32) -> bool {
+    let i = table.partition_point(|&(_, hi)| hi < code);
+    matches!(table.get(i), Some(&(lo, _)) if lo <= code)
+}
+
+
+fn combining_class(ch: char) -> u8 {
+    let code = ch as u32;
+    if code < 0x300 { return 0; }
+    let i = COMBINING_CLASSES.partition_point(|&(_, hi, _)| hi < code);
+    match COMBINING_CLASSES.get(i) {
+        Some(&(lo, _, class)) if lo <= code => class,
+        _ => 0,
+    }
+}
+
+
+fn is_punctuation(ch: char) -> bool {
+    !ch.is_ascii_alphanumeric() && in_ranges(PUNCTUATION, ch as u32)
+}
+
+
+fn is_white_space(ch: char) -> bool {
+    match ch {
+        ' ' | '\t' | '\n' | '\r' => true,
+        _ if ch.is_ascii_alphanumeric() => false,
+        _ => in_ranges(WHITE_SPACE, ch as u32),
+    }
+}
+
+
+/// Sorts every run of combining marks by their combining classes.
+fn reorder(chars: &mut [char]) {
+    let mut start = 0;
+    while start < chars.len() {
+        if combining_class(chars[start]) == 0 {
+            start += 1;
+            continue;
+        }
+        let len = chars[start ..].iter().take_while(|&&ch| combining_class(ch) != 0).count();
+        chars[start .. start + len].sort_by_key(|&ch| combining_class(ch));
+        start += len;
+    }
+}
+
+
+/// Canonical composition of the decomposed and reordered characters, in place.
+fn compose(chars: &mut Vec<char>) {
+    let mut starter: Option<usize> = None;
+    let mut last_class = 0;
+    let mut len = 0;
+
+    for i in 0 .. chars.len() {
+        let ch = chars[i];
+        let class = combining_class(ch);
+        if let Some(s) = starter {
+            let blocked = len > s + 1 && last_class >= class;
+            if !blocked {
+                if let Some(composite) = compose_pair(chars[s], ch) {
+                    chars[s] = composite;
+                    continue;
+                }
+            }
+        }
+        if class == 0 {
+            starter = Some(len);
+        }
+        last_class = class;
+        chars[len] = ch;
+        len += 1;
+    }
+
+    chars.truncate(len);
+}
+
+
+fn compose_pair(first: char, second: char) -> Option<char> {
+    let (code1, code2) = (first as u32, second as u32);
+    if (HANGUL_L_FIRST .. HANGUL_L_FIRST + HANGUL_L_COUNT).contains(&code1)
+        && (HANGUL_V_FIRST .. HANGUL_V_FIRST + HANGUL_V_COUNT).contains(&code2) {
+        let index = (code1 - HANGUL_L_FIRST) * HANGUL_V_COUNT + code2 - HANGUL_V_FIRST;
+        return char::from_u32(HANGUL_FIRST + index * HANGUL_T_COUNT);
+    }
+    if (HANGUL_FIRST ..= HANGUL_LAST).contains(&code1)
+        && (HANGUL_T_FIRST + 1 .. HANGUL_T_FIRST + HANGUL_T_COUNT).contains(&code2) {
+        // Only LV syllables take a trailing consonant.
+        return match (code1 - HANGUL_FIRST) % HANGUL_T_COUNT {
+            0 => char::from_u32(code1 + code2 - HANGUL_T_FIRST),
+            _ => None,
+        };
+    }
+    let i = COMPOSITIONS.binary_search_by_key(&(code1, code2), |&(c1, c2, _)| (c1, c2)).ok()?;
+    char::from_u32(COMPOSITIONS[i].2)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{Normalizer, NormalizeBuffer, Form, CaseFolding, Transliteration, combining_class, is_punctuation, is_white_space};
+    use super::tables::*;
+
+    fn normalizer(form: Form) -> Normalizer {
+        let mut normalizer = Normalizer::new();
+        normalizer.set_form(form);
+        normalizer
+    }
+
+    #[test]
+    fn tables_sorted() {
+        for pair in COMBINING_CLASSES.windows(2) {
+            assert!(pair[0].0 <= pair[0].1 && pair[0].1 < pair[1].0);
+        }
+        for table in &[CANONICAL, COMPATIBILITY, CASE_FOLDING] {
+            for pair in table.windows(2) {
+                assert!(pair[0].0 < pair[1].0);
+            }
+        }
+        for pair in COMPOSITIONS.windows(2) {
+            assert!((pair[0].0, pair[0].1) < (pair[1].0, pair[1].1));
+        }
+        for table in &[PUNCTUATION, WHITE_SPACE] {
+            for pair in table.windows(2) {
+                assert!(pair[0].0 <= pair[0].1 && pair[0].1 < pair[1].0);
+            }
+        }
+    }
+
+    #[test]
+    fn properties() {
+        assert_eq!(combining_class('a'), 0);
+        assert_eq!(combining_class('\u{301}'), 230);
+        assert_eq!(combining_class('\u{327}'), 202);
+        assert!(is_punctuation('!'));
+        assert!(is_punctuation('«'));
+        assert!(is_punctuation('\u{2014}'));
+        assert!(!is_punctuation('$'));
+        assert!(!is_punctuation('a'));
+        assert!(is_white_space('\u{A0}'));
+        assert!(is_white_space('\u{3000}'));
+        assert!(!is_white_space('\u{200B}'));
+    }
+
+    #[test]
+    fn forms() {
+        let sample = [
+            // source, NFC, NFD, NFKC, NFKD
+            ["café", "café", "cafe\u{301}", "café", "cafe\u{301}"],
+            ["Å", "Å", "A\u{30A}", "Å", "A\u{30A}"],
+            ["\u{212B}", "Å", "A\u{30A}", "Å", "A\u{30A}"],
+            ["ﬁ", "ﬁ", "ﬁ", "fi", "fi"],
+            ["ẛ\u{323}", "ẛ\u{323}", "ſ\u{323}\u{307}", "ṩ", "s\u{323}\u{307}"],
+            ["q\u{307}\u{323}", "q\u{323}\u{307}", "q\u{323}\u{307}", "q\u{323}\u{307}", "q\u{323}\u{307}"],
+            ["\u{1E0B}\u{323}", "\u{1E0D}\u{307}", "d\u{323}\u{307}", "\u{1E0D}\u{307}", "d\u{323}\u{307}"],
+            ["한국어", "한국어", "\u{1112}\u{1161}\u{11AB}\u{1100}\u{116E}\u{11A8}\u{110B}\u{1165}", "한국어", "\u{1112}\u{1161}\u{11AB}\u{1100}\u{116E}\u{11A8}\u{110B}\u{1165}"],
+            ["\u{1100}\u{1161}\u{11A8}", "각", "\u{1100}\u{1161}\u{11A8}", "각", "\u{1100}\u{1161}\u{11A8}"],
+            ["①㎏", "①㎏", "①㎏", "1kg", "1kg"],
+            ["a\u{A0}b", "a\u{A0}b", "a\u{A0}b", "a b", "a b"],
+            ["\u{0958}", "\u{915}\u{93C}", "\u{915}\u{93C}", "\u{915}\u{93C}", "\u{915}\u{93C}"],
+            ["mailbox", "mailbox", "mailbox", "mailbox", "mailbox"],
+            ["", "", "", "", ""],
+        ];
+        let forms = [Form::Nfc, Form::Nfd, Form::Nfkc, Form::Nfkd];
+        for row in &sample {
+            for (form, expected) in forms.iter().zip(&row[1 ..]) {
+                assert_eq!(normalizer(*form).normalize(row[0]), *expected, "{:?} of {:?}", form, row[0]);
+            }
+        }
+    }
+
+    #[test]
+    fn case_folding() {
+        let mut full = Normalizer::new();
+        full.set_case_folding(CaseFolding::Full);
+        let mut turkic = Normalizer::new();
+        turkic.set_case_folding(CaseFolding::Turkic);
+        let sample = [
+            // source, full, turkic
+            ["MailBox", "mailbox", "mailbox"],
+            ["Straße", "strasse", "strasse"],
+            ["ΣΊΣΥΦΟΣ", "σίσυφοσ", "σίσυφοσ"],
+            ["Ǆ", "ǆ", "ǆ"],
+            ["ǰ", "ǰ", "ǰ"],
+            ["ﬃ", "ffi", "ffi"],
+            ["\u{212A}", "k", "k"],
+            ["İstanbul", "i\u{307}stanbul", "istanbul"],
+            ["I\u{307}zmir", "i\u{307}zmir", "izmir"],
+            ["ISPARTA", "isparta", "ısparta"],
+            ["I\u{301}\u{307}", "í\u{307}", "ı\u{301}\u{307}"],
+            ["I\u{328}\u{307}", "į\u{307}", "į"],
+        ];
+        for row in &sample {
+            assert_eq!(full.normalize(row[0]), row[1]);
+            assert_eq!(turkic.normalize(row[0]), row[2]);
+        }
+    }
+
+    #[test]
+    fn cleanup() {
+        let mut normalizer = Normalizer::new();
+        normalizer
+            .set_strip_diacritics(true)
+            .set_strip_punctuation(true)
+            .set_collapse_whitespace(true);
+        let sample = [
+            ("", ""),
+            ("   ", ""),
+            (" , ", ""),
+            ("mailbox", "mailbox"),
+            ("  mail\t\u{3000}box  ", "mail box"),
+            ("mail - box", "mail box"),
+            ("«Crème brûlée», s'il vous plaît!", "Creme brulee sil vous plait"),
+            ("Łódź — Kraków", "Łodz Krakow"),
+            ("Ελληνικά: ά, ῷ", "Ελληνικα α ω"),
+            ("a+b=c $5", "a+b=c $5"),
+        ];
+        for &(source, expected) in &sample {
+            assert_eq!(normalizer.normalize(source), expected);
+        }
+    }
+
+    #[test]
+    fn ascii_folding() {
+        let mut normalizer = Normalizer::new();
+        normalizer.set_ascii_folding(true);
+        let sample = [
+            ("", ""),
+            ("mailbox", "mailbox"),
+            ("Ærøskøbing", "AEroskobing"),
+            ("Straße", "Strasse"),
+            ("Nguyễn", "Nguyen"),
+            ("cafe\u{301}", "cafe"),
+            ("Łódź", "Lodz"),
+            ("Ŝerbakov, obʺëm", "Serbakov, ob\"em"),
+            ("ǅurđevac, Ǳ, ǆ", "Dzurdevac, DZ, dz"),
+            ("ﬁle, ﬄ, Ĳ", "file, ffl, IJ"),
+            ("ＡＢＣ１, x², ™", "ABC1, x2, TM"),
+            ("´, ½, ƒ", "´, ½, ƒ"),
+            ("Москва", "Москва"),
+            ("नमस्ते", "नमस्ते"),
+        ];
+        for &(source, expected) in &sample {
+            assert_eq!(normalizer.normalize(source), expected);
+        }
+    }
+
+    #[test]
+    fn ascii_folding_bgn() {
+        let mut normalizer = Normalizer::new();
+        normalizer
+            .set_transliteration(Transliteration::BgnPcgn)
+            .set_ascii_folding(true);
+        assert_eq!(normalizer.normalize("Ельцин, объём"), "Yel'tsin, ob\"yem");
+        assert_eq!(normalizer.normalize("‘quoted’ “twice”"), "'quoted' \"twice\"");
+    }
+
+    #[test]
+    fn reuse() {
+        let mut normalizer = Normalizer::new();
+        normalizer.set_case_folding(CaseFolding::Full);
+        let mut out = String::from("previous");
+        normalizer.normalize_into("MAIL", &mut out);
+        assert_eq!(out, "mail");
+        normalizer.normalize_into("Çà", &mut out);
+        assert_eq!(out, "çà");
+
+        let buffer = NormalizeBuffer::new();
+        let (text1, text2) = buffer.store(&Some(normalizer), "Mailbox", "Çà");
+        assert_eq!((&*text1, &*text2), ("mailbox", "çà"));
+        drop((text1, text2));
+        let (text1, text2) = buffer.store(&None, "Mailbox", "Çà");
+        assert_eq!((&*text1, &*text2), ("Mailbox", "Çà"));
+    }
+}
@@ -0,0 +1,3688 @@
+// Generated by scripts/normalize_tables.pl from Unicode 14.0.0, do not edit manually.
+
+
+/// Nonzero canonical combining classes.
+pub const COMBINING_CLASSES: &[(u32, u32, u8)] = &[
+    (0x0300, 0x0314, 230), (0x0315, 0x0315, 232), (0x0316, 0x0319, 220), (0x031A, 0x031A, 232),
+    (0x031B, 0x031B, 216), (0x031C, 0x0320, 220), (0x0321, 0x0322, 202), (0x0323, 0x0326, 220),
+    (0x0327, 0x0328, 202), (0x0329, 0x0333, 220), (0x0334, 0x0338, 1), (0x0339, 0x033C, 220),
+    (0x033D, 0x0344, 230), (0x0345, 0x0345, 240), (0x0346, 0x0346, 230), (0x0347, 0x0349, 220),
+    (0x034A, 0x034C, 230), (0x034D, 0x034E, 220), (0x0350, 0x0352, 230), (0x0353, 0x0356, 220),
+    (0x0357, 0x0357, 230), (0x0358, 0x0358, 232), (0x0359, 0x035A, 220), (0x035B, 0x035B, 230),
+    (0x035C, 0x035C, 233), (0x035D, 0x035E, 234), (0x035F, 0x035F, 233), (0x0360, 0x0361, 234),
+    (0x0362, 0x0362, 233), (0x0363, 0x036F, 230), (0x0483, 0x0487, 230), (0x0591, 0x0591, 220),
+    (0x0592, 0x0595, 230), (0x0596, 0x0596, 220), (0x0597, 0x0599, 230), (0x059A, 0x059A, 222),
+    (0x059B, 0x059B, 220), (0x059C, 0x05A1, 230), (0x05A2, 0x05A7, 220), (0x05A8, 0x05A9, 230),
+    (0x05AA, 0x05AA, 220), (0x05AB, 0x05AC, 230), (0x05AD, 0x05AD, 222), (0x05AE, 0x05AE, 228),
+    (0x05AF, 0x05AF, 230), (0x05B0, 0x05B0, 10), (0x05B1, 0x05B1, 11), (0x05B2, 0x05B2, 12),
+    (0x05B3, 0x05B3, 13), (0x05B4, 0x05B4, 14), (0x05B5, 0x05B5, 15), (0x05B6, 0x05B6, 16),
+    (0x05B7, 0x05B7, 17), (0x05B8, 0x05B8, 18), (0x05B9, 0x05BA, 19), (0x05BB, 0x05BB, 20),
+    (0x05BC, 0x05BC, 21), (0x05BD, 0x05BD, 22), (0x05BF, 0x05BF, 23), (0x05C1, 0x05C1, 24),
+    (0x05C2, 0x05C2, 25), (0x05C4, 0x05C4, 230), (0x05C5, 0x05C5, 220), (0x05C7, 0x05C7, 18),
+    (0x0610, 0x0617, 230), (0x0618, 0x0618, 30), (0x0619, 0x0619, 31), (0x061A, 0x061A, 32),
+    (0x064B, 0x064B, 27), (0x064C, 0x064C, 28), (0x064D, 0x064D, 29), (0x064E, 0x064E, 30),
+    (0x064F, 0x064F, 31), (0x0650, 0x0650, 32), (0x0651, 0x0651, 33), (0x0652, 0x0652, 34),
+    (0x0653, 0x0654, 230), (0x0655, 0x0656, 220), (0x0657, 0x065B, 230), (0x065C, 0x065C, 220),
+    (0x065D, 0x065E, 230), (0x065F, 0x065F, 220), (0x0670, 0x0670, 35), (0x06D6, 0x06DC, 230),
+    (0x06DF, 0x06E2, 230), (0x06E3, 0x06E3, 220), (0x06E4, 0x06E4, 230), (0x06E7, 0x06E8, 230),
+    (0x06EA, 0x06EA, 220), (0x06EB, 0x06EC, 230), (0x06ED, 0x06ED, 220), (0x0711, 0x0711, 36),
+    (0x0730, 0x0730, 230), (0x0731, 0x0731, 220), (0x0732, 0x0733, 230), (0x0734, 0x0734, 220),
+    (0x0735, 0x0736, 230), (0x0737, 0x0739, 220), (0x073A, 0x073A, 230), (0x073B, 0x073C, 220),
+    (0x073D, 0x073D, 230), (0x073E, 0x073E, 220), (0x073F, 0x0741, 230), (0x0742, 0x0742, 220),
+    (0x0743, 0x0743, 230), (0x0744, 0x0744, 220), (0x0745, 0x0745, 230), (0x0746, 0x0746, 220),
+    (0x0747, 0x0747, 230), (0x0748, 0x0748, 220), (0x0749, 0x074A, 230), (0x07EB, 0x07F1, 230),
+    (0x07F2, 0x07F2, 220), (0x07F3, 0x07F3, 230), (0x07FD, 0x07FD, 220), (0x0816, 0x0819, 230),
+    (0x081B, 0x0823, 230), (0x0825, 0x0827, 230), (0x0829, 0x082D, 230), (0x0859, 0x085B, 220),
+    (0x0898, 0x0898, 230), (0x0899, 0x089B, 220), (0x089C, 0x089F, 230), (0x08CA, 0x08CE, 230),
+    (0x08CF, 0x08D3, 220), (0x08D4, 0x08E1, 230), (0x08E3, 0x08E3, 220), (0x08E4, 0x08E5, 230),
+    (0x08E6, 0x08E6, 220), (0x08E7, 0x08E8, 230), (0x08E9, 0x08E9, 220), (0x08EA, 0x08EC, 230),
+    (0x08ED, 0x08EF, 220), (0x08F0, 0x08F0, 27), (0x08F1, 0x08F1, 28), (0x08F2, 0x08F2, 29),
+    (0x08F3, 0x08F5, 230), (0x08F6, 0x08F6, 220), (0x08F7, 0x08F8, 230), (0x08F9, 0x08FA, 220),
+    (0x08FB, 0x08FF, 230), (0x093C, 0x093C, 7), (0x094D, 0x094D, 9), (0x0951, 0x0951, 230),
+    (0x0952, 0x0952, 220), (0x0953, 0x0954, 230), (0x09BC, 0x09BC, 7), (0x09CD, 0x09CD, 9),
+    (0x09FE, 0x09FE, 230), (0x0A3C, 0x0A3C, 7), (0x0A4D, 0x0A4D, 9), (0x0ABC, 0x0ABC, 7),
+    (0x0ACD, 0x0ACD, 9), (0x0B3C, 0x0B3C, 7), (0x0B4D, 0x0B4D, 9), (0x0BCD, 0x0BCD, 9),
+    (0x0C3C, 0x0C3C, 7), (0x0C4D, 0x0C4D, 9), (0x0C55, 0x0C55, 84), (0x0C56, 0x0C56, 91),
+    (0x0CBC, 0x0CBC, 7), (0x0CCD, 0x0CCD, 9), (0x0D3B, 0x0D3C, 9), (0x0D4D, 0x0D4D, 9),
+    (0x0DCA, 0x0DCA, 9), (0x0E38, 0x0E39, 103), (0x0E3A, 0x0E3A, 9), (0x0E48, 0x0E4B, 107),
+    (0x0EB8, 0x0EB9, 118), (0x0EBA, 0x0EBA, 9), (0x0EC8, 0x0ECB, 122), (0x0F18, 0x0F19, 220),
+    (0x0F35, 0x0F35, 220), (0x0F37, 0x0F37, 220), (0x0F39, 0x0F39, 216), (0x0F71, 0x0F71, 129),
+    (0x0F72, 0x0F72, 130), (0x0F74, 0x0F74, 132), (0x0F7A, 0x0F7D, 130), (0x0F80, 0x0F80, 130),
+    (0x0F82, 0x0F83, 230), (0x0F84, 0x0F84, 9), (0x0F86, 0x0F87, 230), (0x0FC6, 0x0FC6, 220),
+    (0x1037, 0x1037, 7), (0x1039, 0x103A, 9), (0x108D, 0x108D, 220), (0x135D, 0x135F, 230),
+    (0x1714, 0x1715, 9), (0x1734, 0x1734, 9), (0x17D2, 0x17D2, 9), (0x17DD, 0x17DD, 230),
+    (0x18A9, 0x18A9, 228), (0x1939, 0x1939, 222), (0x193A, 0x193A, 230), (0x193B, 0x193B, 220),
+    (0x1A17, 0x1A17, 230), (0x1A18, 0x1A18, 220), (0x1A60, 0x1A60, 9), (0x1A75, 0x1A7C, 230),
+    (0x1A7F, 0x1A7F, 220), (0x1AB0, 0x1AB4, 230), (0x1AB5, 0x1ABA, 220), (0x1ABB, 0x1ABC, 230),
+    (0x1ABD, 0x1ABD, 220), (0x1ABF, 0x1AC0, 220), (0x1AC1, 0x1AC2, 230), (0x1AC3, 0x1AC4, 220),
+    (0x1AC5, 0x1AC9, 230), (0x1ACA, 0x1ACA, 220), (0x1ACB, 0x1ACE, 230), (0x1B34, 0x1B34, 7),
+    (0x1B44, 0x1B44, 9), (0x1B6B, 0x1B6B, 230), (0x1B6C, 0x1B6C, 220), (0x1B6D, 0x1B73, 230),
+    (0x1BAA, 0x1BAB, 9), (0x1BE6, 0x1BE6, 7), (0x1BF2, 0x1BF3, 9), (0x1C37, 0x1C37, 7),
+    (0x1CD0, 0x1CD2, 230), (0x1CD4, 0x1CD4, 1), (0x1CD5, 0x1CD9, 220), (0x1CDA, 0x1CDB, 230),
+    (0x1CDC, 0x1CDF, 220), (0x1CE0, 0x1CE0, 230), (0x1CE2, 0x1CE8, 1), (0x1CED, 0x1CED, 220),
+    (0x1CF4, 0x1CF4, 230), (0x1CF8, 0x1CF9, 230), (0x1DC0, 0x1DC1, 230), (0x1DC2, 0x1DC2, 220),
+    (0x1DC3, 0x1DC9, 230), (0x1DCA, 0x1DCA, 220), (0x1DCB, 0x1DCC, 230), (0x1DCD, 0x1DCD, 234),
+    (0x1DCE, 0x1DCE, 214), (0x1DCF, 0x1DCF, 220), (0x1DD0, 0x1DD0, 202), (0x1DD1, 0x1DF5, 230),
+    (0x1DF6, 0x1DF6, 232), (0x1DF7, 0x1DF8, 228), (0x1DF9, 0x1DF9, 220), (0x1DFA, 0x1DFA, 218),
+    (0x1DFB, 0x1DFB, 230), (0x1DFC, 0x1DFC, 233), (0x1DFD, 0x1DFD, 220), (0x1DFE, 0x1DFE, 230),
+    (0x1DFF, 0x1DFF, 220), (0x20D0, 0x20D1, 230), (0x20D2, 0x20D3, 1), (0x20D4, 0x20D7, 230),
+    (0x20D8, 0x20DA, 1), (0x20DB, 0x20DC, 230), (0x20E1, 0x20E1, 230), (0x20E5, 0x20E6, 1),
+    (0x20E7, 0x20E7, 230), (0x20E8, 0x20E8, 220), (0x20E9, 0x20E9, 230), (0x20EA, 0x20EB, 1),
+    (0x20EC, 0x20EF, 220), (0x20F0, 0x20F0, 230), (0x2CEF, 0x2CF1, 230), (0x2D7F, 0x2D7F, 9),
+    (0x2DE0, 0x2DFF, 230), (0x302A, 0x302A, 218), (0x302B, 0x302B, 228), (0x302C, 0x302C, 232),
+    (0x302D, 0x302D, 222), (0x302E, 0x302F, 224), (0x3099, 0x309A, 8), (0xA66F, 0xA66F, 230),
+    (0xA674, 0xA67D, 230), (0xA69E, 0xA69F, 230), (0xA6F0, 0xA6F1, 230), (0xA806, 0xA806, 9),
+    (0xA82C, 0xA82C, 9), (0xA8C4, 0xA8C4, 9), (0xA8E0, 0xA8F1, 230), (0xA92B, 0xA92D, 220),
+    (0xA953, 0xA953, 9), (0xA9B3, 0xA9B3, 7), (0xA9C0, 0xA9C0, 9), (0xAAB0, 0xAAB0, 230),
+    (0xAAB2, 0xAAB3, 230), (0xAAB4, 0xAAB4, 220), (0xAAB7, 0xAAB8, 230), (0xAABE, 0xAABF, 230),
+    (0xAAC1, 0xAAC1, 230), (0xAAF6, 0xAAF6, 9), (0xABED, 0xABED, 9), (0xFB1E, 0xFB1E, 26),
+    (0xFE20, 0xFE26, 230), (0xFE27, 0xFE2D, 220), (0xFE2E, 0xFE2F, 230), (0x101FD, 0x101FD, 220),
+    (0x102E0, 0x102E0, 220), (0x10376, 0x1037A, 230), (0x10A0D, 0x10A0D, 220), (0x10A0F, 0x10A0F, 230),
+    (0x10A38, 0x10A38, 230), (0x10A39, 0x10A39, 1), (0x10A3A, 0x10A3A, 220), (0x10A3F, 0x10A3F, 9),
+    (0x10AE5, 0x10AE5, 230), (0x10AE6, 0x10AE6, 220), (0x10D24, 0x10D27, 230), (0x10EAB, 0x10EAC, 230),
+    (0x10F46, 0x10F47, 220), (0x10F48, 0x10F4A, 230), (0x10F4B, 0x10F4B, 220), (0x10F4C, 0x10F4C, 230),
+    (0x10F4D, 0x10F50, 220), (0x10F82, 0x10F82, 230), (0x10F83, 0x10F83, 220), (0x10F84, 0x10F84, 230),
+    (0x10F85, 0x10F85, 220), (0x11046, 0x11046, 9), (0x11070, 0x11070, 9), (0x1107F, 0x1107F, 9),
+    (0x110B9, 0x110B9, 9), (0x110BA, 0x110BA, 7), (0x11100, 0x11102, 230), (0x11133, 0x11134, 9),
+    (0x11173, 0x11173, 7), (0x111C0, 0x111C0, 9), (0x111CA, 0x111CA, 7), (0x11235, 0x11235, 9),
+    (0x11236, 0x11236, 7), (0x112E9, 0x112E9, 7), (0x112EA, 0x112EA, 9), (0x1133B, 0x1133C, 7),
+    (0x1134D, 0x1134D, 9), (0x11366, 0x1136C, 230), (0x11370, 0x11374, 230), (0x11442, 0x11442, 9),
+    (0x11446, 0x11446, 7), (0x1145E, 0x1145E, 230), (0x114C2, 0x114C2, 9), (0x114C3, 0x114C3, 7),
+    (0x115BF, 0x115BF, 9), (0x115C0, 0x115C0, 7), (0x1163F, 0x1163F, 9), (0x116B6, 0x116B6, 9),
+    (0x116B7, 0x116B7, 7), (0x1172B, 0x1172B, 9), (0x11839, 0x11839, 9), (0x1183A, 0x1183A, 7),
+    (0x1193D, 0x1193E, 9), (0x11943, 0x11943, 7), (0x119E0, 0x119E0, 9), (0x11A34, 0x11A34, 9),
+    (0x11A47, 0x11A47, 9), (0x11A99, 0x11A99, 9), (0x11C3F, 0x11C3F, 9), (0x11D42, 0x11D42, 7),
+    (0x11D44, 0x11D45, 9), (0x11D97, 0x11D97, 9), (0x16AF0, 0x16AF4, 1), (0x16B30, 0x16B36, 230),
+    (0x16FF0, 0x16FF1, 6), (0x1BC9E, 0x1BC9E, 1), (0x1D165, 0x1D166, 216), (0x1D167, 0x1D169, 1),
+    (0x1D16D, 0x1D16D, 226), (0x1D16E, 0x1D172, 216), (0x1D17B, 0x1D182, 220), (0x1D185, 0x1D189, 230),
+    (0x1D18A, 0x1D18B, 220), (0x1D1AA, 0x1D1AD, 230), (0x1D242, 0x1D244, 230), (0x1E000, 0x1E006, 230),
+    (0x1E008, 0x1E018, 230), (0x1E01B, 0x1E021, 230), (0x1E023, 0x1E024, 230), (0x1E026, 0x1E02A, 230),
+    (0x1E130, 0x1E136, 230), (0x1E2AE, 0x1E2AE, 230), (0x1E2EC, 0x1E2EF, 230), (0x1E8D0, 0x1E8D6, 220),
+    (0x1E944, 0x1E949, 230), (0x1E94A, 0x1E94A, 7),
+];
+
+
+/// Full canonical decompositions, as offsets and lengths in `DECOMPOSED`.
+pub const CANONICAL: &[(u32, u16, u8)] = &[
+    (0x00C0, 0, 2), (0x00C1, 2, 2), (0x00C2, 4, 2), (0x00C3, 6, 2),
+    (0x00C4, 8, 2), (0x00C5, 10, 2), (0x00C7, 12, 2), (0x00C8, 14, 2),
+    (0x00C9, 16, 2), (0x00CA, 18, 2), (0x00CB, 20, 2), (0x00CC, 22, 2),
+    (0x00CD, 24, 2), (0x00CE, 26, 2), (0x00CF, 28, 2), (0x00D1, 30, 2),
+    (0x00D2, 32, 2), (0x00D3, 34, 2), (0x00D4, 36, 2), (0x00D5, 38, 2),
+    (0x00D6, 40, 2), (0x00D9, 42, 2), (0x00DA, 44, 2), (0x00DB, 46, 2),
+    (0x00DC, 48, 2), (0x00DD, 50, 2), (0x00E0, 52, 2), (0x00E1, 54, 2),
+    (0x00E2, 56, 2), (0x00E3, 58, 2), (0x00E4, 60, 2), (0x00E5, 62, 2),
+    (0x00E7, 64, 2), (0x00E8, 66, 2), (0x00E9, 68, 2), (0x00EA, 70, 2),
+    (0x00EB, 72, 2), (0x00EC, 74, 2), (0x00ED, 76, 2), (0x00EE, 78, 2),
+    (0x00EF, 80, 2), (0x00F1, 82, 2), (0x00F2, 84, 2), (0x00F3, 86, 2),
+    (0x00F4, 88, 2), (0x00F5, 90, 2), (0x00F6, 92, 2), (0x00F9, 94, 2),
+    (0x00FA, 96, 2), (0x00FB, 98, 2), (0x00FC, 100, 2), (0x00FD, 102, 2),
+    (0x00FF, 104, 2), (0x0100, 106, 2), (0x0101, 108, 2), (0x0102, 110, 2),
+    (0x0103, 112, 2), (0x0104, 114, 2), (0x0105, 116, 2), (0x0106, 118, 2),
+    (0x0107, 120, 2), (0x0108, 122, 2), (0x0109, 124, 2), (0x010A, 126, 2),
+    (0x010B, 128, 2), (0x010C, 130, 2), (0x010D, 132, 2), (0x010E, 134, 2),
+    (0x010F, 136, 2), (0x0112, 138, 2), (0x0113, 140, 2), (0x0114, 142, 2),
+    (0x0115, 144, 2), (0x0116, 146, 2), (0x0117, 148, 2), (0x0118, 150, 2),
+    (0x0119, 152, 2), (0x011A, 154, 2), (0x011B, 156, 2), (0x011C, 158, 2),
+    (0x011D, 160, 2), (0x011E, 162, 2), (0x011F, 164, 2), (0x0120, 166, 2),
+    (0x0121, 168, 2), (0x0122, 170, 2), (0x0123, 172, 2), (0x0124, 174, 2),
+    (0x0125, 176, 2), (0x0128, 178, 2), (0x0129, 180, 2), (0x012A, 182, 2),
+    (0x012B, 184, 2), (0x012C, 186, 2), (0x012D, 188, 2), (0x012E, 190, 2),
+    (0x012F, 192, 2), (0x0130, 194, 2), (0x0134, 196, 2), (0x0135, 198, 2),
+    (0x0136, 200, 2), (0x0137, 202, 2), (0x0139, 204, 2), (0x013A, 206, 2),
+    (0x013B, 208, 2), (0x013C, 210, 2), (0x013D, 212, 2), (0x013E, 214, 2),
+    (0x0143, 216, 2), (0x0144, 218, 2), (0x0145, 220, 2), (0x0146, 222, 2),
+    (0x0147, 224, 2), (0x0148, 226, 2), (0x014C, 228, 2), (0x014D, 230, 2),
+    (0x014E, 232, 2), (0x014F, 234, 2), (0x0150, 236, 2), (0x0151, 238, 2),
+    (0x0154, 240, 2), (0x0155, 242, 2), (0x0156, 244, 2), (0x0157, 246, 2),
+    (0x0158, 248, 2), (0x0159, 250, 2), (0x015A, 252, 2), (0x015B, 254, 2),
+    (0x015C, 256, 2), (0x015D, 258, 2), (0x015E, 260, 2), (0x015F, 262, 2),
+    (0x0160, 264, 2), (0x0161, 266, 2), (0x0162, 268, 2), (0x0163, 270, 2),
+    (0x0164, 272, 2), (0x0165, 274, 2), (0x0168, 276, 2), (0x0169, 278, 2),
+    (0x016A, 280, 2), (0x016B, 282, 2), (0x016C, 284, 2), (0x016D, 286, 2),
+    (0x016E, 288, 2), (0x016F, 290, 2), (0x0170, 292, 2), (0x0171, 294, 2),
+    (0x0172, 296, 2), (0x0173, 298, 2), (0x0174, 300, 2), (0x0175, 302, 2),
+    (0x0176, 304, 2), (0x0177, 306, 2), (0x0178, 308, 2), (0x0179, 310, 2),
+    (0x017A, 312, 2), (0x017B, 314, 2), (0x017C, 316, 2), (0x017D, 318, 2),
+    (0x017E, 320, 2), (0x01A0, 322, 2), (0x01A1, 324, 2), (0x01AF, 326, 2),
+    (0x01B0, 328, 2), (0x01CD, 330, 2), (0x01CE, 332, 2), (0x01CF, 334, 2),
+    (0x01D0, 336, 2), (0x01D1, 338, 2), (0x01D2, 340, 2), (0x01D3, 342, 2),
+    (0x01D4, 344, 2), (0x01D5, 346, 3), (0x01D6, 349, 3), (0x01D7, 352, 3),
+    (0x01D8, 355, 3), (0x01D9, 358, 3), (0x01DA, 361, 3), (0x01DB, 364, 3),
+    (0x01DC, 367, 3), (0x01DE, 370, 3), (0x01DF, 373, 3), (0x01E0, 376, 3),
+    (0x01E1, 379, 3), (0x01E2, 382, 2), (0x01E3, 384, 2), (0x01E6, 386, 2),
+    (0x01E7, 388, 2), (0x01E8, 390, 2), (0x01E9, 392, 2), (0x01EA, 394, 2),
+    (0x01EB, 396, 2), (0x01EC, 398, 3), (0x01ED, 401, 3), (0x01EE, 404, 2),
+    (0x01EF, 406, 2), (0x01F0, 408, 2), (0x01F4, 410, 2), (0x01F5, 412, 2),
+    (0x01F8, 414, 2), (0x01F9, 416, 2), (0x01FA, 418, 3), (0x01FB, 421, 3),
+    (0x01FC, 424, 2), (0x01FD, 426, 2), (0x01FE, 428, 2), (0x01FF, 430, 2),
+    (0x0200, 432, 2), (0x0201, 434, 2), (0x0202, 436, 2), (0x0203, 438, 2),
+    (0x0204, 440, 2), (0x0205, 442, 2), (0x0206, 444, 2), (0x0207, 446, 2),
+    (0x0208, 448, 2), (0x0209, 450, 2), (0x020A, 452, 2), (0x020B, 454, 2),
+    (0x020C, 456, 2), (0x020D, 458, 2), (0x020E, 460, 2), (0x020F, 462, 2),
+    (0x0210, 464, 2), (0x0211, 466, 2), (0x0212, 468, 2), (0x0213, 470, 2),
+    (0x0214, 472, 2), (0x0215, 474, 2), (0x0216, 476, 2), (0x0217, 478, 2),
+    (0x0218, 480, 2), (0x0219, 482, 2), (0x021A, 484, 2), (0x021B, 486, 2),
+    (0x021E, 488, 2), (0x021F, 490, 2), (0x0226, 492, 2), (0x0227, 494, 2),
+    (0x0228, 496, 2), (0x0229, 498, 2), (0x022A, 500, 3), (0x022B, 503, 3),
+    (0x022C, 506, 3), (0x022D, 509, 3), (0x022E, 512, 2), (0x022F, 514, 2),
+    (0x0230, 516, 3), (0x0231, 519, 3), (0x0232, 522, 2), (0x0233, 524, 2),
+    (0x0340, 526, 1), (0x0341, 527, 1), (0x0343, 528, 1), (0x0344, 529, 2),
+    (0x0374, 531, 1), (0x037E, 532, 1), (0x0385, 533, 2), (0x0386, 535, 2),
+    (0x0387, 537, 1), (0x0388, 538, 2), (0x0389, 540, 2), (0x038A, 542, 2),
+    (0x038C, 544, 2), (0x038E, 546, 2), (0x038F, 548, 2), (0x0390, 550, 3),
+    (0x03AA, 553, 2), (0x03AB, 555, 2), (0x03AC, 557, 2), (0x03AD, 559, 2),
+    (0x03AE, 561, 2), (0x03AF, 563, 2), (0x03B0, 565, 3), (0x03CA, 568, 2),
+    (0x03CB, 570, 2), (0x03CC, 572, 2), (0x03CD, 574, 2), (0x03CE, 576, 2),
+    (0x03D3, 578, 2), (0x03D4, 580, 2), (0x0400, 582, 2), (0x0401, 584, 2),
+    (0x0403, 586, 2), (0x0407, 588, 2), (0x040C, 590, 2), (0x040D, 592, 2),
+    (0x040E, 594, 2), (0x0419, 596, 2), (0x0439, 598, 2), (0x0450, 600, 2),
+    (0x0451, 602, 2), (0x0453, 604, 2), (0x0457, 606, 2), (0x045C, 608, 2),
+    (0x045D, 610, 2), (0x045E, 612, 2), (0x0476, 614, 2), (0x0477, 616, 2),
+    (0x04C1, 618, 2), (0x04C2, 620, 2), (0x04D0, 622, 2), (0x04D1, 624, 2),
+    (0x04D2, 626, 2), (0x04D3, 628, 2), (0x04D6, 630, 2), (0x04D7, 632, 2),
+    (0x04DA, 634, 2), (0x04DB, 636, 2), (0x04DC, 638, 2), (0x04DD, 640, 2),
+    (0x04DE, 642, 2), (0x04DF, 644, 2), (0x04E2, 646, 2), (0x04E3, 648, 2),
+    (0x04E4, 650, 2), (0x04E5, 652, 2), (0x04E6, 654, 2), (0x04E7, 656, 2),
+    (0x04EA, 658, 2), (0x04EB, 660, 2), (0x04EC, 662, 2), (0x04ED, 664, 2),
+    (0x04EE, 666, 2), (0x04EF, 668, 2), (0x04F0, 670, 2), (0x04F1, 672, 2),
+    (0x04F2, 674, 2), (0x04F3, 676, 2), (0x04F4, 678, 2), (0x04F5, 680, 2),
+    (0x04F8, 682, 2), (0x04F9, 684, 2), (0x0622, 686, 2), (0x0623, 688, 2),
+    (0x0624, 690, 2), (0x0625, 692, 2), (0x0626, 694, 2), (0x06C0, 696, 2),
+    (0x06C2, 698, 2), (0x06D3, 700, 2), (0x0929, 702, 2), (0x0931, 704, 2),
+    (0x0934, 706, 2), (0x0958, 708, 2), (0x0959, 710, 2), (0x095A, 712, 2),
+    (0x095B, 714, 2), (0x095C, 716, 2), (0x095D, 718, 2), (0x095E, 720, 2),
+    (0x095F, 722, 2), (0x09CB, 724, 2), (0x09CC, 726, 2), (0x09DC, 728, 2),
+    (0x09DD, 730, 2), (0x09DF, 732, 2), (0x0A33, 734, 2), (0x0A36, 736, 2),
+    (0x0A59, 738, 2), (0x0A5A, 740, 2), (0x0A5B, 742, 2), (0x0A5E, 744, 2),
+    (0x0B48, 746, 2), (0x0B4B, 748, 2), (0x0B4C, 750, 2), (0x0B5C, 752, 2),
+    (0x0B5D, 754, 2), (0x0B94, 756, 2), (0x0BCA, 758, 2), (0x0BCB, 760, 2),
+    (0x0BCC, 762, 2), (0x0C48, 764, 2), (0x0CC0, 766, 2), (0x0CC7, 768, 2),
+    (0x0CC8, 770, 2), (0x0CCA, 772, 2), (0x0CCB, 774, 3), (0x0D4A, 777, 2),
+    (0x0D4B, 779, 2), (0x0D4C, 781, 2), (0x0DDA, 783, 2), (0x0DDC, 785, 2),
+    (0x0DDD, 787, 3), (0x0DDE, 790, 2), (0x0F43, 792, 2), (0x0F4D, 794, 2),
+    (0x0F52, 796, 2), (0x0F57, 798, 2), (0x0F5C, 800, 2), (0x0F69, 802, 2),
+    (0x0F73, 804, 2), (0x0F75, 806, 2), (0x0F76, 808, 2), (0x0F78, 810, 2),
+    (0x0F81, 812, 2), (0x0F93, 814, 2), (0x0F9D, 816, 2), (0x0FA2, 818, 2),
+    (0x0FA7, 820, 2), (0x0FAC, 822, 2), (0x0FB9, 824, 2), (0x1026, 826, 2),
+    (0x1B06, 828, 2), (0x1B08, 830, 2), (0x1B0A, 832, 2), (0x1B0C, 834, 2),
+    (0x1B0E, 836, 2), (0x1B12, 838, 2), (0x1B3B, 840, 2), (0x1B3D, 842, 2),
+    (0x1B40, 844, 2), (0x1B41, 846, 2), (0x1B43, 848, 2), (0x1E00, 850, 2),
+    (0x1E01, 852, 2), (0x1E02, 854, 2), (0x1E03, 856, 2), (0x1E04, 858, 2),
+    (0x1E05, 860, 2), (0x1E06, 862, 2), (0x1E07, 864, 2), (0x1E08, 866, 3),
+    (0x1E09, 869, 3), (0x1E0A, 872, 2), (0x1E0B, 874, 2), (0x1E0C, 876, 2),
+    (0x1E0D, 878, 2), (0x1E0E, 880, 2), (0x1E0F, 882, 2), (0x1E10, 884, 2),
+    (0x1E11, 886, 2), (0x1E12, 888, 2), (0x1E13, 890, 2), (0x1E14, 892, 3),
+    (0x1E15, 895, 3), (0x1E16, 898, 3), (0x1E17, 901, 3), (0x1E18, 904, 2),
+    (0x1E19, 906, 2), (0x1E1A, 908, 2), (0x1E1B, 910, 2), (0x1E1C, 912, 3),
+    (0x1E1D, 915, 3), (0x1E1E, 918, 2), (0x1E1F, 920, 2), (0x1E20, 922, 2),
+    (0x1E21, 924, 2), (0x1E22, 926, 2), (0x1E23, 928, 2), (0x1E24, 930, 2),
+    (0x1E25, 932, 2), (0x1E26, 934, 2), (0x1E27, 936, 2), (0x1E28, 938, 2),
+    (0x1E29, 940, 2), (0x1E2A, 942, 2), (0x1E2B, 944, 2), (0x1E2C, 946, 2),
+    (0x1E2D, 948, 2), (0x1E2E, 950, 3), (0x1E2F, 953, 3), (0x1E30, 956, 2),
+    (0x1E31, 958, 2), (0x1E32, 960, 2), (0x1E33, 962, 2), (0x1E34, 964, 2),
+    (0x1E35, 966, 2), (0x1E36, 968, 2), (0x1E37, 970, 2), (0x1E38, 972, 3),
+    (0x1E39, 975, 3), (0x1E3A, 978, 2), (0x1E3B, 980, 2), (0x1E3C, 982, 2),
+    (0x1E3D, 984, 2), (0x1E3E, 986, 2), (0x1E3F, 988, 2), (0x1E40, 990, 2),
+    (0x1E41, 992, 2), (0x1E42, 994, 2), (0x1E43, 996, 2), (0x1E44, 998, 2),
+    (0x1E45, 1000, 2), (0x1E46, 1002, 2), (0x1E47, 1004, 2), (0x1E48, 1006, 2),
+    (0x1E49, 1008, 2), (0x1E4A, 1010, 2), (0x1E4B, 1012, 2), (0x1E4C, 1014, 3),
+    (0x1E4D, 1017, 3), (0x1E4E, 1020, 3), (0x1E4F, 1023, 3), (0x1E50, 1026, 3),
+    (0x1E51, 1029, 3), (0x1E52, 1032, 3), (0x1E53, 1035, 3), (0x1E54, 1038, 2),
+    (0x1E55, 1040, 2), (0x1E56, 1042, 2), (0x1E57, 1044, 2), (0x1E58, 1046, 2),
+    (0x1E59, 1048, 2), (0x1E5A, 1050, 2), (0x1E5B, 1052, 2), (0x1E5C, 1054, 3),
+    (0x1E5D, 1057, 3), (0x1E5E, 1060, 2), (0x1E5F, 1062, 2), (0x1E60, 1064, 2),
+    (0x1E61, 1066, 2), (0x1E62, 1068, 2), (0x1E63, 1070, 2), (0x1E64, 1072, 3),
+    (0x1E65, 1075, 3), (0x1E66, 1078, 3), (0x1E67, 1081, 3), (0x1E68, 1084, 3),
+    (0x1E69, 1087, 3), (0x1E6A, 1090, 2), (0x1E6B, 1092, 2), (0x1E6C, 1094, 2),
+    (0x1E6D, 1096, 2), (0x1E6E, 1098, 2), (0x1E6F, 1100, 2), (0x1E70, 1102, 2),
+    (0x1E71, 1104, 2), (0x1E72, 1106, 2), (0x1E73, 1108, 2), (0x1E74, 1110, 2),
+    (0x1E75, 1112, 2), (0x1E76, 1114, 2), (0x1E77, 1116, 2), (0x1E78, 1118, 3),
+    (0x1E79, 1121, 3), (0x1E7A, 1124, 3), (0x1E7B, 1127, 3), (0x1E7C, 1130, 2),
+    (0x1E7D, 1132, 2), (0x1E7E, 1134, 2), (0x1E7F, 1136, 2), (0x1E80, 1138, 2),
+    (0x1E81, 1140, 2), (0x1E82, 1142, 2), (0x1E83, 1144, 2), (0x1E84, 1146, 2),
+    (0x1E85, 1148, 2), (0x1E86, 1150, 2), (0x1E87, 1152, 2), (0x1E88, 1154, 2),
+    (0x1E89, 1156, 2), (0x1E8A, 1158, 2), (0x1E8B, 1160, 2), (0x1E8C, 1162, 2),
+    (0x1E8D, 1164, 2), (0x1E8E, 1166, 2), (0x1E8F, 1168, 2), (0x1E90, 1170, 2),
+    (0x1E91, 1172, 2), (0x1E92, 1174, 2), (0x1E93, 1176, 2), (0x1E94, 1178, 2),
+    (0x1E95, 1180, 2), (0x1E96, 1182, 2), (0x1E97, 1184, 2), (0x1E98, 1186, 2),
+    (0x1E99, 1188, 2), (0x1E9B, 1190, 2), (0x1EA0, 1192, 2), (0x1EA1, 1194, 2),
+    (0x1EA2, 1196, 2), (0x1EA3, 1198, 2), (0x1EA4, 1200, 3), (0x1EA5, 1203, 3),
+    (0x1EA6, 1206, 3), (0x1EA7, 1209, 3), (0x1EA8, 1212, 3), (0x1EA9, 1215, 3),
+    (0x1EAA, 1218, 3), (0x1EAB, 1221, 3), (0x1EAC, 1224, 3), (0x1EAD, 1227, 3),
+    (0x1EAE, 1230, 3), (0x1EAF, 1233, 3), (0x1EB0, 1236, 3), (0x1EB1, 1239, 3),
+    (0x1EB2, 1242, 3), (0x1EB3, 1245, 3), (0x1EB4, 1248, 3), (0x1EB5, 1251, 3),
+    (0x1EB6, 1254, 3), (0x1EB7, 1257, 3), (0x1EB8, 1260, 2), (0x1EB9, 1262, 2),
+    (0x1EBA, 1264, 2), (0x1EBB, 1266, 2), (0x1EBC, 1268, 2), (0x1EBD, 1270, 2),
+    (0x1EBE, 1272, 3), (0x1EBF, 1275, 3), (0x1EC0, 1278, 3), (0x1EC1, 1281, 3),
+    (0x1EC2, 1284, 3), (0x1EC3, 1287, 3), (0x1EC4, 1290, 3), (0x1EC5, 1293, 3),
+    (0x1EC6, 1296, 3), (0x1EC7, 1299, 3), (0x1EC8, 1302, 2), (0x1EC9, 1304, 2),
+    (0x1ECA, 1306, 2), (0x1ECB, 1308, 2), (0x1ECC, 1310, 2), (0x1ECD, 1312, 2),
+    (0x1ECE, 1314, 2), (0x1ECF, 1316, 2), (0x1ED0, 1318, 3), (0x1ED1, 1321, 3),
+    (0x1ED2, 1324, 3), (0x1ED3, 1327, 3), (0x1ED4, 1330, 3), (0x1ED5, 1333, 3),
+    (0x1ED6, 1336, 3), (0x1ED7, 1339, 3), (0x1ED8, 1342, 3), (0x1ED9, 1345, 3),
+    (0x1EDA, 1348, 3), (0x1EDB, 1351, 3), (0x1EDC, 1354, 3), (0x1EDD, 1357, 3),
+    (0x1EDE, 1360, 3), (0x1EDF, 1363, 3), (0x1EE0, 1366, 3), (0x1EE1, 1369, 3),
+    (0x1EE2, 1372, 3), (0x1EE3, 1375, 3), (0x1EE4, 1378, 2), (0x1EE5, 1380, 2),
+    (0x1EE6, 1382, 2), (0x1EE7, 1384, 2), (0x1EE8, 1386, 3), (0x1EE9, 1389, 3),
+    (0x1EEA, 1392, 3), (0x1EEB, 1395, 3), (0x1EEC, 1398, 3), (0x1EED, 1401, 3),
+    (0x1EEE, 1404, 3), (0x1EEF, 1407, 3), (0x1EF0, 1410, 3), (0x1EF1, 1413, 3),
+    (0x1EF2, 1416, 2), (0x1EF3, 1418, 2), (0x1EF4, 1420, 2), (0x1EF5, 1422, 2),
+    (0x1EF6, 1424, 2), (0x1EF7, 1426, 2), (0x1EF8, 1428, 2), (0x1EF9, 1430, 2),
+    (0x1F00, 1432, 2), (0x1F01, 1434, 2), (0x1F02, 1436, 3), (0x1F03, 1439, 3),
+    (0x1F04, 1442, 3), (0x1F05, 1445, 3), (0x1F06, 1448, 3), (0x1F07, 1451, 3),
+    (0x1F08, 1454, 2), (0x1F09, 1456, 2), (0x1F0A, 1458, 3), (0x1F0B, 1461, 3),
+    (0x1F0C, 1464, 3), (0x1F0D, 1467, 3), (0x1F0E, 1470, 3), (0x1F0F, 1473, 3),
+    (0x1F10, 1476, 2), (0x1F11, 1478, 2), (0x1F12, 1480, 3), (0x1F13, 1483, 3),
+    (0x1F14, 1486, 3), (0x1F15, 1489, 3), (0x1F18, 1492, 2), (0x1F19, 1494, 2),
+    (0x1F1A, 1496, 3), (0x1F1B, 1499, 3), (0x1F1C, 1502, 3), (0x1F1D, 1505, 3),
+    (0x1F20, 1508, 2), (0x1F21, 1510, 2), (0x1F22, 1512, 3), (0x1F23, 1515, 3),
+    (0x1F24, 1518, 3), (0x1F25, 1521, 3), (0x1F26, 1524, 3), (0x1F27, 1527, 3),
+    (0x1F28, 1530, 2), (0x1F29, 1532, 2), (0x1F2A, 1534, 3), (0x1F2B, 1537, 3),
+    (0x1F2C, 1540, 3), (0x1F2D, 1543, 3), (0x1F2E, 1546, 3), (0x1F2F, 1549, 3),
+    (0x1F30, 1552, 2), (0x1F31, 1554, 2), (0x1F32, 1556, 3), (0x1F33, 1559, 3),
+    (0x1F34, 1562, 3), (0x1F35, 1565, 3), (0x1F36, 1568, 3), (0x1F37, 1571, 3),
+    (0x1F38, 1574, 2), (0x1F39, 1576, 2), (0x1F3A, 1578, 3), (0x1F3B, 1581, 3),
+    (0x1F3C, 1584, 3), (0x1F3D, 1587, 3), (0x1F3E, 1590, 3), (0x1F3F, 1593, 3),
+    (0x1F40, 1596, 2), (0x1F41, 1598, 2), (0x1F42, 1600, 3), (0x1F43, 1603, 3),
+    (0x1F44, 1606, 3), (0x1F45, 1609, 3), (0x1F48, 1612, 2), (0x1F49, 1614, 2),
+    (0x1F4A, 1616, 3), (0x1F4B, 1619, 3), (0x1F4C, 1622, 3), (0x1F4D, 1625, 3),
+    (0x1F50, 1628, 2), (0x1F51, 1630, 2), (0x1F52, 1632, 3), (0x1F53, 1635, 3),
+    (0x1F54, 1638, 3), (0x1F55, 1641, 3), (0x1F56, 1644, 3), (0x1F57, 1647, 3),
+    (0x1F59, 1650, 2), (0x1F5B, 1652, 3), (0x1F5D, 1655, 3), (0x1F5F, 1658, 3),
+    (0x1F60, 1661, 2), (0x1F61, 1663, 2), (0x1F62, 1665, 3), (0x1F63, 1668, 3),
+    (0x1F64, 1671, 3), (0x1F65, 1674, 3), (0x1F66, 1677, 3), (0x1F67, 1680, 3),
+    (0x1F68, 1683, 2), (0x1F69, 1685, 2), (0x1F6A, 1687, 3), (0x1F6B, 1690, 3),
+    (0x1F6C, 1693, 3), (0x1F6D, 1696, 3), (0x1F6E, 1699, 3), (0x1F6F, 1702, 3),
+    (0x1F70, 1705, 2), (0x1F71, 1707, 2), (0x1F72, 1709, 2), (0x1F73, 1711, 2),
+    (0x1F74, 1713, 2), (0x1F75, 1715, 2), (0x1F76, 1717, 2), (0x1F77, 1719, 2),
+    (0x1F78, 1721, 2), (0x1F79, 1723, 2), (0x1F7A, 1725, 2), (0x1F7B, 1727, 2),
+    (0x1F7C, 1729, 2), (0x1F7D, 1731, 2), (0x1F80, 1733, 3), (0x1F81, 1736, 3),
+    (0x1F82, 1739, 4), (0x1F83, 1743, 4), (0x1F84, 1747, 4), (0x1F85, 1751, 4),
+    (0x1F86, 1755, 4), (0x1F87, 1759, 4), (0x1F88, 1763, 3), (0x1F89, 1766, 3),
+    (0x1F8A, 1769, 4), (0x1F8B, 1773, 4), (0x1F8C, 1777, 4), (0x1F8D, 1781, 4),
+    (0x1F8E, 1785, 4), (0x1F8F, 1789, 4), (0x1F90, 1793, 3), (0x1F91, 1796, 3),
+    (0x1F92, 1799, 4), (0x1F93, 1803, 4), (0x1F94, 1807, 4), (0x1F95, 1811, 4),
+    (0x1F96, 1815, 4), (0x1F97, 1819, 4), (0x1F98, 1823, 3), (0x1F99, 1826, 3),
+    (0x1F9A, 1829, 4), (0x1F9B, 1833, 4), (0x1F9C, 1837, 4), (0x1F9D, 1841, 4),
+    (0x1F9E, 1845, 4), (0x1F9F, 1849, 4), (0x1FA0, 1853, 3), (0x1FA1, 1856, 3),
+    (0x1FA2, 1859, 4), (0x1FA3, 1863, 4), (0x1FA4, 1867, 4), (0x1FA5, 1871, 4),
+    (0x1FA6, 1875, 4), (0x1FA7, 1879, 4), (0x1FA8, 1883, 3), (0x1FA9, 1886, 3),
+    (0x1FAA, 1889, 4), (0x1FAB, 1893, 4), (0x1FAC, 1897, 4), (0x1FAD, 1901, 4),
+    (0x1FAE, 1905, 4), (0x1FAF, 1909, 4), (0x1FB0, 1913, 2), (0x1FB1, 1915, 2),
+    (0x1FB2, 1917, 3), (0x1FB3, 1920, 2), (0x1FB4, 1922, 3), (0x1FB6, 1925, 2),
+    (0x1FB7, 1927, 3), (0x1FB8, 1930, 2), (0x1FB9, 1932, 2), (0x1FBA, 1934, 2),
+    (0x1FBB, 1936, 2), (0x1FBC, 1938, 2), (0x1FBE, 1940, 1), (0x1FC1, 1941, 2),
+    (0x1FC2, 1943, 3), (0x1FC3, 1946, 2), (0x1FC4, 1948, 3), (0x1FC6, 1951, 2),
+    (0x1FC7, 1953, 3), (0x1FC8, 1956, 2), (0x1FC9, 1958, 2), (0x1FCA, 1960, 2),
+    (0x1FCB, 1962, 2), (0x1FCC, 1964, 2), (0x1FCD, 1966, 2), (0x1FCE, 1968, 2),
+    (0x1FCF, 1970, 2), (0x1FD0, 1972, 2), (0x1FD1, 1974, 2), (0x1FD2, 1976, 3),
+    (0x1FD3, 1979, 3), (0x1FD6, 1982, 2), (0x1FD7, 1984, 3), (0x1FD8, 1987, 2),
+    (0x1FD9, 1989, 2), (0x1FDA, 1991, 2), (0x1FDB, 1993, 2), (0x1FDD, 1995, 2),
+    (0x1FDE, 1997, 2), (0x1FDF, 1999, 2), (0x1FE0, 2001, 2), (0x1FE1, 2003, 2),
+    (0x1FE2, 2005, 3), (0x1FE3, 2008, 3), (0x1FE4, 2011, 2), (0x1FE5, 2013, 2),
+    (0x1FE6, 2015, 2), (0x1FE7, 2017, 3), (0x1FE8, 2020, 2), (0x1FE9, 2022, 2),
+    (0x1FEA, 2024, 2), (0x1FEB, 2026, 2), (0x1FEC, 2028, 2), (0x1FED, 2030, 2),
+    (0x1FEE, 2032, 2), (0x1FEF, 2034, 1), (0x1FF2, 2035, 3), (0x1FF3, 2038, 2),
+    (0x1FF4, 2040, 3), (0x1FF6, 2043, 2), (0x1FF7, 2045, 3), (0x1FF8, 2048, 2),
+    (0x1FF9, 2050, 2), (0x1FFA, 2052, 2), (0x1FFB, 2054, 2), (0x1FFC, 2056, 2),
+    (0x1FFD, 2058, 1), (0x2000, 2059, 1), (0x2001, 2060, 1), (0x2126, 2061, 1),
+    (0x212A, 2062, 1), (0x212B, 2063, 2), (0x219A, 2065, 2), (0x219B, 2067, 2),
+    (0x21AE, 2069, 2), (0x21CD, 2071, 2), (0x21CE, 2073, 2), (0x21CF, 2075, 2),
+    (0x2204, 2077, 2), (0x2209, 2079, 2), (0x220C, 2081, 2), (0x2224, 2083, 2),
+    (0x2226, 2085, 2), (0x2241, 2087, 2), (0x2244, 2089, 2), (0x2247, 2091, 2),
+    (0x2249, 2093, 2), (0x2260, 2095, 2), (0x2262, 2097, 2), (0x226D, 2099, 2),
+    (0x226E, 2101, 2), (0x226F, 2103, 2), (0x2270, 2105, 2), (0x2271, 2107, 2),
+    (0x2274, 2109, 2), (0x2275, 2111, 2), (0x2278, 2113, 2), (0x2279, 2115, 2),
+    (0x2280, 2117, 2), (0x2281, 2119, 2), (0x2284, 2121, 2), (0x2285, 2123, 2),
+    (0x2288, 2125, 2), (0x2289, 2127, 2), (0x22AC, 2129, 2), (0x22AD, 2131, 2),
+    (0x22AE, 2133, 2), (0x22AF, 2135, 2), (0x22E0, 2137, 2), (0x22E1, 2139, 2),
+    (0x22E2, 2141, 2), (0x22E3, 2143, 2), (0x22EA, 2145, 2), (0x22EB, 2147, 2),
+    (0x22EC, 2149, 2), (0x22ED, 2151, 2), (0x2329, 2153, 1), (0x232A, 2154, 1),
+    (0x2ADC, 2155, 2), (0x304C, 2157, 2), (0x304E, 2159, 2), (0x3050, 2161, 2),
+    (0x3052, 2163, 2), (0x3054, 2165, 2), (0x3056, 2167, 2), (0x3058, 2169, 2),
+    (0x305A, 2171, 2), (0x305C, 2173, 2), (0x305E, 2175, 2), (0x3060, 2177, 2),
+    (0x3062, 2179, 2), (0x3065, 2181, 2), (0x3067, 2183, 2), (0x3069, 2185, 2),
+    (0x3070, 2187, 2), (0x3071, 2189, 2), (0x3073, 2191, 2), (0x3074, 2193, 2),
+    (0x3076, 2195, 2), (0x3077, 2197, 2), (0x3079, 2199, 2), (0x307A, 2201, 2),
+    (0x307C, 2203, 2), (0x307D, 2205, 2), (0x3094, 2207, 2), (0x309E, 2209, 2),
+    (0x30AC, 2211, 2), (0x30AE, 2213, 2), (0x30B0, 2215, 2), (0x30B2, 2217, 2),
+    (0x30B4, 2219, 2), (0x30B6, 2221, 2), (0x30B8, 2223, 2), (0x30BA, 2225, 2),
+    (0x30BC, 2227, 2), (0x30BE, 2229, 2), (0x30C0, 2231, 2), (0x30C2, 2233, 2),
+    (0x30C5, 2235, 2), (0x30C7, 2237, 2), (0x30C9, 2239, 2), (0x30D0, 2241, 2),
+    (0x30D1, 2243, 2), (0x30D3, 2245, 2), (0x30D4, 2247, 2), (0x30D6, 2249, 2),
+    (0x30D7, 2251, 2), (0x30D9, 2253, 2), (0x30DA, 2255, 2), (0x30DC, 2257, 2),
+    (0x30DD, 2259, 2), (0x30F4, 2261, 2), (0x30F7, 2263, 2), (0x30F8, 2265, 2),
+    (0x30F9, 2267, 2), (0x30FA, 2269, 2), (0x30FE, 2271, 2), (0xF900, 2273, 1),
+    (0xF901, 2274, 1), (0xF902, 2275, 1), (0xF903, 2276, 1), (0xF904, 2277, 1),
+    (0xF905, 2278, 1), (0xF906, 2279, 1), (0xF907, 2280, 1), (0xF908, 2281, 1),
+    (0xF909, 2282, 1), (0xF90A, 2283, 1), (0xF90B, 2284, 1), (0xF90C, 2285, 1),
+    (0xF90D, 2286, 1), (0xF90E, 2287, 1), (0xF90F, 2288, 1), (0xF910, 2289, 1),
+    (0xF911, 2290, 1), (0xF912, 2291, 1), (0xF913, 2292, 1), (0xF914, 2293, 1),
+    (0xF915, 2294, 1), (0xF916, 2295, 1), (0xF917, 2296, 1), (0xF918, 2297, 1),
+    (0xF919, 2298, 1), (0xF91A, 2299, 1), (0xF91B, 2300, 1), (0xF91C, 2301, 1),
+    (0xF91D, 2302, 1), (0xF91E, 2303, 1), (0xF91F, 2304, 1), (0xF920, 2305, 1),
+    (0xF921, 2306, 1), (0xF922, 2307, 1), (0xF923, 2308, 1), (0xF924, 2309, 1),
+    (0xF925, 2310, 1), (0xF926, 2311, 1), (0xF927, 2312, 1), (0xF928, 2313, 1),
+    (0xF929, 2314, 1), (0xF92A, 2315, 1), (0xF92B, 2316, 1), (0xF92C, 2317, 1),
+    (0xF92D, 2318, 1), (0xF92E, 2319, 1), (0xF92F, 2320, 1), (0xF930, 2321, 1),
+    (0xF931, 2322, 1), (0xF932, 2323, 1), (0xF933, 2324, 1), (0xF934, 2325, 1),
+    (0xF935, 2326, 1), (0xF936, 2327, 1), (0xF937, 2328, 1), (0xF938, 2329, 1),
+    (0xF939, 2330, 1), (0xF93A, 2331, 1), (0xF93B, 2332, 1), (0xF93C, 2333, 1),
+    (0xF93D, 2334, 1), (0xF93E, 2335, 1), (0xF93F, 2336, 1), (0xF940, 2337, 1),
+    (0xF941, 2338, 1), (0xF942, 2339, 1), (0xF943, 2340, 1), (0xF944, 2341, 1),
+    (0xF945, 2342, 1), (0xF946, 2343, 1), (0xF947, 2344, 1), (0xF948, 2345, 1),
+    (0xF949, 2346, 1), (0xF94A, 2347, 1), (0xF94B, 2348, 1), (0xF94C, 2349, 1),
+    (0xF94D, 2350, 1), (0xF94E, 2351, 1), (0xF94F, 2352, 1), (0xF950, 2353, 1),
+    (0xF951, 2354, 1), (0xF952, 2355, 1), (0xF953, 2356, 1), (0xF954, 2357, 1),
+    (0xF955, 2358, 1), (0xF956, 2359, 1), (0xF957, 2360, 1), (0xF958, 2361, 1),
+    (0xF959, 2362, 1), (0xF95A, 2363, 1), (0xF95B, 2364, 1), (0xF95C, 2365, 1),
+    (0xF95D, 2366, 1), (0xF95E, 2367, 1), (0xF95F, 2368, 1), (0xF960, 2369, 1),
+    (0xF961, 2370, 1), (0xF962, 2371, 1), (0xF963, 2372, 1), (0xF964, 2373, 1),
+    (0xF965, 2374, 1), (0xF966, 2375, 1), (0xF967, 2376, 1), (0xF968, 2377, 1),
+    (0xF969, 2378, 1), (0xF96A, 2379, 1), (0xF96B, 2380, 1), (0xF96C, 2381, 1),
+    (0xF96D, 2382, 1), (0xF96E, 2383, 1), (0xF96F, 2384, 1), (0xF970, 2385, 1),
+    (0xF971, 2386, 1), (0xF972, 2387, 1), (0xF973, 2388, 1), (0xF974, 2389, 1),
+    (0xF975, 2390, 1), (0xF976, 2391, 1), (0xF977, 2392, 1), (0xF978, 2393, 1),
+    (0xF979, 2394, 1), (0xF97A, 2395, 1), (0xF97B, 2396, 1), (0xF97C, 2397, 1),
+    (0xF97D, 2398, 1), (0xF97E, 2399, 1), (0xF97F, 2400, 1), (0xF980, 2401, 1),
+    (0xF981, 2402, 1), (0xF982, 2403, 1), (0xF983, 2404, 1), (0xF984, 2405, 1),
+    (0xF985, 2406, 1), (0xF986, 2407, 1), (0xF987, 2408, 1), (0xF988, 2409, 1),
+    (0xF989, 2410, 1), (0xF98A, 2411, 1), (0xF98B, 2412, 1), (0xF98C, 2413, 1),
+    (0xF98D, 2414, 1), (0xF98E, 2415, 1), (0xF98F, 2416, 1), (0xF990, 2417, 1),
+    (0xF991, 2418, 1), (0xF992, 2419, 1), (0xF993, 2420, 1), (0xF994, 2421, 1),
+    (0xF995, 2422, 1), (0xF996, 2423, 1), (0xF997, 2424, 1), (0xF998, 2425, 1),
+    (0xF999, 2426, 1), (0xF99A, 2427, 1), (0xF99B, 2428, 1), (0xF99C, 2429, 1),
+    (0xF99D, 2430, 1), (0xF99E, 2431, 1), (0xF99F, 2432, 1), (0xF9A0, 2433, 1),
+    (0xF9A1, 2434, 1), (0xF9A2, 2435, 1), (0xF9A3, 2436, 1), (0xF9A4, 2437, 1),
+    (0xF9A5, 2438, 1), (0xF9A6, 2439, 1), (0xF9A7, 2440, 1), (0xF9A8, 2441, 1),
+    (0xF9A9, 2442, 1), (0xF9AA, 2443, 1), (0xF9AB, 2444, 1), (0xF9AC, 2445, 1),
+    (0xF9AD, 2446, 1), (0xF9AE, 2447, 1), (0xF9AF, 2448, 1), (0xF9B0, 2449, 1),
+    (0xF9B1, 2450, 1), (0xF9B2, 2451, 1), (0xF9B3, 2452, 1), (0xF9B4, 2453, 1),
+    (0xF9B5, 2454, 1), (0xF9B6, 2455, 1), (0xF9B7, 2456, 1), (0xF9B8, 2457, 1),
+    (0xF9B9, 2458, 1), (0xF9BA, 2459, 1), (0xF9BB, 2460, 1), (0xF9BC, 2461, 1),
+    (0xF9BD, 2462, 1), (0xF9BE, 2463, 1), (0xF9BF, 2464, 1), (0xF9C0, 2465, 1),
+    (0xF9C1, 2466, 1), (0xF9C2, 2467, 1), (0xF9C3, 2468, 1), (0xF9C4, 2469, 1),
+    (0xF9C5, 2470, 1), (0xF9C6, 2471, 1), (0xF9C7, 2472, 1), (0xF9C8, 2473, 1),
+    (0xF9C9, 2474, 1), (0xF9CA, 2475, 1), (0xF9CB, 2476, 1), (0xF9CC, 2477, 1),
+    (0xF9CD, 2478, 1), (0xF9CE, 2479, 1), (0xF9CF, 2480, 1), (0xF9D0, 2481, 1),
+    (0xF9D1, 2482, 1), (0xF9D2, 2483, 1), (0xF9D3, 2484, 1), (0xF9D4, 2485, 1),
+    (0xF9D5, 2486, 1), (0xF9D6, 2487, 1), (0xF9D7, 2488, 1), (0xF9D8, 2489, 1),
+    (0xF9D9, 2490, 1), (0xF9DA, 2491, 1), (0xF9DB, 2492, 1), (0xF9DC, 2493, 1),
+    (0xF9DD, 2494, 1), (0xF9DE, 2495, 1), (0xF9DF, 2496, 1), (0xF9E0, 2497, 1),
+    (0xF9E1, 2498, 1), (0xF9E2, 2499, 1), (0xF9E3, 2500, 1), (0xF9E4, 2501, 1),
+    (0xF9E5, 2502, 1), (0xF9E6, 2503, 1), (0xF9E7, 2504, 1), (0xF9E8, 2505, 1),
+    (0xF9E9, 2506, 1), (0xF9EA, 2507, 1), (0xF9EB, 2508, 1), (0xF9EC, 2509, 1),
+    (0xF9ED, 2510, 1), (0xF9EE, 2511, 1), (0xF9EF, 2512, 1), (0xF9F0, 2513, 1),
+    (0xF9F1, 2514, 1), (0xF9F2, 2515, 1), (0xF9F3, 2516, 1), (0xF9F4, 2517, 1),
+    (0xF9F5, 2518, 1), (0xF9F6, 2519, 1), (0xF9F7, 2520, 1), (0xF9F8, 2521, 1),
+    (0xF9F9, 2522, 1), (0xF9FA, 2523, 1), (0xF9FB, 2524, 1), (0xF9FC, 2525, 1),
+    (0xF9FD, 2526, 1), (0xF9FE, 2527, 1), (0xF9FF, 2528, 1), (0xFA00, 2529, 1),
+    (0xFA01, 2530, 1), (0xFA02, 2531, 1), (0xFA03, 2532, 1), (0xFA04, 2533, 1),
+    (0xFA05, 2534, 1), (0xFA06, 2535, 1), (0xFA07, 2536, 1), (0xFA08, 2537, 1),
+    (0xFA09, 2538, 1), (0xFA0A, 2539, 1), (0xFA0B, 2540, 1), (0xFA0C, 2541, 1),
+    (0xFA0D, 2542, 1), (0xFA10, 2543, 1), (0xFA12, 2544, 1), (0xFA15, 2545, 1),
+    (0xFA16, 2546, 1), (0xFA17, 2547, 1), (0xFA18, 2548, 1), (0xFA19, 2549, 1),
+    (0xFA1A, 2550, 1), (0xFA1B, 2551, 1), (0xFA1C, 2552, 1), (0xFA1D, 2553, 1),
+    (0xFA1E, 2554, 1), (0xFA20, 2555, 1), (0xFA22, 2556, 1), (0xFA25, 2557, 1),
+    (0xFA26, 2558, 1), (0xFA2A, 2559, 1), (0xFA2B, 2560, 1), (0xFA2C, 2561, 1),
+    (0xFA2D, 2562, 1), (0xFA2E, 2563, 1), (0xFA2F, 2564, 1), (0xFA30, 2565, 1),
+    (0xFA31, 2566, 1), (0xFA32, 2567, 1), (0xFA33, 2568, 1), (0xFA34, 2569, 1),
+    (0xFA35, 2570, 1), (0xFA36, 2571, 1), (0xFA37, 2572, 1), (0xFA38, 2573, 1),
+    (0xFA39, 2574, 1), (0xFA3A, 2575, 1), (0xFA3B, 2576, 1), (0xFA3C, 2577, 1),
+    (0xFA3D, 2578, 1), (0xFA3E, 2579, 1), (0xFA3F, 2580, 1), (0xFA40, 2581, 1),
+    (0xFA41, 2582, 1), (0xFA42, 2583, 1), (0xFA43, 2584, 1), (0xFA44, 2585, 1),
+    (0xFA45, 2586, 1), (0xFA46, 2587, 1), (0xFA47, 2588, 1), (0xFA48, 2589, 1),
+    (0xFA49, 2590, 1), (0xFA4A, 2591, 1), (0xFA4B, 2592, 1), (0xFA4C, 2593, 1),
+    (0xFA4D, 2594, 1), (0xFA4E, 2595, 1), (0xFA4F, 2596, 1), (0xFA50, 2597, 1),
+    (0xFA51, 2598, 1), (0xFA52, 2599, 1), (0xFA53, 2600, 1), (0xFA54, 2601, 1),
+    (0xFA55, 2602, 1), (0xFA56, 2603, 1), (0xFA57, 2604, 1), (0xFA58, 2605, 1),
+    (0xFA59, 2606, 1), (0xFA5A, 2607, 1), (0xFA5B, 2608, 1), (0xFA5C, 2609, 1),
+    (0xFA5D, 2610, 1), (0xFA5E, 2611, 1), (0xFA5F, 2612, 1), (0xFA60, 2613, 1),
+    (0xFA61, 2614, 1), (0xFA62, 2615, 1), (0xFA63, 2616, 1), (0xFA64, 2617, 1),
+    (0xFA65, 2618, 1), (0xFA66, 2619, 1), (0xFA67, 2620, 1), (0xFA68, 2621, 1),
+    (0xFA69, 2622, 1), (0xFA6A, 2623, 1), (0xFA6B, 2624, 1), (0xFA6C, 2625, 1),
+    (0xFA6D, 2626, 1), (0xFA70, 2627, 1), (0xFA71, 2628, 1), (0xFA72, 2629, 1),
+    (0xFA73, 2630, 1), (0xFA74, 2631, 1), (0xFA75, 2632, 1), (0xFA76, 2633, 1),
+    (0xFA77, 2634, 1), (0xFA78, 2635, 1), (0xFA79, 2636, 1), (0xFA7A, 2637, 1),
+    (0xFA7B, 2638, 1), (0xFA7C, 2639, 1), (0xFA7D, 2640, 1), (0xFA7E, 2641, 1),
+    (0xFA7F, 2642, 1), (0xFA80, 2643, 1), (0xFA81, 2644, 1), (0xFA82, 2645, 1),
+    (0xFA83, 2646, 1), (0xFA84, 2647, 1), (0xFA85, 2648, 1), (0xFA86, 2649, 1),
+    (0xFA87, 2650, 1), (0xFA88, 2651, 1), (0xFA89, 2652, 1), (0xFA8A, 2653, 1),
+    (0xFA8B, 2654, 1), (0xFA8C, 2655, 1), (0xFA8D, 2656, 1), (0xFA8E, 2657, 1),
+    (0xFA8F, 2658, 1), (0xFA90, 2659, 1), (0xFA91, 2660, 1), (0xFA92, 2661, 1),
+    (0xFA93, 2662, 1), (0xFA94, 2663, 1), (0xFA95, 2664, 1), (0xFA96, 2665, 1),
+    (0xFA97, 2666, 1), (0xFA98, 2667, 1), (0xFA99, 2668, 1), (0xFA9A, 2669, 1),
+    (0xFA9B, 2670, 1), (0xFA9C, 2671, 1), (0xFA9D, 2672, 1), (0xFA9E, 2673, 1),
+    (0xFA9F, 2674, 1), (0xFAA0, 2675, 1), (0xFAA1, 2676, 1), (0xFAA2, 2677, 1),
+    (0xFAA3, 2678, 1), (0xFAA4, 2679, 1), (0xFAA5, 2680, 1), (0xFAA6, 2681, 1),
+    (0xFAA7, 2682, 1), (0xFAA8, 2683, 1), (0xFAA9, 2684, 1), (0xFAAA, 2685, 1),
+    (0xFAAB, 2686, 1), (0xFAAC, 2687, 1), (0xFAAD, 2688, 1), (0xFAAE, 2689, 1),
+    (0xFAAF, 2690, 1), (0xFAB0, 2691, 1), (0xFAB1, 2692, 1), (0xFAB2, 2693, 1),
+    (0xFAB3, 2694, 1), (0xFAB4, 2695, 1), (0xFAB5, 2696, 1), (0xFAB6, 2697, 1),
+    (0xFAB7, 2698, 1), (0xFAB8, 2699, 1), (0xFAB9, 2700, 1), (0xFABA, 2701, 1),
+    (0xFABB, 2702, 1), (0xFABC, 2703, 1), (0xFABD, 2704, 1), (0xFABE, 2705, 1),
+    (0xFABF, 2706, 1), (0xFAC0, 2707, 1), (0xFAC1, 2708, 1), (0xFAC2, 2709, 1),
+    (0xFAC3, 2710, 1), (0xFAC4, 2711, 1), (0xFAC5, 2712, 1), (0xFAC6, 2713, 1),
+    (0xFAC7, 2714, 1), (0xFAC8, 2715, 1), (0xFAC9, 2716, 1), (0xFACA, 2717, 1),
+    (0xFACB, 2718, 1), (0xFACC, 2719, 1), (0xFACD, 2720, 1), (0xFACE, 2721, 1),
+    (0xFACF, 2722, 1), (0xFAD0, 2723, 1), (0xFAD1, 2724, 1), (0xFAD2, 2725, 1),
+    (0xFAD3, 2726, 1), (0xFAD4, 2727, 1), (0xFAD5, 2728, 1), (0xFAD6, 2729, 1),
+    (0xFAD7, 2730, 1), (0xFAD8, 2731, 1), (0xFAD9, 2732, 1), (0xFB1D, 2733, 2),
+    (0xFB1F, 2735, 2), (0xFB2A, 2737, 2), (0xFB2B, 2739, 2), (0xFB2C, 2741, 3),
+    (0xFB2D, 2744, 3), (0xFB2E, 2747, 2), (0xFB2F, 2749, 2), (0xFB30, 2751, 2),
+    (0xFB31, 2753, 2), (0xFB32, 2755, 2), (0xFB33, 2757, 2), (0xFB34, 2759, 2),
+    (0xFB35, 2761, 2), (0xFB36, 2763, 2), (0xFB38, 2765, 2), (0xFB39, 2767, 2),
+    (0xFB3A, 2769, 2), (0xFB3B, 2771, 2), (0xFB3C, 2773, 2), (0xFB3E, 2775, 2),
+    (0xFB40, 2777, 2), (0xFB41, 2779, 2), (0xFB43, 2781, 2), (0xFB44, 2783, 2),
+    (0xFB46, 2785, 2), (0xFB47, 2787, 2), (0xFB48, 2789, 2), (0xFB49, 2791, 2),
+    (0xFB4A, 2793, 2), (0xFB4B, 2795, 2), (0xFB4C, 2797, 2), (0xFB4D, 2799, 2),
+    (0xFB4E, 2801, 2), (0x1109A, 2803, 2), (0x1109C, 2805, 2), (0x110AB, 2807, 2),
+    (0x1112E, 2809, 2), (0x1112F, 2811, 2), (0x1134B, 2813, 2), (0x1134C, 2815, 2),
+    (0x114BB, 2817, 2), (0x114BC, 2819, 2), (0x114BE, 2821, 2), (0x115BA, 2823, 2),
+    (0x115BB, 2825, 2), (0x11938, 2827, 2), (0x1D15E, 2829, 2), (0x1D15F, 2831, 2),
+    (0x1D160, 2833, 3), (0x1D161, 2836, 3), (0x1D162, 2839, 3), (0x1D163, 2842, 3),
+    (0x1D164, 2845, 3), (0x1D1BB, 2848, 2), (0x1D1BC, 2850, 2), (0x1D1BD, 2852, 3),
+    (0x1D1BE, 2855, 3), (0x1D1BF, 2858, 3), (0x1D1C0, 2861, 3), (0x2F800, 2864, 1),
+    (0x2F801, 2865, 1), (0x2F802, 2866, 1), (0x2F803, 2867, 1), (0x2F804, 2868, 1),
+    (0x2F805, 2869, 1), (0x2F806, 2870, 1), (0x2F807, 2871, 1), (0x2F808, 2872, 1),
+    (0x2F809, 2873, 1), (0x2F80A, 2874, 1), (0x2F80B, 2875, 1), (0x2F80C, 2876, 1),
+    (0x2F80D, 2877, 1), (0x2F80E, 2878, 1), (0x2F80F, 2879, 1), (0x2F810, 2880, 1),
+    (0x2F811, 2881, 1), (0x2F812, 2882, 1), (0x2F813, 2883, 1), (0x2F814, 2884, 1),
+    (0x2F815, 2885, 1), (0x2F816, 2886, 1), (0x2F817, 2887, 1), (0x2F818, 2888, 1),
+    (0x2F819, 2889, 1), (0x2F81A, 2890, 1), (0x2F81B, 2891, 1), (0x2F81C, 2892, 1),
+    (0x2F81D, 2893, 1), (0x2F81E, 2894, 1), (0x2F81F, 2895, 1), (0x2F820, 2896, 1),
+    (0x2F821, 2897, 1), (0x2F822, 2898, 1), (0x2F823, 2899, 1), (0x2F824, 2900, 1),
+    (0x2F825, 2901, 1), (0x2F826, 2902, 1), (0x2F827, 2903, 1), (0x2F828, 2904, 1),
+    (0x2F829, 2905, 1), (0x2F82A, 2906, 1), (0x2F82B, 2907, 1), (0x2F82C, 2908, 1),
+    (0x2F82D, 2909, 1), (0x2F82E, 2910, 1), (0x2F82F, 2911, 1), (0x2F830, 2912, 1),
+    (0x2F831, 2913, 1), (0x2F832, 2914, 1), (0x2F833, 2915, 1), (0x2F834, 2916, 1),
+    (0x2F835, 2917, 1), (0x2F836, 2918, 1), (0x2F837, 2919, 1), (0x2F838, 2920, 1),
+    (0x2F839, 2921, 1), (0x2F83A, 2922, 1), (0x2F83B, 2923, 1), (0x2F83C, 2924, 1),
+    (0x2F83D, 2925, 1), (0x2F83E, 2926, 1), (0x2F83F, 2927, 1), (0x2F840, 2928, 1),
+    (0x2F841, 2929, 1), (0x2F842, 2930, 1), (0x2F843, 2931, 1), (0x2F844, 2932, 1),
+    (0x2F845, 2933, 1), (0x2F846, 2934, 1), (0x2F847, 2935, 1), (0x2F848, 2936, 1),
+    (0x2F849, 2937, 1), (0x2F84A, 2938, 1), (0x2F84B, 2939, 1), (0x2F84C, 2940, 1),
+    (0x2F84D, 2941, 1), (0x2F84E, 2942, 1), (0x2F84F, 2943, 1), (0x2F850, 2944, 1),
+    (0x2F851, 2945, 1), (0x2F852, 2946, 1), (0x2F853, 2947, 1), (0x2F854, 2948, 1),
+    (0x2F855, 2949, 1), (0x2F856, 2950, 1), (0x2F857, 2951, 1), (0x2F858, 2952, 1),
+    (0x2F859, 2953, 1), (0x2F85A, 2954, 1), (0x2F85B, 2955, 1), (0x2F85C, 2956, 1),
+    (0x2F85D, 2957, 1), (0x2F85E, 2958, 1), (0x2F85F, 2959, 1), (0x2F860, 2960, 1),
+    (0x2F861, 2961, 1), (0x2F862, 2962, 1), (0x2F863, 2963, 1), (0x2F864, 2964, 1),
+    (0x2F865, 2965, 1), (0x2F866, 2966, 1), (0x2F867, 2967, 1), (0x2F868, 2968, 1),
+    (0x2F869, 2969, 1), (0x2F86A, 2970, 1), (0x2F86B, 2971, 1), (0x2F86C, 2972, 1),
+    (0x2F86D, 2973, 1), (0x2F86E, 2974, 1), (0x2F86F, 2975, 1), (0x2F870, 2976, 1),
+    (0x2F871, 2977, 1), (0x2F872, 2978, 1), (0x2F873, 2979, 1), (0x2F874, 2980, 1),
+    (0x2F875, 2981, 1), (0x2F876, 2982, 1), (0x2F877, 2983, 1), (0x2F878, 2984, 1),
+    (0x2F879, 2985, 1), (0x2F87A, 2986, 1), (0x2F87B, 2987, 1), (0x2F87C, 2988, 1),
+    (0x2F87D, 2989, 1), (0x2F87E, 2990, 1), (0x2F87F, 2991, 1), (0x2F880, 2992, 1),
+    (0x2F881, 2993, 1), (0x2F882, 2994, 1), (0x2F883, 2995, 1), (0x2F884, 2996, 1),
+    (0x2F885, 2997, 1), (0x2F886, 2998, 1), (0x2F887, 2999, 1), (0x2F888, 3000, 1),
+    (0x2F889, 3001, 1), (0x2F88A, 3002, 1), (0x2F88B, 3003, 1), (0x2F88C, 3004, 1),
+    (0x2F88D, 3005, 1), (0x2F88E, 3006, 1), (0x2F88F, 3007, 1), (0x2F890, 3008, 1),
+    (0x2F891, 3009, 1), (0x2F892, 3010, 1), (0x2F893, 3011, 1), (0x2F894, 3012, 1),
+    (0x2F895, 3013, 1), (0x2F896, 3014, 1), (0x2F897, 3015, 1), (0x2F898, 3016, 1),
+    (0x2F899, 3017, 1), (0x2F89A, 3018, 1), (0x2F89B, 3019, 1), (0x2F89C, 3020, 1),
+    (0x2F89D, 3021, 1), (0x2F89E, 3022, 1), (0x2F89F, 3023, 1), (0x2F8A0, 3024, 1),
+    (0x2F8A1, 3025, 1), (0x2F8A2, 3026, 1), (0x2F8A3, 3027, 1), (0x2F8A4, 3028, 1),
+    (0x2F8A5, 3029, 1), (0x2F8A6, 3030, 1), (0x2F8A7, 3031, 1), (0x2F8A8, 3032, 1),
+    (0x2F8A9, 3033, 1), (0x2F8AA, 3034, 1), (0x2F8AB, 3035, 1), (0x2F8AC, 3036, 1),
+    (0x2F8AD, 3037, 1), (0x2F8AE, 3038, 1), (0x2F8AF, 3039, 1), (0x2F8B0, 3040, 1),
+    (0x2F8B1, 3041, 1), (0x2F8B2, 3042, 1), (0x2F8B3, 3043, 1), (0x2F8B4, 3044, 1),
+    (0x2F8B5, 3045, 1), (0x2F8B6, 3046, 1), (0x2F8B7, 3047, 1), (0x2F8B8, 3048, 1),
+    (0x2F8B9, 3049, 1), (0x2F8BA, 3050, 1), (0x2F8BB, 3051, 1), (0x2F8BC, 3052, 1),
+    (0x2F8BD, 3053, 1), (0x2F8BE, 3054, 1), (0x2F8BF, 3055, 1), (0x2F8C0, 3056, 1),
+    (0x2F8C1, 3057, 1), (0x2F8C2, 3058, 1), (0x2F8C3, 3059, 1), (0x2F8C4, 3060, 1),
+    (0x2F8C5, 3061, 1), (0x2F8C6, 3062, 1), (0x2F8C7, 3063, 1), (0x2F8C8, 3064, 1),
+    (0x2F8C9, 3065, 1), (0x2F8CA, 3066, 1), (0x2F8CB, 3067, 1), (0x2F8CC, 3068, 1),
+    (0x2F8CD, 3069, 1), (0x2F8CE, 3070, 1), (0x2F8CF, 3071, 1), (0x2F8D0, 3072, 1),
+    (0x2F8D1, 3073, 1), (0x2F8D2, 3074, 1), (0x2F8D3, 3075, 1), (0x2F8D4, 3076, 1),
+    (0x2F8D5, 3077, 1), (0x2F8D6, 3078, 1), (0x2F8D7, 3079, 1), (0x2F8D8, 3080, 1),
+    (0x2F8D9, 3081, 1), (0x2F8DA, 3082, 1), (0x2F8DB, 3083, 1), (0x2F8DC, 3084, 1),
+    (0x2F8DD, 3085, 1), (0x2F8DE, 3086, 1), (0x2F8DF, 3087, 1), (0x2F8E0, 3088, 1),
+    (0x2F8E1, 3089, 1), (0x2F8E2, 3090, 1), (0x2F8E3, 3091, 1), (0x2F8E4, 3092, 1),
+    (0x2F8E5, 3093, 1), (0x2F8E6, 3094, 1), (0x2F8E7, 3095, 1), (0x2F8E8, 3096, 1),
+    (0x2F8E9, 3097, 1), (0x2F8EA, 3098, 1), (0x2F8EB, 3099, 1), (0x2F8EC, 3100, 1),
+    (0x2F8ED, 3101, 1), (0x2F8EE, 3102, 1), (0x2F8EF, 3103, 1), (0x2F8F0, 3104, 1),
+    (0x2F8F1, 3105, 1), (0x2F8F2, 3106, 1), (0x2F8F3, 3107, 1), (0x2F8F4, 3108, 1),
+    (0x2F8F5, 3109, 1), (0x2F8F6, 3110, 1), (0x2F8F7, 3111, 1), (0x2F8F8, 3112, 1),
+    (0x2F8F9, 3113, 1), (0x2F8FA, 3114, 1), (0x2F8FB, 3115, 1), (0x2F8FC, 3116, 1),
+    (0x2F8FD, 3117, 1), (0x2F8FE, 3118, 1), (0x2F8FF, 3119, 1), (0x2F900, 3120, 1),
+    (0x2F901, 3121, 1), (0x2F902, 3122, 1), (0x2F903, 3123, 1), (0x2F904, 3124, 1),
+    (0x2F905, 3125, 1), (0x2F906, 3126, 1), (0x2F907, 3127, 1), (0x2F908, 3128, 1),
+    (0x2F909, 3129, 1), (0x2F90A, 3130, 1), (0x2F90B, 3131, 1), (0x2F90C, 3132, 1),
+    (0x2F90D, 3133, 1), (0x2F90E, 3134, 1), (0x2F90F, 3135, 1), (0x2F910, 3136, 1),
+    (0x2F911, 3137, 1), (0x2F912, 3138, 1), (0x2F913, 3139, 1), (0x2F914, 3140, 1),
+    (0x2F915, 3141, 1), (0x2F916, 3142, 1), (0x2F917, 3143, 1), (0x2F918, 3144, 1),
+    (0x2F919, 3145, 1), (0x2F91A, 3146, 1), (0x2F91B, 3147, 1), (0x2F91C, 3148, 1),
+    (0x2F91D, 3149, 1), (0x2F91E, 3150, 1), (0x2F91F, 3151, 1), (0x2F920, 3152, 1),
+    (0x2F921, 3153, 1), (0x2F922, 3154, 1), (0x2F923, 3155, 1), (0x2F924, 3156, 1),
+    (0x2F925, 3157, 1), (0x2F926, 3158, 1), (0x2F927, 3159, 1), (0x2F928, 3160, 1),
+    (0x2F929, 3161, 1), (0x2F92A, 3162, 1), (0x2F92B, 3163, 1), (0x2F92C, 3164, 1),
+    (0x2F92D, 3165, 1), (0x2F92E, 3166, 1), (0x2F92F, 3167, 1), (0x2F930, 3168, 1),
+    (0x2F931, 3169, 1), (0x2F932, 3170, 1), (0x2F933, 3171, 1), (0x2F934, 3172, 1),
+    (0x2F935, 3173, 1), (0x2F936, 3174, 1), (0x2F937, 3175, 1), (0x2F938, 3176, 1),
+    (0x2F939, 3177, 1), (0x2F93A, 3178, 1), (0x2F93B, 3179, 1), (0x2F93C, 3180, 1),
+    (0x2F93D, 3181, 1), (0x2F93E, 3182, 1), (0x2F93F, 3183, 1), (0x2F940, 3184, 1),
+    (0x2F941, 3185, 1), (0x2F942, 3186, 1), (0x2F943, 3187, 1), (0x2F944, 3188, 1),
+    (0x2F945, 3189, 1), (0x2F946, 3190, 1), (0x2F947, 3191, 1), (0x2F948, 3192, 1),
+    (0x2F949, 3193, 1), (0x2F94A, 3194, 1), (0x2F94B, 3195, 1), (0x2F94C, 3196, 1),
+    (0x2F94D, 3197, 1), (0x2F94E, 3198, 1), (0x2F94F, 3199, 1), (0x2F950, 3200, 1),
+    (0x2F951, 3201, 1), (0x2F952, 3202, 1), (0x2F953, 3203, 1), (0x2F954, 3204, 1),
+    (0x2F955, 3205, 1), (0x2F956, 3206, 1), (0x2F957, 3207, 1), (0x2F958, 3208, 1),
+    (0x2F959, 3209, 1), (0x2F95A, 3210, 1), (0x2F95B, 3211, 1), (0x2F95C, 3212, 1),
+    (0x2F95D, 3213, 1), (0x2F95E, 3214, 1), (0x2F95F, 3215, 1), (0x2F960, 3216, 1),
+    (0x2F961, 3217, 1), (0x2F962, 3218, 1), (0x2F963, 3219, 1), (0x2F964, 3220, 1),
+    (0x2F965, 3221, 1), (0x2F966, 3222, 1), (0x2F967, 3223, 1), (0x2F968, 3224, 1),
+    (0x2F969, 3225, 1), (0x2F96A, 3226, 1), (0x2F96B, 3227, 1), (0x2F96C, 3228, 1),
+    (0x2F96D, 3229, 1), (0x2F96E, 3230, 1), (0x2F96F, 3231, 1), (0x2F970, 3232, 1),
+    (0x2F971, 3233, 1), (0x2F972, 3234, 1), (0x2F973, 3235, 1), (0x2F974, 3236, 1),
+    (0x2F975, 3237, 1), (0x2F976, 3238, 1), (0x2F977, 3239, 1), (0x2F978, 3240, 1),
+    (0x2F979, 3241, 1), (0x2F97A, 3242, 1), (0x2F97B, 3243, 1), (0x2F97C, 3244, 1),
+    (0x2F97D, 3245, 1), (0x2F97E, 3246, 1), (0x2F97F, 3247, 1), (0x2F980, 3248, 1),
+    (0x2F981, 3249, 1), (0x2F982, 3250, 1), (0x2F983, 3251, 1), (0x2F984, 3252, 1),
+    (0x2F985, 3253, 1), (0x2F986, 3254, 1), (0x2F987, 3255, 1), (0x2F988, 3256, 1),
+    (0x2F989, 3257, 1), (0x2F98A, 3258, 1), (0x2F98B, 3259, 1), (0x2F98C, 3260, 1),
+    (0x2F98D, 3261, 1), (0x2F98E, 3262, 1), (0x2F98F, 3263, 1), (0x2F990, 3264, 1),
+    (0x2F991, 3265, 1), (0x2F992, 3266, 1), (0x2F993, 3267, 1), (0x2F994, 3268, 1),
+    (0x2F995, 3269, 1), (0x2F996, 3270, 1), (0x2F997, 3271, 1), (0x2F998, 3272, 1),
+    (0x2F999, 3273, 1), (0x2F99A, 3274, 1), (0x2F99B, 3275, 1), (0x2F99C, 3276, 1),
+    (0x2F99D, 3277, 1), (0x2F99E, 3278, 1), (0x2F99F, 3279, 1), (0x2F9A0, 3280, 1),
+    (0x2F9A1, 3281, 1), (0x2F9A2, 3282, 1), (0x2F9A3, 3283, 1), (0x2F9A4, 3284, 1),
+    (0x2F9A5, 3285, 1), (0x2F9A6, 3286, 1), (0x2F9A7, 3287, 1), (0x2F9A8, 3288, 1),
+    (0x2F9A9, 3289, 1), (0x2F9AA, 3290, 1), (0x2F9AB, 3291, 1), (0x2F9AC, 3292, 1),
+    (0x2F9AD, 3293, 1), (0x2F9AE, 3294, 1), (0x2F9AF, 3295, 1), (0x2F9B0, 3296, 1),
+    (0x2F9B1, 3297, 1), (0x2F9B2, 3298, 1), (0x2F9B3, 3299, 1), (0x2F9B4, 3300, 1),
+    (0x2F9B5, 3301, 1), (0x2F9B6, 3302, 1), (0x2F9B7, 3303, 1), (0x2F9B8, 3304, 1),
+    (0x2F9B9, 3305, 1), (0x2F9BA, 3306, 1), (0x2F9BB, 3307, 1), (0x2F9BC, 3308, 1),
+    (0x2F9BD, 3309, 1), (0x2F9BE, 3310, 1), (0x2F9BF, 3311, 1), (0x2F9C0, 3312, 1),
+    (0x2F9C1, 3313, 1), (0x2F9C2, 3314, 1), (0x2F9C3, 3315, 1), (0x2F9C4, 3316, 1),
+    (0x2F9C5, 3317, 1), (0x2F9C6, 3318, 1), (0x2F9C7, 3319, 1), (0x2F9C8, 3320, 1),
+    (0x2F9C9, 3321, 1), (0x2F9CA, 3322, 1), (0x2F9CB, 3323, 1), (0x2F9CC, 3324, 1),
+    (0x2F9CD, 3325, 1), (0x2F9CE, 3326, 1), (0x2F9CF, 3327, 1), (0x2F9D0, 3328, 1),
+    (0x2F9D1, 3329, 1), (0x2F9D2, 3330, 1), (0x2F9D3, 3331, 1), (0x2F9D4, 3332, 1),
+    (0x2F9D5, 3333, 1), (0x2F9D6, 3334, 1), (0x2F9D7, 3335, 1), (0x2F9D8, 3336, 1),
+    (0x2F9D9, 3337, 1), (0x2F9DA, 3338, 1), (0x2F9DB, 3339, 1), (0x2F9DC, 3340, 1),
+    (0x2F9DD, 3341, 1), (0x2F9DE, 3342, 1), (0x2F9DF, 3343, 1), (0x2F9E0, 3344, 1),
+    (0x2F9E1, 3345, 1), (0x2F9E2, 3346, 1), (0x2F9E3, 3347, 1), (0x2F9E4, 3348, 1),
+    (0x2F9E5, 3349, 1), (0x2F9E6, 3350, 1), (0x2F9E7, 3351, 1), (0x2F9E8, 3352, 1),
+    (0x2F9E9, 3353, 1), (0x2F9EA, 3354, 1), (0x2F9EB, 3355, 1), (0x2F9EC, 3356, 1),
+    (0x2F9ED, 3357, 1), (0x2F9EE, 3358, 1), (0x2F9EF, 3359, 1), (0x2F9F0, 3360, 1),
+    (0x2F9F1, 3361, 1), (0x2F9F2, 3362, 1), (0x2F9F3, 3363, 1), (0x2F9F4, 3364, 1),
+    (0x2F9F5, 3365, 1), (0x2F9F6, 3366, 1), (0x2F9F7, 3367, 1), (0x2F9F8, 3368, 1),
+    (0x2F9F9, 3369, 1), (0x2F9FA, 3370, 1), (0x2F9FB, 3371, 1), (0x2F9FC, 3372, 1),
+    (0x2F9FD, 3373, 1), (0x2F9FE, 3374, 1), (0x2F9FF, 3375, 1), (0x2FA00, 3376, 1),
+    (0x2FA01, 3377, 1), (0x2FA02, 3378, 1), (0x2FA03, 3379, 1), (0x2FA04, 3380, 1),
+    (0x2FA05, 3381, 1), (0x2FA06, 3382, 1), (0x2FA07, 3383, 1), (0x2FA08, 3384, 1),
+    (0x2FA09, 3385, 1), (0x2FA0A, 3386, 1), (0x2FA0B, 3387, 1), (0x2FA0C, 3388, 1),
+    (0x2FA0D, 3389, 1), (0x2FA0E, 3390, 1), (0x2FA0F, 3391, 1), (0x2FA10, 3392, 1),
+    (0x2FA11, 3393, 1), (0x2FA12, 3394, 1), (0x2FA13, 3395, 1), (0x2FA14, 3396, 1),
+    (0x2FA15, 3397, 1), (0x2FA16, 3398, 1), (0x2FA17, 3399, 1), (0x2FA18, 3400, 1),
+    (0x2FA19, 3401, 1), (0x2FA1A, 3402, 1), (0x2FA1B, 3403, 1), (0x2FA1C, 3404, 1),
+    (0x2FA1D, 3405, 1),
+];
+
+
+/// Full compatibility decompositions that differ from the canonical ones.
+pub const COMPATIBILITY: &[(u32, u16, u8)] = &[
+    (0x00A0, 3406, 1), (0x00A8, 3407, 2), (0x00AA, 3409, 1), (0x00AF, 3410, 2),
+    (0x00B2, 3412, 1), (0x00B3, 3413, 1), (0x00B4, 3414, 2), (0x00B5, 3416, 1),
+    (0x00B8, 3417, 2), (0x00B9, 3419, 1), (0x00BA, 3420, 1), (0x00BC, 3421, 3),
+    (0x00BD, 3424, 3), (0x00BE, 3427, 3), (0x0132, 3430, 2), (0x0133, 3432, 2),
+    (0x013F, 3434, 2), (0x0140, 3436, 2), (0x0149, 3438, 2), (0x017F, 3440, 1),
+    (0x01C4, 3441, 3), (0x01C5, 3444, 3), (0x01C6, 3447, 3), (0x01C7, 3450, 2),
+    (0x01C8, 3452, 2), (0x01C9, 3454, 2), (0x01CA, 3456, 2), (0x01CB, 3458, 2),
+    (0x01CC, 3460, 2), (0x01F1, 3462, 2), (0x01F2, 3464, 2), (0x01F3, 3466, 2),
+    (0x02B0, 3468, 1), (0x02B1, 3469, 1), (0x02B2, 3470, 1), (0x02B3, 3471, 1),
+    (0x02B4, 3472, 1), (0x02B5, 3473, 1), (0x02B6, 3474, 1), (0x02B7, 3475, 1),
+    (0x02B8, 3476, 1), (0x02D8, 3477, 2), (0x02D9, 3479, 2), (0x02DA, 3481, 2),
+    (0x02DB, 3483, 2), (0x02DC, 3485, 2), (0x02DD, 3487, 2), (0x02E0, 3489, 1),
+    (0x02E1, 3490, 1), (0x02E2, 3491, 1), (0x02E3, 3492, 1), (0x02E4, 3493, 1),
+    (0x037A, 3494, 2), (0x0384, 3496, 2), (0x0385, 3498, 3), (0x03D0, 3501, 1),
+    (0x03D1, 3502, 1), (0x03D2, 3503, 1), (0x03D3, 3504, 2), (0x03D4, 3506, 2),
+    (0x03D5, 3508, 1), (0x03D6, 3509, 1), (0x03F0, 3510, 1), (0x03F1, 3511, 1),
+    (0x03F2, 3512, 1), (0x03F4, 3513, 1), (0x03F5, 3514, 1), (0x03F9, 3515, 1),
+    (0x0587, 3516, 2), (0x0675, 3518, 2), (0x0676, 3520, 2), (0x0677, 3522, 2),
+    (0x0678, 3524, 2), (0x0E33, 3526, 2), (0x0EB3, 3528, 2), (0x0EDC, 3530, 2),
+    (0x0EDD, 3532, 2), (0x0F0C, 3534, 1), (0x0F77, 3535, 3), (0x0F79, 3538, 3),
+    (0x10FC, 3541, 1), (0x1D2C, 3542, 1), (0x1D2D, 3543, 1), (0x1D2E, 3544, 1),
+    (0x1D30, 3545, 1), (0x1D31, 3546, 1), (0x1D32, 3547, 1), (0x1D33, 3548, 1),
+    (0x1D34, 3549, 1), (0x1D35, 3550, 1), (0x1D36, 3551, 1), (0x1D37, 3552, 1),
+    (0x1D38, 3553, 1), (0x1D39, 3554, 1), (0x1D3A, 3555, 1), (0x1D3C, 3556, 1),
+    (0x1D3D, 3557, 1), (0x1D3E, 3558, 1), (0x1D3F, 3559, 1), (0x1D40, 3560, 1),
+    (0x1D41, 3561, 1), (0x1D42, 3562, 1), (0x1D43, 3563, 1), (0x1D44, 3564, 1),
+    (0x1D45, 3565, 1), (0x1D46, 3566, 1), (0x1D47, 3567, 1), (0x1D48, 3568, 1),
+    (0x1D49, 3569, 1), (0x1D4A, 3570, 1), (0x1D4B, 3571, 1), (0x1D4C, 3572, 1),
+    (0x1D4D, 3573, 1), (0x1D4F, 3574, 1), (0x1D50, 3575, 1), (0x1D51, 3576, 1),
+    (0x1D52, 3577, 1), (0x1D53, 3578, 1), (0x1D54, 3579, 1), (0x1D55, 3580, 1),
+    (0x1D56, 3581, 1), (0x1D57, 3582, 1), (0x1D58, 3583, 1), (0x1D59, 3584, 1),
+    (0x1D5A, 3585, 1), (0x1D5B, 3586, 1), (0x1D5C, 3587, 1), (0x1D5D, 3588, 1),
+    (0x1D5E, 3589, 1), (0x1D5F, 3590, 1), (0x1D60, 3591, 1), (0x1D61, 3592, 1),
+    (0x1D62, 3593, 1), (0x1D63, 3594, 1), (0x1D64, 3595, 1), (0x1D65, 3596, 1),
+    (0x1D66, 3597, 1), (0x1D67, 3598, 1), (0x1D68, 3599, 1), (0x1D69, 3600, 1),
+    (0x1D6A, 3601, 1), (0x1D78, 3602, 1), (0x1D9B, 3603, 1), (0x1D9C, 3604, 1),
+    (0x1D9D, 3605, 1), (0x1D9E, 3606, 1), (0x1D9F, 3607, 1), (0x1DA0, 3608, 1),
+    (0x1DA1, 3609, 1), (0x1DA2, 3610, 1), (0x1DA3, 3611, 1), (0x1DA4, 3612, 1),
+    (0x1DA5, 3613, 1), (0x1DA6, 3614, 1), (0x1DA7, 3615, 1), (0x1DA8, 3616, 1),
+    (0x1DA9, 3617, 1), (0x1DAA, 3618, 1), (0x1DAB, 3619, 1), (0x1DAC, 3620, 1),
+    (0x1DAD, 3621, 1), (0x1DAE, 3622, 1), (0x1DAF, 3623, 1), (0x1DB0, 3624, 1),
+    (0x1DB1, 3625, 1), (0x1DB2, 3626, 1), (0x1DB3, 3627, 1), (0x1DB4, 3628, 1),
+    (0x1DB5, 3629, 1), (0x1DB6, 3630, 1), (0x1DB7, 3631, 1), (0x1DB8, 3632, 1),
+    (0x1DB9, 3633, 1), (0x1DBA, 3634, 1), (0x1DBB, 3635, 1), (0x1DBC, 3636, 1),
+    (0x1DBD, 3637, 1), (0x1DBE, 3638, 1), (0x1DBF, 3639, 1), (0x1E9A, 3640, 2),
+    (0x1E9B, 3642, 2), (0x1FBD, 3644, 2), (0x1FBF, 3646, 2), (0x1FC0, 3648, 2),
+    (0x1FC1, 3650, 3), (0x1FCD, 3653, 3), (0x1FCE, 3656, 3), (0x1FCF, 3659, 3),
+    (0x1FDD, 3662, 3), (0x1FDE, 3665, 3), (0x1FDF, 3668, 3), (0x1FED, 3671, 3),
+    (0x1FEE, 3674, 3), (0x1FFD, 3677, 2), (0x1FFE, 3679, 2), (0x2000, 3681, 1),
+    (0x2001, 3682, 1), (0x2002, 3683, 1), (0x2003, 3684, 1), (0x2004, 3685, 1),
+    (0x2005, 3686, 1), (0x2006, 3687, 1), (0x2007, 3688, 1), (0x2008, 3689, 1),
+    (0x2009, 3690, 1), (0x200A, 3691, 1), (0x2011, 3692, 1), (0x2017, 3693, 2),
+    (0x2024, 3695, 1), (0x2025, 3696, 2), (0x2026, 3698, 3), (0x202F, 3701, 1),
+    (0x2033, 3702, 2), (0x2034, 3704, 3), (0x2036, 3707, 2), (0x2037, 3709, 3),
+    (0x203C, 3712, 2), (0x203E, 3714, 2), (0x2047, 3716, 2), (0x2048, 3718, 2),
+    (0x2049, 3720, 2), (0x2057, 3722, 4), (0x205F, 3726, 1), (0x2070, 3727, 1),
+    (0x2071, 3728, 1), (0x2074, 3729, 1), (0x2075, 3730, 1), (0x2076, 3731, 1),
+    (0x2077, 3732, 1), (0x2078, 3733, 1), (0x2079, 3734, 1), (0x207A, 3735, 1),
+    (0x207B, 3736, 1), (0x207C, 3737, 1), (0x207D, 3738, 1), (0x207E, 3739, 1),
+    (0x207F, 3740, 1), (0x2080, 3741, 1), (0x2081, 3742, 1), (0x2082, 3743, 1),
+    (0x2083, 3744, 1), (0x2084, 3745, 1), (0x2085, 3746, 1), (0x2086, 3747, 1),
+    (0x2087, 3748, 1), (0x2088, 3749, 1), (0x2089, 3750, 1), (0x208A, 3751, 1),
+    (0x208B, 3752, 1), (0x208C, 3753, 1), (0x208D, 3754, 1), (0x208E, 3755, 1),
+    (0x2090, 3756, 1), (0x2091, 3757, 1), (0x2092, 3758, 1), (0x2093, 3759, 1),
+    (0x2094, 3760, 1), (0x2095, 3761, 1), (0x2096, 3762, 1), (0x2097, 3763, 1),
+    (0x2098, 3764, 1), (0x2099, 3765, 1), (0x209A, 3766, 1), (0x209B, 3767, 1),
+    (0x209C, 3768, 1), (0x20A8, 3769, 2), (0x2100, 3771, 3), (0x2101, 3774, 3),
+    (0x2102, 3777, 1), (0x2103, 3778, 2), (0x2105, 3780, 3), (0x2106, 3783, 3),
+    (0x2107, 3786, 1), (0x2109, 3787, 2), (0x210A, 3789, 1), (0x210B, 3790, 1),
+    (0x210C, 3791, 1), (0x210D, 3792, 1), (0x210E, 3793, 1), (0x210F, 3794, 1),
+    (0x2110, 3795, 1), (0x2111, 3796, 1), (0x2112, 3797, 1), (0x2113, 3798, 1),
+    (0x2115, 3799, 1), (0x2116, 3800, 2), (0x2119, 3802, 1), (0x211A, 3803, 1),
+    (0x211B, 3804, 1), (0x211C, 3805, 1), (0x211D, 3806, 1), (0x2120, 3807, 2),
+    (0x2121, 3809, 3), (0x2122, 3812, 2), (0x2124, 3814, 1), (0x2128, 3815, 1),
+    (0x212C, 3816, 1), (0x212D, 3817, 1), (0x212F, 3818, 1), (0x2130, 3819, 1),
+    (0x2131, 3820, 1), (0x2133, 3821, 1), (0x2134, 3822, 1), (0x2135, 3823, 1),
+    (0x2136, 3824, 1), (0x2137, 3825, 1), (0x2138, 3826, 1), (0x2139, 3827, 1),
+    (0x213B, 3828, 3), (0x213C, 3831, 1), (0x213D, 3832, 1), (0x213E, 3833, 1),
+    (0x213F, 3834, 1), (0x2140, 3835, 1), (0x2145, 3836, 1), (0x2146, 3837, 1),
+    (0x2147, 3838, 1), (0x2148, 3839, 1), (0x2149, 3840, 1), (0x2150, 3841, 3),
+    (0x2151, 3844, 3), (0x2152, 3847, 4), (0x2153, 3851, 3), (0x2154, 3854, 3),
+    (0x2155, 3857, 3), (0x2156, 3860, 3), (0x2157, 3863, 3), (0x2158, 3866, 3),
+    (0x2159, 3869, 3), (0x215A, 3872, 3), (0x215B, 3875, 3), (0x215C, 3878, 3),
+    (0x215D, 3881, 3), (0x215E, 3884, 3), (0x215F, 3887, 2), (0x2160, 3889, 1),
+    (0x2161, 3890, 2), (0x2162, 3892, 3), (0x2163, 3895, 2), (0x2164, 3897, 1),
+    (0x2165, 3898, 2), (0x2166, 3900, 3), (0x2167, 3903, 4), (0x2168, 3907, 2),
+    (0x2169, 3909, 1), (0x216A, 3910, 2), (0x216B, 3912, 3), (0x216C, 3915, 1),
+    (0x216D, 3916, 1), (0x216E, 3917, 1), (0x216F, 3918, 1), (0x2170, 3919, 1),
+    (0x2171, 3920, 2), (0x2172, 3922, 3), (0x2173, 3925, 2), (0x2174, 3927, 1),
+    (0x2175, 3928, 2), (0x2176, 3930, 3), (0x2177, 3933, 4), (0x2178, 3937, 2),
+    (0x2179, 3939, 1), (0x217A, 3940, 2), (0x217B, 3942, 3), (0x217C, 3945, 1),
+    (0x217D, 3946, 1), (0x217E, 3947, 1), (0x217F, 3948, 1), (0x2189, 3949, 3),
+    (0x222C, 3952, 2), (0x222D, 3954, 3), (0x222F, 3957, 2), (0x2230, 3959, 3),
+    (0x2460, 3962, 1), (0x2461, 3963, 1), (0x2462, 3964, 1), (0x2463, 3965, 1),
+    (0x2464, 3966, 1), (0x2465, 3967, 1), (0x2466, 3968, 1), (0x2467, 3969, 1),
+    (0x2468, 3970, 1), (0x2469, 3971, 2), (0x246A, 3973, 2), (0x246B, 3975, 2),
+    (0x246C, 3977, 2), (0x246D, 3979, 2), (0x246E, 3981, 2), (0x246F, 3983, 2),
+    (0x2470, 3985, 2), (0x2471, 3987, 2), (0x2472, 3989, 2), (0x2473, 3991, 2),
+    (0x2474, 3993, 3), (0x2475, 3996, 3), (0x2476, 3999, 3), (0x2477, 4002, 3),
+    (0x2478, 4005, 3), (0x2479, 4008, 3), (0x247A, 4011, 3), (0x247B, 4014, 3),
+    (0x247C, 4017, 3), (0x247D, 4020, 4), (0x247E, 4024, 4), (0x247F, 4028, 4),
+    (0x2480, 4032, 4), (0x2481, 4036, 4), (0x2482, 4040, 4), (0x2483, 4044, 4),
+    (0x2484, 4048, 4), (0x2485, 4052, 4), (0x2486, 4056, 4), (0x2487, 4060, 4),
+    (0x2488, 4064, 2), (0x2489, 4066, 2), (0x248A, 4068, 2), (0x248B, 4070, 2),
+    (0x248C, 4072, 2), (0x248D, 4074, 2), (0x248E, 4076, 2), (0x248F, 4078, 2),
+    (0x2490, 4080, 2), (0x2491, 4082, 3), (0x2492, 4085, 3), (0x2493, 4088, 3),
+    (0x2494, 4091, 3), (0x2495, 4094, 3), (0x2496, 4097, 3), (0x2497, 4100, 3),
+    (0x2498, 4103, 3), (0x2499, 4106, 3), (0x249A, 4109, 3), (0x249B, 4112, 3),
+    (0x249C, 4115, 3), (0x249D, 4118, 3), (0x249E, 4121, 3), (0x249F, 4124, 3),
+    (0x24A0, 4127, 3), (0x24A1, 4130, 3), (0x24A2, 4133, 3), (0x24A3, 4136, 3),
+    (0x24A4, 4139, 3), (0x24A5, 4142, 3), (0x24A6, 4145, 3), (0x24A7, 4148, 3),
+    (0x24A8, 4151, 3), (0x24A9, 4154, 3), (0x24AA, 4157, 3), (0x24AB, 4160, 3),
+    (0x24AC, 4163, 3), (0x24AD, 4166, 3), (0x24AE, 4169, 3), (0x24AF, 4172, 3),
+    (0x24B0, 4175, 3), (0x24B1, 4178, 3), (0x24B2, 4181, 3), (0x24B3, 4184, 3),
+    (0x24B4, 4187, 3), (0x24B5, 4190, 3), (0x24B6, 4193, 1), (0x24B7, 4194, 1),
+    (0x24B8, 4195, 1), (0x24B9, 4196, 1), (0x24BA, 4197, 1), (0x24BB, 4198, 1),
+    (0x24BC, 4199, 1), (0x24BD, 4200, 1), (0x24BE, 4201, 1), (0x24BF, 4202, 1),
+    (0x24C0, 4203, 1), (0x24C1, 4204, 1), (0x24C2, 4205, 1), (0x24C3, 4206, 1),
+    (0x24C4, 4207, 1), (0x24C5, 4208, 1), (0x24C6, 4209, 1), (0x24C7, 4210, 1),
+    (0x24C8, 4211, 1), (0x24C9, 4212, 1), (0x24CA, 4213, 1), (0x24CB, 4214, 1),
+    (0x24CC, 4215, 1), (0x24CD, 4216, 1), (0x24CE, 4217, 1), (0x24CF, 4218, 1),
+    (0x24D0, 4219, 1), (0x24D1, 4220, 1), (0x24D2, 4221, 1), (0x24D3, 4222, 1),
+    (0x24D4, 4223, 1), (0x24D5, 4224, 1), (0x24D6, 4225, 1), (0x24D7, 4226, 1),
+    (0x24D8, 4227, 1), (0x24D9, 4228, 1), (0x24DA, 4229, 1), (0x24DB, 4230, 1),
+    (0x24DC, 4231, 1), (0x24DD, 4232, 1), (0x24DE, 4233, 1), (0x24DF, 4234, 1),
+    (0x24E0, 4235, 1), (0x24E1, 4236, 1), (0x24E2, 4237, 1), (0x24E3, 4238, 1),
+    (0x24E4, 4239, 1), (0x24E5, 4240, 1), (0x24E6, 4241, 1), (0x24E7, 4242, 1),
+    (0x24E8, 4243, 1), (0x24E9, 4244, 1), (0x24EA, 4245, 1), (0x2A0C, 4246, 4),
+    (0x2A74, 4250, 3), (0x2A75, 4253, 2), (0x2A76, 4255, 3), (0x2C7C, 4258, 1),
+    (0x2C7D, 4259, 1), (0x2D6F, 4260, 1), (0x2E9F, 4261, 1), (0x2EF3, 4262, 1),
+    (0x2F00, 4263, 1), (0x2F01, 4264, 1), (0x2F02, 4265, 1), (0x2F03, 4266, 1),
+    (0x2F04, 4267, 1), (0x2F05, 4268, 1), (0x2F06, 4269, 1), (0x2F07, 4270, 1),
+    (0x2F08, 4271, 1), (0x2F09, 4272, 1), (0x2F0A, 4273, 1), (0x2F0B, 4274, 1),
+    (0x2F0C, 4275, 1), (0x2F0D, 4276, 1), (0x2F0E, 4277, 1), (0x2F0F, 4278, 1),
+    (0x2F10, 4279, 1), (0x2F11, 4280, 1), (0x2F12, 4281, 1), (0x2F13, 4282, 1),
+    (0x2F14, 4283, 1), (0x2F15, 4284, 1), (0x2F16, 4285, 1), (0x2F17, 4286, 1),
+    (0x2F18, 4287, 1), (0x2F19, 4288, 1), (0x2F1A, 4289, 1), (0x2F1B, 4290, 1),
+    (0x2F1C, 4291, 1), (0x2F1D, 4292, 1), (0x2F1E, 4293, 1), (0x2F1F, 4294, 1),
+    (0x2F20, 4295, 1), (0x2F21, 4296, 1), (0x2F22, 4297, 1), (0x2F23, 4298, 1),
+    (0x2F24, 4299, 1), (0x2F25, 4300, 1), (0x2F26, 4301, 1), (0x2F27, 4302, 1),
+    (0x2F28, 4303, 1), (0x2F29, 4304, 1), (0x2F2A, 4305, 1), (0x2F2B, 4306, 1),
+    (0x2F2C, 4307, 1), (0x2F2D, 4308, 1), (0x2F2E, 4309, 1), (0x2F2F, 4310, 1),
+    (0x2F30, 4311, 1), (0x2F31, 4312, 1), (0x2F32, 4313, 1), (0x2F33, 4314, 1),
+    (0x2F34, 4315, 1), (0x2F35, 4316, 1), (0x2F36, 4317, 1), (0x2F37, 4318, 1),
+    (0x2F38, 4319, 1), (0x2F39, 4320, 1), (0x2F3A, 4321, 1), (0x2F3B, 4322, 1),
+    (0x2F3C, 4323, 1), (0x2F3D, 4324, 1), (0x2F3E, 4325, 1), (0x2F3F, 4326, 1),
+    (0x2F40, 4327, 1), (0x2F41, 4328, 1), (0x2F42, 4329, 1), (0x2F43, 4330, 1),
+    (0x2F44, 4331, 1), (0x2F45, 4332, 1), (0x2F46, 4333, 1), (0x2F47, 4334, 1),
+    (0x2F48, 4335, 1), (0x2F49, 4336, 1), (0x2F4A, 4337, 1), (0x2F4B, 4338, 1),
+    (0x2F4C, 4339, 1), (0x2F4D, 4340, 1), (0x2F4E, 4341, 1), (0x2F4F, 4342, 1),
+    (0x2F50, 4343, 1), (0x2F51, 4344, 1), (0x2F52, 4345, 1), (0x2F53, 4346, 1),
+    (0x2F54, 4347, 1), (0x2F55, 4348, 1), (0x2F56, 4349, 1), (0x2F57, 4350, 1),
+    (0x2F58, 4351, 1), (0x2F59, 4352, 1), (0x2F5A, 4353, 1), (0x2F5B, 4354, 1),
+    (0x2F5C, 4355, 1), (0x2F5D, 4356, 1), (0x2F5E, 4357, 1), (0x2F5F, 4358, 1),
+    (0x2F60, 4359, 1), (0x2F61, 4360, 1), (0x2F62, 4361, 1), (0x2F63, 4362, 1),
+    (0x2F64, 4363, 1), (0x2F65, 4364, 1), (0x2F66, 4365, 1), (0x2F67, 4366, 1),
+    (0x2F68, 4367, 1), (0x2F69, 4368, 1), (0x2F6A, 4369, 1), (0x2F6B, 4370, 1),
+    (0x2F6C, 4371, 1), (0x2F6D, 4372, 1), (0x2F6E, 4373, 1), (0x2F6F, 4374, 1),
+    (0x2F70, 4375, 1), (0x2F71, 4376, 1), (0x2F72, 4377, 1), (0x2F73, 4378, 1),
+    (0x2F74, 4379, 1), (0x2F75, 4380, 1), (0x2F76, 4381, 1), (0x2F77, 4382, 1),
+    (0x2F78, 4383, 1), (0x2F79, 4384, 1), (0x2F7A, 4385, 1), (0x2F7B, 4386, 1),
+    (0x2F7C, 4387, 1), (0x2F7D, 4388, 1), (0x2F7E, 4389, 1), (0x2F7F, 4390, 1),
+    (0x2F80, 4391, 1), (0x2F81, 4392, 1), (0x2F82, 4393, 1), (0x2F83, 4394, 1),
+    (0x2F84, 4395, 1), (0x2F85, 4396, 1), (0x2F86, 4397, 1), (0x2F87, 4398, 1),
+    (0x2F88, 4399, 1), (0x2F89, 4400, 1), (0x2F8A, 4401, 1), (0x2F8B, 4402, 1),
+    (0x2F8C, 4403, 1), (0x2F8D, 4404, 1), (0x2F8E, 4405, 1), (0x2F8F, 4406, 1),
+    (0x2F90, 4407, 1), (0x2F91, 4408, 1), (0x2F92, 4409, 1), (0x2F93, 4410, 1),
+    (0x2F94, 4411, 1), (0x2F95, 4412, 1), (0x2F96, 4413, 1), (0x2F97, 4414, 1),
+    (0x2F98, 4415, 1), (0x2F99, 4416, 1), (0x2F9A, 4417, 1), (0x2F9B, 4418, 1),
+    (0x2F9C, 4419, 1), (0x2F9D, 4420, 1), (0x2F9E, 4421, 1), (0x2F9F, 4422, 1),
+    (0x2FA0, 4423, 1), (0x2FA1, 4424, 1), (0x2FA2, 4425, 1), (0x2FA3, 4426, 1),
+    (0x2FA4, 4427, 1), (0x2FA5, 4428, 1), (0x2FA6, 4429, 1), (0x2FA7, 4430, 1),
+    (0x2FA8, 4431, 1), (0x2FA9, 4432, 1), (0x2FAA, 4433, 1), (0x2FAB, 4434, 1),
+    (0x2FAC, 4435, 1), (0x2FAD, 4436, 1), (0x2FAE, 4437, 1), (0x2FAF, 4438, 1),
+    (0x2FB0, 4439, 1), (0x2FB1, 4440, 1), (0x2FB2, 4441, 1), (0x2FB3, 4442, 1),
+    (0x2FB4, 4443, 1), (0x2FB5, 4444, 1), (0x2FB6, 4445, 1), (0x2FB7, 4446, 1),
+    (0x2FB8, 4447, 1), (0x2FB9, 4448, 1), (0x2FBA, 4449, 1), (0x2FBB, 4450, 1),
+    (0x2FBC, 4451, 1), (0x2FBD, 4452, 1), (0x2FBE, 4453, 1), (0x2FBF, 4454, 1),
+    (0x2FC0, 4455, 1), (0x2FC1, 4456, 1), (0x2FC2, 4457, 1), (0x2FC3, 4458, 1),
+    (0x2FC4, 4459, 1), (0x2FC5, 4460, 1), (0x2FC6, 4461, 1), (0x2FC7, 4462, 1),
+    (0x2FC8, 4463, 1), (0x2FC9, 4464, 1), (0x2FCA, 4465, 1), (0x2FCB, 4466, 1),
+    (0x2FCC, 4467, 1), (0x2FCD, 4468, 1), (0x2FCE, 4469, 1), (0x2FCF, 4470, 1),
+    (0x2FD0, 4471, 1), (0x2FD1, 4472, 1), (0x2FD2, 4473, 1), (0x2FD3, 4474, 1),
+    (0x2FD4, 4475, 1), (0x2FD5, 4476, 1), (0x3000, 4477, 1), (0x3036, 4478, 1),
+    (0x3038, 4479, 1), (0x3039, 4480, 1), (0x303A, 4481, 1), (0x309B, 4482, 2),
+    (0x309C, 4484, 2), (0x309F, 4486, 2), (0x30FF, 4488, 2), (0x3131, 4490, 1),
+    (0x3132, 4491, 1), (0x3133, 4492, 1), (0x3134, 4493, 1), (0x3135, 4494, 1),
+    (0x3136, 4495, 1), (0x3137, 4496, 1), (0x3138, 4497, 1), (0x3139, 4498, 1),
+    (0x313A, 4499, 1), (0x313B, 4500, 1), (0x313C, 4501, 1), (0x313D, 4502, 1),
+    (0x313E, 4503, 1), (0x313F, 4504, 1), (0x3140, 4505, 1), (0x3141, 4506, 1),
+    (0x3142, 4507, 1), (0x3143, 4508, 1), (0x3144, 4509, 1), (0x3145, 4510, 1),
+    (0x3146, 4511, 1), (0x3147, 4512, 1), (0x3148, 4513, 1), (0x3149, 4514, 1),
+    (0x314A, 4515, 1), (0x314B, 4516, 1), (0x314C, 4517, 1), (0x314D, 4518, 1),
+    (0x314E, 4519, 1), (0x314F, 4520, 1), (0x3150, 4521, 1), (0x3151, 4522, 1),
+    (0x3152, 4523, 1), (0x3153, 4524, 1), (0x3154, 4525, 1), (0x3155, 4526, 1),
+    (0x3156, 4527, 1), (0x3157, 4528, 1), (0x3158, 4529, 1), (0x3159, 4530, 1),
+    (0x315A, 4531, 1), (0x315B, 4532, 1), (0x315C, 4533, 1), (0x315D, 4534, 1),
+    (0x315E, 4535, 1), (0x315F, 4536, 1), (0x3160, 4537, 1), (0x3161, 4538, 1),
+    (0x3162, 4539, 1), (0x3163, 4540, 1), (0x3164, 4541, 1), (0x3165, 4542, 1),
+    (0x3166, 4543, 1), (0x3167, 4544, 1), (0x3168, 4545, 1), (0x3169, 4546, 1),
+    (0x316A, 4547, 1), (0x316B, 4548, 1), (0x316C, 4549, 1), (0x316D, 4550, 1),
+    (0x316E, 4551, 1), (0x316F, 4552, 1), (0x3170, 4553, 1), (0x3171, 4554, 1),
+    (0x3172, 4555, 1), (0x3173, 4556, 1), (0x3174, 4557, 1), (0x3175, 4558, 1),
+    (0x3176, 4559, 1), (0x3177, 4560, 1), (0x3178, 4561, 1), (0x3179, 4562, 1),
+    (0x317A, 4563, 1), (0x317B, 4564, 1), (0x317C, 4565, 1), (0x317D, 4566, 1),
+    (0x317E, 4567, 1), (0x317F, 4568, 1), (0x3180, 4569, 1), (0x3181, 4570, 1),
+    (0x3182, 4571, 1), (0x3183, 4572, 1), (0x3184, 4573, 1), (0x3185, 4574, 1),
+    (0x3186, 4575, 1), (0x3187, 4576, 1), (0x3188, 4577, 1), (0x3189, 4578, 1),
+    (0x318A, 4579, 1), (0x318B, 4580, 1), (0x318C, 4581, 1), (0x318D, 4582, 1),
+    (0x318E, 4583, 1), (0x3192, 4584, 1), (0x3193, 4585, 1), (0x3194, 4586, 1),
+    (0x3195, 4587, 1), (0x3196, 4588, 1), (0x3197, 4589, 1), (0x3198, 4590, 1),
+    (0x3199, 4591, 1), (0x319A, 4592, 1), (0x319B, 4593, 1), (0x319C, 4594, 1),
+    (0x319D, 4595, 1), (0x319E, 4596, 1), (0x319F, 4597, 1), (0x3200, 4598, 3),
+    (0x3201, 4601, 3), (0x3202, 4604, 3), (0x3203, 4607, 3), (0x3204, 4610, 3),
+    (0x3205, 4613, 3), (0x3206, 4616, 3), (0x3207, 4619, 3), (0x3208, 4622, 3),
+    (0x3209, 4625, 3), (0x320A, 4628, 3), (0x320B, 4631, 3), (0x320C, 4634, 3),
+    (0x320D, 4637, 3), (0x320E, 4640, 4), (0x320F, 4644, 4), (0x3210, 4648, 4),
+    (0x3211, 4652, 4), (0x3212, 4656, 4), (0x3213, 4660, 4), (0x3214, 4664, 4),
+    (0x3215, 4668, 4), (0x3216, 4672, 4), (0x3217, 4676, 4), (0x3218, 4680, 4),
+    (0x3219, 4684, 4), (0x321A, 4688, 4), (0x321B, 4692, 4), (0x321C, 4696, 4),
+    (0x321D, 4700, 7), (0x321E, 4707, 6), (0x3220, 4713, 3), (0x3221, 4716, 3),
+    (0x3222, 4719, 3), (0x3223, 4722, 3), (0x3224, 4725, 3), (0x3225, 4728, 3),
+    (0x3226, 4731, 3), (0x3227, 4734, 3), (0x3228, 4737, 3), (0x3229, 4740, 3),
+    (0x322A, 4743, 3), (0x322B, 4746, 3), (0x322C, 4749, 3), (0x322D, 4752, 3),
+    (0x322E, 4755, 3), (0x322F, 4758, 3), (0x3230, 4761, 3), (0x3231, 4764, 3),
+    (0x3232, 4767, 3), (0x3233, 4770, 3), (0x3234, 4773, 3), (0x3235, 4776, 3),
+    (0x3236, 4779, 3), (0x3237, 4782, 3), (0x3238, 4785, 3), (0x3239, 4788, 3),
+    (0x323A, 4791, 3), (0x323B, 4794, 3), (0x323C, 4797, 3), (0x323D, 4800, 3),
+    (0x323E, 4803, 3), (0x323F, 4806, 3), (0x3240, 4809, 3), (0x3241, 4812, 3),
+    (0x3242, 4815, 3), (0x3243, 4818, 3), (0x3244, 4821, 1), (0x3245, 4822, 1),
+    (0x3246, 4823, 1), (0x3247, 4824, 1), (0x3250, 4825, 3), (0x3251, 4828, 2),
+    (0x3252, 4830, 2), (0x3253, 4832, 2), (0x3254, 4834, 2), (0x3255, 4836, 2),
+    (0x3256, 4838, 2), (0x3257, 4840, 2), (0x3258, 4842, 2), (0x3259, 4844, 2),
+    (0x325A, 4846, 2), (0x325B, 4848, 2), (0x325C, 4850, 2), (0x325D, 4852, 2),
+    (0x325E, 4854, 2), (0x325F, 4856, 2), (0x3260, 4858, 1), (0x3261, 4859, 1),
+    (0x3262, 4860, 1), (0x3263, 4861, 1), (0x3264, 4862, 1), (0x3265, 4863, 1),
+    (0x3266, 4864, 1), (0x3267, 4865, 1), (0x3268, 4866, 1), (0x3269, 4867, 1),
+    (0x326A, 4868, 1), (0x326B, 4869, 1), (0x326C, 4870, 1), (0x326D, 4871, 1),
+    (0x326E, 4872, 2), (0x326F, 4874, 2), (0x3270, 4876, 2), (0x3271, 4878, 2),
+    (0x3272, 4880, 2), (0x3273, 4882, 2), (0x3274, 4884, 2), (0x3275, 4886, 2),
+    (0x3276, 4888, 2), (0x3277, 4890, 2), (0x3278, 4892, 2), (0x3279, 4894, 2),
+    (0x327A, 4896, 2), (0x327B, 4898, 2), (0x327C, 4900, 5), (0x327D, 4905, 4),
+    (0x327E, 4909, 2), (0x3280, 4911, 1), (0x3281, 4912, 1), (0x3282, 4913, 1),
+    (0x3283, 4914, 1), (0x3284, 4915, 1), (0x3285, 4916, 1), (0x3286, 4917, 1),
+    (0x3287, 4918, 1), (0x3288, 4919, 1), (0x3289, 4920, 1), (0x328A, 4921, 1),
+    (0x328B, 4922, 1), (0x328C, 4923, 1), (0x328D, 4924, 1), (0x328E, 4925, 1),
+    (0x328F, 4926, 1), (0x3290, 4927, 1), (0x3291, 4928, 1), (0x3292, 4929, 1),
+    (0x3293, 4930, 1), (0x3294, 4931, 1), (0x3295, 4932, 1), (0x3296, 4933, 1),
+    (0x3297, 4934, 1), (0x3298, 4935, 1), (0x3299, 4936, 1), (0x329A, 4937, 1),
+    (0x329B, 4938, 1), (0x329C, 4939, 1), (0x329D, 4940, 1), (0x329E, 4941, 1),
+    (0x329F, 4942, 1), (0x32A0, 4943, 1), (0x32A1, 4944, 1), (0x32A2, 4945, 1),
+    (0x32A3, 4946, 1), (0x32A4, 4947, 1), (0x32A5, 4948, 1), (0x32A6, 4949, 1),
+    (0x32A7, 4950, 1), (0x32A8, 4951, 1), (0x32A9, 4952, 1), (0x32AA, 4953, 1),
+    (0x32AB, 4954, 1), (0x32AC, 4955, 1), (0x32AD, 4956, 1), (0x32AE, 4957, 1),
+    (0x32AF, 4958, 1), (0x32B0, 4959, 1), (0x32B1, 4960, 2), (0x32B2, 4962, 2),
+    (0x32B3, 4964, 2), (0x32B4, 4966, 2), (0x32B5, 4968, 2), (0x32B6, 4970, 2),
+    (0x32B7, 4972, 2), (0x32B8, 4974, 2), (0x32B9, 4976, 2), (0x32BA, 4978, 2),
+    (0x32BB, 4980, 2), (0x32BC, 4982, 2), (0x32BD, 4984, 2), (0x32BE, 4986, 2),
+    (0x32BF, 4988, 2), (0x32C0, 4990, 2), (0x32C1, 4992, 2), (0x32C2, 4994, 2),
+    (0x32C3, 4996, 2), (0x32C4, 4998, 2), (0x32C5, 5000, 2), (0x32C6, 5002, 2),
+    (0x32C7, 5004, 2), (0x32C8, 5006, 2), (0x32C9, 5008, 3), (0x32CA, 5011, 3),
+    (0x32CB, 5014, 3), (0x32CC, 5017, 2), (0x32CD, 5019, 3), (0x32CE, 5022, 2),
+    (0x32CF, 5024, 3), (0x32D0, 5027, 1), (0x32D1, 5028, 1), (0x32D2, 5029, 1),
+    (0x32D3, 5030, 1), (0x32D4, 5031, 1), (0x32D5, 5032, 1), (0x32D6, 5033, 1),
+    (0x32D7, 5034, 1), (0x32D8, 5035, 1), (0x32D9, 5036, 1), (0x32DA, 5037, 1),
+    (0x32DB, 5038, 1), (0x32DC, 5039, 1), (0x32DD, 5040, 1), (0x32DE, 5041, 1),
+    (0x32DF, 5042, 1), (0x32E0, 5043, 1), (0x32E1, 5044, 1), (0x32E2, 5045, 1),
+    (0x32E3, 5046, 1), (0x32E4, 5047, 1), (0x32E5, 5048, 1), (0x32E6, 5049, 1),
+    (0x32E7, 5050, 1), (0x32E8, 5051, 1), (0x32E9, 5052, 1), (0x32EA, 5053, 1),
+    (0x32EB, 5054, 1), (0x32EC, 5055, 1), (0x32ED, 5056, 1), (0x32EE, 5057, 1),
+    (0x32EF, 5058, 1), (0x32F0, 5059, 1), (0x32F1, 5060, 1), (0x32F2, 5061, 1),
+    (0x32F3, 5062, 1), (0x32F4, 5063, 1), (0x32F5, 5064, 1), (0x32F6, 5065, 1),
+    (0x32F7, 5066, 1), (0x32F8, 5067, 1), (0x32F9, 5068, 1), (0x32FA, 5069, 1),
+    (0x32FB, 5070, 1), (0x32FC, 5071, 1), (0x32FD, 5072, 1), (0x32FE, 5073, 1),
+    (0x32FF, 5074, 2), (0x3300, 5076, 5), (0x3301, 5081, 4), (0x3302, 5085, 5),
+    (0x3303, 5090, 3), (0x3304, 5093, 5), (0x3305, 5098, 3), (0x3306, 5101, 3),
+    (0x3307, 5104, 6), (0x3308, 5110, 4), (0x3309, 5114, 3), (0x330A, 5117, 3),
+    (0x330B, 5120, 3), (0x330C, 5123, 4), (0x330D, 5127, 4), (0x330E, 5131, 4),
+    (0x330F, 5135, 4), (0x3310, 5139, 4), (0x3311, 5143, 4), (0x3312, 5147, 4),
+    (0x3313, 5151, 6), (0x3314, 5157, 2), (0x3315, 5159, 6), (0x3316, 5165, 6),
+    (0x3317, 5171, 5), (0x3318, 5176, 4), (0x3319, 5180, 6), (0x331A, 5186, 6),
+    (0x331B, 5192, 4), (0x331C, 5196, 3), (0x331D, 5199, 3), (0x331E, 5202, 4),
+    (0x331F, 5206, 4), (0x3320, 5210, 5), (0x3321, 5215, 5), (0x3322, 5220, 3),
+    (0x3323, 5223, 3), (0x3324, 5226, 4), (0x3325, 5230, 3), (0x3326, 5233, 3),
+    (0x3327, 5236, 2), (0x3328, 5238, 2), (0x3329, 5240, 3), (0x332A, 5243, 3),
+    (0x332B, 5246, 6), (0x332C, 5252, 4), (0x332D, 5256, 5), (0x332E, 5261, 6),
+    (0x332F, 5267, 4), (0x3330, 5271, 3), (0x3331, 5274, 3), (0x3332, 5277, 6),
+    (0x3333, 5283, 4), (0x3334, 5287, 6), (0x3335, 5293, 3), (0x3336, 5296, 5),
+    (0x3337, 5301, 3), (0x3338, 5304, 4), (0x3339, 5308, 3), (0x333A, 5311, 4),
+    (0x333B, 5315, 5), (0x333C, 5320, 4), (0x333D, 5324, 5), (0x333E, 5329, 4),
+    (0x333F, 5333, 2), (0x3340, 5335, 5), (0x3341, 5340, 3), (0x3342, 5343, 3),
+    (0x3343, 5346, 4), (0x3344, 5350, 3), (0x3345, 5353, 3), (0x3346, 5356, 3),
+    (0x3347, 5359, 5), (0x3348, 5364, 4), (0x3349, 5368, 2), (0x334A, 5370, 6),
+    (0x334B, 5376, 3), (0x334C, 5379, 5), (0x334D, 5384, 4), (0x334E, 5388, 4),
+    (0x334F, 5392, 3), (0x3350, 5395, 3), (0x3351, 5398, 4), (0x3352, 5402, 2),
+    (0x3353, 5404, 4), (0x3354, 5408, 5), (0x3355, 5413, 2), (0x3356, 5415, 6),
+    (0x3357, 5421, 3), (0x3358, 5424, 2), (0x3359, 5426, 2), (0x335A, 5428, 2),
+    (0x335B, 5430, 2), (0x335C, 5432, 2), (0x335D, 5434, 2), (0x335E, 5436, 2),
+    (0x335F, 5438, 2), (0x3360, 5440, 2), (0x3361, 5442, 2), (0x3362, 5444, 3),
+    (0x3363, 5447, 3), (0x3364, 5450, 3), (0x3365, 5453, 3), (0x3366, 5456, 3),
+    (0x3367, 5459, 3), (0x3368, 5462, 3), (0x3369, 5465, 3), (0x336A, 5468, 3),
+    (0x336B, 5471, 3), (0x336C, 5474, 3), (0x336D, 5477, 3), (0x336E, 5480, 3),
+    (0x336F, 5483, 3), (0x3370, 5486, 3), (0x3371, 5489, 3), (0x3372, 5492, 2),
+    (0x3373, 5494, 2), (0x3374, 5496, 3), (0x3375, 5499, 2), (0x3376, 5501, 2),
+    (0x3377, 5503, 2), (0x3378, 5505, 3), (0x3379, 5508, 3), (0x337A, 5511, 2),
+    (0x337B, 5513, 2), (0x337C, 5515, 2), (0x337D, 5517, 2), (0x337E, 5519, 2),
+    (0x337F, 5521, 4), (0x3380, 5525, 2), (0x3381, 5527, 2), (0x3382, 5529, 2),
+    (0x3383, 5531, 2), (0x3384, 5533, 2), (0x3385, 5535, 2), (0x3386, 5537, 2),
+    (0x3387, 5539, 2), (0x3388, 5541, 3), (0x3389, 5544, 4), (0x338A, 5548, 2),
+    (0x338B, 5550, 2), (0x338C, 5552, 2), (0x338D, 5554, 2), (0x338E, 5556, 2),
+    (0x338F, 5558, 2), (0x3390, 5560, 2), (0x3391, 5562, 3), (0x3392, 5565, 3),
+    (0x3393, 5568, 3), (0x3394, 5571, 3), (0x3395, 5574, 2), (0x3396, 5576, 2),
+    (0x3397, 5578, 2), (0x3398, 5580, 2), (0x3399, 5582, 2), (0x339A, 5584, 2),
+    (0x339B, 5586, 2), (0x339C, 5588, 2), (0x339D, 5590, 2), (0x339E, 5592, 2),
+    (0x339F, 5594, 3), (0x33A0, 5597, 3), (0x33A1, 5600, 2), (0x33A2, 5602, 3),
+    (0x33A3, 5605, 3), (0x33A4, 5608, 3), (0x33A5, 5611, 2), (0x33A6, 5613, 3),
+    (0x33A7, 5616, 3), (0x33A8, 5619, 4), (0x33A9, 5623, 2), (0x33AA, 5625, 3),
+    (0x33AB, 5628, 3), (0x33AC, 5631, 3), (0x33AD, 5634, 3), (0x33AE, 5637, 5),
+    (0x33AF, 5642, 6), (0x33B0, 5648, 2), (0x33B1, 5650, 2), (0x33B2, 5652, 2),
+    (0x33B3, 5654, 2), (0x33B4, 5656, 2), (0x33B5, 5658, 2), (0x33B6, 5660, 2),
+    (0x33B7, 5662, 2), (0x33B8, 5664, 2), (0x33B9, 5666, 2), (0x33BA, 5668, 2),
+    (0x33BB, 5670, 2), (0x33BC, 5672, 2), (0x33BD, 5674, 2), (0x33BE, 5676, 2),
+    (0x33BF, 5678, 2), (0x33C0, 5680, 2), (0x33C1, 5682, 2), (0x33C2, 5684, 4),
+    (0x33C3, 5688, 2), (0x33C4, 5690, 2), (0x33C5, 5692, 2), (0x33C6, 5694, 4),
+    (0x33C7, 5698, 3), (0x33C8, 5701, 2), (0x33C9, 5703, 2), (0x33CA, 5705, 2),
+    (0x33CB, 5707, 2), (0x33CC, 5709, 2), (0x33CD, 5711, 2), (0x33CE, 5713, 2),
+    (0x33CF, 5715, 2), (0x33D0, 5717, 2), (0x33D1, 5719, 2), (0x33D2, 5721, 3),
+    (0x33D3, 5724, 2), (0x33D4, 5726, 2), (0x33D5, 5728, 3), (0x33D6, 5731, 3),
+    (0x33D7, 5734, 2), (0x33D8, 5736, 4), (0x33D9, 5740, 3), (0x33DA, 5743, 2),
+    (0x33DB, 5745, 2), (0x33DC, 5747, 2), (0x33DD, 5749, 2), (0x33DE, 5751, 3),
+    (0x33DF, 5754, 3), (0x33E0, 5757, 2), (0x33E1, 5759, 2), (0x33E2, 5761, 2),
+    (0x33E3, 5763, 2), (0x33E4, 5765, 2), (0x33E5, 5767, 2), (0x33E6, 5769, 2),
+    (0x33E7, 5771, 2), (0x33E8, 5773, 2), (0x33E9, 5775, 3), (0x33EA, 5778, 3),
+    (0x33EB, 5781, 3), (0x33EC, 5784, 3), (0x33ED, 5787, 3), (0x33EE, 5790, 3),
+    (0x33EF, 5793, 3), (0x33F0, 5796, 3), (0x33F1, 5799, 3), (0x33F2, 5802, 3),
+    (0x33F3, 5805, 3), (0x33F4, 5808, 3), (0x33F5, 5811, 3), (0x33F6, 5814, 3),
+    (0x33F7, 5817, 3), (0x33F8, 5820, 3), (0x33F9, 5823, 3), (0x33FA, 5826, 3),
+    (0x33FB, 5829, 3), (0x33FC, 5832, 3), (0x33FD, 5835, 3), (0x33FE, 5838, 3),
+    (0x33FF, 5841, 3), (0xA69C, 5844, 1), (0xA69D, 5845, 1), (0xA770, 5846, 1),
+    (0xA7F2, 5847, 1), (0xA7F3, 5848, 1), (0xA7F4, 5849, 1), (0xA7F8, 5850, 1),
+    (0xA7F9, 5851, 1), (0xAB5C, 5852, 1), (0xAB5D, 5853, 1), (0xAB5E, 5854, 1),
+    (0xAB5F, 5855, 1), (0xAB69, 5856, 1), (0xFB00, 5857, 2), (0xFB01, 5859, 2),
+    (0xFB02, 5861, 2), (0xFB03, 5863, 3), (0xFB04, 5866, 3), (0xFB05, 5869, 2),
+    (0xFB06, 5871, 2), (0xFB13, 5873, 2), (0xFB14, 5875, 2), (0xFB15, 5877, 2),
+    (0xFB16, 5879, 2), (0xFB17, 5881, 2), (0xFB20, 5883, 1), (0xFB21, 5884, 1),
+    (0xFB22, 5885, 1), (0xFB23, 5886, 1), (0xFB24, 5887, 1), (0xFB25, 5888, 1),
+    (0xFB26, 5889, 1), (0xFB27, 5890, 1), (0xFB28, 5891, 1), (0xFB29, 5892, 1),
+    (0xFB4F, 5893, 2), (0xFB50, 5895, 1), (0xFB51, 5896, 1), (0xFB52, 5897, 1),
+    (0xFB53, 5898, 1), (0xFB54, 5899, 1), (0xFB55, 5900, 1), (0xFB56, 5901, 1),
+    (0xFB57, 5902, 1), (0xFB58, 5903, 1), (0xFB59, 5904, 1), (0xFB5A, 5905, 1),
+    (0xFB5B, 5906, 1), (0xFB5C, 5907, 1), (0xFB5D, 5908, 1), (0xFB5E, 5909, 1),
+    (0xFB5F, 5910, 1), (0xFB60, 5911, 1), (0xFB61, 5912, 1), (0xFB62, 5913, 1),
+    (0xFB63, 5914, 1), (0xFB64, 5915, 1), (0xFB65, 5916, 1), (0xFB66, 5917, 1),
+    (0xFB67, 5918, 1), (0xFB68, 5919, 1), (0xFB69, 5920, 1), (0xFB6A, 5921, 1),
+    (0xFB6B, 5922, 1), (0xFB6C, 5923, 1), (0xFB6D, 5924, 1), (0xFB6E, 5925, 1),
+    (0xFB6F, 5926, 1), (0xFB70, 5927, 1), (0xFB71, 5928, 1), (0xFB72, 5929, 1),
+    (0xFB73, 5930, 1), (0xFB74, 5931, 1), (0xFB75, 5932, 1), (0xFB76, 5933, 1),
+    (0xFB77, 5934, 1), (0xFB78, 5935, 1), (0xFB79, 5936, 1), (0xFB7A, 5937, 1),
+    (0xFB7B, 5938, 1), (0xFB7C, 5939, 1), (0xFB7D, 5940, 1), (0xFB7E, 5941, 1),
+    (0xFB7F, 5942, 1), (0xFB80, 5943, 1), (0xFB81, 5944, 1), (0xFB82, 5945, 1),
+    (0xFB83, 5946, 1), (0xFB84, 5947, 1), (0xFB85, 5948, 1), (0xFB86, 5949, 1),
+    (0xFB87, 5950, 1), (0xFB88, 5951, 1), (0xFB89, 5952, 1), (0xFB8A, 5953, 1),
+    (0xFB8B, 5954, 1), (0xFB8C, 5955, 1), (0xFB8D, 5956, 1), (0xFB8E, 5957, 1),
+    (0xFB8F, 5958, 1), (0xFB90, 5959, 1), (0xFB91, 5960, 1), (0xFB92, 5961, 1),
+    (0xFB93, 5962, 1), (0xFB94, 5963, 1), (0xFB95, 5964, 1), (0xFB96, 5965, 1),
+    (0xFB97, 5966, 1), (0xFB98, 5967, 1), (0xFB99, 5968, 1), (0xFB9A, 5969, 1),
+    (0xFB9B, 5970, 1), (0xFB9C, 5971, 1), (0xFB9D, 5972, 1), (0xFB9E, 5973, 1),
+    (0xFB9F, 5974, 1), (0xFBA0, 5975, 1), (0xFBA1, 5976, 1), (0xFBA2, 5977, 1),
+    (0xFBA3, 5978, 1), (0xFBA4, 5979, 2), (0xFBA5, 5981, 2), (0xFBA6, 5983, 1),
+    (0xFBA7, 5984, 1), (0xFBA8, 5985, 1), (0xFBA9, 5986, 1), (0xFBAA, 5987, 1),
+    (0xFBAB, 5988, 1), (0xFBAC, 5989, 1), (0xFBAD, 5990, 1), (0xFBAE, 5991, 1),
+    (0xFBAF, 5992, 1), (0xFBB0, 5993, 2), (0xFBB1, 5995, 2), (0xFBD3, 5997, 1),
+    (0xFBD4, 5998, 1), (0xFBD5, 5999, 1), (0xFBD6, 6000, 1), (0xFBD7, 6001, 1),
+    (0xFBD8, 6002, 1), (0xFBD9, 6003, 1), (0xFBDA, 6004, 1), (0xFBDB, 6005, 1),
+    (0xFBDC, 6006, 1), (0xFBDD, 6007, 2), (0xFBDE, 6009, 1), (0xFBDF, 6010, 1),
+    (0xFBE0, 6011, 1), (0xFBE1, 6012, 1), (0xFBE2, 6013, 1), (0xFBE3, 6014, 1),
+    (0xFBE4, 6015, 1), (0xFBE5, 6016, 1), (0xFBE6, 6017, 1), (0xFBE7, 6018, 1),
+    (0xFBE8, 6019, 1), (0xFBE9, 6020, 1), (0xFBEA, 6021, 3), (0xFBEB, 6024, 3),
+    (0xFBEC, 6027, 3), (0xFBED, 6030, 3), (0xFBEE, 6033, 3), (0xFBEF, 6036, 3),
+    (0xFBF0, 6039, 3), (0xFBF1, 6042, 3), (0xFBF2, 6045, 3), (0xFBF3, 6048, 3),
+    (0xFBF4, 6051, 3), (0xFBF5, 6054, 3), (0xFBF6, 6057, 3), (0xFBF7, 6060, 3),
+    (0xFBF8, 6063, 3), (0xFBF9, 6066, 3), (0xFBFA, 6069, 3), (0xFBFB, 6072, 3),
+    (0xFBFC, 6075, 1), (0xFBFD, 6076, 1), (0xFBFE, 6077, 1), (0xFBFF, 6078, 1),
+    (0xFC00, 6079, 3), (0xFC01, 6082, 3), (0xFC02, 6085, 3), (0xFC03, 6088, 3),
+    (0xFC04, 6091, 3), (0xFC05, 6094, 2), (0xFC06, 6096, 2), (0xFC07, 6098, 2),
+    (0xFC08, 6100, 2), (0xFC09, 6102, 2), (0xFC0A, 6104, 2), (0xFC0B, 6106, 2),
+    (0xFC0C, 6108, 2), (0xFC0D, 6110, 2), (0xFC0E, 6112, 2), (0xFC0F, 6114, 2),
+    (0xFC10, 6116, 2), (0xFC11, 6118, 2), (0xFC12, 6120, 2), (0xFC13, 6122, 2),
+    (0xFC14, 6124, 2), (0xFC15, 6126, 2), (0xFC16, 6128, 2), (0xFC17, 6130, 2),
+    (0xFC18, 6132, 2), (0xFC19, 6134, 2), (0xFC1A, 6136, 2), (0xFC1B, 6138, 2),
+    (0xFC1C, 6140, 2), (0xFC1D, 6142, 2), (0xFC1E, 6144, 2), (0xFC1F, 6146, 2),
+    (0xFC20, 6148, 2), (0xFC21, 6150, 2), (0xFC22, 6152, 2), (0xFC23, 6154, 2),
+    (0xFC24, 6156, 2), (0xFC25, 6158, 2), (0xFC26, 6160, 2), (0xFC27, 6162, 2),
+    (0xFC28, 6164, 2), (0xFC29, 6166, 2), (0xFC2A, 6168, 2), (0xFC2B, 6170, 2),
+    (0xFC2C, 6172, 2), (0xFC2D, 6174, 2), (0xFC2E, 6176, 2), (0xFC2F, 6178, 2),
+    (0xFC30, 6180, 2), (0xFC31, 6182, 2), (0xFC32, 6184, 2), (0xFC33, 6186, 2),
+    (0xFC34, 6188, 2), (0xFC35, 6190, 2), (0xFC36, 6192, 2), (0xFC37, 6194, 2),
+    (0xFC38, 6196, 2), (0xFC39, 6198, 2), (0xFC3A, 6200, 2), (0xFC3B, 6202, 2),
+    (0xFC3C, 6204, 2), (0xFC3D, 6206, 2), (0xFC3E, 6208, 2), (0xFC3F, 6210, 2),
+    (0xFC40, 6212, 2), (0xFC41, 6214, 2), (0xFC42, 6216, 2), (0xFC43, 6218, 2),
+    (0xFC44, 6220, 2), (0xFC45, 6222, 2), (0xFC46, 6224, 2), (0xFC47, 6226, 2),
+    (0xFC48, 6228, 2), (0xFC49, 6230, 2), (0xFC4A, 6232, 2), (0xFC4B, 6234, 2),
+    (0xFC4C, 6236, 2), (0xFC4D, 6238, 2), (0xFC4E, 6240, 2), (0xFC4F, 6242, 2),
+    (0xFC50, 6244, 2), (0xFC51, 6246, 2), (0xFC52, 6248, 2), (0xFC53, 6250, 2),
+    (0xFC54, 6252, 2), (0xFC55, 6254, 2), (0xFC56, 6256, 2), (0xFC57, 6258, 2),
+    (0xFC58, 6260, 2), (0xFC59, 6262, 2), (0xFC5A, 6264, 2), (0xFC5B, 6266, 2),
+    (0xFC5C, 6268, 2), (0xFC5D, 6270, 2), (0xFC5E, 6272, 3), (0xFC5F, 6275, 3),
+    (0xFC60, 6278, 3), (0xFC61, 6281, 3), (0xFC62, 6284, 3), (0xFC63, 6287, 3),
+    (0xFC64, 6290, 3), (0xFC65, 6293, 3), (0xFC66, 6296, 3), (0xFC67, 6299, 3),
+    (0xFC68, 6302, 3), (0xFC69, 6305, 3), (0xFC6A, 6308, 2), (0xFC6B, 6310, 2),
+    (0xFC6C, 6312, 2), (0xFC6D, 6314, 2), (0xFC6E, 6316, 2), (0xFC6F, 6318, 2),
+    (0xFC70, 6320, 2), (0xFC71, 6322, 2), (0xFC72, 6324, 2), (0xFC73, 6326, 2),
+    (0xFC74, 6328, 2), (0xFC75, 6330, 2), (0xFC76, 6332, 2), (0xFC77, 6334, 2),
+    (0xFC78, 6336, 2), (0xFC79, 6338, 2), (0xFC7A, 6340, 2), (0xFC7B, 6342, 2),
+    (0xFC7C, 6344, 2), (0xFC7D, 6346, 2), (0xFC7E, 6348, 2), (0xFC7F, 6350, 2),
+    (0xFC80, 6352, 2), (0xFC81, 6354, 2), (0xFC82, 6356, 2), (0xFC83, 6358, 2),
+    (0xFC84, 6360, 2), (0xFC85, 6362, 2), (0xFC86, 6364, 2), (0xFC87, 6366, 2),
+    (0xFC88, 6368, 2), (0xFC89, 6370, 2), (0xFC8A, 6372, 2), (0xFC8B, 6374, 2),
+    (0xFC8C, 6376, 2), (0xFC8D, 6378, 2), (0xFC8E, 6380, 2), (0xFC8F, 6382, 2),
+    (0xFC90, 6384, 2), (0xFC91, 6386, 2), (0xFC92, 6388, 2), (0xFC93, 6390, 2),
+    (0xFC94, 6392, 2), (0xFC95, 6394, 2), (0xFC96, 6396, 2), (0xFC97, 6398, 3),
+    (0xFC98, 6401, 3), (0xFC99, 6404, 3), (0xFC9A, 6407, 3), (0xFC9B, 6410, 3),
+    (0xFC9C, 6413, 2), (0xFC9D, 6415, 2), (0xFC9E, 6417, 2), (0xFC9F, 6419, 2),
+    (0xFCA0, 6421, 2), (0xFCA1, 6423, 2), (0xFCA2, 6425, 2), (0xFCA3, 6427, 2),
+    (0xFCA4, 6429, 2), (0xFCA5, 6431, 2), (0xFCA6, 6433, 2), (0xFCA7, 6435, 2),
+    (0xFCA8, 6437, 2), (0xFCA9, 6439, 2), (0xFCAA, 6441, 2), (0xFCAB, 6443, 2),
+    (0xFCAC, 6445, 2), (0xFCAD, 6447, 2), (0xFCAE, 6449, 2), (0xFCAF, 6451, 2),
+    (0xFCB0, 6453, 2), (0xFCB1, 6455, 2), (0xFCB2, 6457, 2), (0xFCB3, 6459, 2),
+    (0xFCB4, 6461, 2), (0xFCB5, 6463, 2), (0xFCB6, 6465, 2), (0xFCB7, 6467, 2),
+    (0xFCB8, 6469, 2), (0xFCB9, 6471, 2), (0xFCBA, 6473, 2), (0xFCBB, 6475, 2),
+    (0xFCBC, 6477, 2), (0xFCBD, 6479, 2), (0xFCBE, 6481, 2), (0xFCBF, 6483, 2),
+    (0xFCC0, 6485, 2), (0xFCC1, 6487, 2), (0xFCC2, 6489, 2), (0xFCC3, 6491, 2),
+    (0xFCC4, 6493, 2), (0xFCC5, 6495, 2), (0xFCC6, 6497, 2), (0xFCC7, 6499, 2),
+    (0xFCC8, 6501, 2), (0xFCC9, 6503, 2), (0xFCCA, 6505, 2), (0xFCCB, 6507, 2),
+    (0xFCCC, 6509, 2), (0xFCCD, 6511, 2), (0xFCCE, 6513, 2), (0xFCCF, 6515, 2),
+    (0xFCD0, 6517, 2), (0xFCD1, 6519, 2), (0xFCD2, 6521, 2), (0xFCD3, 6523, 2),
+    (0xFCD4, 6525, 2), (0xFCD5, 6527, 2), (0xFCD6, 6529, 2), (0xFCD7, 6531, 2),
+    (0xFCD8, 6533, 2), (0xFCD9, 6535, 2), (0xFCDA, 6537, 2), (0xFCDB, 6539, 2),
+    (0xFCDC, 6541, 2), (0xFCDD, 6543, 2), (0xFCDE, 6545, 2), (0xFCDF, 6547, 3),
+    (0xFCE0, 6550, 3), (0xFCE1, 6553, 2), (0xFCE2, 6555, 2), (0xFCE3, 6557, 2),
+    (0xFCE4, 6559, 2), (0xFCE5, 6561, 2), (0xFCE6, 6563, 2), (0xFCE7, 6565, 2),
+    (0xFCE8, 6567, 2), (0xFCE9, 6569, 2), (0xFCEA, 6571, 2), (0xFCEB, 6573, 2),
+    (0xFCEC, 6575, 2), (0xFCED, 6577, 2), (0xFCEE, 6579, 2), (0xFCEF, 6581, 2),
+    (0xFCF0, 6583, 2), (0xFCF1, 6585, 2), (0xFCF2, 6587, 3), (0xFCF3, 6590, 3),
+    (0xFCF4, 6593, 3), (0xFCF5, 6596, 2), (0xFCF6, 6598, 2), (0xFCF7, 6600, 2),
+    (0xFCF8, 6602, 2), (0xFCF9, 6604, 2), (0xFCFA, 6606, 2), (0xFCFB, 6608, 2),
+    (0xFCFC, 6610, 2), (0xFCFD, 6612, 2), (0xFCFE, 6614, 2), (0xFCFF, 6616, 2),
+    (0xFD00, 6618, 2), (0xFD01, 6620, 2), (0xFD02, 6622, 2), (0xFD03, 6624, 2),
+    (0xFD04, 6626, 2), (0xFD05, 6628, 2), (0xFD06, 6630, 2), (0xFD07, 6632, 2),
+    (0xFD08, 6634, 2), (0xFD09, 6636, 2), (0xFD0A, 6638, 2), (0xFD0B, 6640, 2),
+    (0xFD0C, 6642, 2), (0xFD0D, 6644, 2), (0xFD0E, 6646, 2), (0xFD0F, 6648, 2),
+    (0xFD10, 6650, 2), (0xFD11, 6652, 2), (0xFD12, 6654, 2), (0xFD13, 6656, 2),
+    (0xFD14, 6658, 2), (0xFD15, 6660, 2), (0xFD16, 6662, 2), (0xFD17, 6664, 2),
+    (0xFD18, 6666, 2), (0xFD19, 6668, 2), (0xFD1A, 6670, 2), (0xFD1B, 6672, 2),
+    (0xFD1C, 6674, 2), (0xFD1D, 6676, 2), (0xFD1E, 6678, 2), (0xFD1F, 6680, 2),
+    (0xFD20, 6682, 2), (0xFD21, 6684, 2), (0xFD22, 6686, 2), (0xFD23, 6688, 2),
+    (0xFD24, 6690, 2), (0xFD25, 6692, 2), (0xFD26, 6694, 2), (0xFD27, 6696, 2),
+    (0xFD28, 6698, 2), (0xFD29, 6700, 2), (0xFD2A, 6702, 2), (0xFD2B, 6704, 2),
+    (0xFD2C, 6706, 2), (0xFD2D, 6708, 2), (0xFD2E, 6710, 2), (0xFD2F, 6712, 2),
+    (0xFD30, 6714, 2), (0xFD31, 6716, 2), (0xFD32, 6718, 2), (0xFD33, 6720, 2),
+    (0xFD34, 6722, 2), (0xFD35, 6724, 2), (0xFD36, 6726, 2), (0xFD37, 6728, 2),
+    (0xFD38, 6730, 2), (0xFD39, 6732, 2), (0xFD3A, 6734, 2), (0xFD3B, 6736, 2),
+    (0xFD3C, 6738, 2), (0xFD3D, 6740, 2), (0xFD50, 6742, 3), (0xFD51, 6745, 3),
+    (0xFD52, 6748, 3), (0xFD53, 6751, 3), (0xFD54, 6754, 3), (0xFD55, 6757, 3),
+    (0xFD56, 6760, 3), (0xFD57, 6763, 3), (0xFD58, 6766, 3), (0xFD59, 6769, 3),
+    (0xFD5A, 6772, 3), (0xFD5B, 6775, 3), (0xFD5C, 6778, 3), (0xFD5D, 6781, 3),
+    (0xFD5E, 6784, 3), (0xFD5F, 6787, 3), (0xFD60, 6790, 3), (0xFD61, 6793, 3),
+    (0xFD62, 6796, 3), (0xFD63, 6799, 3), (0xFD64, 6802, 3), (0xFD65, 6805, 3),
+    (0xFD66, 6808, 3), (0xFD67, 6811, 3), (0xFD68, 6814, 3), (0xFD69, 6817, 3),
+    (0xFD6A, 6820, 3), (0xFD6B, 6823, 3), (0xFD6C, 6826, 3), (0xFD6D, 6829, 3),
+    (0xFD6E, 6832, 3), (0xFD6F, 6835, 3), (0xFD70, 6838, 3), (0xFD71, 6841, 3),
+    (0xFD72, 6844, 3), (0xFD73, 6847, 3), (0xFD74, 6850, 3), (0xFD75, 6853, 3),
+    (0xFD76, 6856, 3), (0xFD77, 6859, 3), (0xFD78, 6862, 3), (0xFD79, 6865, 3),
+    (0xFD7A, 6868, 3), (0xFD7B, 6871, 3), (0xFD7C, 6874, 3), (0xFD7D, 6877, 3),
+    (0xFD7E, 6880, 3), (0xFD7F, 6883, 3), (0xFD80, 6886, 3), (0xFD81, 6889, 3),
+    (0xFD82, 6892, 3), (0xFD83, 6895, 3), (0xFD84, 6898, 3), (0xFD85, 6901, 3),
+    (0xFD86, 6904, 3), (0xFD87, 6907, 3), (0xFD88, 6910, 3), (0xFD89, 6913, 3),
+    (0xFD8A, 6916, 3), (0xFD8B, 6919, 3), (0xFD8C, 6922, 3), (0xFD8D, 6925, 3),
+    (0xFD8E, 6928, 3), (0xFD8F, 6931, 3), (0xFD92, 6934, 3), (0xFD93, 6937, 3),
+    (0xFD94, 6940, 3), (0xFD95, 6943, 3), (0xFD96, 6946, 3), (0xFD97, 6949, 3),
+    (0xFD98, 6952, 3), (0xFD99, 6955, 3), (0xFD9A, 6958, 3), (0xFD9B, 6961, 3),
+    (0xFD9C, 6964, 3), (0xFD9D, 6967, 3), (0xFD9E, 6970, 3), (0xFD9F, 6973, 3),
+    (0xFDA0, 6976, 3), (0xFDA1, 6979, 3), (0xFDA2, 6982, 3), (0xFDA3, 6985, 3),
+    (0xFDA4, 6988, 3), (0xFDA5, 6991, 3), (0xFDA6, 6994, 3), (0xFDA7, 6997, 3),
+    (0xFDA8, 7000, 3), (0xFDA9, 7003, 3), (0xFDAA, 7006, 3), (0xFDAB, 7009, 3),
+    (0xFDAC, 7012, 3), (0xFDAD, 7015, 3), (0xFDAE, 7018, 3), (0xFDAF, 7021, 3),
+    (0xFDB0, 7024, 3), (0xFDB1, 7027, 3), (0xFDB2, 7030, 3), (0xFDB3, 7033, 3),
+    (0xFDB4, 7036, 3), (0xFDB5, 7039, 3), (0xFDB6, 7042, 3), (0xFDB7, 7045, 3),
+    (0xFDB8, 7048, 3), (0xFDB9, 7051, 3), (0xFDBA, 7054, 3), (0xFDBB, 7057, 3),
+    (0xFDBC, 7060, 3), (0xFDBD, 7063, 3), (0xFDBE, 7066, 3), (0xFDBF, 7069, 3),
+    (0xFDC0, 7072, 3), (0xFDC1, 7075, 3), (0xFDC2, 7078, 3), (0xFDC3, 7081, 3),
+    (0xFDC4, 7084, 3), (0xFDC5, 7087, 3), (0xFDC6, 7090, 3), (0xFDC7, 7093, 3),
+    (0xFDF0, 7096, 3), (0xFDF1, 7099, 3), (0xFDF2, 7102, 4), (0xFDF3, 7106, 4),
+    (0xFDF4, 7110, 4), (0xFDF5, 7114, 4), (0xFDF6, 7118, 4), (0xFDF7, 7122, 4),
+    (0xFDF8, 7126, 4), (0xFDF9, 7130, 3), (0xFDFA, 7133, 18), (0xFDFB, 7151, 8),
+    (0xFDFC, 7159, 4), (0xFE10, 7163, 1), (0xFE11, 7164, 1), (0xFE12, 7165, 1),
+    (0xFE13, 7166, 1), (0xFE14, 7167, 1), (0xFE15, 7168, 1), (0xFE16, 7169, 1),
+    (0xFE17, 7170, 1), (0xFE18, 7171, 1), (0xFE19, 7172, 3), (0xFE30, 7175, 2),
+    (0xFE31, 7177, 1), (0xFE32, 7178, 1), (0xFE33, 7179, 1), (0xFE34, 7180, 1),
+    (0xFE35, 7181, 1), (0xFE36, 7182, 1), (0xFE37, 7183, 1), (0xFE38, 7184, 1),
+    (0xFE39, 7185, 1), (0xFE3A, 7186, 1), (0xFE3B, 7187, 1), (0xFE3C, 7188, 1),
+    (0xFE3D, 7189, 1), (0xFE3E, 7190, 1), (0xFE3F, 7191, 1), (0xFE40, 7192, 1),
+    (0xFE41, 7193, 1), (0xFE42, 7194, 1), (0xFE43, 7195, 1), (0xFE44, 7196, 1),
+    (0xFE47, 7197, 1), (0xFE48, 7198, 1), (0xFE49, 7199, 2), (0xFE4A, 7201, 2),
+    (0xFE4B, 7203, 2), (0xFE4C, 7205, 2), (0xFE4D, 7207, 1), (0xFE4E, 7208, 1),
+    (0xFE4F, 7209, 1), (0xFE50, 7210, 1), (0xFE51, 7211, 1), (0xFE52, 7212, 1),
+    (0xFE54, 7213, 1), (0xFE55, 7214, 1), (0xFE56, 7215, 1), (0xFE57, 7216, 1),
+    (0xFE58, 7217, 1), (0xFE59, 7218, 1), (0xFE5A, 7219, 1), (0xFE5B, 7220, 1),
+    (0xFE5C, 7221, 1), (0xFE5D, 7222, 1), (0xFE5E, 7223, 1), (0xFE5F, 7224, 1),
+    (0xFE60, 7225, 1), (0xFE61, 7226, 1), (0xFE62, 7227, 1), (0xFE63, 7228, 1),
+    (0xFE64, 7229, 1), (0xFE65, 7230, 1), (0xFE66, 7231, 1), (0xFE68, 7232, 1),
+    (0xFE69, 7233, 1), (0xFE6A, 7234, 1), (0xFE6B, 7235, 1), (0xFE70, 7236, 2),
+    (0xFE71, 7238, 2), (0xFE72, 7240, 2), (0xFE74, 7242, 2), (0xFE76, 7244, 2),
+    (0xFE77, 7246, 2), (0xFE78, 7248, 2), (0xFE79, 7250, 2), (0xFE7A, 7252, 2),
+    (0xFE7B, 7254, 2), (0xFE7C, 7256, 2), (0xFE7D, 7258, 2), (0xFE7E, 7260, 2),
+    (0xFE7F, 7262, 2), (0xFE80, 7264, 1), (0xFE81, 7265, 2), (0xFE82, 7267, 2),
+    (0xFE83, 7269, 2), (0xFE84, 7271, 2), (0xFE85, 7273, 2), (0xFE86, 7275, 2),
+    (0xFE87, 7277, 2), (0xFE88, 7279, 2), (0xFE89, 7281, 2), (0xFE8A, 7283, 2),
+    (0xFE8B, 7285, 2), (0xFE8C, 7287, 2), (0xFE8D, 7289, 1), (0xFE8E, 7290, 1),
+    (0xFE8F, 7291, 1), (0xFE90, 7292, 1), (0xFE91, 7293, 1), (0xFE92, 7294, 1),
+    (0xFE93, 7295, 1), (0xFE94, 7296, 1), (0xFE95, 7297, 1), (0xFE96, 7298, 1),
+    (0xFE97, 7299, 1), (0xFE98, 7300, 1), (0xFE99, 7301, 1), (0xFE9A, 7302, 1),
+    (0xFE9B, 7303, 1), (0xFE9C, 7304, 1), (0xFE9D, 7305, 1), (0xFE9E, 7306, 1),
+    (0xFE9F, 7307, 1), (0xFEA0, 7308, 1), (0xFEA1, 7309, 1), (0xFEA2, 7310, 1),
+    (0xFEA3, 7311, 1), (0xFEA4, 7312, 1), (0xFEA5, 7313, 1), (0xFEA6, 7314, 1),
+    (0xFEA7, 7315, 1), (0xFEA8, 7316, 1), (0xFEA9, 7317, 1), (0xFEAA, 7318, 1),
+    (0xFEAB, 7319, 1), (0xFEAC, 7320, 1), (0xFEAD, 7321, 1), (0xFEAE, 7322, 1),
+    (0xFEAF, 7323, 1), (0xFEB0, 7324, 1), (0xFEB1, 7325, 1), (0xFEB2, 7326, 1),
+    (0xFEB3, 7327, 1), (0xFEB4, 7328, 1), (0xFEB5, 7329, 1), (0xFEB6, 7330, 1),
+    (0xFEB7, 7331, 1), (0xFEB8, 7332, 1), (0xFEB9, 7333, 1), (0xFEBA, 7334, 1),
+    (0xFEBB, 7335, 1), (0xFEBC, 7336, 1), (0xFEBD, 7337, 1), (0xFEBE, 7338, 1),
+    (0xFEBF, 7339, 1), (0xFEC0, 7340, 1), (0xFEC1, 7341, 1), (0xFEC2, 7342, 1),
+    (0xFEC3, 7343, 1), (0xFEC4, 7344, 1), (0xFEC5, 7345, 1), (0xFEC6, 7346, 1),
+    (0xFEC7, 7347, 1), (0xFEC8, 7348, 1), (0xFEC9, 7349, 1), (0xFECA, 7350, 1),
+    (0xFECB, 7351, 1), (0xFECC, 7352, 1), (0xFECD, 7353, 1), (0xFECE, 7354, 1),
+    (0xFECF, 7355, 1), (0xFED0, 7356, 1), (0xFED1, 7357, 1), (0xFED2, 7358, 1),
+    (0xFED3, 7359, 1), (0xFED4, 7360, 1), (0xFED5, 7361, 1), (0xFED6, 7362, 1),
+    (0xFED7, 7363, 1), (0xFED8, 7364, 1), (0xFED9, 7365, 1), (0xFEDA, 7366, 1),
+    (0xFEDB, 7367, 1), (0xFEDC, 7368, 1), (0xFEDD, 7369, 1), (0xFEDE, 7370, 1),
+    (0xFEDF, 7371, 1), (0xFEE0, 7372, 1), (0xFEE1, 7373, 1), (0xFEE2, 7374, 1),
+    (0xFEE3, 7375, 1), (0xFEE4, 7376, 1), (0xFEE5, 7377, 1), (0xFEE6, 7378, 1),
+    (0xFEE7, 7379, 1), (0xFEE8, 7380, 1), (0xFEE9, 7381, 1), (0xFEEA, 7382, 1),
+    (0xFEEB, 7383, 1), (0xFEEC, 7384, 1), (0xFEED, 7385, 1), (0xFEEE, 7386, 1),
+    (0xFEEF, 7387, 1), (0xFEF0, 7388, 1), (0xFEF1, 7389, 1), (0xFEF2, 7390, 1),
+    (0xFEF3, 7391, 1), (0xFEF4, 7392, 1), (0xFEF5, 7393, 3), (0xFEF6, 7396, 3),
+    (0xFEF7, 7399, 3), (0xFEF8, 7402, 3), (0xFEF9, 7405, 3), (0xFEFA, 7408, 3),
+    (0xFEFB, 7411, 2), (0xFEFC, 7413, 2), (0xFF01, 7415, 1), (0xFF02, 7416, 1),
+    (0xFF03, 7417, 1), (0xFF04, 7418, 1), (0xFF05, 7419, 1), (0xFF06, 7420, 1),
+    (0xFF07, 7421, 1), (0xFF08, 7422, 1), (0xFF09, 7423, 1), (0xFF0A, 7424, 1),
+    (0xFF0B, 7425, 1), (0xFF0C, 7426, 1), (0xFF0D, 7427, 1), (0xFF0E, 7428, 1),
+    (0xFF0F, 7429, 1), (0xFF10, 7430, 1), (0xFF11, 7431, 1), (0xFF12, 7432, 1),
+    (0xFF13, 7433, 1), (0xFF14, 7434, 1), (0xFF15, 7435, 1), (0xFF16, 7436, 1),
+    (0xFF17, 7437, 1), (0xFF18, 7438, 1), (0xFF19, 7439, 1), (0xFF1A, 7440, 1),
+    (0xFF1B, 7441, 1), (0xFF1C, 7442, 1), (0xFF1D, 7443, 1), (0xFF1E, 7444, 1),
+    (0xFF1F, 7445, 1), (0xFF20, 7446, 1), (0xFF21, 7447, 1), (0xFF22, 7448, 1),
+    (0xFF23, 7449, 1), (0xFF24, 7450, 1), (0xFF25, 7451, 1), (0xFF26, 7452, 1),
+    (0xFF27, 7453, 1), (0xFF28, 7454, 1), (0xFF29, 7455, 1), (0xFF2A, 7456, 1),
+    (0xFF2B, 7457, 1), (0xFF2C, 7458, 1), (0xFF2D, 7459, 1), (0xFF2E, 7460, 1),
+    (0xFF2F, 7461, 1), (0xFF30, 7462, 1), (0xFF31, 7463, 1), (0xFF32, 7464, 1),
+    (0xFF33, 7465, 1), (0xFF34, 7466, 1), (0xFF35, 7467, 1), (0xFF36, 7468, 1),
+    (0xFF37, 7469, 1), (0xFF38, 7470, 1), (0xFF39, 7471, 1), (0xFF3A, 7472, 1),
+    (0xFF3B, 7473, 1), (0xFF3C, 7474, 1), (0xFF3D, 7475, 1), (0xFF3E, 7476, 1),
+    (0xFF3F, 7477, 1), (0xFF40, 7478, 1), (0xFF41, 7479, 1), (0xFF42, 7480, 1),
+    (0xFF43, 7481, 1), (0xFF44, 7482, 1), (0xFF45, 7483, 1), (0xFF46, 7484, 1),
+    (0xFF47, 7485, 1), (0xFF48, 7486, 1), (0xFF49, 7487, 1), (0xFF4A, 7488, 1),
+    (0xFF4B, 7489, 1), (0xFF4C, 7490, 1), (0xFF4D, 7491, 1), (0xFF4E, 7492, 1),
+    (0xFF4F, 7493, 1), (0xFF50, 7494, 1), (0xFF51, 7495, 1), (0xFF52, 7496, 1),
+    (0xFF53, 7497, 1), (0xFF54, 7498, 1), (0xFF55, 7499, 1), (0xFF56, 7500, 1),
+    (0xFF57, 7501, 1), (0xFF58, 7502, 1), (0xFF59, 7503, 1), (0xFF5A, 7504, 1),
+    (0xFF5B, 7505, 1), (0xFF5C, 7506, 1), (0xFF5D, 7507, 1), (0xFF5E, 7508, 1),
+    (0xFF5F, 7509, 1), (0xFF60, 7510, 1), (0xFF61, 7511, 1), (0xFF62, 7512, 1),
+    (0xFF63, 7513, 1), (0xFF64, 7514, 1), (0xFF65, 7515, 1), (0xFF66, 7516, 1),
+    (0xFF67, 7517, 1), (0xFF68, 7518, 1), (0xFF69, 7519, 1), (0xFF6A, 7520, 1),
+    (0xFF6B, 7521, 1), (0xFF6C, 7522, 1), (0xFF6D, 7523, 1), (0xFF6E, 7524, 1),
+    (0xFF6F, 7525, 1), (0xFF70, 7526, 1), (0xFF71, 7527, 1), (0xFF72, 7528, 1),
+    (0xFF73, 7529, 1), (0xFF74, 7530, 1), (0xFF75, 7531, 1), (0xFF76, 7532, 1),
+    (0xFF77, 7533, 1), (0xFF78, 7534, 1), (0xFF79, 7535, 1), (0xFF7A, 7536, 1),
+    (0xFF7B, 7537, 1), (0xFF7C, 7538, 1), (0xFF7D, 7539, 1), (0xFF7E, 7540, 1),
+    (0xFF7F, 7541, 1), (0xFF80, 7542, 1), (0xFF81, 7543, 1), (0xFF82, 7544, 1),
+    (0xFF83, 7545, 1), (0xFF84, 7546, 1), (0xFF85, 7547, 1), (0xFF86, 7548, 1),
+    (0xFF87, 7549, 1), (0xFF88, 7550, 1), (0xFF89, 7551, 1), (0xFF8A, 7552, 1),
+    (0xFF8B, 7553, 1), (0xFF8C, 7554, 1), (0xFF8D, 7555, 1), (0xFF8E, 7556, 1),
+    (0xFF8F, 7557, 1), (0xFF90, 7558, 1), (0xFF91, 7559, 1), (0xFF92, 7560, 1),
+    (0xFF93, 7561, 1), (0xFF94, 7562, 1), (0xFF95, 7563, 1), (0xFF96, 7564, 1),
+    (0xFF97, 7565, 1), (0xFF98, 7566, 1), (0xFF99, 7567, 1), (0xFF9A, 7568, 1),
+    (0xFF9B, 7569, 1), (0xFF9C, 7570, 1), (0xFF9D, 7571, 1), (0xFF9E, 7572, 1),
+    (0xFF9F, 7573, 1), (0xFFA0, 7574, 1), (0xFFA1, 7575, 1), (0xFFA2, 7576, 1),
+    (0xFFA3, 7577, 1), (0xFFA4, 7578, 1), (0xFFA5, 7579, 1), (0xFFA6, 7580, 1),
+    (0xFFA7, 7581, 1), (0xFFA8, 7582, 1), (0xFFA9, 7583, 1), (0xFFAA, 7584, 1),
+    (0xFFAB, 7585, 1), (0xFFAC, 7586, 1), (0xFFAD, 7587, 1), (0xFFAE, 7588, 1),
+    (0xFFAF, 7589, 1), (0xFFB0, 7590, 1), (0xFFB1, 7591, 1), (0xFFB2, 7592, 1),
+    (0xFFB3, 7593, 1), (0xFFB4, 7594, 1), (0xFFB5, 7595, 1), (0xFFB6, 7596, 1),
+    (0xFFB7, 7597, 1), (0xFFB8, 7598, 1), (0xFFB9, 7599, 1), (0xFFBA, 7600, 1),
+    (0xFFBB, 7601, 1), (0xFFBC, 7602, 1), (0xFFBD, 7603, 1), (0xFFBE, 7604, 1),
+    (0xFFC2, 7605, 1), (0xFFC3, 7606, 1), (0xFFC4, 7607, 1), (0xFFC5, 7608, 1),
+    (0xFFC6, 7609, 1), (0xFFC7, 7610, 1), (0xFFCA, 7611, 1), (0xFFCB, 7612, 1),
+    (0xFFCC, 7613, 1), (0xFFCD, 7614, 1), (0xFFCE, 7615, 1), (0xFFCF, 7616, 1),
+    (0xFFD2, 7617, 1), (0xFFD3, 7618, 1), (0xFFD4, 7619, 1), (0xFFD5, 7620, 1),
+    (0xFFD6, 7621, 1), (0xFFD7, 7622, 1), (0xFFDA, 7623, 1), (0xFFDB, 7624, 1),
+    (0xFFDC, 7625, 1), (0xFFE0, 7626, 1), (0xFFE1, 7627, 1), (0xFFE2, 7628, 1),
+    (0xFFE3, 7629, 2), (0xFFE4, 7631, 1), (0xFFE5, 7632, 1), (0xFFE6, 7633, 1),
+    (0xFFE8, 7634, 1), (0xFFE9, 7635, 1), (0xFFEA, 7636, 1), (0xFFEB, 7637, 1),
+    (0xFFEC, 7638, 1), (0xFFED, 7639, 1), (0xFFEE, 7640, 1), (0x10781, 7641, 1),
+    (0x10782, 7642, 1), (0x10783, 7643, 1), (0x10784, 7644, 1), (0x10785, 7645, 1),
+    (0x10787, 7646, 1), (0x10788, 7647, 1), (0x10789, 7648, 1), (0x1078A, 7649, 1),
+    (0x1078B, 7650, 1), (0x1078C, 7651, 1), (0x1078D, 7652, 1), (0x1078E, 7653, 1),
+    (0x1078F, 7654, 1), (0x10790, 7655, 1), (0x10791, 7656, 1), (0x10792, 7657, 1),
+    (0x10793, 7658, 1), (0x10794, 7659, 1), (0x10795, 7660, 1), (0x10796, 7661, 1),
+    (0x10797, 7662, 1), (0x10798, 7663, 1), (0x10799, 7664, 1), (0x1079A, 7665, 1),
+    (0x1079B, 7666, 1), (0x1079C, 7667, 1), (0x1079D, 7668, 1), (0x1079E, 7669, 1),
+    (0x1079F, 7670, 1), (0x107A0, 7671, 1), (0x107A1, 7672, 1), (0x107A2, 7673, 1),
+    (0x107A3, 7674, 1), (0x107A4, 7675, 1), (0x107A5, 7676, 1), (0x107A6, 7677, 1),
+    (0x107A7, 7678, 1), (0x107A8, 7679, 1), (0x107A9, 7680, 1), (0x107AA, 7681, 1),
+    (0x107AB, 7682, 1), (0x107AC, 7683, 1), (0x107AD, 7684, 1), (0x107AE, 7685, 1),
+    (0x107AF, 7686, 1), (0x107B0, 7687, 1), (0x107B2, 7688, 1), (0x107B3, 7689, 1),
+    (0x107B4, 7690, 1), (0x107B5, 7691, 1), (0x107B6, 7692, 1), (0x107B7, 7693, 1),
+    (0x107B8, 7694, 1), (0x107B9, 7695, 1), (0x107BA, 7696, 1), (0x1D400, 7697, 1),
+    (0x1D401, 7698, 1), (0x1D402, 7699, 1), (0x1D403, 7700, 1), (0x1D404, 7701, 1),
+    (0x1D405, 7702, 1), (0x1D406, 7703, 1), (0x1D407, 7704, 1), (0x1D408, 7705, 1),
+    (0x1D409, 7706, 1), (0x1D40A, 7707, 1), (0x1D40B, 7708, 1), (0x1D40C, 7709, 1),
+    (0x1D40D, 7710, 1), (0x1D40E, 7711, 1), (0x1D40F, 7712, 1), (0x1D410, 7713, 1),
+    (0x1D411, 7714, 1), (0x1D412, 7715, 1), (0x1D413, 7716, 1), (0x1D414, 7717, 1),
+    (0x1D415, 7718, 1), (0x1D416, 7719, 1), (0x1D417, 7720, 1), (0x1D418, 7721, 1),
+    (0x1D419, 7722, 1), (0x1D41A, 7723, 1), (0x1D41B, 7724, 1), (0x1D41C, 7725, 1),
+    (0x1D41D, 7726, 1), (0x1D41E, 7727, 1), (0x1D41F, 7728, 1), (0x1D420, 7729, 1),
+    (0x1D421, 7730, 1), (0x1D422, 7731, 1), (0x1D423, 7732, 1), (0x1D424, 7733, 1),
+    (0x1D425, 7734, 1), (0x1D426, 7735, 1), (0x1D427, 7736, 1), (0x1D428, 7737, 1),
+    (0x1D429, 7738, 1), (0x1D42A, 7739, 1), (0x1D42B, 7740, 1), (0x1D42C, 7741, 1),
+    (0x1D42D, 7742, 1), (0x1D42E, 7743, 1), (0x1D42F, 7744, 1), (0x1D430, 7745, 1),
+    (0x1D431, 7746, 1), (0x1D432, 7747, 1), (0x1D433, 7748, 1), (0x1D434, 7749, 1),
+    (0x1D435, 7750, 1), (0x1D436, 7751, 1), (0x1D437, 7752, 1), (0x1D438, 7753, 1),
+    (0x1D439, 7754, 1), (0x1D43A, 7755, 1), (0x1D43B, 7756, 1), (0x1D43C, 7757, 1),
+    (0x1D43D, 7758, 1), (0x1D43E, 7759, 1), (0x1D43F, 7760, 1), (0x1D440, 7761, 1),
+    (0x1D441, 7762, 1), (0x1D442, 7763, 1), (0x1D443, 7764, 1), (0x1D444, 7765, 1),
+    (0x1D445, 7766, 1), (0x1D446, 7767, 1), (0x1D447, 7768, 1), (0x1D448, 7769, 1),
+    (0x1D449, 7770, 1), (0x1D44A, 7771, 1), (0x1D44B, 7772, 1), (0x1D44C, 7773, 1),
+    (0x1D44D, 7774, 1), (0x1D44E, 7775, 1), (0x1D44F, 7776, 1), (0x1D450, 7777, 1),
+    (0x1D451, 7778, 1), (0x1D452, 7779, 1), (0x1D453, 7780, 1), (0x1D454, 7781, 1),
+    (0x1D456, 7782, 1), (0x1D457, 7783, 1), (0x1D458, 7784, 1), (0x1D459, 7785, 1),
+    (0x1D45A, 7786, 1), (0x1D45B, 7787, 1), (0x1D45C, 7788, 1), (0x1D45D, 7789, 1),
+    (0x1D45E, 7790, 1), (0x1D45F, 7791, 1), (0x1D460, 7792, 1), (0x1D461, 7793, 1),
+    (0x1D462, 7794, 1), (0x1D463, 7795, 1), (0x1D464, 7796, 1), (0x1D465, 7797, 1),
+    (0x1D466, 7798, 1), (0x1D467, 7799, 1), (0x1D468, 7800, 1), (0x1D469, 7801, 1),
+    (0x1D46A, 7802, 1), (0x1D46B, 7803, 1), (0x1D46C, 7804, 1), (0x1D46D, 7805, 1),
+    (0x1D46E, 7806, 1), (0x1D46F, 7807, 1), (0x1D470, 7808, 1), (0x1D471, 7809, 1),
+    (0x1D472, 7810, 1), (0x1D473, 7811, 1), (0x1D474, 7812, 1), (0x1D475, 7813, 1),
+    (0x1D476, 7814, 1), (0x1D477, 7815, 1), (0x1D478, 7816, 1), (0x1D479, 7817, 1),
+    (0x1D47A, 7818, 1), (0x1D47B, 7819, 1), (0x1D47C, 7820, 1), (0x1D47D, 7821, 1),
+    (0x1D47E, 7822, 1), (0x1D47F, 7823, 1), (0x1D480, 7824, 1), (0x1D481, 7825, 1),
+    (0x1D482, 7826, 1), (0x1D483, 7827, 1), (0x1D484, 7828, 1), (0x1D485, 7829, 1),
+    (0x1D486, 7830, 1), (0x1D487, 7831, 1), (0x1D488, 7832, 1), (0x1D489, 7833, 1),
+    (0x1D48A, 7834, 1), (0x1D48B, 7835, 1), (0x1D48C, 7836, 1), (0x1D48D, 7837, 1),
+    (0x1D48E, 7838, 1), (0x1D48F, 7839, 1), (0x1D490, 7840, 1), (0x1D491, 7841, 1),
+    (0x1D492, 7842, 1), (0x1D493, 7843, 1), (0x1D494, 7844, 1), (0x1D495, 7845, 1),
+    (0x1D496, 7846, 1), (0x1D497, 7847, 1), (0x1D498, 7848, 1), (0x1D499, 7849, 1),
+    (0x1D49A, 7850, 1), (0x1D49B, 7851, 1), (0x1D49C, 7852, 1), (0x1D49E, 7853, 1),
+    (0x1D49F, 7854, 1), (0x1D4A2, 7855, 1), (0x1D4A5, 7856, 1), (0x1D4A6, 7857, 1),
+    (0x1D4A9, 7858, 1), (0x1D4AA, 7859, 1), (0x1D4AB, 7860, 1), (0x1D4AC, 7861, 1),
+    (0x1D4AE, 7862, 1), (0x1D4AF, 7863, 1), (0x1D4B0, 7864, 1), (0x1D4B1, 7865, 1),
+    (0x1D4B2, 7866, 1), (0x1D4B3, 7867, 1), (0x1D4B4, 7868, 1), (0x1D4B5, 7869, 1),
+    (0x1D4B6, 7870, 1), (0x1D4B7, 7871, 1), (0x1D4B8, 7872, 1), (0x1D4B9, 7873, 1),
+    (0x1D4BB, 7874, 1), (0x1D4BD, 7875, 1), (0x1D4BE, 7876, 1), (0x1D4BF, 7877, 1),
+    (0x1D4C0, 7878, 1), (0x1D4C1, 7879, 1), (0x1D4C2, 7880, 1), (0x1D4C3, 7881, 1),
+    (0x1D4C5, 7882, 1), (0x1D4C6, 7883, 1), (0x1D4C7, 7884, 1), (0x1D4C8, 7885, 1),
+    (0x1D4C9, 7886, 1), (0x1D4CA, 7887, 1), (0x1D4CB, 7888, 1), (0x1D4CC, 7889, 1),
+    (0x1D4CD, 7890, 1), (0x1D4CE, 7891, 1), (0x1D4CF, 7892, 1), (0x1D4D0, 7893, 1),
+    (0x1D4D1, 7894, 1), (0x1D4D2, 7895, 1), (0x1D4D3, 7896, 1), (0x1D4D4, 7897, 1),
+    (0x1D4D5, 7898, 1), (0x1D4D6, 7899, 1), (0x1D4D7, 7900, 1), (0x1D4D8, 7901, 1),
+    (0x1D4D9, 7902, 1), (0x1D4DA, 7903, 1), (0x1D4DB, 7904, 1), (0x1D4DC, 7905, 1),
+    (0x1D4DD, 7906, 1), (0x1D4DE, 7907, 1), (0x1D4DF, 7908, 1), (0x1D4E0, 7909, 1),
+    (0x1D4E1, 7910, 1), (0x1D4E2, 7911, 1), (0x1D4E3, 7912, 1), (0x1D4E4, 7913, 1),
+    (0x1D4E5, 7914, 1), (0x1D4E6, 7915, 1), (0x1D4E7, 7916, 1), (0x1D4E8, 7917, 1),
+    (0x1D4E9, 7918, 1), (0x1D4EA, 7919, 1), (0x1D4EB, 7920, 1), (0x1D4EC, 7921, 1),
+    (0x1D4ED, 7922, 1), (0x1D4EE, 7923, 1), (0x1D4EF, 7924, 1), (0x1D4F0, 7925, 1),
+    (0x1D4F1, 7926, 1), (0x1D4F2, 7927, 1), (0x1D4F3, 7928, 1), (0x1D4F4, 7929, 1),
+    (0x1D4F5, 7930, 1), (0x1D4F6, 7931, 1), (0x1D4F7, 7932, 1), (0x1D4F8, 7933, 1),
+    (0x1D4F9, 7934, 1), (0x1D4FA, 7935, 1), (0x1D4FB, 7936, 1), (0x1D4FC, 7937, 1),
+    (0x1D4FD, 7938, 1), (0x1D4FE, 7939, 1), (0x1D4FF, 7940, 1), (0x1D500, 7941, 1),
+    (0x1D501, 7942, 1), (0x1D502, 7943, 1), (0x1D503, 7944, 1), (0x1D504, 7945, 1),
+    (0x1D505, 7946, 1), (0x1D507, 7947, 1), (0x1D508, 7948, 1), (0x1D509, 7949, 1),
+    (0x1D50A, 7950, 1), (0x1D50D, 7951, 1), (0x1D50E, 7952, 1), (0x1D50F, 7953, 1),
+    (0x1D510, 7954, 1), (0x1D511, 7955, 1), (0x1D512, 7956, 1), (0x1D513, 7957, 1),
+    (0x1D514, 7958, 1), (0x1D516, 7959, 1), (0x1D517, 7960, 1), (0x1D518, 7961, 1),
+    (0x1D519, 7962, 1), (0x1D51A, 7963, 1), (0x1D51B, 7964, 1), (0x1D51C, 7965, 1),
+    (0x1D51E, 7966, 1), (0x1D51F, 7967, 1), (0x1D520, 7968, 1), (0x1D521, 7969, 1),
+    (0x1D522, 7970, 1), (0x1D523, 7971, 1), (0x1D524, 7972, 1), (0x1D525, 7973, 1),
+    (0x1D526, 7974, 1), (0x1D527, 7975, 1), (0x1D528, 7976, 1), (0x1D529, 7977, 1),
+    (0x1D52A, 7978, 1), (0x1D52B, 7979, 1), (0x1D52C, 7980, 1), (0x1D52D, 7981, 1),
+    (0x1D52E, 7982, 1), (0x1D52F, 7983, 1), (0x1D530, 7984, 1), (0x1D531, 7985, 1),
+    (0x1D532, 7986, 1), (0x1D533, 7987, 1), (0x1D534, 7988, 1), (0x1D535, 7989, 1),
+    (0x1D536, 7990, 1), (0x1D537, 7991, 1), (0x1D538, 7992, 1), (0x1D539, 7993, 1),
+    (0x1D53B, 7994, 1), (0x1D53C, 7995, 1), (0x1D53D, 7996, 1), (0x1D53E, 7997, 1),
+    (0x1D540, 7998, 1), (0x1D541, 7999, 1), (0x1D542, 8000, 1), (0x1D543, 8001, 1),
+    (0x1D544, 8002, 1), (0x1D546, 8003, 1), (0x1D54A, 8004, 1), (0x1D54B, 8005, 1),
+    (0x1D54C, 8006, 1), (0x1D54D, 8007, 1), (0x1D54E, 8008, 1), (0x1D54F, 8009, 1),
+    (0x1D550, 8010, 1), (0x1D552, 8011, 1), (0x1D553, 8012, 1), (0x1D554, 8013, 1),
+    (0x1D555, 8014, 1), (0x1D556, 8015, 1), (0x1D557, 8016, 1), (0x1D558, 8017, 1),
+    (0x1D559, 8018, 1), (0x1D55A, 8019, 1), (0x1D55B, 8020, 1), (0x1D55C, 8021, 1),
+    (0x1D55D, 8022, 1), (0x1D55E, 8023, 1), (0x1D55F, 8024, 1), (0x1D560, 8025, 1),
+    (0x1D561, 8026, 1), (0x1D562, 8027, 1), (0x1D563, 8028, 1), (0x1D564, 8029, 1),
+    (0x1D565, 8030, 1), (0x1D566, 8031, 1), (0x1D567, 8032, 1), (0x1D568, 8033, 1),
+    (0x1D569, 8034, 1), (0x1D56A, 8035, 1), (0x1D56B, 8036, 1), (0x1D56C, 8037, 1),
+    (0x1D56D, 8038, 1), (0x1D56E, 8039, 1), (0x1D56F, 8040, 1), (0x1D570, 8041, 1),
+    (0x1D571, 8042, 1), (0x1D572, 8043, 1), (0x1D573, 8044, 1), (0x1D574, 8045, 1),
+    (0x1D575, 8046, 1), (0x1D576, 8047, 1), (0x1D577, 8048, 1), (0x1D578, 8049, 1),
+    (0x1D579, 8050, 1), (0x1D57A, 8051, 1), (0x1D57B, 8052, 1), (0x1D57C, 8053, 1),
+    (0x1D57D, 8054, 1), (0x1D57E, 8055, 1), (0x1D57F, 8056, 1), (0x1D580, 8057, 1),
+    (0x1D581, 8058, 1), (0x1D582, 8059, 1), (0x1D583, 8060, 1), (0x1D584, 8061, 1),
+    (0x1D585, 8062, 1), (0x1D586, 8063, 1), (0x1D587, 8064, 1), (0x1D588, 8065, 1),
+    (0x1D589, 8066, 1), (0x1D58A, 8067, 1), (0x1D58B, 8068, 1), (0x1D58C, 8069, 1),
+    (0x1D58D, 8070, 1), (0x1D58E, 8071, 1), (0x1D58F, 8072, 1), (0x1D590, 8073, 1),
+    (0x1D591, 8074, 1), (0x1D592, 8075, 1), (0x1D593, 8076, 1), (0x1D594, 8077, 1),
+    (0x1D595, 8078, 1), (0x1D596, 8079, 1), (0x1D597, 8080, 1), (0x1D598, 8081, 1),
+    (0x1D599, 8082, 1), (0x1D59A, 8083, 1), (0x1D59B, 8084, 1), (0x1D59C, 8085, 1),
+    (0x1D59D, 8086, 1), (0x1D59E, 8087, 1), (0x1D59F, 8088, 1), (0x1D5A0, 8089, 1),
+    (0x1D5A1, 8090, 1), (0x1D5A2, 8091, 1), (0x1D5A3, 8092, 1), (0x1D5A4, 8093, 1),
+    (0x1D5A5, 8094, 1), (0x1D5A6, 8095, 1), (0x1D5A7, 8096, 1), (0x1D5A8, 8097, 1),
+    (0x1D5A9, 8098, 1), (0x1D5AA, 8099, 1), (0x1D5AB, 8100, 1), (0x1D5AC, 8101, 1),
+    (0x1D5AD, 8102, 1), (0x1D5AE, 8103, 1), (0x1D5AF, 8104, 1), (0x1D5B0, 8105, 1),
+    (0x1D5B1, 8106, 1), (0x1D5B2, 8107, 1), (0x1D5B3, 8108, 1), (0x1D5B4, 8109, 1),
+    (0x1D5B5, 8110, 1), (0x1D5B6, 8111, 1), (0x1D5B7, 8112, 1), (0x1D5B8, 8113, 1),
+    (0x1D5B9, 8114, 1), (0x1D5BA, 8115, 1), (0x1D5BB, 8116, 1), (0x1D5BC, 8117, 1),
+    (0x1D5BD, 8118, 1), (0x1D5BE, 8119, 1), (0x1D5BF, 8120, 1), (0x1D5C0, 8121, 1),
+    (0x1D5C1, 8122, 1), (0x1D5C2, 8123, 1), (0x1D5C3, 8124, 1), (0x1D5C4, 8125, 1),
+    (0x1D5C5, 8126, 1), (0x1D5C6, 8127, 1), (0x1D5C7, 8128, 1), (0x1D5C8, 8129, 1),
+    (0x1D5C9, 8130, 1), (0x1D5CA, 8131, 1), (0x1D5CB, 8132, 1), (0x1D5CC, 8133, 1),
+    (0x1D5CD, 8134, 1), (0x1D5CE, 8135, 1), (0x1D5CF, 8136, 1), (0x1D5D0, 8137, 1),
+    (0x1D5D1, 8138, 1), (0x1D5D2, 8139, 1), (0x1D5D3, 8140, 1), (0x1D5D4, 8141, 1),
+    (0x1D5D5, 8142, 1), (0x1D5D6, 8143, 1), (0x1D5D7, 8144, 1), (0x1D5D8, 8145, 1),
+    (0x1D5D9, 8146, 1), (0x1D5DA, 8147, 1), (0x1D5DB, 8148, 1), (0x1D5DC, 8149, 1),
+    (0x1D5DD, 8150, 1), (0x1D5DE, 8151, 1), (0x1D5DF, 8152, 1), (0x1D5E0, 8153, 1),
+    (0x1D5E1, 8154, 1), (0x1D5E2, 8155, 1), (0x1D5E3, 8156, 1), (0x1D5E4, 8157, 1),
+    (0x1D5E5, 8158, 1), (0x1D5E6, 8159, 1), (0x1D5E7, 8160, 1), (0x1D5E8, 8161, 1),
+    (0x1D5E9, 8162, 1), (0x1D5EA, 8163, 1), (0x1D5EB, 8164, 1), (0x1D5EC, 8165, 1),
+    (0x1D5ED, 8166, 1), (0x1D5EE, 8167, 1), (0x1D5EF, 8168, 1), (0x1D5F0, 8169, 1),
+    (0x1D5F1, 8170, 1), (0x1D5F2, 8171, 1), (0x1D5F3, 8172, 1), (0x1D5F4, 8173, 1),
+    (0x1D5F5, 8174, 1), (0x1D5F6, 8175, 1), (0x1D5F7, 8176, 1), (0x1D5F8, 8177, 1),
+    (0x1D5F9, 8178, 1), (0x1D5FA, 8179, 1), (0x1D5FB, 8180, 1), (0x1D5FC, 8181, 1),
+    (0x1D5FD, 8182, 1), (0x1D5FE, 8183, 1), (0x1D5FF, 8184, 1), (0x1D600, 8185, 1),
+    (0x1D601, 8186, 1), (0x1D602, 8187, 1), (0x1D603, 8188, 1), (0x1D604, 8189, 1),
+    (0x1D605, 8190, 1), (0x1D606, 8191, 1), (0x1D607, 8192, 1), (0x1D608, 8193, 1),
+    (0x1D609, 8194, 1), (0x1D60A, 8195, 1), (0x1D60B, 8196, 1), (0x1D60C, 8197, 1),
+    (0x1D60D, 8198, 1), (0x1D60E, 8199, 1), (0x1D60F, 8200, 1), (0x1D610, 8201, 1),
+    (0x1D611, 8202, 1), (0x1D612, 8203, 1), (0x1D613, 8204, 1), (0x1D614, 8205, 1),
+    (0x1D615, 8206, 1), (0x1D616, 8207, 1), (0x1D617, 8208, 1), (0x1D618, 8209, 1),
+    (0x1D619, 8210, 1), (0x1D61A, 8211, 1), (0x1D61B, 8212, 1), (0x1D61C, 8213, 1),
+    (0x1D61D, 8214, 1), (0x1D61E, 8215, 1), (0x1D61F, 8216, 1), (0x1D620, 8217, 1),
+    (0x1D621, 8218, 1), (0x1D622, 8219, 1), (0x1D623, 8220, 1), (0x1D624, 8221, 1),
+    (0x1D625, 8222, 1), (0x1D626, 8223, 1), (0x1D627, 8224, 1), (0x1D628, 8225, 1),
+    (0x1D629, 8226, 1), (0x1D62A, 8227, 1), (0x1D62B, 8228, 1), (0x1D62C, 8229, 1),
+    (0x1D62D, 8230, 1), (0x1D62E, 8231, 1), (0x1D62F, 8232, 1), (0x1D630, 8233, 1),
+    (0x1D631, 8234, 1), (0x1D632, 8235, 1), (0x1D633, 8236, 1), (0x1D634, 8237, 1),
+    (0x1D635, 8238, 1), (0x1D636, 8239, 1), (0x1D637, 8240, 1), (0x1D638, 8241, 1),
+    (0x1D639, 8242, 1), (0x1D63A, 8243, 1), (0x1D63B, 8244, 1), (0x1D63C, 8245, 1),
+    (0x1D63D, 8246, 1), (0x1D63E, 8247, 1), (0x1D63F, 8248, 1), (0x1D640, 8249, 1),
+    (0x1D641, 8250, 1), (0x1D642, 8251, 1), (0x1D643, 8252, 1), (0x1D644, 8253, 1),
+    (0x1D645, 8254, 1), (0x1D646, 8255, 1), (0x1D647, 8256, 1), (0x1D648, 8257, 1),
+    (0x1D649, 8258, 1), (0x1D64A, 8259, 1), (0x1D64B, 8260, 1), (0x1D64C, 8261, 1),
+    (0x1D64D, 8262, 1), (0x1D64E, 8263, 1), (0x1D64F, 8264, 1), (0x1D650, 8265, 1),
+    (0x1D651, 8266, 1), (0x1D652, 8267, 1), (0x1D653, 8268, 1), (0x1D654, 8269, 1),
+    (0x1D655, 8270, 1), (0x1D656, 8271, 1), (0x1D657, 8272, 1), (0x1D658, 8273, 1),
+    (0x1D659, 8274, 1), (0x1D65A, 8275, 1), (0x1D65B, 8276, 1), (0x1D65C, 8277, 1),
+    (0x1D65D, 8278, 1), (0x1D65E, 8279, 1), (0x1D65F, 8280, 1), (0x1D660, 8281, 1),
+    (0x1D661, 8282, 1), (0x1D662, 8283, 1), (0x1D663, 8284, 1), (0x1D664, 8285, 1),
+    (0x1D665, 8286, 1), (0x1D666, 8287, 1), (0x1D667, 8288, 1), (0x1D668, 8289, 1),
+    (0x1D669, 8290, 1), (0x1D66A, 8291, 1), (0x1D66B, 8292, 1), (0x1D66C, 8293, 1),
+    (0x1D66D, 8294, 1), (0x1D66E, 8295, 1), (0x1D66F, 8296, 1), (0x1D670, 8297, 1),
+    (0x1D671, 8298, 1), (0x1D672, 8299, 1), (0x1D673, 8300, 1), (0x1D674, 8301, 1),
+    (0x1D675, 8302, 1), (0x1D676, 8303, 1), (0x1D677, 8304, 1), (0x1D678, 8305, 1),
+    (0x1D679, 8306, 1), (0x1D67A, 8307, 1), (0x1D67B, 8308, 1), (0x1D67C, 8309, 1),
+    (0x1D67D, 8310, 1), (0x1D67E, 8311, 1), (0x1D67F, 8312, 1), (0x1D680, 8313, 1),
+    (0x1D681, 8314, 1), (0x1D682, 8315, 1), (0x1D683, 8316, 1), (0x1D684, 8317, 1),
+    (0x1D685, 8318, 1), (0x1D686, 8319, 1), (0x1D687, 8320, 1), (0x1D688, 8321, 1),
+    (0x1D689, 8322, 1), (0x1D68A, 8323, 1), (0x1D68B, 8324, 1), (0x1D68C, 8325, 1),
+    (0x1D68D, 8326, 1), (0x1D68E, 8327, 1), (0x1D68F, 8328, 1), (0x1D690, 8329, 1),
+    (0x1D691, 8330, 1), (0x1D692, 8331, 1), (0x1D693, 8332, 1), (0x1D694, 8333, 1),
+    (0x1D695, 8334, 1), (0x1D696, 8335, 1), (0x1D697, 8336, 1), (0x1D698, 8337, 1),
+    (0x1D699, 8338, 1), (0x1D69A, 8339, 1), (0x1D69B, 8340, 1), (0x1D69C, 8341, 1),
+    (0x1D69D, 8342, 1), (0x1D69E, 8343, 1), (0x1D69F, 8344, 1), (0x1D6A0, 8345, 1),
+    (0x1D6A1, 8346, 1), (0x1D6A2, 8347, 1), (0x1D6A3, 8348, 1), (0x1D6A4, 8349, 1),
+    (0x1D6A5, 8350, 1), (0x1D6A8, 8351, 1), (0x1D6A9, 8352, 1), (0x1D6AA, 8353, 1),
+    (0x1D6AB, 8354, 1), (0x1D6AC, 8355, 1), (0x1D6AD, 8356, 1), (0x1D6AE, 8357, 1),
+    (0x1D6AF, 8358, 1), (0x1D6B0, 8359, 1), (0x1D6B1, 8360, 1), (0x1D6B2, 8361, 1),
+    (0x1D6B3, 8362, 1), (0x1D6B4, 8363, 1), (0x1D6B5, 8364, 1), (0x1D6B6, 8365, 1),
+    (0x1D6B7, 8366, 1), (0x1D6B8, 8367, 1), (0x1D6B9, 8368, 1), (0x1D6BA, 8369, 1),
+    (0x1D6BB, 8370, 1), (0x1D6BC, 8371, 1), (0x1D6BD, 8372, 1), (0x1D6BE, 8373, 1),
+    (0x1D6BF, 8374, 1), (0x1D6C0, 8375, 1), (0x1D6C1, 8376, 1), (0x1D6C2, 8377, 1),
+    (0x1D6C3, 8378, 1), (0x1D6C4, 8379, 1), (0x1D6C5, 8380, 1), (0x1D6C6, 8381, 1),
+    (0x1D6C7, 8382, 1), (0x1D6C8, 8383, 1), (0x1D6C9, 8384, 1), (0x1D6CA, 8385, 1),
+    (0x1D6CB, 8386, 1), (0x1D6CC, 8387, 1), (0x1D6CD, 8388, 1), (0x1D6CE, 8389, 1),
+    (0x1D6CF, 8390, 1), (0x1D6D0, 8391, 1), (0x1D6D1, 8392, 1), (0x1D6D2, 8393, 1),
+    (0x1D6D3, 8394, 1), (0x1D6D4, 8395, 1), (0x1D6D5, 8396, 1), (0x1D6D6, 8397, 1),
+    (0x1D6D7, 8398, 1), (0x1D6D8, 8399, 1), (0x1D6D9, 8400, 1), (0x1D6DA, 8401, 1),
+    (0x1D6DB, 8402, 1), (0x1D6DC, 8403, 1), (0x1D6DD, 8404, 1), (0x1D6DE, 8405, 1),
+    (0x1D6DF, 8406, 1), (0x1D6E0, 8407, 1), (0x1D6E1, 8408, 1), (0x1D6E2, 8409, 1),
+    (0x1D6E3, 8410, 1), (0x1D6E4, 8411, 1), (0x1D6E5, 8412, 1), (0x1D6E6, 8413, 1),
+    (0x1D6E7, 8414, 1), (0x1D6E8, 8415, 1), (0x1D6E9, 8416, 1), (0x1D6EA, 8417, 1),
+    (0x1D6EB, 8418, 1), (0x1D6EC, 8419, 1), (0x1D6ED, 8420, 1), (0x1D6EE, 8421, 1),
+    (0x1D6EF, 8422, 1), (0x1D6F0, 8423, 1), (0x1D6F1, 8424, 1), (0x1D6F2, 8425, 1),
+    (0x1D6F3, 8426, 1), (0x1D6F4, 8427, 1), (0x1D6F5, 8428, 1), (0x1D6F6, 8429, 1),
+    (0x1D6F7, 8430, 1), (0x1D6F8, 8431, 1), (0x1D6F9, 8432, 1), (0x1D6FA, 8433, 1),
+    (0x1D6FB, 8434, 1), (0x1D6FC, 8435, 1), (0x1D6FD, 8436, 1), (0x1D6FE, 8437, 1),
+    (0x1D6FF, 8438, 1), (0x1D700, 8439, 1), (0x1D701, 8440, 1), (0x1D702, 8441, 1),
+    (0x1D703, 8442, 1), (0x1D704, 8443, 1), (0x1D705, 8444, 1), (0x1D706, 8445, 1),
+    (0x1D707, 8446, 1), (0x1D708, 8447, 1), (0x1D709, 8448, 1), (0x1D70A, 8449, 1),
+    (0x1D70B, 8450, 1), (0x1D70C, 8451, 1), (0x1D70D, 8452, 1), (0x1D70E, 8453, 1),
+    (0x1D70F, 8454, 1), (0x1D710, 8455, 1), (0x1D711, 8456, 1), (0x1D712, 8457, 1),
+    (0x1D713, 8458, 1), (0x1D714, 8459, 1), (0x1D715, 8460, 1), (0x1D716, 8461, 1),
+    (0x1D717, 8462, 1), (0x1D718, 8463, 1), (0x1D719, 8464, 1), (0x1D71A, 8465, 1),
+    (0x1D71B, 8466, 1), (0x1D71C, 8467, 1), (0x1D71D, 8468, 1), (0x1D71E, 8469, 1),
+    (0x1D71F, 8470, 1), (0x1D720, 8471, 1), (0x1D721, 8472, 1), (0x1D722, 8473, 1),
+    (0x1D723, 8474, 1), (0x1D724, 8475, 1), (0x1D725, 8476, 1), (0x1D726, 8477, 1),
+    (0x1D727, 8478, 1), (0x1D728, 8479, 1), (0x1D729, 8480, 1), (0x1D72A, 8481, 1),
+    (0x1D72B, 8482, 1), (0x1D72C, 8483, 1), (0x1D72D, 8484, 1), (0x1D72E, 8485, 1),
+    (0x1D72F, 8486, 1), (0x1D730, 8487, 1), (0x1D731, 8488, 1), (0x1D732, 8489, 1),
+    (0x1D733, 8490, 1), (0x1D734, 8491, 1), (0x1D735, 8492, 1), (0x1D736, 8493, 1),
+    (0x1D737, 8494, 1), (0x1D738, 8495, 1), (0x1D739, 8496, 1), (0x1D73A, 8497, 1),
+    (0x1D73B, 8498, 1), (0x1D73C, 8499, 1), (0x1D73D, 8500, 1), (0x1D73E, 8501, 1),
+    (0x1D73F, 8502, 1), (0x1D740, 8503, 1), (0x1D741, 8504, 1), (0x1D742, 8505, 1),
+    (0x1D743, 8506, 1), (0x1D744, 8507, 1), (0x1D745, 8508, 1), (0x1D746, 8509, 1),
+    (0x1D747, 8510, 1), (0x1D748, 8511, 1), (0x1D749, 8512, 1), (0x1D74A, 8513, 1),
+    (0x1D74B, 8514, 1), (0x1D74C, 8515, 1), (0x1D74D, 8516, 1), (0x1D74E, 8517, 1),
+    (0x1D74F, 8518, 1), (0x1D750, 8519, 1), (0x1D751, 8520, 1), (0x1D752, 8521, 1),
+    (0x1D753, 8522, 1), (0x1D754, 8523, 1), (0x1D755, 8524, 1), (0x1D756, 8525, 1),
+    (0x1D757, 8526, 1), (0x1D758, 8527, 1), (0x1D759, 8528, 1), (0x1D75A, 8529, 1),
+    (0x1D75B, 8530, 1), (0x1D75C, 8531, 1), (0x1D75D, 8532, 1), (0x1D75E, 8533, 1),
+    (0x1D75F, 8534, 1), (0x1D760, 8535, 1), (0x1D761, 8536, 1), (0x1D762, 8537, 1),
+    (0x1D763, 8538, 1), (0x1D764, 8539, 1), (0x1D765, 8540, 1), (0x1D766, 8541, 1),
+    (0x1D767, 8542, 1), (0x1D768, 8543, 1), (0x1D769, 8544, 1), (0x1D76A, 8545, 1),
+    (0x1D76B, 8546, 1), (0x1D76C, 8547, 1), (0x1D76D, 8548, 1), (0x1D76E, 8549, 1),
+    (0x1D76F, 8550, 1), (0x1D770, 8551, 1), (0x1D771, 8552, 1), (0x1D772, 8553, 1),
+    (0x1D773, 8554, 1), (0x1D774, 8555, 1), (0x1D775, 8556, 1), (0x1D776, 8557, 1),
+    (0x1D777, 8558, 1), (0x1D778, 8559, 1), (0x1D779, 8560, 1), (0x1D77A, 8561, 1),
+    (0x1D77B, 8562, 1), (0x1D77C, 8563, 1), (0x1D77D, 8564, 1), (0x1D77E, 8565, 1),
+    (0x1D77F, 8566, 1), (0x1D780, 8567, 1), (0x1D781, 8568, 1), (0x1D782, 8569, 1),
+    (0x1D783, 8570, 1), (0x1D784, 8571, 1), (0x1D785, 8572, 1), (0x1D786, 8573, 1),
+    (0x1D787, 8574, 1), (0x1D788, 8575, 1), (0x1D789, 8576, 1), (0x1D78A, 8577, 1),
+    (0x1D78B, 8578, 1), (0x1D78C, 8579, 1), (0x1D78D, 8580, 1), (0x1D78E, 8581, 1),
+    (0x1D78F, 8582, 1), (0x1D790, 8583, 1), (0x1D791, 8584, 1), (0x1D792, 8585, 1),
+    (0x1D793, 8586, 1), (0x1D794, 8587, 1), (0x1D795, 8588, 1), (0x1D796, 8589, 1),
+    (0x1D797, 8590, 1), (0x1D798, 8591, 1), (0x1D799, 8592, 1), (0x1D79A, 8593, 1),
+    (0x1D79B, 8594, 1), (0x1D79C, 8595, 1), (0x1D79D, 8596, 1), (0x1D79E, 8597, 1),
+    (0x1D79F, 8598, 1), (0x1D7A0, 8599, 1), (0x1D7A1, 8600, 1), (0x1D7A2, 8601, 1),
+    (0x1D7A3, 8602, 1), (0x1D7A4, 8603, 1), (0x1D7A5, 8604, 1), (0x1D7A6, 8605, 1),
+    (0x1D7A7, 8606, 1), (0x1D7A8, 8607, 1), (0x1D7A9, 8608, 1), (0x1D7AA, 8609, 1),
+    (0x1D7AB, 8610, 1), (0x1D7AC, 8611, 1), (0x1D7AD, 8612, 1), (0x1D7AE, 8613, 1),
+    (0x1D7AF, 8614, 1), (0x1D7B0, 8615, 1), (0x1D7B1, 8616, 1), (0x1D7B2, 8617, 1),
+    (0x1D7B3, 8618, 1), (0x1D7B4, 8619, 1), (0x1D7B5, 8620, 1), (0x1D7B6, 8621, 1),
+    (0x1D7B7, 8622, 1), (0x1D7B8, 8623, 1), (0x1D7B9, 8624, 1), (0x1D7BA, 8625, 1),
+    (0x1D7BB, 8626, 1), (0x1D7BC, 8627, 1), (0x1D7BD, 8628, 1), (0x1D7BE, 8629, 1),
+    (0x1D7BF, 8630, 1), (0x1D7C0, 8631, 1), (0x1D7C1, 8632, 1), (0x1D7C2, 8633, 1),
+    (0x1D7C3, 8634, 1), (0x1D7C4, 8635, 1), (0x1D7C5, 8636, 1), (0x1D7C6, 8637, 1),
+    (0x1D7C7, 8638, 1), (0x1D7C8, 8639, 1), (0x1D7C9, 8640, 1), (0x1D7CA, 8641, 1),
+    (0x1D7CB, 8642, 1), (0x1D7CE, 8643, 1), (0x1D7CF, 8644, 1), (0x1D7D0, 8645, 1),
+    (0x1D7D1, 8646, 1), (0x1D7D2, 8647, 1), (0x1D7D3, 8648, 1), (0x1D7D4, 8649, 1),
+    (0x1D7D5, 8650, 1), (0x1D7D6, 8651, 1), (0x1D7D7, 8652, 1), (0x1D7D8, 8653, 1),
+    (0x1D7D9, 8654, 1), (0x1D7DA, 8655, 1), (0x1D7DB, 8656, 1), (0x1D7DC, 8657, 1),
+    (0x1D7DD, 8658, 1), (0x1D7DE, 8659, 1), (0x1D7DF, 8660, 1), (0x1D7E0, 8661, 1),
+    (0x1D7E1, 8662, 1), (0x1D7E2, 8663, 1), (0x1D7E3, 8664, 1), (0x1D7E4, 8665, 1),
+    (0x1D7E5, 8666, 1), (0x1D7E6, 8667, 1), (0x1D7E7, 8668, 1), (0x1D7E8, 8669, 1),
+    (0x1D7E9, 8670, 1), (0x1D7EA, 8671, 1), (0x1D7EB, 8672, 1), (0x1D7EC, 8673, 1),
+    (0x1D7ED, 8674, 1), (0x1D7EE, 8675, 1), (0x1D7EF, 8676, 1), (0x1D7F0, 8677, 1),
+    (0x1D7F1, 8678, 1), (0x1D7F2, 8679, 1), (0x1D7F3, 8680, 1), (0x1D7F4, 8681, 1),
+    (0x1D7F5, 8682, 1), (0x1D7F6, 8683, 1), (0x1D7F7, 8684, 1), (0x1D7F8, 8685, 1),
+    (0x1D7F9, 8686, 1), (0x1D7FA, 8687, 1), (0x1D7FB, 8688, 1), (0x1D7FC, 8689, 1),
+    (0x1D7FD, 8690, 1), (0x1D7FE, 8691, 1), (0x1D7FF, 8692, 1), (0x1EE00, 8693, 1),
+    (0x1EE01, 8694, 1), (0x1EE02, 8695, 1), (0x1EE03, 8696, 1), (0x1EE05, 8697, 1),
+    (0x1EE06, 8698, 1), (0x1EE07, 8699, 1), (0x1EE08, 8700, 1), (0x1EE09, 8701, 1),
+    (0x1EE0A, 8702, 1), (0x1EE0B, 8703, 1), (0x1EE0C, 8704, 1), (0x1EE0D, 8705, 1),
+    (0x1EE0E, 8706, 1), (0x1EE0F, 8707, 1), (0x1EE10, 8708, 1), (0x1EE11, 8709, 1),
+    (0x1EE12, 8710, 1), (0x1EE13, 8711, 1), (0x1EE14, 8712, 1), (0x1EE15, 8713, 1),
+    (0x1EE16, 8714, 1), (0x1EE17, 8715, 1), (0x1EE18, 8716, 1), (0x1EE19, 8717, 1),
+    (0x1EE1A, 8718, 1), (0x1EE1B, 8719, 1), (0x1EE1C, 8720, 1), (0x1EE1D, 8721, 1),
+    (0x1EE1E, 8722, 1), (0x1EE1F, 8723, 1), (0x1EE21, 8724, 1), (0x1EE22, 8725, 1),
+    (0x1EE24, 8726, 1), (0x1EE27, 8727, 1), (0x1EE29, 8728, 1), (0x1EE2A, 8729, 1),
+    (0x1EE2B, 8730, 1), (0x1EE2C, 8731, 1), (0x1EE2D, 8732, 1), (0x1EE2E, 8733, 1),
+    (0x1EE2F, 8734, 1), (0x1EE30, 8735, 1), (0x1EE31, 8736, 1), (0x1EE32, 8737, 1),
+    (0x1EE34, 8738, 1), (0x1EE35, 8739, 1), (0x1EE36, 8740, 1), (0x1EE37, 8741, 1),
+    (0x1EE39, 8742, 1), (0x1EE3B, 8743, 1), (0x1EE42, 8744, 1), (0x1EE47, 8745, 1),
+    (0x1EE49, 8746, 1), (0x1EE4B, 8747, 1), (0x1EE4D, 8748, 1), (0x1EE4E, 8749, 1),
+    (0x1EE4F, 8750, 1), (0x1EE51, 8751, 1), (0x1EE52, 8752, 1), (0x1EE54, 8753, 1),
+    (0x1EE57, 8754, 1), (0x1EE59, 8755, 1), (0x1EE5B, 8756, 1), (0x1EE5D, 8757, 1),
+    (0x1EE5F, 8758, 1), (0x1EE61, 8759, 1), (0x1EE62, 8760, 1), (0x1EE64, 8761, 1),
+    (0x1EE67, 8762, 1), (0x1EE68, 8763, 1), (0x1EE69, 8764, 1), (0x1EE6A, 8765, 1),
+    (0x1EE6C, 8766, 1), (0x1EE6D, 8767, 1), (0x1EE6E, 8768, 1), (0x1EE6F, 8769, 1),
+    (0x1EE70, 8770, 1), (0x1EE71, 8771, 1), (0x1EE72, 8772, 1), (0x1EE74, 8773, 1),
+    (0x1EE75, 8774, 1), (0x1EE76, 8775, 1), (0x1EE77, 8776, 1), (0x1EE79, 8777, 1),
+    (0x1EE7A, 8778, 1), (0x1EE7B, 8779, 1), (0x1EE7C, 8780, 1), (0x1EE7E, 8781, 1),
+    (0x1EE80, 8782, 1), (0x1EE81, 8783, 1), (0x1EE82, 8784, 1), (0x1EE83, 8785, 1),
+    (0x1EE84, 8786, 1), (0x1EE85, 8787, 1), (0x1EE86, 8788, 1), (0x1EE87, 8789, 1),
+    (0x1EE88, 8790, 1), (0x1EE89, 8791, 1), (0x1EE8B, 8792, 1), (0x1EE8C, 8793, 1),
+    (0x1EE8D, 8794, 1), (0x1EE8E, 8795, 1), (0x1EE8F, 8796, 1), (0x1EE90, 8797, 1),
+    (0x1EE91, 8798, 1), (0x1EE92, 8799, 1), (0x1EE93, 8800, 1), (0x1EE94, 8801, 1),
+    (0x1EE95, 8802, 1), (0x1EE96, 8803, 1), (0x1EE97, 8804, 1), (0x1EE98, 8805, 1),
+    (0x1EE99, 8806, 1), (0x1EE9A, 8807, 1), (0x1EE9B, 8808, 1), (0x1EEA1, 8809, 1),
+    (0x1EEA2, 8810, 1), (0x1EEA3, 8811, 1), (0x1EEA5, 8812, 1), (0x1EEA6, 8813, 1),
+    (0x1EEA7, 8814, 1), (0x1EEA8, 8815, 1), (0x1EEA9, 8816, 1), (0x1EEAB, 8817, 1),
+    (0x1EEAC, 8818, 1), (0x1EEAD, 8819, 1), (0x1EEAE, 8820, 1), (0x1EEAF, 8821, 1),
+    (0x1EEB0, 8822, 1), (0x1EEB1, 8823, 1), (0x1EEB2, 8824, 1), (0x1EEB3, 8825, 1),
+    (0x1EEB4, 8826, 1), (0x1EEB5, 8827, 1), (0x1EEB6, 8828, 1), (0x1EEB7, 8829, 1),
+    (0x1EEB8, 8830, 1), (0x1EEB9, 8831, 1), (0x1EEBA, 8832, 1), (0x1EEBB, 8833, 1),
+    (0x1F100, 8834, 2), (0x1F101, 8836, 2), (0x1F102, 8838, 2), (0x1F103, 8840, 2),
+    (0x1F104, 8842, 2), (0x1F105, 8844, 2), (0x1F106, 8846, 2), (0x1F107, 8848, 2),
+    (0x1F108, 8850, 2), (0x1F109, 8852, 2), (0x1F10A, 8854, 2), (0x1F110, 8856, 3),
+    (0x1F111, 8859, 3), (0x1F112, 8862, 3), (0x1F113, 8865, 3), (0x1F114, 8868, 3),
+    (0x1F115, 8871, 3), (0x1F116, 8874, 3), (0x1F117, 8877, 3), (0x1F118, 8880, 3),
+    (0x1F119, 8883, 3), (0x1F11A, 8886, 3), (0x1F11B, 8889, 3), (0x1F11C, 8892, 3),
+    (0x1F11D, 8895, 3), (0x1F11E, 8898, 3), (0x1F11F, 8901, 3), (0x1F120, 8904, 3),
+    (0x1F121, 8907, 3), (0x1F122, 8910, 3), (0x1F123, 8913, 3), (0x1F124, 8916, 3),
+    (0x1F125, 8919, 3), (0x1F126, 8922, 3), (0x1F127, 8925, 3), (0x1F128, 8928, 3),
+    (0x1F129, 8931, 3), (0x1F12A, 8934, 3), (0x1F12B, 8937, 1), (0x1F12C, 8938, 1),
+    (0x1F12D, 8939, 2), (0x1F12E, 8941, 2), (0x1F130, 8943, 1), (0x1F131, 8944, 1),
+    (0x1F132, 8945, 1), (0x1F133, 8946, 1), (0x1F134, 8947, 1), (0x1F135, 8948, 1),
+    (0x1F136, 8949, 1), (0x1F137, 8950, 1), (0x1F138, 8951, 1), (0x1F139, 8952, 1),
+    (0x1F13A, 8953, 1), (0x1F13B, 8954, 1), (0x1F13C, 8955, 1), (0x1F13D, 8956, 1),
+    (0x1F13E, 8957, 1), (0x1F13F, 8958, 1), (0x1F140, 8959, 1), (0x1F141, 8960, 1),
+    (0x1F142, 8961, 1), (0x1F143, 8962, 1), (0x1F144, 8963, 1), (0x1F145, 8964, 1),
+    (0x1F146, 8965, 1), (0x1F147, 8966, 1), (0x1F148, 8967, 1), (0x1F149, 8968, 1),
+    (0x1F14A, 8969, 2), (0x1F14B, 8971, 2), (0x1F14C, 8973, 2), (0x1F14D, 8975, 2),
+    (0x1F14E, 8977, 3), (0x1F14F, 8980, 2), (0x1F16A, 8982, 2), (0x1F16B, 8984, 2),
+    (0x1F16C, 8986, 2), (0x1F190, 8988, 2), (0x1F200, 8990, 2), (0x1F201, 8992, 2),
+    (0x1F202, 8994, 1), (0x1F210, 8995, 1), (0x1F211, 8996, 1), (0x1F212, 8997, 1),
+    (0x1F213, 8998, 2), (0x1F214, 9000, 1), (0x1F215, 9001, 1), (0x1F216, 9002, 1),
+    (0x1F217, 9003, 1), (0x1F218, 9004, 1), (0x1F219, 9005, 1), (0x1F21A, 9006, 1),
+    (0x1F21B, 9007, 1), (0x1F21C, 9008, 1), (0x1F21D, 9009, 1), (0x1F21E, 9010, 1),
+    (0x1F21F, 9011, 1), (0x1F220, 9012, 1), (0x1F221, 9013, 1), (0x1F222, 9014, 1),
+    (0x1F223, 9015, 1), (0x1F224, 9016, 1), (0x1F225, 9017, 1), (0x1F226, 9018, 1),
+    (0x1F227, 9019, 1), (0x1F228, 9020, 1), (0x1F229, 9021, 1), (0x1F22A, 9022, 1),
+    (0x1F22B, 9023, 1), (0x1F22C, 9024, 1), (0x1F22D, 9025, 1), (0x1F22E, 9026, 1),
+    (0x1F22F, 9027, 1), (0x1F230, 9028, 1), (0x1F231, 9029, 1), (0x1F232, 9030, 1),
+    (0x1F233, 9031, 1), (0x1F234, 9032, 1), (0x1F235, 9033, 1), (0x1F236, 9034, 1),
+    (0x1F237, 9035, 1), (0x1F238, 9036, 1), (0x1F239, 9037, 1), (0x1F23A, 9038, 1),
+    (0x1F23B, 9039, 1), (0x1F240, 9040, 3), (0x1F241, 9043, 3), (0x1F242, 9046, 3),
+    (0x1F243, 9049, 3), (0x1F244, 9052, 3), (0x1F245, 9055, 3), (0x1F246, 9058, 3),
+    (0x1F247, 9061, 3), (0x1F248, 9064, 3), (0x1F250, 9067, 1), (0x1F251, 9068, 1),
+    (0x1FBF0, 9069, 1), (0x1FBF1, 9070, 1), (0x1FBF2, 9071, 1), (0x1FBF3, 9072, 1),
+    (0x1FBF4, 9073, 1), (0x1FBF5, 9074, 1), (0x1FBF6, 9075, 1), (0x1FBF7, 9076, 1),
+    (0x1FBF8, 9077, 1), (0x1FBF9, 9078, 1),
+];
+
+
+/// Characters of decompositions.
+pub const DECOMPOSED: &[char] = &[
+    '\u{41}', '\u{300}', '\u{41}', '\u{301}', '\u{41}', '\u{302}', '\u{41}', '\u{303}',
+    '\u{41}', '\u{308}', '\u{41}', '\u{30A}', '\u{43}', '\u{327}', '\u{45}', '\u{300}',
+    '\u{45}', '\u{301}', '\u{45}', '\u{302}', '\u{45}', '\u{308}', '\u{49}', '\u{300}',
+    '\u{49}', '\u{301}', '\u{49}', '\u{302}', '\u{49}', '\u{308}', '\u{4E}', '\u{303}',
+    '\u{4F}', '\u{300}', '\u{4F}', '\u{301}', '\u{4F}', '\u{302}', '\u{4F}', '\u{303}',
+    '\u{4F}', '\u{308}', '\u{55}', '\u{300}', '\u{55}', '\u{301}', '\u{55}', '\u{302}',
+    '\u{55}', '\u{308}', '\u{59}', '\u{301}', '\u{61}', '\u{300}', '\u{61}', '\u{301}',
+    '\u{61}', '\u{302}', '\u{61}', '\u{303}', '\u{61}', '\u{308}', '\u{61}', '\u{30A}',
+    '\u{63}', '\u{327}', '\u{65}', '\u{300}', '\u{65}', '\u{301}', '\u{65}', '\u{302}',
+    '\u{65}', '\u{308}', '\u{69}', '\u{300}', '\u{69}', '\u{301}', '\u{69}', '\u{302}',
+    '\u{69}', '\u{308}', '\u{6E}', '\u{303}', '\u{6F}', '\u{300}', '\u{6F}', '\u{301}',
+    '\u{6F}', '\u{302}', '\u{6F}', '\u{303}', '\u{6F}', '\u{308}', '\u{75}', '\u{300}',
+    '\u{75}', '\u{301}', '\u{75}', '\u{302}', '\u{75}', '\u{308}', '\u{79}', '\u{301}',
+    '\u{79}', '\u{308}', '\u{41}', '\u{304}', '\u{61}', '\u{304}', '\u{41}', '\u{306}',
+    '\u{61}', '\u{306}', '\u{41}', '\u{328}', '\u{61}', '\u{328}', '\u{43}', '\u{301}',
+    '\u{63}', '\u{301}', '\u{43}', '\u{302}', '\u{63}', '\u{302}', '\u{43}', '\u{307}',
+    '\u{63}', '\u{307}', '\u{43}', '\u{30C}', '\u{63}', '\u{30C}', '\u{44}', '\u{30C}',
+    '\u{64}', '\u{30C}', '\u{45}', '\u{304}', '\u{65}', '\u{304}', '\u{45}', '\u{306}',
+    '\u{65}', '\u{306}', '\u{45}', '\u{307}', '\u{65}', '\u{307}', '\u{45}', '\u{328}',
+    '\u{65}', '\u{328}', '\u{45}', '\u{30C}', '\u{65}', '\u{30C}', '\u{47}', '\u{302}',
+    '\u{67}', '\u{302}', '\u{47}', '\u{306}', '\u{67}', '\u{306}', '\u{47}', '\u{307}',
+    '\u{67}', '\u{307}', '\u{47}', '\u{327}', '\u{67}', '\u{327}', '\u{48}', '\u{302}',
+    '\u{68}', '\u{302}', '\u{49}', '\u{303}', '\u{69}', '\u{303}', '\u{49}', '\u{304}',
+    '\u{69}', '\u{304}', '\u{49}', '\u{306}', '\u{69}', '\u{306}', '\u{49}', '\u{328}',
+    '\u{69}', '\u{328}', '\u{49}', '\u{307}', '\u{4A}', '\u{302}', '\u{6A}', '\u{302}',
+    '\u{4B}', '\u{327}', '\u{6B}', '\u{327}', '\u{4C}', '\u{301}', '\u{6C}', '\u{301}',
+    '\u{4C}', '\u{327}', '\u{6C}', '\u{327}', '\u{4C}', '\u{30C}', '\u{6C}', '\u{30C}',
+    '\u{4E}', '\u{301}', '\u{6E}', '\u{301}', '\u{4E}', '\u{327}', '\u{6E}', '\u{327}',
+    '\u{4E}', '\u{30C}', '\u{6E}', '\u{30C}', '\u{4F}', '\u{304}', '\u{6F}', '\u{304}',
+    '\u{4F}', '\u{306}', '\u{6F}', '\u{306}', '\u{4F}', '\u{30B}', '\u{6F}', '\u{30B}',
+    '\u{52}', '\u{301}', '\u{72}', '\u{301}', '\u{52}', '\u{327}', '\u{72}', '\u{327}',
+    '\u{52}', '\u{30C}', '\u{72}', '\u{30C}', '\u{53}', '\u{301}', '\u{73}', '\u{301}',
+    '\u{53}', '\u{302}', '\u{73}', '\u{302}', '\u{53}', '\u{327}', '\u{73}', '\u{327}',
+    '\u{53}', '\u{30C}', '\u{73}', '\u{30C}', '\u{54}', '\u{327}', '\u{74}', '\u{327}',
+    '\u{54}', '\u{30C}', '\u{74}', '\u{30C}', '\u{55}', '\u{303}', '\u{75}', '\u{303}',
+    '\u{55}', '\u{304}', '\u{75}', '\u{304}', '\u{55}', '\u{306}', '\u{75}', '\u{306}',
+    '\u{55}', '\u{30A}', '\u{75}', '\u{30A}', '\u{55}', '\u{30B}', '\u{75}', '\u{30B}',
+    '\u{55}', '\u{328}', '\u{75}', '\u{328}', '\u{57}', '\u{302}', '\u{77}', '\u{302}',
+    '\u{59}', '\u{302}', '\u{79}', '\u{302}', '\u{59}', '\u{308}', '\u{5A}', '\u{301}',
+    '\u{7A}', '\u{301}', '\u{5A}', '\u{307}', '\u{7A}', '\u{307}', '\u{5A}', '\u{30C}',
+    '\u{7A}', '\u{30C}', '\u{4F}', '\u{31B}', '\u{6F}', '\u{31B}', '\u{55}', '\u{31B}',
+    '\u{75}', '\u{31B}', '\u{41}', '\u{30C}', '\u{61}', '\u{30C}', '\u{49}', '\u{30C}',
+    '\u{69}', '\u{30C}', '\u{4F}', '\u{30C}', '\u{6F}', '\u{30C}', '\u{55}', '\u{30C}',
+    '\u{75}', '\u{30C}', '\u{55}', '\u{308}', '\u{304}', '\u{75}', '\u{308}', '\u{304}',
+    '\u{55}', '\u{308}', '\u{301}', '\u{75}', '\u{308}', '\u{301}', '\u{55}', '\u{308}',
+    '\u{30C}', '\u{75}', '\u{308}', '\u{30C}', '\u{55}', '\u{308}', '\u{300}', '\u{75}',
+    '\u{308}', '\u{300}', '\u{41}', '\u{308}', '\u{304}', '\u{61}', '\u{308}', '\u{304}',
+    '\u{41}', '\u{307}', '\u{304}', '\u{61}', '\u{307}', '\u{304}', '\u{C6}', '\u{304}',
+    '\u{E6}', '\u{304}', '\u{47}', '\u{30C}', '\u{67}', '\u{30C}', '\u{4B}', '\u{30C}',
+    '\u{6B}', '\u{30C}', '\u{4F}', '\u{328}', '\u{6F}', '\u{328}', '\u{4F}', '\u{328}',
+    '\u{304}', '\u{6F}', '\u{328}', '\u{304}', '\u{1B7}', '\u{30C}', '\u{292}', '\u{30C}',
+    '\u{6A}', '\u{30C}', '\u{47}', '\u{301}', '\u{67}', '\u{301}', '\u{4E}', '\u{300}',
+    '\u{6E}', '\u{300}', '\u{41}', '\u{30A}', '\u{301}', '\u{61}', '\u{30A}', '\u{301}',
+    '\u{C6}', '\u{301}', '\u{E6}', '\u{301}', '\u{D8}', '\u{301}', '\u{F8}', '\u{301}',
+    '\u{41}', '\u{30F}', '\u{61}', '\u{30F}', '\u{41}', '\u{311}', '\u{61}', '\u{311}',
+    '\u{45}', '\u{30F}', '\u{65}', '\u{30F}', '\u{45}', '\u{311}', '\u{65}', '\u{311}',
+    '\u{49}', '\u{30F}', '\u{69}', '\u{30F}', '\u{49}', '\u{311}', '\u{69}', '\u{311}',
+    '\u{4F}', '\u{30F}', '\u{6F}', '\u{30F}', '\u{4F}', '\u{311}', '\u{6F}', '\u{311}',
+    '\u{52}', '\u{30F}', '\u{72}', '\u{30F}', '\u{52}', '\u{311}', '\u{72}', '\u{311}',
+    '\u{55}', '\u{30F}', '\u{75}', '\u{30F}', '\u{55}', '\u{311}', '\u{75}', '\u{311}',
+    '\u{53}', '\u{326}', '\u{73}', '\u{326}', '\u{54}', '\u{326}', '\u{74}', '\u{326}',
+    '\u{48}', '\u{30C}', '\u{68}', '\u{30C}', '\u{41}', '\u{307}', '\u{61}', '\u{307}',
+    '\u{45}', '\u{327}', '\u{65}', '\u{327}', '\u{4F}', '\u{308}', '\u{304}', '\u{6F}',
+    '\u{308}', '\u{304}', '\u{4F}', '\u{303}', '\u{304}', '\u{6F}', '\u{303}', '\u{304}',
+    '\u{4F}', '\u{307}', '\u{6F}', '\u{307}', '\u{4F}', '\u{307}', '\u{304}', '\u{6F}',
+    '\u{307}', '\u{304}', '\u{59}', '\u{304}', '\u{79}', '\u{304}', '\u{300}', '\u{301}',
+    '\u{313}', '\u{308}', '\u{301}', '\u{2B9}', '\u{3B}', '\u{A8}', '\u{301}', '\u{391}',
+    '\u{301}', '\u{B7}', '\u{395}', '\u{301}', '\u{397}', '\u{301}', '\u{399}', '\u{301}',
+    '\u{39F}', '\u{301}', '\u{3A5}', '\u{301}', '\u{3A9}', '\u{301}', '\u{3B9}', '\u{308}',
+    '\u{301}', '\u{399}', '\u{308}', '\u{3A5}', '\u{308}', '\u{3B1}', '\u{301}', '\u{3B5}',
+    '\u{301}', '\u{3B7}', '\u{301}', '\u{3B9}', '\u{301}', '\u{3C5}', '\u{308}', '\u{301}',
+    '\u{3B9}', '\u{308}', '\u{3C5}', '\u{308}', '\u{3BF}', '\u{301}', '\u{3C5}', '\u{301}',
+    '\u{3C9}', '\u{301}', '\u{3D2}', '\u{301}', '\u{3D2}', '\u{308}', '\u{415}', '\u{300}',
+    '\u{415}', '\u{308}', '\u{413}', '\u{301}', '\u{406}', '\u{308}', '\u{41A}', '\u{301}',
+    '\u{418}', '\u{300}', '\u{423}', '\u{306}', '\u{418}', '\u{306}', '\u{438}', '\u{306}',
+    '\u{435}', '\u{300}', '\u{435}', '\u{308}', '\u{433}', '\u{301}', '\u{456}', '\u{308}',
+    '\u{43A}', '\u{301}', '\u{438}', '\u{300}', '\u{443}', '\u{306}', '\u{474}', '\u{30F}',
+    '\u{475}', '\u{30F}', '\u{416}', '\u{306}', '\u{436}', '\u{306}', '\u{410}', '\u{306}',
+    '\u{430}', '\u{306}', '\u{410}', '\u{308}', '\u{430}', '\u{308}', '\u{415}', '\u{306}',
+    '\u{435}', '\u{306}', '\u{4D8}', '\u{308}', '\u{4D9}', '\u{308}', '\u{416}', '\u{308}',
+    '\u{436}', '\u{308}', '\u{417}', '\u{308}', '\u{437}', '\u{308}', '\u{418}', '\u{304}',
+    '\u{438}', '\u{304}', '\u{418}', '\u{308}', '\u{438}', '\u{308}', '\u{41E}', '\u{308}',
+    '\u{43E}', '\u{308}', '\u{4E8}', '\u{308}', '\u{4E9}', '\u{308}', '\u{42D}', '\u{308}',
+    '\u{44D}', '\u{308}', '\u{423}', '\u{304}', '\u{443}', '\u{304}', '\u{423}', '\u{308}',
+    '\u{443}', '\u{308}', '\u{423}', '\u{30B}', '\u{443}', '\u{30B}', '\u{427}', '\u{308}',
+    '\u{447}', '\u{308}', '\u{42B}', '\u{308}', '\u{44B}', '\u{308}', '\u{627}', '\u{653}',
+    '\u{627}', '\u{654}', '\u{648}', '\u{654}', '\u{627}', '\u{655}', '\u{64A}', '\u{654}',
+    '\u{6D5}', '\u{654}', '\u{6C1}', '\u{654}', '\u{6D2}', '\u{654}', '\u{928}', '\u{93C}',
+    '\u{930}', '\u{93C}', '\u{933}', '\u{93C}', '\u{915}', '\u{93C}', '\u{916}', '\u{93C}',
+    '\u{917}', '\u{93C}', '\u{91C}', '\u{93C}', '\u{921}', '\u{93C}', '\u{922}', '\u{93C}',
+    '\u{92B}', '\u{93C}', '\u{92F}', '\u{93C}', '\u{9C7}', '\u{9BE}', '\u{9C7}', '\u{9D7}',
+    '\u{9A1}', '\u{9BC}', '\u{9A2}', '\u{9BC}', '\u{9AF}', '\u{9BC}', '\u{A32}', '\u{A3C}',
+    '\u{A38}', '\u{A3C}', '\u{A16}', '\u{A3C}', '\u{A17}', '\u{A3C}', '\u{A1C}', '\u{A3C}',
+    '\u{A2B}', '\u{A3C}', '\u{B47}', '\u{B56}', '\u{B47}', '\u{B3E}', '\u{B47}', '\u{B57}',
+    '\u{B21}', '\u{B3C}', '\u{B22}', '\u{B3C}', '\u{B92}', '\u{BD7}', '\u{BC6}', '\u{BBE}',
+    '\u{BC7}', '\u{BBE}', '\u{BC6}', '\u{BD7}', '\u{C46}', '\u{C56}', '\u{CBF}', '\u{CD5}',
+    '\u{CC6}', '\u{CD5}', '\u{CC6}', '\u{CD6}', '\u{CC6}', '\u{CC2}', '\u{CC6}', '\u{CC2}',
+    '\u{CD5}', '\u{D46}', '\u{D3E}', '\u{D47}', '\u{D3E}', '\u{D46}', '\u{D57}', '\u{DD9}',
+    '\u{DCA}', '\u{DD9}', '\u{DCF}', '\u{DD9}', '\u{DCF}', '\u{DCA}', '\u{DD9}', '\u{DDF}',
+    '\u{F42}', '\u{FB7}', '\u{F4C}', '\u{FB7}', '\u{F51}', '\u{FB7}', '\u{F56}', '\u{FB7}',
+    '\u{F5B}', '\u{FB7}', '\u{F40}', '\u{FB5}', '\u{F71}', '\u{F72}', '\u{F71}', '\u{F74}',
+    '\u{FB2}', '\u{F80}', '\u{FB3}', '\u{F80}', '\u{F71}', '\u{F80}', '\u{F92}', '\u{FB7}',
+    '\u{F9C}', '\u{FB7}', '\u{FA1}', '\u{FB7}', '\u{FA6}', '\u{FB7}', '\u{FAB}', '\u{FB7}',
+    '\u{F90}', '\u{FB5}', '\u{1025}', '\u{102E}', '\u{1B05}', '\u{1B35}', '\u{1B07}', '\u{1B35}',
+    '\u{1B09}', '\u{1B35}', '\u{1B0B}', '\u{1B35}', '\u{1B0D}', '\u{1B35}', '\u{1B11}', '\u{1B35}',
+    '\u{1B3A}', '\u{1B35}', '\u{1B3C}', '\u{1B35}', '\u{1B3E}', '\u{1B35}', '\u{1B3F}', '\u{1B35}',
+    '\u{1B42}', '\u{1B35}', '\u{41}', '\u{325}', '\u{61}', '\u{325}', '\u{42}', '\u{307}',
+    '\u{62}', '\u{307}', '\u{42}', '\u{323}', '\u{62}', '\u{323}', '\u{42}', '\u{331}',
+    '\u{62}', '\u{331}', '\u{43}', '\u{327}', '\u{301}', '\u{63}', '\u{327}', '\u{301}',
+    '\u{44}', '\u{307}', '\u{64}', '\u{307}', '\u{44}', '\u{323}', '\u{64}', '\u{323}',
+    '\u{44}', '\u{331}', '\u{64}', '\u{331}', '\u{44}', '\u{327}', '\u{64}', '\u{327}',
+    '\u{44}', '\u{32D}', '\u{64}', '\u{32D}', '\u{45}', '\u{304}', '\u{300}', '\u{65}',
+    '\u{304}', '\u{300}', '\u{45}', '\u{304}', '\u{301}', '\u{65}', '\u{304}', '\u{301}',
+    '\u{45}', '\u{32D}', '\u{65}', '\u{32D}', '\u{45}', '\u{330}', '\u{65}', '\u{330}',
+    '\u{45}', '\u{327}', '\u{306}', '\u{65}', '\u{327}', '\u{306}', '\u{46}', '\u{307}',
+    '\u{66}', '\u{307}', '\u{47}', '\u{304}', '\u{67}', '\u{304}', '\u{48}', '\u{307}',
+    '\u{68}', '\u{307}', '\u{48}', '\u{323}', '\u{68}', '\u{323}', '\u{48}', '\u{308}',
+    '\u{68}', '\u{308}', '\u{48}', '\u{327}', '\u{68}', '\u{327}', '\u{48}', '\u{32E}',
+    '\u{68}', '\u{32E}', '\u{49}', '\u{330}', '\u{69}', '\u{330}', '\u{49}', '\u{308}',
+    '\u{301}', '\u{69}', '\u{308}', '\u{301}', '\u{4B}', '\u{301}', '\u{6B}', '\u{301}',
+    '\u{4B}', '\u{323}', '\u{6B}', '\u{323}', '\u{4B}', '\u{331}', '\u{6B}', '\u{331}',
+    '\u{4C}', '\u{323}', '\u{6C}', '\u{323}', '\u{4C}', '\u{323}', '\u{304}', '\u{6C}',
+    '\u{323}', '\u{304}', '\u{4C}', '\u{331}', '\u{6C}', '\u{331}', '\u{4C}', '\u{32D}',
+    '\u{6C}', '\u{32D}', '\u{4D}', '\u{301}', '\u{6D}', '\u{301}', '\u{4D}', '\u{307}',
+    '\u{6D}', '\u{307}', '\u{4D}', '\u{323}', '\u{6D}', '\u{323}', '\u{4E}', '\u{307}',
+    '\u{6E}', '\u{307}', '\u{4E}', '\u{323}', '\u{6E}', '\u{323}', '\u{4E}', '\u{331}',
+    '\u{6E}', '\u{331}', '\u{4E}', '\u{32D}', '\u{6E}', '\u{32D}', '\u{4F}', '\u{303}',
+    '\u{301}', '\u{6F}', '\u{303}', '\u{301}', '\u{4F}', '\u{303}', '\u{308}', '\u{6F}',
+    '\u{303}', '\u{308}', '\u{4F}', '\u{304}', '\u{300}', '\u{6F}', '\u{304}', '\u{300}',
+    '\u{4F}', '\u{304}', '\u{301}', '\u{6F}', '\u{304}', '\u{301}', '\u{50}', '\u{301}',
+    '\u{70}', '\u{301}', '\u{50}', '\u{307}', '\u{70}', '\u{307}', '\u{52}', '\u{307}',
+    '\u{72}', '\u{307}', '\u{52}', '\u{323}', '\u{72}', '\u{323}', '\u{52}', '\u{323}',
+    '\u{304}', '\u{72}', '\u{323}', '\u{304}', '\u{52}', '\u{331}', '\u{72}', '\u{331}',
+    '\u{53}', '\u{307}', '\u{73}', '\u{307}', '\u{53}', '\u{323}', '\u{73}', '\u{323}',
+    '\u{53}', '\u{301}', '\u{307}', '\u{73}', '\u{301}', '\u{307}', '\u{53}', '\u{30C}',
+    '\u{307}', '\u{73}', '\u{30C}', '\u{307}', '\u{53}', '\u{323}', '\u{307}', '\u{73}',
+    '\u{323}', '\u{307}', '\u{54}', '\u{307}', '\u{74}', '\u{307}', '\u{54}', '\u{323}',
+    '\u{74}', '\u{323}', '\u{54}', '\u{331}', '\u{74}', '\u{331}', '\u{54}', '\u{32D}',
+    '\u{74}', '\u{32D}', '\u{55}', '\u{324}', '\u{75}', '\u{324}', '\u{55}', '\u{330}',
+    '\u{75}', '\u{330}', '\u{55}', '\u{32D}', '\u{75}', '\u{32D}', '\u{55}', '\u{303}',
+    '\u{301}', '\u{75}', '\u{303}', '\u{301}', '\u{55}', '\u{304}', '\u{308}', '\u{75}',
+    '\u{304}', '\u{308}', '\u{56}', '\u{303}', '\u{76}', '\u{303}', '\u{56}', '\u{323}',
+    '\u{76}', '\u{323}', '\u{57}', '\u{300}', '\u{77}', '\u{300}', '\u{57}', '\u{301}',
+    '\u{77}', '\u{301}', '\u{57}', '\u{308}', '\u{77}', '\u{308}', '\u{57}', '\u{307}',
+    '\u{77}', '\u{307}', '\u{57}', '\u{323}', '\u{77}', '\u{323}', '\u{58}', '\u{307}',
+    '\u{78}', '\u{307}', '\u{58}', '\u{308}', '\u{78}', '\u{308}', '\u{59}', '\u{307}',
+    '\u{79}', '\u{307}', '\u{5A}', '\u{302}', '\u{7A}', '\u{302}', '\u{5A}', '\u{323}',
+    '\u{7A}', '\u{323}', '\u{5A}', '\u{331}', '\u{7A}', '\u{331}', '\u{68}', '\u{331}',
+    '\u{74}', '\u{308}', '\u{77}', '\u{30A}', '\u{79}', '\u{30A}', '\u{17F}', '\u{307}',
+    '\u{41}', '\u{323}', '\u{61}', '\u{323}', '\u{41}', '\u{309}', '\u{61}', '\u{309}',
+    '\u{41}', '\u{302}', '\u{301}', '\u{61}', '\u{302}', '\u{301}', '\u{41}', '\u{302}',
+    '\u{300}', '\u{61}', '\u{302}', '\u{300}', '\u{41}', '\u{302}', '\u{309}', '\u{61}',
+    '\u{302}', '\u{309}', '\u{41}', '\u{302}', '\u{303}', '\u{61}', '\u{302}', '\u{303}',
+    '\u{41}', '\u{323}', '\u{302}', '\u{61}', '\u{323}', '\u{302}', '\u{41}', '\u{306}',
+    '\u{301}', '\u{61}', '\u{306}', '\u{301}', '\u{41}', '\u{306}', '\u{300}', '\u{61}',
+    '\u{306}', '\u{300}', '\u{41}', '\u{306}', '\u{309}', '\u{61}', '\u{306}', '\u{309}',
+    '\u{41}', '\u{306}', '\u{303}', '\u{61}', '\u{306}', '\u{303}', '\u{41}', '\u{323}',
+    '\u{306}', '\u{61}', '\u{323}', '\u{306}', '\u{45}', '\u{323}', '\u{65}', '\u{323}',
+    '\u{45}', '\u{309}', '\u{65}', '\u{309}', '\u{45}', '\u{303}', '\u{65}', '\u{303}',
+    '\u{45}', '\u{302}', '\u{301}', '\u{65}', '\u{302}', '\u{301}', '\u{45}', '\u{302}',
+    '\u{300}', '\u{65}', '\u{302}', '\u{300}', '\u{45}', '\u{302}', '\u{309}', '\u{65}',
+    '\u{302}', '\u{309}', '\u{45}', '\u{302}', '\u{303}', '\u{65}', '\u{302}', '\u{303}',
+    '\u{45}', '\u{323}', '\u{302}', '\u{65}', '\u{323}', '\u{302}', '\u{49}', '\u{309}',
+    '\u{69}', '\u{309}', '\u{49}', '\u{323}', '\u{69}', '\u{323}', '\u{4F}', '\u{323}',
+    '\u{6F}', '\u{323}', '\u{4F}', '\u{309}', '\u{6F}', '\u{309}', '\u{4F}', '\u{302}',
+    '\u{301}', '\u{6F}', '\u{302}', '\u{301}', '\u{4F}', '\u{302}', '\u{300}', '\u{6F}',
+    '\u{302}', '\u{300}', '\u{4F}', '\u{302}', '\u{309}', '\u{6F}', '\u{302}', '\u{309}',
+    '\u{4F}', '\u{302}', '\u{303}', '\u{6F}', '\u{302}', '\u{303}', '\u{4F}', '\u{323}',
+    '\u{302}', '\u{6F}', '\u{323}', '\u{302}', '\u{4F}', '\u{31B}', '\u{301}', '\u{6F}',
+    '\u{31B}', '\u{301}', '\u{4F}', '\u{31B}', '\u{300}', '\u{6F}', '\u{31B}', '\u{300}',
+    '\u{4F}', '\u{31B}', '\u{309}', '\u{6F}', '\u{31B}', '\u{309}', '\u{4F}', '\u{31B}',
+    '\u{303}', '\u{6F}', '\u{31B}', '\u{303}', '\u{4F}', '\u{31B}', '\u{323}', '\u{6F}',
+    '\u{31B}', '\u{323}', '\u{55}', '\u{323}', '\u{75}', '\u{323}', '\u{55}', '\u{309}',
+    '\u{75}', '\u{309}', '\u{55}', '\u{31B}', '\u{301}', '\u{75}', '\u{31B}', '\u{301}',
+    '\u{55}', '\u{31B}', '\u{300}', '\u{75}', '\u{31B}', '\u{300}', '\u{55}', '\u{31B}',
+    '\u{309}', '\u{75}', '\u{31B}', '\u{309}', '\u{55}', '\u{31B}', '\u{303}', '\u{75}',
+    '\u{31B}', '\u{303}', '\u{55}', '\u{31B}', '\u{323}', '\u{75}', '\u{31B}', '\u{323}',
+    '\u{59}', '\u{300}', '\u{79}', '\u{300}', '\u{59}', '\u{323}', '\u{79}', '\u{323}',
+    '\u{59}', '\u{309}', '\u{79}', '\u{309}', '\u{59}', '\u{303}', '\u{79}', '\u{303}',
+    '\u{3B1}', '\u{313}', '\u{3B1}', '\u{314}', '\u{3B1}', '\u{313}', '\u{300}', '\u{3B1}',
+    '\u{314}', '\u{300}', '\u{3B1}', '\u{313}', '\u{301}', '\u{3B1}', '\u{314}', '\u{301}',
+    '\u{3B1}', '\u{313}', '\u{342}', '\u{3B1}', '\u{314}', '\u{342}', '\u{391}', '\u{313}',
+    '\u{391}', '\u{314}', '\u{391}', '\u{313}', '\u{300}', '\u{391}', '\u{314}', '\u{300}',
+    '\u{391}', '\u{313}', '\u{301}', '\u{391}', '\u{314}', '\u{301}', '\u{391}', '\u{313}',
+    '\u{342}', '\u{391}', '\u{314}', '\u{342}', '\u{3B5}', '\u{313}', '\u{3B5}', '\u{314}',
+    '\u{3B5}', '\u{313}', '\u{300}', '\u{3B5}', '\u{314}', '\u{300}', '\u{3B5}', '\u{313}',
+    '\u{301}', '\u{3B5}', '\u{314}', '\u{301}', '\u{395}', '\u{313}', '\u{395}', '\u{314}',
+    '\u{395}', '\u{313}', '\u{300}', '\u{395}', '\u{314}', '\u{300}', '\u{395}', '\u{313}',
+    '\u{301}', '\u{395}', '\u{314}', '\u{301}', '\u{3B7}', '\u{313}', '\u{3B7}', '\u{314}',
+    '\u{3B7}', '\u{313}', '\u{300}', '\u{3B7}', '\u{314}', '\u{300}', '\u{3B7}', '\u{313}',
+    '\u{301}', '\u{3B7}', '\u{314}', '\u{301}', '\u{3B7}', '\u{313}', '\u{342}', '\u{3B7}',
+    '\u{314}', '\u{342}', '\u{397}', '\u{313}', '\u{397}', '\u{314}', '\u{397}', '\u{313}',
+    '\u{300}', '\u{397}', '\u{314}', '\u{300}', '\u{397}', '\u{313}', '\u{301}', '\u{397}',
+    '\u{314}', '\u{301}', '\u{397}', '\u{313}', '\u{342}', '\u{397}', '\u{314}', '\u{342}',
+    '\u{3B9}', '\u{313}', '\u{3B9}', '\u{314}', '\u{3B9}', '\u{313}', '\u{300}', '\u{3B9}',
+    '\u{314}', '\u{300}', '\u{3B9}', '\u{313}', '\u{301}', '\u{3B9}', '\u{314}', '\u{301}',
+    '\u{3B9}', '\u{313}', '\u{342}', '\u{3B9}', '\u{314}', '\u{342}', '\u{399}', '\u{313}',
+    '\u{399}', '\u{314}', '\u{399}', '\u{313}', '\u{300}', '\u{399}', '\u{314}', '\u{300}',
+    '\u{399}', '\u{313}', '\u{301}', '\u{399}', '\u{314}', '\u{301}', '\u{399}', '\u{313}',
+    '\u{342}', '\u{399}', '\u{314}', '\u{342}', '\u{3BF}', '\u{313}', '\u{3BF}', '\u{314}',
+    '\u{3BF}', '\u{313}', '\u{300}', '\u{3BF}', '\u{314}', '\u{300}', '\u{3BF}', '\u{313}',
+    '\u{301}', '\u{3BF}', '\u{314}', '\u{301}', '\u{39F}', '\u{313}', '\u{39F}', '\u{314}',
+    '\u{39F}', '\u{313}', '\u{300}', '\u{39F}', '\u{314}', '\u{300}', '\u{39F}', '\u{313}',
+    '\u{301}', '\u{39F}', '\u{314}', '\u{301}', '\u{3C5}', '\u{313}', '\u{3C5}', '\u{314}',
+    '\u{3C5}', '\u{313}', '\u{300}', '\u{3C5}', '\u{314}', '\u{300}', '\u{3C5}', '\u{313}',
+    '\u{301}', '\u{3C5}', '\u{314}', '\u{301}', '\u{3C5}', '\u{313}', '\u{342}', '\u{3C5}',
+    '\u{314}', '\u{342}', '\u{3A5}', '\u{314}', '\u{3A5}', '\u{314}', '\u{300}', '\u{3A5}',
+    '\u{314}', '\u{301}', '\u{3A5}', '\u{314}', '\u{342}', '\u{3C9}', '\u{313}', '\u{3C9}',
+    '\u{314}', '\u{3C9}', '\u{313}', '\u{300}', '\u{3C9}', '\u{314}', '\u{300}', '\u{3C9}',
+    '\u{313}', '\u{301}', '\u{3C9}', '\u{314}', '\u{301}', '\u{3C9}', '\u{313}', '\u{342}',
+    '\u{3C9}', '\u{314}', '\u{342}', '\u{3A9}', '\u{313}', '\u{3A9}', '\u{314}', '\u{3A9}',
+    '\u{313}', '\u{300}', '\u{3A9}', '\u{314}', '\u{300}', '\u{3A9}', '\u{313}', '\u{301}',
+    '\u{3A9}', '\u{314}', '\u{301}', '\u{3A9}', '\u{313}', '\u{342}', '\u{3A9}', '\u{314}',
+    '\u{342}', '\u{3B1}', '\u{300}', '\u{3B1}', '\u{301}', '\u{3B5}', '\u{300}', '\u{3B5}',
+    '\u{301}', '\u{3B7}', '\u{300}', '\u{3B7}', '\u{301}', '\u{3B9}', '\u{300}', '\u{3B9}',
+    '\u{301}', '\u{3BF}', '\u{300}', '\u{3BF}', '\u{301}', '\u{3C5}', '\u{300}', '\u{3C5}',
+    '\u{301}', '\u{3C9}', '\u{300}', '\u{3C9}', '\u{301}', '\u{3B1}', '\u{313}', '\u{345}',
+    '\u{3B1}', '\u{314}', '\u{345}', '\u{3B1}', '\u{313}', '\u{300}', '\u{345}', '\u{3B1}',
+    '\u{314}', '\u{300}', '\u{345}', '\u{3B1}', '\u{313}', '\u{301}', '\u{345}', '\u{3B1}',
+    '\u{314}', '\u{301}', '\u{345}', '\u{3B1}', '\u{313}', '\u{342}', '\u{345}', '\u{3B1}',
+    '\u{314}', '\u{342}', '\u{345}', '\u{391}', '\u{313}', '\u{345}', '\u{391}', '\u{314}',
+    '\u{345}', '\u{391}', '\u{313}', '\u{300}', '\u{345}', '\u{391}', '\u{314}', '\u{300}',
+    '\u{345}', '\u{391}', '\u{313}', '\u{301}', '\u{345}', '\u{391}', '\u{314}', '\u{301}',
+    '\u{345}', '\u{391}', '\u{313}', '\u{342}', '\u{345}', '\u{391}', '\u{314}', '\u{342}',
+    '\u{345}', '\u{3B7}', '\u{313}', '\u{345}', '\u{3B7}', '\u{314}', '\u{345}', '\u{3B7}',
+    '\u{313}', '\u{300}', '\u{345}', '\u{3B7}', '\u{314}', '\u{300}', '\u{345}', '\u{3B7}',
+    '\u{313}', '\u{301}', '\u{345}', '\u{3B7}', '\u{314}', '\u{301}', '\u{345}', '\u{3B7}',
+    '\u{313}', '\u{342}', '\u{345}', '\u{3B7}', '\u{314}', '\u{342}', '\u{345}', '\u{397}',
+    '\u{313}', '\u{345}', '\u{397}', '\u{314}', '\u{345}', '\u{397}', '\u{313}', '\u{300}',
+    '\u{345}', '\u{397}', '\u{314}', '\u{300}', '\u{345}', '\u{397}', '\u{313}', '\u{301}',
+    '\u{345}', '\u{397}', '\u{314}', '\u{301}', '\u{345}', '\u{397}', '\u{313}', '\u{342}',
+    '\u{345}', '\u{397}', '\u{314}', '\u{342}', '\u{345}', '\u{3C9}', '\u{313}', '\u{345}',
+    '\u{3C9}', '\u{314}', '\u{345}', '\u{3C9}', '\u{313}', '\u{300}', '\u{345}', '\u{3C9}',
+    '\u{314}', '\u{300}', '\u{345}', '\u{3C9}', '\u{313}', '\u{301}', '\u{345}', '\u{3C9}',
+    '\u{314}', '\u{301}', '\u{345}', '\u{3C9}', '\u{313}', '\u{342}', '\u{345}', '\u{3C9}',
+    '\u{314}', '\u{342}', '\u{345}', '\u{3A9}', '\u{313}', '\u{345}', '\u{3A9}', '\u{314}',
+    '\u{345}', '\u{3A9}', '\u{313}', '\u{300}', '\u{345}', '\u{3A9}', '\u{314}', '\u{300}',
+    '\u{345}', '\u{3A9}', '\u{313}', '\u{301}', '\u{345}', '\u{3A9}', '\u{314}', '\u{301}',
+    '\u{345}', '\u{3A9}', '\u{313}', '\u{342}', '\u{345}', '\u{3A9}', '\u{314}', '\u{342}',
+    '\u{345}', '\u{3B1}', '\u{306}', '\u{3B1}', '\u{304}', '\u{3B1}', '\u{300}', '\u{345}',
+    '\u{3B1}', '\u{345}', '\u{3B1}', '\u{301}', '\u{345}', '\u{3B1}', '\u{342}', '\u{3B1}',
+    '\u{342}', '\u{345}', '\u{391}', '\u{306}', '\u{391}', '\u{304}', '\u{391}', '\u{300}',
+    '\u{391}', '\u{301}', '\u{391}', '\u{345}', '\u{3B9}', '\u{A8}', '\u{342}', '\u{3B7}',
+    '\u{300}', '\u{345}', '\u{3B7}', '\u{345}', '\u{3B7}', '\u{301}', '\u{345}', '\u{3B7}',
+    '\u{342}', '\u{3B7}', '\u{342}', '\u{345}', '\u{395}', '\u{300}', '\u{395}', '\u{301}',
+    '\u{397}', '\u{300}', '\u{397}', '\u{301}', '\u{397}', '\u{345}', '\u{1FBF}', '\u{300}',
+    '\u{1FBF}', '\u{301}', '\u{1FBF}', '\u{342}', '\u{3B9}', '\u{306}', '\u{3B9}', '\u{304}',
+    '\u{3B9}', '\u{308}', '\u{300}', '\u{3B9}', '\u{308}', '\u{301}', '\u{3B9}', '\u{342}',
+    '\u{3B9}', '\u{308}', '\u{342}', '\u{399}', '\u{306}', '\u{399}', '\u{304}', '\u{399}',
+    '\u{300}', '\u{399}', '\u{301}', '\u{1FFE}', '\u{300}', '\u{1FFE}', '\u{301}', '\u{1FFE}',
+    '\u{342}', '\u{3C5}', '\u{306}', '\u{3C5}', '\u{304}', '\u{3C5}', '\u{308}', '\u{300}',
+    '\u{3C5}', '\u{308}', '\u{301}', '\u{3C1}', '\u{313}', '\u{3C1}', '\u{314}', '\u{3C5}',
+    '\u{342}', '\u{3C5}', '\u{308}', '\u{342}', '\u{3A5}', '\u{306}', '\u{3A5}', '\u{304}',
+    '\u{3A5}', '\u{300}', '\u{3A5}', '\u{301}', '\u{3A1}', '\u{314}', '\u{A8}', '\u{300}',
+    '\u{A8}', '\u{301}', '\u{60}', '\u{3C9}', '\u{300}', '\u{345}', '\u{3C9}', '\u{345}',
+    '\u{3C9}', '\u{301}', '\u{345}', '\u{3C9}', '\u{342}', '\u{3C9}', '\u{342}', '\u{345}',
+    '\u{39F}', '\u{300}', '\u{39F}', '\u{301}', '\u{3A9}', '\u{300}', '\u{3A9}', '\u{301}',
+    '\u{3A9}', '\u{345}', '\u{B4}', '\u{2002}', '\u{2003}', '\u{3A9}', '\u{4B}', '\u{41}',
+    '\u{30A}', '\u{2190}', '\u{338}', '\u{2192}', '\u{338}', '\u{2194}', '\u{338}', '\u{21D0}',
+    '\u{338}', '\u{21D4}', '\u{338}', '\u{21D2}', '\u{338}', '\u{2203}', '\u{338}', '\u{2208}',
+    '\u{338}', '\u{220B}', '\u{338}', '\u{2223}', '\u{338}', '\u{2225}', '\u{338}', '\u{223C}',
+    '\u{338}', '\u{2243}', '\u{338}', '\u{2245}', '\u{338}', '\u{2248}', '\u{338}', '\u{3D}',
+    '\u{338}', '\u{2261}', '\u{338}', '\u{224D}', '\u{338}', '\u{3C}', '\u{338}', '\u{3E}',
+    '\u{338}', '\u{2264}', '\u{338}', '\u{2265}', '\u{338}', '\u{2272}', '\u{338}', '\u{2273}',
+    '\u{338}', '\u{2276}', '\u{338}', '\u{2277}', '\u{338}', '\u{227A}', '\u{338}', '\u{227B}',
+    '\u{338}', '\u{2282}', '\u{338}', '\u{2283}', '\u{338}', '\u{2286}', '\u{338}', '\u{2287}',
+    '\u{338}', '\u{22A2}', '\u{338}', '\u{22A8}', '\u{338}', '\u{22A9}', '\u{338}', '\u{22AB}',
+    '\u{338}', '\u{227C}', '\u{338}', '\u{227D}', '\u{338}', '\u{2291}', '\u{338}', '\u{2292}',
+    '\u{338}', '\u{22B2}', '\u{338}', '\u{22B3}', '\u{338}', '\u{22B4}', '\u{338}', '\u{22B5}',
+    '\u{338}', '\u{3008}', '\u{3009}', '\u{2ADD}', '\u{338}', '\u{304B}', '\u{3099}', '\u{304D}',
+    '\u{3099}', '\u{304F}', '\u{3099}', '\u{3051}', '\u{3099}', '\u{3053}', '\u{3099}', '\u{3055}',
+    '\u{3099}', '\u{3057}', '\u{3099}', '\u{3059}', '\u{3099}', '\u{305B}', '\u{3099}', '\u{305D}',
+    '\u{3099}', '\u{305F}', '\u{3099}', '\u{3061}', '\u{3099}', '\u{3064}', '\u{3099}', '\u{3066}',
+    '\u{3099}', '\u{3068}', '\u{3099}', '\u{306F}', '\u{3099}', '\u{306F}', '\u{309A}', '\u{3072}',
+    '\u{3099}', '\u{3072}', '\u{309A}', '\u{3075}', '\u{3099}', '\u{3075}', '\u{309A}', '\u{3078}',
+    '\u{3099}', '\u{3078}', '\u{309A}', '\u{307B}', '\u{3099}', '\u{307B}', '\u{309A}', '\u{3046}',
+    '\u{3099}', '\u{309D}', '\u{3099}', '\u{30AB}', '\u{3099}', '\u{30AD}', '\u{3099}', '\u{30AF}',
+    '\u{3099}', '\u{30B1}', '\u{3099}', '\u{30B3}', '\u{3099}', '\u{30B5}', '\u{3099}', '\u{30B7}',
+    '\u{3099}', '\u{30B9}', '\u{3099}', '\u{30BB}', '\u{3099}', '\u{30BD}', '\u{3099}', '\u{30BF}',
+    '\u{3099}', '\u{30C1}', '\u{3099}', '\u{30C4}', '\u{3099}', '\u{30C6}', '\u{3099}', '\u{30C8}',
+    '\u{3099}', '\u{30CF}', '\u{3099}', '\u{30CF}', '\u{309A}', '\u{30D2}', '\u{3099}', '\u{30D2}',
+    '\u{309A}', '\u{30D5}', '\u{3099}', '\u{30D5}', '\u{309A}', '\u{30D8}', '\u{3099}', '\u{30D8}',
+    '\u{309A}', '\u{30DB}', '\u{3099}', '\u{30DB}', '\u{309A}', '\u{30A6}', '\u{3099}', '\u{30EF}',
+    '\u{3099}', '\u{30F0}', '\u{3099}', '\u{30F1}', '\u{3099}', '\u{30F2}', '\u{3099}', '\u{30FD}',
+    '\u{3099}', '\u{8C48}', '\u{66F4}', '\u{8ECA}', '\u{8CC8}', '\u{6ED1}', '\u{4E32}', '\u{53E5}',
+    '\u{9F9C}', '\u{9F9C}', '\u{5951}', '\u{91D1}', '\u{5587}', '\u{5948}', '\u{61F6}', '\u{7669}',
+    '\u{7F85}', '\u{863F}', '\u{87BA}', '\u{88F8}', '\u{908F}', '\u{6A02}', '\u{6D1B}', '\u{70D9}',
+    '\u{73DE}', '\u{843D}', '\u{916A}', '\u{99F1}', '\u{4E82}', '\u{5375}', '\u{6B04}', '\u{721B}',
+    '\u{862D}', '\u{9E1E}', '\u{5D50}', '\u{6FEB}', '\u{85CD}', '\u{8964}', '\u{62C9}', '\u{81D8}',
+    '\u{881F}', '\u{5ECA}', '\u{6717}', '\u{6D6A}', '\u{72FC}', '\u{90CE}', '\u{4F86}', '\u{51B7}',
+    '\u{52DE}', '\u{64C4}', '\u{6AD3}', '\u{7210}', '\u{76E7}', '\u{8001}', '\u{8606}', '\u{865C}',
+    '\u{8DEF}', '\u{9732}', '\u{9B6F}', '\u{9DFA}', '\u{788C}', '\u{797F}', '\u{7DA0}', '\u{83C9}',
+    '\u{9304}', '\u{9E7F}', '\u{8AD6}', '\u{58DF}', '\u{5F04}', '\u{7C60}', '\u{807E}', '\u{7262}',
+    '\u{78CA}', '\u{8CC2}', '\u{96F7}', '\u{58D8}', '\u{5C62}', '\u{6A13}', '\u{6DDA}', '\u{6F0F}',
+    '\u{7D2F}', '\u{7E37}', '\u{964B}', '\u{52D2}', '\u{808B}', '\u{51DC}', '\u{51CC}', '\u{7A1C}',
+    '\u{7DBE}', '\u{83F1}', '\u{9675}', '\u{8B80}', '\u{62CF}', '\u{6A02}', '\u{8AFE}', '\u{4E39}',
+    '\u{5BE7}', '\u{6012}', '\u{7387}', '\u{7570}', '\u{5317}', '\u{78FB}', '\u{4FBF}', '\u{5FA9}',
+    '\u{4E0D}', '\u{6CCC}', '\u{6578}', '\u{7D22}', '\u{53C3}', '\u{585E}', '\u{7701}', '\u{8449}',
+    '\u{8AAA}', '\u{6BBA}', '\u{8FB0}', '\u{6C88}', '\u{62FE}', '\u{82E5}', '\u{63A0}', '\u{7565}',
+    '\u{4EAE}', '\u{5169}', '\u{51C9}', '\u{6881}', '\u{7CE7}', '\u{826F}', '\u{8AD2}', '\u{91CF}',
+    '\u{52F5}', '\u{5442}', '\u{5973}', '\u{5EEC}', '\u{65C5}', '\u{6FFE}', '\u{792A}', '\u{95AD}',
+    '\u{9A6A}', '\u{9E97}', '\u{9ECE}', '\u{529B}', '\u{66C6}', '\u{6B77}', '\u{8F62}', '\u{5E74}',
+    '\u{6190}', '\u{6200}', '\u{649A}', '\u{6F23}', '\u{7149}', '\u{7489}', '\u{79CA}', '\u{7DF4}',
+    '\u{806F}', '\u{8F26}', '\u{84EE}', '\u{9023}', '\u{934A}', '\u{5217}', '\u{52A3}', '\u{54BD}',
+    '\u{70C8}', '\u{88C2}', '\u{8AAA}', '\u{5EC9}', '\u{5FF5}', '\u{637B}', '\u{6BAE}', '\u{7C3E}',
+    '\u{7375}', '\u{4EE4}', '\u{56F9}', '\u{5BE7}', '\u{5DBA}', '\u{601C}', '\u{73B2}', '\u{7469}',
+    '\u{7F9A}', '\u{8046}', '\u{9234}', '\u{96F6}', '\u{9748}', '\u{9818}', '\u{4F8B}', '\u{79AE}',
+    '\u{91B4}', '\u{96B8}', '\u{60E1}', '\u{4E86}', '\u{50DA}', '\u{5BEE}', '\u{5C3F}', '\u{6599}',
+    '\u{6A02}', '\u{71CE}', '\u{7642}', '\u{84FC}', '\u{907C}', '\u{9F8D}', '\u{6688}', '\u{962E}',
+    '\u{5289}', '\u{677B}', '\u{67F3}', '\u{6D41}', '\u{6E9C}', '\u{7409}', '\u{7559}', '\u{786B}',
+    '\u{7D10}', '\u{985E}', '\u{516D}', '\u{622E}', '\u{9678}', '\u{502B}', '\u{5D19}', '\u{6DEA}',
+    '\u{8F2A}', '\u{5F8B}', '\u{6144}', '\u{6817}', '\u{7387}', '\u{9686}', '\u{5229}', '\u{540F}',
+    '\u{5C65}', '\u{6613}', '\u{674E}', '\u{68A8}', '\u{6CE5}', '\u{7406}', '\u{75E2}', '\u{7F79}',
+    '\u{88CF}', '\u{88E1}', '\u{91CC}', '\u{96E2}', '\u{533F}', '\u{6EBA}', '\u{541D}', '\u{71D0}',
+    '\u{7498}', '\u{85FA}', '\u{96A3}', '\u{9C57}', '\u{9E9F}', '\u{6797}', '\u{6DCB}', '\u{81E8}',
+    '\u{7ACB}', '\u{7B20}', '\u{7C92}', '\u{72C0}', '\u{7099}', '\u{8B58}', '\u{4EC0}', '\u{8336}',
+    '\u{523A}', '\u{5207}', '\u{5EA6}', '\u{62D3}', '\u{7CD6}', '\u{5B85}', '\u{6D1E}', '\u{66B4}',
+    '\u{8F3B}', '\u{884C}', '\u{964D}', '\u{898B}', '\u{5ED3}', '\u{5140}', '\u{55C0}', '\u{585A}',
+    '\u{6674}', '\u{51DE}', '\u{732A}', '\u{76CA}', '\u{793C}', '\u{795E}', '\u{7965}', '\u{798F}',
+    '\u{9756}', '\u{7CBE}', '\u{7FBD}', '\u{8612}', '\u{8AF8}', '\u{9038}', '\u{90FD}', '\u{98EF}',
+    '\u{98FC}', '\u{9928}', '\u{9DB4}', '\u{90DE}', '\u{96B7}', '\u{4FAE}', '\u{50E7}', '\u{514D}',
+    '\u{52C9}', '\u{52E4}', '\u{5351}', '\u{559D}', '\u{5606}', '\u{5668}', '\u{5840}', '\u{58A8}',
+    '\u{5C64}', '\u{5C6E}', '\u{6094}', '\u{6168}', '\u{618E}', '\u{61F2}', '\u{654F}', '\u{65E2}',
+    '\u{6691}', '\u{6885}', '\u{6D77}', '\u{6E1A}', '\u{6F22}', '\u{716E}', '\u{722B}', '\u{7422}',
+    '\u{7891}', '\u{793E}', '\u{7949}', '\u{7948}', '\u{7950}', '\u{7956}', '\u{795D}', '\u{798D}',
+    '\u{798E}', '\u{7A40}', '\u{7A81}', '\u{7BC0}', '\u{7DF4}', '\u{7E09}', '\u{7E41}', '\u{7F72}',
+    '\u{8005}', '\u{81ED}', '\u{8279}', '\u{8279}', '\u{8457}', '\u{8910}', '\u{8996}', '\u{8B01}',
+    '\u{8B39}', '\u{8CD3}', '\u{8D08}', '\u{8FB6}', '\u{9038}', '\u{96E3}', '\u{97FF}', '\u{983B}',
+    '\u{6075}', '\u{242EE}', '\u{8218}', '\u{4E26}', '\u{51B5}', '\u{5168}', '\u{4F80}', '\u{5145}',
+    '\u{5180}', '\u{52C7}', '\u{52FA}', '\u{559D}', '\u{5555}', '\u{5599}', '\u{55E2}', '\u{585A}',
+    '\u{58B3}', '\u{5944}', '\u{5954}', '\u{5A62}', '\u{5B28}', '\u{5ED2}', '\u{5ED9}', '\u{5F69}',
+    '\u{5FAD}', '\u{60D8}', '\u{614E}', '\u{6108}', '\u{618E}', '\u{6160}', '\u{61F2}', '\u{6234}',
+    '\u{63C4}', '\u{641C}', '\u{6452}', '\u{6556}', '\u{6674}', '\u{6717}', '\u{671B}', '\u{6756}',
+    '\u{6B79}', '\u{6BBA}', '\u{6D41}', '\u{6EDB}', '\u{6ECB}', '\u{6F22}', '\u{701E}', '\u{716E}',
+    '\u{77A7}', '\u{7235}', '\u{72AF}', '\u{732A}', '\u{7471}', '\u{7506}', '\u{753B}', '\u{761D}',
+    '\u{761F}', '\u{76CA}', '\u{76DB}', '\u{76F4}', '\u{774A}', '\u{7740}', '\u{78CC}', '\u{7AB1}',
+    '\u{7BC0}', '\u{7C7B}', '\u{7D5B}', '\u{7DF4}', '\u{7F3E}', '\u{8005}', '\u{8352}', '\u{83EF}',
+    '\u{8779}', '\u{8941}', '\u{8986}', '\u{8996}', '\u{8ABF}', '\u{8AF8}', '\u{8ACB}', '\u{8B01}',
+    '\u{8AFE}', '\u{8AED}', '\u{8B39}', '\u{8B8A}', '\u{8D08}', '\u{8F38}', '\u{9072}', '\u{9199}',
+    '\u{9276}', '\u{967C}', '\u{96E3}', '\u{9756}', '\u{97DB}', '\u{97FF}', '\u{980B}', '\u{983B}',
+    '\u{9B12}', '\u{9F9C}', '\u{2284A}', '\u{22844}', '\u{233D5}', '\u{3B9D}', '\u{4018}', '\u{4039}',
+    '\u{25249}', '\u{25CD0}', '\u{27ED3}', '\u{9F43}', '\u{9F8E}', '\u{5D9}', '\u{5B4}', '\u{5F2}',
+    '\u{5B7}', '\u{5E9}', '\u{5C1}', '\u{5E9}', '\u{5C2}', '\u{5E9}', '\u{5BC}', '\u{5C1}',
+    '\u{5E9}', '\u{5BC}', '\u{5C2}', '\u{5D0}', '\u{5B7}', '\u{5D0}', '\u{5B8}', '\u{5D0}',
+    '\u{5BC}', '\u{5D1}', '\u{5BC}', '\u{5D2}', '\u{5BC}', '\u{5D3}', '\u{5BC}', '\u{5D4}',
+    '\u{5BC}', '\u{5D5}', '\u{5BC}', '\u{5D6}', '\u{5BC}', '\u{5D8}', '\u{5BC}', '\u{5D9}',
+    '\u{5BC}', '\u{5DA}', '\u{5BC}', '\u{5DB}', '\u{5BC}', '\u{5DC}', '\u{5BC}', '\u{5DE}',
+    '\u{5BC}', '\u{5E0}', '\u{5BC}', '\u{5E1}', '\u{5BC}', '\u{5E3}', '\u{5BC}', '\u{5E4}',
+    '\u{5BC}', '\u{5E6}', '\u{5BC}', '\u{5E7}', '\u{5BC}', '\u{5E8}', '\u{5BC}', '\u{5E9}',
+    '\u{5BC}', '\u{5EA}', '\u{5BC}', '\u{5D5}', '\u{5B9}', '\u{5D1}', '\u{5BF}', '\u{5DB}',
+    '\u{5BF}', '\u{5E4}', '\u{5BF}', '\u{11099}', '\u{110BA}', '\u{1109B}', '\u{110BA}', '\u{110A5}',
+    '\u{110BA}', '\u{11131}', '\u{11127}', '\u{11132}', '\u{11127}', '\u{11347}', '\u{1133E}', '\u{11347}',
+    '\u{11357}', '\u{114B9}', '\u{114BA}', '\u{114B9}', '\u{114B0}', '\u{114B9}', '\u{114BD}', '\u{115B8}',
+    '\u{115AF}', '\u{115B9}', '\u{115AF}', '\u{11935}', '\u{11930}', '\u{1D157}', '\u{1D165}', '\u{1D158}',
+    '\u{1D165}', '\u{1D158}', '\u{1D165}', '\u{1D16E}', '\u{1D158}', '\u{1D165}', '\u{1D16F}', '\u{1D158}',
+    '\u{1D165}', '\u{1D170}', '\u{1D158}', '\u{1D165}', '\u{1D171}', '\u{1D158}', '\u{1D165}', '\u{1D172}',
+    '\u{1D1B9}', '\u{1D165}', '\u{1D1BA}', '\u{1D165}', '\u{1D1B9}', '\u{1D165}', '\u{1D16E}', '\u{1D1BA}',
+    '\u{1D165}', '\u{1D16E}', '\u{1D1B9}', '\u{1D165}', '\u{1D16F}', '\u{1D1BA}', '\u{1D165}', '\u{1D16F}',
+    '\u{4E3D}', '\u{4E38}', '\u{4E41}', '\u{20122}', '\u{4F60}', '\u{4FAE}', '\u{4FBB}', '\u{5002}',
+    '\u{507A}', '\u{5099}', '\u{50E7}', '\u{50CF}', '\u{349E}', '\u{2063A}', '\u{514D}', '\u{5154}',
+    '\u{5164}', '\u{5177}', '\u{2051C}', '\u{34B9}', '\u{5167}', '\u{518D}', '\u{2054B}', '\u{5197}',
+    '\u{51A4}', '\u{4ECC}', '\u{51AC}', '\u{51B5}', '\u{291DF}', '\u{51F5}', '\u{5203}', '\u{34DF}',
+    '\u{523B}', '\u{5246}', '\u{5272}', '\u{5277}', '\u{3515}', '\u{52C7}', '\u{52C9}', '\u{52E4}',
+    '\u{52FA}', '\u{5305}', '\u{5306}', '\u{5317}', '\u{5349}', '\u{5351}', '\u{535A}', '\u{5373}',
+    '\u{537D}', '\u{537F}', '\u{537F}', '\u{537F}', '\u{20A2C}', '\u{7070}', '\u{53CA}', '\u{53DF}',
+    '\u{20B63}', '\u{53EB}', '\u{53F1}', '\u{5406}', '\u{549E}', '\u{5438}', '\u{5448}', '\u{5468}',
+    '\u{54A2}', '\u{54F6}', '\u{5510}', '\u{5553}', '\u{5563}', '\u{5584}', '\u{5584}', '\u{5599}',
+    '\u{55AB}', '\u{55B3}', '\u{55C2}', '\u{5716}', '\u{5606}', '\u{5717}', '\u{5651}', '\u{5674}',
+    '\u{5207}', '\u{58EE}', '\u{57CE}', '\u{57F4}', '\u{580D}', '\u{578B}', '\u{5832}', '\u{5831}',
+    '\u{58AC}', '\u{214E4}', '\u{58F2}', '\u{58F7}', '\u{5906}', '\u{591A}', '\u{5922}', '\u{5962}',
+    '\u{216A8}', '\u{216EA}', '\u{59EC}', '\u{5A1B}', '\u{5A27}', '\u{59D8}', '\u{5A66}', '\u{36EE}',
+    '\u{36FC}', '\u{5B08}', '\u{5B3E}', '\u{5B3E}', '\u{219C8}', '\u{5BC3}', '\u{5BD8}', '\u{5BE7}',
+    '\u{5BF3}', '\u{21B18}', '\u{5BFF}', '\u{5C06}', '\u{5F53}', '\u{5C22}', '\u{3781}', '\u{5C60}',
+    '\u{5C6E}', '\u{5CC0}', '\u{5C8D}', '\u{21DE4}', '\u{5D43}', '\u{21DE6}', '\u{5D6E}', '\u{5D6B}',
+    '\u{5D7C}', '\u{5DE1}', '\u{5DE2}', '\u{382F}', '\u{5DFD}', '\u{5E28}', '\u{5E3D}', '\u{5E69}',
+    '\u{3862}', '\u{22183}', '\u{387C}', '\u{5EB0}', '\u{5EB3}', '\u{5EB6}', '\u{5ECA}', '\u{2A392}',
+    '\u{5EFE}', '\u{22331}', '\u{22331}', '\u{8201}', '\u{5F22}', '\u{5F22}', '\u{38C7}', '\u{232B8}',
+    '\u{261DA}', '\u{5F62}', '\u{5F6B}', '\u{38E3}', '\u{5F9A}', '\u{5FCD}', '\u{5FD7}', '\u{5FF9}',
+    '\u{6081}', '\u{393A}', '\u{391C}', '\u{6094}', '\u{226D4}', '\u{60C7}', '\u{6148}', '\u{614C}',
+    '\u{614E}', '\u{614C}', '\u{617A}', '\u{618E}', '\u{61B2}', '\u{61A4}', '\u{61AF}', '\u{61DE}',
+    '\u{61F2}', '\u{61F6}', '\u{6210}', '\u{621B}', '\u{625D}', '\u{62B1}', '\u{62D4}', '\u{6350}',
+    '\u{22B0C}', '\u{633D}', '\u{62FC}', '\u{6368}', '\u{6383}', '\u{63E4}', '\u{22BF1}', '\u{6422}',
+    '\u{63C5}', '\u{63A9}', '\u{3A2E}', '\u{6469}', '\u{647E}', '\u{649D}', '\u{6477}', '\u{3A6C}',
+    '\u{654F}', '\u{656C}', '\u{2300A}', '\u{65E3}', '\u{66F8}', '\u{6649}', '\u{3B19}', '\u{6691}',
+    '\u{3B08}', '\u{3AE4}', '\u{5192}', '\u{5195}', '\u{6700}', '\u{669C}', '\u{80AD}', '\u{43D9}',
+    '\u{6717}', '\u{671B}', '\u{6721}', '\u{675E}', '\u{6753}', '\u{233C3}', '\u{3B49}', '\u{67FA}',
+    '\u{6785}', '\u{6852}', '\u{6885}', '\u{2346D}', '\u{688E}', '\u{681F}', '\u{6914}', '\u{3B9D}',
+    '\u{6942}', '\u{69A3}', '\u{69EA}', '\u{6AA8}', '\u{236A3}', '\u{6ADB}', '\u{3C18}', '\u{6B21}',
+    '\u{238A7}', '\u{6B54}', '\u{3C4E}', '\u{6B72}', '\u{6B9F}', '\u{6BBA}', '\u{6BBB}', '\u{23A8D}',
+    '\u{21D0B}', '\u{23AFA}', '\u{6C4E}', '\u{23CBC}', '\u{6CBF}', '\u{6CCD}', '\u{6C67}', '\u{6D16}',
+    '\u{6D3E}', '\u{6D77}', '\u{6D41}', '\u{6D69}', '\u{6D78}', '\u{6D85}', '\u{23D1E}', '\u{6D34}',
+    '\u{6E2F}', '\u{6E6E}', '\u{3D33}', '\u{6ECB}', '\u{6EC7}', '\u{23ED1}', '\u{6DF9}', '\u{6F6E}',
+    '\u{23F5E}', '\u{23F8E}', '\u{6FC6}', '\u{7039}', '\u{701E}', '\u{701B}', '\u{3D96}', '\u{704A}',
+    '\u{707D}', '\u{7077}', '\u{70AD}', '\u{20525}', '\u{7145}', '\u{24263}', '\u{719C}', '\u{243AB}',
+    '\u{7228}', '\u{7235}', '\u{7250}', '\u{24608}', '\u{7280}', '\u{7295}', '\u{24735}', '\u{24814}',
+    '\u{737A}', '\u{738B}', '\u{3EAC}', '\u{73A5}', '\u{3EB8}', '\u{3EB8}', '\u{7447}', '\u{745C}',
+    '\u{7471}', '\u{7485}', '\u{74CA}', '\u{3F1B}', '\u{7524}', '\u{24C36}', '\u{753E}', '\u{24C92}',
+    '\u{7570}', '\u{2219F}', '\u{7610}', '\u{24FA1}', '\u{24FB8}', '\u{25044}', '\u{3FFC}', '\u{4008}',
+    '\u{76F4}', '\u{250F3}', '\u{250F2}', '\u{25119}', '\u{25133}', '\u{771E}', '\u{771F}', '\u{771F}',
+    '\u{774A}', '\u{4039}', '\u{778B}', '\u{4046}', '\u{4096}', '\u{2541D}', '\u{784E}', '\u{788C}',
+    '\u{78CC}', '\u{40E3}', '\u{25626}', '\u{7956}', '\u{2569A}', '\u{256C5}', '\u{798F}', '\u{79EB}',
+    '\u{412F}', '\u{7A40}', '\u{7A4A}', '\u{7A4F}', '\u{2597C}', '\u{25AA7}', '\u{25AA7}', '\u{7AEE}',
+    '\u{4202}', '\u{25BAB}', '\u{7BC6}', '\u{7BC9}', '\u{4227}', '\u{25C80}', '\u{7CD2}', '\u{42A0}',
+    '\u{7CE8}', '\u{7CE3}', '\u{7D00}', '\u{25F86}', '\u{7D63}', '\u{4301}', '\u{7DC7}', '\u{7E02}',
+    '\u{7E45}', '\u{4334}', '\u{26228}', '\u{26247}', '\u{4359}', '\u{262D9}', '\u{7F7A}', '\u{2633E}',
+    '\u{7F95}', '\u{7FFA}', '\u{8005}', '\u{264DA}', '\u{26523}', '\u{8060}', '\u{265A8}', '\u{8070}',
+    '\u{2335F}', '\u{43D5}', '\u{80B2}', '\u{8103}', '\u{440B}', '\u{813E}', '\u{5AB5}', '\u{267A7}',
+    '\u{267B5}', '\u{23393}', '\u{2339C}', '\u{8201}', '\u{8204}', '\u{8F9E}', '\u{446B}', '\u{8291}',
+    '\u{828B}', '\u{829D}', '\u{52B3}', '\u{82B1}', '\u{82B3}', '\u{82BD}', '\u{82E6}', '\u{26B3C}',
+    '\u{82E5}', '\u{831D}', '\u{8363}', '\u{83AD}', '\u{8323}', '\u{83BD}', '\u{83E7}', '\u{8457}',
+    '\u{8353}', '\u{83CA}', '\u{83CC}', '\u{83DC}', '\u{26C36}', '\u{26D6B}', '\u{26CD5}', '\u{452B}',
+    '\u{84F1}', '\u{84F3}', '\u{8516}', '\u{273CA}', '\u{8564}', '\u{26F2C}', '\u{455D}', '\u{4561}',
+    '\u{26FB1}', '\u{270D2}', '\u{456B}', '\u{8650}', '\u{865C}', '\u{8667}', '\u{8669}', '\u{86A9}',
+    '\u{8688}', '\u{870E}', '\u{86E2}', '\u{8779}', '\u{8728}', '\u{876B}', '\u{8786}', '\u{45D7}',
+    '\u{87E1}', '\u{8801}', '\u{45F9}', '\u{8860}', '\u{8863}', '\u{27667}', '\u{88D7}', '\u{88DE}',
+    '\u{4635}', '\u{88FA}', '\u{34BB}', '\u{278AE}', '\u{27966}', '\u{46BE}', '\u{46C7}', '\u{8AA0}',
+    '\u{8AED}', '\u{8B8A}', '\u{8C55}', '\u{27CA8}', '\u{8CAB}', '\u{8CC1}', '\u{8D1B}', '\u{8D77}',
+    '\u{27F2F}', '\u{20804}', '\u{8DCB}', '\u{8DBC}', '\u{8DF0}', '\u{208DE}', '\u{8ED4}', '\u{8F38}',
+    '\u{285D2}', '\u{285ED}', '\u{9094}', '\u{90F1}', '\u{9111}', '\u{2872E}', '\u{911B}', '\u{9238}',
+    '\u{92D7}', '\u{92D8}', '\u{927C}', '\u{93F9}', '\u{9415}', '\u{28BFA}', '\u{958B}', '\u{4995}',
+    '\u{95B7}', '\u{28D77}', '\u{49E6}', '\u{96C3}', '\u{5DB2}', '\u{9723}', '\u{29145}', '\u{2921A}',
+    '\u{4A6E}', '\u{4A76}', '\u{97E0}', '\u{2940A}', '\u{4AB2}', '\u{29496}', '\u{980B}', '\u{980B}',
+    '\u{9829}', '\u{295B6}', '\u{98E2}', '\u{4B33}', '\u{9929}', '\u{99A7}', '\u{99C2}', '\u{99FE}',
+    '\u{4BCE}', '\u{29B30}', '\u{9B12}', '\u{9C40}', '\u{9CFD}', '\u{4CCE}', '\u{4CED}', '\u{9D67}',
+    '\u{2A0CE}', '\u{4CF8}', '\u{2A105}', '\u{2A20E}', '\u{2A291}', '\u{9EBB}', '\u{4D56}', '\u{9EF9}',
+    '\u{9EFE}', '\u{9F05}', '\u{9F0F}', '\u{9F16}', '\u{9F3B}', '\u{2A600}', '\u{20}', '\u{20}',
+    '\u{308}', '\u{61}', '\u{20}', '\u{304}', '\u{32}', '\u{33}', '\u{20}', '\u{301}',
+    '\u{3BC}', '\u{20}', '\u{327}', '\u{31}', '\u{6F}', '\u{31}', '\u{2044}', '\u{34}',
+    '\u{31}', '\u{2044}', '\u{32}', '\u{33}', '\u{2044}', '\u{34}', '\u{49}', '\u{4A}',
+    '\u{69}', '\u{6A}', '\u{4C}', '\u{B7}', '\u{6C}', '\u{B7}', '\u{2BC}', '\u{6E}',
+    '\u{73}', '\u{44}', '\u{5A}', '\u{30C}', '\u{44}', '\u{7A}', '\u{30C}', '\u{64}',
+    '\u{7A}', '\u{30C}', '\u{4C}', '\u{4A}', '\u{4C}', '\u{6A}', '\u{6C}', '\u{6A}',
+    '\u{4E}', '\u{4A}', '\u{4E}', '\u{6A}', '\u{6E}', '\u{6A}', '\u{44}', '\u{5A}',
+    '\u{44}', '\u{7A}', '\u{64}', '\u{7A}', '\u{68}', '\u{266}', '\u{6A}', '\u{72}',
+    '\u{279}', '\u{27B}', '\u{281}', '\u{77}', '\u{79}', '\u{20}', '\u{306}', '\u{20}',
+    '\u{307}', '\u{20}', '\u{30A}', '\u{20}', '\u{328}', '\u{20}', '\u{303}', '\u{20}',
+    '\u{30B}', '\u{263}', '\u{6C}', '\u{73}', '\u{78}', '\u{295}', '\u{20}', '\u{345}',
+    '\u{20}', '\u{301}', '\u{20}', '\u{308}', '\u{301}', '\u{3B2}', '\u{3B8}', '\u{3A5}',
+    '\u{3A5}', '\u{301}', '\u{3A5}', '\u{308}', '\u{3C6}', '\u{3C0}', '\u{3BA}', '\u{3C1}',
+    '\u{3C2}', '\u{398}', '\u{3B5}', '\u{3A3}', '\u{565}', '\u{582}', '\u{627}', '\u{674}',
+    '\u{648}', '\u{674}', '\u{6C7}', '\u{674}', '\u{64A}', '\u{674}', '\u{E4D}', '\u{E32}',
+    '\u{ECD}', '\u{EB2}', '\u{EAB}', '\u{E99}', '\u{EAB}', '\u{EA1}', '\u{F0B}', '\u{FB2}',
+    '\u{F71}', '\u{F80}', '\u{FB3}', '\u{F71}', '\u{F80}', '\u{10DC}', '\u{41}', '\u{C6}',
+    '\u{42}', '\u{44}', '\u{45}', '\u{18E}', '\u{47}', '\u{48}', '\u{49}', '\u{4A}',
+    '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}', '\u{222}', '\u{50}', '\u{52}',
+    '\u{54}', '\u{55}', '\u{57}', '\u{61}', '\u{250}', '\u{251}', '\u{1D02}', '\u{62}',
+    '\u{64}', '\u{65}', '\u{259}', '\u{25B}', '\u{25C}', '\u{67}', '\u{6B}', '\u{6D}',
+    '\u{14B}', '\u{6F}', '\u{254}', '\u{1D16}', '\u{1D17}', '\u{70}', '\u{74}', '\u{75}',
+    '\u{1D1D}', '\u{26F}', '\u{76}', '\u{1D25}', '\u{3B2}', '\u{3B3}', '\u{3B4}', '\u{3C6}',
+    '\u{3C7}', '\u{69}', '\u{72}', '\u{75}', '\u{76}', '\u{3B2}', '\u{3B3}', '\u{3C1}',
+    '\u{3C6}', '\u{3C7}', '\u{43D}', '\u{252}', '\u{63}', '\u{255}', '\u{F0}', '\u{25C}',
+    '\u{66}', '\u{25F}', '\u{261}', '\u{265}', '\u{268}', '\u{269}', '\u{26A}', '\u{1D7B}',
+    '\u{29D}', '\u{26D}', '\u{1D85}', '\u{29F}', '\u{271}', '\u{270}', '\u{272}', '\u{273}',
+    '\u{274}', '\u{275}', '\u{278}', '\u{282}', '\u{283}', '\u{1AB}', '\u{289}', '\u{28A}',
+    '\u{1D1C}', '\u{28B}', '\u{28C}', '\u{7A}', '\u{290}', '\u{291}', '\u{292}', '\u{3B8}',
+    '\u{61}', '\u{2BE}', '\u{73}', '\u{307}', '\u{20}', '\u{313}', '\u{20}', '\u{313}',
+    '\u{20}', '\u{342}', '\u{20}', '\u{308}', '\u{342}', '\u{20}', '\u{313}', '\u{300}',
+    '\u{20}', '\u{313}', '\u{301}', '\u{20}', '\u{313}', '\u{342}', '\u{20}', '\u{314}',
+    '\u{300}', '\u{20}', '\u{314}', '\u{301}', '\u{20}', '\u{314}', '\u{342}', '\u{20}',
+    '\u{308}', '\u{300}', '\u{20}', '\u{308}', '\u{301}', '\u{20}', '\u{301}', '\u{20}',
+    '\u{314}', '\u{20}', '\u{20}', '\u{20}', '\u{20}', '\u{20}', '\u{20}', '\u{20}',
+    '\u{20}', '\u{20}', '\u{20}', '\u{20}', '\u{2010}', '\u{20}', '\u{333}', '\u{2E}',
+    '\u{2E}', '\u{2E}', '\u{2E}', '\u{2E}', '\u{2E}', '\u{20}', '\u{2032}', '\u{2032}',
+    '\u{2032}', '\u{2032}', '\u{2032}', '\u{2035}', '\u{2035}', '\u{2035}', '\u{2035}', '\u{2035}',
+    '\u{21}', '\u{21}', '\u{20}', '\u{305}', '\u{3F}', '\u{3F}', '\u{3F}', '\u{21}',
+    '\u{21}', '\u{3F}', '\u{2032}', '\u{2032}', '\u{2032}', '\u{2032}', '\u{20}', '\u{30}',
+    '\u{69}', '\u{34}', '\u{35}', '\u{36}', '\u{37}', '\u{38}', '\u{39}', '\u{2B}',
+    '\u{2212}', '\u{3D}', '\u{28}', '\u{29}', '\u{6E}', '\u{30}', '\u{31}', '\u{32}',
+    '\u{33}', '\u{34}', '\u{35}', '\u{36}', '\u{37}', '\u{38}', '\u{39}', '\u{2B}',
+    '\u{2212}', '\u{3D}', '\u{28}', '\u{29}', '\u{61}', '\u{65}', '\u{6F}', '\u{78}',
+    '\u{259}', '\u{68}', '\u{6B}', '\u{6C}', '\u{6D}', '\u{6E}', '\u{70}', '\u{73}',
+    '\u{74}', '\u{52}', '\u{73}', '\u{61}', '\u{2F}', '\u{63}', '\u{61}', '\u{2F}',
+    '\u{73}', '\u{43}', '\u{B0}', '\u{43}', '\u{63}', '\u{2F}', '\u{6F}', '\u{63}',
+    '\u{2F}', '\u{75}', '\u{190}', '\u{B0}', '\u{46}', '\u{67}', '\u{48}', '\u{48}',
+    '\u{48}', '\u{68}', '\u{127}', '\u{49}', '\u{49}', '\u{4C}', '\u{6C}', '\u{4E}',
+    '\u{4E}', '\u{6F}', '\u{50}', '\u{51}', '\u{52}', '\u{52}', '\u{52}', '\u{53}',
+    '\u{4D}', '\u{54}', '\u{45}', '\u{4C}', '\u{54}', '\u{4D}', '\u{5A}', '\u{5A}',
+    '\u{42}', '\u{43}', '\u{65}', '\u{45}', '\u{46}', '\u{4D}', '\u{6F}', '\u{5D0}',
+    '\u{5D1}', '\u{5D2}', '\u{5D3}', '\u{69}', '\u{46}', '\u{41}', '\u{58}', '\u{3C0}',
+    '\u{3B3}', '\u{393}', '\u{3A0}', '\u{2211}', '\u{44}', '\u{64}', '\u{65}', '\u{69}',
+    '\u{6A}', '\u{31}', '\u{2044}', '\u{37}', '\u{31}', '\u{2044}', '\u{39}', '\u{31}',
+    '\u{2044}', '\u{31}', '\u{30}', '\u{31}', '\u{2044}', '\u{33}', '\u{32}', '\u{2044}',
+    '\u{33}', '\u{31}', '\u{2044}', '\u{35}', '\u{32}', '\u{2044}', '\u{35}', '\u{33}',
+    '\u{2044}', '\u{35}', '\u{34}', '\u{2044}', '\u{35}', '\u{31}', '\u{2044}', '\u{36}',
+    '\u{35}', '\u{2044}', '\u{36}', '\u{31}', '\u{2044}', '\u{38}', '\u{33}', '\u{2044}',
+    '\u{38}', '\u{35}', '\u{2044}', '\u{38}', '\u{37}', '\u{2044}', '\u{38}', '\u{31}',
+    '\u{2044}', '\u{49}', '\u{49}', '\u{49}', '\u{49}', '\u{49}', '\u{49}', '\u{49}',
+    '\u{56}', '\u{56}', '\u{56}', '\u{49}', '\u{56}', '\u{49}', '\u{49}', '\u{56}',
+    '\u{49}', '\u{49}', '\u{49}', '\u{49}', '\u{58}', '\u{58}', '\u{58}', '\u{49}',
+    '\u{58}', '\u{49}', '\u{49}', '\u{4C}', '\u{43}', '\u{44}', '\u{4D}', '\u{69}',
+    '\u{69}', '\u{69}', '\u{69}', '\u{69}', '\u{69}', '\u{69}', '\u{76}', '\u{76}',
+    '\u{76}', '\u{69}', '\u{76}', '\u{69}', '\u{69}', '\u{76}', '\u{69}', '\u{69}',
+    '\u{69}', '\u{69}', '\u{78}', '\u{78}', '\u{78}', '\u{69}', '\u{78}', '\u{69}',
+    '\u{69}', '\u{6C}', '\u{63}', '\u{64}', '\u{6D}', '\u{30}', '\u{2044}', '\u{33}',
+    '\u{222B}', '\u{222B}', '\u{222B}', '\u{222B}', '\u{222B}', '\u{222E}', '\u{222E}', '\u{222E}',
+    '\u{222E}', '\u{222E}', '\u{31}', '\u{32}', '\u{33}', '\u{34}', '\u{35}', '\u{36}',
+    '\u{37}', '\u{38}', '\u{39}', '\u{31}', '\u{30}', '\u{31}', '\u{31}', '\u{31}',
+    '\u{32}', '\u{31}', '\u{33}', '\u{31}', '\u{34}', '\u{31}', '\u{35}', '\u{31}',
+    '\u{36}', '\u{31}', '\u{37}', '\u{31}', '\u{38}', '\u{31}', '\u{39}', '\u{32}',
+    '\u{30}', '\u{28}', '\u{31}', '\u{29}', '\u{28}', '\u{32}', '\u{29}', '\u{28}',
+    '\u{33}', '\u{29}', '\u{28}', '\u{34}', '\u{29}', '\u{28}', '\u{35}', '\u{29}',
+    '\u{28}', '\u{36}', '\u{29}', '\u{28}', '\u{37}', '\u{29}', '\u{28}', '\u{38}',
+    '\u{29}', '\u{28}', '\u{39}', '\u{29}', '\u{28}', '\u{31}', '\u{30}', '\u{29}',
+    '\u{28}', '\u{31}', '\u{31}', '\u{29}', '\u{28}', '\u{31}', '\u{32}', '\u{29}',
+    '\u{28}', '\u{31}', '\u{33}', '\u{29}', '\u{28}', '\u{31}', '\u{34}', '\u{29}',
+    '\u{28}', '\u{31}', '\u{35}', '\u{29}', '\u{28}', '\u{31}', '\u{36}', '\u{29}',
+    '\u{28}', '\u{31}', '\u{37}', '\u{29}', '\u{28}', '\u{31}', '\u{38}', '\u{29}',
+    '\u{28}', '\u{31}', '\u{39}', '\u{29}', '\u{28}', '\u{32}', '\u{30}', '\u{29}',
+    '\u{31}', '\u{2E}', '\u{32}', '\u{2E}', '\u{33}', '\u{2E}', '\u{34}', '\u{2E}',
+    '\u{35}', '\u{2E}', '\u{36}', '\u{2E}', '\u{37}', '\u{2E}', '\u{38}', '\u{2E}',
+    '\u{39}', '\u{2E}', '\u{31}', '\u{30}', '\u{2E}', '\u{31}', '\u{31}', '\u{2E}',
+    '\u{31}', '\u{32}', '\u{2E}', '\u{31}', '\u{33}', '\u{2E}', '\u{31}', '\u{34}',
+    '\u{2E}', '\u{31}', '\u{35}', '\u{2E}', '\u{31}', '\u{36}', '\u{2E}', '\u{31}',
+    '\u{37}', '\u{2E}', '\u{31}', '\u{38}', '\u{2E}', '\u{31}', '\u{39}', '\u{2E}',
+    '\u{32}', '\u{30}', '\u{2E}', '\u{28}', '\u{61}', '\u{29}', '\u{28}', '\u{62}',
+    '\u{29}', '\u{28}', '\u{63}', '\u{29}', '\u{28}', '\u{64}', '\u{29}', '\u{28}',
+    '\u{65}', '\u{29}', '\u{28}', '\u{66}', '\u{29}', '\u{28}', '\u{67}', '\u{29}',
+    '\u{28}', '\u{68}', '\u{29}', '\u{28}', '\u{69}', '\u{29}', '\u{28}', '\u{6A}',
+    '\u{29}', '\u{28}', '\u{6B}', '\u{29}', '\u{28}', '\u{6C}', '\u{29}', '\u{28}',
+    '\u{6D}', '\u{29}', '\u{28}', '\u{6E}', '\u{29}', '\u{28}', '\u{6F}', '\u{29}',
+    '\u{28}', '\u{70}', '\u{29}', '\u{28}', '\u{71}', '\u{29}', '\u{28}', '\u{72}',
+    '\u{29}', '\u{28}', '\u{73}', '\u{29}', '\u{28}', '\u{74}', '\u{29}', '\u{28}',
+    '\u{75}', '\u{29}', '\u{28}', '\u{76}', '\u{29}', '\u{28}', '\u{77}', '\u{29}',
+    '\u{28}', '\u{78}', '\u{29}', '\u{28}', '\u{79}', '\u{29}', '\u{28}', '\u{7A}',
+    '\u{29}', '\u{41}', '\u{42}', '\u{43}', '\u{44}', '\u{45}', '\u{46}', '\u{47}',
+    '\u{48}', '\u{49}', '\u{4A}', '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}',
+    '\u{50}', '\u{51}', '\u{52}', '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{57}',
+    '\u{58}', '\u{59}', '\u{5A}', '\u{61}', '\u{62}', '\u{63}', '\u{64}', '\u{65}',
+    '\u{66}', '\u{67}', '\u{68}', '\u{69}', '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}',
+    '\u{6E}', '\u{6F}', '\u{70}', '\u{71}', '\u{72}', '\u{73}', '\u{74}', '\u{75}',
+    '\u{76}', '\u{77}', '\u{78}', '\u{79}', '\u{7A}', '\u{30}', '\u{222B}', '\u{222B}',
+    '\u{222B}', '\u{222B}', '\u{3A}', '\u{3A}', '\u{3D}', '\u{3D}', '\u{3D}', '\u{3D}',
+    '\u{3D}', '\u{3D}', '\u{6A}', '\u{56}', '\u{2D61}', '\u{6BCD}', '\u{9F9F}', '\u{4E00}',
+    '\u{4E28}', '\u{4E36}', '\u{4E3F}', '\u{4E59}', '\u{4E85}', '\u{4E8C}', '\u{4EA0}', '\u{4EBA}',
+    '\u{513F}', '\u{5165}', '\u{516B}', '\u{5182}', '\u{5196}', '\u{51AB}', '\u{51E0}', '\u{51F5}',
+    '\u{5200}', '\u{529B}', '\u{52F9}', '\u{5315}', '\u{531A}', '\u{5338}', '\u{5341}', '\u{535C}',
+    '\u{5369}', '\u{5382}', '\u{53B6}', '\u{53C8}', '\u{53E3}', '\u{56D7}', '\u{571F}', '\u{58EB}',
+    '\u{5902}', '\u{590A}', '\u{5915}', '\u{5927}', '\u{5973}', '\u{5B50}', '\u{5B80}', '\u{5BF8}',
+    '\u{5C0F}', '\u{5C22}', '\u{5C38}', '\u{5C6E}', '\u{5C71}', '\u{5DDB}', '\u{5DE5}', '\u{5DF1}',
+    '\u{5DFE}', '\u{5E72}', '\u{5E7A}', '\u{5E7F}', '\u{5EF4}', '\u{5EFE}', '\u{5F0B}', '\u{5F13}',
+    '\u{5F50}', '\u{5F61}', '\u{5F73}', '\u{5FC3}', '\u{6208}', '\u{6236}', '\u{624B}', '\u{652F}',
+    '\u{6534}', '\u{6587}', '\u{6597}', '\u{65A4}', '\u{65B9}', '\u{65E0}', '\u{65E5}', '\u{66F0}',
+    '\u{6708}', '\u{6728}', '\u{6B20}', '\u{6B62}', '\u{6B79}', '\u{6BB3}', '\u{6BCB}', '\u{6BD4}',
+    '\u{6BDB}', '\u{6C0F}', '\u{6C14}', '\u{6C34}', '\u{706B}', '\u{722A}', '\u{7236}', '\u{723B}',
+    '\u{723F}', '\u{7247}', '\u{7259}', '\u{725B}', '\u{72AC}', '\u{7384}', '\u{7389}', '\u{74DC}',
+    '\u{74E6}', '\u{7518}', '\u{751F}', '\u{7528}', '\u{7530}', '\u{758B}', '\u{7592}', '\u{7676}',
+    '\u{767D}', '\u{76AE}', '\u{76BF}', '\u{76EE}', '\u{77DB}', '\u{77E2}', '\u{77F3}', '\u{793A}',
+    '\u{79B8}', '\u{79BE}', '\u{7A74}', '\u{7ACB}', '\u{7AF9}', '\u{7C73}', '\u{7CF8}', '\u{7F36}',
+    '\u{7F51}', '\u{7F8A}', '\u{7FBD}', '\u{8001}', '\u{800C}', '\u{8012}', '\u{8033}', '\u{807F}',
+    '\u{8089}', '\u{81E3}', '\u{81EA}', '\u{81F3}', '\u{81FC}', '\u{820C}', '\u{821B}', '\u{821F}',
+    '\u{826E}', '\u{8272}', '\u{8278}', '\u{864D}', '\u{866B}', '\u{8840}', '\u{884C}', '\u{8863}',
+    '\u{897E}', '\u{898B}', '\u{89D2}', '\u{8A00}', '\u{8C37}', '\u{8C46}', '\u{8C55}', '\u{8C78}',
+    '\u{8C9D}', '\u{8D64}', '\u{8D70}', '\u{8DB3}', '\u{8EAB}', '\u{8ECA}', '\u{8F9B}', '\u{8FB0}',
+    '\u{8FB5}', '\u{9091}', '\u{9149}', '\u{91C6}', '\u{91CC}', '\u{91D1}', '\u{9577}', '\u{9580}',
+    '\u{961C}', '\u{96B6}', '\u{96B9}', '\u{96E8}', '\u{9751}', '\u{975E}', '\u{9762}', '\u{9769}',
+    '\u{97CB}', '\u{97ED}', '\u{97F3}', '\u{9801}', '\u{98A8}', '\u{98DB}', '\u{98DF}', '\u{9996}',
+    '\u{9999}', '\u{99AC}', '\u{9AA8}', '\u{9AD8}', '\u{9ADF}', '\u{9B25}', '\u{9B2F}', '\u{9B32}',
+    '\u{9B3C}', '\u{9B5A}', '\u{9CE5}', '\u{9E75}', '\u{9E7F}', '\u{9EA5}', '\u{9EBB}', '\u{9EC3}',
+    '\u{9ECD}', '\u{9ED1}', '\u{9EF9}', '\u{9EFD}', '\u{9F0E}', '\u{9F13}', '\u{9F20}', '\u{9F3B}',
+    '\u{9F4A}', '\u{9F52}', '\u{9F8D}', '\u{9F9C}', '\u{9FA0}', '\u{20}', '\u{3012}', '\u{5341}',
+    '\u{5344}', '\u{5345}', '\u{20}', '\u{3099}', '\u{20}', '\u{309A}', '\u{3088}', '\u{308A}',
+    '\u{30B3}', '\u{30C8}', '\u{1100}', '\u{1101}', '\u{11AA}', '\u{1102}', '\u{11AC}', '\u{11AD}',
+    '\u{1103}', '\u{1104}', '\u{1105}', '\u{11B0}', '\u{11B1}', '\u{11B2}', '\u{11B3}', '\u{11B4}',
+    '\u{11B5}', '\u{111A}', '\u{1106}', '\u{1107}', '\u{1108}', '\u{1121}', '\u{1109}', '\u{110A}',
+    '\u{110B}', '\u{110C}', '\u{110D}', '\u{110E}', '\u{110F}', '\u{1110}', '\u{1111}', '\u{1112}',
+    '\u{1161}', '\u{1162}', '\u{1163}', '\u{1164}', '\u{1165}', '\u{1166}', '\u{1167}', '\u{1168}',
+    '\u{1169}', '\u{116A}', '\u{116B}', '\u{116C}', '\u{116D}', '\u{116E}', '\u{116F}', '\u{1170}',
+    '\u{1171}', '\u{1172}', '\u{1173}', '\u{1174}', '\u{1175}', '\u{1160}', '\u{1114}', '\u{1115}',
+    '\u{11C7}', '\u{11C8}', '\u{11CC}', '\u{11CE}', '\u{11D3}', '\u{11D7}', '\u{11D9}', '\u{111C}',
+    '\u{11DD}', '\u{11DF}', '\u{111D}', '\u{111E}', '\u{1120}', '\u{1122}', '\u{1123}', '\u{1127}',
+    '\u{1129}', '\u{112B}', '\u{112C}', '\u{112D}', '\u{112E}', '\u{112F}', '\u{1132}', '\u{1136}',
+    '\u{1140}', '\u{1147}', '\u{114C}', '\u{11F1}', '\u{11F2}', '\u{1157}', '\u{1158}', '\u{1159}',
+    '\u{1184}', '\u{1185}', '\u{1188}', '\u{1191}', '\u{1192}', '\u{1194}', '\u{119E}', '\u{11A1}',
+    '\u{4E00}', '\u{4E8C}', '\u{4E09}', '\u{56DB}', '\u{4E0A}', '\u{4E2D}', '\u{4E0B}', '\u{7532}',
+    '\u{4E59}', '\u{4E19}', '\u{4E01}', '\u{5929}', '\u{5730}', '\u{4EBA}', '\u{28}', '\u{1100}',
+    '\u{29}', '\u{28}', '\u{1102}', '\u{29}', '\u{28}', '\u{1103}', '\u{29}', '\u{28}',
+    '\u{1105}', '\u{29}', '\u{28}', '\u{1106}', '\u{29}', '\u{28}', '\u{1107}', '\u{29}',
+    '\u{28}', '\u{1109}', '\u{29}', '\u{28}', '\u{110B}', '\u{29}', '\u{28}', '\u{110C}',
+    '\u{29}', '\u{28}', '\u{110E}', '\u{29}', '\u{28}', '\u{110F}', '\u{29}', '\u{28}',
+    '\u{1110}', '\u{29}', '\u{28}', '\u{1111}', '\u{29}', '\u{28}', '\u{1112}', '\u{29}',
+    '\u{28}', '\u{1100}', '\u{1161}', '\u{29}', '\u{28}', '\u{1102}', '\u{1161}', '\u{29}',
+    '\u{28}', '\u{1103}', '\u{1161}', '\u{29}', '\u{28}', '\u{1105}', '\u{1161}', '\u{29}',
+    '\u{28}', '\u{1106}', '\u{1161}', '\u{29}', '\u{28}', '\u{1107}', '\u{1161}', '\u{29}',
+    '\u{28}', '\u{1109}', '\u{1161}', '\u{29}', '\u{28}', '\u{110B}', '\u{1161}', '\u{29}',
+    '\u{28}', '\u{110C}', '\u{1161}', '\u{29}', '\u{28}', '\u{110E}', '\u{1161}', '\u{29}',
+    '\u{28}', '\u{110F}', '\u{1161}', '\u{29}', '\u{28}', '\u{1110}', '\u{1161}', '\u{29}',
+    '\u{28}', '\u{1111}', '\u{1161}', '\u{29}', '\u{28}', '\u{1112}', '\u{1161}', '\u{29}',
+    '\u{28}', '\u{110C}', '\u{116E}', '\u{29}', '\u{28}', '\u{110B}', '\u{1169}', '\u{110C}',
+    '\u{1165}', '\u{11AB}', '\u{29}', '\u{28}', '\u{110B}', '\u{1169}', '\u{1112}', '\u{116E}',
+    '\u{29}', '\u{28}', '\u{4E00}', '\u{29}', '\u{28}', '\u{4E8C}', '\u{29}', '\u{28}',
+    '\u{4E09}', '\u{29}', '\u{28}', '\u{56DB}', '\u{29}', '\u{28}', '\u{4E94}', '\u{29}',
+    '\u{28}', '\u{516D}', '\u{29}', '\u{28}', '\u{4E03}', '\u{29}', '\u{28}', '\u{516B}',
+    '\u{29}', '\u{28}', '\u{4E5D}', '\u{29}', '\u{28}', '\u{5341}', '\u{29}', '\u{28}',
+    '\u{6708}', '\u{29}', '\u{28}', '\u{706B}', '\u{29}', '\u{28}', '\u{6C34}', '\u{29}',
+    '\u{28}', '\u{6728}', '\u{29}', '\u{28}', '\u{91D1}', '\u{29}', '\u{28}', '\u{571F}',
+    '\u{29}', '\u{28}', '\u{65E5}', '\u{29}', '\u{28}', '\u{682A}', '\u{29}', '\u{28}',
+    '\u{6709}', '\u{29}', '\u{28}', '\u{793E}', '\u{29}', '\u{28}', '\u{540D}', '\u{29}',
+    '\u{28}', '\u{7279}', '\u{29}', '\u{28}', '\u{8CA1}', '\u{29}', '\u{28}', '\u{795D}',
+    '\u{29}', '\u{28}', '\u{52B4}', '\u{29}', '\u{28}', '\u{4EE3}', '\u{29}', '\u{28}',
+    '\u{547C}', '\u{29}', '\u{28}', '\u{5B66}', '\u{29}', '\u{28}', '\u{76E3}', '\u{29}',
+    '\u{28}', '\u{4F01}', '\u{29}', '\u{28}', '\u{8CC7}', '\u{29}', '\u{28}', '\u{5354}',
+    '\u{29}', '\u{28}', '\u{796D}', '\u{29}', '\u{28}', '\u{4F11}', '\u{29}', '\u{28}',
+    '\u{81EA}', '\u{29}', '\u{28}', '\u{81F3}', '\u{29}', '\u{554F}', '\u{5E7C}', '\u{6587}',
+    '\u{7B8F}', '\u{50}', '\u{54}', '\u{45}', '\u{32}', '\u{31}', '\u{32}', '\u{32}',
+    '\u{32}', '\u{33}', '\u{32}', '\u{34}', '\u{32}', '\u{35}', '\u{32}', '\u{36}',
+    '\u{32}', '\u{37}', '\u{32}', '\u{38}', '\u{32}', '\u{39}', '\u{33}', '\u{30}',
+    '\u{33}', '\u{31}', '\u{33}', '\u{32}', '\u{33}', '\u{33}', '\u{33}', '\u{34}',
+    '\u{33}', '\u{35}', '\u{1100}', '\u{1102}', '\u{1103}', '\u{1105}', '\u{1106}', '\u{1107}',
+    '\u{1109}', '\u{110B}', '\u{110C}', '\u{110E}', '\u{110F}', '\u{1110}', '\u{1111}', '\u{1112}',
+    '\u{1100}', '\u{1161}', '\u{1102}', '\u{1161}', '\u{1103}', '\u{1161}', '\u{1105}', '\u{1161}',
+    '\u{1106}', '\u{1161}', '\u{1107}', '\u{1161}', '\u{1109}', '\u{1161}', '\u{110B}', '\u{1161}',
+    '\u{110C}', '\u{1161}', '\u{110E}', '\u{1161}', '\u{110F}', '\u{1161}', '\u{1110}', '\u{1161}',
+    '\u{1111}', '\u{1161}', '\u{1112}', '\u{1161}', '\u{110E}', '\u{1161}', '\u{11B7}', '\u{1100}',
+    '\u{1169}', '\u{110C}', '\u{116E}', '\u{110B}', '\u{1174}', '\u{110B}', '\u{116E}', '\u{4E00}',
+    '\u{4E8C}', '\u{4E09}', '\u{56DB}', '\u{4E94}', '\u{516D}', '\u{4E03}', '\u{516B}', '\u{4E5D}',
+    '\u{5341}', '\u{6708}', '\u{706B}', '\u{6C34}', '\u{6728}', '\u{91D1}', '\u{571F}', '\u{65E5}',
+    '\u{682A}', '\u{6709}', '\u{793E}', '\u{540D}', '\u{7279}', '\u{8CA1}', '\u{795D}', '\u{52B4}',
+    '\u{79D8}', '\u{7537}', '\u{5973}', '\u{9069}', '\u{512A}', '\u{5370}', '\u{6CE8}', '\u{9805}',
+    '\u{4F11}', '\u{5199}', '\u{6B63}', '\u{4E0A}', '\u{4E2D}', '\u{4E0B}', '\u{5DE6}', '\u{53F3}',
+    '\u{533B}', '\u{5B97}', '\u{5B66}', '\u{76E3}', '\u{4F01}', '\u{8CC7}', '\u{5354}', '\u{591C}',
+    '\u{33}', '\u{36}', '\u{33}', '\u{37}', '\u{33}', '\u{38}', '\u{33}', '\u{39}',
+    '\u{34}', '\u{30}', '\u{34}', '\u{31}', '\u{34}', '\u{32}', '\u{34}', '\u{33}',
+    '\u{34}', '\u{34}', '\u{34}', '\u{35}', '\u{34}', '\u{36}', '\u{34}', '\u{37}',
+    '\u{34}', '\u{38}', '\u{34}', '\u{39}', '\u{35}', '\u{30}', '\u{31}', '\u{6708}',
+    '\u{32}', '\u{6708}', '\u{33}', '\u{6708}', '\u{34}', '\u{6708}', '\u{35}', '\u{6708}',
+    '\u{36}', '\u{6708}', '\u{37}', '\u{6708}', '\u{38}', '\u{6708}', '\u{39}', '\u{6708}',
+    '\u{31}', '\u{30}', '\u{6708}', '\u{31}', '\u{31}', '\u{6708}', '\u{31}', '\u{32}',
+    '\u{6708}', '\u{48}', '\u{67}', '\u{65}', '\u{72}', '\u{67}', '\u{65}', '\u{56}',
+    '\u{4C}', '\u{54}', '\u{44}', '\u{30A2}', '\u{30A4}', '\u{30A6}', '\u{30A8}', '\u{30AA}',
+    '\u{30AB}', '\u{30AD}', '\u{30AF}', '\u{30B1}', '\u{30B3}', '\u{30B5}', '\u{30B7}', '\u{30B9}',
+    '\u{30BB}', '\u{30BD}', '\u{30BF}', '\u{30C1}', '\u{30C4}', '\u{30C6}', '\u{30C8}', '\u{30CA}',
+    '\u{30CB}', '\u{30CC}', '\u{30CD}', '\u{30CE}', '\u{30CF}', '\u{30D2}', '\u{30D5}', '\u{30D8}',
+    '\u{30DB}', '\u{30DE}', '\u{30DF}', '\u{30E0}', '\u{30E1}', '\u{30E2}', '\u{30E4}', '\u{30E6}',
+    '\u{30E8}', '\u{30E9}', '\u{30EA}', '\u{30EB}', '\u{30EC}', '\u{30ED}', '\u{30EF}', '\u{30F0}',
+    '\u{30F1}', '\u{30F2}', '\u{4EE4}', '\u{548C}', '\u{30A2}', '\u{30CF}', '\u{309A}', '\u{30FC}',
+    '\u{30C8}', '\u{30A2}', '\u{30EB}', '\u{30D5}', '\u{30A1}', '\u{30A2}', '\u{30F3}', '\u{30D8}',
+    '\u{309A}', '\u{30A2}', '\u{30A2}', '\u{30FC}', '\u{30EB}', '\u{30A4}', '\u{30CB}', '\u{30F3}',
+    '\u{30AF}', '\u{3099}', '\u{30A4}', '\u{30F3}', '\u{30C1}', '\u{30A6}', '\u{30A9}', '\u{30F3}',
+    '\u{30A8}', '\u{30B9}', '\u{30AF}', '\u{30FC}', '\u{30C8}', '\u{3099}', '\u{30A8}', '\u{30FC}',
+    '\u{30AB}', '\u{30FC}', '\u{30AA}', '\u{30F3}', '\u{30B9}', '\u{30AA}', '\u{30FC}', '\u{30E0}',
+    '\u{30AB}', '\u{30A4}', '\u{30EA}', '\u{30AB}', '\u{30E9}', '\u{30C3}', '\u{30C8}', '\u{30AB}',
+    '\u{30ED}', '\u{30EA}', '\u{30FC}', '\u{30AB}', '\u{3099}', '\u{30ED}', '\u{30F3}', '\u{30AB}',
+    '\u{3099}', '\u{30F3}', '\u{30DE}', '\u{30AD}', '\u{3099}', '\u{30AB}', '\u{3099}', '\u{30AD}',
+    '\u{3099}', '\u{30CB}', '\u{30FC}', '\u{30AD}', '\u{30E5}', '\u{30EA}', '\u{30FC}', '\u{30AD}',
+    '\u{3099}', '\u{30EB}', '\u{30BF}', '\u{3099}', '\u{30FC}', '\u{30AD}', '\u{30ED}', '\u{30AD}',
+    '\u{30ED}', '\u{30AF}', '\u{3099}', '\u{30E9}', '\u{30E0}', '\u{30AD}', '\u{30ED}', '\u{30E1}',
+    '\u{30FC}', '\u{30C8}', '\u{30EB}', '\u{30AD}', '\u{30ED}', '\u{30EF}', '\u{30C3}', '\u{30C8}',
+    '\u{30AF}', '\u{3099}', '\u{30E9}', '\u{30E0}', '\u{30AF}', '\u{3099}', '\u{30E9}', '\u{30E0}',
+    '\u{30C8}', '\u{30F3}', '\u{30AF}', '\u{30EB}', '\u{30BB}', '\u{3099}', '\u{30A4}', '\u{30ED}',
+    '\u{30AF}', '\u{30ED}', '\u{30FC}', '\u{30CD}', '\u{30B1}', '\u{30FC}', '\u{30B9}', '\u{30B3}',
+    '\u{30EB}', '\u{30CA}', '\u{30B3}', '\u{30FC}', '\u{30DB}', '\u{309A}', '\u{30B5}', '\u{30A4}',
+    '\u{30AF}', '\u{30EB}', '\u{30B5}', '\u{30F3}', '\u{30C1}', '\u{30FC}', '\u{30E0}', '\u{30B7}',
+    '\u{30EA}', '\u{30F3}', '\u{30AF}', '\u{3099}', '\u{30BB}', '\u{30F3}', '\u{30C1}', '\u{30BB}',
+    '\u{30F3}', '\u{30C8}', '\u{30BF}', '\u{3099}', '\u{30FC}', '\u{30B9}', '\u{30C6}', '\u{3099}',
+    '\u{30B7}', '\u{30C8}', '\u{3099}', '\u{30EB}', '\u{30C8}', '\u{30F3}', '\u{30CA}', '\u{30CE}',
+    '\u{30CE}', '\u{30C3}', '\u{30C8}', '\u{30CF}', '\u{30A4}', '\u{30C4}', '\u{30CF}', '\u{309A}',
+    '\u{30FC}', '\u{30BB}', '\u{30F3}', '\u{30C8}', '\u{30CF}', '\u{309A}', '\u{30FC}', '\u{30C4}',
+    '\u{30CF}', '\u{3099}', '\u{30FC}', '\u{30EC}', '\u{30EB}', '\u{30D2}', '\u{309A}', '\u{30A2}',
+    '\u{30B9}', '\u{30C8}', '\u{30EB}', '\u{30D2}', '\u{309A}', '\u{30AF}', '\u{30EB}', '\u{30D2}',
+    '\u{309A}', '\u{30B3}', '\u{30D2}', '\u{3099}', '\u{30EB}', '\u{30D5}', '\u{30A1}', '\u{30E9}',
+    '\u{30C3}', '\u{30C8}', '\u{3099}', '\u{30D5}', '\u{30A3}', '\u{30FC}', '\u{30C8}', '\u{30D5}',
+    '\u{3099}', '\u{30C3}', '\u{30B7}', '\u{30A7}', '\u{30EB}', '\u{30D5}', '\u{30E9}', '\u{30F3}',
+    '\u{30D8}', '\u{30AF}', '\u{30BF}', '\u{30FC}', '\u{30EB}', '\u{30D8}', '\u{309A}', '\u{30BD}',
+    '\u{30D8}', '\u{309A}', '\u{30CB}', '\u{30D2}', '\u{30D8}', '\u{30EB}', '\u{30C4}', '\u{30D8}',
+    '\u{309A}', '\u{30F3}', '\u{30B9}', '\u{30D8}', '\u{309A}', '\u{30FC}', '\u{30B7}', '\u{3099}',
+    '\u{30D8}', '\u{3099}', '\u{30FC}', '\u{30BF}', '\u{30DB}', '\u{309A}', '\u{30A4}', '\u{30F3}',
+    '\u{30C8}', '\u{30DB}', '\u{3099}', '\u{30EB}', '\u{30C8}', '\u{30DB}', '\u{30F3}', '\u{30DB}',
+    '\u{309A}', '\u{30F3}', '\u{30C8}', '\u{3099}', '\u{30DB}', '\u{30FC}', '\u{30EB}', '\u{30DB}',
+    '\u{30FC}', '\u{30F3}', '\u{30DE}', '\u{30A4}', '\u{30AF}', '\u{30ED}', '\u{30DE}', '\u{30A4}',
+    '\u{30EB}', '\u{30DE}', '\u{30C3}', '\u{30CF}', '\u{30DE}', '\u{30EB}', '\u{30AF}', '\u{30DE}',
+    '\u{30F3}', '\u{30B7}', '\u{30E7}', '\u{30F3}', '\u{30DF}', '\u{30AF}', '\u{30ED}', '\u{30F3}',
+    '\u{30DF}', '\u{30EA}', '\u{30DF}', '\u{30EA}', '\u{30CF}', '\u{3099}', '\u{30FC}', '\u{30EB}',
+    '\u{30E1}', '\u{30AB}', '\u{3099}', '\u{30E1}', '\u{30AB}', '\u{3099}', '\u{30C8}', '\u{30F3}',
+    '\u{30E1}', '\u{30FC}', '\u{30C8}', '\u{30EB}', '\u{30E4}', '\u{30FC}', '\u{30C8}', '\u{3099}',
+    '\u{30E4}', '\u{30FC}', '\u{30EB}', '\u{30E6}', '\u{30A2}', '\u{30F3}', '\u{30EA}', '\u{30C3}',
+    '\u{30C8}', '\u{30EB}', '\u{30EA}', '\u{30E9}', '\u{30EB}', '\u{30D2}', '\u{309A}', '\u{30FC}',
+    '\u{30EB}', '\u{30FC}', '\u{30D5}', '\u{3099}', '\u{30EB}', '\u{30EC}', '\u{30E0}', '\u{30EC}',
+    '\u{30F3}', '\u{30C8}', '\u{30B1}', '\u{3099}', '\u{30F3}', '\u{30EF}', '\u{30C3}', '\u{30C8}',
+    '\u{30}', '\u{70B9}', '\u{31}', '\u{70B9}', '\u{32}', '\u{70B9}', '\u{33}', '\u{70B9}',
+    '\u{34}', '\u{70B9}', '\u{35}', '\u{70B9}', '\u{36}', '\u{70B9}', '\u{37}', '\u{70B9}',
+    '\u{38}', '\u{70B9}', '\u{39}', '\u{70B9}', '\u{31}', '\u{30}', '\u{70B9}', '\u{31}',
+    '\u{31}', '\u{70B9}', '\u{31}', '\u{32}', '\u{70B9}', '\u{31}', '\u{33}', '\u{70B9}',
+    '\u{31}', '\u{34}', '\u{70B9}', '\u{31}', '\u{35}', '\u{70B9}', '\u{31}', '\u{36}',
+    '\u{70B9}', '\u{31}', '\u{37}', '\u{70B9}', '\u{31}', '\u{38}', '\u{70B9}', '\u{31}',
+    '\u{39}', '\u{70B9}', '\u{32}', '\u{30}', '\u{70B9}', '\u{32}', '\u{31}', '\u{70B9}',
+    '\u{32}', '\u{32}', '\u{70B9}', '\u{32}', '\u{33}', '\u{70B9}', '\u{32}', '\u{34}',
+    '\u{70B9}', '\u{68}', '\u{50}', '\u{61}', '\u{64}', '\u{61}', '\u{41}', '\u{55}',
+    '\u{62}', '\u{61}', '\u{72}', '\u{6F}', '\u{56}', '\u{70}', '\u{63}', '\u{64}',
+    '\u{6D}', '\u{64}', '\u{6D}', '\u{32}', '\u{64}', '\u{6D}', '\u{33}', '\u{49}',
+    '\u{55}', '\u{5E73}', '\u{6210}', '\u{662D}', '\u{548C}', '\u{5927}', '\u{6B63}', '\u{660E}',
+    '\u{6CBB}', '\u{682A}', '\u{5F0F}', '\u{4F1A}', '\u{793E}', '\u{70}', '\u{41}', '\u{6E}',
+    '\u{41}', '\u{3BC}', '\u{41}', '\u{6D}', '\u{41}', '\u{6B}', '\u{41}', '\u{4B}',
+    '\u{42}', '\u{4D}', '\u{42}', '\u{47}', '\u{42}', '\u{63}', '\u{61}', '\u{6C}',
+    '\u{6B}', '\u{63}', '\u{61}', '\u{6C}', '\u{70}', '\u{46}', '\u{6E}', '\u{46}',
+    '\u{3BC}', '\u{46}', '\u{3BC}', '\u{67}', '\u{6D}', '\u{67}', '\u{6B}', '\u{67}',
+    '\u{48}', '\u{7A}', '\u{6B}', '\u{48}', '\u{7A}', '\u{4D}', '\u{48}', '\u{7A}',
+    '\u{47}', '\u{48}', '\u{7A}', '\u{54}', '\u{48}', '\u{7A}', '\u{3BC}', '\u{6C}',
+    '\u{6D}', '\u{6C}', '\u{64}', '\u{6C}', '\u{6B}', '\u{6C}', '\u{66}', '\u{6D}',
+    '\u{6E}', '\u{6D}', '\u{3BC}', '\u{6D}', '\u{6D}', '\u{6D}', '\u{63}', '\u{6D}',
+    '\u{6B}', '\u{6D}', '\u{6D}', '\u{6D}', '\u{32}', '\u{63}', '\u{6D}', '\u{32}',
+    '\u{6D}', '\u{32}', '\u{6B}', '\u{6D}', '\u{32}', '\u{6D}', '\u{6D}', '\u{33}',
+    '\u{63}', '\u{6D}', '\u{33}', '\u{6D}', '\u{33}', '\u{6B}', '\u{6D}', '\u{33}',
+    '\u{6D}', '\u{2215}', '\u{73}', '\u{6D}', '\u{2215}', '\u{73}', '\u{32}', '\u{50}',
+    '\u{61}', '\u{6B}', '\u{50}', '\u{61}', '\u{4D}', '\u{50}', '\u{61}', '\u{47}',
+    '\u{50}', '\u{61}', '\u{72}', '\u{61}', '\u{64}', '\u{72}', '\u{61}', '\u{64}',
+    '\u{2215}', '\u{73}', '\u{72}', '\u{61}', '\u{64}', '\u{2215}', '\u{73}', '\u{32}',
+    '\u{70}', '\u{73}', '\u{6E}', '\u{73}', '\u{3BC}', '\u{73}', '\u{6D}', '\u{73}',
+    '\u{70}', '\u{56}', '\u{6E}', '\u{56}', '\u{3BC}', '\u{56}', '\u{6D}', '\u{56}',
+    '\u{6B}', '\u{56}', '\u{4D}', '\u{56}', '\u{70}', '\u{57}', '\u{6E}', '\u{57}',
+    '\u{3BC}', '\u{57}', '\u{6D}', '\u{57}', '\u{6B}', '\u{57}', '\u{4D}', '\u{57}',
+    '\u{6B}', '\u{3A9}', '\u{4D}', '\u{3A9}', '\u{61}', '\u{2E}', '\u{6D}', '\u{2E}',
+    '\u{42}', '\u{71}', '\u{63}', '\u{63}', '\u{63}', '\u{64}', '\u{43}', '\u{2215}',
+    '\u{6B}', '\u{67}', '\u{43}', '\u{6F}', '\u{2E}', '\u{64}', '\u{42}', '\u{47}',
+    '\u{79}', '\u{68}', '\u{61}', '\u{48}', '\u{50}', '\u{69}', '\u{6E}', '\u{4B}',
+    '\u{4B}', '\u{4B}', '\u{4D}', '\u{6B}', '\u{74}', '\u{6C}', '\u{6D}', '\u{6C}',
+    '\u{6E}', '\u{6C}', '\u{6F}', '\u{67}', '\u{6C}', '\u{78}', '\u{6D}', '\u{62}',
+    '\u{6D}', '\u{69}', '\u{6C}', '\u{6D}', '\u{6F}', '\u{6C}', '\u{50}', '\u{48}',
+    '\u{70}', '\u{2E}', '\u{6D}', '\u{2E}', '\u{50}', '\u{50}', '\u{4D}', '\u{50}',
+    '\u{52}', '\u{73}', '\u{72}', '\u{53}', '\u{76}', '\u{57}', '\u{62}', '\u{56}',
+    '\u{2215}', '\u{6D}', '\u{41}', '\u{2215}', '\u{6D}', '\u{31}', '\u{65E5}', '\u{32}',
+    '\u{65E5}', '\u{33}', '\u{65E5}', '\u{34}', '\u{65E5}', '\u{35}', '\u{65E5}', '\u{36}',
+    '\u{65E5}', '\u{37}', '\u{65E5}', '\u{38}', '\u{65E5}', '\u{39}', '\u{65E5}', '\u{31}',
+    '\u{30}', '\u{65E5}', '\u{31}', '\u{31}', '\u{65E5}', '\u{31}', '\u{32}', '\u{65E5}',
+    '\u{31}', '\u{33}', '\u{65E5}', '\u{31}', '\u{34}', '\u{65E5}', '\u{31}', '\u{35}',
+    '\u{65E5}', '\u{31}', '\u{36}', '\u{65E5}', '\u{31}', '\u{37}', '\u{65E5}', '\u{31}',
+    '\u{38}', '\u{65E5}', '\u{31}', '\u{39}', '\u{65E5}', '\u{32}', '\u{30}', '\u{65E5}',
+    '\u{32}', '\u{31}', '\u{65E5}', '\u{32}', '\u{32}', '\u{65E5}', '\u{32}', '\u{33}',
+    '\u{65E5}', '\u{32}', '\u{34}', '\u{65E5}', '\u{32}', '\u{35}', '\u{65E5}', '\u{32}',
+    '\u{36}', '\u{65E5}', '\u{32}', '\u{37}', '\u{65E5}', '\u{32}', '\u{38}', '\u{65E5}',
+    '\u{32}', '\u{39}', '\u{65E5}', '\u{33}', '\u{30}', '\u{65E5}', '\u{33}', '\u{31}',
+    '\u{65E5}', '\u{67}', '\u{61}', '\u{6C}', '\u{44A}', '\u{44C}', '\u{A76F}', '\u{43}',
+    '\u{46}', '\u{51}', '\u{126}', '\u{153}', '\u{A727}', '\u{AB37}', '\u{26B}', '\u{AB52}',
+    '\u{28D}', '\u{66}', '\u{66}', '\u{66}', '\u{69}', '\u{66}', '\u{6C}', '\u{66}',
+    '\u{66}', '\u{69}', '\u{66}', '\u{66}', '\u{6C}', '\u{73}', '\u{74}', '\u{73}',
+    '\u{74}', '\u{574}', '\u{576}', '\u{574}', '\u{565}', '\u{574}', '\u{56B}', '\u{57E}',
+    '\u{576}', '\u{574}', '\u{56D}', '\u{5E2}', '\u{5D0}', '\u{5D3}', '\u{5D4}', '\u{5DB}',
+    '\u{5DC}', '\u{5DD}', '\u{5E8}', '\u{5EA}', '\u{2B}', '\u{5D0}', '\u{5DC}', '\u{671}',
+    '\u{671}', '\u{67B}', '\u{67B}', '\u{67B}', '\u{67B}', '\u{67E}', '\u{67E}', '\u{67E}',
+    '\u{67E}', '\u{680}', '\u{680}', '\u{680}', '\u{680}', '\u{67A}', '\u{67A}', '\u{67A}',
+    '\u{67A}', '\u{67F}', '\u{67F}', '\u{67F}', '\u{67F}', '\u{679}', '\u{679}', '\u{679}',
+    '\u{679}', '\u{6A4}', '\u{6A4}', '\u{6A4}', '\u{6A4}', '\u{6A6}', '\u{6A6}', '\u{6A6}',
+    '\u{6A6}', '\u{684}', '\u{684}', '\u{684}', '\u{684}', '\u{683}', '\u{683}', '\u{683}',
+    '\u{683}', '\u{686}', '\u{686}', '\u{686}', '\u{686}', '\u{687}', '\u{687}', '\u{687}',
+    '\u{687}', '\u{68D}', '\u{68D}', '\u{68C}', '\u{68C}', '\u{68E}', '\u{68E}', '\u{688}',
+    '\u{688}', '\u{698}', '\u{698}', '\u{691}', '\u{691}', '\u{6A9}', '\u{6A9}', '\u{6A9}',
+    '\u{6A9}', '\u{6AF}', '\u{6AF}', '\u{6AF}', '\u{6AF}', '\u{6B3}', '\u{6B3}', '\u{6B3}',
+    '\u{6B3}', '\u{6B1}', '\u{6B1}', '\u{6B1}', '\u{6B1}', '\u{6BA}', '\u{6BA}', '\u{6BB}',
+    '\u{6BB}', '\u{6BB}', '\u{6BB}', '\u{6D5}', '\u{654}', '\u{6D5}', '\u{654}', '\u{6C1}',
+    '\u{6C1}', '\u{6C1}', '\u{6C1}', '\u{6BE}', '\u{6BE}', '\u{6BE}', '\u{6BE}', '\u{6D2}',
+    '\u{6D2}', '\u{6D2}', '\u{654}', '\u{6D2}', '\u{654}', '\u{6AD}', '\u{6AD}', '\u{6AD}',
+    '\u{6AD}', '\u{6C7}', '\u{6C7}', '\u{6C6}', '\u{6C6}', '\u{6C8}', '\u{6C8}', '\u{6C7}',
+    '\u{674}', '\u{6CB}', '\u{6CB}', '\u{6C5}', '\u{6C5}', '\u{6C9}', '\u{6C9}', '\u{6D0}',
+    '\u{6D0}', '\u{6D0}', '\u{6D0}', '\u{649}', '\u{649}', '\u{64A}', '\u{654}', '\u{627}',
+    '\u{64A}', '\u{654}', '\u{627}', '\u{64A}', '\u{654}', '\u{6D5}', '\u{64A}', '\u{654}',
+    '\u{6D5}', '\u{64A}', '\u{654}', '\u{648}', '\u{64A}', '\u{654}', '\u{648}', '\u{64A}',
+    '\u{654}', '\u{6C7}', '\u{64A}', '\u{654}', '\u{6C7}', '\u{64A}', '\u{654}', '\u{6C6}',
+    '\u{64A}', '\u{654}', '\u{6C6}', '\u{64A}', '\u{654}', '\u{6C8}', '\u{64A}', '\u{654}',
+    '\u{6C8}', '\u{64A}', '\u{654}', '\u{6D0}', '\u{64A}', '\u{654}', '\u{6D0}', '\u{64A}',
+    '\u{654}', '\u{6D0}', '\u{64A}', '\u{654}', '\u{649}', '\u{64A}', '\u{654}', '\u{649}',
+    '\u{64A}', '\u{654}', '\u{649}', '\u{6CC}', '\u{6CC}', '\u{6CC}', '\u{6CC}', '\u{64A}',
+    '\u{654}', '\u{62C}', '\u{64A}', '\u{654}', '\u{62D}', '\u{64A}', '\u{654}', '\u{645}',
+    '\u{64A}', '\u{654}', '\u{649}', '\u{64A}', '\u{654}', '\u{64A}', '\u{628}', '\u{62C}',
+    '\u{628}', '\u{62D}', '\u{628}', '\u{62E}', '\u{628}', '\u{645}', '\u{628}', '\u{649}',
+    '\u{628}', '\u{64A}', '\u{62A}', '\u{62C}', '\u{62A}', '\u{62D}', '\u{62A}', '\u{62E}',
+    '\u{62A}', '\u{645}', '\u{62A}', '\u{649}', '\u{62A}', '\u{64A}', '\u{62B}', '\u{62C}',
+    '\u{62B}', '\u{645}', '\u{62B}', '\u{649}', '\u{62B}', '\u{64A}', '\u{62C}', '\u{62D}',
+    '\u{62C}', '\u{645}', '\u{62D}', '\u{62C}', '\u{62D}', '\u{645}', '\u{62E}', '\u{62C}',
+    '\u{62E}', '\u{62D}', '\u{62E}', '\u{645}', '\u{633}', '\u{62C}', '\u{633}', '\u{62D}',
+    '\u{633}', '\u{62E}', '\u{633}', '\u{645}', '\u{635}', '\u{62D}', '\u{635}', '\u{645}',
+    '\u{636}', '\u{62C}', '\u{636}', '\u{62D}', '\u{636}', '\u{62E}', '\u{636}', '\u{645}',
+    '\u{637}', '\u{62D}', '\u{637}', '\u{645}', '\u{638}', '\u{645}', '\u{639}', '\u{62C}',
+    '\u{639}', '\u{645}', '\u{63A}', '\u{62C}', '\u{63A}', '\u{645}', '\u{641}', '\u{62C}',
+    '\u{641}', '\u{62D}', '\u{641}', '\u{62E}', '\u{641}', '\u{645}', '\u{641}', '\u{649}',
+    '\u{641}', '\u{64A}', '\u{642}', '\u{62D}', '\u{642}', '\u{645}', '\u{642}', '\u{649}',
+    '\u{642}', '\u{64A}', '\u{643}', '\u{627}', '\u{643}', '\u{62C}', '\u{643}', '\u{62D}',
+    '\u{643}', '\u{62E}', '\u{643}', '\u{644}', '\u{643}', '\u{645}', '\u{643}', '\u{649}',
+    '\u{643}', '\u{64A}', '\u{644}', '\u{62C}', '\u{644}', '\u{62D}', '\u{644}', '\u{62E}',
+    '\u{644}', '\u{645}', '\u{644}', '\u{649}', '\u{644}', '\u{64A}', '\u{645}', '\u{62C}',
+    '\u{645}', '\u{62D}', '\u{645}', '\u{62E}', '\u{645}', '\u{645}', '\u{645}', '\u{649}',
+    '\u{645}', '\u{64A}', '\u{646}', '\u{62C}', '\u{646}', '\u{62D}', '\u{646}', '\u{62E}',
+    '\u{646}', '\u{645}', '\u{646}', '\u{649}', '\u{646}', '\u{64A}', '\u{647}', '\u{62C}',
+    '\u{647}', '\u{645}', '\u{647}', '\u{649}', '\u{647}', '\u{64A}', '\u{64A}', '\u{62C}',
+    '\u{64A}', '\u{62D}', '\u{64A}', '\u{62E}', '\u{64A}', '\u{645}', '\u{64A}', '\u{649}',
+    '\u{64A}', '\u{64A}', '\u{630}', '\u{670}', '\u{631}', '\u{670}', '\u{649}', '\u{670}',
+    '\u{20}', '\u{64C}', '\u{651}', '\u{20}', '\u{64D}', '\u{651}', '\u{20}', '\u{64E}',
+    '\u{651}', '\u{20}', '\u{64F}', '\u{651}', '\u{20}', '\u{650}', '\u{651}', '\u{20}',
+    '\u{651}', '\u{670}', '\u{64A}', '\u{654}', '\u{631}', '\u{64A}', '\u{654}', '\u{632}',
+    '\u{64A}', '\u{654}', '\u{645}', '\u{64A}', '\u{654}', '\u{646}', '\u{64A}', '\u{654}',
+    '\u{649}', '\u{64A}', '\u{654}', '\u{64A}', '\u{628}', '\u{631}', '\u{628}', '\u{632}',
+    '\u{628}', '\u{645}', '\u{628}', '\u{646}', '\u{628}', '\u{649}', '\u{628}', '\u{64A}',
+    '\u{62A}', '\u{631}', '\u{62A}', '\u{632}', '\u{62A}', '\u{645}', '\u{62A}', '\u{646}',
+    '\u{62A}', '\u{649}', '\u{62A}', '\u{64A}', '\u{62B}', '\u{631}', '\u{62B}', '\u{632}',
+    '\u{62B}', '\u{645}', '\u{62B}', '\u{646}', '\u{62B}', '\u{649}', '\u{62B}', '\u{64A}',
+    '\u{641}', '\u{649}', '\u{641}', '\u{64A}', '\u{642}', '\u{649}', '\u{642}', '\u{64A}',
+    '\u{643}', '\u{627}', '\u{643}', '\u{644}', '\u{643}', '\u{645}', '\u{643}', '\u{649}',
+    '\u{643}', '\u{64A}', '\u{644}', '\u{645}', '\u{644}', '\u{649}', '\u{644}', '\u{64A}',
+    '\u{645}', '\u{627}', '\u{645}', '\u{645}', '\u{646}', '\u{631}', '\u{646}', '\u{632}',
+    '\u{646}', '\u{645}', '\u{646}', '\u{646}', '\u{646}', '\u{649}', '\u{646}', '\u{64A}',
+    '\u{649}', '\u{670}', '\u{64A}', '\u{631}', '\u{64A}', '\u{632}', '\u{64A}', '\u{645}',
+    '\u{64A}', '\u{646}', '\u{64A}', '\u{649}', '\u{64A}', '\u{64A}', '\u{64A}', '\u{654}',
+    '\u{62C}', '\u{64A}', '\u{654}', '\u{62D}', '\u{64A}', '\u{654}', '\u{62E}', '\u{64A}',
+    '\u{654}', '\u{645}', '\u{64A}', '\u{654}', '\u{647}', '\u{628}', '\u{62C}', '\u{628}',
+    '\u{62D}', '\u{628}', '\u{62E}', '\u{628}', '\u{645}', '\u{628}', '\u{647}', '\u{62A}',
+    '\u{62C}', '\u{62A}', '\u{62D}', '\u{62A}', '\u{62E}', '\u{62A}', '\u{645}', '\u{62A}',
+    '\u{647}', '\u{62B}', '\u{645}', '\u{62C}', '\u{62D}', '\u{62C}', '\u{645}', '\u{62D}',
+    '\u{62C}', '\u{62D}', '\u{645}', '\u{62E}', '\u{62C}', '\u{62E}', '\u{645}', '\u{633}',
+    '\u{62C}', '\u{633}', '\u{62D}', '\u{633}', '\u{62E}', '\u{633}', '\u{645}', '\u{635}',
+    '\u{62D}', '\u{635}', '\u{62E}', '\u{635}', '\u{645}', '\u{636}', '\u{62C}', '\u{636}',
+    '\u{62D}', '\u{636}', '\u{62E}', '\u{636}', '\u{645}', '\u{637}', '\u{62D}', '\u{638}',
+    '\u{645}', '\u{639}', '\u{62C}', '\u{639}', '\u{645}', '\u{63A}', '\u{62C}', '\u{63A}',
+    '\u{645}', '\u{641}', '\u{62C}', '\u{641}', '\u{62D}', '\u{641}', '\u{62E}', '\u{641}',
+    '\u{645}', '\u{642}', '\u{62D}', '\u{642}', '\u{645}', '\u{643}', '\u{62C}', '\u{643}',
+    '\u{62D}', '\u{643}', '\u{62E}', '\u{643}', '\u{644}', '\u{643}', '\u{645}', '\u{644}',
+    '\u{62C}', '\u{644}', '\u{62D}', '\u{644}', '\u{62E}', '\u{644}', '\u{645}', '\u{644}',
+    '\u{647}', '\u{645}', '\u{62C}', '\u{645}', '\u{62D}', '\u{645}', '\u{62E}', '\u{645}',
+    '\u{645}', '\u{646}', '\u{62C}', '\u{646}', '\u{62D}', '\u{646}', '\u{62E}', '\u{646}',
+    '\u{645}', '\u{646}', '\u{647}', '\u{647}', '\u{62C}', '\u{647}', '\u{645}', '\u{647}',
+    '\u{670}', '\u{64A}', '\u{62C}', '\u{64A}', '\u{62D}', '\u{64A}', '\u{62E}', '\u{64A}',
+    '\u{645}', '\u{64A}', '\u{647}', '\u{64A}', '\u{654}', '\u{645}', '\u{64A}', '\u{654}',
+    '\u{647}', '\u{628}', '\u{645}', '\u{628}', '\u{647}', '\u{62A}', '\u{645}', '\u{62A}',
+    '\u{647}', '\u{62B}', '\u{645}', '\u{62B}', '\u{647}', '\u{633}', '\u{645}', '\u{633}',
+    '\u{647}', '\u{634}', '\u{645}', '\u{634}', '\u{647}', '\u{643}', '\u{644}', '\u{643}',
+    '\u{645}', '\u{644}', '\u{645}', '\u{646}', '\u{645}', '\u{646}', '\u{647}', '\u{64A}',
+    '\u{645}', '\u{64A}', '\u{647}', '\u{640}', '\u{64E}', '\u{651}', '\u{640}', '\u{64F}',
+    '\u{651}', '\u{640}', '\u{650}', '\u{651}', '\u{637}', '\u{649}', '\u{637}', '\u{64A}',
+    '\u{639}', '\u{649}', '\u{639}', '\u{64A}', '\u{63A}', '\u{649}', '\u{63A}', '\u{64A}',
+    '\u{633}', '\u{649}', '\u{633}', '\u{64A}', '\u{634}', '\u{649}', '\u{634}', '\u{64A}',
+    '\u{62D}', '\u{649}', '\u{62D}', '\u{64A}', '\u{62C}', '\u{649}', '\u{62C}', '\u{64A}',
+    '\u{62E}', '\u{649}', '\u{62E}', '\u{64A}', '\u{635}', '\u{649}', '\u{635}', '\u{64A}',
+    '\u{636}', '\u{649}', '\u{636}', '\u{64A}', '\u{634}', '\u{62C}', '\u{634}', '\u{62D}',
+    '\u{634}', '\u{62E}', '\u{634}', '\u{645}', '\u{634}', '\u{631}', '\u{633}', '\u{631}',
+    '\u{635}', '\u{631}', '\u{636}', '\u{631}', '\u{637}', '\u{649}', '\u{637}', '\u{64A}',
+    '\u{639}', '\u{649}', '\u{639}', '\u{64A}', '\u{63A}', '\u{649}', '\u{63A}', '\u{64A}',
+    '\u{633}', '\u{649}', '\u{633}', '\u{64A}', '\u{634}', '\u{649}', '\u{634}', '\u{64A}',
+    '\u{62D}', '\u{649}', '\u{62D}', '\u{64A}', '\u{62C}', '\u{649}', '\u{62C}', '\u{64A}',
+    '\u{62E}', '\u{649}', '\u{62E}', '\u{64A}', '\u{635}', '\u{649}', '\u{635}', '\u{64A}',
+    '\u{636}', '\u{649}', '\u{636}', '\u{64A}', '\u{634}', '\u{62C}', '\u{634}', '\u{62D}',
+    '\u{634}', '\u{62E}', '\u{634}', '\u{645}', '\u{634}', '\u{631}', '\u{633}', '\u{631}',
+    '\u{635}', '\u{631}', '\u{636}', '\u{631}', '\u{634}', '\u{62C}', '\u{634}', '\u{62D}',
+    '\u{634}', '\u{62E}', '\u{634}', '\u{645}', '\u{633}', '\u{647}', '\u{634}', '\u{647}',
+    '\u{637}', '\u{645}', '\u{633}', '\u{62C}', '\u{633}', '\u{62D}', '\u{633}', '\u{62E}',
+    '\u{634}', '\u{62C}', '\u{634}', '\u{62D}', '\u{634}', '\u{62E}', '\u{637}', '\u{645}',
+    '\u{638}', '\u{645}', '\u{627}', '\u{64B}', '\u{627}', '\u{64B}', '\u{62A}', '\u{62C}',
+    '\u{645}', '\u{62A}', '\u{62D}', '\u{62C}', '\u{62A}', '\u{62D}', '\u{62C}', '\u{62A}',
+    '\u{62D}', '\u{645}', '\u{62A}', '\u{62E}', '\u{645}', '\u{62A}', '\u{645}', '\u{62C}',
+    '\u{62A}', '\u{645}', '\u{62D}', '\u{62A}', '\u{645}', '\u{62E}', '\u{62C}', '\u{645}',
+    '\u{62D}', '\u{62C}', '\u{645}', '\u{62D}', '\u{62D}', '\u{645}', '\u{64A}', '\u{62D}',
+    '\u{645}', '\u{649}', '\u{633}', '\u{62D}', '\u{62C}', '\u{633}', '\u{62C}', '\u{62D}',
+    '\u{633}', '\u{62C}', '\u{649}', '\u{633}', '\u{645}', '\u{62D}', '\u{633}', '\u{645}',
+    '\u{62D}', '\u{633}', '\u{645}', '\u{62C}', '\u{633}', '\u{645}', '\u{645}', '\u{633}',
+    '\u{645}', '\u{645}', '\u{635}', '\u{62D}', '\u{62D}', '\u{635}', '\u{62D}', '\u{62D}',
+    '\u{635}', '\u{645}', '\u{645}', '\u{634}', '\u{62D}', '\u{645}', '\u{634}', '\u{62D}',
+    '\u{645}', '\u{634}', '\u{62C}', '\u{64A}', '\u{634}', '\u{645}', '\u{62E}', '\u{634}',
+    '\u{645}', '\u{62E}', '\u{634}', '\u{645}', '\u{645}', '\u{634}', '\u{645}', '\u{645}',
+    '\u{636}', '\u{62D}', '\u{649}', '\u{636}', '\u{62E}', '\u{645}', '\u{636}', '\u{62E}',
+    '\u{645}', '\u{637}', '\u{645}', '\u{62D}', '\u{637}', '\u{645}', '\u{62D}', '\u{637}',
+    '\u{645}', '\u{645}', '\u{637}', '\u{645}', '\u{64A}', '\u{639}', '\u{62C}', '\u{645}',
+    '\u{639}', '\u{645}', '\u{645}', '\u{639}', '\u{645}', '\u{645}', '\u{639}', '\u{645}',
+    '\u{649}', '\u{63A}', '\u{645}', '\u{645}', '\u{63A}', '\u{645}', '\u{64A}', '\u{63A}',
+    '\u{645}', '\u{649}', '\u{641}', '\u{62E}', '\u{645}', '\u{641}', '\u{62E}', '\u{645}',
+    '\u{642}', '\u{645}', '\u{62D}', '\u{642}', '\u{645}', '\u{645}', '\u{644}', '\u{62D}',
+    '\u{645}', '\u{644}', '\u{62D}', '\u{64A}', '\u{644}', '\u{62D}', '\u{649}', '\u{644}',
+    '\u{62C}', '\u{62C}', '\u{644}', '\u{62C}', '\u{62C}', '\u{644}', '\u{62E}', '\u{645}',
+    '\u{644}', '\u{62E}', '\u{645}', '\u{644}', '\u{645}', '\u{62D}', '\u{644}', '\u{645}',
+    '\u{62D}', '\u{645}', '\u{62D}', '\u{62C}', '\u{645}', '\u{62D}', '\u{645}', '\u{645}',
+    '\u{62D}', '\u{64A}', '\u{645}', '\u{62C}', '\u{62D}', '\u{645}', '\u{62C}', '\u{645}',
+    '\u{645}', '\u{62E}', '\u{62C}', '\u{645}', '\u{62E}', '\u{645}', '\u{645}', '\u{62C}',
+    '\u{62E}', '\u{647}', '\u{645}', '\u{62C}', '\u{647}', '\u{645}', '\u{645}', '\u{646}',
+    '\u{62D}', '\u{645}', '\u{646}', '\u{62D}', '\u{649}', '\u{646}', '\u{62C}', '\u{645}',
+    '\u{646}', '\u{62C}', '\u{645}', '\u{646}', '\u{62C}', '\u{649}', '\u{646}', '\u{645}',
+    '\u{64A}', '\u{646}', '\u{645}', '\u{649}', '\u{64A}', '\u{645}', '\u{645}', '\u{64A}',
+    '\u{645}', '\u{645}', '\u{628}', '\u{62E}', '\u{64A}', '\u{62A}', '\u{62C}', '\u{64A}',
+    '\u{62A}', '\u{62C}', '\u{649}', '\u{62A}', '\u{62E}', '\u{64A}', '\u{62A}', '\u{62E}',
+    '\u{649}', '\u{62A}', '\u{645}', '\u{64A}', '\u{62A}', '\u{645}', '\u{649}', '\u{62C}',
+    '\u{645}', '\u{64A}', '\u{62C}', '\u{62D}', '\u{649}', '\u{62C}', '\u{645}', '\u{649}',
+    '\u{633}', '\u{62E}', '\u{649}', '\u{635}', '\u{62D}', '\u{64A}', '\u{634}', '\u{62D}',
+    '\u{64A}', '\u{636}', '\u{62D}', '\u{64A}', '\u{644}', '\u{62C}', '\u{64A}', '\u{644}',
+    '\u{645}', '\u{64A}', '\u{64A}', '\u{62D}', '\u{64A}', '\u{64A}', '\u{62C}', '\u{64A}',
+    '\u{64A}', '\u{645}', '\u{64A}', '\u{645}', '\u{645}', '\u{64A}', '\u{642}', '\u{645}',
+    '\u{64A}', '\u{646}', '\u{62D}', '\u{64A}', '\u{642}', '\u{645}', '\u{62D}', '\u{644}',
+    '\u{62D}', '\u{645}', '\u{639}', '\u{645}', '\u{64A}', '\u{643}', '\u{645}', '\u{64A}',
+    '\u{646}', '\u{62C}', '\u{62D}', '\u{645}', '\u{62E}', '\u{64A}', '\u{644}', '\u{62C}',
+    '\u{645}', '\u{643}', '\u{645}', '\u{645}', '\u{644}', '\u{62C}', '\u{645}', '\u{646}',
+    '\u{62C}', '\u{62D}', '\u{62C}', '\u{62D}', '\u{64A}', '\u{62D}', '\u{62C}', '\u{64A}',
+    '\u{645}', '\u{62C}', '\u{64A}', '\u{641}', '\u{645}', '\u{64A}', '\u{628}', '\u{62D}',
+    '\u{64A}', '\u{643}', '\u{645}', '\u{645}', '\u{639}', '\u{62C}', '\u{645}', '\u{635}',
+    '\u{645}', '\u{645}', '\u{633}', '\u{62E}', '\u{64A}', '\u{646}', '\u{62C}', '\u{64A}',
+    '\u{635}', '\u{644}', '\u{6D2}', '\u{642}', '\u{644}', '\u{6D2}', '\u{627}', '\u{644}',
+    '\u{644}', '\u{647}', '\u{627}', '\u{643}', '\u{628}', '\u{631}', '\u{645}', '\u{62D}',
+    '\u{645}', '\u{62F}', '\u{635}', '\u{644}', '\u{639}', '\u{645}', '\u{631}', '\u{633}',
+    '\u{648}', '\u{644}', '\u{639}', '\u{644}', '\u{64A}', '\u{647}', '\u{648}', '\u{633}',
+    '\u{644}', '\u{645}', '\u{635}', '\u{644}', '\u{649}', '\u{635}', '\u{644}', '\u{649}',
+    '\u{20}', '\u{627}', '\u{644}', '\u{644}', '\u{647}', '\u{20}', '\u{639}', '\u{644}',
+    '\u{64A}', '\u{647}', '\u{20}', '\u{648}', '\u{633}', '\u{644}', '\u{645}', '\u{62C}',
+    '\u{644}', '\u{20}', '\u{62C}', '\u{644}', '\u{627}', '\u{644}', '\u{647}', '\u{631}',
+    '\u{6CC}', '\u{627}', '\u{644}', '\u{2C}', '\u{3001}', '\u{3002}', '\u{3A}', '\u{3B}',
+    '\u{21}', '\u{3F}', '\u{3016}', '\u{3017}', '\u{2E}', '\u{2E}', '\u{2E}', '\u{2E}',
+    '\u{2E}', '\u{2014}', '\u{2013}', '\u{5F}', '\u{5F}', '\u{28}', '\u{29}', '\u{7B}',
+    '\u{7D}', '\u{3014}', '\u{3015}', '\u{3010}', '\u{3011}', '\u{300A}', '\u{300B}', '\u{3008}',
+    '\u{3009}', '\u{300C}', '\u{300D}', '\u{300E}', '\u{300F}', '\u{5B}', '\u{5D}', '\u{20}',
+    '\u{305}', '\u{20}', '\u{305}', '\u{20}', '\u{305}', '\u{20}', '\u{305}', '\u{5F}',
+    '\u{5F}', '\u{5F}', '\u{2C}', '\u{3001}', '\u{2E}', '\u{3B}', '\u{3A}', '\u{3F}',
+    '\u{21}', '\u{2014}', '\u{28}', '\u{29}', '\u{7B}', '\u{7D}', '\u{3014}', '\u{3015}',
+    '\u{23}', '\u{26}', '\u{2A}', '\u{2B}', '\u{2D}', '\u{3C}', '\u{3E}', '\u{3D}',
+    '\u{5C}', '\u{24}', '\u{25}', '\u{40}', '\u{20}', '\u{64B}', '\u{640}', '\u{64B}',
+    '\u{20}', '\u{64C}', '\u{20}', '\u{64D}', '\u{20}', '\u{64E}', '\u{640}', '\u{64E}',
+    '\u{20}', '\u{64F}', '\u{640}', '\u{64F}', '\u{20}', '\u{650}', '\u{640}', '\u{650}',
+    '\u{20}', '\u{651}', '\u{640}', '\u{651}', '\u{20}', '\u{652}', '\u{640}', '\u{652}',
+    '\u{621}', '\u{627}', '\u{653}', '\u{627}', '\u{653}', '\u{627}', '\u{654}', '\u{627}',
+    '\u{654}', '\u{648}', '\u{654}', '\u{648}', '\u{654}', '\u{627}', '\u{655}', '\u{627}',
+    '\u{655}', '\u{64A}', '\u{654}', '\u{64A}', '\u{654}', '\u{64A}', '\u{654}', '\u{64A}',
+    '\u{654}', '\u{627}', '\u{627}', '\u{628}', '\u{628}', '\u{628}', '\u{628}', '\u{629}',
+    '\u{629}', '\u{62A}', '\u{62A}', '\u{62A}', '\u{62A}', '\u{62B}', '\u{62B}', '\u{62B}',
+    '\u{62B}', '\u{62C}', '\u{62C}', '\u{62C}', '\u{62C}', '\u{62D}', '\u{62D}', '\u{62D}',
+    '\u{62D}', '\u{62E}', '\u{62E}', '\u{62E}', '\u{62E}', '\u{62F}', '\u{62F}', '\u{630}',
+    '\u{630}', '\u{631}', '\u{631}', '\u{632}', '\u{632}', '\u{633}', '\u{633}', '\u{633}',
+    '\u{633}', '\u{634}', '\u{634}', '\u{634}', '\u{634}', '\u{635}', '\u{635}', '\u{635}',
+    '\u{635}', '\u{636}', '\u{636}', '\u{636}', '\u{636}', '\u{637}', '\u{637}', '\u{637}',
+    '\u{637}', '\u{638}', '\u{638}', '\u{638}', '\u{638}', '\u{639}', '\u{639}', '\u{639}',
+    '\u{639}', '\u{63A}', '\u{63A}', '\u{63A}', '\u{63A}', '\u{641}', '\u{641}', '\u{641}',
+    '\u{641}', '\u{642}', '\u{642}', '\u{642}', '\u{642}', '\u{643}', '\u{643}', '\u{643}',
+    '\u{643}', '\u{644}', '\u{644}', '\u{644}', '\u{644}', '\u{645}', '\u{645}', '\u{645}',
+    '\u{645}', '\u{646}', '\u{646}', '\u{646}', '\u{646}', '\u{647}', '\u{647}', '\u{647}',
+    '\u{647}', '\u{648}', '\u{648}', '\u{649}', '\u{649}', '\u{64A}', '\u{64A}', '\u{64A}',
+    '\u{64A}', '\u{644}', '\u{627}', '\u{653}', '\u{644}', '\u{627}', '\u{653}', '\u{644}',
+    '\u{627}', '\u{654}', '\u{644}', '\u{627}', '\u{654}', '\u{644}', '\u{627}', '\u{655}',
+    '\u{644}', '\u{627}', '\u{655}', '\u{644}', '\u{627}', '\u{644}', '\u{627}', '\u{21}',
+    '\u{22}', '\u{23}', '\u{24}', '\u{25}', '\u{26}', '\u{27}', '\u{28}', '\u{29}',
+    '\u{2A}', '\u{2B}', '\u{2C}', '\u{2D}', '\u{2E}', '\u{2F}', '\u{30}', '\u{31}',
+    '\u{32}', '\u{33}', '\u{34}', '\u{35}', '\u{36}', '\u{37}', '\u{38}', '\u{39}',
+    '\u{3A}', '\u{3B}', '\u{3C}', '\u{3D}', '\u{3E}', '\u{3F}', '\u{40}', '\u{41}',
+    '\u{42}', '\u{43}', '\u{44}', '\u{45}', '\u{46}', '\u{47}', '\u{48}', '\u{49}',
+    '\u{4A}', '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}', '\u{50}', '\u{51}',
+    '\u{52}', '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}', '\u{59}',
+    '\u{5A}', '\u{5B}', '\u{5C}', '\u{5D}', '\u{5E}', '\u{5F}', '\u{60}', '\u{61}',
+    '\u{62}', '\u{63}', '\u{64}', '\u{65}', '\u{66}', '\u{67}', '\u{68}', '\u{69}',
+    '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}', '\u{6E}', '\u{6F}', '\u{70}', '\u{71}',
+    '\u{72}', '\u{73}', '\u{74}', '\u{75}', '\u{76}', '\u{77}', '\u{78}', '\u{79}',
+    '\u{7A}', '\u{7B}', '\u{7C}', '\u{7D}', '\u{7E}', '\u{2985}', '\u{2986}', '\u{3002}',
+    '\u{300C}', '\u{300D}', '\u{3001}', '\u{30FB}', '\u{30F2}', '\u{30A1}', '\u{30A3}', '\u{30A5}',
+    '\u{30A7}', '\u{30A9}', '\u{30E3}', '\u{30E5}', '\u{30E7}', '\u{30C3}', '\u{30FC}', '\u{30A2}',
+    '\u{30A4}', '\u{30A6}', '\u{30A8}', '\u{30AA}', '\u{30AB}', '\u{30AD}', '\u{30AF}', '\u{30B1}',
+    '\u{30B3}', '\u{30B5}', '\u{30B7}', '\u{30B9}', '\u{30BB}', '\u{30BD}', '\u{30BF}', '\u{30C1}',
+    '\u{30C4}', '\u{30C6}', '\u{30C8}', '\u{30CA}', '\u{30CB}', '\u{30CC}', '\u{30CD}', '\u{30CE}',
+    '\u{30CF}', '\u{30D2}', '\u{30D5}', '\u{30D8}', '\u{30DB}', '\u{30DE}', '\u{30DF}', '\u{30E0}',
+    '\u{30E1}', '\u{30E2}', '\u{30E4}', '\u{30E6}', '\u{30E8}', '\u{30E9}', '\u{30EA}', '\u{30EB}',
+    '\u{30EC}', '\u{30ED}', '\u{30EF}', '\u{30F3}', '\u{3099}', '\u{309A}', '\u{1160}', '\u{1100}',
+    '\u{1101}', '\u{11AA}', '\u{1102}', '\u{11AC}', '\u{11AD}', '\u{1103}', '\u{1104}', '\u{1105}',
+    '\u{11B0}', '\u{11B1}', '\u{11B2}', '\u{11B3}', '\u{11B4}', '\u{11B5}', '\u{111A}', '\u{1106}',
+    '\u{1107}', '\u{1108}', '\u{1121}', '\u{1109}', '\u{110A}', '\u{110B}', '\u{110C}', '\u{110D}',
+    '\u{110E}', '\u{110F}', '\u{1110}', '\u{1111}', '\u{1112}', '\u{1161}', '\u{1162}', '\u{1163}',
+    '\u{1164}', '\u{1165}', '\u{1166}', '\u{1167}', '\u{1168}', '\u{1169}', '\u{116A}', '\u{116B}',
+    '\u{116C}', '\u{116D}', '\u{116E}', '\u{116F}', '\u{1170}', '\u{1171}', '\u{1172}', '\u{1173}',
+    '\u{1174}', '\u{1175}', '\u{A2}', '\u{A3}', '\u{AC}', '\u{20}', '\u{304}', '\u{A6}',
+    '\u{A5}', '\u{20A9}', '\u{2502}', '\u{2190}', '\u{2191}', '\u{2192}', '\u{2193}', '\u{25A0}',
+    '\u{25CB}', '\u{2D0}', '\u{2D1}', '\u{E6}', '\u{299}', '\u{253}', '\u{2A3}', '\u{AB66}',
+    '\u{2A5}', '\u{2A4}', '\u{256}', '\u{257}', '\u{1D91}', '\u{258}', '\u{25E}', '\u{2A9}',
+    '\u{264}', '\u{262}', '\u{260}', '\u{29B}', '\u{127}', '\u{29C}', '\u{267}', '\u{284}',
+    '\u{2AA}', '\u{2AB}', '\u{26C}', '\u{1DF04}', '\u{A78E}', '\u{26E}', '\u{1DF05}', '\u{28E}',
+    '\u{1DF06}', '\u{F8}', '\u{276}', '\u{277}', '\u{71}', '\u{27A}', '\u{1DF08}', '\u{27D}',
+    '\u{27E}', '\u{280}', '\u{2A8}', '\u{2A6}', '\u{AB67}', '\u{2A7}', '\u{288}', '\u{2C71}',
+    '\u{28F}', '\u{2A1}', '\u{2A2}', '\u{298}', '\u{1C0}', '\u{1C1}', '\u{1C2}', '\u{1DF0A}',
+    '\u{1DF1E}', '\u{41}', '\u{42}', '\u{43}', '\u{44}', '\u{45}', '\u{46}', '\u{47}',
+    '\u{48}', '\u{49}', '\u{4A}', '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}',
+    '\u{50}', '\u{51}', '\u{52}', '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{57}',
+    '\u{58}', '\u{59}', '\u{5A}', '\u{61}', '\u{62}', '\u{63}', '\u{64}', '\u{65}',
+    '\u{66}', '\u{67}', '\u{68}', '\u{69}', '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}',
+    '\u{6E}', '\u{6F}', '\u{70}', '\u{71}', '\u{72}', '\u{73}', '\u{74}', '\u{75}',
+    '\u{76}', '\u{77}', '\u{78}', '\u{79}', '\u{7A}', '\u{41}', '\u{42}', '\u{43}',
+    '\u{44}', '\u{45}', '\u{46}', '\u{47}', '\u{48}', '\u{49}', '\u{4A}', '\u{4B}',
+    '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}', '\u{50}', '\u{51}', '\u{52}', '\u{53}',
+    '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}', '\u{59}', '\u{5A}', '\u{61}',
+    '\u{62}', '\u{63}', '\u{64}', '\u{65}', '\u{66}', '\u{67}', '\u{69}', '\u{6A}',
+    '\u{6B}', '\u{6C}', '\u{6D}', '\u{6E}', '\u{6F}', '\u{70}', '\u{71}', '\u{72}',
+    '\u{73}', '\u{74}', '\u{75}', '\u{76}', '\u{77}', '\u{78}', '\u{79}', '\u{7A}',
+    '\u{41}', '\u{42}', '\u{43}', '\u{44}', '\u{45}', '\u{46}', '\u{47}', '\u{48}',
+    '\u{49}', '\u{4A}', '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}', '\u{50}',
+    '\u{51}', '\u{52}', '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}',
+    '\u{59}', '\u{5A}', '\u{61}', '\u{62}', '\u{63}', '\u{64}', '\u{65}', '\u{66}',
+    '\u{67}', '\u{68}', '\u{69}', '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}', '\u{6E}',
+    '\u{6F}', '\u{70}', '\u{71}', '\u{72}', '\u{73}', '\u{74}', '\u{75}', '\u{76}',
+    '\u{77}', '\u{78}', '\u{79}', '\u{7A}', '\u{41}', '\u{43}', '\u{44}', '\u{47}',
+    '\u{4A}', '\u{4B}', '\u{4E}', '\u{4F}', '\u{50}', '\u{51}', '\u{53}', '\u{54}',
+    '\u{55}', '\u{56}', '\u{57}', '\u{58}', '\u{59}', '\u{5A}', '\u{61}', '\u{62}',
+    '\u{63}', '\u{64}', '\u{66}', '\u{68}', '\u{69}', '\u{6A}', '\u{6B}', '\u{6C}',
+    '\u{6D}', '\u{6E}', '\u{70}', '\u{71}', '\u{72}', '\u{73}', '\u{74}', '\u{75}',
+    '\u{76}', '\u{77}', '\u{78}', '\u{79}', '\u{7A}', '\u{41}', '\u{42}', '\u{43}',
+    '\u{44}', '\u{45}', '\u{46}', '\u{47}', '\u{48}', '\u{49}', '\u{4A}', '\u{4B}',
+    '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}', '\u{50}', '\u{51}', '\u{52}', '\u{53}',
+    '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}', '\u{59}', '\u{5A}', '\u{61}',
+    '\u{62}', '\u{63}', '\u{64}', '\u{65}', '\u{66}', '\u{67}', '\u{68}', '\u{69}',
+    '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}', '\u{6E}', '\u{6F}', '\u{70}', '\u{71}',
+    '\u{72}', '\u{73}', '\u{74}', '\u{75}', '\u{76}', '\u{77}', '\u{78}', '\u{79}',
+    '\u{7A}', '\u{41}', '\u{42}', '\u{44}', '\u{45}', '\u{46}', '\u{47}', '\u{4A}',
+    '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}', '\u{50}', '\u{51}', '\u{53}',
+    '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}', '\u{59}', '\u{61}', '\u{62}',
+    '\u{63}', '\u{64}', '\u{65}', '\u{66}', '\u{67}', '\u{68}', '\u{69}', '\u{6A}',
+    '\u{6B}', '\u{6C}', '\u{6D}', '\u{6E}', '\u{6F}', '\u{70}', '\u{71}', '\u{72}',
+    '\u{73}', '\u{74}', '\u{75}', '\u{76}', '\u{77}', '\u{78}', '\u{79}', '\u{7A}',
+    '\u{41}', '\u{42}', '\u{44}', '\u{45}', '\u{46}', '\u{47}', '\u{49}', '\u{4A}',
+    '\u{4B}', '\u{4C}', '\u{4D}', '\u{4F}', '\u{53}', '\u{54}', '\u{55}', '\u{56}',
+    '\u{57}', '\u{58}', '\u{59}', '\u{61}', '\u{62}', '\u{63}', '\u{64}', '\u{65}',
+    '\u{66}', '\u{67}', '\u{68}', '\u{69}', '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}',
+    '\u{6E}', '\u{6F}', '\u{70}', '\u{71}', '\u{72}', '\u{73}', '\u{74}', '\u{75}',
+    '\u{76}', '\u{77}', '\u{78}', '\u{79}', '\u{7A}', '\u{41}', '\u{42}', '\u{43}',
+    '\u{44}', '\u{45}', '\u{46}', '\u{47}', '\u{48}', '\u{49}', '\u{4A}', '\u{4B}',
+    '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}', '\u{50}', '\u{51}', '\u{52}', '\u{53}',
+    '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}', '\u{59}', '\u{5A}', '\u{61}',
+    '\u{62}', '\u{63}', '\u{64}', '\u{65}', '\u{66}', '\u{67}', '\u{68}', '\u{69}',
+    '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}', '\u{6E}', '\u{6F}', '\u{70}', '\u{71}',
+    '\u{72}', '\u{73}', '\u{74}', '\u{75}', '\u{76}', '\u{77}', '\u{78}', '\u{79}',
+    '\u{7A}', '\u{41}', '\u{42}', '\u{43}', '\u{44}', '\u{45}', '\u{46}', '\u{47}',
+    '\u{48}', '\u{49}', '\u{4A}', '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}',
+    '\u{50}', '\u{51}', '\u{52}', '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{57}',
+    '\u{58}', '\u{59}', '\u{5A}', '\u{61}', '\u{62}', '\u{63}', '\u{64}', '\u{65}',
+    '\u{66}', '\u{67}', '\u{68}', '\u{69}', '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}',
+    '\u{6E}', '\u{6F}', '\u{70}', '\u{71}', '\u{72}', '\u{73}', '\u{74}', '\u{75}',
+    '\u{76}', '\u{77}', '\u{78}', '\u{79}', '\u{7A}', '\u{41}', '\u{42}', '\u{43}',
+    '\u{44}', '\u{45}', '\u{46}', '\u{47}', '\u{48}', '\u{49}', '\u{4A}', '\u{4B}',
+    '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}', '\u{50}', '\u{51}', '\u{52}', '\u{53}',
+    '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}', '\u{59}', '\u{5A}', '\u{61}',
+    '\u{62}', '\u{63}', '\u{64}', '\u{65}', '\u{66}', '\u{67}', '\u{68}', '\u{69}',
+    '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}', '\u{6E}', '\u{6F}', '\u{70}', '\u{71}',
+    '\u{72}', '\u{73}', '\u{74}', '\u{75}', '\u{76}', '\u{77}', '\u{78}', '\u{79}',
+    '\u{7A}', '\u{41}', '\u{42}', '\u{43}', '\u{44}', '\u{45}', '\u{46}', '\u{47}',
+    '\u{48}', '\u{49}', '\u{4A}', '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}',
+    '\u{50}', '\u{51}', '\u{52}', '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{57}',
+    '\u{58}', '\u{59}', '\u{5A}', '\u{61}', '\u{62}', '\u{63}', '\u{64}', '\u{65}',
+    '\u{66}', '\u{67}', '\u{68}', '\u{69}', '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}',
+    '\u{6E}', '\u{6F}', '\u{70}', '\u{71}', '\u{72}', '\u{73}', '\u{74}', '\u{75}',
+    '\u{76}', '\u{77}', '\u{78}', '\u{79}', '\u{7A}', '\u{41}', '\u{42}', '\u{43}',
+    '\u{44}', '\u{45}', '\u{46}', '\u{47}', '\u{48}', '\u{49}', '\u{4A}', '\u{4B}',
+    '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}', '\u{50}', '\u{51}', '\u{52}', '\u{53}',
+    '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}', '\u{59}', '\u{5A}', '\u{61}',
+    '\u{62}', '\u{63}', '\u{64}', '\u{65}', '\u{66}', '\u{67}', '\u{68}', '\u{69}',
+    '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}', '\u{6E}', '\u{6F}', '\u{70}', '\u{71}',
+    '\u{72}', '\u{73}', '\u{74}', '\u{75}', '\u{76}', '\u{77}', '\u{78}', '\u{79}',
+    '\u{7A}', '\u{41}', '\u{42}', '\u{43}', '\u{44}', '\u{45}', '\u{46}', '\u{47}',
+    '\u{48}', '\u{49}', '\u{4A}', '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}',
+    '\u{50}', '\u{51}', '\u{52}', '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{57}',
+    '\u{58}', '\u{59}', '\u{5A}', '\u{61}', '\u{62}', '\u{63}', '\u{64}', '\u{65}',
+    '\u{66}', '\u{67}', '\u{68}', '\u{69}', '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}',
+    '\u{6E}', '\u{6F}', '\u{70}', '\u{71}', '\u{72}', '\u{73}', '\u{74}', '\u{75}',
+    '\u{76}', '\u{77}', '\u{78}', '\u{79}', '\u{7A}', '\u{131}', '\u{237}', '\u{391}',
+    '\u{392}', '\u{393}', '\u{394}', '\u{395}', '\u{396}', '\u{397}', '\u{398}', '\u{399}',
+    '\u{39A}', '\u{39B}', '\u{39C}', '\u{39D}', '\u{39E}', '\u{39F}', '\u{3A0}', '\u{3A1}',
+    '\u{398}', '\u{3A3}', '\u{3A4}', '\u{3A5}', '\u{3A6}', '\u{3A7}', '\u{3A8}', '\u{3A9}',
+    '\u{2207}', '\u{3B1}', '\u{3B2}', '\u{3B3}', '\u{3B4}', '\u{3B5}', '\u{3B6}', '\u{3B7}',
+    '\u{3B8}', '\u{3B9}', '\u{3BA}', '\u{3BB}', '\u{3BC}', '\u{3BD}', '\u{3BE}', '\u{3BF}',
+    '\u{3C0}', '\u{3C1}', '\u{3C2}', '\u{3C3}', '\u{3C4}', '\u{3C5}', '\u{3C6}', '\u{3C7}',
+    '\u{3C8}', '\u{3C9}', '\u{2202}', '\u{3B5}', '\u{3B8}', '\u{3BA}', '\u{3C6}', '\u{3C1}',
+    '\u{3C0}', '\u{391}', '\u{392}', '\u{393}', '\u{394}', '\u{395}', '\u{396}', '\u{397}',
+    '\u{398}', '\u{399}', '\u{39A}', '\u{39B}', '\u{39C}', '\u{39D}', '\u{39E}', '\u{39F}',
+    '\u{3A0}', '\u{3A1}', '\u{398}', '\u{3A3}', '\u{3A4}', '\u{3A5}', '\u{3A6}', '\u{3A7}',
+    '\u{3A8}', '\u{3A9}', '\u{2207}', '\u{3B1}', '\u{3B2}', '\u{3B3}', '\u{3B4}', '\u{3B5}',
+    '\u{3B6}', '\u{3B7}', '\u{3B8}', '\u{3B9}', '\u{3BA}', '\u{3BB}', '\u{3BC}', '\u{3BD}',
+    '\u{3BE}', '\u{3BF}', '\u{3C0}', '\u{3C1}', '\u{3C2}', '\u{3C3}', '\u{3C4}', '\u{3C5}',
+    '\u{3C6}', '\u{3C7}', '\u{3C8}', '\u{3C9}', '\u{2202}', '\u{3B5}', '\u{3B8}', '\u{3BA}',
+    '\u{3C6}', '\u{3C1}', '\u{3C0}', '\u{391}', '\u{392}', '\u{393}', '\u{394}', '\u{395}',
+    '\u{396}', '\u{397}', '\u{398}', '\u{399}', '\u{39A}', '\u{39B}', '\u{39C}', '\u{39D}',
+    '\u{39E}', '\u{39F}', '\u{3A0}', '\u{3A1}', '\u{398}', '\u{3A3}', '\u{3A4}', '\u{3A5}',
+    '\u{3A6}', '\u{3A7}', '\u{3A8}', '\u{3A9}', '\u{2207}', '\u{3B1}', '\u{3B2}', '\u{3B3}',
+    '\u{3B4}', '\u{3B5}', '\u{3B6}', '\u{3B7}', '\u{3B8}', '\u{3B9}', '\u{3BA}', '\u{3BB}',
+    '\u{3BC}', '\u{3BD}', '\u{3BE}', '\u{3BF}', '\u{3C0}', '\u{3C1}', '\u{3C2}', '\u{3C3}',
+    '\u{3C4}', '\u{3C5}', '\u{3C6}', '\u{3C7}', '\u{3C8}', '\u{3C9}', '\u{2202}', '\u{3B5}',
+    '\u{3B8}', '\u{3BA}', '\u{3C6}', '\u{3C1}', '\u{3C0}', '\u{391}', '\u{392}', '\u{393}',
+    '\u{394}', '\u{395}', '\u{396}', '\u{397}', '\u{398}', '\u{399}', '\u{39A}', '\u{39B}',
+    '\u{39C}', '\u{39D}', '\u{39E}', '\u{39F}', '\u{3A0}', '\u{3A1}', '\u{398}', '\u{3A3}',
+    '\u{3A4}', '\u{3A5}', '\u{3A6}', '\u{3A7}', '\u{3A8}', '\u{3A9}', '\u{2207}', '\u{3B1}',
+    '\u{3B2}', '\u{3B3}', '\u{3B4}', '\u{3B5}', '\u{3B6}', '\u{3B7}', '\u{3B8}', '\u{3B9}',
+    '\u{3BA}', '\u{3BB}', '\u{3BC}', '\u{3BD}', '\u{3BE}', '\u{3BF}', '\u{3C0}', '\u{3C1}',
+    '\u{3C2}', '\u{3C3}', '\u{3C4}', '\u{3C5}', '\u{3C6}', '\u{3C7}', '\u{3C8}', '\u{3C9}',
+    '\u{2202}', '\u{3B5}', '\u{3B8}', '\u{3BA}', '\u{3C6}', '\u{3C1}', '\u{3C0}', '\u{391}',
+    '\u{392}', '\u{393}', '\u{394}', '\u{395}', '\u{396}', '\u{397}', '\u{398}', '\u{399}',
+    '\u{39A}', '\u{39B}', '\u{39C}', '\u{39D}', '\u{39E}', '\u{39F}', '\u{3A0}', '\u{3A1}',
+    '\u{398}', '\u{3A3}', '\u{3A4}', '\u{3A5}', '\u{3A6}', '\u{3A7}', '\u{3A8}', '\u{3A9}',
+    '\u{2207}', '\u{3B1}', '\u{3B2}', '\u{3B3}', '\u{3B4}', '\u{3B5}', '\u{3B6}', '\u{3B7}',
+    '\u{3B8}', '\u{3B9}', '\u{3BA}', '\u{3BB}', '\u{3BC}', '\u{3BD}', '\u{3BE}', '\u{3BF}',
+    '\u{3C0}', '\u{3C1}', '\u{3C2}', '\u{3C3}', '\u{3C4}', '\u{3C5}', '\u{3C6}', '\u{3C7}',
+    '\u{3C8}', '\u{3C9}', '\u{2202}', '\u{3B5}', '\u{3B8}', '\u{3BA}', '\u{3C6}', '\u{3C1}',
+    '\u{3C0}', '\u{3DC}', '\u{3DD}', '\u{30}', '\u{31}', '\u{32}', '\u{33}', '\u{34}',
+    '\u{35}', '\u{36}', '\u{37}', '\u{38}', '\u{39}', '\u{30}', '\u{31}', '\u{32}',
+    '\u{33}', '\u{34}', '\u{35}', '\u{36}', '\u{37}', '\u{38}', '\u{39}', '\u{30}',
+    '\u{31}', '\u{32}', '\u{33}', '\u{34}', '\u{35}', '\u{36}', '\u{37}', '\u{38}',
+    '\u{39}', '\u{30}', '\u{31}', '\u{32}', '\u{33}', '\u{34}', '\u{35}', '\u{36}',
+    '\u{37}', '\u{38}', '\u{39}', '\u{30}', '\u{31}', '\u{32}', '\u{33}', '\u{34}',
+    '\u{35}', '\u{36}', '\u{37}', '\u{38}', '\u{39}', '\u{627}', '\u{628}', '\u{62C}',
+    '\u{62F}', '\u{648}', '\u{632}', '\u{62D}', '\u{637}', '\u{64A}', '\u{643}', '\u{644}',
+    '\u{645}', '\u{646}', '\u{633}', '\u{639}', '\u{641}', '\u{635}', '\u{642}', '\u{631}',
+    '\u{634}', '\u{62A}', '\u{62B}', '\u{62E}', '\u{630}', '\u{636}', '\u{638}', '\u{63A}',
+    '\u{66E}', '\u{6BA}', '\u{6A1}', '\u{66F}', '\u{628}', '\u{62C}', '\u{647}', '\u{62D}',
+    '\u{64A}', '\u{643}', '\u{644}', '\u{645}', '\u{646}', '\u{633}', '\u{639}', '\u{641}',
+    '\u{635}', '\u{642}', '\u{634}', '\u{62A}', '\u{62B}', '\u{62E}', '\u{636}', '\u{63A}',
+    '\u{62C}', '\u{62D}', '\u{64A}', '\u{644}', '\u{646}', '\u{633}', '\u{639}', '\u{635}',
+    '\u{642}', '\u{634}', '\u{62E}', '\u{636}', '\u{63A}', '\u{6BA}', '\u{66F}', '\u{628}',
+    '\u{62C}', '\u{647}', '\u{62D}', '\u{637}', '\u{64A}', '\u{643}', '\u{645}', '\u{646}',
+    '\u{633}', '\u{639}', '\u{641}', '\u{635}', '\u{642}', '\u{634}', '\u{62A}', '\u{62B}',
+    '\u{62E}', '\u{636}', '\u{638}', '\u{63A}', '\u{66E}', '\u{6A1}', '\u{627}', '\u{628}',
+    '\u{62C}', '\u{62F}', '\u{647}', '\u{648}', '\u{632}', '\u{62D}', '\u{637}', '\u{64A}',
+    '\u{644}', '\u{645}', '\u{646}', '\u{633}', '\u{639}', '\u{641}', '\u{635}', '\u{642}',
+    '\u{631}', '\u{634}', '\u{62A}', '\u{62B}', '\u{62E}', '\u{630}', '\u{636}', '\u{638}',
+    '\u{63A}', '\u{628}', '\u{62C}', '\u{62F}', '\u{648}', '\u{632}', '\u{62D}', '\u{637}',
+    '\u{64A}', '\u{644}', '\u{645}', '\u{646}', '\u{633}', '\u{639}', '\u{641}', '\u{635}',
+    '\u{642}', '\u{631}', '\u{634}', '\u{62A}', '\u{62B}', '\u{62E}', '\u{630}', '\u{636}',
+    '\u{638}', '\u{63A}', '\u{30}', '\u{2E}', '\u{30}', '\u{2C}', '\u{31}', '\u{2C}',
+    '\u{32}', '\u{2C}', '\u{33}', '\u{2C}', '\u{34}', '\u{2C}', '\u{35}', '\u{2C}',
+    '\u{36}', '\u{2C}', '\u{37}', '\u{2C}', '\u{38}', '\u{2C}', '\u{39}', '\u{2C}',
+    '\u{28}', '\u{41}', '\u{29}', '\u{28}', '\u{42}', '\u{29}', '\u{28}', '\u{43}',
+    '\u{29}', '\u{28}', '\u{44}', '\u{29}', '\u{28}', '\u{45}', '\u{29}', '\u{28}',
+    '\u{46}', '\u{29}', '\u{28}', '\u{47}', '\u{29}', '\u{28}', '\u{48}', '\u{29}',
+    '\u{28}', '\u{49}', '\u{29}', '\u{28}', '\u{4A}', '\u{29}', '\u{28}', '\u{4B}',
+    '\u{29}', '\u{28}', '\u{4C}', '\u{29}', '\u{28}', '\u{4D}', '\u{29}', '\u{28}',
+    '\u{4E}', '\u{29}', '\u{28}', '\u{4F}', '\u{29}', '\u{28}', '\u{50}', '\u{29}',
+    '\u{28}', '\u{51}', '\u{29}', '\u{28}', '\u{52}', '\u{29}', '\u{28}', '\u{53}',
+    '\u{29}', '\u{28}', '\u{54}', '\u{29}', '\u{28}', '\u{55}', '\u{29}', '\u{28}',
+    '\u{56}', '\u{29}', '\u{28}', '\u{57}', '\u{29}', '\u{28}', '\u{58}', '\u{29}',
+    '\u{28}', '\u{59}', '\u{29}', '\u{28}', '\u{5A}', '\u{29}', '\u{3014}', '\u{53}',
+    '\u{3015}', '\u{43}', '\u{52}', '\u{43}', '\u{44}', '\u{57}', '\u{5A}', '\u{41}',
+    '\u{42}', '\u{43}', '\u{44}', '\u{45}', '\u{46}', '\u{47}', '\u{48}', '\u{49}',
+    '\u{4A}', '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}', '\u{50}', '\u{51}',
+    '\u{52}', '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}', '\u{59}',
+    '\u{5A}', '\u{48}', '\u{56}', '\u{4D}', '\u{56}', '\u{53}', '\u{44}', '\u{53}',
+    '\u{53}', '\u{50}', '\u{50}', '\u{56}', '\u{57}', '\u{43}', '\u{4D}', '\u{43}',
+    '\u{4D}', '\u{44}', '\u{4D}', '\u{52}', '\u{44}', '\u{4A}', '\u{307B}', '\u{304B}',
+    '\u{30B3}', '\u{30B3}', '\u{30B5}', '\u{624B}', '\u{5B57}', '\u{53CC}', '\u{30C6}', '\u{3099}',
+    '\u{4E8C}', '\u{591A}', '\u{89E3}', '\u{5929}', '\u{4EA4}', '\u{6620}', '\u{7121}', '\u{6599}',
+    '\u{524D}', '\u{5F8C}', '\u{518D}', '\u{65B0}', '\u{521D}', '\u{7D42}', '\u{751F}', '\u{8CA9}',
+    '\u{58F0}', '\u{5439}', '\u{6F14}', '\u{6295}', '\u{6355}', '\u{4E00}', '\u{4E09}', '\u{904A}',
+    '\u{5DE6}', '\u{4E2D}', '\u{53F3}', '\u{6307}', '\u{8D70}', '\u{6253}', '\u{7981}', '\u{7A7A}',
+    '\u{5408}', '\u{6E80}', '\u{6709}', '\u{6708}', '\u{7533}', '\u{5272}', '\u{55B6}', '\u{914D}',
+    '\u{3014}', '\u{672C}', '\u{3015}', '\u{3014}', '\u{4E09}', '\u{3015}', '\u{3014}', '\u{4E8C}',
+    '\u{3015}', '\u{3014}', '\u{5B89}', '\u{3015}', '\u{3014}', '\u{70B9}', '\u{3015}', '\u{3014}',
+    '\u{6253}', '\u{3015}', '\u{3014}', '\u{76D7}', '\u{3015}', '\u{3014}', '\u{52DD}', '\u{3015}',
+    '\u{3014}', '\u{6557}', '\u{3015}', '\u{5F97}', '\u{53EF}', '\u{30}', '\u{31}', '\u{32}',
+    '\u{33}', '\u{34}', '\u{35}', '\u{36}', '\u{37}', '\u{38}', '\u{39}',
+];
+
+
+/// Primary composites of pairs of characters, sorted by the pairs.
+pub const COMPOSITIONS: &[(u32, u32, u32)] = &[
+    (0x003C, 0x0338, 0x226E), (0x003D, 0x0338, 0x2260), (0x003E, 0x0338, 0x226F),
+    (0x0041, 0x0300, 0x00C0), (0x0041, 0x0301, 0x00C1), (0x0041, 0x0302, 0x00C2),
+    (0x0041, 0x0303, 0x00C3), (0x0041, 0x0304, 0x0100), (0x0041, 0x0306, 0x0102),
+    (0x0041, 0x0307, 0x0226), (0x0041, 0x0308, 0x00C4), (0x0041, 0x0309, 0x1EA2),
+    (0x0041, 0x030A, 0x00C5), (0x0041, 0x030C, 0x01CD), (0x0041, 0x030F, 0x0200),
+    (0x0041, 0x0311, 0x0202), (0x0041, 0x0323, 0x1EA0), (0x0041, 0x0325, 0x1E00),
+    (0x0041, 0x0328, 0x0104), (0x0042, 0x0307, 0x1E02), (0x0042, 0x0323, 0x1E04),
+    (0x0042, 0x0331, 0x1E06), (0x0043, 0x0301, 0x0106), (0x0043, 0x0302, 0x0108),
+    (0x0043, 0x0307, 0x010A), (0x0043, 0x030C, 0x010C), (0x0043, 0x0327, 0x00C7),
+    (0x0044, 0x0307, 0x1E0A), (0x0044, 0x030C, 0x010E), (0x0044, 0x0323, 0x1E0C),
+    (0x0044, 0x0327, 0x1E10), (0x0044, 0x032D, 0x1E12), (0x0044, 0x0331, 0x1E0E),
+    (0x0045, 0x0300, 0x00C8), (0x0045, 0x0301, 0x00C9), (0x0045, 0x0302, 0x00CA),
+    (0x0045, 0x0303, 0x1EBC), (0x0045, 0x0304, 0x0112), (0x0045, 0x0306, 0x0114),
+    (0x0045, 0x0307, 0x0116), (0x0045, 0x0308, 0x00CB), (0x0045, 0x0309, 0x1EBA),
+    (0x0045, 0x030C, 0x011A), (0x0045, 0x030F, 0x0204), (0x0045, 0x0311, 0x0206),
+    (0x0045, 0x0323, 0x1EB8), (0x0045, 0x0327, 0x0228), (0x0045, 0x0328, 0x0118),
+    (0x0045, 0x032D, 0x1E18), (0x0045, 0x0330, 0x1E1A), (0x0046, 0x0307, 0x1E1E),
+    (0x0047, 0x0301, 0x01F4), (0x0047, 0x0302, 0x011C), (0x0047, 0x0304, 0x1E20),
+    (0x0047, 0x0306, 0x011E), (0x0047, 0x0307, 0x0120), (0x0047, 0x030C, 0x01E6),
+    (0x0047, 0x0327, 0x0122), (0x0048, 0x0302, 0x0124), (0x0048, 0x0307, 0x1E22),
+    (0x0048, 0x0308, 0x1E26), (0x0048, 0x030C, 0x021E), (0x0048, 0x0323, 0x1E24),
+    (0x0048, 0x0327, 0x1E28), (0x0048, 0x032E, 0x1E2A), (0x0049, 0x0300, 0x00CC),
+    (0x0049, 0x0301, 0x00CD), (0x0049, 0x0302, 0x00CE), (0x0049, 0x0303, 0x0128),
+    (0x0049, 0x0304, 0x012A), (0x0049, 0x0306, 0x012C), (0x0049, 0x0307, 0x0130),
+    (0x0049, 0x0308, 0x00CF), (0x0049, 0x0309, 0x1EC8), (0x0049, 0x030C, 0x01CF),
+    (0x0049, 0x030F, 0x0208), (0x0049, 0x0311, 0x020A), (0x0049, 0x0323, 0x1ECA),
+    (0x0049, 0x0328, 0x012E), (0x0049, 0x0330, 0x1E2C), (0x004A, 0x0302, 0x0134),
+    (0x004B, 0x0301, 0x1E30), (0x004B, 0x030C, 0x01E8), (0x004B, 0x0323, 0x1E32),
+    (0x004B, 0x0327, 0x0136), (0x004B, 0x0331, 0x1E34), (0x004C, 0x0301, 0x0139),
+    (0x004C, 0x030C, 0x013D), (0x004C, 0x0323, 0x1E36), (0x004C, 0x0327, 0x013B),
+    (0x004C, 0x032D, 0x1E3C), (0x004C, 0x0331, 0x1E3A), (0x004D, 0x0301, 0x1E3E),
+    (0x004D, 0x0307, 0x1E40), (0x004D, 0x0323, 0x1E42), (0x004E, 0x0300, 0x01F8),
+    (0x004E, 0x0301, 0x0143), (0x004E, 0x0303, 0x00D1), (0x004E, 0x0307, 0x1E44),
+    (0x004E, 0x030C, 0x0147), (0x004E, 0x0323, 0x1E46), (0x004E, 0x0327, 0x0145),
+    (0x004E, 0x032D, 0x1E4A), (0x004E, 0x0331, 0x1E48), (0x004F, 0x0300, 0x00D2),
+    (0x004F, 0x0301, 0x00D3), (0x004F, 0x0302, 0x00D4), (0x004F, 0x0303, 0x00D5),
+    (0x004F, 0x0304, 0x014C), (0x004F, 0x0306, 0x014E), (0x004F, 0x0307, 0x022E),
+    (0x004F, 0x0308, 0x00D6), (0x004F, 0x0309, 0x1ECE), (0x004F, 0x030B, 0x0150),
+    (0x004F, 0x030C, 0x01D1), (0x004F, 0x030F, 0x020C), (0x004F, 0x0311, 0x020E),
+    (0x004F, 0x031B, 0x01A0), (0x004F, 0x0323, 0x1ECC), (0x004F, 0x0328, 0x01EA),
+    (0x0050, 0x0301, 0x1E54), (0x0050, 0x0307, 0x1E56), (0x0052, 0x0301, 0x0154),
+    (0x0052, 0x0307, 0x1E58), (0x0052, 0x030C, 0x0158), (0x0052, 0x030F, 0x0210),
+    (0x0052, 0x0311, 0x0212), (0x0052, 0x0323, 0x1E5A), (0x0052, 0x0327, 0x0156),
+    (0x0052, 0x0331, 0x1E5E), (0x0053, 0x0301, 0x015A), (0x0053, 0x0302, 0x015C),
+    (0x0053, 0x0307, 0x1E60), (0x0053, 0x030C, 0x0160), (0x0053, 0x0323, 0x1E62),
+    (0x0053, 0x0326, 0x0218), (0x0053, 0x0327, 0x015E), (0x0054, 0x0307, 0x1E6A),
+    (0x0054, 0x030C, 0x0164), (0x0054, 0x0323, 0x1E6C), (0x0054, 0x0326, 0x021A),
+    (0x0054, 0x0327, 0x0162), (0x0054, 0x032D, 0x1E70), (0x0054, 0x0331, 0x1E6E),
+    (0x0055, 0x0300, 0x00D9), (0x0055, 0x0301, 0x00DA), (0x0055, 0x0302, 0x00DB),
+    (0x0055, 0x0303, 0x0168), (0x0055, 0x0304, 0x016A), (0x0055, 0x0306, 0x016C),
+    (0x0055, 0x0308, 0x00DC), (0x0055, 0x0309, 0x1EE6), (0x0055, 0x030A, 0x016E),
+    (0x0055, 0x030B, 0x0170), (0x0055, 0x030C, 0x01D3), (0x0055, 0x030F, 0x0214),
+    (0x0055, 0x0311, 0x0216), (0x0055, 0x031B, 0x01AF), (0x0055, 0x0323, 0x1EE4),
+    (0x0055, 0x0324, 0x1E72), (0x0055, 0x0328, 0x0172), (0x0055, 0x032D, 0x1E76),
+    (0x0055, 0x0330, 0x1E74), (0x0056, 0x0303, 0x1E7C), (0x0056, 0x0323, 0x1E7E),
+    (0x0057, 0x0300, 0x1E80), (0x0057, 0x0301, 0x1E82), (0x0057, 0x0302, 0x0174),
+    (0x0057, 0x0307, 0x1E86), (0x0057, 0x0308, 0x1E84), (0x0057, 0x0323, 0x1E88),
+    (0x0058, 0x0307, 0x1E8A), (0x0058, 0x0308, 0x1E8C), (0x0059, 0x0300, 0x1EF2),
+    (0x0059, 0x0301, 0x00DD), (0x0059, 0x0302, 0x0176), (0x0059, 0x0303, 0x1EF8),
+    (0x0059, 0x0304, 0x0232), (0x0059, 0x0307, 0x1E8E), (0x0059, 0x0308, 0x0178),
+    (0x0059, 0x0309, 0x1EF6), (0x0059, 0x0323, 0x1EF4), (0x005A, 0x0301, 0x0179),
+    (0x005A, 0x0302, 0x1E90), (0x005A, 0x0307, 0x017B), (0x005A, 0x030C, 0x017D),
+    (0x005A, 0x0323, 0x1E92), (0x005A, 0x0331, 0x1E94), (0x0061, 0x0300, 0x00E0),
+    (0x0061, 0x0301, 0x00E1), (0x0061, 0x0302, 0x00E2), (0x0061, 0x0303, 0x00E3),
+    (0x0061, 0x0304, 0x0101), (0x0061, 0x0306, 0x0103), (0x0061, 0x0307, 0x0227),
+    (0x0061, 0x0308, 0x00E4), (0x0061, 0x0309, 0x1EA3), (0x0061, 0x030A, 0x00E5),
+    (0x0061, 0x030C, 0x01CE), (0x0061, 0x030F, 0x0201), (0x0061, 0x0311, 0x0203),
+    (0x0061, 0x0323, 0x1EA1), (0x0061, 0x0325, 0x1E01), (0x0061, 0x0328, 0x0105),
+    (0x0062, 0x0307, 0x1E03), (0x0062, 0x0323, 0x1E05), (0x0062, 0x0331, 0x1E07),
+    (0x0063, 0x0301, 0x0107), (0x0063, 0x0302, 0x0109), (0x0063, 0x0307, 0x010B),
+    (0x0063, 0x030C, 0x010D), (0x0063, 0x0327, 0x00E7), (0x0064, 0x0307, 0x1E0B),
+    (0x0064, 0x030C, 0x010F), (0x0064, 0x0323, 0x1E0D), (0x0064, 0x0327, 0x1E11),
+    (0x0064, 0x032D, 0x1E13), (0x0064, 0x0331, 0x1E0F), (0x0065, 0x0300, 0x00E8),
+    (0x0065, 0x0301, 0x00E9), (0x0065, 0x0302, 0x00EA), (0x0065, 0x0303, 0x1EBD),
+    (0x0065, 0x0304, 0x0113), (0x0065, 0x0306, 0x0115), (0x0065, 0x0307, 0x0117),
+    (0x0065, 0x0308, 0x00EB), (0x0065, 0x0309, 0x1EBB), (0x0065, 0x030C, 0x011B),
+    (0x0065, 0x030F, 0x0205), (0x0065, 0x0311, 0x0207), (0x0065, 0x0323, 0x1EB9),
+    (0x0065, 0x0327, 0x0229), (0x0065, 0x0328, 0x0119), (0x0065, 0x032D, 0x1E19),
+    (0x0065, 0x0330, 0x1E1B), (0x0066, 0x0307, 0x1E1F), (0x0067, 0x0301, 0x01F5),
+    (0x0067, 0x0302, 0x011D), (0x0067, 0x0304, 0x1E21), (0x0067, 0x0306, 0x011F),
+    (0x0067, 0x0307, 0x0121), (0x0067, 0x030C, 0x01E7), (0x0067, 0x0327, 0x0123),
+    (0x0068, 0x0302, 0x0125), (0x0068, 0x0307, 0x1E23), (0x0068, 0x0308, 0x1E27),
+    (0x0068, 0x030C, 0x021F), (0x0068, 0x0323, 0x1E25), (0x0068, 0x0327, 0x1E29),
+    (0x0068, 0x032E, 0x1E2B), (0x0068, 0x0331, 0x1E96), (0x0069, 0x0300, 0x00EC),
+    (0x0069, 0x0301, 0x00ED), (0x0069, 0x0302, 0x00EE), (0x0069, 0x0303, 0x0129),
+    (0x0069, 0x0304, 0x012B), (0x0069, 0x0306, 0x012D), (0x0069, 0x0308, 0x00EF),
+    (0x0069, 0x0309, 0x1EC9), (0x0069, 0x030C, 0x01D0), (0x0069, 0x030F, 0x0209),
+    (0x0069, 0x0311, 0x020B), (0x0069, 0x0323, 0x1ECB), (0x0069, 0x0328, 0x012F),
+    (0x0069, 0x0330, 0x1E2D), (0x006A, 0x0302, 0x0135), (0x006A, 0x030C, 0x01F0),
+    (0x006B, 0x0301, 0x1E31), (0x006B, 0x030C, 0x01E9), (0x006B, 0x0323, 0x1E33),
+    (0x006B, 0x0327, 0x0137), (0x006B, 0x0331, 0x1E35), (0x006C, 0x0301, 0x013A),
+    (0x006C, 0x030C, 0x013E), (0x006C, 0x0323, 0x1E37), (0x006C, 0x0327, 0x013C),
+    (0x006C, 0x032D, 0x1E3D), (0x006C, 0x0331, 0x1E3B), (0x006D, 0x0301, 0x1E3F),
+    (0x006D, 0x0307, 0x1E41), (0x006D, 0x0323, 0x1E43), (0x006E, 0x0300, 0x01F9),
+    (0x006E, 0x0301, 0x0144), (0x006E, 0x0303, 0x00F1), (0x006E, 0x0307, 0x1E45),
+    (0x006E, 0x030C, 0x0148), (0x006E, 0x0323, 0x1E47), (0x006E, 0x0327, 0x0146),
+    (0x006E, 0x032D, 0x1E4B), (0x006E, 0x0331, 0x1E49), (0x006F, 0x0300, 0x00F2),
+    (0x006F, 0x0301, 0x00F3), (0x006F, 0x0302, 0x00F4), (0x006F, 0x0303, 0x00F5),
+    (0x006F, 0x0304, 0x014D), (0x006F, 0x0306, 0x014F), (0x006F, 0x0307, 0x022F),
+    (0x006F, 0x0308, 0x00F6), (0x006F, 0x0309, 0x1ECF), (0x006F, 0x030B, 0x0151),
+    (0x006F, 0x030C, 0x01D2), (0x006F, 0x030F, 0x020D), (0x006F, 0x0311, 0x020F),
+    (0x006F, 0x031B, 0x01A1), (0x006F, 0x0323, 0x1ECD), (0x006F, 0x0328, 0x01EB),
+    (0x0070, 0x0301, 0x1E55), (0x0070, 0x0307, 0x1E57), (0x0072, 0x0301, 0x0155),
+    (0x0072, 0x0307, 0x1E59), (0x0072, 0x030C, 0x0159), (0x0072, 0x030F, 0x0211),
+    (0x0072, 0x0311, 0x0213), (0x0072, 0x0323, 0x1E5B), (0x0072, 0x0327, 0x0157),
+    (0x0072, 0x0331, 0x1E5F), (0x0073, 0x0301, 0x015B), (0x0073, 0x0302, 0x015D),
+    (0x0073, 0x0307, 0x1E61), (0x0073, 0x030C, 0x0161), (0x0073, 0x0323, 0x1E63),
+    (0x0073, 0x0326, 0x0219), (0x0073, 0x0327, 0x015F), (0x0074, 0x0307, 0x1E6B),
+    (0x0074, 0x0308, 0x1E97), (0x0074, 0x030C, 0x0165), (0x0074, 0x0323, 0x1E6D),
+    (0x0074, 0x0326, 0x021B), (0x0074, 0x0327, 0x0163), (0x0074, 0x032D, 0x1E71),
+    (0x0074, 0x0331, 0x1E6F), (0x0075, 0x0300, 0x00F9), (0x0075, 0x0301, 0x00FA),
+    (0x0075, 0x0302, 0x00FB), (0x0075, 0x0303, 0x0169), (0x0075, 0x0304, 0x016B),
+    (0x0075, 0x0306, 0x016D), (0x0075, 0x0308, 0x00FC), (0x0075, 0x0309, 0x1EE7),
+    (0x0075, 0x030A, 0x016F), (0x0075, 0x030B, 0x0171), (0x0075, 0x030C, 0x01D4),
+    (0x0075, 0x030F, 0x0215), (0x0075, 0x0311, 0x0217), (0x0075, 0x031B, 0x01B0),
+    (0x0075, 0x0323, 0x1EE5), (0x0075, 0x0324, 0x1E73), (0x0075, 0x0328, 0x0173),
+    (0x0075, 0x032D, 0x1E77), (0x0075, 0x0330, 0x1E75), (0x0076, 0x0303, 0x1E7D),
+    (0x0076, 0x0323, 0x1E7F), (0x0077, 0x0300, 0x1E81), (0x0077, 0x0301, 0x1E83),
+    (0x0077, 0x0302, 0x0175), (0x0077, 0x0307, 0x1E87), (0x0077, 0x0308, 0x1E85),
+    (0x0077, 0x030A, 0x1E98), (0x0077, 0x0323, 0x1E89), (0x0078, 0x0307, 0x1E8B),
+    (0x0078, 0x0308, 0x1E8D), (0x0079, 0x0300, 0x1EF3), (0x0079, 0x0301, 0x00FD),
+    (0x0079, 0x0302, 0x0177), (0x0079, 0x0303, 0x1EF9), (0x0079, 0x0304, 0x0233),
+    (0x0079, 0x0307, 0x1E8F), (0x0079, 0x0308, 0x00FF), (0x0079, 0x0309, 0x1EF7),
+    (0x0079, 0x030A, 0x1E99), (0x0079, 0x0323, 0x1EF5), (0x007A, 0x0301, 0x017A),
+    (0x007A, 0x0302, 0x1E91), (0x007A, 0x0307, 0x017C), (0x007A, 0x030C, 0x017E),
+    (0x007A, 0x0323, 0x1E93), (0x007A, 0x0331, 0x1E95), (0x00A8, 0x0300, 0x1FED),
+    (0x00A8, 0x0301, 0x0385), (0x00A8, 0x0342, 0x1FC1), (0x00C2, 0x0300, 0x1EA6),
+    (0x00C2, 0x0301, 0x1EA4), (0x00C2, 0x0303, 0x1EAA), (0x00C2, 0x0309, 0x1EA8),
+    (0x00C4, 0x0304, 0x01DE), (0x00C5, 0x0301, 0x01FA), (0x00C6, 0x0301, 0x01FC),
+    (0x00C6, 0x0304, 0x01E2), (0x00C7, 0x0301, 0x1E08), (0x00CA, 0x0300, 0x1EC0),
+    (0x00CA, 0x0301, 0x1EBE), (0x00CA, 0x0303, 0x1EC4), (0x00CA, 0x0309, 0x1EC2),
+    (0x00CF, 0x0301, 0x1E2E), (0x00D4, 0x0300, 0x1ED2), (0x00D4, 0x0301, 0x1ED0),
+    (0x00D4, 0x0303, 0x1ED6), (0x00D4, 0x0309, 0x1ED4), (0x00D5, 0x0301, 0x1E4C),
+    (0x00D5, 0x0304, 0x022C), (0x00D5, 0x0308, 0x1E4E), (0x00D6, 0x0304, 0x022A),
+    (0x00D8, 0x0301, 0x01FE), (0x00DC, 0x0300, 0x01DB), (0x00DC, 0x0301, 0x01D7),
+    (0x00DC, 0x0304, 0x01D5), (0x00DC, 0x030C, 0x01D9), (0x00E2, 0x0300, 0x1EA7),
+    (0x00E2, 0x0301, 0x1EA5), (0x00E2, 0x0303, 0x1EAB), (0x00E2, 0x0309, 0x1EA9),
+    (0x00E4, 0x0304, 0x01DF), (0x00E5, 0x0301, 0x01FB), (0x00E6, 0x0301, 0x01FD),
+    (0x00E6, 0x0304, 0x01E3), (0x00E7, 0x0301, 0x1E09), (0x00EA, 0x0300, 0x1EC1),
+    (0x00EA, 0x0301, 0x1EBF), (0x00EA, 0x0303, 0x1EC5), (0x00EA, 0x0309, 0x1EC3),
+    (0x00EF, 0x0301, 0x1E2F), (0x00F4, 0x0300, 0x1ED3), (0x00F4, 0x0301, 0x1ED1),
+    (0x00F4, 0x0303, 0x1ED7), (0x00F4, 0x0309, 0x1ED5), (0x00F5, 0x0301, 0x1E4D),
+    (0x00F5, 0x0304, 0x022D), (0x00F5, 0x0308, 0x1E4F), (0x00F6, 0x0304, 0x022B),
+    (0x00F8, 0x0301, 0x01FF), (0x00FC, 0x0300, 0x01DC), (0x00FC, 0x0301, 0x01D8),
+    (0x00FC, 0x0304, 0x01D6), (0x00FC, 0x030C, 0x01DA), (0x0102, 0x0300, 0x1EB0),
+    (0x0102, 0x0301, 0x1EAE), (0x0102, 0x0303, 0x1EB4), (0x0102, 0x0309, 0x1EB2),
+    (0x0103, 0x0300, 0x1EB1), (0x0103, 0x0301, 0x1EAF), (0x0103, 0x0303, 0x1EB5),
+    (0x0103, 0x0309, 0x1EB3), (0x0112, 0x0300, 0x1E14), (0x0112, 0x0301, 0x1E16),
+    (0x0113, 0x0300, 0x1E15), (0x0113, 0x0301, 0x1E17), (0x014C, 0x0300, 0x1E50),
+    (0x014C, 0x0301, 0x1E52), (0x014D, 0x0300, 0x1E51), (0x014D, 0x0301, 0x1E53),
+    (0x015A, 0x0307, 0x1E64), (0x015B, 0x0307, 0x1E65), (0x0160, 0x0307, 0x1E66),
+    (0x0161, 0x0307, 0x1E67), (0x0168, 0x0301, 0x1E78), (0x0169, 0x0301, 0x1E79),
+    (0x016A, 0x0308, 0x1E7A), (0x016B, 0x0308, 0x1E7B), (0x017F, 0x0307, 0x1E9B),
+    (0x01A0, 0x0300, 0x1EDC), (0x01A0, 0x0301, 0x1EDA), (0x01A0, 0x0303, 0x1EE0),
+    (0x01A0, 0x0309, 0x1EDE), (0x01A0, 0x0323, 0x1EE2), (0x01A1, 0x0300, 0x1EDD),
+    (0x01A1, 0x0301, 0x1EDB), (0x01A1, 0x0303, 0x1EE1), (0x01A1, 0x0309, 0x1EDF),
+    (0x01A1, 0x0323, 0x1EE3), (0x01AF, 0x0300, 0x1EEA), (0x01AF, 0x0301, 0x1EE8),
+    (0x01AF, 0x0303, 0x1EEE), (0x01AF, 0x0309, 0x1EEC), (0x01AF, 0x0323, 0x1EF0),
+    (0x01B0, 0x0300, 0x1EEB), (0x01B0, 0x0301, 0x1EE9), (0x01B0, 0x0303, 0x1EEF),
+    (0x01B0, 0x0309, 0x1EED), (0x01B0, 0x0323, 0x1EF1), (0x01B7, 0x030C, 0x01EE),
+    (0x01EA, 0x0304, 0x01EC), (0x01EB, 0x0304, 0x01ED), (0x0226, 0x0304, 0x01E0),
+    (0x0227, 0x0304, 0x01E1), (0x0228, 0x0306, 0x1E1C), (0x0229, 0x0306, 0x1E1D),
+    (0x022E, 0x0304, 0x0230), (0x022F, 0x0304, 0x0231), (0x0292, 0x030C, 0x01EF),
+    (0x0391, 0x0300, 0x1FBA), (0x0391, 0x0301, 0x0386), (0x0391, 0x0304, 0x1FB9),
+    (0x0391, 0x0306, 0x1FB8), (0x0391, 0x0313, 0x1F08), (0x0391, 0x0314, 0x1F09),
+    (0x0391, 0x0345, 0x1FBC), (0x0395, 0x0300, 0x1FC8), (0x0395, 0x0301, 0x0388),
+    (0x0395, 0x0313, 0x1F18), (0x0395, 0x0314, 0x1F19), (0x0397, 0x0300, 0x1FCA),
+    (0x0397, 0x0301, 0x0389), (0x0397, 0x0313, 0x1F28), (0x0397, 0x0314, 0x1F29),
+    (0x0397, 0x0345, 0x1FCC), (0x0399, 0x0300, 0x1FDA), (0x0399, 0x0301, 0x038A),
+    (0x0399, 0x0304, 0x1FD9), (0x0399, 0x0306, 0x1FD8), (0x0399, 0x0308, 0x03AA),
+    (0x0399, 0x0313, 0x1F38), (0x0399, 0x0314, 0x1F39), (0x039F, 0x0300, 0x1FF8),
+    (0x039F, 0x0301, 0x038C), (0x039F, 0x0313, 0x1F48), (0x039F, 0x0314, 0x1F49),
+    (0x03A1, 0x0314, 0x1FEC), (0x03A5, 0x0300, 0x1FEA), (0x03A5, 0x0301, 0x038E),
+    (0x03A5, 0x0304, 0x1FE9), (0x03A5, 0x0306, 0x1FE8), (0x03A5, 0x0308, 0x03AB),
+    (0x03A5, 0x0314, 0x1F59), (0x03A9, 0x0300, 0x1FFA), (0x03A9, 0x0301, 0x038F),
+    (0x03A9, 0x0313, 0x1F68), (0x03A9, 0x0314, 0x1F69), (0x03A9, 0x0345, 0x1FFC),
+    (0x03AC, 0x0345, 0x1FB4), (0x03AE, 0x0345, 0x1FC4), (0x03B1, 0x0300, 0x1F70),
+    (0x03B1, 0x0301, 0x03AC), (0x03B1, 0x0304, 0x1FB1), (0x03B1, 0x0306, 0x1FB0),
+    (0x03B1, 0x0313, 0x1F00), (0x03B1, 0x0314, 0x1F01), (0x03B1, 0x0342, 0x1FB6),
+    (0x03B1, 0x0345, 0x1FB3), (0x03B5, 0x0300, 0x1F72), (0x03B5, 0x0301, 0x03AD),
+    (0x03B5, 0x0313, 0x1F10), (0x03B5, 0x0314, 0x1F11), (0x03B7, 0x0300, 0x1F74),
+    (0x03B7, 0x0301, 0x03AE), (0x03B7, 0x0313, 0x1F20), (0x03B7, 0x0314, 0x1F21),
+    (0x03B7, 0x0342, 0x1FC6), (0x03B7, 0x0345, 0x1FC3), (0x03B9, 0x0300, 0x1F76),
+    (0x03B9, 0x0301, 0x03AF), (0x03B9, 0x0304, 0x1FD1), (0x03B9, 0x0306, 0x1FD0),
+    (0x03B9, 0x0308, 0x03CA), (0x03B9, 0x0313, 0x1F30), (0x03B9, 0x0314, 0x1F31),
+    (0x03B9, 0x0342, 0x1FD6), (0x03BF, 0x0300, 0x1F78), (0x03BF, 0x0301, 0x03CC),
+    (0x03BF, 0x0313, 0x1F40), (0x03BF, 0x0314, 0x1F41), (0x03C1, 0x0313, 0x1FE4),
+    (0x03C1, 0x0314, 0x1FE5), (0x03C5, 0x0300, 0x1F7A), (0x03C5, 0x0301, 0x03CD),
+    (0x03C5, 0x0304, 0x1FE1), (0x03C5, 0x0306, 0x1FE0), (0x03C5, 0x0308, 0x03CB),
+    (0x03C5, 0x0313, 0x1F50), (0x03C5, 0x0314, 0x1F51), (0x03C5, 0x0342, 0x1FE6),
+    (0x03C9, 0x0300, 0x1F7C), (0x03C9, 0x0301, 0x03CE), (0x03C9, 0x0313, 0x1F60),
+    (0x03C9, 0x0314, 0x1F61), (0x03C9, 0x0342, 0x1FF6), (0x03C9, 0x0345, 0x1FF3),
+    (0x03CA, 0x0300, 0x1FD2), (0x03CA, 0x0301, 0x0390), (0x03CA, 0x0342, 0x1FD7),
+    (0x03CB, 0x0300, 0x1FE2), (0x03CB, 0x0301, 0x03B0), (0x03CB, 0x0342, 0x1FE7),
+    (0x03CE, 0x0345, 0x1FF4), (0x03D2, 0x0301, 0x03D3), (0x03D2, 0x0308, 0x03D4),
+    (0x0406, 0x0308, 0x0407), (0x0410, 0x0306, 0x04D0), (0x0410, 0x0308, 0x04D2),
+    (0x0413, 0x0301, 0x0403), (0x0415, 0x0300, 0x0400), (0x0415, 0x0306, 0x04D6),
+    (0x0415, 0x0308, 0x0401), (0x0416, 0x0306, 0x04C1), (0x0416, 0x0308, 0x04DC),
+    (0x0417, 0x0308, 0x04DE), (0x0418, 0x0300, 0x040D), (0x0418, 0x0304, 0x04E2),
+    (0x0418, 0x0306, 0x0419), (0x0418, 0x0308, 0x04E4), (0x041A, 0x0301, 0x040C),
+    (0x041E, 0x0308, 0x04E6), (0x0423, 0x0304, 0x04EE), (0x0423, 0x0306, 0x040E),
+    (0x0423, 0x0308, 0x04F0), (0x0423, 0x030B, 0x04F2), (0x0427, 0x0308, 0x04F4),
+    (0x042B, 0x0308, 0x04F8), (0x042D, 0x0308, 0x04EC), (0x0430, 0x0306, 0x04D1),
+    (0x0430, 0x0308, 0x04D3), (0x0433, 0x0301, 0x0453), (0x0435, 0x0300, 0x0450),
+    (0x0435, 0x0306, 0x04D7), (0x0435, 0x0308, 0x0451), (0x0436, 0x0306, 0x04C2),
+    (0x0436, 0x0308, 0x04DD), (0x0437, 0x0308, 0x04DF), (0x0438, 0x0300, 0x045D),
+    (0x0438, 0x0304, 0x04E3), (0x0438, 0x0306, 0x0439), (0x0438, 0x0308, 0x04E5),
+    (0x043A, 0x0301, 0x045C), (0x043E, 0x0308, 0x04E7), (0x0443, 0x0304, 0x04EF),
+    (0x0443, 0x0306, 0x045E), (0x0443, 0x0308, 0x04F1), (0x0443, 0x030B, 0x04F3),
+    (0x0447, 0x0308, 0x04F5), (0x044B, 0x0308, 0x04F9), (0x044D, 0x0308, 0x04ED),
+    (0x0456, 0x0308, 0x0457), (0x0474, 0x030F, 0x0476), (0x0475, 0x030F, 0x0477),
+    (0x04D8, 0x0308, 0x04DA), (0x04D9, 0x0308, 0x04DB), (0x04E8, 0x0308, 0x04EA),
+    (0x04E9, 0x0308, 0x04EB), (0x0627, 0x0653, 0x0622), (0x0627, 0x0654, 0x0623),
+    (0x0627, 0x0655, 0x0625), (0x0648, 0x0654, 0x0624), (0x064A, 0x0654, 0x0626),
+    (0x06C1, 0x0654, 0x06C2), (0x06D2, 0x0654, 0x06D3), (0x06D5, 0x0654, 0x06C0),
+    (0x0928, 0x093C, 0x0929), (0x0930, 0x093C, 0x0931), (0x0933, 0x093C, 0x0934),
+    (0x09C7, 0x09BE, 0x09CB), (0x09C7, 0x09D7, 0x09CC), (0x0B47, 0x0B3E, 0x0B4B),
+    (0x0B47, 0x0B56, 0x0B48), (0x0B47, 0x0B57, 0x0B4C), (0x0B92, 0x0BD7, 0x0B94),
+    (0x0BC6, 0x0BBE, 0x0BCA), (0x0BC6, 0x0BD7, 0x0BCC), (0x0BC7, 0x0BBE, 0x0BCB),
+    (0x0C46, 0x0C56, 0x0C48), (0x0CBF, 0x0CD5, 0x0CC0), (0x0CC6, 0x0CC2, 0x0CCA),
+    (0x0CC6, 0x0CD5, 0x0CC7), (0x0CC6, 0x0CD6, 0x0CC8), (0x0CCA, 0x0CD5, 0x0CCB),
+    (0x0D46, 0x0D3E, 0x0D4A), (0x0D46, 0x0D57, 0x0D4C), (0x0D47, 0x0D3E, 0x0D4B),
+    (0x0DD9, 0x0DCA, 0x0DDA), (0x0DD9, 0x0DCF, 0x0DDC), (0x0DD9, 0x0DDF, 0x0DDE),
+    (0x0DDC, 0x0DCA, 0x0DDD), (0x1025, 0x102E, 0x1026), (0x1B05, 0x1B35, 0x1B06),
+    (0x1B07, 0x1B35, 0x1B08), (0x1B09, 0x1B35, 0x1B0A), (0x1B0B, 0x1B35, 0x1B0C),
+    (0x1B0D, 0x1B35, 0x1B0E), (0x1B11, 0x1B35, 0x1B12), (0x1B3A, 0x1B35, 0x1B3B),
+    (0x1B3C, 0x1B35, 0x1B3D), (0x1B3E, 0x1B35, 0x1B40), (0x1B3F, 0x1B35, 0x1B41),
+    (0x1B42, 0x1B35, 0x1B43), (0x1E36, 0x0304, 0x1E38), (0x1E37, 0x0304, 0x1E39),
+    (0x1E5A, 0x0304, 0x1E5C), (0x1E5B, 0x0304, 0x1E5D), (0x1E62, 0x0307, 0x1E68),
+    (0x1E63, 0x0307, 0x1E69), (0x1EA0, 0x0302, 0x1EAC), (0x1EA0, 0x0306, 0x1EB6),
+    (0x1EA1, 0x0302, 0x1EAD), (0x1EA1, 0x0306, 0x1EB7), (0x1EB8, 0x0302, 0x1EC6),
+    (0x1EB9, 0x0302, 0x1EC7), (0x1ECC, 0x0302, 0x1ED8), (0x1ECD, 0x0302, 0x1ED9),
+    (0x1F00, 0x0300, 0x1F02), (0x1F00, 0x0301, 0x1F04), (0x1F00, 0x0342, 0x1F06),
+    (0x1F00, 0x0345, 0x1F80), (0x1F01, 0x0300, 0x1F03), (0x1F01, 0x0301, 0x1F05),
+    (0x1F01, 0x0342, 0x1F07), (0x1F01, 0x0345, 0x1F81), (0x1F02, 0x0345, 0x1F82),
+    (0x1F03, 0x0345, 0x1F83), (0x1F04, 0x0345, 0x1F84), (0x1F05, 0x0345, 0x1F85),
+    (0x1F06, 0x0345, 0x1F86), (0x1F07, 0x0345, 0x1F87), (0x1F08, 0x0300, 0x1F0A),
+    (0x1F08, 0x0301, 0x1F0C), (0x1F08, 0x0342, 0x1F0E), (0x1F08, 0x0345, 0x1F88),
+    (0x1F09, 0x0300, 0x1F0B), (0x1F09, 0x0301, 0x1F0D), (0x1F09, 0x0342, 0x1F0F),
+    (0x1F09, 0x0345, 0x1F89), (0x1F0A, 0x0345, 0x1F8A), (0x1F0B, 0x0345, 0x1F8B),
+    (0x1F0C, 0x0345, 0x1F8C), (0x1F0D, 0x0345, 0x1F8D), (0x1F0E, 0x0345, 0x1F8E),
+    (0x1F0F, 0x0345, 0x1F8F), (0x1F10, 0x0300, 0x1F12), (0x1F10, 0x0301, 0x1F14),
+    (0x1F11, 0x0300, 0x1F13), (0x1F11, 0x0301, 0x1F15), (0x1F18, 0x0300, 0x1F1A),
+    (0x1F18, 0x0301, 0x1F1C), (0x1F19, 0x0300, 0x1F1B), (0x1F19, 0x0301, 0x1F1D),
+    (0x1F20, 0x0300, 0x1F22), (0x1F20, 0x0301, 0x1F24), (0x1F20, 0x0342, 0x1F26),
+    (0x1F20, 0x0345, 0x1F90), (0x1F21, 0x0300, 0x1F23), (0x1F21, 0x0301, 0x1F25),
+    (0x1F21, 0x0342, 0x1F27), (0x1F21, 0x0345, 0x1F91), (0x1F22, 0x0345, 0x1F92),
+    (0x1F23, 0x0345, 0x1F93), (0x1F24, 0x0345, 0x1F94), (0x1F25, 0x0345, 0x1F95),
+    (0x1F26, 0x0345, 0x1F96), (0x1F27, 0x0345, 0x1F97), (0x1F28, 0x0300, 0x1F2A),
+    (0x1F28, 0x0301, 0x1F2C), (0x1F28, 0x0342, 0x1F2E), (0x1F28, 0x0345, 0x1F98),
+    (0x1F29, 0x0300, 0x1F2B), (0x1F29, 0x0301, 0x1F2D), (0x1F29, 0x0342, 0x1F2F),
+    (0x1F29, 0x0345, 0x1F99), (0x1F2A, 0x0345, 0x1F9A), (0x1F2B, 0x0345, 0x1F9B),
+    (0x1F2C, 0x0345, 0x1F9C), (0x1F2D, 0x0345, 0x1F9D), (0x1F2E, 0x0345, 0x1F9E),
+    (0x1F2F, 0x0345, 0x1F9F), (0x1F30, 0x0300, 0x1F32), (0x1F30, 0x0301, 0x1F34),
+    (0x1F30, 0x0342, 0x1F36), (0x1F31, 0x0300, 0x1F33), (0x1F31, 0x0301, 0x1F35),
+    (0x1F31, 0x0342, 0x1F37), (0x1F38, 0x0300, 0x1F3A), (0x1F38, 0x0301, 0x1F3C),
+    (0x1F38, 0x0342, 0x1F3E), (0x1F39, 0x0300, 0x1F3B), (0x1F39, 0x0301, 0x1F3D),
+    (0x1F39, 0x0342, 0x1F3F), (0x1F40, 0x0300, 0x1F42), (0x1F40, 0x0301, 0x1F44),
+    (0x1F41, 0x0300, 0x1F43), (0x1F41, 0x0301, 0x1F45), (0x1F48, 0x0300, 0x1F4A),
+    (0x1F48, 0x0301, 0x1F4C), (0x1F49, 0x0300, 0x1F4B), (0x1F49, 0x0301, 0x1F4D),
+    (0x1F50, 0x0300, 0x1F52), (0x1F50, 0x0301, 0x1F54), (0x1F50, 0x0342, 0x1F56),
+    (0x1F51, 0x0300, 0x1F53), (0x1F51, 0x0301, 0x1F55), (0x1F51, 0x0342, 0x1F57),
+    (0x1F59, 0x0300, 0x1F5B), (0x1F59, 0x0301, 0x1F5D), (0x1F59, 0x0342, 0x1F5F),
+    (0x1F60, 0x0300, 0x1F62), (0x1F60, 0x0301, 0x1F64), (0x1F60, 0x0342, 0x1F66),
+    (0x1F60, 0x0345, 0x1FA0), (0x1F61, 0x0300, 0x1F63), (0x1F61, 0x0301, 0x1F65),
+    (0x1F61, 0x0342, 0x1F67), (0x1F61, 0x0345, 0x1FA1), (0x1F62, 0x0345, 0x1FA2),
+    (0x1F63, 0x0345, 0x1FA3), (0x1F64, 0x0345, 0x1FA4), (0x1F65, 0x0345, 0x1FA5),
+    (0x1F66, 0x0345, 0x1FA6), (0x1F67, 0x0345, 0x1FA7), (0x1F68, 0x0300, 0x1F6A),
+    (0x1F68, 0x0301, 0x1F6C), (0x1F68, 0x0342, 0x1F6E), (0x1F68, 0x0345, 0x1FA8),
+    (0x1F69, 0x0300, 0x1F6B), (0x1F69, 0x0301, 0x1F6D), (0x1F69, 0x0342, 0x1F6F),
+    (0x1F69, 0x0345, 0x1FA9), (0x1F6A, 0x0345, 0x1FAA), (0x1F6B, 0x0345, 0x1FAB),
+    (0x1F6C, 0x0345, 0x1FAC), (0x1F6D, 0x0345, 0x1FAD), (0x1F6E, 0x0345, 0x1FAE),
+    (0x1F6F, 0x0345, 0x1FAF), (0x1F70, 0x0345, 0x1FB2), (0x1F74, 0x0345, 0x1FC2),
+    (0x1F7C, 0x0345, 0x1FF2), (0x1FB6, 0x0345, 0x1FB7), (0x1FBF, 0x0300, 0x1FCD),
+    (0x1FBF, 0x0301, 0x1FCE), (0x1FBF, 0x0342, 0x1FCF), (0x1FC6, 0x0345, 0x1FC7),
+    (0x1FF6, 0x0345, 0x1FF7), (0x1FFE, 0x0300, 0x1FDD), (0x1FFE, 0x0301, 0x1FDE),
+    (0x1FFE, 0x0342, 0x1FDF), (0x2190, 0x0338, 0x219A), (0x2192, 0x0338, 0x219B),
+    (0x2194, 0x0338, 0x21AE), (0x21D0, 0x0338, 0x21CD), (0x21D2, 0x0338, 0x21CF),
+    (0x21D4, 0x0338, 0x21CE), (0x2203, 0x0338, 0x2204), (0x2208, 0x0338, 0x2209),
+    (0x220B, 0x0338, 0x220C), (0x2223, 0x0338, 0x2224), (0x2225, 0x0338, 0x2226),
+    (0x223C, 0x0338, 0x2241), (0x2243, 0x0338, 0x2244), (0x2245, 0x0338, 0x2247),
+    (0x2248, 0x0338, 0x2249), (0x224D, 0x0338, 0x226D), (0x2261, 0x0338, 0x2262),
+    (0x2264, 0x0338, 0x2270), (0x2265, 0x0338, 0x2271), (0x2272, 0x0338, 0x2274),
+    (0x2273, 0x0338, 0x2275), (0x2276, 0x0338, 0x2278), (0x2277, 0x0338, 0x2279),
+    (0x227A, 0x0338, 0x2280), (0x227B, 0x0338, 0x2281), (0x227C, 0x0338, 0x22E0),
+    (0x227D, 0x0338, 0x22E1), (0x2282, 0x0338, 0x2284), (0x2283, 0x0338, 0x2285),
+    (0x2286, 0x0338, 0x2288), (0x2287, 0x0338, 0x2289), (0x2291, 0x0338, 0x22E2),
+    (0x2292, 0x0338, 0x22E3), (0x22A2, 0x0338, 0x22AC), (0x22A8, 0x0338, 0x22AD),
+    (0x22A9, 0x0338, 0x22AE), (0x22AB, 0x0338, 0x22AF), (0x22B2, 0x0338, 0x22EA),
+    (0x22B3, 0x0338, 0x22EB), (0x22B4, 0x0338, 0x22EC), (0x22B5, 0x0338, 0x22ED),
+    (0x3046, 0x3099, 0x3094), (0x304B, 0x3099, 0x304C), (0x304D, 0x3099, 0x304E),
+    (0x304F, 0x3099, 0x3050), (0x3051, 0x3099, 0x3052), (0x3053, 0x3099, 0x3054),
+    (0x3055, 0x3099, 0x3056), (0x3057, 0x3099, 0x3058), (0x3059, 0x3099, 0x305A),
+    (0x305B, 0x3099, 0x305C), (0x305D, 0x3099, 0x305E), (0x305F, 0x3099, 0x3060),
+    (0x3061, 0x3099, 0x3062), (0x3064, 0x3099, 0x3065), (0x3066, 0x3099, 0x3067),
+    (0x3068, 0x3099, 0x3069), (0x306F, 0x3099, 0x3070), (0x306F, 0x309A, 0x3071),
+    (0x3072, 0x3099, 0x3073), (0x3072, 0x309A, 0x3074), (0x3075, 0x3099, 0x3076),
+    (0x3075, 0x309A, 0x3077), (0x3078, 0x3099, 0x3079), (0x3078, 0x309A, 0x307A),
+    (0x307B, 0x3099, 0x307C), (0x307B, 0x309A, 0x307D), (0x309D, 0x3099, 0x309E),
+    (0x30A6, 0x3099, 0x30F4), (0x30AB, 0x3099, 0x30AC), (0x30AD, 0x3099, 0x30AE),
+    (0x30AF, 0x3099, 0x30B0), (0x30B1, 0x3099, 0x30B2), (0x30B3, 0x3099, 0x30B4),
+    (0x30B5, 0x3099, 0x30B6), (0x30B7, 0x3099, 0x30B8), (0x30B9, 0x3099, 0x30BA),
+    (0x30BB, 0x3099, 0x30BC), (0x30BD, 0x3099, 0x30BE), (0x30BF, 0x3099, 0x30C0),
+    (0x30C1, 0x3099, 0x30C2), (0x30C4, 0x3099, 0x30C5), (0x30C6, 0x3099, 0x30C7),
+    (0x30C8, 0x3099, 0x30C9), (0x30CF, 0x3099, 0x30D0), (0x30CF, 0x309A, 0x30D1),
+    (0x30D2, 0x3099, 0x30D3), (0x30D2, 0x309A, 0x30D4), (0x30D5, 0x3099, 0x30D6),
+    (0x30D5, 0x309A, 0x30D7), (0x30D8, 0x3099, 0x30D9), (0x30D8, 0x309A, 0x30DA),
+    (0x30DB, 0x3099, 0x30DC), (0x30DB, 0x309A, 0x30DD), (0x30EF, 0x3099, 0x30F7),
+    (0x30F0, 0x3099, 0x30F8), (0x30F1, 0x3099, 0x30F9), (0x30F2, 0x3099, 0x30FA),
+    (0x30FD, 0x3099, 0x30FE), (0x11099, 0x110BA, 0x1109A), (0x1109B, 0x110BA, 0x1109C),
+    (0x110A5, 0x110BA, 0x110AB), (0x11131, 0x11127, 0x1112E), (0x11132, 0x11127, 0x1112F),
+    (0x11347, 0x1133E, 0x1134B), (0x11347, 0x11357, 0x1134C), (0x114B9, 0x114B0, 0x114BC),
+    (0x114B9, 0x114BA, 0x114BB), (0x114B9, 0x114BD, 0x114BE), (0x115B8, 0x115AF, 0x115BA),
+    (0x115B9, 0x115AF, 0x115BB), (0x11935, 0x11930, 0x11938),
+];
+
+
+/// Full case foldings, as offsets and lengths in `FOLDED`.
+pub const CASE_FOLDING: &[(u32, u16, u8)] = &[
+    (0x0041, 0, 1), (0x0042, 1, 1), (0x0043, 2, 1), (0x0044, 3, 1),
+    (0x0045, 4, 1), (0x0046, 5, 1), (0x0047, 6, 1), (0x0048, 7, 1),
+    (0x0049, 8, 1), (0x004A, 9, 1), (0x004B, 10, 1), (0x004C, 11, 1),
+    (0x004D, 12, 1), (0x004E, 13, 1), (0x004F, 14, 1), (0x0050, 15, 1),
+    (0x0051, 16, 1), (0x0052, 17, 1), (0x0053, 18, 1), (0x0054, 19, 1),
+    (0x0055, 20, 1), (0x0056, 21, 1), (0x0057, 22, 1), (0x0058, 23, 1),
+    (0x0059, 24, 1), (0x005A, 25, 1), (0x00B5, 26, 1), (0x00C0, 27, 1),
+    (0x00C1, 28, 1), (0x00C2, 29, 1), (0x00C3, 30, 1), (0x00C4, 31, 1),
+    (0x00C5, 32, 1), (0x00C6, 33, 1), (0x00C7, 34, 1), (0x00C8, 35, 1),
+    (0x00C9, 36, 1), (0x00CA, 37, 1), (0x00CB, 38, 1), (0x00CC, 39, 1),
+    (0x00CD, 40, 1), (0x00CE, 41, 1), (0x00CF, 42, 1), (0x00D0, 43, 1),
+    (0x00D1, 44, 1), (0x00D2, 45, 1), (0x00D3, 46, 1), (0x00D4, 47, 1),
+    (0x00D5, 48, 1), (0x00D6, 49, 1), (0x00D8, 50, 1), (0x00D9, 51, 1),
+    (0x00DA, 52, 1), (0x00DB, 53, 1), (0x00DC, 54, 1), (0x00DD, 55, 1),
+    (0x00DE, 56, 1), (0x00DF, 57, 2), (0x0100, 59, 1), (0x0102, 60, 1),
+    (0x0104, 61, 1), (0x0106, 62, 1), (0x0108, 63, 1), (0x010A, 64, 1),
+    (0x010C, 65, 1), (0x010E, 66, 1), (0x0110, 67, 1), (0x0112, 68, 1),
+    (0x0114, 69, 1), (0x0116, 70, 1), (0x0118, 71, 1), (0x011A, 72, 1),
+    (0x011C, 73, 1), (0x011E, 74, 1), (0x0120, 75, 1), (0x0122, 76, 1),
+    (0x0124, 77, 1), (0x0126, 78, 1), (0x0128, 79, 1), (0x012A, 80, 1),
+    (0x012C, 81, 1), (0x012E, 82, 1), (0x0130, 83, 2), (0x0132, 85, 1),
+    (0x0134, 86, 1), (0x0136, 87, 1), (0x0139, 88, 1), (0x013B, 89, 1),
+    (0x013D, 90, 1), (0x013F, 91, 1), (0x0141, 92, 1), (0x0143, 93, 1),
+    (0x0145, 94, 1), (0x0147, 95, 1), (0x0149, 96, 2), (0x014A, 98, 1),
+    (0x014C, 99, 1), (0x014E, 100, 1), (0x0150, 101, 1), (0x0152, 102, 1),
+    (0x0154, 103, 1), (0x0156, 104, 1), (0x0158, 105, 1), (0x015A, 106, 1),
+    (0x015C, 107, 1), (0x015E, 108, 1), (0x0160, 109, 1), (0x0162, 110, 1),
+    (0x0164, 111, 1), (0x0166, 112, 1), (0x0168, 113, 1), (0x016A, 114, 1),
+    (0x016C, 115, 1), (0x016E, 116, 1), (0x0170, 117, 1), (0x0172, 118, 1),
+    (0x0174, 119, 1), (0x0176, 120, 1), (0x0178, 121, 1), (0x0179, 122, 1),
+    (0x017B, 123, 1), (0x017D, 124, 1), (0x017F, 125, 1), (0x0181, 126, 1),
+    (0x0182, 127, 1), (0x0184, 128, 1), (0x0186, 129, 1), (0x0187, 130, 1),
+    (0x0189, 131, 1), (0x018A, 132, 1), (0x018B, 133, 1), (0x018E, 134, 1),
+    (0x018F, 135, 1), (0x0190, 136, 1), (0x0191, 137, 1), (0x0193, 138, 1),
+    (0x0194, 139, 1), (0x0196, 140, 1), (0x0197, 141, 1), (0x0198, 142, 1),
+    (0x019C, 143, 1), (0x019D, 144, 1), (0x019F, 145, 1), (0x01A0, 146, 1),
+    (0x01A2, 147, 1), (0x01A4, 148, 1), (0x01A6, 149, 1), (0x01A7, 150, 1),
+    (0x01A9, 151, 1), (0x01AC, 152, 1), (0x01AE, 153, 1), (0x01AF, 154, 1),
+    (0x01B1, 155, 1), (0x01B2, 156, 1), (0x01B3, 157, 1), (0x01B5, 158, 1),
+    (0x01B7, 159, 1), (0x01B8, 160, 1), (0x01BC, 161, 1), (0x01C4, 162, 1),
+    (0x01C5, 163, 1), (0x01C7, 164, 1), (0x01C8, 165, 1), (0x01CA, 166, 1),
+    (0x01CB, 167, 1), (0x01CD, 168, 1), (0x01CF, 169, 1), (0x01D1, 170, 1),
+    (0x01D3, 171, 1), (0x01D5, 172, 1), (0x01D7, 173, 1), (0x01D9, 174, 1),
+    (0x01DB, 175, 1), (0x01DE, 176, 1), (0x01E0, 177, 1), (0x01E2, 178, 1),
+    (0x01E4, 179, 1), (0x01E6, 180, 1), (0x01E8, 181, 1), (0x01EA, 182, 1),
+    (0x01EC, 183, 1), (0x01EE, 184, 1), (0x01F0, 185, 2), (0x01F1, 187, 1),
+    (0x01F2, 188, 1), (0x01F4, 189, 1), (0x01F6, 190, 1), (0x01F7, 191, 1),
+    (0x01F8, 192, 1), (0x01FA, 193, 1), (0x01FC, 194, 1), (0x01FE, 195, 1),
+    (0x0200, 196, 1), (0x0202, 197, 1), (0x0204, 198, 1), (0x0206, 199, 1),
+    (0x0208, 200, 1), (0x020A, 201, 1), (0x020C, 202, 1), (0x020E, 203, 1),
+    (0x0210, 204, 1), (0x0212, 205, 1), (0x0214, 206, 1), (0x0216, 207, 1),
+    (0x0218, 208, 1), (0x021A, 209, 1), (0x021C, 210, 1), (0x021E, 211, 1),
+    (0x0220, 212, 1), (0x0222, 213, 1), (0x0224, 214, 1), (0x0226, 215, 1),
+    (0x0228, 216, 1), (0x022A, 217, 1), (0x022C, 218, 1), (0x022E, 219, 1),
+    (0x0230, 220, 1), (0x0232, 221, 1), (0x023A, 222, 1), (0x023B, 223, 1),
+    (0x023D, 224, 1), (0x023E, 225, 1), (0x0241, 226, 1), (0x0243, 227, 1),
+    (0x0244, 228, 1), (0x0245, 229, 1), (0x0246, 230, 1), (0x0248, 231, 1),
+    (0x024A, 232, 1), (0x024C, 233, 1), (0x024E, 234, 1), (0x0345, 235, 1),
+    (0x0370, 236, 1), (0x0372, 237, 1), (0x0376, 238, 1), (0x037F, 239, 1),
+    (0x0386, 240, 1), (0x0388, 241, 1), (0x0389, 242, 1), (0x038A, 243, 1),
+    (0x038C, 244, 1), (0x038E, 245, 1), (0x038F, 246, 1), (0x0390, 247, 3),
+    (0x0391, 250, 1), (0x0392, 251, 1), (0x0393, 252, 1), (0x0394, 253, 1),
+    (0x0395, 254, 1), (0x0396, 255, 1), (0x0397, 256, 1), (0x0398, 257, 1),
+    (0x0399, 258, 1), (0x039A, 259, 1), (0x039B, 260, 1), (0x039C, 261, 1),
+    (0x039D, 262, 1), (0x039E, 263, 1), (0x039F, 264, 1), (0x03A0, 265, 1),
+    (0x03A1, 266, 1), (0x03A3, 267, 1), (0x03A4, 268, 1), (0x03A5, 269, 1),
+    (0x03A6, 270, 1), (0x03A7, 271, 1), (0x03A8, 272, 1), (0x03A9, 273, 1),
+    (0x03AA, 274, 1), (0x03AB, 275, 1), (0x03B0, 276, 3), (0x03C2, 279, 1),
+    (0x03CF, 280, 1), (0x03D0, 281, 1), (0x03D1, 282, 1), (0x03D5, 283, 1),
+    (0x03D6, 284, 1), (0x03D8, 285, 1), (0x03DA, 286, 1), (0x03DC, 287, 1),
+    (0x03DE, 288, 1), (0x03E0, 289, 1), (0x03E2, 290, 1), (0x03E4, 291, 1),
+    (0x03E6, 292, 1), (0x03E8, 293, 1), (0x03EA, 294, 1), (0x03EC, 295, 1),
+    (0x03EE, 296, 1), (0x03F0, 297, 1), (0x03F1, 298, 1), (0x03F4, 299, 1),
+    (0x03F5, 300, 1), (0x03F7, 301, 1), (0x03F9, 302, 1), (0x03FA, 303, 1),
+    (0x03FD, 304, 1), (0x03FE, 305, 1), (0x03FF, 306, 1), (0x0400, 307, 1),
+    (0x0401, 308, 1), (0x0402, 309, 1), (0x0403, 310, 1), (0x0404, 311, 1),
+    (0x0405, 312, 1), (0x0406, 313, 1), (0x0407, 314, 1), (0x0408, 315, 1),
+    (0x0409, 316, 1), (0x040A, 317, 1), (0x040B, 318, 1), (0x040C, 319, 1),
+    (0x040D, 320, 1), (0x040E, 321, 1), (0x040F, 322, 1), (0x0410, 323, 1),
+    (0x0411, 324, 1), (0x0412, 325, 1), (0x0413, 326, 1), (0x0414, 327, 1),
+    (0x0415, 328, 1), (0x0416, 329, 1), (0x0417, 330, 1), (0x0418, 331, 1),
+    (0x0419, 332, 1), (0x041A, 333, 1), (0x041B, 334, 1), (0x041C, 335, 1),
+    (0x041D, 336, 1), (0x041E, 337, 1), (0x041F, 338, 1), (0x0420, 339, 1),
+    (0x0421, 340, 1), (0x0422, 341, 1), (0x0423, 342, 1), (0x0424, 343, 1),
+    (0x0425, 344, 1), (0x0426, 345, 1), (0x0427, 346, 1), (0x0428, 347, 1),
+    (0x0429, 348, 1), (0x042A, 349, 1), (0x042B, 350, 1), (0x042C, 351, 1),
+    (0x042D, 352, 1), (0x042E, 353, 1), (0x042F, 354, 1), (0x0460, 355, 1),
+    (0x0462, 356, 1), (0x0464, 357, 1), (0x0466, 358, 1), (0x0468, 359, 1),
+    (0x046A, 360, 1), (0x046C, 361, 1), (0x046E, 362, 1), (0x0470, 363, 1),
+    (0x0472, 364, 1), (0x0474, 365, 1), (0x0476, 366, 1), (0x0478, 367, 1),
+    (0x047A, 368, 1), (0x047C, 369, 1), (0x047E, 370, 1), (0x0480, 371, 1),
+    (0x048A, 372, 1), (0x048C, 373, 1), (0x048E, 374, 1), (0x0490, 375, 1),
+    (0x0492, 376, 1), (0x0494, 377, 1), (0x0496, 378, 1), (0x0498, 379, 1),
+    (0x049A, 380, 1), (0x049C, 381, 1), (0x049E, 382, 1), (0x04A0, 383, 1),
+    (0x04A2, 384, 1), (0x04A4, 385, 1), (0x04A6, 386, 1), (0x04A8, 387, 1),
+    (0x04AA, 388, 1), (0x04AC, 389, 1), (0x04AE, 390, 1), (0x04B0, 391, 1),
+    (0x04B2, 392, 1), (0x04B4, 393, 1), (0x04B6, 394, 1), (0x04B8, 395, 1),
+    (0x04BA, 396, 1), (0x04BC, 397, 1), (0x04BE, 398, 1), (0x04C0, 399, 1),
+    (0x04C1, 400, 1), (0x04C3, 401, 1), (0x04C5, 402, 1), (0x04C7, 403, 1),
+    (0x04C9, 404, 1), (0x04CB, 405, 1), (0x04CD, 406, 1), (0x04D0, 407, 1),
+    (0x04D2, 408, 1), (0x04D4, 409, 1), (0x04D6, 410, 1), (0x04D8, 411, 1),
+    (0x04DA, 412, 1), (0x04DC, 413, 1), (0x04DE, 414, 1), (0x04E0, 415, 1),
+    (0x04E2, 416, 1), (0x04E4, 417, 1), (0x04E6, 418, 1), (0x04E8, 419, 1),
+    (0x04EA, 420, 1), (0x04EC, 421, 1), (0x04EE, 422, 1), (0x04F0, 423, 1),
+    (0x04F2, 424, 1), (0x04F4, 425, 1), (0x04F6, 426, 1), (0x04F8, 427, 1),
+    (0x04FA, 428, 1), (0x04FC, 429, 1), (0x04FE, 430, 1), (0x0500, 431, 1),
+    (0x0502, 432, 1), (0x0504, 433, 1), (0x0506, 434, 1), (0x0508, 435, 1),
+    (0x050A, 436, 1), (0x050C, 437, 1), (0x050E, 438, 1), (0x0510, 439, 1),
+    (0x0512, 440, 1), (0x0514, 441, 1), (0x0516, 442, 1), (0x0518, 443, 1),
+    (0x051A, 444, 1), (0x051C, 445, 1), (0x051E, 446, 1), (0x0520, 447, 1),
+    (0x0522, 448, 1), (0x0524, 449, 1), (0x0526, 450, 1), (0x0528, 451, 1),
+    (0x052A, 452, 1), (0x052C, 453, 1), (0x052E, 454, 1), (0x0531, 455, 1),
+    (0x0532, 456, 1), (0x0533, 457, 1), (0x0534, 458, 1), (0x0535, 459, 1),
+    (0x0536, 460, 1), (0x0537, 461, 1), (0x0538, 462, 1), (0x0539, 463, 1),
+    (0x053A, 464, 1), (0x053B, 465, 1), (0x053C, 466, 1), (0x053D, 467, 1),
+    (0x053E, 468, 1), (0x053F, 469, 1), (0x0540, 470, 1), (0x0541, 471, 1),
+    (0x0542, 472, 1), (0x0543, 473, 1), (0x0544, 474, 1), (0x0545, 475, 1),
+    (0x0546, 476, 1), (0x0547, 477, 1), (0x0548, 478, 1), (0x0549, 479, 1),
+    (0x054A, 480, 1), (0x054B, 481, 1), (0x054C, 482, 1), (0x054D, 483, 1),
+    (0x054E, 484, 1), (0x054F, 485, 1), (0x0550, 486, 1), (0x0551, 487, 1),
+    (0x0552, 488, 1), (0x0553, 489, 1), (0x0554, 490, 1), (0x0555, 491, 1),
+    (0x0556, 492, 1), (0x0587, 493, 2), (0x10A0, 495, 1), (0x10A1, 496, 1),
+    (0x10A2, 497, 1), (0x10A3, 498, 1), (0x10A4, 499, 1), (0x10A5, 500, 1),
+    (0x10A6, 501, 1), (0x10A7, 502, 1), (0x10A8, 503, 1), (0x10A9, 504, 1),
+    (0x10AA, 505, 1), (0x10AB, 506, 1), (0x10AC, 507, 1), (0x10AD, 508, 1),
+    (0x10AE, 509, 1), (0x10AF, 510, 1), (0x10B0, 511, 1), (0x10B1, 512, 1),
+    (0x10B2, 513, 1), (0x10B3, 514, 1), (0x10B4, 515, 1), (0x10B5, 516, 1),
+    (0x10B6, 517, 1), (0x10B7, 518, 1), (0x10B8, 519, 1), (0x10B9, 520, 1),
+    (0x10BA, 521, 1), (0x10BB, 522, 1), (0x10BC, 523, 1), (0x10BD, 524, 1),
+    (0x10BE, 525, 1), (0x10BF, 526, 1), (0x10C0, 527, 1), (0x10C1, 528, 1),
+    (0x10C2, 529, 1), (0x10C3, 530, 1), (0x10C4, 531, 1), (0x10C5, 532, 1),
+    (0x10C7, 533, 1), (0x10CD, 534, 1), (0x13F8, 535, 1), (0x13F9, 536, 1),
+    (0x13FA, 537, 1), (0x13FB, 538, 1), (0x13FC, 539, 1), (0x13FD, 540, 1),
+    (0x1C80, 541, 1), (0x1C81, 542, 1), (0x1C82, 543, 1), (0x1C83, 544, 1),
+    (0x1C84, 545, 1), (0x1C85, 546, 1), (0x1C86, 547, 1), (0x1C87, 548, 1),
+    (0x1C88, 549, 1), (0x1C90, 550, 1), (0x1C91, 551, 1), (0x1C92, 552, 1),
+    (0x1C93, 553, 1), (0x1C94, 554, 1), (0x1C95, 555, 1), (0x1C96, 556, 1),
+    (0x1C97, 557, 1), (0x1C98, 558, 1), (0x1C99, 559, 1), (0x1C9A, 560, 1),
+    (0x1C9B, 561, 1), (0x1C9C, 562, 1), (0x1C9D, 563, 1), (0x1C9E, 564, 1),
+    (0x1C9F, 565, 1), (0x1CA0, 566, 1), (0x1CA1, 567, 1), (0x1CA2, 568, 1),
+    (0x1CA3, 569, 1), (0x1CA4, 570, 1), (0x1CA5, 571, 1), (0x1CA6, 572, 1),
+    (0x1CA7, 573, 1), (0x1CA8, 574, 1), (0x1CA9, 575, 1), (0x1CAA, 576, 1),
+    (0x1CAB, 577, 1), (0x1CAC, 578, 1), (0x1CAD, 579, 1), (0x1CAE, 580, 1),
+    (0x1CAF, 581, 1), (0x1CB0, 582, 1), (0x1CB1, 583, 1), (0x1CB2, 584, 1),
+    (0x1CB3, 585, 1), (0x1CB4, 586, 1), (0x1CB5, 587, 1), (0x1CB6, 588, 1),
+    (0x1CB7, 589, 1), (0x1CB8, 590, 1), (0x1CB9, 591, 1), (0x1CBA, 592, 1),
+    (0x1CBD, 593, 1), (0x1CBE, 594, 1), (0x1CBF, 595, 1), (0x1E00, 596, 1),
+    (0x1E02, 597, 1), (0x1E04, 598, 1), (0x1E06, 599, 1), (0x1E08, 600, 1),
+    (0x1E0A, 601, 1), (0x1E0C, 602, 1), (0x1E0E, 603, 1), (0x1E10, 604, 1),
+    (0x1E12, 605, 1), (0x1E14, 606, 1), (0x1E16, 607, 1), (0x1E18, 608, 1),
+    (0x1E1A, 609, 1), (0x1E1C, 610, 1), (0x1E1E, 611, 1), (0x1E20, 612, 1),
+    (0x1E22, 613, 1), (0x1E24, 614, 1), (0x1E26, 615, 1), (0x1E28, 616, 1),
+    (0x1E2A, 617, 1), (0x1E2C, 618, 1), (0x1E2E, 619, 1), (0x1E30, 620, 1),
+    (0x1E32, 621, 1), (0x1E34, 622, 1), (0x1E36, 623, 1), (0x1E38, 624, 1),
+    (0x1E3A, 625, 1), (0x1E3C, 626, 1), (0x1E3E, 627, 1), (0x1E40, 628, 1),
+    (0x1E42, 629, 1), (0x1E44, 630, 1), (0x1E46, 631, 1), (0x1E48, 632, 1),
+    (0x1E4A, 633, 1), (0x1E4C, 634, 1), (0x1E4E, 635, 1), (0x1E50, 636, 1),
+    (0x1E52, 637, 1), (0x1E54, 638, 1), (0x1E56, 639, 1), (0x1E58, 640, 1),
+    (0x1E5A, 641, 1), (0x1E5C, 642, 1), (0x1E5E, 643, 1), (0x1E60, 644, 1),
+    (0x1E62, 645, 1), (0x1E64, 646, 1), (0x1E66, 647, 1), (0x1E68, 648, 1),
+    (0x1E6A, 649, 1), (0x1E6C, 650, 1), (0x1E6E, 651, 1), (0x1E70, 652, 1),
+    (0x1E72, 653, 1), (0x1E74, 654, 1), (0x1E76, 655, 1), (0x1E78, 656, 1),
+    (0x1E7A, 657, 1), (0x1E7C, 658, 1), (0x1E7E, 659, 1), (0x1E80, 660, 1),
+    (0x1E82, 661, 1), (0x1E84, 662, 1), (0x1E86, 663, 1), (0x1E88, 664, 1),
+    (0x1E8A, 665, 1), (0x1E8C, 666, 1), (0x1E8E, 667, 1), (0x1E90, 668, 1),
+    (0x1E92, 669, 1), (0x1E94, 670, 1), (0x1E96, 671, 2), (0x1E97, 673, 2),
+    (0x1E98, 675, 2), (0x1E99, 677, 2), (0x1E9A, 679, 2), (0x1E9B, 681, 1),
+    (0x1E9E, 682, 2), (0x1EA0, 684, 1), (0x1EA2, 685, 1), (0x1EA4, 686, 1),
+    (0x1EA6, 687, 1), (0x1EA8, 688, 1), (0x1EAA, 689, 1), (0x1EAC, 690, 1),
+    (0x1EAE, 691, 1), (0x1EB0, 692, 1), (0x1EB2, 693, 1), (0x1EB4, 694, 1),
+    (0x1EB6, 695, 1), (0x1EB8, 696, 1), (0x1EBA, 697, 1), (0x1EBC, 698, 1),
+    (0x1EBE, 699, 1), (0x1EC0, 700, 1), (0x1EC2, 701, 1), (0x1EC4, 702, 1),
+    (0x1EC6, 703, 1), (0x1EC8, 704, 1), (0x1ECA, 705, 1), (0x1ECC, 706, 1),
+    (0x1ECE, 707, 1), (0x1ED0, 708, 1), (0x1ED2, 709, 1), (0x1ED4, 710, 1),
+    (0x1ED6, 711, 1), (0x1ED8, 712, 1), (0x1EDA, 713, 1), (0x1EDC, 714, 1),
+    (0x1EDE, 715, 1), (0x1EE0, 716, 1), (0x1EE2, 717, 1), (0x1EE4, 718, 1),
+    (0x1EE6, 719, 1), (0x1EE8, 720, 1), (0x1EEA, 721, 1), (0x1EEC, 722, 1),
+    (0x1EEE, 723, 1), (0x1EF0, 724, 1), (0x1EF2, 725, 1), (0x1EF4, 726, 1),
+    (0x1EF6, 727, 1), (0x1EF8, 728, 1), (0x1EFA, 729, 1), (0x1EFC, 730, 1),
+    (0x1EFE, 731, 1), (0x1F08, 732, 1), (0x1F09, 733, 1), (0x1F0A, 734, 1),
+    (0x1F0B, 735, 1), (0x1F0C, 736, 1), (0x1F0D, 737, 1), (0x1F0E, 738, 1),
+    (0x1F0F, 739, 1), (0x1F18, 740, 1), (0x1F19, 741, 1), (0x1F1A, 742, 1),
+    (0x1F1B, 743, 1), (0x1F1C, 744, 1), (0x1F1D, 745, 1), (0x1F28, 746, 1),
+    (0x1F29, 747, 1), (0x1F2A, 748, 1), (0x1F2B, 749, 1), (0x1F2C, 750, 1),
+    (0x1F2D, 751, 1), (0x1F2E, 752, 1), (0x1F2F, 753, 1), (0x1F38, 754, 1),
+    (0x1F39, 755, 1), (0x1F3A, 756, 1), (0x1F3B, 757, 1), (0x1F3C, 758, 1),
+    (0x1F3D, 759, 1), (0x1F3E, 760, 1), (0x1F3F, 761, 1), (0x1F48, 762, 1),
+    (0x1F49, 763, 1), (0x1F4A, 764, 1), (0x1F4B, 765, 1), (0x1F4C, 766, 1),
+    (0x1F4D, 767, 1), (0x1F50, 768, 2), (0x1F52, 770, 3), (0x1F54, 773, 3),
+    (0x1F56, 776, 3), (0x1F59, 779, 1), (0x1F5B, 780, 1), (0x1F5D, 781, 1),
+    (0x1F5F, 782, 1), (0x1F68, 783, 1), (0x1F69, 784, 1), (0x1F6A, 785, 1),
+    (0x1F6B, 786, 1), (0x1F6C, 787, 1), (0x1F6D, 788, 1), (0x1F6E, 789, 1),
+    (0x1F6F, 790, 1), (0x1F80, 791, 2), (0x1F81, 793, 2), (0x1F82, 795, 2),
+    (0x1F83, 797, 2), (0x1F84, 799, 2), (0x1F85, 801, 2), (0x1F86, 803, 2),
+    (0x1F87, 805, 2), (0x1F88, 807, 2), (0x1F89, 809, 2), (0x1F8A, 811, 2),
+    (0x1F8B, 813, 2), (0x1F8C, 815, 2), (0x1F8D, 817, 2), (0x1F8E, 819, 2),
+    (0x1F8F, 821, 2), (0x1F90, 823, 2), (0x1F91, 825, 2), (0x1F92, 827, 2),
+    (0x1F93, 829, 2), (0x1F94, 831, 2), (0x1F95, 833, 2), (0x1F96, 835, 2),
+    (0x1F97, 837, 2), (0x1F98, 839, 2), (0x1F99, 841, 2), (0x1F9A, 843, 2),
+    (0x1F9B, 845, 2), (0x1F9C, 847, 2), (0x1F9D, 849, 2), (0x1F9E, 851, 2),
+    (0x1F9F, 853, 2), (0x1FA0, 855, 2), (0x1FA1, 857, 2), (0x1FA2, 859, 2),
+    (0x1FA3, 861, 2), (0x1FA4, 863, 2), (0x1FA5, 865, 2), (0x1FA6, 867, 2),
+    (0x1FA7, 869, 2), (0x1FA8, 871, 2), (0x1FA9, 873, 2), (0x1FAA, 875, 2),
+    (0x1FAB, 877, 2), (0x1FAC, 879, 2), (0x1FAD, 881, 2), (0x1FAE, 883, 2),
+    (0x1FAF, 885, 2), (0x1FB2, 887, 2), (0x1FB3, 889, 2), (0x1FB4, 891, 2),
+    (0x1FB6, 893, 2), (0x1FB7, 895, 3), (0x1FB8, 898, 1), (0x1FB9, 899, 1),
+    (0x1FBA, 900, 1), (0x1FBB, 901, 1), (0x1FBC, 902, 2), (0x1FBE, 904, 1),
+    (0x1FC2, 905, 2), (0x1FC3, 907, 2), (0x1FC4, 909, 2), (0x1FC6, 911, 2),
+    (0x1FC7, 913, 3), (0x1FC8, 916, 1), (0x1FC9, 917, 1), (0x1FCA, 918, 1),
+    (0x1FCB, 919, 1), (0x1FCC, 920, 2), (0x1FD2, 922, 3), (0x1FD3, 925, 3),
+    (0x1FD6, 928, 2), (0x1FD7, 930, 3), (0x1FD8, 933, 1), (0x1FD9, 934, 1),
+    (0x1FDA, 935, 1), (0x1FDB, 936, 1), (0x1FE2, 937, 3), (0x1FE3, 940, 3),
+    (0x1FE4, 943, 2), (0x1FE6, 945, 2), (0x1FE7, 947, 3), (0x1FE8, 950, 1),
+    (0x1FE9, 951, 1), (0x1FEA, 952, 1), (0x1FEB, 953, 1), (0x1FEC, 954, 1),
+    (0x1FF2, 955, 2), (0x1FF3, 957, 2), (0x1FF4, 959, 2), (0x1FF6, 961, 2),
+    (0x1FF7, 963, 3), (0x1FF8, 966, 1), (0x1FF9, 967, 1), (0x1FFA, 968, 1),
+    (0x1FFB, 969, 1), (0x1FFC, 970, 2), (0x2126, 972, 1), (0x212A, 973, 1),
+    (0x212B, 974, 1), (0x2132, 975, 1), (0x2160, 976, 1), (0x2161, 977, 1),
+    (0x2162, 978, 1), (0x2163, 979, 1), (0x2164, 980, 1), (0x2165, 981, 1),
+    (0x2166, 982, 1), (0x2167, 983, 1), (0x2168, 984, 1), (0x2169, 985, 1),
+    (0x216A, 986, 1), (0x216B, 987, 1), (0x216C, 988, 1), (0x216D, 989, 1),
+    (0x216E, 990, 1), (0x216F, 991, 1), (0x2183, 992, 1), (0x24B6, 993, 1),
+    (0x24B7, 994, 1), (0x24B8, 995, 1), (0x24B9, 996, 1), (0x24BA, 997, 1),
+    (0x24BB, 998, 1), (0x24BC, 999, 1), (0x24BD, 1000, 1), (0x24BE, 1001, 1),
+    (0x24BF, 1002, 1), (0x24C0, 1003, 1), (0x24C1, 1004, 1), (0x24C2, 1005, 1),
+    (0x24C3, 1006, 1), (0x24C4, 1007, 1), (0x24C5, 1008, 1), (0x24C6, 1009, 1),
+    (0x24C7, 1010, 1), (0x24C8, 1011, 1), (0x24C9, 1012, 1), (0x24CA, 1013, 1),
+    (0x24CB, 1014, 1), (0x24CC, 1015, 1), (0x24CD, 1016, 1), (0x24CE, 1017, 1),
+    (0x24CF, 1018, 1), (0x2C00, 1019, 1), (0x2C01, 1020, 1), (0x2C02, 1021, 1),
+    (0x2C03, 1022, 1), (0x2C04, 1023, 1), (0x2C05, 1024, 1), (0x2C06, 1025, 1),
+    (0x2C07, 1026, 1), (0x2C08, 1027, 1), (0x2C09, 1028, 1), (0x2C0A, 1029, 1),
+    (0x2C0B, 1030, 1), (0x2C0C, 1031, 1), (0x2C0D, 1032, 1), (0x2C0E, 1033, 1),
+    (0x2C0F, 1034, 1), (0x2C10, 1035, 1), (0x2C11, 1036, 1), (0x2C12, 1037, 1),
+    (0x2C13, 1038, 1), (0x2C14, 1039, 1), (0x2C15, 1040, 1), (0x2C16, 1041, 1),
+    (0x2C17, 1042, 1), (0x2C18, 1043, 1), (0x2C19, 1044, 1), (0x2C1A, 1045, 1),
+    (0x2C1B, 1046, 1), (0x2C1C, 1047, 1), (0x2C1D, 1048, 1), (0x2C1E, 1049, 1),
+    (0x2C1F, 1050, 1), (0x2C20, 1051, 1), (0x2C21, 1052, 1), (0x2C22, 1053, 1),
+    (0x2C23, 1054, 1), (0x2C24, 1055, 1), (0x2C25, 1056, 1), (0x2C26, 1057, 1),
+    (0x2C27, 1058, 1), (0x2C28, 1059, 1), (0x2C29, 1060, 1), (0x2C2A, 1061, 1),
+    (0x2C2B, 1062, 1), (0x2C2C, 1063, 1), (0x2C2D, 1064, 1), (0x2C2E, 1065, 1),
+    (0x2C2F, 1066, 1), (0x2C60, 1067, 1), (0x2C62, 1068, 1), (0x2C63, 1069, 1),
+    (0x2C64, 1070, 1), (0x2C67, 1071, 1), (0x2C69, 1072, 1), (0x2C6B, 1073, 1),
+    (0x2C6D, 1074, 1), (0x2C6E, 1075, 1), (0x2C6F, 1076, 1), (0x2C70, 1077, 1),
+    (0x2C72, 1078, 1), (0x2C75, 1079, 1), (0x2C7E, 1080, 1), (0x2C7F, 1081, 1),
+    (0x2C80, 1082, 1), (0x2C82, 1083, 1), (0x2C84, 1084, 1), (0x2C86, 1085, 1),
+    (0x2C88, 1086, 1), (0x2C8A, 1087, 1), (0x2C8C, 1088, 1), (0x2C8E, 1089, 1),
+    (0x2C90, 1090, 1), (0x2C92, 1091, 1), (0x2C94, 1092, 1), (0x2C96, 1093, 1),
+    (0x2C98, 1094, 1), (0x2C9A, 1095, 1), (0x2C9C, 1096, 1), (0x2C9E, 1097, 1),
+    (0x2CA0, 1098, 1), (0x2CA2, 1099, 1), (0x2CA4, 1100, 1), (0x2CA6, 1101, 1),
+    (0x2CA8, 1102, 1), (0x2CAA, 1103, 1), (0x2CAC, 1104, 1), (0x2CAE, 1105, 1),
+    (0x2CB0, 1106, 1), (0x2CB2, 1107, 1), (0x2CB4, 1108, 1), (0x2CB6, 1109, 1),
+    (0x2CB8, 1110, 1), (0x2CBA, 1111, 1), (0x2CBC, 1112, 1), (0x2CBE, 1113, 1),
+    (0x2CC0, 1114, 1), (0x2CC2, 1115, 1), (0x2CC4, 1116, 1), (0x2CC6, 1117, 1),
+    (0x2CC8, 1118, 1), (0x2CCA, 1119, 1), (0x2CCC, 1120, 1), (0x2CCE, 1121, 1),
+    (0x2CD0, 1122, 1), (0x2CD2, 1123, 1), (0x2CD4, 1124, 1), (0x2CD6, 1125, 1),
+    (0x2CD8, 1126, 1), (0x2CDA, 1127, 1), (0x2CDC, 1128, 1), (0x2CDE, 1129, 1),
+    (0x2CE0, 1130, 1), (0x2CE2, 1131, 1), (0x2CEB, 1132, 1), (0x2CED, 1133, 1),
+    (0x2CF2, 1134, 1), (0xA640, 1135, 1), (0xA642, 1136, 1), (0xA644, 1137, 1),
+    (0xA646, 1138, 1), (0xA648, 1139, 1), (0xA64A, 1140, 1), (0xA64C, 1141, 1),
+    (0xA64E, 1142, 1), (0xA650, 1143, 1), (0xA652, 1144, 1), (0xA654, 1145, 1),
+    (0xA656, 1146, 1), (0xA658, 1147, 1), (0xA65A, 1148, 1), (0xA65C, 1149, 1),
+    (0xA65E, 1150, 1), (0xA660, 1151, 1), (0xA662, 1152, 1), (0xA664, 1153, 1),
+    (0xA666, 1154, 1), (0xA668, 1155, 1), (0xA66A, 1156, 1), (0xA66C, 1157, 1),
+    (0xA680, 1158, 1), (0xA682, 1159, 1), (0xA684, 1160, 1), (0xA686, 1161, 1),
+    (0xA688, 1162, 1), (0xA68A, 1163, 1), (0xA68C, 1164, 1), (0xA68E, 1165, 1),
+    (0xA690, 1166, 1), (0xA692, 1167, 1), (0xA694, 1168, 1), (0xA696, 1169, 1),
+    (0xA698, 1170, 1), (0xA69A, 1171, 1), (0xA722, 1172, 1), (0xA724, 1173, 1),
+    (0xA726, 1174, 1), (0xA728, 1175, 1), (0xA72A, 1176, 1), (0xA72C, 1177, 1),
+    (0xA72E, 1178, 1), (0xA732, 1179, 1), (0xA734, 1180, 1), (0xA736, 1181, 1),
+    (0xA738, 1182, 1), (0xA73A, 1183, 1), (0xA73C, 1184, 1), (0xA73E, 1185, 1),
+    (0xA740, 1186, 1), (0xA742, 1187, 1), (0xA744, 1188, 1), (0xA746, 1189, 1),
+    (0xA748, 1190, 1), (0xA74A, 1191, 1), (0xA74C, 1192, 1), (0xA74E, 1193, 1),
+    (0xA750, 1194, 1), (0xA752, 1195, 1), (0xA754, 1196, 1), (0xA756, 1197, 1),
+    (0xA758, 1198, 1), (0xA75A, 1199, 1), (0xA75C, 1200, 1), (0xA75E, 1201, 1),
+    (0xA760, 1202, 1), (0xA762, 1203, 1), (0xA764, 1204, 1), (0xA766, 1205, 1),
+    (0xA768, 1206, 1), (0xA76A, 1207, 1), (0xA76C, 1208, 1), (0xA76E, 1209, 1),
+    (0xA779, 1210, 1), (0xA77B, 1211, 1), (0xA77D, 1212, 1), (0xA77E, 1213, 1),
+    (0xA780, 1214, 1), (0xA782, 1215, 1), (0xA784, 1216, 1), (0xA786, 1217, 1),
+    (0xA78B, 1218, 1), (0xA78D, 1219, 1), (0xA790, 1220, 1), (0xA792, 1221, 1),
+    (0xA796, 1222, 1), (0xA798, 1223, 1), (0xA79A, 1224, 1), (0xA79C, 1225, 1),
+    (0xA79E, 1226, 1), (0xA7A0, 1227, 1), (0xA7A2, 1228, 1), (0xA7A4, 1229, 1),
+    (0xA7A6, 1230, 1), (0xA7A8, 1231, 1), (0xA7AA, 1232, 1), (0xA7AB, 1233, 1),
+    (0xA7AC, 1234, 1), (0xA7AD, 1235, 1), (0xA7AE, 1236, 1), (0xA7B0, 1237, 1),
+    (0xA7B1, 1238, 1), (0xA7B2, 1239, 1), (0xA7B3, 1240, 1), (0xA7B4, 1241, 1),
+    (0xA7B6, 1242, 1), (0xA7B8, 1243, 1), (0xA7BA, 1244, 1), (0xA7BC, 1245, 1),
+    (0xA7BE, 1246, 1), (0xA7C0, 1247, 1), (0xA7C2, 1248, 1), (0xA7C4, 1249, 1),
+    (0xA7C5, 1250, 1), (0xA7C6, 1251, 1), (0xA7C7, 1252, 1), (0xA7C9, 1253, 1),
+    (0xA7D0, 1254, 1), (0xA7D6, 1255, 1), (0xA7D8, 1256, 1), (0xA7F5, 1257, 1),
+    (0xAB70, 1258, 1), (0xAB71, 1259, 1), (0xAB72, 1260, 1), (0xAB73, 1261, 1),
+    (0xAB74, 1262, 1), (0xAB75, 1263, 1), (0xAB76, 1264, 1), (0xAB77, 1265, 1),
+    (0xAB78, 1266, 1), (0xAB79, 1267, 1), (0xAB7A, 1268, 1), (0xAB7B, 1269, 1),
+    (0xAB7C, 1270, 1), (0xAB7D, 1271, 1), (0xAB7E, 1272, 1), (0xAB7F, 1273, 1),
+    (0xAB80, 1274, 1), (0xAB81, 1275, 1), (0xAB82, 1276, 1), (0xAB83, 1277, 1),
+    (0xAB84, 1278, 1), (0xAB85, 1279, 1), (0xAB86, 1280, 1), (0xAB87, 1281, 1),
+    (0xAB88, 1282, 1), (0xAB89, 1283, 1), (0xAB8A, 1284, 1), (0xAB8B, 1285, 1),
+    (0xAB8C, 1286, 1), (0xAB8D, 1287, 1), (0xAB8E, 1288, 1), (0xAB8F, 1289, 1),
+    (0xAB90, 1290, 1), (0xAB91, 1291, 1), (0xAB92, 1292, 1), (0xAB93, 1293, 1),
+    (0xAB94, 1294, 1), (0xAB95, 1295, 1), (0xAB96, 1296, 1), (0xAB97, 1297, 1),
+    (0xAB98, 1298, 1), (0xAB99, 1299, 1), (0xAB9A, 1300, 1), (0xAB9B, 1301, 1),
+    (0xAB9C, 1302, 1), (0xAB9D, 1303, 1), (0xAB9E, 1304, 1), (0xAB9F, 1305, 1),
+    (0xABA0, 1306, 1), (0xABA1, 1307, 1), (0xABA2, 1308, 1), (0xABA3, 1309, 1),
+    (0xABA4, 1310, 1), (0xABA5, 1311, 1), (0xABA6, 1312, 1), (0xABA7, 1313, 1),
+    (0xABA8, 1314, 1), (0xABA9, 1315, 1), (0xABAA, 1316, 1), (0xABAB, 1317, 1),
+    (0xABAC, 1318, 1), (0xABAD, 1319, 1), (0xABAE, 1320, 1), (0xABAF, 1321, 1),
+    (0xABB0, 1322, 1), (0xABB1, 1323, 1), (0xABB2, 1324, 1), (0xABB3, 1325, 1),
+    (0xABB4, 1326, 1), (0xABB5, 1327, 1), (0xABB6, 1328, 1), (0xABB7, 1329, 1),
+    (0xABB8, 1330, 1), (0xABB9, 1331, 1), (0xABBA, 1332, 1), (0xABBB, 1333, 1),
+    (0xABBC, 1334, 1), (0xABBD, 1335, 1), (0xABBE, 1336, 1), (0xABBF, 1337, 1),
+    (0xFB00, 1338, 2), (0xFB01, 1340, 2), (0xFB02, 1342, 2), (0xFB03, 1344, 3),
+    (0xFB04, 1347, 3), (0xFB05, 1350, 2), (0xFB06, 1352, 2), (0xFB13, 1354, 2),
+    (0xFB14, 1356, 2), (0xFB15, 1358, 2), (0xFB16, 1360, 2), (0xFB17, 1362, 2),
+    (0xFF21, 1364, 1), (0xFF22, 1365, 1), (0xFF23, 1366, 1), (0xFF24, 1367, 1),
+    (0xFF25, 1368, 1), (0xFF26, 1369, 1), (0xFF27, 1370, 1), (0xFF28, 1371, 1),
+    (0xFF29, 1372, 1), (0xFF2A, 1373, 1), (0xFF2B, 1374, 1), (0xFF2C, 1375, 1),
+    (0xFF2D, 1376, 1), (0xFF2E, 1377, 1), (0xFF2F, 1378, 1), (0xFF30, 1379, 1),
+    (0xFF31, 1380, 1), (0xFF32, 1381, 1), (0xFF33, 1382, 1), (0xFF34, 1383, 1),
+    (0xFF35, 1384, 1), (0xFF36, 1385, 1), (0xFF37, 1386, 1), (0xFF38, 1387, 1),
+    (0xFF39, 1388, 1), (0xFF3A, 1389, 1), (0x10400, 1390, 1), (0x10401, 1391, 1),
+    (0x10402, 1392, 1), (0x10403, 1393, 1), (0x10404, 1394, 1), (0x10405, 1395, 1),
+    (0x10406, 1396, 1), (0x10407, 1397, 1), (0x10408, 1398, 1), (0x10409, 1399, 1),
+    (0x1040A, 1400, 1), (0x1040B, 1401, 1), (0x1040C, 1402, 1), (0x1040D, 1403, 1),
+    (0x1040E, 1404, 1), (0x1040F, 1405, 1), (0x10410, 1406, 1), (0x10411, 1407, 1),
+    (0x10412, 1408, 1), (0x10413, 1409, 1), (0x10414, 1410, 1), (0x10415, 1411, 1),
+    (0x10416, 1412, 1), (0x10417, 1413, 1), (0x10418, 1414, 1), (0x10419, 1415, 1),
+    (0x1041A, 1416, 1), (0x1041B, 1417, 1), (0x1041C, 1418, 1), (0x1041D, 1419, 1),
+    (0x1041E, 1420, 1), (0x1041F, 1421, 1), (0x10420, 1422, 1), (0x10421, 1423, 1),
+    (0x10422, 1424, 1), (0x10423, 1425, 1), (0x10424, 1426, 1), (0x10425, 1427, 1),
+    (0x10426, 1428, 1), (0x10427, 1429, 1), (0x104B0, 1430, 1), (0x104B1, 1431, 1),
+    (0x104B2, 1432, 1), (0x104B3, 1433, 1), (0x104B4, 1434, 1), (0x104B5, 1435, 1),
+    (0x104B6, 1436, 1), (0x104B7, 1437, 1), (0x104B8, 1438, 1), (0x104B9, 1439, 1),
+    (0x104BA, 1440, 1), (0x104BB, 1441, 1), (0x104BC, 1442, 1), (0x104BD, 1443, 1),
+    (0x104BE, 1444, 1), (0x104BF, 1445, 1), (0x104C0, 1446, 1), (0x104C1, 1447, 1),
+    (0x104C2, 1448, 1), (0x104C3, 1449, 1), (0x104C4, 1450, 1), (0x104C5, 1451, 1),
+    (0x104C6, 1452, 1), (0x104C7, 1453, 1), (0x104C8, 1454, 1), (0x104C9, 1455, 1),
+    (0x104CA, 1456, 1), (0x104CB, 1457, 1), (0x104CC, 1458, 1), (0x104CD, 1459, 1),
+    (0x104CE, 1460, 1), (0x104CF, 1461, 1), (0x104D0, 1462, 1), (0x104D1, 1463, 1),
+    (0x104D2, 1464, 1), (0x104D3, 1465, 1), (0x10570, 1466, 1), (0x10571, 1467, 1),
+    (0x10572, 1468, 1), (0x10573, 1469, 1), (0x10574, 1470, 1), (0x10575, 1471, 1),
+    (0x10576, 1472, 1), (0x10577, 1473, 1), (0x10578, 1474, 1), (0x10579, 1475, 1),
+    (0x1057A, 1476, 1), (0x1057C, 1477, 1), (0x1057D, 1478, 1), (0x1057E, 1479, 1),
+    (0x1057F, 1480, 1), (0x10580, 1481, 1), (0x10581, 1482, 1), (0x10582, 1483, 1),
+    (0x10583, 1484, 1), (0x10584, 1485, 1), (0x10585, 1486, 1), (0x10586, 1487, 1),
+    (0x10587, 1488, 1), (0x10588, 1489, 1), (0x10589, 1490, 1), (0x1058A, 1491, 1),
+    (0x1058C, 1492, 1), (0x1058D, 1493, 1), (0x1058E, 1494, 1), (0x1058F, 1495, 1),
+    (0x10590, 1496, 1), (0x10591, 1497, 1), (0x10592, 1498, 1), (0x10594, 1499, 1),
+    (0x10595, 1500, 1), (0x10C80, 1501, 1), (0x10C81, 1502, 1), (0x10C82, 1503, 1),
+    (0x10C83, 1504, 1), (0x10C84, 1505, 1), (0x10C85, 1506, 1), (0x10C86, 1507, 1),
+    (0x10C87, 1508, 1), (0x10C88, 1509, 1), (0x10C89, 1510, 1), (0x10C8A, 1511, 1),
+    (0x10C8B, 1512, 1), (0x10C8C, 1513, 1), (0x10C8D, 1514, 1), (0x10C8E, 1515, 1),
+    (0x10C8F, 1516, 1), (0x10C90, 1517, 1), (0x10C91, 1518, 1), (0x10C92, 1519, 1),
+    (0x10C93, 1520, 1), (0x10C94, 1521, 1), (0x10C95, 1522, 1), (0x10C96, 1523, 1),
+    (0x10C97, 1524, 1), (0x10C98, 1525, 1), (0x10C99, 1526, 1), (0x10C9A, 1527, 1),
+    (0x10C9B, 1528, 1), (0x10C9C, 1529, 1), (0x10C9D, 1530, 1), (0x10C9E, 1531, 1),
+    (0x10C9F, 1532, 1), (0x10CA0, 1533, 1), (0x10CA1, 1534, 1), (0x10CA2, 1535, 1),
+    (0x10CA3, 1536, 1), (0x10CA4, 1537, 1), (0x10CA5, 1538, 1), (0x10CA6, 1539, 1),
+    (0x10CA7, 1540, 1), (0x10CA8, 1541, 1), (0x10CA9, 1542, 1), (0x10CAA, 1543, 1),
+    (0x10CAB, 1544, 1), (0x10CAC, 1545, 1), (0x10CAD, 1546, 1), (0x10CAE, 1547, 1),
+    (0x10CAF, 1548, 1), (0x10CB0, 1549, 1), (0x10CB1, 1550, 1), (0x10CB2, 1551, 1),
+    (0x118A0, 1552, 1), (0x118A1, 1553, 1), (0x118A2, 1554, 1), (0x118A3, 1555, 1),
+    (0x118A4, 1556, 1), (0x118A5, 1557, 1), (0x118A6, 1558, 1), (0x118A7, 1559, 1),
+    (0x118A8, 1560, 1), (0x118A9, 1561, 1), (0x118AA, 1562, 1), (0x118AB, 1563, 1),
+    (0x118AC, 1564, 1), (0x118AD, 1565, 1), (0x118AE, 1566, 1), (0x118AF, 1567, 1),
+    (0x118B0, 1568, 1), (0x118B1, 1569, 1), (0x118B2, 1570, 1), (0x118B3, 1571, 1),
+    (0x118B4, 1572, 1), (0x118B5, 1573, 1), (0x118B6, 1574, 1), (0x118B7, 1575, 1),
+    (0x118B8, 1576, 1), (0x118B9, 1577, 1), (0x118BA, 1578, 1), (0x118BB, 1579, 1),
+    (0x118BC, 1580, 1), (0x118BD, 1581, 1), (0x118BE, 1582, 1), (0x118BF, 1583, 1),
+    (0x16E40, 1584, 1), (0x16E41, 1585, 1), (0x16E42, 1586, 1), (0x16E43, 1587, 1),
+    (0x16E44, 1588, 1), (0x16E45, 1589, 1), (0x16E46, 1590, 1), (0x16E47, 1591, 1),
+    (0x16E48, 1592, 1), (0x16E49, 1593, 1), (0x16E4A, 1594, 1), (0x16E4B, 1595, 1),
+    (0x16E4C, 1596, 1), (0x16E4D, 1597, 1), (0x16E4E, 1598, 1), (0x16E4F, 1599, 1),
+    (0x16E50, 1600, 1), (0x16E51, 1601, 1), (0x16E52, 1602, 1), (0x16E53, 1603, 1),
+    (0x16E54, 1604, 1), (0x16E55, 1605, 1), (0x16E56, 1606, 1), (0x16E57, 1607, 1),
+    (0x16E58, 1608, 1), (0x16E59, 1609, 1), (0x16E5A, 1610, 1), (0x16E5B, 1611, 1),
+    (0x16E5C, 1612, 1), (0x16E5D, 1613, 1), (0x16E5E, 1614, 1), (0x16E5F, 1615, 1),
+    (0x1E900, 1616, 1), (0x1E901, 1617, 1), (0x1E902, 1618, 1), (0x1E903, 1619, 1),
+    (0x1E904, 1620, 1), (0x1E905, 1621, 1), (0x1E906, 1622, 1), (0x1E907, 1623, 1),
+    (0x1E908, 1624, 1), (0x1E909, 1625, 1), (0x1E90A, 1626, 1), (0x1E90B, 1627, 1),
+    (0x1E90C, 1628, 1), (0x1E90D, 1629, 1), (0x1E90E, 1630, 1), (0x1E90F, 1631, 1),
+    (0x1E910, 1632, 1), (0x1E911, 1633, 1), (0x1E912, 1634, 1), (0x1E913, 1635, 1),
+    (0x1E914, 1636, 1), (0x1E915, 1637, 1), (0x1E916, 1638, 1), (0x1E917, 1639, 1),
+    (0x1E918, 1640, 1), (0x1E919, 1641, 1), (0x1E91A, 1642, 1), (0x1E91B, 1643, 1),
+    (0x1E91C, 1644, 1), (0x1E91D, 1645, 1), (0x1E91E, 1646, 1), (0x1E91F, 1647, 1),
+    (0x1E920, 1648, 1), (0x1E921, 1649, 1),
+];
+
+
+/// Characters of case foldings.
+pub const FOLDED: &[char] = &[
+    '\u{61}', '\u{62}', '\u{63}', '\u{64}', '\u{65}', '\u{66}', '\u{67}', '\u{68}',
+    '\u{69}', '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}', '\u{6E}', '\u{6F}', '\u{70}',
+    '\u{71}', '\u{72}', '\u{73}', '\u{74}', '\u{75}', '\u{76}', '\u{77}', '\u{78}',
+    '\u{79}', '\u{7A}', '\u{3BC}', '\u{E0}', '\u{E1}', '\u{E2}', '\u{E3}', '\u{E4}',
+    '\u{E5}', '\u{E6}', '\u{E7}', '\u{E8}', '\u{E9}', '\u{EA}', '\u{EB}', '\u{EC}',
+    '\u{ED}', '\u{EE}', '\u{EF}', '\u{F0}', '\u{F1}', '\u{F2}', '\u{F3}', '\u{F4}',
+    '\u{F5}', '\u{F6}', '\u{F8}', '\u{F9}', '\u{FA}', '\u{FB}', '\u{FC}', '\u{FD}',
+    '\u{FE}', '\u{73}', '\u{73}', '\u{101}', '\u{103}', '\u{105}', '\u{107}', '\u{109}',
+    '\u{10B}', '\u{10D}', '\u{10F}', '\u{111}', '\u{113}', '\u{115}', '\u{117}', '\u{119}',
+    '\u{11B}', '\u{11D}', '\u{11F}', '\u{121}', '\u{123}', '\u{125}', '\u{127}', '\u{129}',
+    '\u{12B}', '\u{12D}', '\u{12F}', '\u{69}', '\u{307}', '\u{133}', '\u{135}', '\u{137}',
+    '\u{13A}', '\u{13C}', '\u{13E}', '\u{140}', '\u{142}', '\u{144}', '\u{146}', '\u{148}',
+    '\u{2BC}', '\u{6E}', '\u{14B}', '\u{14D}', '\u{14F}', '\u{151}', '\u{153}', '\u{155}',
+    '\u{157}', '\u{159}', '\u{15B}', '\u{15D}', '\u{15F}', '\u{161}', '\u{163}', '\u{165}',
+    '\u{167}', '\u{169}', '\u{16B}', '\u{16D}', '\u{16F}', '\u{171}', '\u{173}', '\u{175}',
+    '\u{177}', '\u{FF}', '\u{17A}', '\u{17C}', '\u{17E}', '\u{73}', '\u{253}', '\u{183}',
+    '\u{185}', '\u{254}', '\u{188}', '\u{256}', '\u{257}', '\u{18C}', '\u{1DD}', '\u{259}',
+    '\u{25B}', '\u{192}', '\u{260}', '\u{263}', '\u{269}', '\u{268}', '\u{199}', '\u{26F}',
+    '\u{272}', '\u{275}', '\u{1A1}', '\u{1A3}', '\u{1A5}', '\u{280}', '\u{1A8}', '\u{283}',
+    '\u{1AD}', '\u{288}', '\u{1B0}', '\u{28A}', '\u{28B}', '\u{1B4}', '\u{1B6}', '\u{292}',
+    '\u{1B9}', '\u{1BD}', '\u{1C6}', '\u{1C6}', '\u{1C9}', '\u{1C9}', '\u{1CC}', '\u{1CC}',
+    '\u{1CE}', '\u{1D0}', '\u{1D2}', '\u{1D4}', '\u{1D6}', '\u{1D8}', '\u{1DA}', '\u{1DC}',
+    '\u{1DF}', '\u{1E1}', '\u{1E3}', '\u{1E5}', '\u{1E7}', '\u{1E9}', '\u{1EB}', '\u{1ED}',
+    '\u{1EF}', '\u{6A}', '\u{30C}', '\u{1F3}', '\u{1F3}', '\u{1F5}', '\u{195}', '\u{1BF}',
+    '\u{1F9}', '\u{1FB}', '\u{1FD}', '\u{1FF}', '\u{201}', '\u{203}', '\u{205}', '\u{207}',
+    '\u{209}', '\u{20B}', '\u{20D}', '\u{20F}', '\u{211}', '\u{213}', '\u{215}', '\u{217}',
+    '\u{219}', '\u{21B}', '\u{21D}', '\u{21F}', '\u{19E}', '\u{223}', '\u{225}', '\u{227}',
+    '\u{229}', '\u{22B}', '\u{22D}', '\u{22F}', '\u{231}', '\u{233}', '\u{2C65}', '\u{23C}',
+    '\u{19A}', '\u{2C66}', '\u{242}', '\u{180}', '\u{289}', '\u{28C}', '\u{247}', '\u{249}',
+    '\u{24B}', '\u{24D}', '\u{24F}', '\u{3B9}', '\u{371}', '\u{373}', '\u{377}', '\u{3F3}',
+    '\u{3AC}', '\u{3AD}', '\u{3AE}', '\u{3AF}', '\u{3CC}', '\u{3CD}', '\u{3CE}', '\u{3B9}',
+    '\u{308}', '\u{301}', '\u{3B1}', '\u{3B2}', '\u{3B3}', '\u{3B4}', '\u{3B5}', '\u{3B6}',
+    '\u{3B7}', '\u{3B8}', '\u{3B9}', '\u{3BA}', '\u{3BB}', '\u{3BC}', '\u{3BD}', '\u{3BE}',
+    '\u{3BF}', '\u{3C0}', '\u{3C1}', '\u{3C3}', '\u{3C4}', '\u{3C5}', '\u{3C6}', '\u{3C7}',
+    '\u{3C8}', '\u{3C9}', '\u{3CA}', '\u{3CB}', '\u{3C5}', '\u{308}', '\u{301}', '\u{3C3}',
+    '\u{3D7}', '\u{3B2}', '\u{3B8}', '\u{3C6}', '\u{3C0}', '\u{3D9}', '\u{3DB}', '\u{3DD}',
+    '\u{3DF}', '\u{3E1}', '\u{3E3}', '\u{3E5}', '\u{3E7}', '\u{3E9}', '\u{3EB}', '\u{3ED}',
+    '\u{3EF}', '\u{3BA}', '\u{3C1}', '\u{3B8}', '\u{3B5}', '\u{3F8}', '\u{3F2}', '\u{3FB}',
+    '\u{37B}', '\u{37C}', '\u{37D}', '\u{450}', '\u{451}', '\u{452}', '\u{453}', '\u{454}',
+    '\u{455}', '\u{456}', '\u{457}', '\u{458}', '\u{459}', '\u{45A}', '\u{45B}', '\u{45C}',
+    '\u{45D}', '\u{45E}', '\u{45F}', '\u{430}', '\u{431}', '\u{432}', '\u{433}', '\u{434}',
+    '\u{435}', '\u{436}', '\u{437}', '\u{438}', '\u{439}', '\u{43A}', '\u{43B}', '\u{43C}',
+    '\u{43D}', '\u{43E}', '\u{43F}', '\u{440}', '\u{441}', '\u{442}', '\u{443}', '\u{444}',
+    '\u{445}', '\u{446}', '\u{447}', '\u{448}', '\u{449}', '\u{44A}', '\u{44B}', '\u{44C}',
+    '\u{44D}', '\u{44E}', '\u{44F}', '\u{461}', '\u{463}', '\u{465}', '\u{467}', '\u{469}',
+    '\u{46B}', '\u{46D}', '\u{46F}', '\u{471}', '\u{473}', '\u{475}', '\u{477}', '\u{479}',
+    '\u{47B}', '\u{47D}', '\u{47F}', '\u{481}', '\u{48B}', '\u{48D}', '\u{48F}', '\u{491}',
+    '\u{493}', '\u{495}', '\u{497}', '\u{499}', '\u{49B}', '\u{49D}', '\u{49F}', '\u{4A1}',
+    '\u{4A3}', '\u{4A5}', '\u{4A7}', '\u{4A9}', '\u{4AB}', '\u{4AD}', '\u{4AF}', '\u{4B1}',
+    '\u{4B3}', '\u{4B5}', '\u{4B7}', '\u{4B9}', '\u{4BB}', '\u{4BD}', '\u{4BF}', '\u{4CF}',
+    '\u{4C2}', '\u{4C4}', '\u{4C6}', '\u{4C8}', '\u{4CA}', '\u{4CC}', '\u{4CE}', '\u{4D1}',
+    '\u{4D3}', '\u{4D5}', '\u{4D7}', '\u{4D9}', '\u{4DB}', '\u{4DD}', '\u{4DF}', '\u{4E1}',
+    '\u{4E3}', '\u{4E5}', '\u{4E7}', '\u{4E9}', '\u{4EB}', '\u{4ED}', '\u{4EF}', '\u{4F1}',
+    '\u{4F3}', '\u{4F5}', '\u{4F7}', '\u{4F9}', '\u{4FB}', '\u{4FD}', '\u{4FF}', '\u{501}',
+    '\u{503}', '\u{505}', '\u{507}', '\u{509}', '\u{50B}', '\u{50D}', '\u{50F}', '\u{511}',
+    '\u{513}', '\u{515}', '\u{517}', '\u{519}', '\u{51B}', '\u{51D}', '\u{51F}', '\u{521}',
+    '\u{523}', '\u{525}', '\u{527}', '\u{529}', '\u{52B}', '\u{52D}', '\u{52F}', '\u{561}',
+    '\u{562}', '\u{563}', '\u{564}', '\u{565}', '\u{566}', '\u{567}', '\u{568}', '\u{569}',
+    '\u{56A}', '\u{56B}', '\u{56C}', '\u{56D}', '\u{56E}', '\u{56F}', '\u{570}', '\u{571}',
+    '\u{572}', '\u{573}', '\u{574}', '\u{575}', '\u{576}', '\u{577}', '\u{578}', '\u{579}',
+    '\u{57A}', '\u{57B}', '\u{57C}', '\u{57D}', '\u{57E}', '\u{57F}', '\u{580}', '\u{581}',
+    '\u{582}', '\u{583}', '\u{584}', '\u{585}', '\u{586}', '\u{565}', '\u{582}', '\u{2D00}',
+    '\u{2D01}', '\u{2D02}', '\u{2D03}', '\u{2D04}', '\u{2D05}', '\u{2D06}', '\u{2D07}', '\u{2D08}',
+    '\u{2D09}', '\u{2D0A}', '\u{2D0B}', '\u{2D0C}', '\u{2D0D}', '\u{2D0E}', '\u{2D0F}', '\u{2D10}',
+    '\u{2D11}', '\u{2D12}', '\u{2D13}', '\u{2D14}', '\u{2D15}', '\u{2D16}', '\u{2D17}', '\u{2D18}',
+    '\u{2D19}', '\u{2D1A}', '\u{2D1B}', '\u{2D1C}', '\u{2D1D}', '\u{2D1E}', '\u{2D1F}', '\u{2D20}',
+    '\u{2D21}', '\u{2D22}', '\u{2D23}', '\u{2D24}', '\u{2D25}', '\u{2D27}', '\u{2D2D}', '\u{13F0}',
+    '\u{13F1}', '\u{13F2}', '\u{13F3}', '\u{13F4}', '\u{13F5}', '\u{432}', '\u{434}', '\u{43E}',
+    '\u{441}', '\u{442}', '\u{442}', '\u{44A}', '\u{463}', '\u{A64B}', '\u{10D0}', '\u{10D1}',
+    '\u{10D2}', '\u{10D3}', '\u{10D4}', '\u{10D5}', '\u{10D6}', '\u{10D7}', '\u{10D8}', '\u{10D9}',
+    '\u{10DA}', '\u{10DB}', '\u{10DC}', '\u{10DD}', '\u{10DE}', '\u{10DF}', '\u{10E0}', '\u{10E1}',
+    '\u{10E2}', '\u{10E3}', '\u{10E4}', '\u{10E5}', '\u{10E6}', '\u{10E7}', '\u{10E8}', '\u{10E9}',
+    '\u{10EA}', '\u{10EB}', '\u{10EC}', '\u{10ED}', '\u{10EE}', '\u{10EF}', '\u{10F0}', '\u{10F1}',
+    '\u{10F2}', '\u{10F3}', '\u{10F4}', '\u{10F5}', '\u{10F6}', '\u{10F7}', '\u{10F8}', '\u{10F9}',
+    '\u{10FA}', '\u{10FD}', '\u{10FE}', '\u{10FF}', '\u{1E01}', '\u{1E03}', '\u{1E05}', '\u{1E07}',
+    '\u{1E09}', '\u{1E0B}', '\u{1E0D}', '\u{1E0F}', '\u{1E11}', '\u{1E13}', '\u{1E15}', '\u{1E17}',
+    '\u{1E19}', '\u{1E1B}', '\u{1E1D}', '\u{1E1F}', '\u{1E21}', '\u{1E23}', '\u{1E25}', '\u{1E27}',
+    '\u{1E29}', '\u{1E2B}', '\u{1E2D}', '\u{1E2F}', '\u{1E31}', '\u{1E33}', '\u{1E35}', '\u{1E37}',
+    '\u{1E39}', '\u{1E3B}', '\u{1E3D}', '\u{1E3F}', '\u{1E41}', '\u{1E43}', '\u{1E45}', '\u{1E47}',
+    '\u{1E49}', '\u{1E4B}', '\u{1E4D}', '\u{1E4F}', '\u{1E51}', '\u{1E53}', '\u{1E55}', '\u{1E57}',
+    '\u{1E59}', '\u{1E5B}', '\u{1E5D}', '\u{1E5F}', '\u{1E61}', '\u{1E63}', '\u{1E65}', '\u{1E67}',
+    '\u{1E69}', '\u{1E6B}', '\u{1E6D}', '\u{1E6F}', '\u{1E71}', '\u{1E73}', '\u{1E75}', '\u{1E77}',
+    '\u{1E79}', '\u{1E7B}', '\u{1E7D}', '\u{1E7F}', '\u{1E81}', '\u{1E83}', '\u{1E85}', '\u{1E87}',
+    '\u{1E89}', '\u{1E8B}', '\u{1E8D}', '\u{1E8F}', '\u{1E91}', '\u{1E93}', '\u{1E95}', '\u{68}',
+    '\u{331}', '\u{74}', '\u{308}', '\u{77}', '\u{30A}', '\u{79}', '\u{30A}', '\u{61}',
+    '\u{2BE}', '\u{1E61}', '\u{73}', '\u{73}', '\u{1EA1}', '\u{1EA3}', '\u{1EA5}', '\u{1EA7}',
+    '\u{1EA9}', '\u{1EAB}', '\u{1EAD}', '\u{1EAF}', '\u{1EB1}', '\u{1EB3}', '\u{1EB5}', '\u{1EB7}',
+    '\u{1EB9}', '\u{1EBB}', '\u{1EBD}', '\u{1EBF}', '\u{1EC1}', '\u{1EC3}', '\u{1EC5}', '\u{1EC7}',
+    '\u{1EC9}', '\u{1ECB}', '\u{1ECD}', '\u{1ECF}', '\u{1ED1}', '\u{1ED3}', '\u{1ED5}', '\u{1ED7}',
+    '\u{1ED9}', '\u{1EDB}', '\u{1EDD}', '\u{1EDF}', '\u{1EE1}', '\u{1EE3}', '\u{1EE5}', '\u{1EE7}',
+    '\u{1EE9}', '\u{1EEB}', '\u{1EED}', '\u{1EEF}', '\u{1EF1}', '\u{1EF3}', '\u{1EF5}', '\u{1EF7}',
+    '\u{1EF9}', '\u{1EFB}', '\u{1EFD}', '\u{1EFF}', '\u{1F00}', '\u{1F01}', '\u{1F02}', '\u{1F03}',
+    '\u{1F04}', '\u{1F05}', '\u{1F06}', '\u{1F07}', '\u{1F10}', '\u{1F11}', '\u{1F12}', '\u{1F13}',
+    '\u{1F14}', '\u{1F15}', '\u{1F20}', '\u{1F21}', '\u{1F22}', '\u{1F23}', '\u{1F24}', '\u{1F25}',
+    '\u{1F26}', '\u{1F27}', '\u{1F30}', '\u{1F31}', '\u{1F32}', '\u{1F33}', '\u{1F34}', '\u{1F35}',
+    '\u{1F36}', '\u{1F37}', '\u{1F40}', '\u{1F41}', '\u{1F42}', '\u{1F43}', '\u{1F44}', '\u{1F45}',
+    '\u{3C5}', '\u{313}', '\u{3C5}', '\u{313}', '\u{300}', '\u{3C5}', '\u{313}', '\u{301}',
+    '\u{3C5}', '\u{313}', '\u{342}', '\u{1F51}', '\u{1F53}', '\u{1F55}', '\u{1F57}', '\u{1F60}',
+    '\u{1F61}', '\u{1F62}', '\u{1F63}', '\u{1F64}', '\u{1F65}', '\u{1F66}', '\u{1F67}', '\u{1F00}',
+    '\u{3B9}', '\u{1F01}', '\u{3B9}', '\u{1F02}', '\u{3B9}', '\u{1F03}', '\u{3B9}', '\u{1F04}',
+    '\u{3B9}', '\u{1F05}', '\u{3B9}', '\u{1F06}', '\u{3B9}', '\u{1F07}', '\u{3B9}', '\u{1F00}',
+    '\u{3B9}', '\u{1F01}', '\u{3B9}', '\u{1F02}', '\u{3B9}', '\u{1F03}', '\u{3B9}', '\u{1F04}',
+    '\u{3B9}', '\u{1F05}', '\u{3B9}', '\u{1F06}', '\u{3B9}', '\u{1F07}', '\u{3B9}', '\u{1F20}',
+    '\u{3B9}', '\u{1F21}', '\u{3B9}', '\u{1F22}', '\u{3B9}', '\u{1F23}', '\u{3B9}', '\u{1F24}',
+    '\u{3B9}', '\u{1F25}', '\u{3B9}', '\u{1F26}', '\u{3B9}', '\u{1F27}', '\u{3B9}', '\u{1F20}',
+    '\u{3B9}', '\u{1F21}', '\u{3B9}', '\u{1F22}', '\u{3B9}', '\u{1F23}', '\u{3B9}', '\u{1F24}',
+    '\u{3B9}', '\u{1F25}', '\u{3B9}', '\u{1F26}', '\u{3B9}', '\u{1F27}', '\u{3B9}', '\u{1F60}',
+    '\u{3B9}', '\u{1F61}', '\u{3B9}', '\u{1F62}', '\u{3B9}', '\u{1F63}', '\u{3B9}', '\u{1F64}',
+    '\u{3B9}', '\u{1F65}', '\u{3B9}', '\u{1F66}', '\u{3B9}', '\u{1F67}', '\u{3B9}', '\u{1F60}',
+    '\u{3B9}', '\u{1F61}', '\u{3B9}', '\u{1F62}', '\u{3B9}', '\u{1F63}', '\u{3B9}', '\u{1F64}',
+    '\u{3B9}', '\u{1F65}', '\u{3B9}', '\u{1F66}', '\u{3B9}', '\u{1F67}', '\u{3B9}', '\u{1F70}',
+    '\u{3B9}', '\u{3B1}', '\u{3B9}', '\u{3AC}', '\u{3B9}', '\u{3B1}', '\u{342}', '\u{3B1}',
+    '\u{342}', '\u{3B9}', '\u{1FB0}', '\u{1FB1}', '\u{1F70}', '\u{1F71}', '\u{3B1}', '\u{3B9}',
+    '\u{3B9}', '\u{1F74}', '\u{3B9}', '\u{3B7}', '\u{3B9}', '\u{3AE}', '\u{3B9}', '\u{3B7}',
+    '\u{342}', '\u{3B7}', '\u{342}', '\u{3B9}', '\u{1F72}', '\u{1F73}', '\u{1F74}', '\u{1F75}',
+    '\u{3B7}', '\u{3B9}', '\u{3B9}', '\u{308}', '\u{300}', '\u{3B9}', '\u{308}', '\u{301}',
+    '\u{3B9}', '\u{342}', '\u{3B9}', '\u{308}', '\u{342}', '\u{1FD0}', '\u{1FD1}', '\u{1F76}',
+    '\u{1F77}', '\u{3C5}', '\u{308}', '\u{300}', '\u{3C5}', '\u{308}', '\u{301}', '\u{3C1}',
+    '\u{313}', '\u{3C5}', '\u{342}', '\u{3C5}', '\u{308}', '\u{342}', '\u{1FE0}', '\u{1FE1}',
+    '\u{1F7A}', '\u{1F7B}', '\u{1FE5}', '\u{1F7C}', '\u{3B9}', '\u{3C9}', '\u{3B9}', '\u{3CE}',
+    '\u{3B9}', '\u{3C9}', '\u{342}', '\u{3C9}', '\u{342}', '\u{3B9}', '\u{1F78}', '\u{1F79}',
+    '\u{1F7C}', '\u{1F7D}', '\u{3C9}', '\u{3B9}', '\u{3C9}', '\u{6B}', '\u{E5}', '\u{214E}',
+    '\u{2170}', '\u{2171}', '\u{2172}', '\u{2173}', '\u{2174}', '\u{2175}', '\u{2176}', '\u{2177}',
+    '\u{2178}', '\u{2179}', '\u{217A}', '\u{217B}', '\u{217C}', '\u{217D}', '\u{217E}', '\u{217F}',
+    '\u{2184}', '\u{24D0}', '\u{24D1}', '\u{24D2}', '\u{24D3}', '\u{24D4}', '\u{24D5}', '\u{24D6}',
+    '\u{24D7}', '\u{24D8}', '\u{24D9}', '\u{24DA}', '\u{24DB}', '\u{24DC}', '\u{24DD}', '\u{24DE}',
+    '\u{24DF}', '\u{24E0}', '\u{24E1}', '\u{24E2}', '\u{24E3}', '\u{24E4}', '\u{24E5}', '\u{24E6}',
+    '\u{24E7}', '\u{24E8}', '\u{24E9}', '\u{2C30}', '\u{2C31}', '\u{2C32}', '\u{2C33}', '\u{2C34}',
+    '\u{2C35}', '\u{2C36}', '\u{2C37}', '\u{2C38}', '\u{2C39}', '\u{2C3A}', '\u{2C3B}', '\u{2C3C}',
+    '\u{2C3D}', '\u{2C3E}', '\u{2C3F}', '\u{2C40}', '\u{2C41}', '\u{2C42}', '\u{2C43}', '\u{2C44}',
+    '\u{2C45}', '\u{2C46}', '\u{2C47}', '\u{2C48}', '\u{2C49}', '\u{2C4A}', '\u{2C4B}', '\u{2C4C}',
+    '\u{2C4D}', '\u{2C4E}', '\u{2C4F}', '\u{2C50}', '\u{2C51}', '\u{2C52}', '\u{2C53}', '\u{2C54}',
+    '\u{2C55}', '\u{2C56}', '\u{2C57}', '\u{2C58}', '\u{2C59}', '\u{2C5A}', '\u{2C5B}', '\u{2C5C}',
+    '\u{2C5D}', '\u{2C5E}', '\u{2C5F}', '\u{2C61}', '\u{26B}', '\u{1D7D}', '\u{27D}', '\u{2C68}',
+    '\u{2C6A}', '\u{2C6C}', '\u{251}', '\u{271}', '\u{250}', '\u{252}', '\u{2C73}', '\u{2C76}',
+    '\u{23F}', '\u{240}', '\u{2C81}', '\u{2C83}', '\u{2C85}', '\u{2C87}', '\u{2C89}', '\u{2C8B}',
+    '\u{2C8D}', '\u{2C8F}', '\u{2C91}', '\u{2C93}', '\u{2C95}', '\u{2C97}', '\u{2C99}', '\u{2C9B}',
+    '\u{2C9D}', '\u{2C9F}', '\u{2CA1}', '\u{2CA3}', '\u{2CA5}', '\u{2CA7}', '\u{2CA9}', '\u{2CAB}',
+    '\u{2CAD}', '\u{2CAF}', '\u{2CB1}', '\u{2CB3}', '\u{2CB5}', '\u{2CB7}', '\u{2CB9}', '\u{2CBB}',
+    '\u{2CBD}', '\u{2CBF}', '\u{2CC1}', '\u{2CC3}', '\u{2CC5}', '\u{2CC7}', '\u{2CC9}', '\u{2CCB}',
+    '\u{2CCD}', '\u{2CCF}', '\u{2CD1}', '\u{2CD3}', '\u{2CD5}', '\u{2CD7}', '\u{2CD9}', '\u{2CDB}',
+    '\u{2CDD}', '\u{2CDF}', '\u{2CE1}', '\u{2CE3}', '\u{2CEC}', '\u{2CEE}', '\u{2CF3}', '\u{A641}',
+    '\u{A643}', '\u{A645}', '\u{A647}', '\u{A649}', '\u{A64B}', '\u{A64D}', '\u{A64F}', '\u{A651}',
+    '\u{A653}', '\u{A655}', '\u{A657}', '\u{A659}', '\u{A65B}', '\u{A65D}', '\u{A65F}', '\u{A661}',
+    '\u{A663}', '\u{A665}', '\u{A667}', '\u{A669}', '\u{A66B}', '\u{A66D}', '\u{A681}', '\u{A683}',
+    '\u{A685}', '\u{A687}', '\u{A689}', '\u{A68B}', '\u{A68D}', '\u{A68F}', '\u{A691}', '\u{A693}',
+    '\u{A695}', '\u{A697}', '\u{A699}', '\u{A69B}', '\u{A723}', '\u{A725}', '\u{A727}', '\u{A729}',
+    '\u{A72B}', '\u{A72D}', '\u{A72F}', '\u{A733}', '\u{A735}', '\u{A737}', '\u{A739}', '\u{A73B}',
+    '\u{A73D}', '\u{A73F}', '\u{A741}', '\u{A743}', '\u{A745}', '\u{A747}', '\u{A749}', '\u{A74B}',
+    '\u{A74D}', '\u{A74F}', '\u{A751}', '\u{A753}', '\u{A755}', '\u{A757}', '\u{A759}', '\u{A75B}',
+    '\u{A75D}', '\u{A75F}', '\u{A761}', '\u{A763}', '\u{A765}', '\u{A767}', '\u{A769}', '\u{A76B}',
+    '\u{A76D}', '\u{A76F}', '\u{A77A}', '\u{A77C}', '\u{1D79}', '\u{A77F}', '\u{A781}', '\u{A783}',
+    '\u{A785}', '\u{A787}', '\u{A78C}', '\u{265}', '\u{A791}', '\u{A793}', '\u{A797}', '\u{A799}',
+    '\u{A79B}', '\u{A79D}', '\u{A79F}', '\u{A7A1}', '\u{A7A3}', '\u{A7A5}', '\u{A7A7}', '\u{A7A9}',
+    '\u{266}', '\u{25C}', '\u{261}', '\u{26C}', '\u{26A}', '\u{29E}', '\u{287}', '\u{29D}',
+    '\u{AB53}', '\u{A7B5}', '\u{A7B7}', '\u{A7B9}', '\u{A7BB}', '\u{A7BD}', '\u{A7BF}', '\u{A7C1}',
+    '\u{A7C3}', '\u{A794}', '\u{282}', '\u{1D8E}', '\u{A7C8}', '\u{A7CA}', '\u{A7D1}', '\u{A7D7}',
+    '\u{A7D9}', '\u{A7F6}', '\u{13A0}', '\u{13A1}', '\u{13A2}', '\u{13A3}', '\u{13A4}', '\u{13A5}',
+    '\u{13A6}', '\u{13A7}', '\u{13A8}', '\u{13A9}', '\u{13AA}', '\u{13AB}', '\u{13AC}', '\u{13AD}',
+    '\u{13AE}', '\u{13AF}', '\u{13B0}', '\u{13B1}', '\u{13B2}', '\u{13B3}', '\u{13B4}', '\u{13B5}',
+    '\u{13B6}', '\u{13B7}', '\u{13B8}', '\u{13B9}', '\u{13BA}', '\u{13BB}', '\u{13BC}', '\u{13BD}',
+    '\u{13BE}', '\u{13BF}', '\u{13C0}', '\u{13C1}', '\u{13C2}', '\u{13C3}', '\u{13C4}', '\u{13C5}',
+    '\u{13C6}', '\u{13C7}', '\u{13C8}', '\u{13C9}', '\u{13CA}', '\u{13CB}', '\u{13CC}', '\u{13CD}',
+    '\u{13CE}', '\u{13CF}', '\u{13D0}', '\u{13D1}', '\u{13D2}', '\u{13D3}', '\u{13D4}', '\u{13D5}',
+    '\u{13D6}', '\u{13D7}', '\u{13D8}', '\u{13D9}', '\u{13DA}', '\u{13DB}', '\u{13DC}', '\u{13DD}',
+    '\u{13DE}', '\u{13DF}', '\u{13E0}', '\u{13E1}', '\u{13E2}', '\u{13E3}', '\u{13E4}', '\u{13E5}',
+    '\u{13E6}', '\u{13E7}', '\u{13E8}', '\u{13E9}', '\u{13EA}', '\u{13EB}', '\u{13EC}', '\u{13ED}',
+    '\u{13EE}', '\u{13EF}', '\u{66}', '\u{66}', '\u{66}', '\u{69}', '\u{66}', '\u{6C}',
+    '\u{66}', '\u{66}', '\u{69}', '\u{66}', '\u{66}', '\u{6C}', '\u{73}', '\u{74}',
+    '\u{73}', '\u{74}', '\u{574}', '\u{576}', '\u{574}', '\u{565}', '\u{574}', '\u{56B}',
+    '\u{57E}', '\u{576}', '\u{574}', '\u{56D}', '\u{FF41}', '\u{FF42}', '\u{FF43}', '\u{FF44}',
+    '\u{FF45}', '\u{FF46}', '\u{FF47}', '\u{FF48}', '\u{FF49}', '\u{FF4A}', '\u{FF4B}', '\u{FF4C}',
+    '\u{FF4D}', '\u{FF4E}', '\u{FF4F}', '\u{FF50}', '\u{FF51}', '\u{FF52}', '\u{FF53}', '\u{FF54}',
+    '\u{FF55}', '\u{FF56}', '\u{FF57}', '\u{FF58}', '\u{FF59}', '\u{FF5A}', '\u{10428}', '\u{10429}',
+    '\u{1042A}', '\u{1042B}', '\u{1042C}', '\u{1042D}', '\u{1042E}', '\u{1042F}', '\u{10430}', '\u{10431}',
+    '\u{10432}', '\u{10433}', '\u{10434}', '\u{10435}', '\u{10436}', '\u{10437}', '\u{10438}', '\u{10439}',
+    '\u{1043A}', '\u{1043B}', '\u{1043C}', '\u{1043D}', '\u{1043E}', '\u{1043F}', '\u{10440}', '\u{10441}',
+    '\u{10442}', '\u{10443}', '\u{10444}', '\u{10445}', '\u{10446}', '\u{10447}', '\u{10448}', '\u{10449}',
+    '\u{1044A}', '\u{1044B}', '\u{1044C}', '\u{1044D}', '\u{1044E}', '\u{1044F}', '\u{104D8}', '\u{104D9}',
+    '\u{104DA}', '\u{104DB}', '\u{104DC}', '\u{104DD}', '\u{104DE}', '\u{104DF}', '\u{104E0}', '\u{104E1}',
+    '\u{104E2}', '\u{104E3}', '\u{104E4}', '\u{104E5}', '\u{104E6}', '\u{104E7}', '\u{104E8}', '\u{104E9}',
+    '\u{104EA}', '\u{104EB}', '\u{104EC}', '\u{104ED}', '\u{104EE}', '\u{104EF}', '\u{104F0}', '\u{104F1}',
+    '\u{104F2}', '\u{104F3}', '\u{104F4}', '\u{104F5}', '\u{104F6}', '\u{104F7}', '\u{104F8}', '\u{104F9}',
+    '\u{104FA}', '\u{104FB}', '\u{10597}', '\u{10598}', '\u{10599}', '\u{1059A}', '\u{1059B}', '\u{1059C}',
+    '\u{1059D}', '\u{1059E}', '\u{1059F}', '\u{105A0}', '\u{105A1}', '\u{105A3}', '\u{105A4}', '\u{105A5}',
+    '\u{105A6}', '\u{105A7}', '\u{105A8}', '\u{105A9}', '\u{105AA}', '\u{105AB}', '\u{105AC}', '\u{105AD}',
+    '\u{105AE}', '\u{105AF}', '\u{105B0}', '\u{105B1}', '\u{105B3}', '\u{105B4}', '\u{105B5}', '\u{105B6}',
+    '\u{105B7}', '\u{105B8}', '\u{105B9}', '\u{105BB}', '\u{105BC}', '\u{10CC0}', '\u{10CC1}', '\u{10CC2}',
+    '\u{10CC3}', '\u{10CC4}', '\u{10CC5}', '\u{10CC6}', '\u{10CC7}', '\u{10CC8}', '\u{10CC9}', '\u{10CCA}',
+    '\u{10CCB}', '\u{10CCC}', '\u{10CCD}', '\u{10CCE}', '\u{10CCF}', '\u{10CD0}', '\u{10CD1}', '\u{10CD2}',
+    '\u{10CD3}', '\u{10CD4}', '\u{10CD5}', '\u{10CD6}', '\u{10CD7}', '\u{10CD8}', '\u{10CD9}', '\u{10CDA}',
+    '\u{10CDB}', '\u{10CDC}', '\u{10CDD}', '\u{10CDE}', '\u{10CDF}', '\u{10CE0}', '\u{10CE1}', '\u{10CE2}',
+    '\u{10CE3}', '\u{10CE4}', '\u{10CE5}', '\u{10CE6}', '\u{10CE7}', '\u{10CE8}', '\u{10CE9}', '\u{10CEA}',
+    '\u{10CEB}', '\u{10CEC}', '\u{10CED}', '\u{10CEE}', '\u{10CEF}', '\u{10CF0}', '\u{10CF1}', '\u{10CF2}',
+    '\u{118C0}', '\u{118C1}', '\u{118C2}', '\u{118C3}', '\u{118C4}', '\u{118C5}', '\u{118C6}', '\u{118C7}',
+    '\u{118C8}', '\u{118C9}', '\u{118CA}', '\u{118CB}', '\u{118CC}', '\u{118CD}', '\u{118CE}', '\u{118CF}',
+    '\u{118D0}', '\u{118D1}', '\u{118D2}', '\u{118D3}', '\u{118D4}', '\u{118D5}', '\u{118D6}', '\u{118D7}',
+    '\u{118D8}', '\u{118D9}', '\u{118DA}', '\u{118DB}', '\u{118DC}', '\u{118DD}', '\u{118DE}', '\u{118DF}',
+    '\u{16E60}', '\u{16E61}', '\u{16E62}', '\u{16E63}', '\u{16E64}', '\u{16E65}', '\u{16E66}', '\u{16E67}',
+    '\u{16E68}', '\u{16E69}', '\u{16E6A}', '\u{16E6B}', '\u{16E6C}', '\u{16E6D}', '\u{16E6E}', '\u{16E6F}',
+    '\u{16E70}', '\u{16E71}', '\u{16E72}', '\u{16E73}', '\u{16E74}', '\u{16E75}', '\u{16E76}', '\u{16E77}',
+    '\u{16E78}', '\u{16E79}', '\u{16E7A}', '\u{16E7B}', '\u{16E7C}', '\u{16E7D}', '\u{16E7E}', '\u{16E7F}',
+    '\u{1E922}', '\u{1E923}', '\u{1E924}', '\u{1E925}', '\u{1E926}', '\u{1E927}', '\u{1E928}', '\u{1E929}',
+    '\u{1E92A}', '\u{1E92B}', '\u{1E92C}', '\u{1E92D}', '\u{1E92E}', '\u{1E92F}', '\u{1E930}', '\u{1E931}',
+    '\u{1E932}', '\u{1E933}', '\u{1E934}', '\u{1E935}', '\u{1E936}', '\u{1E937}', '\u{1E938}', '\u{1E939}',
+    '\u{1E93A}', '\u{1E93B}', '\u{1E93C}', '\u{1E93D}', '\u{1E93E}', '\u{1E93F}', '\u{1E940}', '\u{1E941}',
+    '\u{1E942}', '\u{1E943}',
+];
+
+
+/// Characters of the punctuation general categories.
+pub const PUNCTUATION: &[(u32, u32)] = &[
+    (0x0021, 0x0023), (0x0025, 0x002A), (0x002C, 0x002F), (0x003A, 0x003B),
+    (0x003F, 0x0040), (0x005B, 0x005D), (0x005F, 0x005F), (0x007B, 0x007B),
+    (0x007D, 0x007D), (0x00A1, 0x00A1), (0x00A7, 0x00A7), (0x00AB, 0x00AB),
+    (0x00B6, 0x00B7), (0x00BB, 0x00BB), (0x00BF, 0x00BF), (0x037E, 0x037E),
+    (0x0387, 0x0387), (0x055A, 0x055F), (0x0589, 0x058A), (0x05BE, 0x05BE),
+    (0x05C0, 0x05C0), (0x05C3, 0x05C3), (0x05C6, 0x05C6), (0x05F3, 0x05F4),
+    (0x0609, 0x060A), (0x060C, 0x060D), (0x061B, 0x061B), (0x061D, 0x061F),
+    (0x066A, 0x066D), (0x06D4, 0x06D4), (0x0700, 0x070D), (0x07F7, 0x07F9),
+    (0x0830, 0x083E), (0x085E, 0x085E), (0x0964, 0x0965), (0x0970, 0x0970),
+    (0x09FD, 0x09FD), (0x0A76, 0x0A76), (0x0AF0, 0x0AF0), (0x0C77, 0x0C77),
+    (0x0C84, 0x0C84), (0x0DF4, 0x0DF4), (0x0E4F, 0x0E4F), (0x0E5A, 0x0E5B),
+    (0x0F04, 0x0F12), (0x0F14, 0x0F14), (0x0F3A, 0x0F3D), (0x0F85, 0x0F85),
+    (0x0FD0, 0x0FD4), (0x0FD9, 0x0FDA), (0x104A, 0x104F), (0x10FB, 0x10FB),
+    (0x1360, 0x1368), (0x1400, 0x1400), (0x166E, 0x166E), (0x169B, 0x169C),
+    (0x16EB, 0x16ED), (0x1735, 0x1736), (0x17D4, 0x17D6), (0x17D8, 0x17DA),
+    (0x1800, 0x180A), (0x1944, 0x1945), (0x1A1E, 0x1A1F), (0x1AA0, 0x1AA6),
+    (0x1AA8, 0x1AAD), (0x1B5A, 0x1B60), (0x1B7D, 0x1B7E), (0x1BFC, 0x1BFF),
+    (0x1C3B, 0x1C3F), (0x1C7E, 0x1C7F), (0x1CC0, 0x1CC7), (0x1CD3, 0x1CD3),
+    (0x2010, 0x2027), (0x2030, 0x2043), (0x2045, 0x2051), (0x2053, 0x205E),
+    (0x207D, 0x207E), (0x208D, 0x208E), (0x2308, 0x230B), (0x2329, 0x232A),
+    (0x2768, 0x2775), (0x27C5, 0x27C6), (0x27E6, 0x27EF), (0x2983, 0x2998),
+    (0x29D8, 0x29DB), (0x29FC, 0x29FD), (0x2CF9, 0x2CFC), (0x2CFE, 0x2CFF),
+    (0x2D70, 0x2D70), (0x2E00, 0x2E2E), (0x2E30, 0x2E4F), (0x2E52, 0x2E5D),
+    (0x3001, 0x3003), (0x3008, 0x3011), (0x3014, 0x301F), (0x3030, 0x3030),
+    (0x303D, 0x303D), (0x30A0, 0x30A0), (0x30FB, 0x30FB), (0xA4FE, 0xA4FF),
+    (0xA60D, 0xA60F), (0xA673, 0xA673), (0xA67E, 0xA67E), (0xA6F2, 0xA6F7),
+    (0xA874, 0xA877), (0xA8CE, 0xA8CF), (0xA8F8, 0xA8FA), (0xA8FC, 0xA8FC),
+    (0xA92E, 0xA92F), (0xA95F, 0xA95F), (0xA9C1, 0xA9CD), (0xA9DE, 0xA9DF),
+    (0xAA5C, 0xAA5F), (0xAADE, 0xAADF), (0xAAF0, 0xAAF1), (0xABEB, 0xABEB),
+    (0xFD3E, 0xFD3F), (0xFE10, 0xFE19), (0xFE30, 0xFE52), (0xFE54, 0xFE61),
+    (0xFE63, 0xFE63), (0xFE68, 0xFE68), (0xFE6A, 0xFE6B), (0xFF01, 0xFF03),
+    (0xFF05, 0xFF0A), (0xFF0C, 0xFF0F), (0xFF1A, 0xFF1B), (0xFF1F, 0xFF20),
+    (0xFF3B, 0xFF3D), (0xFF3F, 0xFF3F), (0xFF5B, 0xFF5B), (0xFF5D, 0xFF5D),
+    (0xFF5F, 0xFF65), (0x10100, 0x10102), (0x1039F, 0x1039F), (0x103D0, 0x103D0),
+    (0x1056F, 0x1056F), (0x10857, 0x10857), (0x1091F, 0x1091F), (0x1093F, 0x1093F),
+    (0x10A50, 0x10A58), (0x10A7F, 0x10A7F), (0x10AF0, 0x10AF6), (0x10B39, 0x10B3F),
+    (0x10B99, 0x10B9C), (0x10EAD, 0x10EAD), (0x10F55, 0x10F59), (0x10F86, 0x10F89),
+    (0x11047, 0x1104D), (0x110BB, 0x110BC), (0x110BE, 0x110C1), (0x11140, 0x11143),
+    (0x11174, 0x11175), (0x111C5, 0x111C8), (0x111CD, 0x111CD), (0x111DB, 0x111DB),
+    (0x111DD, 0x111DF), (0x11238, 0x1123D), (0x112A9, 0x112A9), (0x1144B, 0x1144F),
+    (0x1145A, 0x1145B), (0x1145D, 0x1145D), (0x114C6, 0x114C6), (0x115C1, 0x115D7),
+    (0x11641, 0x11643), (0x11660, 0x1166C), (0x116B9, 0x116B9), (0x1173C, 0x1173E),
+    (0x1183B, 0x1183B), (0x11944, 0x11946), (0x119E2, 0x119E2), (0x11A3F, 0x11A46),
+    (0x11A9A, 0x11A9C), (0x11A9E, 0x11AA2), (0x11C41, 0x11C45), (0x11C70, 0x11C71),
+    (0x11EF7, 0x11EF8), (0x11FFF, 0x11FFF), (0x12470, 0x12474), (0x12FF1, 0x12FF2),
+    (0x16A6E, 0x16A6F), (0x16AF5, 0x16AF5), (0x16B37, 0x16B3B), (0x16B44, 0x16B44),
+    (0x16E97, 0x16E9A), (0x16FE2, 0x16FE2), (0x1BC9F, 0x1BC9F), (0x1DA87, 0x1DA8B),
+    (0x1E95E, 0x1E95F),
+];
+
+
+/// Characters with the White_Space property.
+pub const WHITE_SPACE: &[(u32, u32)] = &[
+    (0x0009, 0x000D), (0x0020, 0x0020), (0x0085, 0x0085), (0x00A0, 0x00A0),
+    (0x1680, 0x1680), (0x2000, 0x200A), (0x2028, 0x2029), (0x202F, 0x202F),
+    (0x205F, 0x205F), (0x3000, 0x3000),
+];
+
+
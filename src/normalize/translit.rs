@@ -0,0 +1,257 @@
+//! Rule-based transliteration of Cyrillic and Greek into Latin.
+
+use alloc::vec::Vec;
+use super::lookup;
+use super::tables::{CANONICAL, DECOMPOSED};
+
+
+/// Transliteration scheme for Cyrillic and Greek letters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transliteration {
+    /// Keeps all scripts as they are.
+    Off,
+    /// [ISO 9][1] for Cyrillic and [ISO 843][2] for Greek:
+    /// a single Latin letter per letter, using diacritics, e.g. "Щербаков" becomes "Ŝerbakov".
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/ISO_9
+    /// [2]: https://en.wikipedia.org/wiki/ISO_843
+    Iso,
+    /// [BGN/PCGN][1] for Cyrillic, with the Russian rules for letters shared by several languages,
+    /// and for Greek (ELOT 743): mostly plain letters and digraphs, e.g. "Щербаков" becomes "Shcherbakov".
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/BGN/PCGN_romanization
+    BgnPcgn,
+}
+
+
+/// Latin spellings of Cyrillic letters from U+0430 to U+045F, as (ISO 9, BGN/PCGN).
+const CYRILLIC: [(&str, &str); 48] = [
+    // U+0430
+    ("a", "a"), ("b", "b"), ("v", "v"), ("g", "g"), ("d", "d"), ("e", "e"), ("ž", "zh"), ("z", "z"),
+    ("i", "i"), ("j", "y"), ("k", "k"), ("l", "l"), ("m", "m"), ("n", "n"), ("o", "o"), ("p", "p"),
+    // U+0440
+    ("r", "r"), ("s", "s"), ("t", "t"), ("u", "u"), ("f", "f"), ("h", "kh"), ("c", "ts"), ("č", "ch"),
+    ("š", "sh"), ("ŝ", "shch"), ("ʺ", "”"), ("y", "y"), ("ʹ", "’"), ("è", "e"), ("û", "yu"), ("â", "ya"),
+    // U+0450
+    ("è", "è"), ("ë", "ë"), ("đ", "đ"), ("ǵ", "gj"), ("ê", "ye"), ("ẑ", "dz"), ("ì", "i"), ("ï", "yi"),
+    ("ǰ", "j"), ("l\u{302}", "lj"), ("n\u{302}", "nj"), ("ć", "ć"), ("ḱ", "kj"), ("ì", "ì"), ("ŭ", "w"), ("d\u{302}", "dž"),
+];
+
+
+/// Latin spellings of Greek letters from U+03B1 to U+03C9, as (ISO 843, BGN/PCGN).
+const GREEK: [(&str, &str); 25] = [
+    ("a", "a"), ("v", "v"), ("g", "g"), ("d", "d"), ("e", "e"), ("z", "z"), ("ī", "i"), ("th", "th"),
+    ("i", "i"), ("k", "k"), ("l", "l"), ("m", "m"), ("n", "n"), ("x", "x"), ("o", "o"), ("p", "p"),
+    ("r", "r"), ("s", "s"), ("s", "s"), ("t", "t"), ("y", "y"), ("f", "f"), ("ch", "ch"), ("ps", "ps"),
+    ("ō", "o"),
+];
+
+
+/// Cyrillic letters after which BGN/PCGN spells "е" and "ё" as "ye" and "yë".
+const CYRILLIC_Y_AFTER: &str = "аеёиоуыэюяйъьєії";
+
+
+/// Greek consonants before which "αυ", "ευ" and "ηυ" end with "f" rather than "v".
+const GREEK_VOICELESS: &str = "θκξπσςτφχψ";
+
+
+/// Letter case of a transliterated letter.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Case {
+    Lower,
+    Title,
+    Upper,
+}
+
+
+/// Transliterates the composed characters into `out`, keeping the other scripts as they are.
+pub(super) fn transliterate(scheme: Transliteration, chars: &[char], out: &mut Vec<char>) {
+    out.clear();
+    let bgn = scheme == Transliteration::BgnPcgn;
+    let mut i = 0;
+    while i < chars.len() {
+        i += match chars[i] as u32 {
+            0x0400 ..= 0x045F | 0x0490 | 0x0491 => cyrillic(bgn, chars, i, out),
+            0x0386 ..= 0x03CE | 0x1F00 ..= 0x1FFC => greek(bgn, chars, i, out),
+            _ => { out.push(chars[i]); 1 }
+        };
+    }
+}
+
+
+fn cyrillic(bgn: bool, chars: &[char], i: usize, out: &mut Vec<char>) -> usize {
+    let lower = lowercase(chars[i]);
+    let latin = match lower as u32 {
+        code @ 0x0430 ..= 0x045F => {
+            let (iso, bgn_pcgn) = CYRILLIC[(code - 0x0430) as usize];
+            if bgn { bgn_pcgn } else { iso }
+        }
+        0x0491 => if bgn { "g" } else { "g\u{300}" },
+        _ => { out.push(chars[i]); return 1; }
+    };
+    let prefix = match (i.checked_sub(1).map(|j| chars[j]), lower) {
+        (prev, 'е') | (prev, 'ё') if bgn => match prev {
+            Some(prev) if prev.is_alphabetic() => {
+                if CYRILLIC_Y_AFTER.contains(lowercase(prev)) { "y" } else { "" }
+            }
+            _ => "y",
+        },
+        _ => "",
+    };
+    emit(out, prefix.chars().chain(latin.chars()), case(chars, i));
+    1
+}
+
+
+fn greek(bgn: bool, chars: &[char], i: usize, out: &mut Vec<char>) -> usize {
+    let (base, marks) = split(chars[i]);
+    let latin = match base as u32 {
+        code @ 0x03B1 ..= 0x03C9 => {
+            let (iso, bgn_pcgn) = GREEK[(code - 0x03B1) as usize];
+            if bgn { bgn_pcgn } else { iso }
+        }
+        _ => { out.push(chars[i]); return 1; }
+    };
+    let next = chars.get(i + 1).map(|&ch| split(ch));
+
+    // Diphthongs with "υ", unless it has a diaeresis.
+    if let Some(('υ', marks2)) = next {
+        if "αεηο".contains(base) && !marks2.contains(&'\u{308}') {
+            let ending = match base {
+                'ο' => "u",
+                _ if !bgn => "u",
+                _ if voiceless(chars, i + 2) => "f",
+                _ => "v",
+            };
+            let vowel = latin.chars().chain(marks.iter().copied());
+            match ending {
+                "u" => emit(out, vowel.chain(ending.chars()).chain(marks2.iter().copied()), case(chars, i)),
+                _ => emit(out, vowel.chain(marks2.iter().copied()).chain(ending.chars()), case(chars, i)),
+            }
+            return 2;
+        }
+    }
+
+    // Nasal "γ" before velars.
+    let latin = match next {
+        Some(('γ', _)) | Some(('ξ', _)) | Some(('χ', _)) if base == 'γ' => "n",
+        Some(('κ', _)) if base == 'γ' && !bgn => "n",
+        _ => latin,
+    };
+    emit(out, latin.chars().chain(marks.iter().copied()), case(chars, i));
+    1
+}
+
+
+/// Pushes the Latin characters in the given case.
+fn emit(out: &mut Vec<char>, latin: impl Iterator<Item=char>, case: Case) {
+    for (j, ch) in latin.enumerate() {
+        match case {
+            Case::Upper => out.extend(ch.to_uppercase()),
+            Case::Title if j == 0 => out.extend(ch.to_uppercase()),
+            _ => out.push(ch),
+        }
+    }
+}
+
+
+/// Returns the case of the letter, which is upper if the neighbouring letters are uppercase too,
+/// e.g. "Щ" becomes "SHCH" in "ЩИ", but "Shch" in "Щи" and "Щ".
+fn case(chars: &[char], i: usize) -> Case {
+    if !chars[i].is_uppercase() { return Case::Lower; }
+    match chars.get(i + 1) {
+        Some(next) if next.is_alphabetic() => {
+            if next.is_uppercase() { Case::Upper } else { Case::Title }
+        }
+        _ if i > 0 && chars[i - 1].is_uppercase() => Case::Upper,
+        _ => Case::Title,
+    }
+}
+
+
+/// Whether the word ends at the position or continues with a voiceless Greek consonant.
+fn voiceless(chars: &[char], i: usize) -> bool {
+    match chars.get(i) {
+        Some(&ch) if ch.is_alphabetic() => GREEK_VOICELESS.contains(split(ch).0),
+        _ => true,
+    }
+}
+
+
+/// Splits a Greek letter into its lowercase base and the marks of its canonical decomposition.
+fn split(ch: char) -> (char, &'static [char]) {
+    match lookup(CANONICAL, DECOMPOSED, ch as u32) {
+        Some(decomposed) => (lowercase(decomposed[0]), &decomposed[1 ..]),
+        None => (lowercase(ch), &[]),
+    }
+}
+
+
+fn lowercase(ch: char) -> char {
+    ch.to_lowercase().next().unwrap_or(ch)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::Transliteration;
+    use crate::normalize::Normalizer;
+
+    fn translit(scheme: Transliteration, s: &str) -> String {
+        let mut normalizer = Normalizer::new();
+        normalizer.set_transliteration(scheme);
+        normalizer.normalize(s)
+    }
+
+    #[test]
+    fn cyrillic() {
+        let sample = [
+            // source, ISO 9, BGN/PCGN
+            ["Щербаков", "Ŝerbakov", "Shcherbakov"],
+            ["ЩЕРБАКОВ", "ŜERBAKOV", "SHCHERBAKOV"],
+            ["Достоевский", "Dostoevskij", "Dostoyevskiy"],
+            ["Ельцин", "Elʹcin", "Yel’tsin"],
+            ["Юлия Чайковская", "Ûliâ Čajkovskaâ", "Yuliya Chaykovskaya"],
+            ["ЮЛИЯ", "ÛLIÂ", "YULIYA"],
+            ["объём", "obʺëm", "ob”yëm"],
+            ["Київ", "Kiïv", "Kiyiv"],
+            ["Ђорђе Џаја", "Đorđe D\u{302}aǰa", "Đorđe Džaja"],
+            ["Ґ", "G\u{300}", "G"],
+            ["и\u{306}од", "jod", "yod"],
+        ];
+        for row in &sample {
+            assert_eq!(translit(Transliteration::Iso, row[0]), row[1]);
+            assert_eq!(translit(Transliteration::BgnPcgn, row[0]), row[2]);
+        }
+    }
+
+    #[test]
+    fn greek() {
+        let sample = [
+            // source, ISO 843, BGN/PCGN
+            ["Αθήνα", "Athī\u{301}na", "Athína"],
+            ["Θεσσαλονίκη", "Thessaloníkī", "Thessaloníki"],
+            ["Παύλος", "Paúlos", "Pávlos"],
+            ["Ευαγγέλιο", "Euangélio", "Evangélio"],
+            ["αυτός", "autós", "aftós"],
+            ["ευ", "eu", "ef"],
+            ["Άγκυρα", "Ánkyra", "Ágkyra"],
+            ["Ψυχή", "Psychī\u{301}", "Psychí"],
+            ["ΟΥΡΑΝΟΣ", "OURANOS", "OURANOS"],
+            ["Ωρωπός", "Ōrōpós", "Oropós"],
+            ["προϋπόθεση", "proÿpóthesī", "proÿpóthesi"],
+        ];
+        for row in &sample {
+            assert_eq!(translit(Transliteration::Iso, row[0]), row[1]);
+            assert_eq!(translit(Transliteration::BgnPcgn, row[0]), row[2]);
+        }
+    }
+
+    #[test]
+    fn other_scripts() {
+        for s in &["", "mailbox", "Ærøskøbing", "もしもし", "123 - !"] {
+            assert_eq!(translit(Transliteration::Iso, s), *s);
+            assert_eq!(translit(Transliteration::BgnPcgn, s), *s);
+        }
+    }
+}
@@ -0,0 +1,628 @@
+/// Number of bytes in a hash.
+const SLOTS: usize = 8;
+
+/// Weights of the differing bits in each byte of a hash, starting from the least significant one.
+const WEIGHTS: [usize; SLOTS] = [1, 2, 3, 5, 8, 13, 21, 34];
+
+/// Maximum weighted distance between two hashes.
+const MAX_DISTANCE: usize = 8 * (1 + 2 + 3 + 5 + 8 + 13 + 21 + 34);
+
+
+/// Phones of the letters after the first one, one bit per property:
+///
+/// | Bit | Property     | Phones                   |
+/// |-----|--------------|:------------------------:|
+/// | 1   | Discriminant | (for tagging duplicates) |
+/// | 2   | Nasal        | mn                       |
+/// | 3   | Fricative    | fvsjxzhct                |
+/// | 4   | Plosive      | pbtdcgqk                 |
+/// | 5   | Dental       | tdnzs                    |
+/// | 6   | Liquid       | lr                       |
+/// | 7   | Labial       | bfpv                     |
+/// | 8   | Confident    | lrxzq                    |
+///
+/// Vowels are represented by 0 and 1 (open and close respectively).
+const PHONES: [u8; 26] = [
+    0, // a
+//    +--------- Confident
+//    |+-------- Labial
+//    ||+------- Liquid
+//    |||+------ Dental
+//    ||||+----- Plosive
+//    |||||+---- Fricative
+//    ||||||+--- Nasal
+//    |||||||+-- Discriminant
+//    ||||||||
+    0b01001000, // b
+    0b00001100, // c
+    0b00011000, // d
+    0,          // e
+    0b01000100, // f
+    0b00001000, // g
+    0b00000100, // h
+    1,          // i
+    0b00000101, // j
+    0b00001001, // k
+    0b10100000, // l
+    0b00000010, // m
+    0b00010010, // n
+    0,          // o
+    0b01001001, // p
+    0b10101000, // q
+    0b10100001, // r
+    0b00010100, // s
+    0b00011101, // t
+    1,          // u
+    0b01000101, // v
+    0b00000000, // w
+    0b10000100, // x
+    1,          // y
+    0b10010100, // z
+];
+
+/// Phones of the Latin-1 letters from U+00DF (ß) to U+00FF (ÿ), approximated.
+const PHONES_LATIN1: [u8; 33] = [
+    PHONES[18] ^ 1, // ß
+    0,              // à
+    0,              // á
+    0,              // â
+    0,              // ã
+    0,              // ä [æ]
+    1,              // å [oː]
+    0,              // æ [æ]
+    PHONES[25] ^ 1, // ç [t͡ʃ]
+    1,              // è
+    1,              // é
+    1,              // ê
+    1,              // ë
+    1,              // ì
+    1,              // í
+    1,              // î
+    1,              // ï
+    0b00010101,     // ð [ð̠] (a non-plosive t)
+    0b00010111,     // ñ [nj] (a combination of n and j)
+    0,              // ò
+    0,              // ó
+    0,              // ô
+    0,              // õ
+    1,              // ö [ø]
+    0,              // ÷ (not a letter, never looked up)
+    1,              // ø [ø]
+    1,              // ù
+    1,              // ú
+    1,              // û
+    1,              // ü
+    1,              // ý
+    0b00010101,     // þ [ð̠] (a non-plosive t)
+    1,              // ÿ
+];
+
+/// Injective phones of the first letter, one bit per property:
+///
+/// | Bit | Vowel               | Consonant                                  |
+/// |-----|---------------------|--------------------------------------------|
+/// | 1   | Discriminant        | Bit 2 of the phones table or discriminant  |
+/// | 2   | Is it open-mid?     | Bit 3 of the phones table                  |
+/// | 3   | Is it central?      | Bit 4 of the phones table                  |
+/// | 4   | Is it close-mid?    | Bit 5 of the phones table                  |
+/// | 5   | Is it front?        | Bit 6 of the phones table                  |
+/// | 6   | Is it close?        | Bit 7 of the phones table                  |
+/// | 7   | More close than [ɜ] | Bit 8 of the phones table                  |
+/// | 8   | Vowel               | Vowel                                      |
+const INJECTIVE_PHONES: [u8; 26] = [
+//    +--------- Vowel
+//    |+-------- Closer than ɜ
+//    ||+------- Close
+//    |||+------ Front
+//    ||||+----- Close-mid
+//    |||||+---- Central
+//    ||||||+--- Open-mid
+//    |||||||+-- Discriminant
+//    ||||||||
+    0b10000100, // a
+    0b00100100, // b
+    0b00000110, // c
+    0b00001100, // d
+    0b11011000, // e
+    0b00100010, // f
+    0b00000100, // g
+    0b00000010, // h
+    0b11111000, // i
+    0b00000011, // j
+    0b00000101, // k
+    0b01010000, // l
+    0b00000001, // m
+    0b00001001, // n
+    0b10010100, // o
+    0b00100101, // p
+    0b01010100, // q
+    0b01010001, // r
+    0b00001010, // s
+    0b00001110, // t
+    0b11100000, // u
+    0b00100011, // v
+    0b00000000, // w
+    0b01000010, // x
+    0b11100100, // y
+    0b01001010, // z
+];
+
+/// Injective phones of the Latin-1 letters from U+00DF (ß) to U+00FF (ÿ).
+const INJECTIVE_PHONES_LATIN1: [u8; 33] = [
+    INJECTIVE_PHONES[18] ^ 1, // ß
+    INJECTIVE_PHONES[0] ^ 1,  // à
+    INJECTIVE_PHONES[0] ^ 1,  // á
+    0b10000000,               // â
+    0b10000110,               // ã
+    0b10100110,               // ä [æ]
+    0b11000010,               // å [oː]
+    0b10100111,               // æ [æ]
+    0b01010100,               // ç [t͡ʃ]
+    INJECTIVE_PHONES[4] ^ 1,  // è
+    INJECTIVE_PHONES[4] ^ 1,  // é
+    INJECTIVE_PHONES[4] ^ 1,  // ê
+    0b11000110,               // ë [ə] or [œ]
+    INJECTIVE_PHONES[8] ^ 1,  // ì
+    INJECTIVE_PHONES[8] ^ 1,  // í
+    INJECTIVE_PHONES[8] ^ 1,  // î
+    INJECTIVE_PHONES[8] ^ 1,  // ï
+    0b00001011,               // ð [ð̠] (a non-plosive t)
+    0b00001011,               // ñ [nj] (a combination of n and j)
+    INJECTIVE_PHONES[14] ^ 1, // ò
+    INJECTIVE_PHONES[14] ^ 1, // ó
+    INJECTIVE_PHONES[14] ^ 1, // ô
+    INJECTIVE_PHONES[14] ^ 1, // õ
+    0b11011100,               // ö [œ] or [ø]
+    0,                        // ÷ (not a letter, never looked up)
+    0b11011101,               // ø [œ] or [ø]
+    INJECTIVE_PHONES[20] ^ 1, // ù
+    INJECTIVE_PHONES[20] ^ 1, // ú
+    INJECTIVE_PHONES[20] ^ 1, // û
+    INJECTIVE_PHONES[24] ^ 1, // ü
+    INJECTIVE_PHONES[24] ^ 1, // ý
+    0b00001011,               // þ [ð̠] (a non-plosive t)
+    INJECTIVE_PHONES[24] ^ 1, // ÿ
+];
+
+
+/// # Eudex phonetic hash.
+///
+/// Encodes a word into a 64-bit hash in such a way that the bitwise
+/// Hamming distance between two hashes reflects how differently the words sound.
+///
+/// The most significant byte encodes the first letter of the word
+/// with a table of phonetic properties that keeps vowels apart.
+/// The following letters are encoded with a coarser table and shifted into the hash
+/// from the least significant byte, skipping a letter whose discriminant bit
+/// is the same as in the previous one, which collapses repeated and similar sounds.
+/// Differing bits are weighted with the Fibonacci numbers from 34 for the most significant byte
+/// down to 1 for the least significant one.
+///
+/// Letters are compared case-insensitively, and all the other characters are skipped.
+/// Hashes of ASCII words are equal to the ones of [the original implementation][1].
+/// Latin-1 letters are encoded with its tables too, where the original skips their UTF-8 bytes.
+///
+/// [1]: https://docs.rs/eudex/
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::phonetic::Eudex;
+///
+/// let eudex = Eudex::new();
+/// let dist = eudex.distance("Maier", "Meyer");
+/// assert_eq!(dist, 0);
+/// ```
+///
+/// Hashes can be precomputed and compared in constant time:
+/// ```rust
+/// # let eudex = eddie::phonetic::Eudex::new();
+/// let hash1 = eudex.hash("Smith");
+/// let hash2 = eudex.hash("Smythe");
+/// assert_eq!(eudex.hash_distance(hash1, hash2), 0);
+/// ```
+///
+/// # Complementary metrics
+///
+/// Relative distance:
+/// ```rust
+/// # let eudex = eddie::phonetic::Eudex::new();
+/// # let s1 = "smith";
+/// # let s2 = "mailbox";
+/// let sim = eudex.similarity(s1, s2);
+/// let dist = eudex.rel_dist(s1, s2);
+/// assert_eq!(dist, 1.0 - sim);
+/// ```
+pub struct Eudex { }
+
+
+impl Eudex {
+    /// Creates a new instance of Eudex struct.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::phonetic::Eudex;
+    ///
+    /// let eudex = Eudex::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { }
+    }
+
+    /// Computes a phonetic hash of the string.
+    /// The empty string is hashed to zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # let eudex = eddie::phonetic::Eudex::new();
+    /// assert_eq!(eudex.hash("Jon"), eudex.hash("John"));
+    /// assert_eq!(eudex.hash("Smith"), 0x0a00_0000_0000_0104);
+    /// ```
+    pub fn hash(&self, s: &str) -> u64 {
+        let mut chars = s.chars();
+        let first = match chars.next() {
+            Some(ch) => phone(ch, &INJECTIVE_PHONES, &INJECTIVE_PHONES_LATIN1).unwrap_or(0),
+            None     => return 0,
+        };
+
+        let mut hash = 0;
+        let mut slots = 0;
+        for ch in chars {
+            if slots == SLOTS { break; }
+            let code = match phone(ch, &PHONES, &PHONES_LATIN1) {
+                Some(code) => code,
+                None       => continue,
+            };
+            if code & 1 != hash as u8 & 1 {
+                hash = hash << 8 | code as u64;
+                slots += 1;
+            }
+        }
+
+        hash | (first as u64) << (8 * (SLOTS - 1))
+    }
+
+    /// Distance between two precomputed hashes.
+    /// Returns the number of differing bits weighted by byte,
+    /// from 34 for the most significant byte down to 1 for the least significant one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # let eudex = eddie::phonetic::Eudex::new();
+    /// let hash1 = eudex.hash("Jon");
+    /// let hash2 = eudex.hash("Tom");
+    /// assert_eq!(eudex.hash_distance(hash1, hash2), 34 * 3);
+    /// ```
+    pub fn hash_distance(&self, hash1: u64, hash2: u64) -> usize {
+        let xor = hash1 ^ hash2;
+        WEIGHTS.iter()
+            .enumerate()
+            .map(|(slot, weight)| {
+                let byte = (xor >> (8 * slot)) as u8;
+                byte.count_ones() as usize * weight
+            })
+            .sum()
+    }
+
+    /// Distance metric. Returns the weighted number
+    /// of differing bits between the phonetic hashes of two strings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # let eudex = eddie::phonetic::Eudex::new();
+    /// assert_eq!(eudex.distance("Jon", "John"), 0);
+    /// assert_eq!(eudex.distance("Jon", "Tom"), 102);
+    /// ```
+    pub fn distance(&self, str1: &str, str2: &str) -> usize {
+        self.hash_distance(self.hash(str1), self.hash(str2))
+    }
+
+    /// Relative distance metric. Returns the distance relative to
+    /// the maximum possible distance between two hashes,
+    /// ranging from 0.0 (same sound) to 1.0 (nothing in common).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # let eudex = eddie::phonetic::Eudex::new();
+    /// let dist = eudex.rel_dist("Jon", "Tom");
+    /// assert!((dist - 0.146).abs() < 0.001);
+    /// ```
+    pub fn rel_dist(&self, str1: &str, str2: &str) -> f64 {
+        self.distance(str1, str2) as f64 / MAX_DISTANCE as f64
+    }
+
+    /// Similarity metric. Inversion of relative distance,
+    /// ranging from 1.0 (same sound) to 0.0 (nothing in common).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # let eudex = eddie::phonetic::Eudex::new();
+    /// let sim = eudex.similarity("Jon", "Tom");
+    /// assert!((sim - 0.853).abs() < 0.001);
+    /// ```
+    pub fn similarity(&self, str1: &str, str2: &str) -> f64 {
+        1.0 - self.rel_dist(str1, str2)
+    }
+}
+
+
+impl Default for Eudex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+/// Looks up a letter in the tables of ASCII and Latin-1 letters, folding case.
+fn phone(ch: char, ascii: &[u8; 26], latin1: &[u8; 33]) -> Option<u8> {
+    let code = match ch {
+        // Folding '×' would turn it into '÷', and neither of them is a letter.
+        '×' | '÷' => return None,
+        'A' ..= 'Z' | 'À' ..= 'Þ' => ch as usize | 32,
+        _ => ch as usize,
+    };
+    match code {
+        0x61 ..= 0x7A => Some(ascii[code - 0x61]),
+        0xDF ..= 0xFF => Some(latin1[code - 0xDF]),
+        _ => None,
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{Eudex, MAX_DISTANCE};
+
+    fn floor3(num: f64) -> f64 {
+        let p = 10usize.pow(3) as f64;
+        (num * p).floor() / p
+    }
+
+    #[test]
+    fn empty() {
+        let eudex = Eudex::new();
+        let sample = ["", " ", "123", "-", "もしもし"];
+        for s in &sample {
+            assert_eq!(eudex.hash(s), 0);
+        }
+    }
+
+    #[test]
+    fn equality() {
+        let eudex = Eudex::new();
+        let sample = [
+            "",
+            "m",
+            "ma",
+            "mai",
+            "mail",
+            "mailb",
+            "mailbo",
+            "mailbox",
+        ];
+        for s in &sample {
+            assert_eq!(eudex.distance(s, s), 0);
+        }
+    }
+
+    #[test]
+    fn reference_hashes() {
+        let eudex = Eudex::new();
+        let sample = [
+            ("a",                 0x8400_0000_0000_0000),
+            ("jon",               0x0300_0000_0000_0000),
+            ("tom",               0x0e00_0000_0000_0000),
+            ("smith",             0x0a00_0000_0000_0104),
+            ("catherine",         0x0600_0000_1d04_a112),
+            ("kathryn",           0x0500_0000_1d04_a112),
+            ("mailbox",           0x0100_0000_0000_01a0),
+            ("Rupert",            0x5100_0000_0001_00a1),
+            ("hellou",            0x0200_0000_0000_0001),
+            ("computer",          0x0600_0000_0049_00a1),
+            ("lizzard",           0x5000_0000_0194_a118),
+            ("schmidt",           0x0a00_0000_0001_181d),
+            ("martha",            0x0100_0000_0000_a104),
+            ("gray",              0x0400_0000_00a1_0001),
+            ("olga",              0x9400_0000_0000_0000),
+            ("bcdfgjklmnpqrstvz", 0x25a0_49a8_a114_1d94),
+        ];
+        for &(s, hash) in &sample {
+            assert_eq!(eudex.hash(s), hash);
+        }
+    }
+
+    #[test]
+    fn reference_distances() {
+        let eudex = Eudex::new();
+        let sample = [
+            (0,   "jon",       "john"),
+            (102, "jon",       "tom"),
+            (0,   "smith",     "smythe"),
+            (68,  "catherine", "kathryn"),
+            (4,   "lulz",      "lol"),
+            (1,   "hello",     "hellou"),
+            (68,  "lizzard",   "wizzard"),
+            (3,   "Rupert",    "Robert"),
+            (0,   "maier",     "meyer"),
+            (1,   "martha",    "marta"),
+            (140, "mailbox",   "olga"),
+            (34,  "tom",       "dom"),
+            (170, "peter",     "lester"),
+            (11,  "schmid",    "schmidt"),
+            (72,  "trump",     "drumpf"),
+        ];
+        for &(d, s1, s2) in &sample {
+            assert_eq!(eudex.distance(s1, s2), d);
+            assert_eq!(eudex.distance(s2, s1), d);
+        }
+    }
+
+    #[test]
+    fn exact() {
+        let eudex = Eudex::new();
+        let sample = [
+            ("JAva",         "jAva"),
+            ("co!mputer",    "computer"),
+            ("comp-uter",    "computer"),
+            ("comp@u#te?r",  "computer"),
+            ("lal",          "lel"),
+            ("rindom",       "ryndom"),
+            ("riiiindom",    "ryyyyyndom"),
+            ("riyiyiiindom", "ryyyyyndom"),
+            ("triggered",    "TRIGGERED"),
+            ("repert",       "ropert"),
+        ];
+        for (s1, s2) in &sample {
+            assert_eq!(eudex.hash(s1), eudex.hash(s2));
+        }
+    }
+
+    #[test]
+    fn mismatch() {
+        let eudex = Eudex::new();
+        let sample = [
+            ("reddit",   "eddit"),
+            ("lol",      "lulz"),
+            ("ijava",    "java"),
+            ("jiva",     "java"),
+            ("jesus",    "iesus"),
+            ("aesus",    "iesus"),
+            ("iesus",    "yesus"),
+            ("rupirt",   "ropert"),
+            ("ripert",   "ropyrt"),
+            ("rrr",      "rraaaa"),
+            ("randomal", "randomai"),
+        ];
+        for (s1, s2) in &sample {
+            assert_ne!(eudex.hash(s1), eudex.hash(s2));
+        }
+    }
+
+    #[test]
+    fn similar() {
+        let eudex = Eudex::new();
+        let similar = [
+            ("yay",   "yuy"),
+            ("what",  "wat"),
+            ("jesus", "jeuses"),
+            ("",      ""),
+            ("jumpo", "jumbo"),
+            ("lol",   "lulz"),
+            ("goth",  "god"),
+            ("maier", "meyer"),
+            ("java",  "jiva"),
+        ];
+        for (s1, s2) in &similar {
+            assert!(eudex.distance(s1, s2) < 15);
+        }
+        let different = [
+            ("youtube", "reddit"),
+            ("yet",     "vet"),
+            ("hacker",  "4chan"),
+            ("awesome", "me"),
+            ("prisco",  "vkisco"),
+            ("no",      "go"),
+            ("horse",   "norse"),
+            ("nice",    "mice"),
+        ];
+        for (s1, s2) in &different {
+            assert!(eudex.distance(s1, s2) >= 15);
+        }
+    }
+
+    #[test]
+    fn latin1() {
+        let eudex = Eudex::new();
+        let sample = [
+            ("müller",   "MÜLLER"),
+            ("françois", "FRANÇOIS"),
+            ("ærø",      "ÆRØ"),
+        ];
+        for (s1, s2) in &sample {
+            assert_eq!(eudex.hash(s1), eudex.hash(s2));
+        }
+        assert_ne!(eudex.hash("strasse"), eudex.hash("straße"));
+        assert_ne!(eudex.hash("Éva"), eudex.hash("Eva"));
+        assert!(eudex.distance("möier", "meyer") < 15);
+        assert!(eudex.distance("fümlaut", "fymlaut") < 15);
+        assert_eq!(eudex.hash("a÷b"), eudex.hash("ab"));
+        assert_eq!(eudex.hash("A×B"), eudex.hash("ab"));
+        assert_eq!(eudex.hash("÷×"), 0);
+    }
+
+    #[test]
+    fn truncation() {
+        let eudex = Eudex::new();
+        assert_eq!(eudex.hash("bcdfgjklmnpqrstvz"), eudex.hash("bcdfgjklmnpqrstvzbcdfg"));
+        assert_ne!(eudex.hash("bcdfgjklmnpqrs"), eudex.hash("bcdfgjklmnpqrst"));
+    }
+
+    #[test]
+    fn hash_distance() {
+        let eudex = Eudex::new();
+        let sample = [
+            (0,            0x0000_0000_0000_0000, 0x0000_0000_0000_0000),
+            (1,            0x0000_0000_0000_0001, 0x0000_0000_0000_0000),
+            (8,            0x0000_0000_0000_00ff, 0x0000_0000_0000_0000),
+            (2,            0x0000_0000_0000_0100, 0x0000_0000_0000_0000),
+            (34,           0x0100_0000_0000_0000, 0x0000_0000_0000_0000),
+            (8 * 34,       0xff00_0000_0000_0000, 0x0000_0000_0000_0000),
+            (MAX_DISTANCE, 0xffff_ffff_ffff_ffff, 0x0000_0000_0000_0000),
+            (0,            0xffff_ffff_ffff_ffff, 0xffff_ffff_ffff_ffff),
+        ];
+        for &(d, h1, h2) in &sample {
+            assert_eq!(eudex.hash_distance(h1, h2), d);
+            assert_eq!(eudex.hash_distance(h2, h1), d);
+        }
+    }
+
+    #[test]
+    fn triangle_inequality() {
+        let eudex = Eudex::new();
+        let words = ["jon", "tom", "smith", "smart", "anne", "olga", "martha", "mailbox", ""];
+        for w1 in &words {
+            for w2 in &words {
+                for w3 in &words {
+                    let d12 = eudex.distance(w1, w2);
+                    let d23 = eudex.distance(w2, w3);
+                    let d13 = eudex.distance(w1, w3);
+                    assert!(d13 <= d12 + d23);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rel_dist() {
+        let eudex = Eudex::new();
+        let sample = [
+            (0.000, "",      ""),
+            (0.000, "jon",   "john"),
+            (0.146, "jon",   "tom"),
+        ];
+        for (d, s1, s2) in &sample {
+            assert_eq!(floor3(eudex.rel_dist(s1, s2)), *d);
+            assert_eq!(floor3(eudex.rel_dist(s2, s1)), *d);
+        }
+    }
+
+    #[test]
+    fn similarity() {
+        let eudex = Eudex::new();
+        let sample = [
+            (1.000, "",      ""),
+            (1.000, "jon",   "john"),
+            (0.853, "jon",   "tom"),
+        ];
+        for (d, s1, s2) in &sample {
+            assert_eq!(floor3(eudex.similarity(s1, s2)), *d);
+            assert_eq!(floor3(eudex.similarity(s2, s1)), *d);
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! Phonetic hashing: encoding words by their pronunciation.
+//!
+//! Words that sound alike are encoded into equal or close hash values,
+//! which can be compared in constant time and stored in indexes.
+//!
+//!
+//! # Basic Usage
+//!
+//! Eudex:
+//! ```rust
+//! use eddie::phonetic::Eudex;
+//! let eudex = Eudex::new();
+//! let dist = eudex.distance("Smith", "Smythe");
+//! assert_eq!(dist, 0);
+//! ```
+
+
+mod eudex;
+
+pub use eudex::Eudex;
@@ -0,0 +1,491 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use crate::{Levenshtein, DamerauLevenshtein, Hamming, Jaro, JaroWinkler};
+use crate::normalize::Normalizer;
+use crate::utils::graphemes;
+
+
+/// Maximum length of the common prefix rewarded by Jaro-Winkler similarity.
+const MAX_PREFIX: usize = 4;
+
+
+/// Function applied to strings before scoring.
+type Processor = Box<dyn Fn(&str) -> String>;
+
+
+/// Similarity of two strings, from 0.0 (nothing in common) to 1.0 (equality),
+/// used to rank the choices.
+///
+/// Implemented by `Levenshtein`, `DamerauLevenshtein`, `Hamming`, `Jaro` and `JaroWinkler`
+/// (via their `similarity` methods, with `Hamming` scoring strings of different lengths as 0.0),
+/// and by any function `Fn(&str, &str) -> f64`.
+pub trait Scorer {
+    /// Returns a similarity of two strings.
+    fn score(&self, str1: &str, str2: &str) -> f64;
+
+    /// Returns the maximum possible similarity of two strings
+    /// with the given [lengths][1]. Used to skip choices
+    /// that can not be good enough without scoring them.
+    ///
+    /// [1]: #method.length
+    fn max_score(&self, _len1: usize, _len2: usize) -> f64 {
+        1.0
+    }
+
+    /// Returns the length of a string as the scorer counts it, in characters by default.
+    /// `None` means that the length is not known without scoring,
+    /// so choices are never skipped by their lengths.
+    fn length(&self, s: &str) -> Option<usize> {
+        Some(s.chars().count())
+    }
+}
+
+
+impl<F: Fn(&str, &str) -> f64> Scorer for F {
+    fn score(&self, str1: &str, str2: &str) -> f64 {
+        self(str1, str2)
+    }
+}
+
+
+/// Length of a string for the metrics from `eddie::str`: in grapheme clusters in the grapheme mode,
+/// and unknown with a normalizer, which may change it arbitrarily.
+fn str_length(s: &str, grapheme_mode: bool, normalizer: Option<Normalizer>) -> Option<usize> {
+    match (normalizer, grapheme_mode) {
+        (Some(_), _) => None,
+        (None, true) => Some(graphemes(s).count()),
+        (None, false) => Some(s.chars().count()),
+    }
+}
+
+
+/// Maximum edit distance similarity: the distance is at least the difference of lengths.
+fn max_edit_score(len1: usize, len2: usize) -> f64 {
+    if len1 == len2 { return 1.0; }
+    min!(len1, len2) as f64 / max!(len1, len2) as f64
+}
+
+
+/// Maximum Jaro similarity: at most all characters of the shorter string match,
+/// with no transpositions.
+fn max_jaro_score(len1: usize, len2: usize) -> f64 {
+    match (len1, len2) {
+        (0, 0) => 1.0,
+        (0, _) | (_, 0) => 0.0,
+        _ => (2.0 + min!(len1, len2) as f64 / max!(len1, len2) as f64) / 3.0,
+    }
+}
+
+
+impl Scorer for Levenshtein {
+    fn score(&self, str1: &str, str2: &str) -> f64 {
+        self.similarity(str1, str2)
+    }
+
+    fn max_score(&self, len1: usize, len2: usize) -> f64 {
+        max_edit_score(len1, len2)
+    }
+
+    fn length(&self, s: &str) -> Option<usize> {
+        str_length(s, self.grapheme_mode(), self.normalizer())
+    }
+}
+
+
+impl Scorer for DamerauLevenshtein {
+    fn score(&self, str1: &str, str2: &str) -> f64 {
+        self.similarity(str1, str2)
+    }
+
+    fn max_score(&self, len1: usize, len2: usize) -> f64 {
+        max_edit_score(len1, len2)
+    }
+
+    fn length(&self, s: &str) -> Option<usize> {
+        str_length(s, self.grapheme_mode(), self.normalizer())
+    }
+}
+
+
+impl Scorer for Hamming {
+    fn score(&self, str1: &str, str2: &str) -> f64 {
+        self.similarity(str1, str2).unwrap_or(0.0)
+    }
+
+    fn max_score(&self, len1: usize, len2: usize) -> f64 {
+        if len1 == len2 { 1.0 } else { 0.0 }
+    }
+
+    fn length(&self, s: &str) -> Option<usize> {
+        str_length(s, self.grapheme_mode(), self.normalizer())
+    }
+}
+
+
+impl Scorer for Jaro {
+    fn score(&self, str1: &str, str2: &str) -> f64 {
+        self.similarity(str1, str2)
+    }
+
+    fn max_score(&self, len1: usize, len2: usize) -> f64 {
+        max_jaro_score(len1, len2)
+    }
+
+    fn length(&self, s: &str) -> Option<usize> {
+        str_length(s, self.grapheme_mode(), self.normalizer())
+    }
+}
+
+
+impl Scorer for JaroWinkler {
+    fn score(&self, str1: &str, str2: &str) -> f64 {
+        self.similarity(str1, str2)
+    }
+
+    fn max_score(&self, len1: usize, len2: usize) -> f64 {
+        let jaro = max_jaro_score(len1, len2);
+        let prefix = min!(len1, len2, MAX_PREFIX) as f64;
+        jaro + prefix * self.scaling() * (1.0 - jaro)
+    }
+
+    fn length(&self, s: &str) -> Option<usize> {
+        str_length(s, self.grapheme_mode(), self.normalizer())
+    }
+}
+
+
+/// # Fuzzy extractor.
+///
+/// Finds the choices most similar to a query according to a [scorer][1],
+/// like `extract` and `extractOne` from Python's RapidFuzz and FuzzyWuzzy.
+///
+/// Both the query and the choices can be transformed with a preprocessor before scoring,
+/// e.g. to lowercase them. Choices scoring below the cutoff are skipped.
+/// Choices that can not score high enough given their lengths are skipped
+/// without calling the scorer. The scorer is reused across calls,
+/// so are its internal buffers.
+///
+/// Results are sorted by score, ties are broken by the order of the choices.
+///
+/// [1]: trait.Scorer.html
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::Levenshtein;
+/// use eddie::process::Extractor;
+///
+/// let mut extractor = Extractor::new(Levenshtein::new());
+/// extractor
+///     .set_processor(|s| s.to_lowercase())
+///     .set_cutoff(0.5);
+///
+/// let choices = vec!["Mailbox", "BoxMail", "Mail", "Martha"];
+/// let found: Vec<(&str, usize)> = extractor.extract("MAILBX", &choices, 10).iter()
+///     .map(|e| (*e.choice, e.index))
+///     .collect();
+/// assert_eq!(found, vec![("Mailbox", 0), ("Mail", 2)]);
+/// ```
+pub struct Extractor<M> {
+    scorer: M,
+    cutoff: f64,
+    processor: Option<Processor>,
+}
+
+
+/// A choice found by [`Extractor`][1].
+///
+/// [1]: struct.Extractor.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Extracted<S> {
+    /// The choice itself.
+    pub choice: S,
+    /// Index of the choice in the list.
+    pub index: usize,
+    /// Similarity of the choice to the query.
+    pub score: f64,
+}
+
+
+impl<M: Scorer> Extractor<M> {
+    /// Creates a new extractor with the given scorer, no preprocessor and zero cutoff.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::Jaro;
+    /// use eddie::process::Extractor;
+    ///
+    /// let extractor = Extractor::new(Jaro::new());
+    /// ```
+    pub fn new(scorer: M) -> Self {
+        Self { scorer, cutoff: 0.0, processor: None }
+    }
+
+    /// Returns a reference to the scorer.
+    pub fn scorer(&self) -> &M {
+        &self.scorer
+    }
+
+    /// Returns the minimum score of the results.
+    pub fn cutoff(&self) -> f64 {
+        self.cutoff
+    }
+
+    /// Sets the minimum score of the results.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::Levenshtein;
+    /// use eddie::process::Extractor;
+    ///
+    /// let mut extractor = Extractor::new(Levenshtein::new());
+    /// extractor.set_cutoff(0.8);
+    /// assert_eq!(extractor.extract_one("mailbx", &["mail", "box"]), None);
+    /// ```
+    pub fn set_cutoff(&mut self, cutoff: f64) -> &mut Self {
+        self.cutoff = cutoff;
+        self
+    }
+
+    /// Sets a function applied to the query and every choice before scoring.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::Levenshtein;
+    /// use eddie::process::Extractor;
+    ///
+    /// let mut extractor = Extractor::new(Levenshtein::new());
+    /// extractor.set_processor(|s| s.trim().to_lowercase());
+    /// let best = extractor.extract_one("mailbox", &[" MailBox ", "mail"]).unwrap();
+    /// assert_eq!(best.score, 1.0);
+    /// ```
+    pub fn set_processor<P: Fn(&str) -> String + 'static>(&mut self, processor: P) -> &mut Self {
+        self.processor = Some(Box::new(processor));
+        self
+    }
+
+    /// Returns at most `limit` choices most similar to the query,
+    /// sorted by score in descending order, ties are broken by the order of the choices.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::Levenshtein;
+    /// use eddie::process::Extractor;
+    ///
+    /// let extractor = Extractor::new(Levenshtein::new());
+    /// let found = extractor.extract("mail", vec!["boxmail", "mailbox", "mail"], 2);
+    /// assert_eq!(found[0].choice, "mail");
+    /// assert_eq!(found[1].choice, "boxmail");
+    /// assert_eq!(found[1].index, 0);
+    /// ```
+    pub fn extract<I, S>(&self, query: &str, choices: I, limit: usize) -> Vec<Extracted<S>>
+    where
+        I: IntoIterator<Item=S>,
+        S: AsRef<str>,
+    {
+        if limit == 0 { return Vec::new(); }
+
+        let processed_query;
+        let query = match &self.processor {
+            Some(processor) => { processed_query = processor(query); processed_query.as_str() }
+            None => query,
+        };
+        let query_len = self.scorer.length(query);
+
+        // Current results with the worst one on top.
+        let mut heap: BinaryHeap<Entry<S>> = BinaryHeap::new();
+        for (index, choice) in choices.into_iter().enumerate() {
+            let worst = if heap.len() == limit { heap.peek().map(|e| e.0.score) } else { None };
+            // Ties are lost to the earlier choices.
+            let good_enough = |score: f64| score >= self.cutoff && worst.map_or(true, |worst| score > worst);
+
+            let processed;
+            let string = match &self.processor {
+                Some(processor) => { processed = processor(choice.as_ref()); processed.as_str() }
+                None => choice.as_ref(),
+            };
+            if let (Some(len1), Some(len2)) = (query_len, self.scorer.length(string)) {
+                if !good_enough(self.scorer.max_score(len1, len2)) { continue; }
+            }
+
+            let score = self.scorer.score(query, string);
+            if !good_enough(score) { continue; }
+            if heap.len() == limit { heap.pop(); }
+            heap.push(Entry(Extracted { choice, index, score }));
+        }
+
+        heap.into_sorted_vec().into_iter().map(|e| e.0).collect()
+    }
+
+    /// Returns the choice most similar to the query, the first one in case of a tie.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::Jaro;
+    /// use eddie::process::Extractor;
+    ///
+    /// let extractor = Extractor::new(Jaro::new());
+    /// let best = extractor.extract_one("martha", &["marhta", "martha"]).unwrap();
+    /// assert_eq!(best.index, 1);
+    /// assert_eq!(best.score, 1.0);
+    /// ```
+    pub fn extract_one<I, S>(&self, query: &str, choices: I) -> Option<Extracted<S>>
+    where
+        I: IntoIterator<Item=S>,
+        S: AsRef<str>,
+    {
+        self.extract(query, choices, 1).pop()
+    }
+}
+
+
+/// Result ordered from the best to the worst.
+struct Entry<S>(Extracted<S>);
+
+impl<S> Ord for Entry<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.score.partial_cmp(&self.0.score)
+            .unwrap_or(Ordering::Equal)
+            .then(self.0.index.cmp(&other.0.index))
+    }
+}
+
+impl<S> PartialOrd for Entry<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> PartialEq for Entry<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S> Eq for Entry<S> { }
+
+
+#[cfg(test)]
+mod tests {
+    use super::{Extractor, Scorer};
+    use crate::{Levenshtein, DamerauLevenshtein, Hamming, Jaro, JaroWinkler};
+    use crate::normalize::{Normalizer, CaseFolding};
+
+    const CHOICES: [&str; 14] = [
+        "mailbox", "boxmail", "mail", "box", "martha", "marhta", "mailboxes",
+        "", "m", "mailbx", "amilbox", "mialbox", "もしもし", "mailbox",
+    ];
+
+    const QUERIES: [&str; 6] = ["mailbox", "martha", "", "mbx", "もしまし", "boxmial"];
+
+    /// Scores all choices and sorts them.
+    fn naive<M: Scorer>(scorer: &M, query: &str, cutoff: f64, limit: usize) -> Vec<(usize, f64)> {
+        let mut scored: Vec<(usize, f64)> = CHOICES.iter().enumerate()
+            .map(|(i, choice)| (i, scorer.score(query, choice)))
+            .filter(|&(_, score)| score >= cutoff)
+            .collect();
+        scored.sort_by(|(i1, s1), (i2, s2)| s2.partial_cmp(s1).unwrap().then(i1.cmp(i2)));
+        scored.truncate(limit);
+        scored
+    }
+
+    fn check<M: Scorer>(scorer: M) {
+        let mut extractor = Extractor::new(scorer);
+        for &cutoff in &[0.0, 0.5, 0.8, 1.0] {
+            extractor.set_cutoff(cutoff);
+            for query in &QUERIES {
+                for &limit in &[0, 1, 3, 100] {
+                    let found: Vec<(usize, f64)> = extractor.extract(query, &CHOICES, limit).iter()
+                        .map(|e| (e.index, e.score))
+                        .collect();
+                    assert_eq!(found, naive(extractor.scorer(), query, cutoff, limit));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn matches_naive() {
+        check(Levenshtein::new());
+        check(DamerauLevenshtein::new());
+        check(Hamming::new());
+        check(Jaro::new());
+        check(JaroWinkler::new());
+        let mut jarwin = JaroWinkler::new();
+        jarwin.set_scaling(0.25);
+        check(jarwin);
+        let lev = Levenshtein::new();
+        check(move |s1: &str, s2: &str| lev.similarity(s1, s2) * 0.5);
+    }
+
+    #[test]
+    fn max_score() {
+        let scorers: [&dyn Scorer; 5] = [
+            &Levenshtein::new(), &DamerauLevenshtein::new(), &Hamming::new(),
+            &Jaro::new(), &JaroWinkler::new(),
+        ];
+        for scorer in &scorers {
+            for s1 in &CHOICES {
+                for s2 in &CHOICES {
+                    let max = scorer.max_score(scorer.length(s1).unwrap(), scorer.length(s2).unwrap());
+                    assert!(scorer.score(s1, s2) <= max + 1e-9, "{} {}", s1, s2);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn grapheme_mode() {
+        let mut lev = Levenshtein::new();
+        lev.set_grapheme_mode(true);
+        let mut extractor = Extractor::new(lev);
+        extractor.set_cutoff(0.45);
+        let found = extractor.extract_one("xb", &["a\u{301}\u{302}\u{303}b"]).unwrap();
+        assert_eq!(found.score, 0.5);
+        let mut jarwin = JaroWinkler::new();
+        jarwin.set_grapheme_mode(true);
+        check(jarwin);
+    }
+
+    #[test]
+    fn normalizer() {
+        let mut normalizer = Normalizer::new();
+        normalizer
+            .set_case_folding(CaseFolding::Full)
+            .set_strip_diacritics(true)
+            .set_collapse_whitespace(true);
+        let mut extractor = Extractor::new(Levenshtein::with_normalizer(normalizer));
+        extractor.set_cutoff(0.95);
+        let found = extractor.extract_one("creme brulee", &["Crème  Brûlée"]).unwrap();
+        assert_eq!(found.score, 1.0);
+        assert_eq!(extractor.scorer().length("creme"), None);
+        check(Jaro::with_normalizer(normalizer));
+    }
+
+    #[test]
+    fn ties() {
+        let extractor = Extractor::new(Levenshtein::new());
+        let found: Vec<usize> = extractor.extract("mailbox", &CHOICES, 3).iter().map(|e| e.index).collect();
+        assert_eq!(found, vec![0, 13, 9]);
+        assert_eq!(extractor.extract_one("mailbox", &CHOICES).unwrap().index, 0);
+        let found: Vec<usize> = extractor.extract("bx", &["ab", "xb", "bb"], 2).iter().map(|e| e.index).collect();
+        assert_eq!(found, vec![2, 0]);
+    }
+
+    #[test]
+    fn processor() {
+        let mut extractor = Extractor::new(Levenshtein::new());
+        extractor.set_processor(|s| s.to_lowercase());
+        let choices = vec![String::from("MAILBOX"), String::from("Mail")];
+        let found = extractor.extract("MailBox", choices, 10);
+        assert_eq!(found[0].choice, "MAILBOX");
+        assert_eq!(found[0].score, 1.0);
+        assert_eq!(found[1].choice, "Mail");
+        assert_eq!(extractor.extract_one("mail", Vec::<&str>::new()), None);
+    }
+}
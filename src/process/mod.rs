@@ -0,0 +1,40 @@
+//! Batch processing: fuzzy extraction of the best matches for a query from a list of choices,
+//! and pairwise distance matrices.
+//!
+//!
+//! # Basic Usage
+//!
+//! Extraction:
+//! ```rust
+//! use eddie::JaroWinkler;
+//! use eddie::process::Extractor;
+//!
+//! let extractor = Extractor::new(JaroWinkler::new());
+//! let choices = ["mailbox", "boxmail", "martha", "marhta"];
+//!
+//! let best = extractor.extract_one("martah", &choices).unwrap();
+//! assert_eq!((best.choice, best.index), (&"martha", 2));
+//!
+//! let found: Vec<&str> = extractor.extract("martah", &choices, 2).iter()
+//!     .map(|e| *e.choice)
+//!     .collect();
+//! assert_eq!(found, vec!["martha", "marhta"]);
+//! ```
+//!
+//! Distance matrix:
+//! ```rust
+//! use eddie::Levenshtein;
+//! use eddie::process::Pairwise;
+//!
+//! let pairwise = Pairwise::new(Levenshtein::new);
+//! let mut dists = vec![0; 4];
+//! pairwise.cdist(&["mailbox", "martha"], &["mail", "marhta"], None, &mut dists);
+//! assert_eq!(dists, vec![3, 5, 4, 2]);
+//! ```
+
+
+mod extract;
+mod pairwise;
+
+pub use extract::{Extractor, Extracted, Scorer};
+pub use pairwise::Pairwise;
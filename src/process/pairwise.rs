@@ -0,0 +1,427 @@
+use std::thread;
+use crate::metric::Distance;
+use super::Scorer;
+
+
+/// # Pairwise distance matrices.
+///
+/// Computes distances or similarities between all pairs of strings from two lists
+/// (`cdist`, a full matrix), or within one list (`pdist`, a condensed upper triangle),
+/// like `cdist` from Python's RapidFuzz and `pdist` from SciPy.
+///
+/// The work is split between scoped threads. As metrics keep internal buffers
+/// and can not be shared between threads, every worker creates its own metric instance
+/// with the given function. Results are written to a preallocated buffer.
+///
+/// Distances are computed with any [integer-valued distance][1], similarities with any [scorer][2].
+/// With a cutoff, distances above it are replaced with `max_dist + 1`,
+/// and similarities below it are replaced with `0.0`.
+///
+/// [1]: ../metric/trait.Distance.html
+/// [2]: trait.Scorer.html
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::{Levenshtein, JaroWinkler};
+/// use eddie::process::Pairwise;
+///
+/// let names = ["martha", "marhta", "mailbox"];
+///
+/// let mut pairwise = Pairwise::new(Levenshtein::new);
+/// pairwise.set_workers(2);
+/// let mut dists = vec![0; 3];
+/// pairwise.pdist(&names, Some(2), &mut dists);
+/// assert_eq!(dists, vec![2, 3, 3]);
+///
+/// let pairwise = Pairwise::new(JaroWinkler::new);
+/// let mut sims = vec![0.0; 9];
+/// pairwise.cdist_scores(&names, &names, Some(0.9), &mut sims);
+/// assert_eq!(sims[0], 1.0);
+/// assert!(sims[1] > 0.9);
+/// assert_eq!(sims[2], 0.0);
+/// ```
+pub struct Pairwise<F> {
+    make: F,
+    workers: usize,
+}
+
+
+impl<M, F: Fn() -> M + Sync> Pairwise<F> {
+    /// Creates a new instance with the given function creating metric instances,
+    /// using as many workers as there are available CPUs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::{Levenshtein, JaroWinkler};
+    /// use eddie::process::Pairwise;
+    ///
+    /// let pairwise = Pairwise::new(Levenshtein::new);
+    /// let pairwise = Pairwise::new(|| {
+    ///     let mut jarwin = JaroWinkler::new();
+    ///     jarwin.set_scaling(0.2);
+    ///     jarwin
+    /// });
+    /// ```
+    pub fn new(make: F) -> Self {
+        let workers = thread::available_parallelism().map_or(1, |n| n.get());
+        Self { make, workers }
+    }
+
+    /// Returns the maximum number of worker threads.
+    pub fn workers(&self) -> usize {
+        self.workers
+    }
+
+    /// Sets the maximum number of worker threads. With a single worker,
+    /// everything is computed in the current thread.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero.
+    pub fn set_workers(&mut self, workers: usize) -> &mut Self {
+        assert!(workers > 0, "Number of workers should be positive");
+        self.workers = workers;
+        self
+    }
+
+    /// Computes distances between every row and every column.
+    /// The distance between `rows[i]` and `cols[j]` is written to `out[i * cols.len() + j]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `out` is not `rows.len() * cols.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::Levenshtein;
+    /// use eddie::process::Pairwise;
+    ///
+    /// let pairwise = Pairwise::new(Levenshtein::new);
+    /// let mut dists = vec![0; 6];
+    /// pairwise.cdist(&["mailbox", "martha"], &["mail", "marhta", "boxmail"], Some(4), &mut dists);
+    /// assert_eq!(dists, vec![
+    ///     3, 5, 5,
+    ///     4, 2, 5,
+    /// ]);
+    /// ```
+    pub fn cdist<S1, S2>(&self, rows: &[S1], cols: &[S2], max_dist: Option<usize>, out: &mut [usize])
+    where
+        M: Distance<str>,
+        S1: AsRef<str> + Sync,
+        S2: AsRef<str> + Sync,
+    {
+        self.cdist_with(rows, cols, out, |metric, s1, s2| cut_distance(metric.distance(s1, s2), max_dist));
+    }
+
+    /// Computes similarities between every row and every column.
+    /// The similarity between `rows[i]` and `cols[j]` is written to `out[i * cols.len() + j]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `out` is not `rows.len() * cols.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::Levenshtein;
+    /// use eddie::process::Pairwise;
+    ///
+    /// let pairwise = Pairwise::new(Levenshtein::new);
+    /// let mut sims = vec![0.0; 2];
+    /// pairwise.cdist_scores(&["mailbox"], &["mailbx", "boxmail"], Some(0.5), &mut sims);
+    /// assert!((sims[0] - 0.857).abs() < 0.001);
+    /// assert_eq!(sims[1], 0.0);
+    /// ```
+    pub fn cdist_scores<S1, S2>(&self, rows: &[S1], cols: &[S2], cutoff: Option<f64>, out: &mut [f64])
+    where
+        M: Scorer,
+        S1: AsRef<str> + Sync,
+        S2: AsRef<str> + Sync,
+    {
+        self.cdist_with(rows, cols, out, |metric, s1, s2| cut_score(metric.score(s1, s2), cutoff));
+    }
+
+    /// Computes distances between all pairs of items, written in the condensed form:
+    /// the upper triangle of the matrix without the diagonal, row by row.
+    /// The distance between `items[i]` and `items[j]` with `i < j` is written
+    /// to `out[n * i - i * (i + 1) / 2 + j - i - 1]`, where `n` is the number of items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `out` is not `n * (n - 1) / 2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::Levenshtein;
+    /// use eddie::process::Pairwise;
+    ///
+    /// let pairwise = Pairwise::new(Levenshtein::new);
+    /// let mut dists = vec![0; 3];
+    /// pairwise.pdist(&["mailbox", "mail", "box"], None, &mut dists);
+    /// assert_eq!(dists, vec![3, 4, 4]);
+    /// ```
+    pub fn pdist<S>(&self, items: &[S], max_dist: Option<usize>, out: &mut [usize])
+    where
+        M: Distance<str>,
+        S: AsRef<str> + Sync,
+    {
+        self.pdist_with(items, out, |metric, s1, s2| cut_distance(metric.distance(s1, s2), max_dist));
+    }
+
+    /// Computes similarities between all pairs of items, written in the condensed form.
+    /// See [`pdist`][1] for the layout.
+    ///
+    /// [1]: #method.pdist
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `out` is not `n * (n - 1) / 2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::Jaro;
+    /// use eddie::process::Pairwise;
+    ///
+    /// let pairwise = Pairwise::new(Jaro::new);
+    /// let mut sims = vec![0.0; 1];
+    /// pairwise.pdist_scores(&["martha", "marhta"], None, &mut sims);
+    /// assert!((sims[0] - 0.94).abs() < 0.01);
+    /// ```
+    pub fn pdist_scores<S>(&self, items: &[S], cutoff: Option<f64>, out: &mut [f64])
+    where
+        M: Scorer,
+        S: AsRef<str> + Sync,
+    {
+        self.pdist_with(items, out, |metric, s1, s2| cut_score(metric.score(s1, s2), cutoff));
+    }
+
+    fn cdist_with<S1, S2, T, G>(&self, rows: &[S1], cols: &[S2], out: &mut [T], compute: G)
+    where
+        S1: AsRef<str> + Sync,
+        S2: AsRef<str> + Sync,
+        T: Send,
+        G: Fn(&M, &str, &str) -> T + Sync,
+    {
+        assert_eq!(out.len(), rows.len() * cols.len(), "Output length should be rows.len() * cols.len()");
+        if out.is_empty() { return; }
+
+        let rows_per_worker = (rows.len() + self.workers - 1) / self.workers;
+        let blocks = rows.chunks(rows_per_worker).zip(out.chunks_mut(rows_per_worker * cols.len()));
+        let compute = &compute;
+        self.run(blocks, move |metric, (rows, out)| {
+            for (row, out) in rows.iter().zip(out.chunks_mut(cols.len())) {
+                for (col, out) in cols.iter().zip(out.iter_mut()) {
+                    *out = compute(metric, row.as_ref(), col.as_ref());
+                }
+            }
+        });
+    }
+
+    fn pdist_with<S, T, G>(&self, items: &[S], out: &mut [T], compute: G)
+    where
+        S: AsRef<str> + Sync,
+        T: Send,
+        G: Fn(&M, &str, &str) -> T + Sync,
+    {
+        let n = items.len();
+        assert_eq!(out.len(), n * n.saturating_sub(1) / 2, "Output length should be n * (n - 1) / 2");
+        if out.is_empty() { return; }
+
+        // Contiguous ranges of rows with about the same number of pairs.
+        let per_worker = (out.len() + self.workers - 1) / self.workers;
+        let mut blocks = Vec::with_capacity(self.workers);
+        let mut rest = out;
+        let mut start = 0;
+        // Rows left after the last pair have no pairs.
+        while !rest.is_empty() {
+            let mut end = start;
+            let mut len = 0;
+            while end < n && len < per_worker {
+                len += n - end - 1;
+                end += 1;
+            }
+            let (block, tail) = rest.split_at_mut(len);
+            blocks.push((start .. end, block));
+            rest = tail;
+            start = end;
+        }
+
+        let compute = &compute;
+        self.run(blocks, move |metric, (range, out)| {
+            let mut out = out.iter_mut();
+            for i in range {
+                for item in &items[i + 1 ..] {
+                    *out.next().unwrap() = compute(metric, items[i].as_ref(), item.as_ref());
+                }
+            }
+        });
+    }
+
+    /// Processes every block with its own metric instance in a separate thread.
+    fn run<B, I, W>(&self, blocks: I, work: W)
+    where
+        B: Send,
+        I: IntoIterator<Item=B>,
+        W: Fn(&M, B) + Sync,
+    {
+        if self.workers == 1 {
+            let metric = (self.make)();
+            for block in blocks { work(&metric, block); }
+            return;
+        }
+        let work = &work;
+        thread::scope(|scope| {
+            for block in blocks {
+                scope.spawn(move || work(&(self.make)(), block));
+            }
+        });
+    }
+}
+
+
+fn cut_distance(dist: usize, max_dist: Option<usize>) -> usize {
+    match max_dist {
+        Some(max_dist) if dist > max_dist => max_dist + 1,
+        _ => dist,
+    }
+}
+
+
+fn cut_score(score: f64, cutoff: Option<f64>) -> f64 {
+    match cutoff {
+        Some(cutoff) if score < cutoff => 0.0,
+        _ => score,
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use super::Pairwise;
+    use crate::{Levenshtein, DamerauLevenshtein, JaroWinkler};
+    use crate::phonetic::Eudex;
+    use crate::process::Scorer;
+
+    const WORDS: [&str; 9] = ["mailbox", "boxmail", "mail", "", "martha", "marhta", "もしもし", "mialbox", "m"];
+
+    #[test]
+    fn cdist() {
+        let lev = Levenshtein::new();
+        for workers in 1..12 {
+            for rows in 0 .. WORDS.len() {
+                let rows = &WORDS[.. rows];
+                let cols = &WORDS[2 ..];
+                let mut pairwise = Pairwise::new(Levenshtein::new);
+                pairwise.set_workers(workers);
+
+                let mut out = vec![usize::MAX; rows.len() * cols.len()];
+                pairwise.cdist(rows, cols, None, &mut out);
+                let expected: Vec<usize> = rows.iter()
+                    .flat_map(|s1| cols.iter().map(move |s2| (s1, s2)))
+                    .map(|(s1, s2)| lev.distance(s1, s2))
+                    .collect();
+                assert_eq!(out, expected);
+
+                pairwise.cdist(rows, cols, Some(2), &mut out);
+                let cut: Vec<usize> = expected.iter().map(|&d| if d > 2 { 3 } else { d }).collect();
+                assert_eq!(out, cut);
+
+                let mut out = vec![-1.0; rows.len() * cols.len()];
+                pairwise.cdist_scores(rows, cols, Some(0.5), &mut out);
+                let expected: Vec<f64> = rows.iter()
+                    .flat_map(|s1| cols.iter().map(move |s2| (s1, s2)))
+                    .map(|(s1, s2)| lev.similarity(s1, s2))
+                    .map(|s| if s < 0.5 { 0.0 } else { s })
+                    .collect();
+                assert_eq!(out, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn pdist() {
+        let damlev = DamerauLevenshtein::new();
+        let jarwin = JaroWinkler::new();
+        for workers in 1..12 {
+            for n in 0 .. WORDS.len() {
+                let items = &WORDS[.. n];
+                let mut pairwise = Pairwise::new(DamerauLevenshtein::new);
+                pairwise.set_workers(workers);
+                let mut out = vec![usize::MAX; n * n.saturating_sub(1) / 2];
+                pairwise.pdist(items, Some(3), &mut out);
+                for i in 0 .. n {
+                    for j in i + 1 .. n {
+                        let dist = damlev.distance(items[i], items[j]);
+                        let dist = if dist > 3 { 4 } else { dist };
+                        assert_eq!(out[n * i - i * (i + 1) / 2 + j - i - 1], dist);
+                    }
+                }
+
+                let mut pairwise = Pairwise::new(JaroWinkler::new);
+                pairwise.set_workers(workers);
+                let mut out = vec![-1.0; n * n.saturating_sub(1) / 2];
+                pairwise.pdist_scores(items, None, &mut out);
+                let expected: Vec<f64> = (0 .. n)
+                    .flat_map(|i| (i + 1 .. n).map(move |j| (i, j)))
+                    .map(|(i, j)| jarwin.score(items[i], items[j]))
+                    .collect();
+                assert_eq!(out, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn workers() {
+        let made = AtomicUsize::new(0);
+        let make = || {
+            made.fetch_add(1, Ordering::Relaxed);
+            Levenshtein::new()
+        };
+        for workers in 1..12 {
+            for n in 0 .. WORDS.len() {
+                let items = &WORDS[.. n];
+                let mut pairwise = Pairwise::new(make);
+                pairwise.set_workers(workers);
+
+                made.store(0, Ordering::Relaxed);
+                let mut out = vec![0; n * n.saturating_sub(1) / 2];
+                pairwise.pdist(items, None, &mut out);
+                assert!(made.load(Ordering::Relaxed) <= workers);
+
+                made.store(0, Ordering::Relaxed);
+                let mut out = vec![0; n * WORDS.len()];
+                pairwise.cdist(items, &WORDS, None, &mut out);
+                assert!(made.load(Ordering::Relaxed) <= workers);
+            }
+        }
+    }
+
+    #[test]
+    fn any_metric() {
+        let mut pairwise = Pairwise::new(Eudex::new);
+        pairwise.set_workers(2);
+        let mut out = vec![0; 4];
+        pairwise.cdist(&["jumpo", "jumbo"], &["jumpo", "jumbo"], None, &mut out);
+        assert_eq!(out[0], 0);
+        assert_eq!(out[1], out[2]);
+
+        let pairwise = Pairwise::new(|| |s1: &str, s2: &str| (s1.len() == s2.len()) as u8 as f64);
+        let mut out = vec![0.0; 3];
+        pairwise.pdist_scores(&["ab", "cd", "efg"], None, &mut out);
+        assert_eq!(out, vec![1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn wrong_length() {
+        let pairwise = Pairwise::new(Levenshtein::new);
+        let mut out = vec![0; 3];
+        pairwise.cdist(&["a", "b"], &["c", "d"], None, &mut out);
+    }
+}
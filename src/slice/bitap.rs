@@ -0,0 +1,649 @@
+use core::fmt;
+use super::Match;
+use alloc::vec;
+use alloc::vec::Vec;
+
+
+/// Maximum number of pattern positions.
+const MAX_LEN: usize = 64;
+
+/// Number of symbols with precomputed masks.
+const TABLE_SIZE: usize = 256;
+
+
+/// # Bitap fuzzy search.
+///
+/// Finds all occurrences of a pattern in a text within the given Levenshtein distance,
+/// with the Wu-Manber extension of the [Bitap algorithm][1] (as in agrep).
+///
+/// The pattern may contain:
+/// - `.` matching any single byte,
+/// - `[...]` matching any byte of the class, e.g. `[abc]`, `[a-z0-9_]`,
+///   or any byte not in the class if it starts with `^`, e.g. `[^ ]`,
+/// - `#` matching any sequence of bytes (including empty) at no cost,
+/// - `\` escaping the following byte.
+///
+/// Up to 64 positions are supported (`#` does not take a position).
+/// Leading and trailing `#` are ignored.
+///
+/// Start positions of the matches are recovered by running the reversed pattern
+/// backwards from their ends, so the shortest matching substrings are reported.
+/// Overlapping matches are reported only once, as in [`Sellers`][2].
+///
+/// [1]: https://en.wikipedia.org/wiki/Bitap_algorithm
+/// [2]: struct.Sellers.html
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::slice::{Bitap, Match};
+///
+/// let bitap = Bitap::new(b"error [0-9][0-9]", 1).unwrap();
+/// let log = b"info 200\neror 42\nerror 7x\n";
+/// let found: Vec<&[u8]> = bitap.find_iter(log)
+///     .map(|m| &log[m.start .. m.end])
+///     .collect();
+/// assert_eq!(found, vec![&b"eror 42"[..], &b"error 7"[..]]);
+/// ```
+pub struct Bitap {
+    pattern: Pattern<u8>,
+}
+
+
+/// An error in a search pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternError {
+    /// The pattern has more than 64 positions.
+    TooLong,
+    /// The pattern ends with an escape character `\`.
+    DanglingEscape,
+    /// A character class starting at the given position is not closed.
+    UnclosedClass(usize),
+    /// A character class starting at the given position matches nothing.
+    EmptyClass(usize),
+}
+
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternError::TooLong => write!(f, "pattern is longer than {} positions", MAX_LEN),
+            PatternError::DanglingEscape => write!(f, "pattern ends with an escape character"),
+            PatternError::UnclosedClass(i) => write!(f, "unclosed character class at {}", i),
+            PatternError::EmptyClass(i) => write!(f, "empty character class at {}", i),
+        }
+    }
+}
+
+
+#[cfg(feature = "std")]
+impl std::error::Error for PatternError { }
+
+
+impl Bitap {
+    /// Compiles the pattern for the given maximum distance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::slice::{Bitap, PatternError};
+    ///
+    /// assert!(Bitap::new(b"mail[bB]ox", 1).is_ok());
+    /// assert_eq!(Bitap::new(b"mail[bB", 1).err(), Some(PatternError::UnclosedClass(4)));
+    /// ```
+    pub fn new(pattern: &[u8], max_dist: usize) -> Result<Self, PatternError> {
+        Ok(Self { pattern: Pattern::new(pattern, max_dist)? })
+    }
+
+    /// Returns the maximum distance.
+    pub fn max_dist(&self) -> usize {
+        self.pattern.max_dist()
+    }
+
+    /// Returns `true` if the pattern occurs in the text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::slice::Bitap;
+    /// let bitap = Bitap::new(b"time#out", 1).unwrap();
+    /// assert!(bitap.is_match(b"connection timed out"));
+    /// assert!(bitap.is_match(b"connection tmeout"));
+    /// assert!(!bitap.is_match(b"connection refused"));
+    /// ```
+    pub fn is_match(&self, text: &[u8]) -> bool {
+        self.pattern.is_match(text.iter().copied())
+    }
+
+    /// Returns an iterator over the matches of the pattern in the text.
+    /// Match positions are indices in the text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::slice::Bitap;
+    /// let bitap = Bitap::new(b"m.il", 0).unwrap();
+    /// let found: Vec<(usize, usize, usize)> = bitap.find_iter(b"mail, mall")
+    ///     .map(|m| (m.start, m.end, m.distance))
+    ///     .collect();
+    /// assert_eq!(found, vec![(0, 4, 0)]);
+    /// ```
+    pub fn find_iter<'a>(&'a self, text: &'a [u8]) -> BitapMatches<'a> {
+        BitapMatches {
+            scanner: Scanner::new(&self.pattern),
+            text,
+            pos: 0,
+        }
+    }
+}
+
+
+/// An iterator over the matches found by [`Bitap::find_iter`][1].
+///
+/// [1]: struct.Bitap.html#method.find_iter
+pub struct BitapMatches<'a> {
+    scanner: Scanner<'a, u8>,
+    text: &'a [u8],
+    pos: usize,
+}
+
+
+impl<'a> Iterator for BitapMatches<'a> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        let text = self.text;
+        while self.pos < text.len() {
+            let end = self.pos + 1;
+            let x = text[self.pos];
+            self.pos = end;
+            let backwards = text[.. end].iter().copied().enumerate().rev();
+            if let Some(found) = self.scanner.push(end, x, backwards) {
+                return Some(found);
+            }
+        }
+        self.scanner.finish()
+    }
+}
+
+
+/// Items of search patterns: bytes or chars.
+pub trait Symbol: Copy + Ord {
+    fn from_ascii(byte: u8) -> Self;
+    /// Index in the table of precomputed masks, if it fits there.
+    fn index(self) -> usize;
+}
+
+impl Symbol for u8 {
+    fn from_ascii(byte: u8) -> Self { byte }
+    fn index(self) -> usize { self as usize }
+}
+
+impl Symbol for char {
+    fn from_ascii(byte: u8) -> Self { byte as char }
+    fn index(self) -> usize { self as usize }
+}
+
+
+/// A pattern element.
+#[derive(Clone)]
+enum Element<T> {
+    /// Any single symbol, or a symbol from a class.
+    Class(Class<T>),
+    /// Any sequence of symbols.
+    Gap,
+}
+
+
+/// A set of symbols: a list of inclusive ranges, possibly negated.
+#[derive(Clone)]
+struct Class<T> {
+    ranges: Vec<(T, T)>,
+    negated: bool,
+}
+
+
+impl<T: Symbol> Class<T> {
+    fn any() -> Self {
+        Self { ranges: Vec::new(), negated: true }
+    }
+
+    fn single(x: T) -> Self {
+        Self { ranges: vec![(x, x)], negated: false }
+    }
+
+    fn contains(&self, x: T) -> bool {
+        self.ranges.iter().any(|&(lo, hi)| lo <= x && x <= hi) != self.negated
+    }
+
+    /// Tells whether the class contains the symbol with the given table index.
+    fn contains_index(&self, i: usize) -> bool {
+        self.ranges.iter().any(|&(lo, hi)| lo.index() <= i && i <= hi.index()) != self.negated
+    }
+}
+
+
+/// Parses a pattern into elements.
+fn parse<T: Symbol>(pattern: &[T]) -> Result<Vec<Element<T>>, PatternError> {
+    let special = |x: T, byte: u8| x == T::from_ascii(byte);
+    let mut elements = Vec::new();
+    let mut i = 0;
+
+    while i < pattern.len() {
+        let x = pattern[i];
+        if special(x, b'\\') {
+            let escaped = *pattern.get(i + 1).ok_or(PatternError::DanglingEscape)?;
+            elements.push(Element::Class(Class::single(escaped)));
+            i += 2;
+        } else if special(x, b'.') {
+            elements.push(Element::Class(Class::any()));
+            i += 1;
+        } else if special(x, b'#') {
+            elements.push(Element::Gap);
+            i += 1;
+        } else if special(x, b'[') {
+            let start = i;
+            i += 1;
+            let negated = i < pattern.len() && special(pattern[i], b'^');
+            if negated { i += 1; }
+            let mut ranges = Vec::new();
+            let mut first = true;
+            loop {
+                let mut x = *pattern.get(i).ok_or(PatternError::UnclosedClass(start))?;
+                if special(x, b']') && !first { i += 1; break; }
+                first = false;
+                if special(x, b'\\') {
+                    x = *pattern.get(i + 1).ok_or(PatternError::DanglingEscape)?;
+                    i += 1;
+                }
+                i += 1;
+                let is_range = i + 1 < pattern.len() && special(pattern[i], b'-') && !special(pattern[i + 1], b']');
+                if is_range {
+                    let mut y = pattern[i + 1];
+                    i += 2;
+                    if special(y, b'\\') {
+                        y = *pattern.get(i).ok_or(PatternError::DanglingEscape)?;
+                        i += 1;
+                    }
+                    if x <= y { ranges.push((x, y)); }
+                } else {
+                    ranges.push((x, x));
+                }
+            }
+            if ranges.is_empty() && !negated {
+                return Err(PatternError::EmptyClass(start));
+            }
+            elements.push(Element::Class(Class { ranges, negated }));
+        } else {
+            elements.push(Element::Class(Class::single(x)));
+            i += 1;
+        }
+    }
+
+    Ok(elements)
+}
+
+
+/// A compiled pattern: automata for forward and backward search.
+pub struct Pattern<T> {
+    forward: Automaton<T>,
+    backward: Automaton<T>,
+    max_dist: usize,
+}
+
+
+impl<T: Symbol> Pattern<T> {
+    pub fn new(pattern: &[T], max_dist: usize) -> Result<Self, PatternError> {
+        let mut elements = parse(pattern)?;
+        let forward = Automaton::new(&elements)?;
+        elements.reverse();
+        let backward = Automaton::new(&elements)?;
+        Ok(Self { forward, backward, max_dist })
+    }
+
+    pub fn max_dist(&self) -> usize {
+        self.max_dist
+    }
+
+    /// Tells whether the pattern occurs in the text.
+    pub fn is_match<I: Iterator<Item=T>>(&self, mut text: I) -> bool {
+        let mut rows = self.forward.start(self.max_dist);
+        text.any(|x| self.forward.step(&mut rows, x).is_some())
+    }
+
+    /// Finds the start of the shortest match with the given distance
+    /// ending at the given position, given the text preceding it in reverse order.
+    fn match_start<I: Iterator<Item=(usize, T)>>(&self, dist: usize, end: usize, backwards: I) -> usize {
+        let mut rows = self.backward.start(dist);
+        if self.backward.accepts(&rows).is_some() {
+            return end;
+        }
+        let mut start = end;
+        for (n, (i, x)) in backwards.enumerate() {
+            start = i;
+            if self.backward.advance(&mut rows, x, Some(n)).is_some() { break; }
+        }
+        start
+    }
+}
+
+
+/// Wu-Manber automaton: bit `i` of row `d` is set if the pattern prefix of length `i + 1`
+/// matches a suffix of the text read so far with at most `d` errors.
+struct Automaton<T> {
+    len: usize,
+    mask: u64,
+    // Positions after which any text can be skipped (`#`).
+    loops: u64,
+    table: Vec<u64>,
+    classes: Vec<(Class<T>, u64)>,
+}
+
+
+impl<T: Symbol> Automaton<T> {
+    fn new(elements: &[Element<T>]) -> Result<Self, PatternError> {
+        let mut classes: Vec<(Class<T>, u64)> = Vec::new();
+        let mut loops = 0;
+        let mut len = 0;
+
+        for element in elements {
+            match element {
+                Element::Class(class) => {
+                    if len == MAX_LEN { return Err(PatternError::TooLong); }
+                    classes.push((class.clone(), 1 << len));
+                    len += 1;
+                }
+                Element::Gap => {
+                    if len > 0 { loops |= 1 << (len - 1); }
+                }
+            }
+        }
+
+        let mask = if len == MAX_LEN { u64::MAX } else { (1 << len) - 1 };
+        // Gaps at the end are meaningless, as matches are reported as soon as possible.
+        if len > 0 { loops &= !(1 << (len - 1)); }
+
+        let mut automaton = Self { len, mask, loops, table: Vec::new(), classes };
+        automaton.table = automaton.build_table();
+        Ok(automaton)
+    }
+
+    fn build_table(&self) -> Vec<u64> {
+        let mut table = vec![0; TABLE_SIZE];
+        for (class, bit) in &self.classes {
+            for (i, mask) in table.iter_mut().enumerate() {
+                if class.contains_index(i) { *mask |= bit; }
+            }
+        }
+        table
+    }
+
+    /// Returns the mask of positions matching the symbol.
+    fn symbol_mask(&self, x: T) -> u64 {
+        let i = x.index();
+        if i < TABLE_SIZE { return self.table[i]; }
+        self.classes.iter()
+            .filter(|(class, _)| class.contains(x))
+            .fold(0, |mask, (_, bit)| mask | bit)
+    }
+
+    /// Returns the initial rows for the given maximum distance.
+    fn start(&self, max_dist: usize) -> Vec<u64> {
+        (0 ..= max_dist)
+            .map(|d| if d >= 64 { u64::MAX } else { (1 << d) - 1 })
+            .map(|row| row & self.mask)
+            .collect()
+    }
+
+    /// Returns the minimal distance of a match ending here, if any.
+    fn accepts(&self, rows: &[u64]) -> Option<usize> {
+        if self.len == 0 { return None; }
+        let high = 1 << (self.len - 1);
+        rows.iter().position(|row| row & high != 0)
+    }
+
+    /// Consumes a symbol of the text and returns the minimal distance
+    /// of a match ending at it, if any.
+    fn step(&self, rows: &mut [u64], x: T) -> Option<usize> {
+        self.advance(rows, x, None)
+    }
+
+    /// Consumes a symbol of the text. For matches anchored at the start of the text,
+    /// takes the number of symbols consumed before, otherwise a match may start anywhere.
+    fn advance(&self, rows: &mut [u64], x: T, anchored: Option<usize>) -> Option<usize> {
+        if self.len == 0 { return None; }
+        // First position bit, if the empty prefix is matched with at most `d` errors
+        // after consuming `n` symbols.
+        let seed = |d: usize, n: usize| anchored.map_or(1, |before| (before + n <= d) as u64);
+        let eq = self.symbol_mask(x);
+        let mut prev_old = rows[0];
+        rows[0] = ((prev_old << 1 | seed(0, 0)) & eq | prev_old & self.loops) & self.mask;
+        for d in 1 .. rows.len() {
+            let old = rows[d];
+            let matched = (old << 1 | seed(d, 0)) & eq | old & self.loops;
+            let substituted = prev_old << 1 | seed(d - 1, 0);
+            let inserted = prev_old;
+            let deleted = rows[d - 1] << 1 | seed(d - 1, 1);
+            rows[d] = (matched | substituted | inserted | deleted) & self.mask;
+            prev_old = old;
+        }
+        self.accepts(rows)
+    }
+}
+
+
+/// Search state: consumes text symbols one by one, and reports the matches ending at them.
+pub struct Scanner<'a, T> {
+    pattern: &'a Pattern<T>,
+    rows: Vec<u64>,
+    pending: Option<Match>,
+}
+
+
+impl<'a, T: Symbol> Scanner<'a, T> {
+    pub fn new(pattern: &'a Pattern<T>) -> Self {
+        Self { pattern, rows: pattern.forward.start(pattern.max_dist), pending: None }
+    }
+
+    /// Consumes the next symbol of the text ending at the given position,
+    /// given the text up to it in reverse order.
+    /// Returns a match that can not be superseded by the following ones, if any.
+    pub fn push<I>(&mut self, end: usize, x: T, backwards: I) -> Option<Match>
+    where
+        I: Iterator<Item=(usize, T)>,
+    {
+        let dist = self.pattern.forward.step(&mut self.rows, x)?;
+        let start = self.pattern.match_start(dist, end, backwards);
+        let found = Match { start, end, distance: dist };
+        match self.pending {
+            Some(pending) if found.start < pending.end => {
+                if found.distance < pending.distance { self.pending = Some(found); }
+                None
+            }
+            pending => {
+                self.pending = Some(found);
+                pending
+            }
+        }
+    }
+
+    /// Returns the last match, if any.
+    pub fn finish(&mut self) -> Option<Match> {
+        self.pending.take()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{Bitap, PatternError, Pattern, Class, Element, parse};
+    use crate::slice::{Levenshtein, Sellers, Match};
+
+    fn words(alphabet: &[u8], max_len: usize) -> Vec<Vec<u8>> {
+        let mut words = vec![vec![]];
+        let mut last = vec![vec![]];
+        for _ in 0 .. max_len {
+            last = last.iter()
+                .flat_map(|w| alphabet.iter().map(move |&x| { let mut w = w.clone(); w.push(x); w }))
+                .collect();
+            words.extend(last.iter().cloned());
+        }
+        words
+    }
+
+    /// Semi-global distances with classes at every text position, without gaps.
+    fn naive_ends(classes: &[Class<u8>], text: &[u8]) -> Vec<usize> {
+        let mut column: Vec<usize> = (0 ..= classes.len()).collect();
+        let mut ends = Vec::new();
+        for &x in text {
+            let mut diag = column[0];
+            for (i, class) in classes.iter().enumerate() {
+                let left = column[i + 1];
+                column[i + 1] = min!(left + 1, column[i] + 1, diag + !class.contains(x) as usize);
+                diag = left;
+            }
+            ends.push(column[classes.len()]);
+        }
+        ends
+    }
+
+    fn classes(pattern: &[u8]) -> Vec<Class<u8>> {
+        parse(pattern).unwrap().into_iter()
+            .map(|e| match e { Element::Class(class) => class, Element::Gap => panic!() })
+            .collect()
+    }
+
+    #[test]
+    fn plain_patterns() {
+        let lev = Levenshtein::new();
+        let patterns = words(b"abc", 4);
+        let texts = words(b"abcd", 5);
+        for max_dist in 0..3 {
+            for pattern in patterns.iter().skip(1) {
+                let compiled = Pattern::new(pattern, max_dist).unwrap();
+                for text in texts.iter().step_by(11) {
+                    let expected = naive_ends(&classes(pattern), text);
+                    let mut rows = compiled.forward.start(max_dist);
+                    for (i, &x) in text.iter().enumerate() {
+                        let dist = compiled.forward.step(&mut rows, x);
+                        let expected = if expected[i] <= max_dist { Some(expected[i]) } else { None };
+                        assert_eq!(dist, expected);
+                    }
+                    let bitap = Bitap::new(pattern, max_dist).unwrap();
+                    for m in bitap.find_iter(text) {
+                        assert_eq!(lev.distance(pattern, &text[m.start .. m.end]), m.distance);
+                    }
+                    let sellers: Vec<usize> = Sellers::new(max_dist).find_iter(pattern, text).map(|m| m.end).collect();
+                    let bitap: Vec<usize> = bitap.find_iter(text).map(|m| m.end).collect();
+                    assert_eq!(bitap.is_empty(), sellers.is_empty());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn class_patterns() {
+        let patterns: [&[u8]; 6] = [b"a.c", b"[ab]c", b"[^a]b", b"[a-c]d", b".", b"a[b-]"];
+        let texts = words(b"abcd-", 4);
+        for max_dist in 0..3 {
+            for &pattern in &patterns {
+                let compiled = Pattern::new(pattern, max_dist).unwrap();
+                for text in &texts {
+                    let expected = naive_ends(&classes(pattern), text);
+                    let mut rows = compiled.forward.start(max_dist);
+                    for (i, &x) in text.iter().enumerate() {
+                        let dist = compiled.forward.step(&mut rows, x);
+                        let expected = if expected[i] <= max_dist { Some(expected[i]) } else { None };
+                        assert_eq!(dist, expected, "{:?} {:?}", pattern, text);
+                    }
+                }
+            }
+        }
+    }
+
+    type Sample<'a> = (&'a [u8], usize, &'a [u8], &'a [(usize, usize, usize)]);
+
+    #[test]
+    fn gaps() {
+        let sample: [Sample; 7] = [
+            (b"ab#cd",   0, b"ab cd",          &[(0, 5, 0)]),
+            (b"ab#cd",   0, b"abcd",           &[(0, 4, 0)]),
+            (b"ab#cd",   0, b"acd ab xx cd",   &[(4, 12, 0)]),
+            (b"ab#cd",   1, b"xb xx cd",       &[(1, 8, 1)]),
+            (b"#ab#",    0, b"xxabxx",         &[(2, 4, 0)]),
+            (b"a#b#c",   0, b"a1b2c",          &[(0, 5, 0)]),
+            (b"time#out",1, b"connection timed out", &[(11, 20, 0)]),
+        ];
+        for &(pattern, max_dist, text, expected) in &sample {
+            let bitap = Bitap::new(pattern, max_dist).unwrap();
+            let found: Vec<(usize, usize, usize)> = bitap.find_iter(text)
+                .map(|m| (m.start, m.end, m.distance))
+                .collect();
+            assert_eq!(found, expected, "{:?}", std::str::from_utf8(pattern));
+        }
+    }
+
+    #[test]
+    fn find_iter() {
+        let bitap = Bitap::new(b"mailbox", 1).unwrap();
+        let found: Vec<Match> = bitap.find_iter(b"mailbox, malbox, mail, mailbox").collect();
+        assert_eq!(found, vec![
+            Match { start: 0, end: 7, distance: 0 },
+            Match { start: 9, end: 15, distance: 1 },
+            Match { start: 23, end: 30, distance: 0 },
+        ]);
+        let bitap = Bitap::new(b"ab", 2).unwrap();
+        assert!(!bitap.is_match(b""));
+        assert!(bitap.is_match(b"x"));
+        let bitap = Bitap::new(b"", 0).unwrap();
+        assert!(!bitap.is_match(b"mailbox"));
+        assert_eq!(bitap.find_iter(b"mailbox").count(), 0);
+    }
+
+    #[test]
+    fn long_pattern() {
+        let pattern: Vec<u8> = b"mailbox".iter().cycle().take(64).copied().collect();
+        let mut text = b"xx".to_vec();
+        text.extend(&pattern);
+        text[30] = b'_';
+        let bitap = Bitap::new(&pattern, 1).unwrap();
+        let found: Vec<Match> = bitap.find_iter(&text).collect();
+        assert_eq!(found, vec![Match { start: 2, end: 66, distance: 1 }]);
+
+        let mut pattern = pattern;
+        pattern.push(b'#');
+        assert!(Bitap::new(&pattern, 1).is_ok());
+        pattern.push(b'x');
+        assert_eq!(Bitap::new(&pattern, 1).err(), Some(PatternError::TooLong));
+    }
+
+    #[test]
+    fn errors() {
+        let sample: [(&[u8], Option<PatternError>); 9] = [
+            (b"ab\\",      Some(PatternError::DanglingEscape)),
+            (b"a[bc",      Some(PatternError::UnclosedClass(1))),
+            (b"a[bc\\]",   Some(PatternError::UnclosedClass(1))),
+            (b"a[^",       Some(PatternError::UnclosedClass(1))),
+            (b"a[z-a]",    Some(PatternError::EmptyClass(1))),
+            (b"a[]]",      None),
+            (b"a[^]",      Some(PatternError::UnclosedClass(1))),
+            (b"a\\[b\\]",  None),
+            (b"[a-]",      None),
+        ];
+        for &(pattern, expected) in &sample {
+            assert_eq!(Bitap::new(pattern, 1).err(), expected, "{:?}", std::str::from_utf8(pattern));
+        }
+        assert_eq!(PatternError::UnclosedClass(3).to_string(), "unclosed character class at 3");
+    }
+
+    #[test]
+    fn escapes() {
+        let bitap = Bitap::new(b"a\\.b\\#", 0).unwrap();
+        assert!(bitap.is_match(b"xa.b#"));
+        assert!(!bitap.is_match(b"xaxb#"));
+        let bitap = Bitap::new(b"[\\]x]", 0).unwrap();
+        assert!(bitap.is_match(b"]"));
+        assert!(bitap.is_match(b"x"));
+        assert!(!bitap.is_match(b"\\"));
+    }
+}
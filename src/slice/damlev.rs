@@ -1,12 +1,10 @@
-use std::cmp::max;
-use std::collections::BTreeMap;
-use std::cell::RefCell;
+use core::cmp::max;
+use alloc::collections::BTreeMap;
+use core::cell::RefCell;
 use crate::utils::common_affix_sizes;
 use super::matrix::DistMatrix;
 
 
-const DEFAULT_CAPACITY: usize = 20;
-
 /// # Damerau-Levenshtein distance.
 ///
 /// See [the detailed description][1].
@@ -56,6 +54,7 @@ pub struct DamerauLevenshtein<T: PartialEq + Copy + Ord> {
 impl<T: PartialEq + Copy + Ord> DamerauLevenshtein<T> {
     /// Creates a new instance of DamerauLevenshtein struct with
     /// an internal state for the metric methods to reuse.
+    /// The state is allocated on the first use, so this can be called in a `const` context.
     ///
     /// # Example
     ///
@@ -64,8 +63,8 @@ impl<T: PartialEq + Copy + Ord> DamerauLevenshtein<T> {
     ///
     /// let damlev: DamerauLevenshtein<usize> = DamerauLevenshtein::new();
     /// ```
-    pub fn new() -> Self {
-        let dists   = RefCell::new(DistMatrix::new(DEFAULT_CAPACITY + 2));
+    pub const fn new() -> Self {
+        let dists   = RefCell::new(DistMatrix::empty());
         let last_i1 = RefCell::new(BTreeMap::new());
         Self { dists, last_i1 }
     }
@@ -83,40 +82,9 @@ impl<T: PartialEq + Copy + Ord> DamerauLevenshtein<T> {
     /// assert_eq!(dist, 1);
     /// ```
     pub fn distance(&self, slice1: &[T], slice2: &[T]) -> usize {
-        let (prefix, postfix) = common_affix_sizes(slice1, slice2);
-        let mut slice1 = { let len = slice1.len(); &slice1[prefix .. len - postfix] };
-        let mut slice2 = { let len = slice2.len(); &slice2[prefix .. len - postfix] };
-        if slice2.len() < slice1.len() {
-            std::mem::swap(&mut slice1, &mut slice2);
-        }
-
-        let dists = &mut *self.dists.borrow_mut();
-        dists.grow(max(slice1.len() + 2, slice2.len() + 2));
-
+        let dists   = &mut *self.dists.borrow_mut();
         let last_i1 = &mut *self.last_i1.borrow_mut();
-        last_i1.clear();
-
-        for (i1, &x1) in slice1.iter().enumerate() {
-            let mut l2 = 0;
-
-            for (i2, &x2) in slice2.iter().enumerate() {
-                let l1 = *last_i1.get(&x2).unwrap_or(&0);
-
-                unsafe {
-                    dists.set(i1 + 2, i2 + 2, min!(
-                        dists.get(i1 + 2, i2 + 1) + 1,
-                        dists.get(i1 + 1, i2 + 2) + 1,
-                        dists.get(i1 + 1, i2 + 1) + (x1 != x2) as usize,
-                        dists.get(l1, l2) + (i1 - l1) + (i2 - l2) + 1
-                    ));
-                }
-
-                if x1 == x2 { l2 = i2 + 1; }
-            }
-            last_i1.insert(x1, i1 + 1);
-        }
-
-        unsafe { dists.get(slice1.len() + 1, slice2.len() + 1) }
+        damerau_levenshtein(dists, last_i1, slice1, slice2)
     }
 
     /// Relative distance metric. Returns a number of edits relative to the length of
@@ -153,6 +121,47 @@ impl<T: PartialEq + Copy + Ord> DamerauLevenshtein<T> {
 }
 
 
+pub(crate) fn damerau_levenshtein<T: PartialEq + Copy + Ord>(
+    dists:   &mut DistMatrix,
+    last_i1: &mut BTreeMap<T, usize>,
+    slice1:  &[T],
+    slice2:  &[T],
+) -> usize {
+    let (prefix, postfix) = common_affix_sizes(slice1, slice2);
+    let mut slice1 = { let len = slice1.len(); &slice1[prefix .. len - postfix] };
+    let mut slice2 = { let len = slice2.len(); &slice2[prefix .. len - postfix] };
+    if slice2.len() < slice1.len() {
+        core::mem::swap(&mut slice1, &mut slice2);
+    }
+
+    dists.grow(max(slice1.len() + 2, slice2.len() + 2));
+
+    last_i1.clear();
+
+    for (i1, &x1) in slice1.iter().enumerate() {
+        let mut l2 = 0;
+
+        for (i2, &x2) in slice2.iter().enumerate() {
+            let l1 = *last_i1.get(&x2).unwrap_or(&0);
+
+            unsafe {
+                dists.set(i1 + 2, i2 + 2, min!(
+                    dists.get(i1 + 2, i2 + 1) + 1,
+                    dists.get(i1 + 1, i2 + 2) + 1,
+                    dists.get(i1 + 1, i2 + 1) + (x1 != x2) as usize,
+                    dists.get(l1, l2) + (i1 - l1) + (i2 - l2) + 1
+                ));
+            }
+
+            if x1 == x2 { l2 = i2 + 1; }
+        }
+        last_i1.insert(x1, i1 + 1);
+    }
+
+    unsafe { dists.get(slice1.len() + 1, slice2.len() + 1) }
+}
+
+
 #[cfg(test)]
 mod tests {
     use super::DamerauLevenshtein;
@@ -1,11 +1,8 @@
-use std::cmp::min;
-use std::ops::Range;
-use std::cell::RefCell;
+use core::cmp::min;
+use core::ops::Range;
+use core::cell::RefCell;
 use crate::utils::common_prefix_size;
-use crate::utils::Zippable;
-
-
-const DEFAULT_CAPACITY: usize = 25;
+use crate::utils::{Zippable, SmallVec, STACK_SIZE};
 
 
 /// # Jaro similarity.
@@ -35,15 +32,34 @@ const DEFAULT_CAPACITY: usize = 25;
 /// let dist = jaro.rel_dist(s1, s2);
 /// assert_eq!(dist, 1.0 - sim);
 /// ```
-pub struct Jaro {
-    matches1: RefCell<Vec<bool>>,
-    matches2: RefCell<Vec<bool>>,
+///
+/// # Stack buffers
+///
+/// Slices up to `N` items long (32 by default) are compared without heap allocations.
+/// Longer slices spill to the heap. Without the `alloc` feature there is no heap to spill to,
+/// so the metric methods panic on them, and the metric is created only with `with_stack`
+/// to choose the buffer size explicitly.
+/// The metric can also be created in a `const` context, e.g. for a `thread_local!`:
+/// ```rust
+/// use eddie::slice::Jaro;
+///
+/// thread_local! {
+///     static JARO: Jaro<64> = const { Jaro::with_stack() };
+/// }
+///
+/// let sim = JARO.with(|jaro| jaro.similarity(&[1, 2, 3, 4, 5], &[1, 3, 2, 4, 5]));
+/// assert!((sim - 0.93).abs() < 0.01);
+/// ```
+pub struct Jaro<const N: usize = STACK_SIZE> {
+    matches1: RefCell<SmallVec<bool, N>>,
+    matches2: RefCell<SmallVec<bool, N>>,
 }
 
 
 impl Jaro {
     /// Creates a new instance of Jaro struct with an internal state
     /// for the metric methods to reuse.
+    /// Only available with the `alloc` feature, use `with_stack` without it.
     ///
     /// # Example
     ///
@@ -52,16 +68,38 @@ impl Jaro {
     ///
     /// let jaro = Jaro::new();
     /// ```
-    pub fn new() -> Self {
+    #[cfg(feature = "alloc")]
+    pub const fn new() -> Self {
+        Self::with_stack()
+    }
+}
+
+
+impl<const N: usize> Jaro<N> {
+    /// Creates a new instance of Jaro struct
+    /// with stack buffers for slices up to `N` items long.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::slice::Jaro;
+    ///
+    /// let jaro = Jaro::<64>::with_stack();
+    /// ```
+    pub const fn with_stack() -> Self {
         Self {
-            matches1: RefCell::new(Vec::with_capacity(DEFAULT_CAPACITY)),
-            matches2: RefCell::new(Vec::with_capacity(DEFAULT_CAPACITY)),
+            matches1: RefCell::new(SmallVec::new()),
+            matches2: RefCell::new(SmallVec::new()),
         }
     }
 
     /// Similarity metric. Reflects how close two slices are,
     /// ranging from 1.0 (equality) to 0.0 (nothing in common).
     ///
+    /// # Panics
+    ///
+    /// Without the `alloc` feature, may panic if a slice is longer than `N` items.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -71,70 +109,19 @@ impl Jaro {
     /// assert!((sim - 0.93).abs() < 0.01);
     /// ```
     pub fn similarity<T: PartialEq + Copy>(&self, slice1: &[T], slice2: &[T]) -> f64 {
-        match (slice1.len(), slice2.len()) {
-            (0, 0) => { return 1.0; }
-            (_, 0) => { return 0.0; }
-            (0, _) => { return 0.0; }
-            (_, _) => { }
-        }
-
-        let prefix = common_prefix_size(slice1, slice2);
-        let mut slice1 = &slice1[prefix..];
-        let mut slice2 = &slice2[prefix..];
-        if slice2.len() < slice1.len() {
-            std::mem::swap(&mut slice1, &mut slice2);
-        }
-
         let matches1 = &mut *self.matches1.borrow_mut();
         let matches2 = &mut *self.matches2.borrow_mut();
-        matches1.clear();
-        matches2.clear();
-        matches1.resize(slice1.len(), false);
-        matches2.resize(slice2.len(), false);
-
-        let mut matches = 0;
-
-        let len1 = slice1.len();
-        let len2 = slice2.len();
-        let i2_range = max!(1, (len1 + prefix) / 2, (len2 + prefix) / 2) - 1;
-        let mut i1 = 0;
-
-        for (x1, match1) in (slice1, &mut matches1[..]).zip() {
-            let rng = get_range(i1, i2_range, len2);
-            if rng.start >= rng.end { continue; }
-            for (x2, match2) in (&slice2[rng.clone()], &mut matches2[rng]).zip() {
-                if !*match2 && x1 == x2 {
-                    *match1 = true;
-                    *match2 = true;
-                    matches += 1;
-                    break;
-                }
-            }
-            i1 += 1;
-        }
-
-        if prefix + matches == 0 { return 0.0; }
-
-        let trans = if matches == 0 { 0 } else {
-            let matched1 = (slice1, matches1).zip().filter_map(|(x, m)| some_if(*m, *x));
-            let matched2 = (slice2, matches2).zip().filter_map(|(x, m)| some_if(*m, *x));
-            (matched1, matched2).zip()
-                .filter(|(x1, x2)| x1 != x2)
-                .count()
-        };
-
-        let matches = (prefix + matches) as f64;
-        let trans = trans as f64;
-        let len1 = (prefix + len1) as f64;
-        let len2 = (prefix + len2) as f64;
-
-        (matches/len1 + matches/len2 + ((matches - trans/2.) / matches)) / 3.
+        jaro(matches1, matches2, slice1, slice2)
     }
 
     /// Relative distance metric. Inversion of similarity.
     /// Reflects how far apart two slices are,
     /// ranging from 0.0 (equality) to 1.0 (nothing in common).
     ///
+    /// # Panics
+    ///
+    /// Without the `alloc` feature, may panic if a slice is longer than `N` items.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -162,9 +149,73 @@ fn some_if<T>(cond: bool, val: T) -> Option<T> {
 }
 
 
+pub(crate) fn jaro<T: PartialEq + Copy, const N: usize>(
+    matches1: &mut SmallVec<bool, N>,
+    matches2: &mut SmallVec<bool, N>,
+    slice1:   &[T],
+    slice2:   &[T],
+) -> f64 {
+    match (slice1.len(), slice2.len()) {
+        (0, 0) => { return 1.0; }
+        (_, 0) => { return 0.0; }
+        (0, _) => { return 0.0; }
+        (_, _) => { }
+    }
+
+    let prefix = common_prefix_size(slice1, slice2);
+    let mut slice1 = &slice1[prefix..];
+    let mut slice2 = &slice2[prefix..];
+    if slice2.len() < slice1.len() {
+        core::mem::swap(&mut slice1, &mut slice2);
+    }
+
+    matches1.resize(slice1.len(), false);
+    matches2.resize(slice2.len(), false);
+
+    let mut matches = 0;
+
+    let len1 = slice1.len();
+    let len2 = slice2.len();
+    let i2_range = max!(1, (len1 + prefix) / 2, (len2 + prefix) / 2) - 1;
+    let mut i1 = 0;
+
+    for (x1, match1) in (slice1, &mut matches1[..]).zip() {
+        let rng = get_range(i1, i2_range, len2);
+        if rng.start >= rng.end { continue; }
+        for (x2, match2) in (&slice2[rng.clone()], &mut matches2[rng]).zip() {
+            if !*match2 && x1 == x2 {
+                *match1 = true;
+                *match2 = true;
+                matches += 1;
+                break;
+            }
+        }
+        i1 += 1;
+    }
+
+    if prefix + matches == 0 { return 0.0; }
+
+    let trans = if matches == 0 { 0 } else {
+        let matched1 = (slice1, &matches1[..]).zip().filter_map(|(x, m)| some_if(*m, *x));
+        let matched2 = (slice2, &matches2[..]).zip().filter_map(|(x, m)| some_if(*m, *x));
+        (matched1, matched2).zip()
+            .filter(|(x1, x2)| x1 != x2)
+            .count()
+    };
+
+    let matches = (prefix + matches) as f64;
+    let trans = trans as f64;
+    let len1 = (prefix + len1) as f64;
+    let len2 = (prefix + len2) as f64;
+
+    (matches/len1 + matches/len2 + ((matches - trans/2.) / matches)) / 3.
+}
+
+
 #[cfg(test)]
 mod tests {
     use super::Jaro;
+    use crate::utils::STACK_SIZE;
 
     fn floor3(num: f64) -> f64 {
         let p = 10usize.pow(3) as f64;
@@ -174,7 +225,7 @@ mod tests {
 
     #[test]
     fn equality() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (1., vec![]),
             (1., vec![1]),
@@ -189,7 +240,7 @@ mod tests {
 
     #[test]
     fn inequality() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0., vec![1],       vec![2]),
             (0., vec![1, 1],    vec![2, 2]),
@@ -203,7 +254,7 @@ mod tests {
 
     #[test]
     fn prefix() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0.916, vec![1, 2, 3, 4], vec![1, 2, 3]),
             (0.833, vec![1, 2, 3, 4], vec![1, 2]),
@@ -218,7 +269,7 @@ mod tests {
 
     #[test]
     fn postfix() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0.916, vec![1, 2, 3, 4], vec![2, 3, 4]),
             (0.000, vec![1, 2, 3, 4], vec![3, 4]),
@@ -233,7 +284,7 @@ mod tests {
 
     #[test]
     fn match_distance() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0.000, vec![1, 2, 3, 4, 5], vec![3, 0, 0, 0, 0]),
             (0.466, vec![1, 2, 3, 4, 5], vec![0, 3, 0, 0, 0]),
@@ -249,7 +300,7 @@ mod tests {
 
     #[test]
     fn add_del_continuous() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0.933, vec![1, 2, 3, 4], vec![0, 1, 2, 3, 4]),
             (0.888, vec![1, 2, 3, 4], vec![0, 0, 1, 2, 3, 4]),
@@ -275,7 +326,7 @@ mod tests {
 
     #[test]
     fn sub_continuous() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0.833, vec![1, 2, 3, 4], vec![1, 2, 3, 0]),
             (0.666, vec![1, 2, 3, 4], vec![1, 2, 0, 0]),
@@ -294,7 +345,7 @@ mod tests {
 
     #[test]
     fn add_del_intermittent() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0.933, vec![1, 2, 3, 4], vec![1, 2, 3, 4, 0]),
             (0.888, vec![1, 2, 3, 4], vec![1, 2, 3, 0, 4, 0]),
@@ -314,7 +365,7 @@ mod tests {
 
     #[test]
     fn sub_intermittent() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0.833, vec![1, 2, 3, 4], vec![1, 2, 3, 0]),
             (0.666, vec![1, 2, 3, 4], vec![1, 0, 3, 0]),
@@ -331,7 +382,7 @@ mod tests {
 
     #[test]
     fn transpose() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0.916, vec![1, 2, 3, 4], vec![2, 1, 3, 4]),
             (0.916, vec![1, 2, 3, 4], vec![1, 2, 4, 3]),
@@ -346,7 +397,7 @@ mod tests {
 
     #[test]
     fn rel_dist() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0.000, vec![],           vec![]),
             (1.000, vec![1, 2, 3, 4], vec![]),
@@ -366,8 +417,9 @@ mod tests {
 
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn growth() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         for len in (1..1001).step_by(100) {
             let mut v1 = Vec::with_capacity(len);
             let mut v2 = Vec::with_capacity(len);
@@ -1,4 +1,5 @@
 use crate::slice::Jaro;
+use crate::utils::STACK_SIZE;
 
 
 const MAX_PREFIX: usize = 4;
@@ -35,15 +36,34 @@ const DEFAULT_SCALING: f64 = 0.1;
 /// let dist = jarwin.rel_dist(s1, s2);
 /// assert_eq!(dist, 1.0 - sim);
 /// ```
-pub struct JaroWinkler {
+///
+/// # Stack buffers
+///
+/// Slices up to `N` items long (32 by default) are compared without heap allocations.
+/// Longer slices spill to the heap. Without the `alloc` feature there is no heap to spill to,
+/// so the metric methods panic on them, and the metric is created only with `with_stack`
+/// to choose the buffer size explicitly.
+/// The metric can also be created in a `const` context, e.g. for a `thread_local!`:
+/// ```rust
+/// use eddie::slice::JaroWinkler;
+///
+/// thread_local! {
+///     static JARWIN: JaroWinkler<64> = const { JaroWinkler::with_stack() };
+/// }
+///
+/// let sim = JARWIN.with(|jarwin| jarwin.similarity(&[1, 2, 3, 4, 5], &[1, 3, 2, 4, 5]));
+/// assert!((sim - 0.93).abs() < 0.01);
+/// ```
+pub struct JaroWinkler<const N: usize = STACK_SIZE> {
     scaling: f64,
-    jaro: Jaro,
+    jaro: Jaro<N>,
 }
 
 
 impl JaroWinkler {
     /// Creates a new instance of JaroWinkler struct with an internal state
     /// for the metric methods to reuse.
+    /// Only available with the `alloc` feature, use `with_stack` without it.
     ///
     /// # Example
     ///
@@ -52,9 +72,27 @@ impl JaroWinkler {
     ///
     /// let jarwin = JaroWinkler::new();
     /// ```
-    pub fn new() -> JaroWinkler {
+    #[cfg(feature = "alloc")]
+    pub const fn new() -> JaroWinkler {
+        Self::with_stack()
+    }
+}
+
+
+impl<const N: usize> JaroWinkler<N> {
+    /// Creates a new instance of JaroWinkler struct
+    /// with stack buffers for slices up to `N` items long.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::slice::JaroWinkler;
+    ///
+    /// let jarwin = JaroWinkler::<64>::with_stack();
+    /// ```
+    pub const fn with_stack() -> Self {
         let scaling = DEFAULT_SCALING;
-        let jaro = Jaro::new();
+        let jaro = Jaro::with_stack();
         JaroWinkler { scaling, jaro }
     }
 
@@ -85,9 +123,26 @@ impl JaroWinkler {
         self.scaling = scaling;
     }
 
+    /// Returns scaling factor for common prefix score boost.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::slice::JaroWinkler;
+    /// let jarwin = JaroWinkler::new();
+    /// assert_eq!(jarwin.scaling(), 0.1);
+    /// ```
+    pub fn scaling(&self) -> f64 {
+        self.scaling
+    }
+
     /// Similarity metric. Reflects how close two slices are,
     /// ranging from 1.0 (equality) to 0.0 (nothing in common).
     ///
+    /// # Panics
+    ///
+    /// Without the `alloc` feature, may panic if a slice is longer than `N` items.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -98,21 +153,17 @@ impl JaroWinkler {
     /// ```
     pub fn similarity<T: Copy + PartialEq>(&self, slice1: &[T], slice2: &[T]) -> f64 {
         let jaro_dist = self.jaro.similarity(slice1, slice2);
-        if jaro_dist == 0. { return 0.; }
-
-        let prefix_size = slice1.iter()
-            .zip(slice2.iter())
-            .take(MAX_PREFIX)
-            .take_while(|(x1, x2)| x1 == x2)
-            .count() as f64;
-
-        jaro_dist + prefix_size * self.scaling * (1. - jaro_dist)
+        winkler(jaro_dist, self.scaling, slice1, slice2)
     }
 
     /// Relative distance metric. Inversion of similarity.
     /// Reflects how far apart two slices are,
     /// ranging from 0.0 (equality) to 1.0 (nothing in common).
     ///
+    /// # Panics
+    ///
+    /// Without the `alloc` feature, may panic if a slice is longer than `N` items.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -127,9 +178,23 @@ impl JaroWinkler {
 }
 
 
+
+pub(crate) fn winkler<T: Copy + PartialEq>(jaro: f64, scaling: f64, slice1: &[T], slice2: &[T]) -> f64 {
+    if jaro == 0. { return 0.; }
+
+    let prefix_size = slice1.iter()
+        .zip(slice2.iter())
+        .take(MAX_PREFIX)
+        .take_while(|(x1, x2)| x1 == x2)
+        .count() as f64;
+
+    jaro + prefix_size * scaling * (1. - jaro)
+}
+
 #[cfg(test)]
 mod tests {
     use super::{JaroWinkler};
+    use crate::utils::STACK_SIZE;
 
     fn floor3(num: f64) -> f64 {
         let p = 10usize.pow(3) as f64;
@@ -139,7 +204,7 @@ mod tests {
 
     #[test]
     fn equality() {
-        let jarwin = JaroWinkler::new();
+        let jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
         let sample = [
             (1., vec![]),
             (1., vec![1]),
@@ -154,7 +219,7 @@ mod tests {
 
     #[test]
     fn inequality() {
-        let jarwin = JaroWinkler::new();
+        let jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
         let sample = [
             (0., vec![1],       vec![2]),
             (0., vec![1, 1],    vec![2, 2]),
@@ -168,7 +233,7 @@ mod tests {
 
     #[test]
     fn prefix() {
-        let jarwin = JaroWinkler::new();
+        let jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
         let sample = [
             (0.941, vec![1, 2, 3, 4], vec![1, 2, 3]),
             (0.866, vec![1, 2, 3, 4], vec![1, 2]),
@@ -183,7 +248,7 @@ mod tests {
 
     #[test]
     fn postfix() {
-        let jarwin = JaroWinkler::new();
+        let jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
         let sample = [
             (0.916, vec![1, 2, 3, 4], vec![2, 3, 4]),
             (0.000, vec![1, 2, 3, 4], vec![3, 4]),
@@ -198,7 +263,7 @@ mod tests {
 
     #[test]
     fn match_distance() {
-        let jarwin = JaroWinkler::new();
+        let jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
         let sample = [
             (0.000, vec![1, 2, 3, 4, 5], vec![3, 0, 0, 0, 0]),
             (0.466, vec![1, 2, 3, 4, 5], vec![0, 3, 0, 0, 0]),
@@ -214,7 +279,7 @@ mod tests {
 
     #[test]
     fn add_del_continuous() {
-        let jarwin = JaroWinkler::new();
+        let jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
         let sample = [
             (0.933, vec![1, 2, 3, 4], vec![0, 1, 2, 3, 4]),
             (0.888, vec![1, 2, 3, 4], vec![0, 0, 1, 2, 3, 4]),
@@ -240,7 +305,7 @@ mod tests {
 
     #[test]
     fn sub_continuous() {
-        let jarwin = JaroWinkler::new();
+        let jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
         let sample = [
             (0.883, vec![1, 2, 3, 4], vec![1, 2, 3, 0]),
             (0.733, vec![1, 2, 3, 4], vec![1, 2, 0, 0]),
@@ -259,7 +324,7 @@ mod tests {
 
     #[test]
     fn add_del_intermittent() {
-        let jarwin = JaroWinkler::new();
+        let jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
         let sample = [
             (0.960, vec![1, 2, 3, 4], vec![1, 2, 3, 4, 0]),
             (0.922, vec![1, 2, 3, 4], vec![1, 2, 3, 0, 4, 0]),
@@ -279,7 +344,7 @@ mod tests {
 
     #[test]
     fn sub_intermittent() {
-        let jarwin = JaroWinkler::new();
+        let jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
         let sample = [
             (0.883, vec![1, 2, 3, 4], vec![1, 2, 3, 0]),
             (0.700, vec![1, 2, 3, 4], vec![1, 0, 3, 0]),
@@ -296,7 +361,7 @@ mod tests {
 
     #[test]
     fn transpose() {
-        let jarwin = JaroWinkler::new();
+        let jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
         let sample = [
             (0.916, vec![1, 2, 3, 4], vec![2, 1, 3, 4]),
             (0.933, vec![1, 2, 3, 4], vec![1, 2, 4, 3]),
@@ -310,7 +375,7 @@ mod tests {
 
     #[test]
     fn rel_dist() {
-        let jarwin = JaroWinkler::new();
+        let jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
         let sample = [
             (0.000, vec![],           vec![]),
             (1.000, vec![1, 2, 3, 4], vec![]),
@@ -330,8 +395,9 @@ mod tests {
 
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn growth() {
-        let jarwin = JaroWinkler::new();
+        let jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
         for len in (1..1001).step_by(100) {
             let mut v1 = Vec::with_capacity(len);
             let mut v2 = Vec::with_capacity(len);
@@ -1,7 +1,5 @@
-use crate::utils::{common_affix_sizes, Buffer};
-
-
-const DEFAULT_CAPACITY: usize = 25;
+use core::cell::RefCell;
+use crate::utils::{common_affix_sizes, SmallVec, STACK_SIZE};
 
 
 /// # Levenshtein distance.
@@ -44,14 +42,33 @@ const DEFAULT_CAPACITY: usize = 25;
 /// let sim = lev.similarity(s1, s2);
 /// assert_eq!(sim, 1.0 - rel);
 /// ```
-pub struct Levenshtein {
-    dists: Buffer<usize>,
+///
+/// # Stack buffers
+///
+/// Slices up to `N` items long (32 by default) are compared without heap allocations.
+/// Longer slices spill to the heap. Without the `alloc` feature there is no heap to spill to,
+/// so the metric methods panic on them, and the metric is created only with `with_stack`
+/// to choose the buffer size explicitly.
+/// The metric can also be created in a `const` context, e.g. for a `thread_local!`:
+/// ```rust
+/// use eddie::slice::Levenshtein;
+///
+/// thread_local! {
+///     static LEV: Levenshtein<64> = const { Levenshtein::with_stack() };
+/// }
+///
+/// let dist = LEV.with(|lev| lev.distance(&[1, 2, 3], &[1, 3, 2]));
+/// assert_eq!(dist, 2);
+/// ```
+pub struct Levenshtein<const N: usize = STACK_SIZE> {
+    dists: RefCell<SmallVec<usize, N>>,
 }
 
 
 impl Levenshtein {
     /// Creates a new instance of Levenshtein struct with an internal state
     /// for the metric methods to reuse.
+    /// Only available with the `alloc` feature, use `with_stack` without it.
     ///
     /// # Example
     ///
@@ -60,14 +77,36 @@ impl Levenshtein {
     ///
     /// let lev: Levenshtein = Levenshtein::new();
     /// ```
-    pub fn new() -> Self {
-        Self { dists: Buffer::with_capacity(DEFAULT_CAPACITY + 1) }
+    #[cfg(feature = "alloc")]
+    pub const fn new() -> Self {
+        Self::with_stack()
+    }
+}
+
+
+impl<const N: usize> Levenshtein<N> {
+    /// Creates a new instance of Levenshtein struct
+    /// with stack buffers for slices up to `N` items long.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::slice::Levenshtein;
+    ///
+    /// let lev = Levenshtein::<64>::with_stack();
+    /// ```
+    pub const fn with_stack() -> Self {
+        Self { dists: RefCell::new(SmallVec::new()) }
     }
 
     /// Distance metric. Returns a number of edits
     /// (character additions, deletions, and substitutions)
     /// required to transform one slice into the other.
     ///
+    /// # Panics
+    ///
+    /// Without the `alloc` feature, may panic if a slice is longer than `N` items.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -77,39 +116,16 @@ impl Levenshtein {
     /// assert_eq!(dist, 2);
     /// ```
     pub fn distance<T: PartialEq + Copy>(&self, slice1: &[T], slice2: &[T]) -> usize {
-        let (prefix, postfix) = common_affix_sizes(slice1, slice2);
-        let mut slice1 = { let len = slice1.len(); &slice1[prefix .. len - postfix] };
-        let mut slice2 = { let len = slice2.len(); &slice2[prefix .. len - postfix] };
-        if slice2.len() < slice1.len() {
-            std::mem::swap(&mut slice1, &mut slice2);
-        }
-
-        let dists = &mut *self.dists.store(1 .. slice2.len() + 2).borrow_mut();
-
-        let mut dist = slice2.len();
-        let mut prev;
-
-        for (i1, x1) in slice1.iter().enumerate() {
-            dist = i1 + 1;
-            prev = i1;
-
-            for (x2, prev2) in slice2.iter().zip(dists.iter_mut()) {
-                dist = min!(
-                    dist + 1,
-                    *prev2 + 1,
-                    prev + (x1 != x2) as usize
-                );
-                prev = *prev2;
-                *prev2 = dist;
-            }
-        }
-
-        dist
+        levenshtein(&mut self.dists.borrow_mut(), slice1, slice2)
     }
 
     /// Relative distance metric. Returns a number of edits relative to the length of
     /// the longest slice, ranging from 0.0 (equality) to 1.0 (nothing in common).
     ///
+    /// # Panics
+    ///
+    /// Without the `alloc` feature, may panic if a slice is longer than `N` items.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -127,6 +143,10 @@ impl Levenshtein {
     /// Similarity metric. Inversion of relative distance,
     /// ranging from 1.0 (equality) to 0.0 (nothing in common).
     ///
+    /// # Panics
+    ///
+    /// Without the `alloc` feature, may panic if a slice is longer than `N` items.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -141,13 +161,48 @@ impl Levenshtein {
 }
 
 
+pub(crate) fn levenshtein<T, const N: usize>(dists: &mut SmallVec<usize, N>, slice1: &[T], slice2: &[T]) -> usize
+where T: PartialEq + Copy
+{
+    let (prefix, postfix) = common_affix_sizes(slice1, slice2);
+    let mut slice1 = { let len = slice1.len(); &slice1[prefix .. len - postfix] };
+    let mut slice2 = { let len = slice2.len(); &slice2[prefix .. len - postfix] };
+    if slice2.len() < slice1.len() {
+        core::mem::swap(&mut slice1, &mut slice2);
+    }
+
+    dists.fill(1 .. slice2.len() + 1);
+
+    let mut dist = slice2.len();
+    let mut prev;
+
+    for (i1, x1) in slice1.iter().enumerate() {
+        dist = i1 + 1;
+        prev = i1;
+
+        for (x2, prev2) in slice2.iter().zip(dists.iter_mut()) {
+            dist = min!(
+                dist + 1,
+                *prev2 + 1,
+                prev + (x1 != x2) as usize
+            );
+            prev = *prev2;
+            *prev2 = dist;
+        }
+    }
+
+    dist
+}
+
+
 #[cfg(test)]
 mod tests {
     use super::Levenshtein;
+    use crate::utils::STACK_SIZE;
 
     #[test]
     fn equality() {
-        let leven = Levenshtein::new();
+        let leven = Levenshtein::<STACK_SIZE>::with_stack();
         let sample = [
             vec![],
             vec![1],
@@ -161,7 +216,7 @@ mod tests {
 
     #[test]
     fn prefix() {
-        let leven = Levenshtein::new();
+        let leven = Levenshtein::<STACK_SIZE>::with_stack();
         let sample = [
             (0, vec![1, 2, 3], vec![1, 2, 3]),
             (1, vec![1, 2, 3], vec![1, 2]),
@@ -176,7 +231,7 @@ mod tests {
 
     #[test]
     fn add_del_continuous() {
-        let leven = Levenshtein::new();
+        let leven = Levenshtein::<STACK_SIZE>::with_stack();
         let sample = [
             (1, vec![1, 2, 3], vec![0, 1, 2, 3]),
             (2, vec![1, 2, 3], vec![0, 0, 1, 2, 3]),
@@ -198,7 +253,7 @@ mod tests {
 
     #[test]
     fn sub_continuous() {
-        let leven = Levenshtein::new();
+        let leven = Levenshtein::<STACK_SIZE>::with_stack();
         let sample = [
             (1, vec![1, 2, 3, 4], vec![0, 2, 3, 4]),
             (2, vec![1, 2, 3, 4], vec![0, 0, 3, 4]),
@@ -218,7 +273,7 @@ mod tests {
 
     #[test]
     fn trans_continuous() {
-        let leven = Levenshtein::new();
+        let leven = Levenshtein::<STACK_SIZE>::with_stack();
         let sample = [
             (2, vec![1, 2, 3, 4], vec![2, 1, 3, 4]), // swap 1 and 2
             (3, vec![1, 2, 3, 4], vec![2, 1, 4, 3]), // swap 3 and 4
@@ -231,7 +286,7 @@ mod tests {
 
     #[test]
     fn add_del_intermittent() {
-        let leven = Levenshtein::new();
+        let leven = Levenshtein::<STACK_SIZE>::with_stack();
         let sample = [
             (1, vec![1, 2, 3], vec![0, 1, 2, 3]),
             (2, vec![1, 2, 3], vec![0, 1, 0, 2, 3]),
@@ -249,7 +304,7 @@ mod tests {
 
     #[test]
     fn sub_intermittent() {
-        let leven = Levenshtein::new();
+        let leven = Levenshtein::<STACK_SIZE>::with_stack();
         let sample = [
             (1, vec![1, 2, 3, 4], vec![0, 2, 3, 4]),
             (2, vec![1, 2, 3, 4], vec![0, 2, 0, 4]),
@@ -264,7 +319,7 @@ mod tests {
 
     #[test]
     fn rel_dist() {
-        let leven = Levenshtein::new();
+        let leven = Levenshtein::<STACK_SIZE>::with_stack();
         let sample = [
             (0.00, vec![],           vec![]),
             (1.00, vec![1, 2, 3, 4], vec![]),
@@ -282,7 +337,7 @@ mod tests {
 
     #[test]
     fn similarity() {
-        let leven = Levenshtein::new();
+        let leven = Levenshtein::<STACK_SIZE>::with_stack();
         let sample = [
             (1.00, vec![],           vec![]),
             (0.00, vec![1, 2, 3, 4], vec![]),
@@ -299,8 +354,9 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn growth() {
-        let leven = Levenshtein::new();
+        let leven = Levenshtein::<STACK_SIZE>::with_stack();
         for len in (1..1001).step_by(100) {
             let mut v1 = Vec::with_capacity(len);
             let mut v2 = Vec::with_capacity(len);
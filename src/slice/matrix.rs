@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 
 pub struct DistMatrix {
     size: usize,
@@ -5,11 +7,8 @@ pub struct DistMatrix {
 }
 
 impl DistMatrix {
-    pub fn new(size: usize) -> Self {
-        let raw = vec![0; size * size];
-        let mut matrix = Self { size, raw };
-        matrix.init();
-        matrix
+    pub const fn empty() -> Self {
+        Self { size: 0, raw: Vec::new() }
     }
 
     pub fn grow(&mut self, size: usize) {
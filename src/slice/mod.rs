@@ -50,14 +50,40 @@
 
 
 mod leven;
+#[cfg(feature = "alloc")]
 mod damlev;
 mod hamming;
 mod jaro;
 mod jarwin;
+#[cfg(feature = "alloc")]
 mod matrix;
+#[cfg(feature = "alloc")]
+mod sellers;
+#[cfg(feature = "alloc")]
+mod bitap;
 
 pub use leven::Levenshtein;
+#[cfg(feature = "alloc")]
 pub use damlev::DamerauLevenshtein;
 pub use hamming::Hamming;
 pub use jaro::Jaro;
 pub use jarwin::JaroWinkler;
+#[cfg(feature = "alloc")]
+pub use sellers::{Sellers, Matches, Match};
+#[cfg(feature = "alloc")]
+pub use bitap::{Bitap, BitapMatches, PatternError};
+
+#[cfg(feature = "alloc")]
+pub(crate) use sellers::Searcher;
+#[cfg(feature = "alloc")]
+pub(crate) use bitap::{Pattern, Scanner};
+#[cfg(feature = "alloc")]
+pub(crate) use matrix::DistMatrix;
+#[cfg(feature = "alloc")]
+pub(crate) use leven::levenshtein;
+#[cfg(feature = "alloc")]
+pub(crate) use damlev::damerau_levenshtein;
+#[cfg(feature = "alloc")]
+pub(crate) use jaro::jaro;
+#[cfg(feature = "alloc")]
+pub(crate) use jarwin::winkler;
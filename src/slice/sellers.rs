@@ -0,0 +1,431 @@
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+
+/// Maximum pattern length for the bit-parallel engine.
+const MAX_BIT_PARALLEL: usize = 64;
+
+
+/// # Approximate substring search.
+///
+/// Finds all occurrences of a pattern in a text within the given Levenshtein distance,
+/// i.e. substrings of the text that can be turned into the pattern
+/// with at most `max_dist` insertions, deletions and substitutions.
+///
+/// Uses the [Sellers algorithm][1], with the bit-parallel engine of [Myers][2]
+/// for patterns up to 64 items long. Start positions of the matches
+/// are recovered with a reverse search over at most `pattern.len() + max_dist` items.
+///
+/// Overlapping matches are reported only once, by the one with the smallest distance
+/// (the first one of those with equal distances, extended as far as possible
+/// without increasing the distance). Among the substrings with the smallest distance
+/// ending at the same position, the longest one is reported.
+///
+/// [1]: https://doi.org/10.1016/0196-6774(80)90002-X
+/// [2]: https://doi.org/10.1145/316542.316550
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::slice::{Sellers, Match};
+///
+/// let sellers = Sellers::new(1);
+/// let text = [1, 2, 3, 4, 5, 1, 2, 4, 5];
+/// let found: Vec<Match> = sellers.find_iter(&[2, 3, 4], &text).collect();
+/// assert_eq!(found, vec![
+///     Match { start: 1, end: 4, distance: 0 },
+///     Match { start: 6, end: 8, distance: 1 },
+/// ]);
+/// ```
+pub struct Sellers {
+    max_dist: usize,
+}
+
+
+/// An occurrence of a pattern found by approximate search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Match {
+    /// Start of the matching substring (inclusive).
+    pub start: usize,
+    /// End of the matching substring (exclusive).
+    pub end: usize,
+    /// Distance between the substring and the pattern.
+    pub distance: usize,
+}
+
+
+impl Sellers {
+    /// Creates a new instance of Sellers struct for the given maximum distance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::slice::Sellers;
+    ///
+    /// let sellers = Sellers::new(2);
+    /// ```
+    pub fn new(max_dist: usize) -> Self {
+        Self { max_dist }
+    }
+
+    /// Returns the maximum distance.
+    pub fn max_dist(&self) -> usize {
+        self.max_dist
+    }
+
+    /// Returns an iterator over the matches of the pattern in the text.
+    /// Match positions are indices in the text.
+    /// Empty pattern does not match anything.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::slice::Sellers;
+    /// let sellers = Sellers::new(1);
+    /// let text = b"mailbox, malbox, mail";
+    /// let found: Vec<(usize, usize, usize)> = sellers.find_iter(b"mailbox", text)
+    ///     .map(|m| (m.start, m.end, m.distance))
+    ///     .collect();
+    /// assert_eq!(found, vec![(0, 7, 0), (9, 15, 1)]);
+    /// ```
+    pub fn find_iter<'a, T: Copy + Ord>(&self, pattern: &[T], text: &'a [T]) -> Matches<'a, T> {
+        Matches {
+            searcher: Searcher::new(pattern, self.max_dist),
+            text: text.iter().enumerate(),
+        }
+    }
+}
+
+
+/// An iterator over the matches found by [`Sellers::find_iter`][1].
+///
+/// [1]: struct.Sellers.html#method.find_iter
+pub struct Matches<'a, T> {
+    searcher: Searcher<T>,
+    text: core::iter::Enumerate<core::slice::Iter<'a, T>>,
+}
+
+
+impl<'a, T: Copy + Ord> Iterator for Matches<'a, T> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        for (i, &x) in &mut self.text {
+            if let Some(found) = self.searcher.push(i, i + 1, x) {
+                return Some(found);
+            }
+        }
+        self.searcher.finish()
+    }
+}
+
+
+/// Streaming approximate search: consumes text items one by one,
+/// and reports the matches ending at them.
+pub struct Searcher<T> {
+    pattern: Vec<T>,
+    max_dist: usize,
+    engine: Engine<T>,
+    // Recent text items with their start positions, enough to recover match starts.
+    window: VecDeque<(usize, T)>,
+    column1: Vec<usize>,
+    column2: Vec<usize>,
+    pending: Option<Match>,
+}
+
+
+impl<T: Copy + Ord> Searcher<T> {
+    pub fn new(pattern: &[T], max_dist: usize) -> Self {
+        let engine = if pattern.len() <= MAX_BIT_PARALLEL {
+            Engine::BitParallel(BitParallel::new(pattern))
+        } else {
+            Engine::Dp(Dp::new(pattern))
+        };
+        Self::with_engine(pattern, max_dist, engine)
+    }
+
+    fn with_engine(pattern: &[T], max_dist: usize, engine: Engine<T>) -> Self {
+        Self {
+            pattern: pattern.to_vec(),
+            max_dist,
+            engine,
+            window: VecDeque::with_capacity(pattern.len() + max_dist + 1),
+            column1: Vec::with_capacity(pattern.len() + 1),
+            column2: Vec::with_capacity(pattern.len() + 1),
+            pending: None,
+        }
+    }
+
+    /// Consumes the next text item spanning positions `start .. end`.
+    /// Returns a match that can not be superseded by the following ones, if any.
+    pub fn push(&mut self, start: usize, end: usize, item: T) -> Option<Match> {
+        if self.pattern.is_empty() { return None; }
+
+        if self.window.len() == self.pattern.len() + self.max_dist {
+            self.window.pop_front();
+        }
+        self.window.push_back((start, item));
+
+        let dist = match &mut self.engine {
+            Engine::BitParallel(engine) => engine.step(item),
+            Engine::Dp(engine) => engine.step(&self.pattern, item),
+        };
+        if dist > self.max_dist { return None; }
+
+        let found = Match { start: self.match_start(dist, end), end, distance: dist };
+        match self.pending {
+            Some(pending) if found.start < pending.end => {
+                let extends = found.distance == pending.distance && found.start == pending.start;
+                if found.distance < pending.distance || extends { self.pending = Some(found); }
+                None
+            }
+            pending => {
+                self.pending = Some(found);
+                pending
+            }
+        }
+    }
+
+    /// Returns the last match, if any.
+    pub fn finish(&mut self) -> Option<Match> {
+        self.pending.take()
+    }
+
+    /// Finds the start of the longest substring ending at the last window item
+    /// that has the given distance to the pattern.
+    fn match_start(&mut self, dist: usize, end: usize) -> usize {
+        let pattern = &self.pattern;
+        let len = pattern.len();
+        let prev = &mut self.column1;
+        let curr = &mut self.column2;
+        prev.clear();
+        prev.extend(0 ..= len);
+        let mut found = end;
+
+        for (j, &(start, x)) in self.window.iter().rev().enumerate() {
+            curr.clear();
+            curr.push(j + 1);
+            for i in 1 ..= len {
+                let y = pattern[len - i];
+                curr.push(min!(prev[i] + 1, curr[i - 1] + 1, prev[i - 1] + (x != y) as usize));
+            }
+            if curr[len] == dist { found = start; }
+            if curr.iter().all(|&d| d > dist) { break; }
+            core::mem::swap(prev, curr);
+        }
+
+        found
+    }
+}
+
+
+enum Engine<T> {
+    BitParallel(BitParallel<T>),
+    Dp(Dp),
+}
+
+
+/// Myers' bit-vector algorithm, for patterns up to 64 items long.
+struct BitParallel<T> {
+    peq: Vec<(T, u64)>,
+    mask: u64,
+    high: u64,
+    pv: u64,
+    mv: u64,
+    score: usize,
+}
+
+
+impl<T: Copy + Ord> BitParallel<T> {
+    fn new(pattern: &[T]) -> Self {
+        let len = pattern.len();
+        let mut peq: Vec<(T, u64)> = Vec::new();
+        for (i, &x) in pattern.iter().enumerate() {
+            match peq.binary_search_by(|(y, _)| y.cmp(&x)) {
+                Ok(j) => peq[j].1 |= 1 << i,
+                Err(j) => peq.insert(j, (x, 1 << i)),
+            }
+        }
+        let mask = if len == 64 { u64::MAX } else { (1 << len) - 1 };
+        let high = if len == 0 { 0 } else { 1 << (len - 1) };
+        Self { peq, mask, high, pv: mask, mv: 0, score: len }
+    }
+
+    fn step(&mut self, x: T) -> usize {
+        let eq = match self.peq.binary_search_by(|(y, _)| y.cmp(&x)) {
+            Ok(j) => self.peq[j].1,
+            Err(_) => 0,
+        };
+        let (pv, mv) = (self.pv, self.mv);
+        let xv = eq | mv;
+        let xh = ((eq & pv).wrapping_add(pv) ^ pv) | eq;
+        let ph = mv | !(xh | pv);
+        let mh = pv & xh;
+        if ph & self.high != 0 {
+            self.score += 1;
+        } else if mh & self.high != 0 {
+            self.score -= 1;
+        }
+        let ph = ph << 1;
+        let mh = mh << 1;
+        self.pv = (mh | !(xv | ph)) & self.mask;
+        self.mv = ph & xv & self.mask;
+        self.score
+    }
+}
+
+
+/// Dynamic programming over a single column, for patterns of any length.
+struct Dp {
+    column: Vec<usize>,
+}
+
+
+impl Dp {
+    fn new<T>(pattern: &[T]) -> Self {
+        Self { column: (0 ..= pattern.len()).collect() }
+    }
+
+    fn step<T: PartialEq>(&mut self, pattern: &[T], x: T) -> usize {
+        let column = &mut self.column;
+        let mut diag = column[0];
+        for (i, y) in pattern.iter().enumerate() {
+            let left = column[i + 1];
+            column[i + 1] = min!(left + 1, column[i] + 1, diag + (*y != x) as usize);
+            diag = left;
+        }
+        column[pattern.len()]
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{Sellers, Searcher, Match, Engine, Dp, BitParallel};
+    use crate::slice::Levenshtein;
+
+    fn words(alphabet: &[u8], max_len: usize) -> Vec<Vec<u8>> {
+        let mut words = vec![vec![]];
+        let mut last = vec![vec![]];
+        for _ in 0 .. max_len {
+            last = last.iter()
+                .flat_map(|w| alphabet.iter().map(move |&x| { let mut w = w.clone(); w.push(x); w }))
+                .collect();
+            words.extend(last.iter().cloned());
+        }
+        words
+    }
+
+    /// Distances of the best matches ending at every text position.
+    fn naive_ends(pattern: &[u8], text: &[u8]) -> Vec<usize> {
+        let lev = Levenshtein::new();
+        (1 ..= text.len())
+            .map(|end| (0 ..= end).map(|start| lev.distance(pattern, &text[start .. end])).min().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn engines() {
+        let patterns = words(b"abc", 4);
+        let texts = words(b"abcd", 5);
+        for pattern in patterns.iter().skip(1) {
+            for text in texts.iter().step_by(7) {
+                let expected = naive_ends(pattern, text);
+                let mut dp = Dp::new(pattern);
+                let mut bits = BitParallel::new(pattern);
+                let dp_ends: Vec<usize> = text.iter().map(|&x| dp.step(pattern, x)).collect();
+                let bits_ends: Vec<usize> = text.iter().map(|&x| bits.step(x)).collect();
+                assert_eq!(dp_ends, expected);
+                assert_eq!(bits_ends, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn long_pattern() {
+        let pattern: Vec<u8> = b"mailbox".iter().cycle().take(64).copied().collect();
+        let mut text = b"xx".to_vec();
+        text.extend(&pattern);
+        text.extend(b"yy");
+        text.remove(20);
+        let expected = naive_ends(&pattern, &text);
+        let mut bits = BitParallel::new(&pattern);
+        let ends: Vec<usize> = text.iter().map(|&x| bits.step(x)).collect();
+        assert_eq!(ends, expected);
+
+        let pattern: Vec<u8> = b"mailbox".iter().cycle().take(100).copied().collect();
+        let mut text = pattern.clone();
+        text[50] = b'_';
+        text.insert(0, b'_');
+        let sellers = Sellers::new(2);
+        let found: Vec<Match> = sellers.find_iter(&pattern, &text).collect();
+        assert_eq!(found, vec![Match { start: 1, end: 101, distance: 1 }]);
+    }
+
+    #[test]
+    fn starts() {
+        // Every match is a substring at the reported distance,
+        // and there are no substrings closer to the pattern ending at the same position.
+        let lev = Levenshtein::new();
+        let patterns = words(b"abc", 3);
+        let texts = words(b"abc", 6);
+        for max_dist in 0..3 {
+            for pattern in patterns.iter().skip(1) {
+                for text in texts.iter().step_by(11) {
+                    let ends = naive_ends(pattern, text);
+                    let found: Vec<Match> = Sellers::new(max_dist).find_iter(pattern, text).collect();
+                    for m in &found {
+                        assert_eq!(lev.distance(pattern, &text[m.start .. m.end]), m.distance);
+                        assert_eq!(ends[m.end - 1], m.distance);
+                    }
+                    for w in found.windows(2) {
+                        assert!(w[0].end <= w[1].start);
+                    }
+                    let has_matches = ends.iter().any(|&d| d <= max_dist);
+                    assert_eq!(found.is_empty(), !has_matches);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn engines_agree() {
+        let pattern = b"abcab";
+        let texts = words(b"abc", 7);
+        for max_dist in 0..3 {
+            for text in texts.iter().step_by(13) {
+                let mut dp = Searcher::with_engine(pattern, max_dist, Engine::Dp(Dp::new(pattern)));
+                let mut bits = Searcher::new(pattern, max_dist);
+                let mut dp_found = Vec::new();
+                let mut bits_found = Vec::new();
+                for (i, &x) in text.iter().enumerate() {
+                    dp_found.extend(dp.push(i, i + 1, x));
+                    bits_found.extend(bits.push(i, i + 1, x));
+                }
+                dp_found.extend(dp.finish());
+                bits_found.extend(bits.finish());
+                assert_eq!(dp_found, bits_found);
+            }
+        }
+    }
+
+    #[test]
+    fn dedup() {
+        let sellers = Sellers::new(1);
+        type Sample<'a> = (&'a [u8], &'a [u8], &'a [(usize, usize, usize)]);
+        let sample: [Sample; 6] = [
+            (b"abc", b"xabcx",     &[(1, 4, 0)]),
+            (b"aaa", b"aaaaaaa",   &[(0, 3, 0), (3, 6, 0)]),
+            (b"abc", b"abcabc",    &[(0, 3, 0), (3, 6, 0)]),
+            (b"abc", b"abxabc",    &[(0, 3, 1), (3, 6, 0)]),
+            (b"abc", b"",          &[]),
+            (b"",    b"abc",       &[]),
+        ];
+        for &(pattern, text, expected) in &sample {
+            let found: Vec<(usize, usize, usize)> = sellers.find_iter(pattern, text)
+                .map(|m| (m.start, m.end, m.distance))
+                .collect();
+            assert_eq!(found, expected);
+        }
+    }
+}
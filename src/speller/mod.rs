@@ -0,0 +1,524 @@
+//! Spelling correction based on a frequency dictionary.
+//!
+//!
+//! # Basic Usage
+//!
+//! ```rust
+//! use eddie::speller::Speller;
+//!
+//! let mut speller = Speller::new(2);
+//! speller.insert("the", 1000);
+//! speller.insert("mailbox", 10);
+//! speller.insert("is", 500);
+//! speller.insert("full", 50);
+//!
+//! assert_eq!(speller.correct("Teh mailbx is ful!"), "The mailbox is full!");
+//! ```
+
+
+mod text;
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+use crate::index::DeletionIndex;
+use text::{tokens, Case};
+
+
+const DEFAULT_ERROR_RATE: f64 = 0.01;
+
+/// Weight of bigram probabilities when interpolated with unigram ones.
+const BIGRAM_WEIGHT: f64 = 0.8;
+
+
+/// # Spelling corrector.
+///
+/// Suggests corrections for misspelled words using a dictionary of words with frequencies,
+/// and optionally frequencies of word pairs (bigrams).
+///
+/// Candidates within the maximum distance from the word are found with a
+/// [symmetric deletion index][1] and ranked by the noisy channel model:
+/// the probability of the word given the candidate (the error rate raised to the power
+/// of the optimal string alignment distance between them), times the probability
+/// of the candidate (its relative frequency, interpolated with the bigram
+/// probability given the previous word, if any).
+///
+/// Words are stored in lower case.
+///
+/// [1]: ../index/struct.DeletionIndex.html
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::speller::Speller;
+///
+/// let mut speller = Speller::new(2);
+/// speller.insert("martha", 10);
+/// speller.insert("marta", 50);
+/// speller.insert("mailbox", 30);
+///
+/// let suggestions: Vec<&str> = speller.suggest("marhta")
+///     .iter()
+///     .map(|c| c.word)
+///     .collect();
+/// assert_eq!(suggestions, vec!["marta", "martha"]);
+/// ```
+pub struct Speller {
+    index: DeletionIndex,
+    bigrams: HashMap<String, HashMap<String, u64>>,
+    total: u64,
+    error_rate: f64,
+}
+
+
+/// A correction suggested by [`Speller`][1].
+///
+/// [1]: struct.Speller.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Candidate<'a> {
+    /// The word from the dictionary.
+    pub word: &'a str,
+    /// Its distance to the misspelled word.
+    pub distance: usize,
+    /// Its frequency in the dictionary.
+    pub frequency: u64,
+    /// Logarithm of its noisy channel score, the greater the better.
+    pub score: f64,
+}
+
+
+impl Speller {
+    /// Creates a new speller with an empty dictionary,
+    /// suggesting corrections within the given maximum distance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::speller::Speller;
+    ///
+    /// let speller = Speller::new(2);
+    /// ```
+    pub fn new(max_dist: usize) -> Self {
+        Self {
+            index: DeletionIndex::new(max_dist),
+            bigrams: HashMap::new(),
+            total: 0,
+            error_rate: DEFAULT_ERROR_RATE,
+        }
+    }
+
+    /// Creates a new speller with a dictionary loaded from a text file.
+    /// See [`load`][1] for the file format.
+    ///
+    /// [1]: #method.load
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use eddie::speller::Speller;
+    ///
+    /// let speller = Speller::from_file("words.txt", 2).unwrap();
+    /// ```
+    pub fn from_file<P: AsRef<Path>>(path: P, max_dist: usize) -> io::Result<Self> {
+        let mut speller = Self::new(max_dist);
+        speller.load(BufReader::new(File::open(path)?))?;
+        Ok(speller)
+    }
+
+    /// Sets the probability of a single edit.
+    /// Default value is 0.01.
+    /// Panics if it's not in range `(0.0, 1.0)`.
+    ///
+    /// The lower it is, the more candidates at smaller distances
+    /// are preferred to more frequent ones.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::speller::Speller;
+    /// let mut speller = Speller::new(2);
+    /// speller.insert("mail", 1);
+    /// speller.insert("mailbox", 1000);
+    /// assert_eq!(speller.suggest("mailb")[0].word, "mailbox");
+    ///
+    /// speller.set_error_rate(0.0001);
+    /// assert_eq!(speller.suggest("mailb")[0].word, "mail");
+    /// ```
+    pub fn set_error_rate(&mut self, error_rate: f64) -> &mut Self {
+        assert!(error_rate > 0.0 && error_rate < 1.0, "Error rate should be in range (0.0, 1.0)");
+        self.error_rate = error_rate;
+        self
+    }
+
+    /// Returns a number of words in the dictionary.
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Returns `true` if the dictionary is empty.
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    /// Returns `true` if the word is in the dictionary, ignoring case.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::speller::Speller;
+    /// let mut speller = Speller::new(2);
+    /// speller.insert("Martha", 10);
+    /// assert!(speller.contains("martha"));
+    /// assert!(speller.contains("MARTHA"));
+    /// ```
+    pub fn contains(&self, word: &str) -> bool {
+        self.index.frequency(&word.to_lowercase()).is_some()
+    }
+
+    /// Adds a word to the dictionary, or increases its frequency.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::speller::Speller;
+    /// let mut speller = Speller::new(2);
+    /// speller.insert("martha", 10);
+    /// speller.insert("martha", 5);
+    /// assert_eq!(speller.suggest("marhta")[0].frequency, 15);
+    /// ```
+    pub fn insert(&mut self, word: &str, frequency: u64) {
+        self.index.insert(&word.to_lowercase(), frequency);
+        self.total = self.total.saturating_add(frequency);
+    }
+
+    /// Adds a pair of consecutive words to the dictionary, or increases its frequency.
+    /// The words themselves should be added with [`insert`][1].
+    ///
+    /// [1]: #method.insert
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::speller::Speller;
+    /// let mut speller = Speller::new(1);
+    /// speller.insert("mail", 10);
+    /// speller.insert("male", 10);
+    /// speller.insert("box", 10);
+    /// speller.insert_bigram("mail", "box", 5);
+    /// speller.insert_bigram("male", "voice", 5);
+    ///
+    /// assert_eq!(speller.correct("mial box"), "mail box");
+    /// ```
+    pub fn insert_bigram(&mut self, word1: &str, word2: &str, frequency: u64) {
+        let count = self.bigrams
+            .entry(word1.to_lowercase())
+            .or_default()
+            .entry(word2.to_lowercase())
+            .or_insert(0);
+        *count = count.saturating_add(frequency);
+    }
+
+    /// Loads words with frequencies from a reader.
+    ///
+    /// Every line should contain a word and its frequency, separated with whitespace.
+    /// Frequency may be omitted, then it's equal to 1. Empty lines are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::speller::Speller;
+    /// let mut speller = Speller::new(2);
+    /// speller.load("martha 10\nmarta 50\n\nmailbox\n".as_bytes()).unwrap();
+    /// assert_eq!(speller.len(), 3);
+    /// assert!(speller.load("martha ten".as_bytes()).is_err());
+    /// ```
+    pub fn load<R: BufRead>(&mut self, reader: R) -> io::Result<()> {
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            let mut fields = line.split_whitespace();
+            let word = match fields.next() {
+                Some(word) => word,
+                None => continue,
+            };
+            let frequency = match fields.next() {
+                Some(field) => parse_frequency(field, i)?,
+                None => 1,
+            };
+            self.insert(word, frequency);
+        }
+        Ok(())
+    }
+
+    /// Loads pairs of consecutive words with frequencies from a reader.
+    ///
+    /// Every line should contain two words and their frequency, separated with whitespace.
+    /// Frequency may be omitted, then it's equal to 1. Empty lines are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::speller::Speller;
+    /// let mut speller = Speller::new(2);
+    /// speller.load_bigrams("mail box 10\nmale voice 5\n".as_bytes()).unwrap();
+    /// assert!(speller.load_bigrams("mail".as_bytes()).is_err());
+    /// ```
+    pub fn load_bigrams<R: BufRead>(&mut self, reader: R) -> io::Result<()> {
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            let mut fields = line.split_whitespace();
+            let word1 = match fields.next() {
+                Some(word) => word,
+                None => continue,
+            };
+            let word2 = match fields.next() {
+                Some(word) => word,
+                None => return Err(invalid_line(i, "expected two words")),
+            };
+            let frequency = match fields.next() {
+                Some(field) => parse_frequency(field, i)?,
+                None => 1,
+            };
+            self.insert_bigram(word1, word2, frequency);
+        }
+        Ok(())
+    }
+
+    /// Suggests corrections for the word, the best first.
+    /// If the word is in the dictionary, it's suggested too.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::speller::Speller;
+    /// let mut speller = Speller::new(2);
+    /// speller.insert("mailbox", 10);
+    /// speller.insert("mail", 20);
+    ///
+    /// let suggestions = speller.suggest("Mailbx");
+    /// assert_eq!(suggestions[0].word, "mailbox");
+    /// assert_eq!(suggestions[0].distance, 1);
+    /// assert_eq!(suggestions[1].word, "mail");
+    /// ```
+    pub fn suggest(&self, word: &str) -> Vec<Candidate<'_>> {
+        self.rank(None, word)
+    }
+
+    /// Suggests corrections for the word following the given one, the best first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::speller::Speller;
+    /// let mut speller = Speller::new(1);
+    /// speller.insert("the", 100);
+    /// speller.insert("mail", 10);
+    /// speller.insert("male", 20);
+    /// speller.insert_bigram("the", "mail", 5);
+    ///
+    /// assert_eq!(speller.suggest("mal")[0].word, "male");
+    /// assert_eq!(speller.suggest_after("the", "mal")[0].word, "mail");
+    /// ```
+    pub fn suggest_after(&self, prev: &str, word: &str) -> Vec<Candidate<'_>> {
+        self.rank(Some(&prev.to_lowercase()), word)
+    }
+
+    /// Corrects all the misspelled words in the text,
+    /// keeping their case, and all the punctuation and whitespace.
+    /// Words from the dictionary, words with digits,
+    /// and words without suggestions are left intact.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::speller::Speller;
+    /// let mut speller = Speller::new(2);
+    /// speller.load("martha 10\nhas 20\na 50\nmailbox 5\n".as_bytes()).unwrap();
+    ///
+    /// let text = "MARHTA has a mailbx (2 mailboxes).";
+    /// assert_eq!(speller.correct(text), "MARTHA has a mailbox (2 mailbox).");
+    /// ```
+    pub fn correct(&self, text: &str) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut prev: Option<String> = None;
+
+        for (is_word, token) in tokens(text) {
+            if !is_word || token.chars().any(|ch| ch.is_numeric()) {
+                result.push_str(token);
+                if is_word { prev = None; }
+                continue;
+            }
+
+            let word = token.to_lowercase();
+            if self.index.frequency(&word).is_some() {
+                result.push_str(token);
+                prev = Some(word);
+                continue;
+            }
+
+            let best = self.rank(prev.as_deref(), &word).first().map(|c| c.word);
+            match best {
+                Some(best) => {
+                    result.push_str(&Case::of(token).apply(best));
+                    prev = Some(best.to_string());
+                }
+                None => {
+                    result.push_str(token);
+                    prev = None;
+                }
+            }
+        }
+
+        result
+    }
+
+    fn rank(&self, prev: Option<&str>, word: &str) -> Vec<Candidate<'_>> {
+        let word = word.to_lowercase();
+        let mut candidates: Vec<Candidate> = self.index.lookup(&word, self.index.max_dist())
+            .into_iter()
+            .map(|s| Candidate {
+                word: s.word,
+                distance: s.distance,
+                frequency: s.frequency,
+                score: self.score(prev, s.word, s.frequency, s.distance),
+            })
+            .collect();
+        candidates.sort_by(|c1, c2| {
+            c2.score.partial_cmp(&c1.score).unwrap()
+                .then(c1.distance.cmp(&c2.distance))
+                .then(c1.word.cmp(c2.word))
+        });
+        candidates
+    }
+
+    /// Logarithm of the noisy channel score.
+    fn score(&self, prev: Option<&str>, word: &str, frequency: u64, dist: usize) -> f64 {
+        // Add-one smoothing, so that words with zero frequencies are still ranked.
+        let total = self.total as f64 + self.index.len() as f64;
+        let mut prob = (frequency as f64 + 1.0) / total;
+
+        let prev_count = prev.and_then(|prev| self.index.frequency(prev));
+        let bigrams = prev.and_then(|prev| self.bigrams.get(prev));
+        if let (Some(prev_count), Some(bigrams)) = (prev_count, bigrams) {
+            let count = bigrams.get(word).copied().unwrap_or(0);
+            let bigram_prob = count as f64 / max!(1, prev_count) as f64;
+            prob = BIGRAM_WEIGHT * bigram_prob.min(1.0) + (1.0 - BIGRAM_WEIGHT) * prob;
+        }
+
+        dist as f64 * self.error_rate.ln() + prob.ln()
+    }
+}
+
+
+fn parse_frequency(field: &str, line: usize) -> io::Result<u64> {
+    field.parse().map_err(|_| invalid_line(line, "invalid frequency"))
+}
+
+
+fn invalid_line(line: usize, message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("line {}: {}", line + 1, message))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{Speller, Candidate};
+    use std::io::Write;
+
+    fn speller() -> Speller {
+        let mut speller = Speller::new(2);
+        speller.load("
+            the 1000
+            a 800
+            is 500
+            martha 10
+            marta 50
+            mail 40
+            male 60
+            mailbox 30
+            box 25
+            voice 5
+            full 50
+        ".as_bytes()).unwrap();
+        speller
+    }
+
+    #[test]
+    fn load() {
+        let speller = speller();
+        assert_eq!(speller.len(), 11);
+        assert_eq!(speller.total, 2570);
+        assert!(speller.contains("Mailbox"));
+        assert!(!speller.contains("mailbx"));
+
+        let mut speller = Speller::new(1);
+        let err = speller.load("mail 1\nbox x\n".as_bytes()).unwrap_err();
+        assert_eq!(err.to_string(), "line 2: invalid frequency");
+        let err = speller.load_bigrams("mail box 1\nbox\n".as_bytes()).unwrap_err();
+        assert_eq!(err.to_string(), "line 2: expected two words");
+    }
+
+    #[test]
+    fn from_file() {
+        let path = std::env::temp_dir().join(format!("eddie-speller-{}.txt", std::process::id()));
+        let mut file = std::fs::File::create(&path).unwrap();
+        writeln!(file, "mailbox 10\nmartha 5").unwrap();
+        drop(file);
+        let speller = Speller::from_file(&path, 1).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(speller.len(), 2);
+        assert!(Speller::from_file(&path, 1).is_err());
+    }
+
+    #[test]
+    fn suggest() {
+        let speller = speller();
+        fn words(found: Vec<Candidate<'_>>) -> Vec<&str> {
+            found.iter().map(|c| c.word).collect()
+        }
+        assert_eq!(words(speller.suggest("mailbx")), vec!["mailbox", "mail"]);
+        assert_eq!(words(speller.suggest("marhta")), vec!["marta", "martha"]);
+        assert_eq!(words(speller.suggest("MAL")), vec!["male", "mail", "a"]);
+        assert_eq!(words(speller.suggest("xyzxyz")), Vec::<&str>::new());
+
+        let found = speller.suggest("mailbox");
+        assert_eq!(found[0].word, "mailbox");
+        assert_eq!(found[0].distance, 0);
+        assert!(found.windows(2).all(|w| w[0].score >= w[1].score));
+    }
+
+    #[test]
+    fn bigrams() {
+        let mut speller = speller();
+        assert_eq!(speller.suggest_after("the", "mal")[0].word, "male");
+        speller.insert_bigram("the", "mail", 100);
+        assert_eq!(speller.suggest_after("THE", "mal")[0].word, "mail");
+        assert_eq!(speller.suggest("mal")[0].word, "male");
+        assert_eq!(speller.correct("the mal"), "the mail");
+        assert_eq!(speller.correct("a mal"), "a male");
+    }
+
+    #[test]
+    fn correct() {
+        let speller = speller();
+        let sample = [
+            ("", ""),
+            ("Teh mailbx is ful!", "The mailbox is full!"),
+            ("MARHTA's mailbox", "MARHTA's mailbox"),
+            ("MARHTA, mailbox", "MARTA, mailbox"),
+            ("  (Mailbx)\n\tbx... ", "  (Mailbox)\n\tbox... "),
+            ("mailbx2 2mailbx", "mailbx2 2mailbx"),
+            ("xyzxyz mailbx", "xyzxyz mailbox"),
+            ("もしもし mailbx", "もしもし mailbox"),
+        ];
+        for &(text, expected) in &sample {
+            assert_eq!(speller.correct(text), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn error_rate_out_of_range() {
+        Speller::new(1).set_error_rate(1.0);
+    }
+}
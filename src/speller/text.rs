@@ -0,0 +1,119 @@
+/// Splits text into tokens: words (runs of alphanumeric characters,
+/// possibly with apostrophes inside) and everything in between.
+/// Returns pairs of a flag telling whether the token is a word, and the token itself.
+pub fn tokens(text: &str) -> Vec<(bool, &str)> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    let mut start = 0;
+    let mut in_word = false;
+
+    while let Some((i, ch)) = chars.next() {
+        let is_word = ch.is_alphanumeric()
+            || in_word && is_apostrophe(ch) && chars.peek().map_or(false, |&(_, next)| next.is_alphanumeric());
+        if is_word != in_word && i > start {
+            tokens.push((in_word, &text[start .. i]));
+            start = i;
+        }
+        in_word = is_word;
+    }
+    if start < text.len() {
+        tokens.push((in_word, &text[start ..]));
+    }
+
+    tokens
+}
+
+
+fn is_apostrophe(ch: char) -> bool {
+    ch == '\'' || ch == '\u{2019}'
+}
+
+
+/// Letter case of a word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Case {
+    Lower,
+    Title,
+    Upper,
+}
+
+
+impl Case {
+    /// Detects the case of the word. Mixed case words are considered lower case.
+    pub fn of(word: &str) -> Case {
+        let mut letters = word.chars().filter(|ch| ch.is_alphabetic());
+        let first_upper = match letters.next() {
+            Some(ch) => ch.is_uppercase(),
+            None => return Case::Lower,
+        };
+        if !first_upper { return Case::Lower; }
+        let mut rest = letters.peekable();
+        if rest.peek().is_none() { return Case::Title; }
+        let upper = rest.clone().all(|ch| ch.is_uppercase());
+        let lower = rest.all(|ch| ch.is_lowercase());
+        match (upper, lower) {
+            (true, _)      => Case::Upper,
+            (false, true)  => Case::Title,
+            (false, false) => Case::Lower,
+        }
+    }
+
+    /// Applies the case to the lower case word.
+    pub fn apply(self, word: &str) -> String {
+        match self {
+            Case::Lower => word.to_string(),
+            Case::Upper => word.to_uppercase(),
+            Case::Title => {
+                let mut chars = word.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{tokens, Case};
+
+    #[test]
+    fn tokenize() {
+        let sample: [(&str, &[(bool, &str)]); 7] = [
+            ("", &[]),
+            ("mailbox", &[(true, "mailbox")]),
+            (" mailbox,", &[(false, " "), (true, "mailbox"), (false, ",")]),
+            ("Martha's mailbox.", &[(true, "Martha's"), (false, " "), (true, "mailbox"), (false, ".")]),
+            ("'mail' box", &[(false, "'"), (true, "mail"), (false, "' "), (true, "box")]),
+            ("box2 — もしもし!", &[(true, "box2"), (false, " — "), (true, "もしもし"), (false, "!")]),
+            ("don’t", &[(true, "don’t")]),
+        ];
+        for &(text, expected) in &sample {
+            assert_eq!(tokens(text), expected);
+        }
+    }
+
+    #[test]
+    fn case() {
+        let sample = [
+            (Case::Lower, "mailbox"),
+            (Case::Lower, "mailBox"),
+            (Case::Lower, "123"),
+            (Case::Title, "Mailbox"),
+            (Case::Title, "I"),
+            (Case::Upper, "MAILBOX"),
+            (Case::Upper, "ДА"),
+            (Case::Lower, "MailBox"),
+        ];
+        for &(case, word) in &sample {
+            assert_eq!(Case::of(word), case, "{}", word);
+        }
+        assert_eq!(Case::Lower.apply("mailbox"), "mailbox");
+        assert_eq!(Case::Title.apply("mailbox"), "Mailbox");
+        assert_eq!(Case::Title.apply("äpfel"), "Äpfel");
+        assert_eq!(Case::Upper.apply("mailbox"), "MAILBOX");
+        assert_eq!(Case::Title.apply(""), "");
+    }
+}
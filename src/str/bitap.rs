@@ -0,0 +1,164 @@
+use crate::slice::{Pattern, Scanner};
+use alloc::vec::Vec;
+
+pub use crate::slice::{Match, PatternError};
+
+
+/// # Bitap fuzzy search.
+///
+/// Finds all occurrences of a pattern in a text within the given Levenshtein distance.
+/// Patterns are matched against characters, so `.` and character classes
+/// match any single character, e.g. `[а-я]` matches lower case Cyrillic letters.
+///
+/// See [`eddie::slice::Bitap`][1] for the pattern syntax and details.
+///
+/// [1]: slice/struct.Bitap.html
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::Bitap;
+///
+/// let bitap = Bitap::new("conn#refused#port [0-9]", 1).unwrap();
+/// assert!(bitap.is_match("connexion refused on port 5432"));
+/// assert!(!bitap.is_match("connection reset on port 5432"));
+/// ```
+pub struct Bitap {
+    pattern: Pattern<char>,
+}
+
+
+impl Bitap {
+    /// Compiles the pattern for the given maximum distance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::Bitap;
+    ///
+    /// let bitap = Bitap::new("[Mm]art.a", 1).unwrap();
+    /// ```
+    pub fn new(pattern: &str, max_dist: usize) -> Result<Self, PatternError> {
+        let pattern: Vec<char> = pattern.chars().collect();
+        Ok(Self { pattern: Pattern::new(&pattern, max_dist)? })
+    }
+
+    /// Returns the maximum distance.
+    pub fn max_dist(&self) -> usize {
+        self.pattern.max_dist()
+    }
+
+    /// Returns `true` if the pattern occurs in the text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Bitap;
+    /// let bitap = Bitap::new("もし[^し]し", 0).unwrap();
+    /// assert!(bitap.is_match("はい、もしもし。"));
+    /// assert!(!bitap.is_match("はい、もししし。"));
+    /// ```
+    pub fn is_match(&self, text: &str) -> bool {
+        self.pattern.is_match(text.chars())
+    }
+
+    /// Returns an iterator over the matches of the pattern in the text.
+    /// Match positions are byte offsets in the text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::{Bitap, Match};
+    /// let bitap = Bitap::new("[Mm]art.a", 1).unwrap();
+    /// let found: Vec<Match> = bitap.find_iter("Märtha and marhta").collect();
+    /// assert_eq!(found, vec![
+    ///     Match { start: 0, end: 7, distance: 1 },
+    ///     Match { start: 12, end: 18, distance: 1 },
+    /// ]);
+    /// ```
+    pub fn find_iter<'a>(&'a self, text: &'a str) -> BitapMatches<'a> {
+        BitapMatches {
+            scanner: Scanner::new(&self.pattern),
+            text,
+            pos: 0,
+        }
+    }
+}
+
+
+/// An iterator over the matches found by [`Bitap::find_iter`][1].
+///
+/// [1]: struct.Bitap.html#method.find_iter
+pub struct BitapMatches<'a> {
+    scanner: Scanner<'a, char>,
+    text: &'a str,
+    pos: usize,
+}
+
+
+impl<'a> Iterator for BitapMatches<'a> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        let text = self.text;
+        while let Some(ch) = text[self.pos ..].chars().next() {
+            let end = self.pos + ch.len_utf8();
+            self.pos = end;
+            let backwards = text[.. end].char_indices().rev();
+            if let Some(found) = self.scanner.push(end, ch, backwards) {
+                return Some(found);
+            }
+        }
+        self.scanner.finish()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{Bitap, Match};
+    use crate::slice;
+
+    #[test]
+    fn byte_offsets() {
+        let sample = [
+            (0, "mailbox", "mailbox", vec![(0, 7, 0)]),
+            (1, "mail[bp]ox", "my mailpx!", vec![(3, 9, 1)]),
+            (1, "もし.し", "もしもし, もしまし", vec![(0, 12, 0), (14, 26, 0)]),
+            (1, "[äa]pfel", "Äpfel und apfel", vec![(2, 6, 1), (11, 16, 0)]),
+            (0, "ап#ль", "апрель", vec![(0, 12, 0)]),
+            (0, "", "mailbox", vec![]),
+            (1, "mailbox", "", vec![]),
+        ];
+        for (max_dist, pattern, text, expected) in sample.iter() {
+            let found: Vec<(usize, usize, usize)> = Bitap::new(pattern, *max_dist).unwrap().find_iter(text)
+                .map(|m| (m.start, m.end, m.distance))
+                .collect();
+            assert_eq!(&found, expected, "{}", pattern);
+        }
+    }
+
+    #[test]
+    fn matches_slice() {
+        let text = "mailbox boxmail mail box mialbox, malbox mailboxes";
+        for max_dist in 0..4 {
+            for pattern in &["mailbox", "m[aei]l#x", "[^ ]ox"] {
+                let found: Vec<Match> = Bitap::new(pattern, max_dist).unwrap().find_iter(text).collect();
+                let expected: Vec<Match> = slice::Bitap::new(pattern.as_bytes(), max_dist).unwrap()
+                    .find_iter(text.as_bytes())
+                    .collect();
+                assert_eq!(found, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn non_latin_classes() {
+        let bitap = Bitap::new("[а-я]+", 0).unwrap();
+        assert!(bitap.is_match("да+"));
+        assert!(!bitap.is_match("da+"));
+        let bitap = Bitap::new("[^а-я]", 0).unwrap();
+        assert!(!bitap.is_match("да"));
+        assert!(bitap.is_match("даw"));
+    }
+}
@@ -1,8 +1,8 @@
 use crate::slice;
-use crate::utils::Buffer;
-
-
-const DEFAULT_CAPACITY: usize = 20;
+use crate::utils::{ascii_bytes, GraphemeBuffer, SmallBuffer, STACK_SIZE};
+use crate::normalize::{NormalizeBuffer, Normalizer};
+use alloc::vec::Vec;
+use alloc::string::{String, ToString};
 
 
 /// # Damerau-Levenshtein distance.
@@ -45,10 +45,32 @@ const DEFAULT_CAPACITY: usize = 20;
 /// let sim = damlev.similarity(s1, s2);
 /// assert_eq!(sim, 1.0 - rel);
 /// ```
-pub struct DamerauLevenshtein {
+///
+/// # Stack buffers
+///
+/// Characters of strings up to `N` characters long (32 by default) are kept on the stack.
+/// The distance matrix and the last occurrences of the characters are still kept on the heap,
+/// allocated on the first use and reused by later comparisons.
+/// The metric can also be created in a `const` context, e.g. for a `thread_local!`:
+/// ```rust
+/// use eddie::DamerauLevenshtein;
+///
+/// thread_local! {
+///     static DAMLEV: DamerauLevenshtein<64> = const { DamerauLevenshtein::with_stack() };
+/// }
+///
+/// let dist = DAMLEV.with(|damlev| damlev.distance("martha", "marhta"));
+/// assert_eq!(dist, 1);
+/// ```
+pub struct DamerauLevenshtein<const N: usize = STACK_SIZE> {
     sliced: slice::DamerauLevenshtein<char>,
-    buffer1: Buffer<char>,
-    buffer2: Buffer<char>,
+    bytes: slice::DamerauLevenshtein<u8>,
+    clusters: slice::DamerauLevenshtein<u32>,
+    buffer1: SmallBuffer<char, N>,
+    buffer2: SmallBuffer<char, N>,
+    graphemes: Option<GraphemeBuffer>,
+    normalizer: Option<Normalizer>,
+    normalized: NormalizeBuffer,
 }
 
 
@@ -63,11 +85,115 @@ impl DamerauLevenshtein {
     ///
     /// let damlev = DamerauLevenshtein::new();
     /// ```
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
+        Self::with_stack()
+    }
+
+    /// Creates a new instance of DamerauLevenshtein struct
+    /// which normalizes both strings before comparison.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::DamerauLevenshtein;
+    /// use eddie::normalize::Normalizer;
+    ///
+    /// let damlev = DamerauLevenshtein::with_normalizer(Normalizer::new());
+    /// ```
+    pub fn with_normalizer(normalizer: Normalizer) -> Self {
+        let mut damlev = Self::new();
+        damlev.set_normalizer(Some(normalizer));
+        damlev
+    }
+}
+
+
+impl<const N: usize> DamerauLevenshtein<N> {
+    /// Creates a new instance of DamerauLevenshtein struct
+    /// with stack buffers for the characters of strings up to `N` characters long.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::DamerauLevenshtein;
+    ///
+    /// let damlev = DamerauLevenshtein::<64>::with_stack();
+    /// ```
+    pub const fn with_stack() -> Self {
         let sliced = slice::DamerauLevenshtein::new();
-        let buffer1 = Buffer::with_capacity(DEFAULT_CAPACITY);
-        let buffer2 = Buffer::with_capacity(DEFAULT_CAPACITY);
-        Self { sliced, buffer1, buffer2 }
+        let bytes = slice::DamerauLevenshtein::new();
+        let clusters = slice::DamerauLevenshtein::new();
+        let buffer1 = SmallBuffer::new();
+        let buffer2 = SmallBuffer::new();
+        let normalized = NormalizeBuffer::new();
+        Self { sliced, bytes, clusters, buffer1, buffer2, graphemes: None, normalizer: None, normalized }
+    }
+
+    /// Enables or disables the grapheme mode, disabled by default.
+    /// In this mode the metric compares extended grapheme clusters instead of characters,
+    /// so that e.g. "é" written as "e" and a combining accent is a single unit,
+    /// and the lengths for relative metrics are counted in clusters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::DamerauLevenshtein;
+    /// let mut damlev = DamerauLevenshtein::new();
+    /// assert_eq!(damlev.distance("cafe\u{301}", "café"), 2);
+    ///
+    /// damlev.set_grapheme_mode(true);
+    /// assert_eq!(damlev.distance("cafe\u{301}", "café"), 1);
+    /// ```
+    pub fn set_grapheme_mode(&mut self, enabled: bool) -> &mut Self {
+        self.graphemes = if enabled { Some(GraphemeBuffer::new()) } else { None };
+        self
+    }
+
+    /// Returns whether the grapheme mode is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::DamerauLevenshtein;
+    /// let damlev = DamerauLevenshtein::new();
+    /// assert!(!damlev.grapheme_mode());
+    /// ```
+    pub fn grapheme_mode(&self) -> bool {
+        self.graphemes.is_some()
+    }
+
+    /// Sets the normalizer applied to both strings before comparison, none by default.
+    /// The lengths for relative metrics are counted in the normalized strings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::DamerauLevenshtein;
+    /// use eddie::normalize::{Normalizer, CaseFolding};
+    ///
+    /// let mut normalizer = Normalizer::new();
+    /// normalizer.set_case_folding(CaseFolding::Full).set_strip_diacritics(true);
+    ///
+    /// let mut damlev = DamerauLevenshtein::new();
+    /// damlev.set_normalizer(Some(normalizer));
+    /// assert_eq!(damlev.distance("Crème", "creme"), 0);
+    /// ```
+    pub fn set_normalizer(&mut self, normalizer: Option<Normalizer>) -> &mut Self {
+        self.normalizer = normalizer;
+        self
+    }
+
+    /// Returns the normalizer applied to both strings before comparison.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::DamerauLevenshtein;
+    /// let damlev = DamerauLevenshtein::new();
+    /// assert!(damlev.normalizer().is_none());
+    /// ```
+    pub fn normalizer(&self) -> Option<Normalizer> {
+        self.normalizer
     }
 
     /// Distance metric. Returns a number of edits
@@ -83,6 +209,15 @@ impl DamerauLevenshtein {
     /// assert_eq!(dist, 1);
     /// ```
     pub fn distance(&self, str1: &str, str2: &str) -> usize {
+        let (text1, text2) = self.normalized.store(&self.normalizer, str1, str2);
+        let (str1, str2) = (&*text1, &*text2);
+        if let Some(graphemes) = &self.graphemes {
+            let (ids1, ids2) = graphemes.store(str1, str2);
+            return self.clusters.distance(&ids1, &ids2);
+        }
+        if let Some((bytes1, bytes2)) = ascii_bytes(str1, str2) {
+            return self.bytes.distance(bytes1, bytes2);
+        }
         let buf1 = &*self.buffer1.store(str1.chars()).borrow();
         let buf2 = &*self.buffer2.store(str2.chars()).borrow();
         self.sliced.distance(buf1, buf2)
@@ -100,6 +235,15 @@ impl DamerauLevenshtein {
     /// assert!((dist - 0.167).abs() < 0.001);
     /// ```
     pub fn rel_dist(&self, str1: &str, str2: &str) -> f64 {
+        let (text1, text2) = self.normalized.store(&self.normalizer, str1, str2);
+        let (str1, str2) = (&*text1, &*text2);
+        if let Some(graphemes) = &self.graphemes {
+            let (ids1, ids2) = graphemes.store(str1, str2);
+            return self.clusters.rel_dist(&ids1, &ids2);
+        }
+        if let Some((bytes1, bytes2)) = ascii_bytes(str1, str2) {
+            return self.bytes.rel_dist(bytes1, bytes2);
+        }
         let buf1 = &*self.buffer1.store(str1.chars()).borrow();
         let buf2 = &*self.buffer2.store(str2.chars()).borrow();
         self.sliced.rel_dist(buf1, buf2)
@@ -117,16 +261,90 @@ impl DamerauLevenshtein {
     /// assert!((sim - 0.833).abs() < 0.001);
     /// ```
     pub fn similarity(&self, str1: &str, str2: &str) -> f64 {
+        let (text1, text2) = self.normalized.store(&self.normalizer, str1, str2);
+        let (str1, str2) = (&*text1, &*text2);
+        if let Some(graphemes) = &self.graphemes {
+            let (ids1, ids2) = graphemes.store(str1, str2);
+            return self.clusters.similarity(&ids1, &ids2);
+        }
+        if let Some((bytes1, bytes2)) = ascii_bytes(str1, str2) {
+            return self.bytes.similarity(bytes1, bytes2);
+        }
         let buf1 = &*self.buffer1.store(str1.chars()).borrow();
         let buf2 = &*self.buffer2.store(str2.chars()).borrow();
         self.sliced.similarity(buf1, buf2)
     }
+
+    /// Prepares the query for comparison with many candidates.
+    /// The prepared query keeps its characters,
+    /// so that comparisons do not repeat the work on the query.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::DamerauLevenshtein;
+    /// let damlev = DamerauLevenshtein::new();
+    /// let query = damlev.prepare("martha");
+    /// assert_eq!(query.distance_to("marhta"), 1);
+    /// assert_eq!(query.distance_to("martha"), 0);
+    /// ```
+    pub fn prepare(&self, query: &str) -> PreparedDamerauLevenshtein<'_, N> {
+        if self.graphemes.is_some() || self.normalizer.is_some() {
+            let text = Some(query.to_string());
+            return PreparedDamerauLevenshtein { metric: self, chars: Vec::new(), text };
+        }
+        PreparedDamerauLevenshtein { metric: self, chars: query.chars().collect(), text: None }
+    }
+}
+
+
+/// A query prepared for comparison with many candidates,
+/// returned by [`DamerauLevenshtein::prepare`][1].
+///
+/// [1]: struct.DamerauLevenshtein.html#method.prepare
+pub struct PreparedDamerauLevenshtein<'a, const N: usize = STACK_SIZE> {
+    metric: &'a DamerauLevenshtein<N>,
+    chars: Vec<char>,
+    /// Kept instead of the characters in the grapheme mode or with a normalizer,
+    /// which compare whole strings.
+    text: Option<String>,
+}
+
+
+impl<'a, const N: usize> PreparedDamerauLevenshtein<'a, N> {
+    /// Returns the same as `damlev.distance(query, candidate)`.
+    pub fn distance_to(&self, candidate: &str) -> usize {
+        if let Some(query) = &self.text {
+            return self.metric.distance(query, candidate);
+        }
+        let buf2 = &*self.metric.buffer2.store(candidate.chars()).borrow();
+        self.metric.sliced.distance(&self.chars, buf2)
+    }
+
+    /// Returns the same as `damlev.rel_dist(query, candidate)`.
+    pub fn rel_dist_to(&self, candidate: &str) -> f64 {
+        if let Some(query) = &self.text {
+            return self.metric.rel_dist(query, candidate);
+        }
+        let buf2 = &*self.metric.buffer2.store(candidate.chars()).borrow();
+        self.metric.sliced.rel_dist(&self.chars, buf2)
+    }
+
+    /// Returns the same as `damlev.similarity(query, candidate)`.
+    pub fn similarity_to(&self, candidate: &str) -> f64 {
+        if let Some(query) = &self.text {
+            return self.metric.similarity(query, candidate);
+        }
+        let buf2 = &*self.metric.buffer2.store(candidate.chars()).borrow();
+        self.metric.sliced.similarity(&self.chars, buf2)
+    }
 }
 
 
 #[cfg(test)]
 mod tests {
-    use super::{DamerauLevenshtein, DEFAULT_CAPACITY};
+    use super::DamerauLevenshtein;
+    use crate::utils::STACK_SIZE;
 
     fn floor3(num: f64) -> f64 {
         let p = 10usize.pow(3) as f64;
@@ -299,7 +517,7 @@ mod tests {
     fn growth() {
         let damlev = DamerauLevenshtein::new();
 
-        for len in DEFAULT_CAPACITY + 1 .. DEFAULT_CAPACITY * 2 {
+        for len in STACK_SIZE + 1 .. STACK_SIZE * 2 {
             let s1 = &"a".repeat(len);
             let s2 = &"b".repeat(len);
             assert_eq!(damlev.distance(s1, s1), 0);
@@ -362,4 +580,89 @@ mod tests {
             assert_eq!(floor3(damlev.similarity(s2, s1)), *d);
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn prepared() {
+        let damlev = DamerauLevenshtein::new();
+        let sample = ["", "m", "mailbox", "boxmail", "mail__box", "amliobx", "もしもし", "もしまし", "Äpfel"];
+        for s1 in &sample {
+            let query = damlev.prepare(s1);
+            for s2 in &sample {
+                assert_eq!(query.distance_to(s2), damlev.distance(s1, s2));
+                assert_eq!(query.rel_dist_to(s2), damlev.rel_dist(s1, s2));
+                assert_eq!(query.similarity_to(s2), damlev.similarity(s1, s2));
+            }
+        }
+    }
+
+    #[test]
+    fn ascii() {
+        let damlev = DamerauLevenshtein::new();
+        let sliced = crate::slice::DamerauLevenshtein::new();
+        let sample = ["", "m", "mailbox", "boxmail", "mail__box", "amliobx", "martha", "marhta", "Äpfel", "もしもし"];
+        for s1 in &sample {
+            let chars1: Vec<char> = s1.chars().collect();
+            for s2 in &sample {
+                let chars2: Vec<char> = s2.chars().collect();
+                assert_eq!(damlev.distance(s1, s2), sliced.distance(&chars1, &chars2));
+                assert_eq!(damlev.rel_dist(s1, s2), sliced.rel_dist(&chars1, &chars2));
+                assert_eq!(damlev.similarity(s1, s2), sliced.similarity(&chars1, &chars2));
+            }
+        }
+    }
+
+    #[test]
+    fn grapheme_mode() {
+        use crate::utils::graphemes;
+        let mut damlev = DamerauLevenshtein::new();
+        damlev.set_grapheme_mode(true);
+        let sliced = crate::slice::DamerauLevenshtein::new();
+        let sample = [
+            "", "m", "mailbox", "cafe\u{301}", "café", "e\u{301}e\u{301}", "\r\n", "\n\r",
+            "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", "\u{1F468}\u{1F469}\u{1F467}",
+            "\u{1F1FA}\u{1F1F8}\u{1F1EB}\u{1F1F7}", "\u{1F1F8}\u{1F1FA}\u{1F1EB}\u{1F1F7}", "नमस्ते", "नमसते",
+        ];
+        for s1 in &sample {
+            let query = damlev.prepare(s1);
+            let clusters1: Vec<&str> = graphemes(s1).collect();
+            for s2 in &sample {
+                let clusters2: Vec<&str> = graphemes(s2).collect();
+                assert_eq!(damlev.distance(s1, s2), sliced.distance(&clusters1, &clusters2));
+                assert_eq!(damlev.rel_dist(s1, s2), sliced.rel_dist(&clusters1, &clusters2));
+                assert_eq!(damlev.similarity(s1, s2), sliced.similarity(&clusters1, &clusters2));
+                assert_eq!(query.distance_to(s2), damlev.distance(s1, s2));
+                assert_eq!(query.rel_dist_to(s2), damlev.rel_dist(s1, s2));
+                assert_eq!(query.similarity_to(s2), damlev.similarity(s1, s2));
+            }
+        }
+    }
+
+    #[test]
+    fn normalizer() {
+        use crate::normalize::{Normalizer, CaseFolding};
+        let mut normalizer = Normalizer::new();
+        normalizer
+            .set_case_folding(CaseFolding::Full)
+            .set_strip_diacritics(true)
+            .set_collapse_whitespace(true);
+        let damlev = DamerauLevenshtein::with_normalizer(normalizer);
+        let plain = DamerauLevenshtein::new();
+        let sample = [
+            "", " ", "mailbox", "MAILBOX", "  Mail  Box ", "Crème Brûlée", "creme brulee",
+            "Straße", "strasse", "cafe\u{301}", "CAFÉ", "もしもし",
+        ];
+        for s1 in &sample {
+            let query = damlev.prepare(s1);
+            let norm1 = normalizer.normalize(s1);
+            for s2 in &sample {
+                let norm2 = normalizer.normalize(s2);
+                assert_eq!(damlev.distance(s1, s2), plain.distance(&norm1, &norm2));
+                assert_eq!(damlev.rel_dist(s1, s2), plain.rel_dist(&norm1, &norm2));
+                assert_eq!(damlev.similarity(s1, s2), plain.similarity(&norm1, &norm2));
+                assert_eq!(query.distance_to(s2), damlev.distance(s1, s2));
+                assert_eq!(query.rel_dist_to(s2), damlev.rel_dist(s1, s2));
+                assert_eq!(query.similarity_to(s2), damlev.similarity(s1, s2));
+            }
+        }
+    }
+}
@@ -1,4 +1,13 @@
-use std::cell::Cell;
+use core::cmp::{max, min};
+use crate::utils::{ascii_bytes, graphemes};
+#[cfg(feature = "alloc")]
+use crate::normalize::Normalizer;
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
 
 
 /// # Hamming distance.
@@ -65,13 +74,15 @@ use std::cell::Cell;
 /// # }
 /// ```
 pub struct Hamming {
-    len: Cell<usize>,
+    graphemes: bool,
+    #[cfg(feature = "alloc")]
+    normalizer: Option<Normalizer>,
 }
 
 
 impl Hamming {
-    /// Creates a new instance of Hamming struct with an internal state
-    /// for the metric methods to reuse.
+    /// Creates a new instance of Hamming struct. It holds no internal state,
+    /// so it is `Sync` and is also available from `eddie::sync`.
     ///
     /// # Example
     ///
@@ -80,8 +91,100 @@ impl Hamming {
     ///
     /// let hamming = Hamming::new();
     /// ```
-    pub fn new() -> Self {
-        Self { len: Cell::new(0) }
+    pub const fn new() -> Self {
+        Self {
+            graphemes: false,
+            #[cfg(feature = "alloc")]
+            normalizer: None,
+        }
+    }
+
+    /// Creates a new instance of Hamming struct
+    /// which normalizes both strings before comparison.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::Hamming;
+    /// use eddie::normalize::Normalizer;
+    ///
+    /// let hamming = Hamming::with_normalizer(Normalizer::new());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn with_normalizer(normalizer: Normalizer) -> Self {
+        let mut hamming = Self::new();
+        hamming.set_normalizer(Some(normalizer));
+        hamming
+    }
+
+    /// Enables or disables the grapheme mode, disabled by default.
+    /// In this mode the metric compares extended grapheme clusters instead of characters,
+    /// so that e.g. "é" written as "e" and a combining accent is a single unit,
+    /// and the lengths for relative metrics are counted in clusters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Hamming;
+    /// let mut hamming = Hamming::new();
+    /// assert_eq!(hamming.distance("cafe\u{301}", "cafè"), None);
+    ///
+    /// hamming.set_grapheme_mode(true);
+    /// assert_eq!(hamming.distance("cafe\u{301}", "cafè"), Some(1));
+    /// ```
+    pub fn set_grapheme_mode(&mut self, enabled: bool) -> &mut Self {
+        self.graphemes = enabled;
+        self
+    }
+
+    /// Returns whether the grapheme mode is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Hamming;
+    /// let hamming = Hamming::new();
+    /// assert!(!hamming.grapheme_mode());
+    /// ```
+    pub fn grapheme_mode(&self) -> bool {
+        self.graphemes
+    }
+
+    /// Sets the normalizer applied to both strings before comparison, none by default.
+    /// The lengths are compared and counted in the normalized strings.
+    /// Hamming keeps no internal buffers, so the normalized strings are allocated on every call.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Hamming;
+    /// use eddie::normalize::{Normalizer, CaseFolding};
+    ///
+    /// let mut normalizer = Normalizer::new();
+    /// normalizer.set_case_folding(CaseFolding::Full).set_strip_diacritics(true);
+    ///
+    /// let mut hamming = Hamming::new();
+    /// hamming.set_normalizer(Some(normalizer));
+    /// assert_eq!(hamming.distance("Crème", "creme"), Some(0));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn set_normalizer(&mut self, normalizer: Option<Normalizer>) -> &mut Self {
+        self.normalizer = normalizer;
+        self
+    }
+
+    /// Returns the normalizer applied to both strings before comparison.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Hamming;
+    /// let hamming = Hamming::new();
+    /// assert!(hamming.normalizer().is_none());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn normalizer(&self) -> Option<Normalizer> {
+        self.normalizer
     }
 
     /// Distance metric. Returns a number of positions
@@ -100,23 +203,9 @@ impl Hamming {
     /// assert_eq!(dist2, None);
     /// ```
     pub fn distance(&self, str1: &str, str2: &str) -> Option<usize> {
-        let mut len = 0;
-        let mut dist = 0;
-        let mut chars1 = str1.chars();
-        let mut chars2 = str2.chars();
-
-        loop {
-            match (chars1.next(), chars2.next()) {
-                (Some(ch1), Some(ch2)) => {
-                    if ch1 != ch2 { dist += 1; }
-                    len += 1;
-                }
-                (None, None) => {
-                    self.len.set(len);
-                    return Some(dist);
-                }
-                _ => return None,
-            }
+        match self.count(str1, str2) {
+            (dist, len1, len2) if len1 == len2 => Some(dist),
+            _ => None,
         }
     }
 
@@ -147,12 +236,9 @@ impl Hamming {
     /// # }
     /// ```
     pub fn rel_dist(&self, str1: &str, str2: &str) -> Option<f64> {
-        match self.distance(str1, str2) {
-            None => None,
-            Some(0) => Some(0.0),
-            Some(dist) => {
-                Some(dist as f64 / self.len.get() as f64)
-            },
+        match self.count(str1, str2) {
+            (dist, len1, len2) if len1 == len2 => Some(dist as f64 / max(len1, 1) as f64),
+            _ => None,
         }
     }
 
@@ -185,6 +271,123 @@ impl Hamming {
     pub fn similarity(&self, str1: &str, str2: &str) -> Option<f64> {
         self.rel_dist(str1, str2).map(|d| 1.0 - d)
     }
+
+    /// Prepares the query for comparison with many candidates.
+    /// The prepared query keeps its characters,
+    /// so that comparisons do not decode the query again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Hamming;
+    /// let hamming = Hamming::new();
+    /// let query = hamming.prepare("martha");
+    /// assert_eq!(query.distance_to("marhta"), Some(2));
+    /// assert_eq!(query.distance_to("mart"), None);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn prepare(&self, query: &str) -> PreparedHamming<'_> {
+        if self.graphemes || self.normalizer.is_some() {
+            let text = Some(query.to_string());
+            return PreparedHamming { metric: self, chars: Vec::new(), text };
+        }
+        PreparedHamming { metric: self, chars: query.chars().collect(), text: None }
+    }
+
+    /// Same as `distance`, but counts the difference in lengths as mismatches
+    /// instead of returning `None`.
+    pub(crate) fn padded_distance(&self, str1: &str, str2: &str) -> usize {
+        let (dist, len1, len2) = self.count(str1, str2);
+        dist + max(len1, len2) - min(len1, len2)
+    }
+
+    /// Returns the number of mismatched positions and the lengths of both strings.
+    fn count(&self, str1: &str, str2: &str) -> (usize, usize, usize) {
+        #[cfg(feature = "alloc")]
+        let (text1, text2) = match &self.normalizer {
+            Some(normalizer) => (Cow::Owned(normalizer.normalize(str1)), Cow::Owned(normalizer.normalize(str2))),
+            None => (Cow::Borrowed(str1), Cow::Borrowed(str2)),
+        };
+        #[cfg(feature = "alloc")]
+        let (str1, str2) = (&*text1, &*text2);
+        if self.graphemes {
+            return compare(graphemes(str1), graphemes(str2));
+        }
+        if let Some((bytes1, bytes2)) = ascii_bytes(str1, str2) {
+            let dist = bytes1.iter().zip(bytes2).filter(|(b1, b2)| b1 != b2).count();
+            return (dist, bytes1.len(), bytes2.len());
+        }
+        compare(str1.chars(), str2.chars())
+    }
+}
+
+
+/// A query prepared for comparison with many candidates,
+/// returned by [`Hamming::prepare`][1].
+///
+/// [1]: struct.Hamming.html#method.prepare
+#[cfg(feature = "alloc")]
+pub struct PreparedHamming<'a> {
+    metric: &'a Hamming,
+    chars: Vec<char>,
+    /// Kept instead of the characters in the grapheme mode or with a normalizer,
+    /// which compare whole strings.
+    text: Option<String>,
+}
+
+
+#[cfg(feature = "alloc")]
+impl<'a> PreparedHamming<'a> {
+    /// Returns the same as `hamming.distance(query, candidate)`.
+    pub fn distance_to(&self, candidate: &str) -> Option<usize> {
+        if let Some(query) = &self.text {
+            return self.metric.distance(query, candidate);
+        }
+        let mut query = self.chars.iter();
+        let mut dist = 0;
+        for ch2 in candidate.chars() {
+            match query.next() {
+                Some(&ch1) => { if ch1 != ch2 { dist += 1; } }
+                None => return None,
+            }
+        }
+        if query.next().is_some() { return None; }
+        Some(dist)
+    }
+
+    /// Returns the same as `hamming.rel_dist(query, candidate)`.
+    pub fn rel_dist_to(&self, candidate: &str) -> Option<f64> {
+        if let Some(query) = &self.text {
+            return self.metric.rel_dist(query, candidate);
+        }
+        match self.distance_to(candidate) {
+            None => None,
+            Some(0) => Some(0.0),
+            Some(dist) => Some(dist as f64 / self.chars.len() as f64),
+        }
+    }
+
+    /// Returns the same as `hamming.similarity(query, candidate)`.
+    pub fn similarity_to(&self, candidate: &str) -> Option<f64> {
+        self.rel_dist_to(candidate).map(|d| 1.0 - d)
+    }
+}
+
+
+fn compare<T: PartialEq>(mut items1: impl Iterator<Item=T>, mut items2: impl Iterator<Item=T>) -> (usize, usize, usize) {
+    let mut len = 0;
+    let mut dist = 0;
+    loop {
+        match (items1.next(), items2.next()) {
+            (Some(item1), Some(item2)) => {
+                if item1 != item2 { dist += 1; }
+                len += 1;
+            }
+            (Some(_), None) => return (dist, len + 1 + items1.count(), len),
+            (None, Some(_)) => return (dist, len, len + 1 + items2.count()),
+            (None, None)    => return (dist, len, len),
+        }
+    }
 }
 
 
@@ -335,4 +538,99 @@ mod tests {
             assert_eq!(hamming.similarity(s2, s1).map(floor3), *d);
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn prepared() {
+        let hamming = Hamming::new();
+        let sample = ["", "m", "mailbox", "boxmail", "mail__box", "amilobx", "もしもし", "もしまし", "Äpfel"];
+        for s1 in &sample {
+            let query = hamming.prepare(s1);
+            for s2 in &sample {
+                assert_eq!(query.distance_to(s2), hamming.distance(s1, s2));
+                assert_eq!(query.rel_dist_to(s2), hamming.rel_dist(s1, s2));
+                assert_eq!(query.similarity_to(s2), hamming.similarity(s1, s2));
+            }
+        }
+    }
+
+    #[test]
+    fn ascii() {
+        let hamming = Hamming::new();
+        let sample = ["", "m", "mailbox", "boxmail", "amilobx", "martha", "marhta", "march"];
+        for s1 in &sample {
+            for s2 in &sample {
+                let unicode1 = format!("{}ä", s1);
+                let unicode2 = format!("{}ä", s2);
+                assert_eq!(hamming.distance(s1, s2), hamming.distance(&unicode1, &unicode2));
+            }
+        }
+    }
+
+    #[test]
+    fn grapheme_mode() {
+        let mut hamming = Hamming::new();
+        hamming.set_grapheme_mode(true);
+        let sample = [
+            ("", "", Some(0), 0),
+            ("cafe\u{301}", "café", Some(1), 1),
+            ("cafe\u{301}", "cafe", Some(1), 1),
+            ("e\u{301}e\u{301}", "ee", Some(2), 2),
+            ("\r\n", "\n", Some(1), 1),
+            ("\r\n", "\n\r", None, 2),
+            ("\u{1F1FA}\u{1F1F8}\u{1F1EB}\u{1F1F7}", "\u{1F1FA}\u{1F1F8}\u{1F1E9}\u{1F1EA}", Some(1), 1),
+        ];
+        for (s1, s2, dist, padded) in &sample {
+            assert_eq!(hamming.distance(s1, s2), *dist);
+            assert_eq!(hamming.distance(s2, s1), *dist);
+            assert_eq!(hamming.padded_distance(s1, s2), *padded);
+        }
+        assert_eq!(hamming.rel_dist("cafe\u{301}", "café"), Some(0.25));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn prepared_grapheme_mode() {
+        let mut hamming = Hamming::new();
+        hamming.set_grapheme_mode(true);
+        let sample = ["", "cafe\u{301}", "café", "cafe", "e\u{301}e\u{301}", "ee", "\r\n", "\n\r"];
+        for s1 in &sample {
+            let query = hamming.prepare(s1);
+            for s2 in &sample {
+                assert_eq!(query.distance_to(s2), hamming.distance(s1, s2));
+                assert_eq!(query.rel_dist_to(s2), hamming.rel_dist(s1, s2));
+                assert_eq!(query.similarity_to(s2), hamming.similarity(s1, s2));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn normalizer() {
+        use crate::normalize::{Normalizer, CaseFolding};
+        let mut normalizer = Normalizer::new();
+        normalizer
+            .set_case_folding(CaseFolding::Full)
+            .set_strip_diacritics(true)
+            .set_collapse_whitespace(true);
+        let hamming = Hamming::with_normalizer(normalizer);
+        let plain = Hamming::new();
+        let sample = [
+            "", " ", "mailbox", "MAILBOX", "  Mail  Box ", "Crème Brûlée", "creme brulee",
+            "Straße", "strasse", "cafe\u{301}", "CAFÉ", "もしもし",
+        ];
+        for s1 in &sample {
+            let query = hamming.prepare(s1);
+            let norm1 = normalizer.normalize(s1);
+            for s2 in &sample {
+                let norm2 = normalizer.normalize(s2);
+                assert_eq!(hamming.distance(s1, s2), plain.distance(&norm1, &norm2));
+                assert_eq!(hamming.rel_dist(s1, s2), plain.rel_dist(&norm1, &norm2));
+                assert_eq!(hamming.similarity(s1, s2), plain.similarity(&norm1, &norm2));
+                assert_eq!(query.distance_to(s2), hamming.distance(s1, s2));
+                assert_eq!(query.rel_dist_to(s2), hamming.rel_dist(s1, s2));
+                assert_eq!(query.similarity_to(s2), hamming.similarity(s1, s2));
+            }
+        }
+    }
+}
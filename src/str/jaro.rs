@@ -1,9 +1,15 @@
 use crate::slice;
-use crate::utils::Buffer;
+use crate::utils::{ascii_bytes, SmallBuffer, STACK_SIZE};
+#[cfg(feature = "alloc")]
+use crate::utils::{CharMasks, common_prefix_size, GraphemeBuffer, MAX_MASKED};
+#[cfg(feature = "alloc")]
+use crate::normalize::{NormalizeBuffer, Normalizer};
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 
-const DEFAULT_CAPACITY: usize = 25;
-
 /// # Jaro similarity.
 ///
 /// See [the detailed description][1].
@@ -31,16 +37,41 @@ const DEFAULT_CAPACITY: usize = 25;
 /// let dist = jaro.rel_dist(s1, s2);
 /// assert_eq!(dist, 1.0 - sim);
 /// ```
-pub struct Jaro {
-    sliced: slice::Jaro,
-    buffer1: Buffer<char>,
-    buffer2: Buffer<char>,
+///
+/// # Stack buffers
+///
+/// Strings up to `N` characters long (32 by default) are compared without heap allocations.
+/// Longer strings spill to the heap. Without the `alloc` feature there is no heap to spill to,
+/// so the metric methods panic on them, and the metric is created only with `with_stack`
+/// to choose the buffer size explicitly.
+/// The metric can also be created in a `const` context, e.g. for a `thread_local!`:
+/// ```rust
+/// use eddie::Jaro;
+///
+/// thread_local! {
+///     static JARO: Jaro<64> = const { Jaro::with_stack() };
+/// }
+///
+/// let sim = JARO.with(|jaro| jaro.similarity("martha", "marhta"));
+/// assert!((sim - 0.94).abs() < 0.01);
+/// ```
+pub struct Jaro<const N: usize = STACK_SIZE> {
+    sliced: slice::Jaro<N>,
+    buffer1: SmallBuffer<char, N>,
+    buffer2: SmallBuffer<char, N>,
+    #[cfg(feature = "alloc")]
+    graphemes: Option<GraphemeBuffer>,
+    #[cfg(feature = "alloc")]
+    normalizer: Option<Normalizer>,
+    #[cfg(feature = "alloc")]
+    normalized: NormalizeBuffer,
 }
 
 
 impl Jaro {
     /// Creates a new instance of Jaro struct with an internal state
     /// for the metric methods to reuse.
+    /// Only available with the `alloc` feature, use `with_stack` without it.
     ///
     /// # Example
     ///
@@ -49,17 +80,134 @@ impl Jaro {
     ///
     /// let jaro = Jaro::new();
     /// ```
-    pub fn new() -> Self {
+    #[cfg(feature = "alloc")]
+    pub const fn new() -> Self {
+        Self::with_stack()
+    }
+
+    /// Creates a new instance of Jaro struct
+    /// which normalizes both strings before comparison.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::Jaro;
+    /// use eddie::normalize::Normalizer;
+    ///
+    /// let jaro = Jaro::with_normalizer(Normalizer::new());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn with_normalizer(normalizer: Normalizer) -> Self {
+        let mut jaro = Self::new();
+        jaro.set_normalizer(Some(normalizer));
+        jaro
+    }
+}
+
+
+impl<const N: usize> Jaro<N> {
+    /// Creates a new instance of Jaro struct
+    /// with stack buffers for strings up to `N` characters long.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::Jaro;
+    ///
+    /// let jaro = Jaro::<64>::with_stack();
+    /// ```
+    pub const fn with_stack() -> Self {
         Self {
-            sliced: slice::Jaro::new(),
-            buffer1: Buffer::with_capacity(DEFAULT_CAPACITY),
-            buffer2: Buffer::with_capacity(DEFAULT_CAPACITY),
+            sliced: slice::Jaro::with_stack(),
+            buffer1: SmallBuffer::new(),
+            buffer2: SmallBuffer::new(),
+            #[cfg(feature = "alloc")]
+            graphemes: None,
+            #[cfg(feature = "alloc")]
+            normalizer: None,
+            #[cfg(feature = "alloc")]
+            normalized: NormalizeBuffer::new(),
         }
     }
 
+    /// Enables or disables the grapheme mode, disabled by default.
+    /// In this mode the metric compares extended grapheme clusters instead of characters,
+    /// so that e.g. "é" written as "e" and a combining accent is a single unit,
+    /// and the lengths for relative metrics are counted in clusters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Jaro;
+    /// let mut jaro = Jaro::new();
+    /// assert!((jaro.similarity("cafe\u{301}", "café") - 0.78).abs() < 0.01);
+    ///
+    /// jaro.set_grapheme_mode(true);
+    /// assert!((jaro.similarity("cafe\u{301}", "café") - 0.83).abs() < 0.01);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn set_grapheme_mode(&mut self, enabled: bool) -> &mut Self {
+        self.graphemes = if enabled { Some(GraphemeBuffer::new()) } else { None };
+        self
+    }
+
+    /// Returns whether the grapheme mode is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Jaro;
+    /// let jaro = Jaro::new();
+    /// assert!(!jaro.grapheme_mode());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn grapheme_mode(&self) -> bool {
+        self.graphemes.is_some()
+    }
+
+    /// Sets the normalizer applied to both strings before comparison, none by default.
+    /// The lengths for relative metrics are counted in the normalized strings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Jaro;
+    /// use eddie::normalize::{Normalizer, CaseFolding};
+    ///
+    /// let mut normalizer = Normalizer::new();
+    /// normalizer.set_case_folding(CaseFolding::Full).set_strip_diacritics(true);
+    ///
+    /// let mut jaro = Jaro::new();
+    /// jaro.set_normalizer(Some(normalizer));
+    /// assert_eq!(jaro.similarity("Crème", "creme"), 1.0);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn set_normalizer(&mut self, normalizer: Option<Normalizer>) -> &mut Self {
+        self.normalizer = normalizer;
+        self
+    }
+
+    /// Returns the normalizer applied to both strings before comparison.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Jaro;
+    /// let jaro = Jaro::new();
+    /// assert!(jaro.normalizer().is_none());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn normalizer(&self) -> Option<Normalizer> {
+        self.normalizer
+    }
+
     /// Similarity metric. Reflects how close two strings are,
     /// ranging from 1.0 (equality) to 0.0 (nothing in common).
     ///
+    /// # Panics
+    ///
+    /// Without the `alloc` feature, may panic if a string is longer than `N` characters.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -69,12 +217,24 @@ impl Jaro {
     /// assert!((sim - 0.94).abs() < 0.01);
     /// ```
     pub fn similarity(&self, str1: &str, str2: &str) -> f64 {
+        #[cfg(feature = "alloc")]
+        let (text1, text2) = self.normalized.store(&self.normalizer, str1, str2);
+        #[cfg(feature = "alloc")]
+        let (str1, str2) = (&*text1, &*text2);
         match (str1.len(), str2.len()) {
             (0, 0) => { return 1.0; }
             (_, 0) => { return 0.0; }
             (0, _) => { return 0.0; }
             (_, _) => { }
         }
+        #[cfg(feature = "alloc")]
+        if let Some(graphemes) = &self.graphemes {
+            let (ids1, ids2) = graphemes.store(str1, str2);
+            return self.sliced.similarity(&ids1, &ids2);
+        }
+        if let Some((bytes1, bytes2)) = ascii_bytes(str1, str2) {
+            return self.sliced.similarity(bytes1, bytes2);
+        }
         let buf1 = &*self.buffer1.store(str1.chars()).borrow();
         let buf2 = &*self.buffer2.store(str2.chars()).borrow();
         self.sliced.similarity(buf1, buf2)
@@ -84,6 +244,10 @@ impl Jaro {
     /// Reflects how far apart two strings are,
     /// ranging from 0.0 (equality) to 1.0 (nothing in common).
     ///
+    /// # Panics
+    ///
+    /// Without the `alloc` feature, may panic if a string is longer than `N` characters.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -93,22 +257,146 @@ impl Jaro {
     /// assert!((dist - 0.06).abs() < 0.01);
     /// ```
     pub fn rel_dist(&self, str1: &str, str2: &str) -> f64 {
+        #[cfg(feature = "alloc")]
+        let (text1, text2) = self.normalized.store(&self.normalizer, str1, str2);
+        #[cfg(feature = "alloc")]
+        let (str1, str2) = (&*text1, &*text2);
         match (str1.len(), str2.len()) {
             (0, 0) => { return 0.0; }
             (_, 0) => { return 1.0; }
             (0, _) => { return 1.0; }
             (_, _) => { }
         }
+        #[cfg(feature = "alloc")]
+        if let Some(graphemes) = &self.graphemes {
+            let (ids1, ids2) = graphemes.store(str1, str2);
+            return self.sliced.rel_dist(&ids1, &ids2);
+        }
+        if let Some((bytes1, bytes2)) = ascii_bytes(str1, str2) {
+            return self.sliced.rel_dist(bytes1, bytes2);
+        }
         let buf1 = &*self.buffer1.store(str1.chars()).borrow();
         let buf2 = &*self.buffer2.store(str2.chars()).borrow();
         self.sliced.rel_dist(buf1, buf2)
     }
+
+    /// Prepares the query for comparison with many candidates.
+    /// The prepared query keeps its characters and, for queries of at most 64 characters,
+    /// their bit-masks to find matching characters within the match window at once,
+    /// so that comparisons do not repeat the work on the query.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Jaro;
+    /// let jaro = Jaro::new();
+    /// let query = jaro.prepare("martha");
+    /// assert_eq!(query.similarity_to("marhta"), jaro.similarity("martha", "marhta"));
+    /// assert_eq!(query.similarity_to("martha"), 1.0);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn prepare(&self, query: &str) -> PreparedJaro<'_, N> {
+        if self.graphemes.is_some() || self.normalizer.is_some() {
+            let text = Some(query.to_string());
+            return PreparedJaro { metric: self, chars: Vec::new(), masks: None, text };
+        }
+        let chars: Vec<char> = query.chars().collect();
+        let masks = if chars.len() <= MAX_MASKED { Some(CharMasks::new(&chars)) } else { None };
+        PreparedJaro { metric: self, chars, masks, text: None }
+    }
+}
+
+
+/// A query prepared for comparison with many candidates,
+/// returned by [`Jaro::prepare`][1].
+///
+/// [1]: struct.Jaro.html#method.prepare
+#[cfg(feature = "alloc")]
+pub struct PreparedJaro<'a, const N: usize = STACK_SIZE> {
+    metric: &'a Jaro<N>,
+    chars: Vec<char>,
+    masks: Option<CharMasks>,
+    /// Kept instead of the characters and masks in the grapheme mode or with a normalizer,
+    /// which compare whole strings.
+    text: Option<String>,
+}
+
+
+#[cfg(feature = "alloc")]
+impl<'a, const N: usize> PreparedJaro<'a, N> {
+    /// Returns the same as `jaro.similarity(query, candidate)`.
+    pub fn similarity_to(&self, candidate: &str) -> f64 {
+        if let Some(query) = &self.text {
+            return self.metric.similarity(query, candidate);
+        }
+        let buf2 = &*self.metric.buffer2.store(candidate.chars()).borrow();
+        self.masks.as_ref()
+            .and_then(|masks| masked_similarity(&self.chars, masks, buf2))
+            .unwrap_or_else(|| self.metric.sliced.similarity(&self.chars, buf2))
+    }
+
+    /// Returns the same as `jaro.rel_dist(query, candidate)`.
+    pub fn rel_dist_to(&self, candidate: &str) -> f64 {
+        1.0 - self.similarity_to(candidate)
+    }
+}
+
+
+/// Computes the same as `slice::Jaro::similarity` using bit-masks of the query,
+/// if the candidate is shorter than the query. Otherwise returns `None`.
+///
+/// Characters of the candidate are matched with the leftmost unmatched equal characters
+/// of the query within the match window, which are found with a few bitwise operations.
+#[cfg(feature = "alloc")]
+pub(crate) fn masked_similarity(query: &[char], masks: &CharMasks, candidate: &[char]) -> Option<f64> {
+    if candidate.len() >= query.len() { return None; }
+    if candidate.is_empty() { return Some(0.0); }
+
+    let prefix = common_prefix_size(query, candidate);
+    let len1 = candidate.len() - prefix;
+    let len2 = query.len() - prefix;
+    let range = max!(1, query.len() / 2, candidate.len() / 2) - 1;
+
+    // Matched positions after the common prefix.
+    let mut matched1: u64 = 0;
+    let mut matched2: u64 = 0;
+    let mut matches = 0;
+    for (i1, &ch) in candidate[prefix ..].iter().enumerate() {
+        let lo = i1 - min!(range, i1);
+        let hi = min!(i1 + range + 1, len2);
+        let window = if hi == 64 { !0 } else { (1 << hi) - 1 } & !((1 << lo) - 1);
+        let found = (masks.get(ch) >> prefix) & window & !matched2;
+        if found != 0 {
+            matched1 |= 1 << i1;
+            matched2 |= found & found.wrapping_neg();
+            matches += 1;
+        }
+    }
+
+    if prefix + matches == 0 { return Some(0.0); }
+
+    let mut trans = 0;
+    while matched1 != 0 {
+        let i1 = matched1.trailing_zeros() as usize;
+        let i2 = matched2.trailing_zeros() as usize;
+        if candidate[prefix + i1] != query[prefix + i2] { trans += 1; }
+        matched1 &= matched1 - 1;
+        matched2 &= matched2 - 1;
+    }
+
+    let matches = (prefix + matches) as f64;
+    let trans = trans as f64;
+    let len1 = (prefix + len1) as f64;
+    let len2 = (prefix + len2) as f64;
+
+    Some((matches/len1 + matches/len2 + ((matches - trans/2.) / matches)) / 3.)
 }
 
 
 #[cfg(test)]
 mod tests {
-    use super::{Jaro, DEFAULT_CAPACITY};
+    use super::Jaro;
+    use crate::utils::STACK_SIZE;
 
     fn floor3(num: f64) -> f64 {
         let p = 10usize.pow(3) as f64;
@@ -118,7 +406,7 @@ mod tests {
 
     #[test]
     fn equality() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (1., ""),
             (1., "m"),
@@ -137,7 +425,7 @@ mod tests {
 
     #[test]
     fn inequality() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0., "a",     "b"),
             (0., "aa",    "bb"),
@@ -153,7 +441,7 @@ mod tests {
 
     #[test]
     fn prefix() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0.952, "mailbox", "mailbo"),
             (0.904, "mailbox", "mailb"),
@@ -172,7 +460,7 @@ mod tests {
 
     #[test]
     fn postfix() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0.952, "mailbox", "ailbox"),
             (0.904, "mailbox", "ilbox"),
@@ -191,7 +479,7 @@ mod tests {
 
     #[test]
     fn match_distance() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0.000, "mailbox", "l......"),
             (0.428, "mailbox", ".l....."),
@@ -209,7 +497,7 @@ mod tests {
 
     #[test]
     fn add_del_continuous() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0.958, "mailbox", ".mailbox"),
             (0.925, "mailbox", "..mailbox"),
@@ -241,7 +529,7 @@ mod tests {
 
     #[test]
     fn sub_continuous() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0.904, "mailbox", "mailbo."),
             (0.809, "mailbox", "mailb.."),
@@ -265,7 +553,7 @@ mod tests {
 
     #[test]
     fn add_del_intermittent() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0.958, "mailbox", "mailbox."),
             (0.925, "mailbox", "mailbo.x."),
@@ -292,7 +580,7 @@ mod tests {
 
     #[test]
     fn sub_intermittent() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0.904, "mailbox", "mailbo."),
             (0.809, "mailbox", "mail.o."),
@@ -311,7 +599,7 @@ mod tests {
 
     #[test]
     fn transpose() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0.952, "mailbox", "amilbox"),
             (0.928, "mailbox", "imalbox"),
@@ -336,7 +624,7 @@ mod tests {
 
     #[test]
     fn utf_multibyte() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0.933, "もしもし", "もしもしし"),
             (1.000, "もしもし", "もしもし"),
@@ -356,7 +644,7 @@ mod tests {
 
     #[test]
     fn mixed() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0.000, "ca",        "abc"),
             (0.783, "a tc",      "a cat"),
@@ -379,10 +667,12 @@ mod tests {
 
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn growth() {
-        let jaro = Jaro::new();
+        use crate::utils::STACK_SIZE;
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
 
-        for len in 1 .. DEFAULT_CAPACITY * 2 {
+        for len in 1 .. STACK_SIZE * 2 {
             let s1 = &"a".repeat(len);
             let s2 = &"b".repeat(len);
             assert_eq!(jaro.similarity(s1, s1), 1.0);
@@ -391,9 +681,25 @@ mod tests {
     }
 
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn stack_sizes() {
+        let jaro0 = Jaro::<0>::with_stack();
+        let jaro4 = Jaro::<4>::with_stack();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
+        let sample = ["", "m", "ma", "martha", "marhta", "dixon", "dicksonx", "もしもし"];
+        for s1 in &sample {
+            for s2 in &sample {
+                assert_eq!(jaro0.similarity(s1, s2), jaro.similarity(s1, s2));
+                assert_eq!(jaro4.similarity(s1, s2), jaro.similarity(s1, s2));
+            }
+        }
+    }
+
+
     #[test]
     fn rel_dist() {
-        let jaro = Jaro::new();
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
         let sample = [
             (0.000, "",        ""),
             (1.000, "mailbox", ""),
@@ -410,4 +716,108 @@ mod tests {
             assert_eq!(floor3(jaro.rel_dist(s2, s1)), *d);
         }
     }
-}
\ No newline at end of file
+
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn prepared() {
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
+        let long1: String = "mailbox".chars().cycle().take(64).collect();
+        let long2: String = "malbox".chars().cycle().take(60).collect();
+        let long3: String = "mailbox".chars().cycle().take(70).collect();
+        let sample = [
+            "", "m", "mailbox", "boxmail", "mail", "ilbox", "..l....", "....mailbox", "amlibox",
+            "もしもし", "もしまし", "Äpfel", "abcabcabc", "cbacba", &long1, &long2, &long3,
+        ];
+        for s1 in &sample {
+            let query = jaro.prepare(s1);
+            for s2 in &sample {
+                assert_eq!(query.similarity_to(s2), jaro.similarity(s1, s2), "{} {}", s1, s2);
+                assert_eq!(query.rel_dist_to(s2), jaro.rel_dist(s1, s2));
+            }
+        }
+
+        let mut words = vec![String::new()];
+        for _ in 0..5 {
+            let longer: Vec<String> = words.iter()
+                .filter(|w| w.len() == words.last().unwrap().len())
+                .flat_map(|w| "abc".chars().map(move |ch| format!("{}{}", w, ch)))
+                .collect();
+            words.extend(longer);
+        }
+        for s1 in &words {
+            let query = jaro.prepare(s1);
+            for s2 in words.iter().step_by(3) {
+                assert_eq!(query.similarity_to(s2), jaro.similarity(s1, s2), "{} {}", s1, s2);
+            }
+        }
+    }
+
+    #[test]
+    fn ascii() {
+        let jaro = Jaro::<STACK_SIZE>::with_stack();
+        let sliced = crate::slice::Jaro::<STACK_SIZE>::with_stack();
+        let sample = ["", "m", "mailbox", "boxmail", "mail__box", "amliobx", "martha", "marhta", "Äpfel", "もしもし"];
+        for s1 in &sample {
+            let chars1: Vec<char> = s1.chars().collect();
+            for s2 in &sample {
+                let chars2: Vec<char> = s2.chars().collect();
+                assert_eq!(jaro.similarity(s1, s2), sliced.similarity(&chars1, &chars2));
+                assert_eq!(jaro.rel_dist(s1, s2), sliced.rel_dist(&chars1, &chars2));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn grapheme_mode() {
+        use crate::utils::graphemes;
+        let mut jaro = Jaro::<STACK_SIZE>::with_stack();
+        jaro.set_grapheme_mode(true);
+        let sliced = crate::slice::Jaro::<STACK_SIZE>::with_stack();
+        let sample = [
+            "", "m", "mailbox", "cafe\u{301}", "café", "e\u{301}e\u{301}", "\r\n", "\n\r",
+            "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", "\u{1F468}\u{1F469}\u{1F467}",
+            "\u{1F1FA}\u{1F1F8}\u{1F1EB}\u{1F1F7}", "\u{1F1F8}\u{1F1FA}\u{1F1EB}\u{1F1F7}", "नमस्ते", "नमसते",
+        ];
+        for s1 in &sample {
+            let query = jaro.prepare(s1);
+            let clusters1: Vec<&str> = graphemes(s1).collect();
+            for s2 in &sample {
+                let clusters2: Vec<&str> = graphemes(s2).collect();
+                assert_eq!(jaro.similarity(s1, s2), sliced.similarity(&clusters1, &clusters2));
+                assert_eq!(jaro.rel_dist(s1, s2), sliced.rel_dist(&clusters1, &clusters2));
+                assert_eq!(query.similarity_to(s2), jaro.similarity(s1, s2));
+                assert_eq!(query.rel_dist_to(s2), jaro.rel_dist(s1, s2));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn normalizer() {
+        use crate::normalize::{Normalizer, CaseFolding};
+        let mut normalizer = Normalizer::new();
+        normalizer
+            .set_case_folding(CaseFolding::Full)
+            .set_strip_diacritics(true)
+            .set_collapse_whitespace(true);
+        let jaro = Jaro::with_normalizer(normalizer);
+        let plain = Jaro::<STACK_SIZE>::with_stack();
+        let sample = [
+            "", " ", "mailbox", "MAILBOX", "  Mail  Box ", "Crème Brûlée", "creme brulee",
+            "Straße", "strasse", "cafe\u{301}", "CAFÉ", "もしもし",
+        ];
+        for s1 in &sample {
+            let query = jaro.prepare(s1);
+            let norm1 = normalizer.normalize(s1);
+            for s2 in &sample {
+                let norm2 = normalizer.normalize(s2);
+                assert_eq!(jaro.similarity(s1, s2), plain.similarity(&norm1, &norm2));
+                assert_eq!(jaro.rel_dist(s1, s2), plain.rel_dist(&norm1, &norm2));
+                assert_eq!(query.similarity_to(s2), jaro.similarity(s1, s2));
+                assert_eq!(query.rel_dist_to(s2), jaro.rel_dist(s1, s2));
+            }
+        }
+    }
+}
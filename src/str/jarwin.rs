@@ -1,8 +1,17 @@
 use crate::slice;
-use crate::utils::Buffer;
-
-
-const DEFAULT_CAPACITY: usize = 25;
+use crate::utils::{ascii_bytes, SmallBuffer, STACK_SIZE};
+#[cfg(feature = "alloc")]
+use crate::utils::{CharMasks, GraphemeBuffer, MAX_MASKED};
+#[cfg(feature = "alloc")]
+use crate::normalize::{NormalizeBuffer, Normalizer};
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use crate::slice::winkler;
+#[cfg(feature = "alloc")]
+use super::jaro::masked_similarity;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 
 /// # Jaro-Winkler similarity.
@@ -35,16 +44,41 @@ const DEFAULT_CAPACITY: usize = 25;
 /// let dist = jarwin.rel_dist(s1, s2);
 /// assert_eq!(dist, 1.0 - sim);
 /// ```
-pub struct JaroWinkler {
-    sliced: slice::JaroWinkler,
-    buffer1: Buffer<char>,
-    buffer2: Buffer<char>,
+///
+/// # Stack buffers
+///
+/// Strings up to `N` characters long (32 by default) are compared without heap allocations.
+/// Longer strings spill to the heap. Without the `alloc` feature there is no heap to spill to,
+/// so the metric methods panic on them, and the metric is created only with `with_stack`
+/// to choose the buffer size explicitly.
+/// The metric can also be created in a `const` context, e.g. for a `thread_local!`:
+/// ```rust
+/// use eddie::JaroWinkler;
+///
+/// thread_local! {
+///     static JARWIN: JaroWinkler<64> = const { JaroWinkler::with_stack() };
+/// }
+///
+/// let sim = JARWIN.with(|jarwin| jarwin.similarity("martha", "marhta"));
+/// assert!((sim - 0.96).abs() < 0.01);
+/// ```
+pub struct JaroWinkler<const N: usize = STACK_SIZE> {
+    sliced: slice::JaroWinkler<N>,
+    buffer1: SmallBuffer<char, N>,
+    buffer2: SmallBuffer<char, N>,
+    #[cfg(feature = "alloc")]
+    graphemes: Option<GraphemeBuffer>,
+    #[cfg(feature = "alloc")]
+    normalizer: Option<Normalizer>,
+    #[cfg(feature = "alloc")]
+    normalized: NormalizeBuffer,
 }
 
 
 impl JaroWinkler {
     /// Creates a new instance of JaroWinkler struct with an internal state
     /// for the metric methods to reuse.
+    /// Only available with the `alloc` feature, use `with_stack` without it.
     ///
     /// # Example
     ///
@@ -53,11 +87,53 @@ impl JaroWinkler {
     ///
     /// let jarwin = JaroWinkler::new();
     /// ```
-    pub fn new() -> JaroWinkler {
+    #[cfg(feature = "alloc")]
+    pub const fn new() -> Self {
+        Self::with_stack()
+    }
+
+    /// Creates a new instance of JaroWinkler struct
+    /// which normalizes both strings before comparison.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::JaroWinkler;
+    /// use eddie::normalize::Normalizer;
+    ///
+    /// let jarwin = JaroWinkler::with_normalizer(Normalizer::new());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn with_normalizer(normalizer: Normalizer) -> Self {
+        let mut jarwin = Self::new();
+        jarwin.set_normalizer(Some(normalizer));
+        jarwin
+    }
+}
+
+
+impl<const N: usize> JaroWinkler<N> {
+    /// Creates a new instance of JaroWinkler struct
+    /// with stack buffers for strings up to `N` characters long.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::JaroWinkler;
+    ///
+    /// let jarwin = JaroWinkler::<64>::with_stack();
+    /// ```
+    pub const fn with_stack() -> Self {
         Self {
-            sliced: slice::JaroWinkler::new(),
-            buffer1: Buffer::with_capacity(DEFAULT_CAPACITY),
-            buffer2: Buffer::with_capacity(DEFAULT_CAPACITY),
+            sliced: slice::JaroWinkler::with_stack(),
+            buffer1: SmallBuffer::new(),
+            buffer2: SmallBuffer::new(),
+            #[cfg(feature = "alloc")]
+            graphemes: None,
+            #[cfg(feature = "alloc")]
+            normalizer: None,
+            #[cfg(feature = "alloc")]
+            normalized: NormalizeBuffer::new(),
         }
     }
 
@@ -83,9 +159,97 @@ impl JaroWinkler {
         self
     }
 
+    /// Returns scaling factor for common prefix score boost.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::JaroWinkler;
+    /// let jarwin = JaroWinkler::new();
+    /// assert_eq!(jarwin.scaling(), 0.1);
+    /// ```
+    pub fn scaling(&self) -> f64 {
+        self.sliced.scaling()
+    }
+
+    /// Enables or disables the grapheme mode, disabled by default.
+    /// In this mode the metric compares extended grapheme clusters instead of characters,
+    /// so that e.g. "é" written as "e" and a combining accent is a single unit,
+    /// and the lengths for relative metrics are counted in clusters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::JaroWinkler;
+    /// let mut jarwin = JaroWinkler::new();
+    /// assert!((jarwin.similarity("cafe\u{301}", "café") - 0.85).abs() < 0.01);
+    ///
+    /// jarwin.set_grapheme_mode(true);
+    /// assert!((jarwin.similarity("cafe\u{301}", "café") - 0.88).abs() < 0.01);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn set_grapheme_mode(&mut self, enabled: bool) -> &mut Self {
+        self.graphemes = if enabled { Some(GraphemeBuffer::new()) } else { None };
+        self
+    }
+
+    /// Returns whether the grapheme mode is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::JaroWinkler;
+    /// let jarwin = JaroWinkler::new();
+    /// assert!(!jarwin.grapheme_mode());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn grapheme_mode(&self) -> bool {
+        self.graphemes.is_some()
+    }
+
+    /// Sets the normalizer applied to both strings before comparison, none by default.
+    /// The lengths for relative metrics are counted in the normalized strings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::JaroWinkler;
+    /// use eddie::normalize::{Normalizer, CaseFolding};
+    ///
+    /// let mut normalizer = Normalizer::new();
+    /// normalizer.set_case_folding(CaseFolding::Full).set_strip_diacritics(true);
+    ///
+    /// let mut jarwin = JaroWinkler::new();
+    /// jarwin.set_normalizer(Some(normalizer));
+    /// assert_eq!(jarwin.similarity("Crème", "creme"), 1.0);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn set_normalizer(&mut self, normalizer: Option<Normalizer>) -> &mut Self {
+        self.normalizer = normalizer;
+        self
+    }
+
+    /// Returns the normalizer applied to both strings before comparison.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::JaroWinkler;
+    /// let jarwin = JaroWinkler::new();
+    /// assert!(jarwin.normalizer().is_none());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn normalizer(&self) -> Option<Normalizer> {
+        self.normalizer
+    }
+
     /// Similarity metric. Reflects how close two strings are,
     /// ranging from 1.0 (equality) to 0.0 (nothing in common).
     ///
+    /// # Panics
+    ///
+    /// Without the `alloc` feature, may panic if a string is longer than `N` characters.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -95,6 +259,18 @@ impl JaroWinkler {
     /// assert!((sim - 0.96).abs() < 0.01);
     /// ```
     pub fn similarity(&self, str1: &str, str2: &str) -> f64 {
+        #[cfg(feature = "alloc")]
+        let (text1, text2) = self.normalized.store(&self.normalizer, str1, str2);
+        #[cfg(feature = "alloc")]
+        let (str1, str2) = (&*text1, &*text2);
+        #[cfg(feature = "alloc")]
+        if let Some(graphemes) = &self.graphemes {
+            let (ids1, ids2) = graphemes.store(str1, str2);
+            return self.sliced.similarity(&ids1, &ids2);
+        }
+        if let Some((bytes1, bytes2)) = ascii_bytes(str1, str2) {
+            return self.sliced.similarity(bytes1, bytes2);
+        }
         let buf1 = &*self.buffer1.store(str1.chars()).borrow();
         let buf2 = &*self.buffer2.store(str2.chars()).borrow();
         self.sliced.similarity(buf1, buf2)
@@ -104,6 +280,10 @@ impl JaroWinkler {
     /// Reflects how far apart two strings are,
     /// ranging from 0.0 (equality) to 1.0 (nothing in common).
     ///
+    /// # Panics
+    ///
+    /// Without the `alloc` feature, may panic if a string is longer than `N` characters.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -115,12 +295,75 @@ impl JaroWinkler {
     pub fn rel_dist(&self, str1: &str, str2: &str) -> f64 {
         1.0 - self.similarity(str1, str2)
     }
+
+    /// Prepares the query for comparison with many candidates.
+    /// See [`Jaro::prepare`][1] for details.
+    ///
+    /// [1]: struct.Jaro.html#method.prepare
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::JaroWinkler;
+    /// let jarwin = JaroWinkler::new();
+    /// let query = jarwin.prepare("martha");
+    /// assert_eq!(query.similarity_to("marhta"), jarwin.similarity("martha", "marhta"));
+    /// assert_eq!(query.similarity_to("martha"), 1.0);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn prepare(&self, query: &str) -> PreparedJaroWinkler<'_, N> {
+        if self.graphemes.is_some() || self.normalizer.is_some() {
+            let text = Some(query.to_string());
+            return PreparedJaroWinkler { metric: self, chars: Vec::new(), masks: None, text };
+        }
+        let chars: Vec<char> = query.chars().collect();
+        let masks = if chars.len() <= MAX_MASKED { Some(CharMasks::new(&chars)) } else { None };
+        PreparedJaroWinkler { metric: self, chars, masks, text: None }
+    }
+}
+
+
+/// A query prepared for comparison with many candidates,
+/// returned by [`JaroWinkler::prepare`][1].
+///
+/// [1]: struct.JaroWinkler.html#method.prepare
+#[cfg(feature = "alloc")]
+pub struct PreparedJaroWinkler<'a, const N: usize = STACK_SIZE> {
+    metric: &'a JaroWinkler<N>,
+    chars: Vec<char>,
+    masks: Option<CharMasks>,
+    /// Kept instead of the characters and masks in the grapheme mode or with a normalizer,
+    /// which compare whole strings.
+    text: Option<String>,
+}
+
+
+#[cfg(feature = "alloc")]
+impl<'a, const N: usize> PreparedJaroWinkler<'a, N> {
+    /// Returns the same as `jarwin.similarity(query, candidate)`.
+    pub fn similarity_to(&self, candidate: &str) -> f64 {
+        if let Some(query) = &self.text {
+            return self.metric.similarity(query, candidate);
+        }
+        let buf2 = &*self.metric.buffer2.store(candidate.chars()).borrow();
+        let jaro = match self.masks.as_ref().and_then(|masks| masked_similarity(&self.chars, masks, buf2)) {
+            Some(jaro) => jaro,
+            None => return self.metric.sliced.similarity(&self.chars, buf2),
+        };
+        winkler(jaro, self.metric.scaling(), &self.chars, buf2)
+    }
+
+    /// Returns the same as `jarwin.rel_dist(query, candidate)`.
+    pub fn rel_dist_to(&self, candidate: &str) -> f64 {
+        1.0 - self.similarity_to(candidate)
+    }
 }
 
 
 #[cfg(test)]
 mod tests {
     use super::JaroWinkler;
+    use crate::utils::STACK_SIZE;
 
 
     fn floor3(num: f64) -> f64 {
@@ -131,7 +374,7 @@ mod tests {
 
     #[test]
     fn equality() {
-        let jarwin = JaroWinkler::new();
+        let jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
         let sample = [
             (1., ""),
             (1., "m"),
@@ -150,7 +393,7 @@ mod tests {
 
     #[test]
     fn inequality() {
-        let jarwin = JaroWinkler::new();
+        let jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
         let sample = [
             (0., "a",     "b"),
             (0., "aa",    "bb"),
@@ -167,7 +410,7 @@ mod tests {
 
     #[test]
     fn prefix() {
-        let jarwin = JaroWinkler::new();
+        let jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
         let sample = [
             (0.000, "mailbox", ""),
             (0.742, "mailbox", "m"),
@@ -186,7 +429,7 @@ mod tests {
 
     #[test]
     fn postfix() {
-        let jarwin = JaroWinkler::new();
+        let jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
         let sample = [
             (0.952, "mailbox", "ailbox"),
             (0.904, "mailbox", "ilbox"),
@@ -205,7 +448,7 @@ mod tests {
 
     #[test]
     fn common_prefix_length() {
-        let jarwin = JaroWinkler::new();
+        let jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
         let sample = [
             (0.904, "_ailbox", "-ailbox"),
             (0.914, "m_ilbox", "m-ilbox"),
@@ -224,7 +467,7 @@ mod tests {
 
     #[test]
     fn mixed() {
-        let jarwin = JaroWinkler::new();
+        let jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
         let sample = [
             (0.000, "ca",        "abc"),
             (0.826, "a tc",      "a cat"),
@@ -248,7 +491,7 @@ mod tests {
 
     #[test]
     fn rel_dist() {
-        let jarwin = JaroWinkler::new();
+        let jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
         let sample = [
             (0.000, "",        ""),
             (1.000, "mailbox", ""),
@@ -263,4 +506,96 @@ mod tests {
             assert_eq!(floor3(jarwin.rel_dist(s2, s1)), *d);
         }
     }
-}
\ No newline at end of file
+
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn prepared() {
+        let mut jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
+        let long1: String = "mailbox".chars().cycle().take(64).collect();
+        let long2: String = "malbox".chars().cycle().take(60).collect();
+        let long3: String = "mailbox".chars().cycle().take(70).collect();
+        let sample = [
+            "", "m", "mailbox", "boxmail", "mail", "ilbox", "..l....", "....mailbox", "amlibox",
+            "もしもし", "もしまし", "Äpfel", "abcabcabc", "cbacba", &long1, &long2, &long3,
+        ];
+        for &scaling in &[0.1, 0.25] {
+            jarwin.set_scaling(scaling);
+            for s1 in &sample {
+                let query = jarwin.prepare(s1);
+                for s2 in &sample {
+                    assert_eq!(query.similarity_to(s2), jarwin.similarity(s1, s2), "{} {}", s1, s2);
+                    assert_eq!(query.rel_dist_to(s2), jarwin.rel_dist(s1, s2));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ascii() {
+        let jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
+        let sliced = crate::slice::JaroWinkler::<STACK_SIZE>::with_stack();
+        let sample = ["", "m", "mailbox", "boxmail", "mail__box", "amliobx", "martha", "marhta", "Äpfel", "もしもし"];
+        for s1 in &sample {
+            let chars1: Vec<char> = s1.chars().collect();
+            for s2 in &sample {
+                let chars2: Vec<char> = s2.chars().collect();
+                assert_eq!(jarwin.similarity(s1, s2), sliced.similarity(&chars1, &chars2));
+                assert_eq!(jarwin.rel_dist(s1, s2), sliced.rel_dist(&chars1, &chars2));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn grapheme_mode() {
+        use crate::utils::graphemes;
+        let mut jarwin = JaroWinkler::<STACK_SIZE>::with_stack();
+        jarwin.set_grapheme_mode(true);
+        let sliced = crate::slice::JaroWinkler::<STACK_SIZE>::with_stack();
+        let sample = [
+            "", "m", "mailbox", "cafe\u{301}", "café", "e\u{301}e\u{301}", "\r\n", "\n\r",
+            "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", "\u{1F468}\u{1F469}\u{1F467}",
+            "\u{1F1FA}\u{1F1F8}\u{1F1EB}\u{1F1F7}", "\u{1F1F8}\u{1F1FA}\u{1F1EB}\u{1F1F7}", "नमस्ते", "नमसते",
+        ];
+        for s1 in &sample {
+            let query = jarwin.prepare(s1);
+            let clusters1: Vec<&str> = graphemes(s1).collect();
+            for s2 in &sample {
+                let clusters2: Vec<&str> = graphemes(s2).collect();
+                assert_eq!(jarwin.similarity(s1, s2), sliced.similarity(&clusters1, &clusters2));
+                assert_eq!(jarwin.rel_dist(s1, s2), sliced.rel_dist(&clusters1, &clusters2));
+                assert_eq!(query.similarity_to(s2), jarwin.similarity(s1, s2));
+                assert_eq!(query.rel_dist_to(s2), jarwin.rel_dist(s1, s2));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn normalizer() {
+        use crate::normalize::{Normalizer, CaseFolding};
+        let mut normalizer = Normalizer::new();
+        normalizer
+            .set_case_folding(CaseFolding::Full)
+            .set_strip_diacritics(true)
+            .set_collapse_whitespace(true);
+        let jarwin = JaroWinkler::with_normalizer(normalizer);
+        let plain = JaroWinkler::<STACK_SIZE>::with_stack();
+        let sample = [
+            "", " ", "mailbox", "MAILBOX", "  Mail  Box ", "Crème Brûlée", "creme brulee",
+            "Straße", "strasse", "cafe\u{301}", "CAFÉ", "もしもし",
+        ];
+        for s1 in &sample {
+            let query = jarwin.prepare(s1);
+            let norm1 = normalizer.normalize(s1);
+            for s2 in &sample {
+                let norm2 = normalizer.normalize(s2);
+                assert_eq!(jarwin.similarity(s1, s2), plain.similarity(&norm1, &norm2));
+                assert_eq!(jarwin.rel_dist(s1, s2), plain.rel_dist(&norm1, &norm2));
+                assert_eq!(query.similarity_to(s2), jarwin.similarity(s1, s2));
+                assert_eq!(query.rel_dist_to(s2), jarwin.rel_dist(s1, s2));
+            }
+        }
+    }
+}
@@ -1,7 +1,13 @@
 use crate::slice;
-use crate::utils::Buffer;
-
-const DEFAULT_CAPACITY: usize = 20;
+use crate::utils::{ascii_bytes, SmallBuffer, STACK_SIZE};
+#[cfg(feature = "alloc")]
+use crate::utils::{CharMasks, GraphemeBuffer, myers_distance, MAX_MASKED};
+#[cfg(feature = "alloc")]
+use crate::normalize::{NormalizeBuffer, Normalizer};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
 
 /// # Levenshtein distance.
 ///
@@ -43,16 +49,41 @@ const DEFAULT_CAPACITY: usize = 20;
 /// let sim = lev.similarity(s1, s2);
 /// assert_eq!(sim, 1.0 - rel);
 /// ```
-pub struct Levenshtein {
-    sliced: slice::Levenshtein,
-    buffer1: Buffer<char>,
-    buffer2: Buffer<char>,
+///
+/// # Stack buffers
+///
+/// Strings up to `N` characters long (32 by default) are compared without heap allocations.
+/// Longer strings spill to the heap. Without the `alloc` feature there is no heap to spill to,
+/// so the metric methods panic on them, and the metric is created only with `with_stack`
+/// to choose the buffer size explicitly.
+/// The metric can also be created in a `const` context, e.g. for a `thread_local!`:
+/// ```rust
+/// use eddie::Levenshtein;
+///
+/// thread_local! {
+///     static LEV: Levenshtein<64> = const { Levenshtein::with_stack() };
+/// }
+///
+/// let dist = LEV.with(|lev| lev.distance("martha", "marhta"));
+/// assert_eq!(dist, 2);
+/// ```
+pub struct Levenshtein<const N: usize = STACK_SIZE> {
+    sliced: slice::Levenshtein<N>,
+    buffer1: SmallBuffer<char, N>,
+    buffer2: SmallBuffer<char, N>,
+    #[cfg(feature = "alloc")]
+    graphemes: Option<GraphemeBuffer>,
+    #[cfg(feature = "alloc")]
+    normalizer: Option<Normalizer>,
+    #[cfg(feature = "alloc")]
+    normalized: NormalizeBuffer,
 }
 
 
 impl Levenshtein {
     /// Creates a new instance of Levenshtein struct with an internal state
     /// for the metric methods to reuse.
+    /// Only available with the `alloc` feature, use `with_stack` without it.
     ///
     /// # Example
     ///
@@ -61,17 +92,138 @@ impl Levenshtein {
     ///
     /// let lev: Levenshtein = Levenshtein::new();
     /// ```
-    pub fn new() -> Self {
-        let sliced = slice::Levenshtein::new();
-        let buffer1 = Buffer::with_capacity(DEFAULT_CAPACITY);
-        let buffer2 = Buffer::with_capacity(DEFAULT_CAPACITY);
-        Self { sliced, buffer1, buffer2 }
+    #[cfg(feature = "alloc")]
+    pub const fn new() -> Self {
+        Self::with_stack()
+    }
+
+    /// Creates a new instance of Levenshtein struct
+    /// which normalizes both strings before comparison.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::Levenshtein;
+    /// use eddie::normalize::Normalizer;
+    ///
+    /// let lev = Levenshtein::with_normalizer(Normalizer::new());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn with_normalizer(normalizer: Normalizer) -> Self {
+        let mut lev = Self::new();
+        lev.set_normalizer(Some(normalizer));
+        lev
+    }
+}
+
+
+impl<const N: usize> Levenshtein<N> {
+    /// Creates a new instance of Levenshtein struct
+    /// with stack buffers for strings up to `N` characters long.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::Levenshtein;
+    ///
+    /// let lev = Levenshtein::<64>::with_stack();
+    /// ```
+    pub const fn with_stack() -> Self {
+        let sliced = slice::Levenshtein::with_stack();
+        let buffer1 = SmallBuffer::new();
+        let buffer2 = SmallBuffer::new();
+        Self {
+            sliced,
+            buffer1,
+            buffer2,
+            #[cfg(feature = "alloc")]
+            graphemes: None,
+            #[cfg(feature = "alloc")]
+            normalizer: None,
+            #[cfg(feature = "alloc")]
+            normalized: NormalizeBuffer::new(),
+        }
+    }
+
+    /// Enables or disables the grapheme mode, disabled by default.
+    /// In this mode the metric compares extended grapheme clusters instead of characters,
+    /// so that e.g. "é" written as "e" and a combining accent is a single unit,
+    /// and the lengths for relative metrics are counted in clusters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// let mut lev = Levenshtein::new();
+    /// assert_eq!(lev.distance("cafe\u{301}", "café"), 2);
+    ///
+    /// lev.set_grapheme_mode(true);
+    /// assert_eq!(lev.distance("cafe\u{301}", "café"), 1);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn set_grapheme_mode(&mut self, enabled: bool) -> &mut Self {
+        self.graphemes = if enabled { Some(GraphemeBuffer::new()) } else { None };
+        self
+    }
+
+    /// Returns whether the grapheme mode is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// let lev = Levenshtein::new();
+    /// assert!(!lev.grapheme_mode());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn grapheme_mode(&self) -> bool {
+        self.graphemes.is_some()
+    }
+
+    /// Sets the normalizer applied to both strings before comparison, none by default.
+    /// The lengths for relative metrics are counted in the normalized strings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// use eddie::normalize::{Normalizer, CaseFolding};
+    ///
+    /// let mut normalizer = Normalizer::new();
+    /// normalizer.set_case_folding(CaseFolding::Full).set_strip_diacritics(true);
+    ///
+    /// let mut lev = Levenshtein::new();
+    /// lev.set_normalizer(Some(normalizer));
+    /// assert_eq!(lev.distance("Crème", "creme"), 0);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn set_normalizer(&mut self, normalizer: Option<Normalizer>) -> &mut Self {
+        self.normalizer = normalizer;
+        self
+    }
+
+    /// Returns the normalizer applied to both strings before comparison.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// let lev = Levenshtein::new();
+    /// assert!(lev.normalizer().is_none());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn normalizer(&self) -> Option<Normalizer> {
+        self.normalizer
     }
 
     /// Distance metric. Returns a number of edits
     /// (character additions, deletions, and substitutions)
     /// required to transform one string into the other.
     ///
+    /// # Panics
+    ///
+    /// Without the `alloc` feature, may panic if a string is longer than `N` characters.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -81,6 +233,18 @@ impl Levenshtein {
     /// assert_eq!(dist, 2);
     /// ```
     pub fn distance(&self, str1: &str, str2: &str) -> usize {
+        #[cfg(feature = "alloc")]
+        let (text1, text2) = self.normalized.store(&self.normalizer, str1, str2);
+        #[cfg(feature = "alloc")]
+        let (str1, str2) = (&*text1, &*text2);
+        #[cfg(feature = "alloc")]
+        if let Some(graphemes) = &self.graphemes {
+            let (ids1, ids2) = graphemes.store(str1, str2);
+            return self.sliced.distance(&ids1, &ids2);
+        }
+        if let Some((bytes1, bytes2)) = ascii_bytes(str1, str2) {
+            return self.sliced.distance(bytes1, bytes2);
+        }
         let buf1 = &*self.buffer1.store(str1.chars()).borrow();
         let buf2 = &*self.buffer2.store(str2.chars()).borrow();
         self.sliced.distance(buf1, buf2)
@@ -89,6 +253,10 @@ impl Levenshtein {
     /// Relative distance metric. Returns a number of edits relative to the length of
     /// the longest string, ranging from 0.0 (equality) to 1.0 (nothing in common).
     ///
+    /// # Panics
+    ///
+    /// Without the `alloc` feature, may panic if a string is longer than `N` characters.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -98,6 +266,18 @@ impl Levenshtein {
     /// assert!((dist - 0.333).abs() < 0.001);
     /// ```
     pub fn rel_dist(&self, str1: &str, str2: &str) -> f64 {
+        #[cfg(feature = "alloc")]
+        let (text1, text2) = self.normalized.store(&self.normalizer, str1, str2);
+        #[cfg(feature = "alloc")]
+        let (str1, str2) = (&*text1, &*text2);
+        #[cfg(feature = "alloc")]
+        if let Some(graphemes) = &self.graphemes {
+            let (ids1, ids2) = graphemes.store(str1, str2);
+            return self.sliced.rel_dist(&ids1, &ids2);
+        }
+        if let Some((bytes1, bytes2)) = ascii_bytes(str1, str2) {
+            return self.sliced.rel_dist(bytes1, bytes2);
+        }
         let buf1 = &*self.buffer1.store(str1.chars()).borrow();
         let buf2 = &*self.buffer2.store(str2.chars()).borrow();
         self.sliced.rel_dist(buf1, buf2)
@@ -106,6 +286,10 @@ impl Levenshtein {
     /// Similarity metric. Inversion of relative distance,
     /// ranging from 1.0 (equality) to 0.0 (nothing in common).
     ///
+    /// # Panics
+    ///
+    /// Without the `alloc` feature, may panic if a string is longer than `N` characters.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -115,16 +299,102 @@ impl Levenshtein {
     /// assert!((sim - 0.666).abs() < 0.001);
     /// ```
     pub fn similarity(&self, str1: &str, str2: &str) -> f64 {
+        #[cfg(feature = "alloc")]
+        let (text1, text2) = self.normalized.store(&self.normalizer, str1, str2);
+        #[cfg(feature = "alloc")]
+        let (str1, str2) = (&*text1, &*text2);
+        #[cfg(feature = "alloc")]
+        if let Some(graphemes) = &self.graphemes {
+            let (ids1, ids2) = graphemes.store(str1, str2);
+            return self.sliced.similarity(&ids1, &ids2);
+        }
+        if let Some((bytes1, bytes2)) = ascii_bytes(str1, str2) {
+            return self.sliced.similarity(bytes1, bytes2);
+        }
         let buf1 = &*self.buffer1.store(str1.chars()).borrow();
         let buf2 = &*self.buffer2.store(str2.chars()).borrow();
         self.sliced.similarity(buf1, buf2)
     }
+
+    /// Prepares the query for comparison with many candidates.
+    /// The prepared query keeps its characters and, for queries of at most 64 characters,
+    /// their bit-masks for the bit-parallel algorithm by Myers and Hyyrö,
+    /// so that comparisons do not repeat the work on the query.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::Levenshtein;
+    /// let lev = Levenshtein::new();
+    /// let query = lev.prepare("martha");
+    /// assert_eq!(query.distance_to("marhta"), 2);
+    /// assert_eq!(query.distance_to("martha"), 0);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn prepare(&self, query: &str) -> PreparedLevenshtein<'_, N> {
+        if self.graphemes.is_some() || self.normalizer.is_some() {
+            let text = Some(query.to_string());
+            return PreparedLevenshtein { metric: self, chars: Vec::new(), masks: None, text };
+        }
+        let chars: Vec<char> = query.chars().collect();
+        let masks = if chars.len() <= MAX_MASKED { Some(CharMasks::new(&chars)) } else { None };
+        PreparedLevenshtein { metric: self, chars, masks, text: None }
+    }
+}
+
+
+/// A query prepared for comparison with many candidates,
+/// returned by [`Levenshtein::prepare`][1].
+///
+/// [1]: struct.Levenshtein.html#method.prepare
+#[cfg(feature = "alloc")]
+pub struct PreparedLevenshtein<'a, const N: usize = STACK_SIZE> {
+    metric: &'a Levenshtein<N>,
+    chars: Vec<char>,
+    masks: Option<CharMasks>,
+    /// Kept instead of the characters and masks in the grapheme mode or with a normalizer,
+    /// which compare whole strings.
+    text: Option<String>,
+}
+
+
+#[cfg(feature = "alloc")]
+impl<'a, const N: usize> PreparedLevenshtein<'a, N> {
+    /// Returns the same as `lev.distance(query, candidate)`.
+    pub fn distance_to(&self, candidate: &str) -> usize {
+        if let Some(query) = &self.text {
+            return self.metric.distance(query, candidate);
+        }
+        match &self.masks {
+            Some(masks) => myers_distance(masks, self.chars.len(), candidate.chars()),
+            None => {
+                let buf2 = &*self.metric.buffer2.store(candidate.chars()).borrow();
+                self.metric.sliced.distance(&self.chars, buf2)
+            }
+        }
+    }
+
+    /// Returns the same as `lev.rel_dist(query, candidate)`.
+    pub fn rel_dist_to(&self, candidate: &str) -> f64 {
+        if let Some(query) = &self.text {
+            return self.metric.rel_dist(query, candidate);
+        }
+        let dist = self.distance_to(candidate);
+        let len = max!(1, self.chars.len(), candidate.chars().count());
+        dist as f64 / len as f64
+    }
+
+    /// Returns the same as `lev.similarity(query, candidate)`.
+    pub fn similarity_to(&self, candidate: &str) -> f64 {
+        1.0 - self.rel_dist_to(candidate)
+    }
 }
 
 
 #[cfg(test)]
 mod tests {
-    use super::{Levenshtein, DEFAULT_CAPACITY};
+    use super::Levenshtein;
+    use crate::utils::STACK_SIZE;
 
     fn floor3(num: f64) -> f64 {
         let p = 10usize.pow(3) as f64;
@@ -133,7 +403,7 @@ mod tests {
 
     #[test]
     fn equality() {
-        let lev = Levenshtein::new();
+        let lev = Levenshtein::<STACK_SIZE>::with_stack();
         let sample = [
             "",
             "m",
@@ -151,7 +421,7 @@ mod tests {
 
     #[test]
     fn prefix() {
-        let lev = Levenshtein::new();
+        let lev = Levenshtein::<STACK_SIZE>::with_stack();
         let sample = [
             (1, "mailbox", "mailbo"),
             (2, "mailbox", "mailb"),
@@ -169,7 +439,7 @@ mod tests {
 
     #[test]
     fn add_del_continuous() {
-        let lev = Levenshtein::new();
+        let lev = Levenshtein::<STACK_SIZE>::with_stack();
         let sample = [
             (1, "mailbox", "_mailbox"),
             (2, "mailbox", "__mailbox"),
@@ -194,7 +464,7 @@ mod tests {
 
     #[test]
     fn sub_continuous() {
-        let lev = Levenshtein::new();
+        let lev = Levenshtein::<STACK_SIZE>::with_stack();
         let sample = [
             (1, "mailbox", "_ailbox"),
             (2, "mailbox", "__ilbox"),
@@ -218,7 +488,7 @@ mod tests {
 
     #[test]
     fn add_del_intermittent() {
-        let lev = Levenshtein::new();
+        let lev = Levenshtein::<STACK_SIZE>::with_stack();
         let sample = [
             (1, "mailbox", "_mailbox"),
             (2, "mailbox", "_m_ailbox"),
@@ -238,7 +508,7 @@ mod tests {
 
     #[test]
     fn sub_intermittent() {
-        let lev = Levenshtein::new();
+        let lev = Levenshtein::<STACK_SIZE>::with_stack();
         let sample = [
             (1, "mailbox", "_ailbox"),
             (2, "mailbox", "_a_lbox"),
@@ -255,7 +525,7 @@ mod tests {
 
     #[test]
     fn mixed() {
-        let lev = Levenshtein::new();
+        let lev = Levenshtein::<STACK_SIZE>::with_stack();
         let sample = [
             (3, "ca",        "abc"),
             (3, "a tc",      "a cat"),
@@ -277,10 +547,12 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn growth() {
-        let lev = Levenshtein::new();
+        use crate::utils::STACK_SIZE;
+        let lev = Levenshtein::<STACK_SIZE>::with_stack();
 
-        for len in 0 .. DEFAULT_CAPACITY * 2 {
+        for len in 0 .. STACK_SIZE * 2 {
             let s1 = &"a".repeat(len);
             let s2 = &"b".repeat(len);
             assert_eq!(lev.distance(s1, s1), 0);
@@ -288,9 +560,24 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn stack_sizes() {
+        let lev0 = Levenshtein::<0>::with_stack();
+        let lev4 = Levenshtein::<4>::with_stack();
+        let lev = Levenshtein::<STACK_SIZE>::with_stack();
+        let sample = ["", "m", "ma", "mail", "mailbox", "boxmail", "kitten", "sitting", "もしもし"];
+        for s1 in &sample {
+            for s2 in &sample {
+                assert_eq!(lev0.distance(s1, s2), lev.distance(s1, s2));
+                assert_eq!(lev4.distance(s1, s2), lev.distance(s1, s2));
+            }
+        }
+    }
+
     #[test]
     fn utf_multibyte() {
-        let lev = Levenshtein::new();
+        let lev = Levenshtein::<STACK_SIZE>::with_stack();
         let s1 = "もしもし";
         let sample= [
             (1, "もしもしし"),
@@ -308,7 +595,7 @@ mod tests {
 
     #[test]
     fn rel_dist() {
-        let lev = Levenshtein::new();
+        let lev = Levenshtein::<STACK_SIZE>::with_stack();
         let sample = [
             (0.000, "",        ""),
             (1.000, "mailbox", ""),
@@ -326,7 +613,7 @@ mod tests {
 
     #[test]
     fn similarity() {
-        let lev = Levenshtein::new();
+        let lev = Levenshtein::<STACK_SIZE>::with_stack();
         let sample = [
             (1.000, "",        ""),
             (0.000, "mailbox", ""),
@@ -341,4 +628,130 @@ mod tests {
             assert_eq!(floor3(lev.similarity(s2, s1)), d);
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn prepared() {
+        let lev = Levenshtein::<STACK_SIZE>::with_stack();
+        let long1: String = "mailbox".chars().cycle().take(64).collect();
+        let long2: String = "malbox".chars().cycle().take(80).collect();
+        let sample = [
+            "", "m", "mailbox", "boxmail", "mail__box", "amliobx", "もしもし", "もしまし", "Äpfel",
+            &long1, &long2,
+        ];
+        for s1 in &sample {
+            let query = lev.prepare(s1);
+            for s2 in &sample {
+                assert_eq!(query.distance_to(s2), lev.distance(s1, s2));
+                assert_eq!(query.rel_dist_to(s2), lev.rel_dist(s1, s2));
+                assert_eq!(query.similarity_to(s2), lev.similarity(s1, s2));
+            }
+        }
+    }
+
+    #[test]
+    fn ascii() {
+        let lev = Levenshtein::<STACK_SIZE>::with_stack();
+        let sliced = crate::slice::Levenshtein::<STACK_SIZE>::with_stack();
+        let sample = ["", "m", "mailbox", "boxmail", "mail__box", "amliobx", "martha", "marhta", "Äpfel", "もしもし"];
+        for s1 in &sample {
+            let chars1: Vec<char> = s1.chars().collect();
+            for s2 in &sample {
+                let chars2: Vec<char> = s2.chars().collect();
+                assert_eq!(lev.distance(s1, s2), sliced.distance(&chars1, &chars2));
+                assert_eq!(lev.rel_dist(s1, s2), sliced.rel_dist(&chars1, &chars2));
+                assert_eq!(lev.similarity(s1, s2), sliced.similarity(&chars1, &chars2));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn grapheme_mode() {
+        use crate::utils::graphemes;
+        let mut lev = Levenshtein::<STACK_SIZE>::with_stack();
+        lev.set_grapheme_mode(true);
+        let sliced = crate::slice::Levenshtein::<STACK_SIZE>::with_stack();
+        let sample = [
+            "", "m", "mailbox", "cafe\u{301}", "café", "e\u{301}e\u{301}", "\r\n", "\n\r",
+            "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", "\u{1F468}\u{1F469}\u{1F467}",
+            "\u{1F1FA}\u{1F1F8}\u{1F1EB}\u{1F1F7}", "\u{1F1F8}\u{1F1FA}\u{1F1EB}\u{1F1F7}", "नमस्ते", "नमसते",
+        ];
+        for s1 in &sample {
+            let query = lev.prepare(s1);
+            assert!(query.chars.is_empty() && query.masks.is_none());
+            let clusters1: Vec<&str> = graphemes(s1).collect();
+            for s2 in &sample {
+                let clusters2: Vec<&str> = graphemes(s2).collect();
+                assert_eq!(lev.distance(s1, s2), sliced.distance(&clusters1, &clusters2));
+                assert_eq!(lev.rel_dist(s1, s2), sliced.rel_dist(&clusters1, &clusters2));
+                assert_eq!(lev.similarity(s1, s2), sliced.similarity(&clusters1, &clusters2));
+                assert_eq!(query.distance_to(s2), lev.distance(s1, s2));
+                assert_eq!(query.rel_dist_to(s2), lev.rel_dist(s1, s2));
+                assert_eq!(query.similarity_to(s2), lev.similarity(s1, s2));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn normalizer() {
+        use crate::normalize::{Normalizer, CaseFolding};
+        let mut normalizer = Normalizer::new();
+        normalizer
+            .set_case_folding(CaseFolding::Full)
+            .set_strip_diacritics(true)
+            .set_collapse_whitespace(true);
+        let lev = Levenshtein::with_normalizer(normalizer);
+        let plain = Levenshtein::<STACK_SIZE>::with_stack();
+        let sample = [
+            "", " ", "mailbox", "MAILBOX", "  Mail  Box ", "Crème Brûlée", "creme brulee",
+            "Straße", "strasse", "cafe\u{301}", "CAFÉ", "もしもし",
+        ];
+        for s1 in &sample {
+            let query = lev.prepare(s1);
+            assert!(query.chars.is_empty() && query.masks.is_none());
+            let norm1 = normalizer.normalize(s1);
+            for s2 in &sample {
+                let norm2 = normalizer.normalize(s2);
+                assert_eq!(lev.distance(s1, s2), plain.distance(&norm1, &norm2));
+                assert_eq!(lev.rel_dist(s1, s2), plain.rel_dist(&norm1, &norm2));
+                assert_eq!(lev.similarity(s1, s2), plain.similarity(&norm1, &norm2));
+                assert_eq!(query.distance_to(s2), lev.distance(s1, s2));
+                assert_eq!(query.rel_dist_to(s2), lev.rel_dist(s1, s2));
+                assert_eq!(query.similarity_to(s2), lev.similarity(s1, s2));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn transliteration() {
+        use crate::normalize::{Normalizer, CaseFolding, Transliteration};
+        let mut normalizer = Normalizer::new();
+        normalizer
+            .set_transliteration(Transliteration::BgnPcgn)
+            .set_ascii_folding(true)
+            .set_case_folding(CaseFolding::Full)
+            .set_strip_punctuation(true);
+        let lev = Levenshtein::with_normalizer(normalizer);
+        let sample = [
+            (0, "Щербаков",    "Shcherbakov"),
+            (0, "ЩЕРБАКОВ",    "shcherbakov"),
+            (0, "Ельцин",      "Yeltsin"),
+            (2, "Достоевский", "Dostoevsky"),
+            (0, "Αθήνα",       "Athina"),
+            (0, "Θεσσαλονίκη", "Thessaloniki"),
+            (2, "Щербаков",    "Шербаков"),
+        ];
+        for &(d, s1, s2) in &sample {
+            assert_eq!(lev.distance(s1, s2), d);
+            assert_eq!(lev.distance(s2, s1), d);
+        }
+
+        normalizer.set_strip_punctuation(false);
+        let lev = Levenshtein::with_normalizer(normalizer);
+        assert_eq!(lev.distance("Ельцин", "Yel'tsin"), 0);
+        assert_eq!(lev.distance("Ельцин", "Yeltsin"), 1);
+    }
+}
@@ -48,13 +48,31 @@
 
 
 mod leven;
+#[cfg(feature = "alloc")]
 mod damlev;
 mod hamming;
 mod jaro;
 mod jarwin;
+#[cfg(feature = "alloc")]
+mod sellers;
+#[cfg(feature = "alloc")]
+mod bitap;
 
 pub use leven::Levenshtein;
-pub use damlev::DamerauLevenshtein;
 pub use hamming::Hamming;
 pub use jaro::Jaro;
 pub use jarwin::JaroWinkler;
+#[cfg(feature = "alloc")]
+pub use leven::PreparedLevenshtein;
+#[cfg(feature = "alloc")]
+pub use damlev::{DamerauLevenshtein, PreparedDamerauLevenshtein};
+#[cfg(feature = "alloc")]
+pub use hamming::PreparedHamming;
+#[cfg(feature = "alloc")]
+pub use jaro::PreparedJaro;
+#[cfg(feature = "alloc")]
+pub use jarwin::PreparedJaroWinkler;
+#[cfg(feature = "alloc")]
+pub use sellers::{Sellers, Matches, Match};
+#[cfg(feature = "alloc")]
+pub use bitap::{Bitap, BitapMatches, PatternError};
@@ -0,0 +1,138 @@
+use core::str::CharIndices;
+use crate::slice::Searcher;
+use alloc::vec::Vec;
+
+pub use crate::slice::Match;
+
+
+/// # Approximate substring search.
+///
+/// Finds all occurrences of a pattern in a text within the given Levenshtein distance,
+/// i.e. substrings of the text that can be turned into the pattern
+/// with at most `max_dist` insertions, deletions and substitutions of characters.
+///
+/// The text is processed in a single pass, so it can be arbitrarily long.
+///
+/// See [`eddie::slice::Sellers`][1] for details.
+///
+/// [1]: slice/struct.Sellers.html
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::{Sellers, Match};
+///
+/// let sellers = Sellers::new(2);
+/// let text = "Martha's mailbox is next to Marhta's.";
+/// let found: Vec<&str> = sellers.find_iter("Martha", text)
+///     .map(|m| &text[m.start .. m.end])
+///     .collect();
+/// assert_eq!(found, vec!["Martha", "Marhta"]);
+/// ```
+pub struct Sellers {
+    max_dist: usize,
+}
+
+
+impl Sellers {
+    /// Creates a new instance of Sellers struct for the given maximum distance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::Sellers;
+    ///
+    /// let sellers = Sellers::new(2);
+    /// ```
+    pub fn new(max_dist: usize) -> Self {
+        Self { max_dist }
+    }
+
+    /// Returns the maximum distance.
+    pub fn max_dist(&self) -> usize {
+        self.max_dist
+    }
+
+    /// Returns an iterator over the matches of the pattern in the text.
+    /// Match positions are byte offsets in the text.
+    /// Empty pattern does not match anything.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::{Sellers, Match};
+    /// let sellers = Sellers::new(1);
+    /// let found: Vec<Match> = sellers.find_iter("もしもし", "はい、もしまし。").collect();
+    /// assert_eq!(found, vec![Match { start: 9, end: 21, distance: 1 }]);
+    /// ```
+    pub fn find_iter<'a>(&self, pattern: &str, text: &'a str) -> Matches<'a> {
+        let pattern: Vec<char> = pattern.chars().collect();
+        Matches {
+            searcher: Searcher::new(&pattern, self.max_dist),
+            text: text.char_indices(),
+        }
+    }
+}
+
+
+/// An iterator over the matches found by [`Sellers::find_iter`][1].
+///
+/// [1]: struct.Sellers.html#method.find_iter
+pub struct Matches<'a> {
+    searcher: Searcher<char>,
+    text: CharIndices<'a>,
+}
+
+
+impl<'a> Iterator for Matches<'a> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        for (i, ch) in &mut self.text {
+            if let Some(found) = self.searcher.push(i, i + ch.len_utf8(), ch) {
+                return Some(found);
+            }
+        }
+        self.searcher.finish()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{Sellers, Match};
+    use crate::slice;
+
+    #[test]
+    fn byte_offsets() {
+        let sample = [
+            (0, "mailbox", "mailbox", vec![(0, 7, 0)]),
+            (1, "mailbox", "my mailbx!", vec![(3, 9, 1)]),
+            (1, "もしもし", "もしもし, もしまし", vec![(0, 12, 0), (14, 26, 1)]),
+            (2, "äpfel", "Äpfel und apfel", vec![(0, 6, 1), (11, 16, 1)]),
+            (0, "", "mailbox", vec![]),
+            (1, "mailbox", "", vec![]),
+        ];
+        for (max_dist, pattern, text, expected) in sample.iter() {
+            let found: Vec<(usize, usize, usize)> = Sellers::new(*max_dist).find_iter(pattern, text)
+                .map(|m| (m.start, m.end, m.distance))
+                .collect();
+            assert_eq!(&found, expected);
+        }
+    }
+
+    #[test]
+    fn matches_slice() {
+        let text = "mailbox boxmail mail box mialbox, malbox もしもし mailboxes";
+        let chars: Vec<char> = text.chars().collect();
+        let offsets: Vec<usize> = text.char_indices().map(|(i, _)| i).chain(Some(text.len())).collect();
+        for max_dist in 0..4 {
+            let found: Vec<Match> = Sellers::new(max_dist).find_iter("mailbox", text).collect();
+            let pattern: Vec<char> = "mailbox".chars().collect();
+            let expected: Vec<Match> = slice::Sellers::new(max_dist).find_iter(&pattern, &chars)
+                .map(|m| Match { start: offsets[m.start], end: offsets[m.end], distance: m.distance })
+                .collect();
+            assert_eq!(found, expected);
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::slice::jaro;
+use super::Workspace;
+#[cfg(feature = "std")]
+use super::with_workspace;
+
+
+/// # Thread-safe Jaro similarity.
+///
+/// Same as [`eddie::Jaro`][1], but stateless:
+/// `Sync + Send` and constructible in a `const` context.
+///
+/// [1]: ../struct.Jaro.html
+///
+/// # Usage
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use eddie::sync::Jaro;
+///
+/// static JARO: Jaro = Jaro::new();
+///
+/// let sim = JARO.similarity("martha", "marhta");
+/// assert!((sim - 0.94).abs() < 0.01);
+/// # }
+/// ```
+///
+/// # Complementary metrics
+///
+/// Relative distance:
+/// ```rust
+/// # let jaro = eddie::sync::Jaro::new();
+/// # let mut ws = eddie::sync::Workspace::new();
+/// # let s1 = "martha";
+/// # let s2 = "marhta";
+/// let sim = jaro.similarity_with(&mut ws, s1, s2);
+/// let dist = jaro.rel_dist_with(&mut ws, s1, s2);
+/// assert_eq!(dist, 1.0 - sim);
+/// ```
+pub struct Jaro { }
+
+
+impl Jaro {
+    /// Creates a new instance of Jaro struct.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::sync::Jaro;
+    ///
+    /// let jaro = Jaro::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self { }
+    }
+
+    /// Similarity metric. Reflects how close two strings are,
+    /// ranging from 1.0 (equality) to 0.0 (nothing in common).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::sync::Jaro;
+    /// # let jaro = Jaro::new();
+    /// let sim = jaro.similarity("martha", "marhta");
+    /// assert!((sim - 0.94).abs() < 0.01);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn similarity(&self, str1: &str, str2: &str) -> f64 {
+        with_workspace(|ws| self.similarity_with(ws, str1, str2))
+    }
+
+    /// Same as `similarity`, but uses the given workspace.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::sync::{Jaro, Workspace};
+    /// # let jaro = Jaro::new();
+    /// let mut ws = Workspace::new();
+    /// let sim = jaro.similarity_with(&mut ws, "martha", "marhta");
+    /// assert!((sim - 0.94).abs() < 0.01);
+    /// ```
+    pub fn similarity_with<const N: usize>(&self, ws: &mut Workspace<N>, str1: &str, str2: &str) -> f64 {
+        ws.store(str1, str2);
+        jaro(&mut ws.matches1, &mut ws.matches2, &ws.chars1, &ws.chars2)
+    }
+
+    /// Relative distance metric. Inversion of similarity.
+    /// Reflects how far apart two strings are,
+    /// ranging from 0.0 (equality) to 1.0 (nothing in common).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::sync::Jaro;
+    /// # let jaro = Jaro::new();
+    /// let dist = jaro.rel_dist("martha", "marhta");
+    /// assert!((dist - 0.06).abs() < 0.01);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn rel_dist(&self, str1: &str, str2: &str) -> f64 {
+        1.0 - self.similarity(str1, str2)
+    }
+
+    /// Same as `rel_dist`, but uses the given workspace.
+    pub fn rel_dist_with<const N: usize>(&self, ws: &mut Workspace<N>, str1: &str, str2: &str) -> f64 {
+        1.0 - self.similarity_with(ws, str1, str2)
+    }
+}
+
+
+impl Default for Jaro {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::Jaro;
+    use crate::str;
+
+    const SAMPLE: [&str; 8] = ["", "m", "martha", "marhta", "dixon", "dicksonx", "もしもし", "もしまし"];
+
+    #[test]
+    fn same_as_str() {
+        let jaro1 = Jaro::new();
+        let jaro2 = str::Jaro::new();
+        for s1 in &SAMPLE {
+            for s2 in &SAMPLE {
+                assert_eq!(jaro1.similarity(s1, s2), jaro2.similarity(s1, s2));
+                assert_eq!(jaro1.rel_dist(s1, s2), jaro2.rel_dist(s1, s2));
+            }
+        }
+    }
+}
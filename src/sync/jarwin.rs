@@ -0,0 +1,201 @@
+use crate::slice::{jaro, winkler};
+use super::Workspace;
+#[cfg(feature = "std")]
+use super::with_workspace;
+
+
+const DEFAULT_SCALING: f64 = 0.1;
+
+
+/// # Thread-safe Jaro-Winkler similarity.
+///
+/// Same as [`eddie::JaroWinkler`][1], but stateless:
+/// `Sync + Send` and constructible in a `const` context.
+///
+/// [1]: ../struct.JaroWinkler.html
+///
+/// # Usage
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use eddie::sync::JaroWinkler;
+///
+/// static JARWIN: JaroWinkler = JaroWinkler::new();
+///
+/// let sim = JARWIN.similarity("martha", "marhta");
+/// assert!((sim - 0.96).abs() < 0.01);
+/// # }
+/// ```
+///
+/// # Complementary metrics
+///
+/// Relative distance:
+/// ```rust
+/// # let jarwin = eddie::sync::JaroWinkler::new();
+/// # let mut ws = eddie::sync::Workspace::new();
+/// # let s1 = "martha";
+/// # let s2 = "marhta";
+/// let sim = jarwin.similarity_with(&mut ws, s1, s2);
+/// let dist = jarwin.rel_dist_with(&mut ws, s1, s2);
+/// assert_eq!(dist, 1.0 - sim);
+/// ```
+pub struct JaroWinkler {
+    scaling: f64,
+}
+
+
+impl JaroWinkler {
+    /// Creates a new instance of JaroWinkler struct.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::sync::JaroWinkler;
+    ///
+    /// let jarwin = JaroWinkler::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self { scaling: DEFAULT_SCALING }
+    }
+
+    /// Sets scaling factor for common prefix score boost.
+    /// Default value is 0.1.
+    /// Panics if it's not in range `[0.0, 0.25]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::sync::JaroWinkler;
+    /// let mut jarwin = JaroWinkler::new();
+    /// # let mut ws = eddie::sync::Workspace::new();
+    ///
+    /// let sim1 = jarwin.similarity_with(&mut ws, "martha", "marhta");
+    /// jarwin.set_scaling(0.25);
+    /// let sim2 = jarwin.similarity_with(&mut ws, "martha", "marhta");
+    ///
+    /// assert!((sim1 - 0.96).abs() < 0.01);
+    /// assert!((sim2 - 0.98).abs() < 0.01);
+    /// ```
+    pub fn set_scaling(&mut self, scaling: f64) -> &mut Self {
+        if scaling > 0.25 {
+            panic!("Scaling factor should not be greater than 0.25");
+        }
+        if scaling < 0.0 {
+            panic!("Scaling factor should not be less than 0.0");
+        }
+        self.scaling = scaling;
+        self
+    }
+
+    /// Returns scaling factor for common prefix score boost.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::sync::JaroWinkler;
+    /// let jarwin = JaroWinkler::new();
+    /// assert_eq!(jarwin.scaling(), 0.1);
+    /// ```
+    pub fn scaling(&self) -> f64 {
+        self.scaling
+    }
+
+    /// Similarity metric. Reflects how close two strings are,
+    /// ranging from 1.0 (equality) to 0.0 (nothing in common).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::sync::JaroWinkler;
+    /// # let jarwin = JaroWinkler::new();
+    /// let sim = jarwin.similarity("martha", "marhta");
+    /// assert!((sim - 0.96).abs() < 0.01);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn similarity(&self, str1: &str, str2: &str) -> f64 {
+        with_workspace(|ws| self.similarity_with(ws, str1, str2))
+    }
+
+    /// Same as `similarity`, but uses the given workspace.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::sync::{JaroWinkler, Workspace};
+    /// # let jarwin = JaroWinkler::new();
+    /// let mut ws = Workspace::new();
+    /// let sim = jarwin.similarity_with(&mut ws, "martha", "marhta");
+    /// assert!((sim - 0.96).abs() < 0.01);
+    /// ```
+    pub fn similarity_with<const N: usize>(&self, ws: &mut Workspace<N>, str1: &str, str2: &str) -> f64 {
+        ws.store(str1, str2);
+        let jaro = jaro(&mut ws.matches1, &mut ws.matches2, &ws.chars1, &ws.chars2);
+        winkler(jaro, self.scaling, &ws.chars1, &ws.chars2)
+    }
+
+    /// Relative distance metric. Inversion of similarity.
+    /// Reflects how far apart two strings are,
+    /// ranging from 0.0 (equality) to 1.0 (nothing in common).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::sync::JaroWinkler;
+    /// # let jarwin = JaroWinkler::new();
+    /// let dist = jarwin.rel_dist("martha", "marhta");
+    /// assert!((dist - 0.04).abs() < 0.01);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn rel_dist(&self, str1: &str, str2: &str) -> f64 {
+        1.0 - self.similarity(str1, str2)
+    }
+
+    /// Same as `rel_dist`, but uses the given workspace.
+    pub fn rel_dist_with<const N: usize>(&self, ws: &mut Workspace<N>, str1: &str, str2: &str) -> f64 {
+        1.0 - self.similarity_with(ws, str1, str2)
+    }
+}
+
+
+impl Default for JaroWinkler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::JaroWinkler;
+    use crate::str;
+
+    const SAMPLE: [&str; 8] = ["", "m", "martha", "marhta", "dwayne", "duane", "もしもし", "もしまし"];
+
+    #[test]
+    fn same_as_str() {
+        let mut jarwin1 = JaroWinkler::new();
+        let mut jarwin2 = str::JaroWinkler::new();
+        for &scaling in &[0.1, 0.0, 0.25] {
+            jarwin1.set_scaling(scaling);
+            jarwin2.set_scaling(scaling);
+            for s1 in &SAMPLE {
+                for s2 in &SAMPLE {
+                    assert_eq!(jarwin1.similarity(s1, s2), jarwin2.similarity(s1, s2));
+                    assert_eq!(jarwin1.rel_dist(s1, s2), jarwin2.rel_dist(s1, s2));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn scaling_too_high() {
+        JaroWinkler::new().set_scaling(0.3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn scaling_negative() {
+        JaroWinkler::new().set_scaling(-0.1);
+    }
+}
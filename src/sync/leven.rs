@@ -0,0 +1,174 @@
+use crate::slice::levenshtein;
+use super::Workspace;
+#[cfg(feature = "std")]
+use super::with_workspace;
+
+
+/// # Thread-safe Levenshtein distance.
+///
+/// Same as [`eddie::Levenshtein`][1], but stateless:
+/// `Sync + Send` and constructible in a `const` context.
+///
+/// [1]: ../struct.Levenshtein.html
+///
+/// # Usage
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use eddie::sync::Levenshtein;
+///
+/// static LEV: Levenshtein = Levenshtein::new();
+///
+/// let dist = LEV.distance("martha", "marhta");
+/// assert_eq!(dist, 2);
+/// # }
+/// ```
+///
+/// # Complementary metrics
+///
+/// Relative distance:
+/// ```rust
+/// # use std::cmp::max;
+/// # let lev = eddie::sync::Levenshtein::new();
+/// # let mut ws = eddie::sync::Workspace::new();
+/// # let s1 = "martha";
+/// # let s2 = "marhta";
+/// let dist = lev.distance_with(&mut ws, s1, s2);
+/// let rel = lev.rel_dist_with(&mut ws, s1, s2);
+/// let max_len = max(s1.len(), s2.len());
+/// assert_eq!(rel, dist as f64 / max_len as f64);
+/// ```
+///
+/// Similarity:
+/// ```rust
+/// # let lev = eddie::sync::Levenshtein::new();
+/// # let mut ws = eddie::sync::Workspace::new();
+/// # let s1 = "martha";
+/// # let s2 = "marhta";
+/// let rel = lev.rel_dist_with(&mut ws, s1, s2);
+/// let sim = lev.similarity_with(&mut ws, s1, s2);
+/// assert_eq!(sim, 1.0 - rel);
+/// ```
+pub struct Levenshtein { }
+
+
+impl Levenshtein {
+    /// Creates a new instance of Levenshtein struct.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::sync::Levenshtein;
+    ///
+    /// let lev = Levenshtein::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self { }
+    }
+
+    /// Distance metric. Returns a number of edits
+    /// (character additions, deletions, and substitutions)
+    /// required to transform one string into the other.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::sync::Levenshtein;
+    /// # let lev = Levenshtein::new();
+    /// let dist = lev.distance("martha", "marhta");
+    /// assert_eq!(dist, 2);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn distance(&self, str1: &str, str2: &str) -> usize {
+        with_workspace(|ws| self.distance_with(ws, str1, str2))
+    }
+
+    /// Same as `distance`, but uses the given workspace.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::sync::{Levenshtein, Workspace};
+    /// # let lev = Levenshtein::new();
+    /// let mut ws = Workspace::new();
+    /// let dist = lev.distance_with(&mut ws, "martha", "marhta");
+    /// assert_eq!(dist, 2);
+    /// ```
+    pub fn distance_with<const N: usize>(&self, ws: &mut Workspace<N>, str1: &str, str2: &str) -> usize {
+        ws.store(str1, str2);
+        levenshtein(&mut ws.dists, &ws.chars1, &ws.chars2)
+    }
+
+    /// Relative distance metric. Returns a number of edits relative to the length of
+    /// the longest string, ranging from 0.0 (equality) to 1.0 (nothing in common).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::sync::Levenshtein;
+    /// # let lev = Levenshtein::new();
+    /// let dist = lev.rel_dist("martha", "marhta");
+    /// assert!((dist - 0.333).abs() < 0.001);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn rel_dist(&self, str1: &str, str2: &str) -> f64 {
+        with_workspace(|ws| self.rel_dist_with(ws, str1, str2))
+    }
+
+    /// Same as `rel_dist`, but uses the given workspace.
+    pub fn rel_dist_with<const N: usize>(&self, ws: &mut Workspace<N>, str1: &str, str2: &str) -> f64 {
+        let dist = self.distance_with(ws, str1, str2);
+        let len = max!(1, ws.chars1.len(), ws.chars2.len());
+        dist as f64 / len as f64
+    }
+
+    /// Similarity metric. Inversion of relative distance,
+    /// ranging from 1.0 (equality) to 0.0 (nothing in common).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use eddie::sync::Levenshtein;
+    /// # let lev = Levenshtein::new();
+    /// let sim = lev.similarity("martha", "marhta");
+    /// assert!((sim - 0.666).abs() < 0.001);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn similarity(&self, str1: &str, str2: &str) -> f64 {
+        1.0 - self.rel_dist(str1, str2)
+    }
+
+    /// Same as `similarity`, but uses the given workspace.
+    pub fn similarity_with<const N: usize>(&self, ws: &mut Workspace<N>, str1: &str, str2: &str) -> f64 {
+        1.0 - self.rel_dist_with(ws, str1, str2)
+    }
+}
+
+
+impl Default for Levenshtein {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::Levenshtein;
+    use crate::str;
+
+    const SAMPLE: [&str; 8] = ["", "m", "martha", "marhta", "mailbox", "boxmail", "もしもし", "もしまし"];
+
+    #[test]
+    fn same_as_str() {
+        let lev1 = Levenshtein::new();
+        let lev2 = str::Levenshtein::new();
+        for s1 in &SAMPLE {
+            for s2 in &SAMPLE {
+                assert_eq!(lev1.distance(s1, s2), lev2.distance(s1, s2));
+                assert_eq!(lev1.rel_dist(s1, s2), lev2.rel_dist(s1, s2));
+                assert_eq!(lev1.similarity(s1, s2), lev2.similarity(s1, s2));
+            }
+        }
+    }
+}
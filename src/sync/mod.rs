@@ -0,0 +1,220 @@
+//! Thread-safe string metrics.
+//!
+//! Metrics from `eddie::str` and `eddie::slice` keep their scratch space in `RefCell`s,
+//! so they are not `Sync` and can't be shared across threads or stored in a `static`.
+//! Metrics from this module hold no internal state:
+//! they are `Sync + Send`, can be created in a `const` context,
+//! and keep their scratch space in a [`Workspace`][1] instead.
+//!
+//! Every metric method comes in two flavours:
+//! - `distance`, `rel_dist`, `similarity` use a workspace cached in a thread-local;
+//! - `distance_with`, `rel_dist_with`, `similarity_with` take an explicit `&mut Workspace`.
+//!
+//! Both produce the same results as the corresponding metrics from `eddie::str`.
+//!
+//! [1]: struct.Workspace.html
+//!
+//! # Basic Usage
+//!
+//! Sharing a metric across threads:
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! use std::thread;
+//! use eddie::sync::Levenshtein;
+//!
+//! static LEV: Levenshtein = Levenshtein::new();
+//!
+//! let handles: Vec<_> = ["marhta", "martha", "mars"].iter()
+//!     .map(|&s| thread::spawn(move || LEV.distance("martha", s)))
+//!     .collect();
+//! let dists: Vec<usize> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+//! assert_eq!(dists, [2, 0, 3]);
+//! # }
+//! ```
+//!
+//! Explicit workspace:
+//! ```rust
+//! use eddie::sync::{Workspace, JaroWinkler};
+//!
+//! let jarwin = JaroWinkler::new();
+//! let mut ws = Workspace::new();
+//! let sim = jarwin.similarity_with(&mut ws, "martha", "marhta");
+//! assert!((sim - 0.96).abs() < 0.01);
+//! ```
+
+mod leven;
+mod damlev;
+mod jaro;
+mod jarwin;
+
+pub use leven::Levenshtein;
+pub use damlev::DamerauLevenshtein;
+pub use jaro::Jaro;
+pub use jarwin::JaroWinkler;
+pub use crate::str::Hamming;
+
+#[cfg(feature = "std")]
+use core::cell::RefCell;
+use alloc::collections::BTreeMap;
+use crate::slice::DistMatrix;
+use crate::utils::{SmallVec, STACK_SIZE};
+
+
+/// # Scratch space for thread-safe metrics.
+///
+/// Holds the buffers the metrics from `eddie::sync` reuse between calls.
+/// One workspace can be shared by any number of metrics,
+/// but only by one thread at a time.
+///
+/// Strings up to `N` characters long (32 by default) are kept on the stack,
+/// so Levenshtein, Jaro and Jaro-Winkler don't allocate for them.
+/// Damerau-Levenshtein allocates its distance matrix on the first use.
+///
+/// # Usage
+///
+/// ```rust
+/// use eddie::sync::{Workspace, Levenshtein, DamerauLevenshtein};
+///
+/// let lev = Levenshtein::new();
+/// let damlev = DamerauLevenshtein::new();
+/// let mut ws = Workspace::new();
+///
+/// assert_eq!(lev.distance_with(&mut ws, "martha", "marhta"), 2);
+/// assert_eq!(damlev.distance_with(&mut ws, "martha", "marhta"), 1);
+/// ```
+pub struct Workspace<const N: usize = STACK_SIZE> {
+    pub(crate) chars1:   SmallVec<char, N>,
+    pub(crate) chars2:   SmallVec<char, N>,
+    pub(crate) dists:    SmallVec<usize, N>,
+    pub(crate) matrix:   DistMatrix,
+    pub(crate) last_i1:  BTreeMap<char, usize>,
+    pub(crate) matches1: SmallVec<bool, N>,
+    pub(crate) matches2: SmallVec<bool, N>,
+}
+
+
+impl Workspace {
+    /// Creates a new empty workspace. Doesn't allocate,
+    /// so it can be called in a `const` context.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::sync::Workspace;
+    ///
+    /// let mut ws = Workspace::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self::with_stack()
+    }
+}
+
+
+impl Default for Workspace {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+impl<const N: usize> Workspace<N> {
+    /// Creates a new empty workspace with stack buffers
+    /// for strings up to `N` characters long.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use eddie::sync::{Workspace, Levenshtein};
+    ///
+    /// let mut ws = Workspace::<64>::with_stack();
+    /// let dist = Levenshtein::new().distance_with(&mut ws, "martha", "marhta");
+    /// assert_eq!(dist, 2);
+    /// ```
+    pub const fn with_stack() -> Self {
+        Self {
+            chars1:   SmallVec::new(),
+            chars2:   SmallVec::new(),
+            dists:    SmallVec::new(),
+            matrix:   DistMatrix::empty(),
+            last_i1:  BTreeMap::new(),
+            matches1: SmallVec::new(),
+            matches2: SmallVec::new(),
+        }
+    }
+
+    pub(crate) fn store(&mut self, str1: &str, str2: &str) {
+        self.chars1.fill(str1.chars());
+        self.chars2.fill(str2.chars());
+    }
+}
+
+
+#[cfg(feature = "std")]
+thread_local! {
+    static WORKSPACE: RefCell<Workspace> = const { RefCell::new(Workspace::new()) };
+}
+
+
+/// Runs `f` with the workspace of the current thread,
+/// or with a fresh one if that is already in use.
+#[cfg(feature = "std")]
+pub(crate) fn with_workspace<R, F: FnOnce(&mut Workspace) -> R>(f: F) -> R {
+    WORKSPACE.with(|cell| match cell.try_borrow_mut() {
+        Ok(mut ws) => f(&mut ws),
+        Err(_) => f(&mut Workspace::new()),
+    })
+}
+
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{Workspace, Levenshtein, DamerauLevenshtein, Hamming, Jaro, JaroWinkler, with_workspace};
+
+    fn assert_sync<T: Sync + Send>() { }
+
+    #[test]
+    fn metrics_are_sync() {
+        assert_sync::<Workspace>();
+        assert_sync::<Levenshtein>();
+        assert_sync::<DamerauLevenshtein>();
+        assert_sync::<Hamming>();
+        assert_sync::<Jaro>();
+        assert_sync::<JaroWinkler>();
+    }
+
+    #[test]
+    fn shared_workspace() {
+        let lev = Levenshtein::new();
+        let damlev = DamerauLevenshtein::new();
+        let jarwin = JaroWinkler::new();
+        let mut ws = Workspace::new();
+        for _ in 0..2 {
+            assert_eq!(lev.distance_with(&mut ws, "martha", "marhta"), 2);
+            assert_eq!(damlev.distance_with(&mut ws, "martha", "marhta"), 1);
+            assert_eq!(lev.distance_with(&mut ws, "", "mailbox"), 7);
+            assert!((jarwin.similarity_with(&mut ws, "martha", "marhta") - 0.961).abs() < 0.001);
+        }
+    }
+
+    #[test]
+    fn nested_workspace() {
+        let lev = Levenshtein::new();
+        let dist = with_workspace(|ws| {
+            lev.distance_with(ws, "kitten", "sitting") + lev.distance("martha", "marhta")
+        });
+        assert_eq!(dist, 5);
+    }
+
+    #[test]
+    fn threads() {
+        static LEV: Levenshtein = Levenshtein::new();
+        let handles: Vec<_> = (0..4)
+            .map(|i| std::thread::spawn(move || {
+                let s: String = "abcdefg".chars().cycle().take(i * 5).collect();
+                LEV.distance("", &s)
+            }))
+            .collect();
+        let dists: Vec<usize> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert_eq!(dists, [0, 5, 10, 15]);
+    }
+}
@@ -0,0 +1,155 @@
+use core::cell::RefCell;
+use core::mem::swap;
+use super::common_affix_sizes;
+
+
+/// Optimal string alignment distance (restricted Damerau-Levenshtein),
+/// computed only within the diagonal band of the given width
+/// and terminated early as soon as it exceeds the given maximum.
+pub struct BoundedOsa {
+    rows: RefCell<[Vec<usize>; 3]>,
+}
+
+
+impl BoundedOsa {
+    pub fn new() -> Self {
+        Self { rows: RefCell::new([Vec::new(), Vec::new(), Vec::new()]) }
+    }
+
+    /// Returns the distance, or `None` if it is greater than `max_dist`.
+    pub fn distance<T: PartialEq + Copy>(&self, slice1: &[T], slice2: &[T], max_dist: usize) -> Option<usize> {
+        let (prefix, postfix) = common_affix_sizes(slice1, slice2);
+        let mut slice1 = { let len = slice1.len(); &slice1[prefix .. len - postfix] };
+        let mut slice2 = { let len = slice2.len(); &slice2[prefix .. len - postfix] };
+        if slice2.len() < slice1.len() {
+            core::mem::swap(&mut slice1, &mut slice2);
+        }
+
+        let len1 = slice1.len();
+        let len2 = slice2.len();
+        if len2 - len1 > max_dist { return None; }
+        if len1 == 0 { return Some(len2); }
+
+        let inf = max_dist + 1;
+        let rows = &mut *self.rows.borrow_mut();
+        for row in rows.iter_mut() {
+            row.clear();
+            row.resize(len2 + 1, inf);
+        }
+        let [prev2, prev, curr] = rows;
+        for (j, dist) in prev.iter_mut().enumerate().take(max_dist + 1) {
+            *dist = j;
+        }
+
+        for i1 in 1 ..= len1 {
+            let lo = max!(1, i1.saturating_sub(max_dist));
+            let hi = min!(len2, i1 + max_dist);
+            let x1 = slice1[i1 - 1];
+
+            curr[lo - 1] = if lo == 1 && i1 <= max_dist { i1 } else { inf };
+            if hi < len2 { curr[hi + 1] = inf; }
+            let mut row_min = curr[lo - 1];
+
+            for i2 in lo ..= hi {
+                let x2 = slice2[i2 - 1];
+                let mut dist = min!(
+                    prev[i2] + 1,
+                    curr[i2 - 1] + 1,
+                    prev[i2 - 1] + (x1 != x2) as usize
+                );
+                if i1 > 1 && i2 > 1 && x1 == slice2[i2 - 2] && slice1[i1 - 2] == x2 {
+                    dist = min!(dist, prev2[i2 - 2] + 1);
+                }
+                curr[i2] = min!(dist, inf);
+                row_min = min!(row_min, curr[i2]);
+            }
+
+            if row_min > max_dist { return None; }
+            swap(prev2, prev);
+            swap(prev, curr);
+        }
+
+        let dist = prev[len2];
+        if dist <= max_dist { Some(dist) } else { None }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::BoundedOsa;
+
+    fn osa(s1: &[char], s2: &[char]) -> usize {
+        let mut d = vec![vec![0; s2.len() + 1]; s1.len() + 1];
+        for (i, row) in d.iter_mut().enumerate() { row[0] = i; }
+        for (j, cell) in d[0].iter_mut().enumerate() { *cell = j; }
+        for i in 1 ..= s1.len() {
+            for j in 1 ..= s2.len() {
+                let cost = (s1[i - 1] != s2[j - 1]) as usize;
+                d[i][j] = min!(d[i - 1][j] + 1, d[i][j - 1] + 1, d[i - 1][j - 1] + cost);
+                if i > 1 && j > 1 && s1[i - 1] == s2[j - 2] && s1[i - 2] == s2[j - 1] {
+                    d[i][j] = min!(d[i][j], d[i - 2][j - 2] + 1);
+                }
+            }
+        }
+        d[s1.len()][s2.len()]
+    }
+
+    fn vec(s: &str) -> Vec<char> { s.chars().collect() }
+
+    #[test]
+    fn mixed() {
+        let bounded = BoundedOsa::new();
+        let sample = [
+            (3, "ca",        "abc"),
+            (3, "a tc",      "a cat"),
+            (4, "a cat",     "an abct"),
+            (2, "crate",     "trace"),
+            (2, "captain",   "ptain"),
+            (2, "dwayne",    "duane"),
+            (1, "martha",    "marhta"),
+            (3, "kitten",    "sitting"),
+            (6, "mailbox",   "boxmail"),
+            (3, "mailbox",   "alimbox"),
+            (4, "dixon",     "dicksonx"),
+            (2, "jellyfish", "smellyfish"),
+            (0, "",          ""),
+            (7, "mailbox",   ""),
+        ];
+        for &(d, s1, s2) in &sample {
+            let (v1, v2) = (vec(s1), vec(s2));
+            assert_eq!(osa(&v1, &v2), d);
+            for max_dist in 0..8 {
+                let expected = if d <= max_dist { Some(d) } else { None };
+                assert_eq!(bounded.distance(&v1, &v2, max_dist), expected);
+                assert_eq!(bounded.distance(&v2, &v1, max_dist), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn exhaustive() {
+        let bounded = BoundedOsa::new();
+        let alphabet = ['a', 'b', 'c'];
+        let mut words = vec![vec![]];
+        for len in 1..6 {
+            let prev: Vec<Vec<char>> = words.iter().filter(|w| w.len() == len - 1).cloned().collect();
+            for w in prev {
+                for &x in &alphabet {
+                    let mut w = w.clone();
+                    w.push(x);
+                    words.push(w);
+                }
+            }
+        }
+        for w1 in words.iter().step_by(7) {
+            for w2 in &words {
+                let d = osa(w1, w2);
+                for max_dist in 0..4 {
+                    let expected = if d <= max_dist { Some(d) } else { None };
+                    assert_eq!(bounded.distance(w1, w2, max_dist), expected);
+                }
+            }
+        }
+    }
+}
@@ -1,5 +1,5 @@
-use std::cmp::max;
-use std::cell::RefCell;
+use core::cmp::max;
+use core::cell::RefCell;
 
 
 pub struct Buffer<T: Copy> {
@@ -13,29 +13,31 @@ impl<T: Copy> Buffer<T> {
     }
 
     pub fn store<Iter: Iterator<Item=T>>(&self, iter: Iter) -> &RefCell<Vec<T>> {
-        {
-            let buf = &mut *self.cell.borrow_mut();
-            buf.clear();
-            let mut cap = buf.capacity();
-            let mut i = 0;
-            for item in iter {
-                if i >= cap {
-                    buf.reserve(max(cap * 2, 1));
-                    cap = buf.capacity();
-                }
-                unsafe { *buf.get_unchecked_mut(i) = item; }
-                i += 1;
-            }
-            unsafe { buf.set_len(i); }
-        }
+        fill(&mut *self.cell.borrow_mut(), iter);
         &self.cell
     }
 }
 
 
+pub fn fill<T: Copy, Iter: Iterator<Item=T>>(buf: &mut Vec<T>, iter: Iter) {
+    buf.clear();
+    let mut cap = buf.capacity();
+    let mut i = 0;
+    for item in iter {
+        if i >= cap {
+            buf.reserve(max(cap * 2, 1));
+            cap = buf.capacity();
+        }
+        unsafe { buf.as_mut_ptr().add(i).write(item); }
+        i += 1;
+    }
+    unsafe { buf.set_len(i); }
+}
+
+
 #[cfg(test)]
 mod tests {
-    use super::Buffer;
+    use super::{Buffer, fill};
 
     #[test]
     fn store_empty() {
@@ -97,4 +99,15 @@ mod tests {
             }
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn fill_reuses_vec() {
+        let mut vec = Vec::with_capacity(2);
+        fill(&mut vec, "foo".chars());
+        assert_eq!(vec, vec!['f', 'o', 'o']);
+        assert_eq!(vec.capacity(), 4);
+        fill(&mut vec, "ba".chars());
+        assert_eq!(vec, vec!['b', 'a']);
+        assert_eq!(vec.capacity(), 4);
+    }
+}
@@ -0,0 +1,58 @@
+/// ASCII replacements of Latin-1 Supplement and Latin Extended-A letters (U+00C0 to U+017F),
+/// empty for characters without one.
+const LATIN: [&str; 192] = [
+    // U+00C0
+    "A", "A", "A", "A", "A", "A", "AE", "C", "E", "E", "E", "E", "I", "I", "I", "I",
+    // U+00D0
+    "D", "N", "O", "O", "O", "O", "O", "", "O", "U", "U", "U", "U", "Y", "TH", "ss",
+    // U+00E0
+    "a", "a", "a", "a", "a", "a", "ae", "c", "e", "e", "e", "e", "i", "i", "i", "i",
+    // U+00F0
+    "d", "n", "o", "o", "o", "o", "o", "", "o", "u", "u", "u", "u", "y", "th", "y",
+    // U+0100
+    "A", "a", "A", "a", "A", "a", "C", "c", "C", "c", "C", "c", "C", "c", "D", "d",
+    // U+0110
+    "D", "d", "E", "e", "E", "e", "E", "e", "E", "e", "E", "e", "G", "g", "G", "g",
+    // U+0120
+    "G", "g", "G", "g", "H", "h", "H", "h", "I", "i", "I", "i", "I", "i", "I", "i",
+    // U+0130
+    "I", "i", "IJ", "ij", "J", "j", "K", "k", "k", "L", "l", "L", "l", "L", "l", "L",
+    // U+0140
+    "l", "L", "l", "N", "n", "N", "n", "N", "n", "n", "N", "n", "O", "o", "O", "o",
+    // U+0150
+    "O", "o", "OE", "oe", "R", "r", "R", "r", "R", "r", "S", "s", "S", "s", "S", "s",
+    // U+0160
+    "S", "s", "T", "t", "T", "t", "T", "t", "U", "u", "U", "u", "U", "u", "U", "u",
+    // U+0170
+    "U", "u", "U", "u", "W", "w", "Y", "y", "Y", "Z", "z", "Z", "z", "Z", "z", "s",
+];
+
+
+/// Returns the ASCII replacement of a Latin letter with diacritics or a ligature,
+/// e.g. `é` becomes `e` and `æ` becomes `ae`. Only the Latin-1 Supplement and Latin Extended-A
+/// letters (U+00C0 to U+017F) are covered, other characters give `None`.
+pub fn fold_latin(ch: char) -> Option<&'static str> {
+    let code = ch as usize;
+    if !(0xC0 .. 0x180).contains(&code) { return None; }
+    match LATIN[code - 0xC0] {
+        "" => None,
+        folded => Some(folded),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::fold_latin;
+
+    #[test]
+    fn fold() {
+        let folded: String = "Ærøskøbing, Łódź, Straße × Ÿ".chars()
+            .map(|ch| fold_latin(ch).map_or(ch.to_string(), String::from))
+            .collect();
+        assert_eq!(folded, "AEroskobing, Lodz, Strasse × Y");
+        assert_eq!(fold_latin('a'), None);
+        assert_eq!(fold_latin('ж'), None);
+        assert_eq!(fold_latin('ſ'), Some("s"));
+    }
+}
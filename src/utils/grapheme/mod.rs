@@ -0,0 +1,277 @@
+//! Extended grapheme cluster segmentation, see [UAX #29][1].
+//!
+//! [1]: https://www.unicode.org/reports/tr29/
+
+mod tables;
+
+#[cfg(feature = "alloc")]
+use core::cell::{Ref, RefCell};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use tables::CATEGORIES;
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Category {
+    Any,
+    CR,
+    LF,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    Pictographic,
+}
+
+
+const HANGUL_FIRST: u32 = 0xAC00;
+const HANGUL_LAST: u32 = 0xD7A3;
+const HANGUL_T_COUNT: u32 = 28;
+
+
+pub fn category(ch: char) -> Category {
+    let code = ch as u32;
+    if code < 0x7F {
+        return match ch {
+            '\r' => Category::CR,
+            '\n' => Category::LF,
+            _ if code < 0x20 => Category::Control,
+            _ => Category::Any,
+        };
+    }
+    if (HANGUL_FIRST ..= HANGUL_LAST).contains(&code) {
+        return match (code - HANGUL_FIRST) % HANGUL_T_COUNT {
+            0 => Category::Lv,
+            _ => Category::Lvt,
+        };
+    }
+    let i = CATEGORIES.partition_point(|&(_, hi, _)| hi < code);
+    match CATEGORIES.get(i) {
+        Some(&(lo, _, cat)) if lo <= code => cat,
+        _ => Category::Any,
+    }
+}
+
+
+fn is_boundary(prev: Category, next: Category, pictographic: bool, odd_regional: bool) -> bool {
+    use Category::*;
+    match (prev, next) {
+        (CR, LF)                                 => false,
+        (CR, _) | (LF, _) | (Control, _)         => true,
+        (_, CR) | (_, LF) | (_, Control)         => true,
+        (L, L) | (L, V) | (L, Lv) | (L, Lvt)     => false,
+        (Lv, V) | (Lv, T) | (V, V) | (V, T)      => false,
+        (Lvt, T) | (T, T)                        => false,
+        (_, Extend) | (_, Zwj)                   => false,
+        (_, SpacingMark)                         => false,
+        (Prepend, _)                             => false,
+        (Zwj, Pictographic)                      => !pictographic,
+        (RegionalIndicator, RegionalIndicator)   => !odd_regional,
+        _                                        => true,
+    }
+}
+
+
+/// Iterator over extended grapheme clusters of a string.
+pub struct Graphemes<'a> {
+    rest: &'a str,
+}
+
+
+pub fn graphemes(s: &str) -> Graphemes<'_> {
+    Graphemes { rest: s }
+}
+
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let mut chars = self.rest.char_indices();
+        let mut prev = category(chars.next()?.1);
+        // Whether the cluster so far ends with `Pictographic Extend* ZWJ?`.
+        let mut pictographic = prev == Category::Pictographic;
+        // Whether the cluster so far ends with an odd number of regional indicators.
+        let mut odd_regional = prev == Category::RegionalIndicator;
+        let mut end = self.rest.len();
+
+        for (i, ch) in chars {
+            let next = category(ch);
+            if is_boundary(prev, next, pictographic, odd_regional) {
+                end = i;
+                break;
+            }
+            pictographic = match next {
+                Category::Pictographic => true,
+                Category::Extend | Category::Zwj => pictographic && prev != Category::Zwj,
+                _ => false,
+            };
+            odd_regional = next == Category::RegionalIndicator && !odd_regional;
+            prev = next;
+        }
+
+        let (cluster, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(cluster)
+    }
+}
+
+
+/// The first id of multi-character clusters, right after the last code point.
+#[cfg(feature = "alloc")]
+const FIRST_CLUSTER_ID: u32 = 0x11_0000;
+
+
+/// Keeps two strings as sequences of grapheme cluster ids comparable to each other.
+/// Single-character clusters map to their code points,
+/// longer clusters map to numbers beyond the range of code points.
+#[cfg(feature = "alloc")]
+pub struct GraphemeBuffer {
+    ids1: RefCell<Vec<u32>>,
+    ids2: RefCell<Vec<u32>>,
+}
+
+
+#[cfg(feature = "alloc")]
+impl GraphemeBuffer {
+    pub const fn new() -> Self {
+        Self { ids1: RefCell::new(Vec::new()), ids2: RefCell::new(Vec::new()) }
+    }
+
+    pub fn store(&self, str1: &str, str2: &str) -> (Ref<'_, Vec<u32>>, Ref<'_, Vec<u32>>) {
+        let mut clusters: Vec<&str> = graphemes(str1).chain(graphemes(str2))
+            .filter(|cluster| cluster.chars().nth(1).is_some())
+            .collect();
+        clusters.sort_unstable();
+        clusters.dedup();
+
+        let id = |cluster: &str| {
+            let mut chars = cluster.chars();
+            match (chars.next(), chars.next()) {
+                (Some(ch), None) => ch as u32,
+                _ => FIRST_CLUSTER_ID + clusters.binary_search(&cluster).unwrap() as u32,
+            }
+        };
+
+        let mut ids1 = self.ids1.borrow_mut();
+        let mut ids2 = self.ids2.borrow_mut();
+        ids1.clear();
+        ids2.clear();
+        ids1.extend(graphemes(str1).map(id));
+        ids2.extend(graphemes(str2).map(id));
+        drop(ids1);
+        drop(ids2);
+
+        (self.ids1.borrow(), self.ids2.borrow())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{category, graphemes, Category};
+    use super::tables::CATEGORIES;
+
+    fn split(s: &str) -> Vec<&str> {
+        graphemes(s).collect()
+    }
+
+    #[test]
+    fn tables_sorted() {
+        for pair in CATEGORIES.windows(2) {
+            assert!(pair[0].0 <= pair[0].1);
+            assert!(pair[0].1 < pair[1].0);
+        }
+    }
+
+    #[test]
+    fn categories() {
+        assert_eq!(category('a'), Category::Any);
+        assert_eq!(category('\r'), Category::CR);
+        assert_eq!(category('\n'), Category::LF);
+        assert_eq!(category('\t'), Category::Control);
+        assert_eq!(category('\u{301}'), Category::Extend);
+        assert_eq!(category('\u{200D}'), Category::Zwj);
+        assert_eq!(category('\u{1F1FA}'), Category::RegionalIndicator);
+        assert_eq!(category('\u{0903}'), Category::SpacingMark);
+        assert_eq!(category('\u{1100}'), Category::L);
+        assert_eq!(category('\u{1161}'), Category::V);
+        assert_eq!(category('\u{11A8}'), Category::T);
+        assert_eq!(category('가'), Category::Lv);
+        assert_eq!(category('각'), Category::Lvt);
+        assert_eq!(category('\u{1F600}'), Category::Pictographic);
+        assert_eq!(category('も'), Category::Any);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(split(""), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn simple() {
+        assert_eq!(split("mailbox"), ["m", "a", "i", "l", "b", "o", "x"]);
+        assert_eq!(split("もしもし"), ["も", "し", "も", "し"]);
+    }
+
+    #[test]
+    fn controls() {
+        assert_eq!(split("a\r\nb"), ["a", "\r\n", "b"]);
+        assert_eq!(split("\n\r"), ["\n", "\r"]);
+        assert_eq!(split("a\u{301}\t\u{301}"), ["a\u{301}", "\t", "\u{301}"]);
+    }
+
+    #[test]
+    fn combining() {
+        assert_eq!(split("e\u{301}te\u{301}"), ["e\u{301}", "t", "e\u{301}"]);
+        assert_eq!(split("\u{301}a"), ["\u{301}", "a"]);
+        assert_eq!(split("o\u{308}\u{304}"), ["o\u{308}\u{304}"]);
+    }
+
+    #[test]
+    fn hangul() {
+        assert_eq!(split("\u{1100}\u{1161}\u{11A8}"), ["\u{1100}\u{1161}\u{11A8}"]);
+        assert_eq!(split("가\u{11A8}각"), ["가\u{11A8}", "각"]);
+        assert_eq!(split("각\u{1161}"), ["각", "\u{1161}"]);
+    }
+
+    #[test]
+    fn indic() {
+        assert_eq!(split("नमस्ते"), ["न", "म", "स्", "ते"]);
+    }
+
+    #[test]
+    fn emoji() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        assert_eq!(split(family), [family]);
+        assert_eq!(split("\u{1F44D}\u{1F3FD}!"), ["\u{1F44D}\u{1F3FD}", "!"]);
+        assert_eq!(split("a\u{200D}\u{1F600}"), ["a\u{200D}", "\u{1F600}"]);
+    }
+
+    #[test]
+    fn flags() {
+        let (us, fr) = ("\u{1F1FA}\u{1F1F8}", "\u{1F1EB}\u{1F1F7}");
+        assert_eq!(split(&format!("{}{}", us, fr)), [us, fr]);
+        assert_eq!(split(&format!("{}\u{1F1EB}", us)), [us, "\u{1F1EB}"]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn buffer() {
+        let buf = super::GraphemeBuffer::new();
+        let (ids1, ids2) = buf.store("e\u{301}te\u{301}", "e\u{301}tu\u{308}");
+        assert_eq!(ids1[0], ids1[2]);
+        assert_eq!(ids1[0], ids2[0]);
+        assert_eq!(ids1[1], 't' as u32);
+        assert_ne!(ids1[2], ids2[2]);
+        assert_eq!(ids1.len(), 3);
+        assert_eq!(ids2.len(), 3);
+    }
+}
@@ -0,0 +1,109 @@
+use alloc::vec::Vec;
+
+
+/// Maximum length of a query for bit-parallel algorithms.
+pub const MAX_MASKED: usize = 64;
+
+const ASCII_SIZE: usize = 128;
+
+
+/// Bit-masks of character positions in a query of at most 64 characters:
+/// bit `i` of the mask of a character is set if it occurs at position `i`.
+pub struct CharMasks {
+    ascii: [u64; ASCII_SIZE],
+    // Sorted by characters.
+    other: Vec<(char, u64)>,
+}
+
+
+impl CharMasks {
+    pub fn new(chars: &[char]) -> Self {
+        assert!(chars.len() <= MAX_MASKED);
+        let mut masks = Self { ascii: [0; ASCII_SIZE], other: Vec::new() };
+        for (i, &ch) in chars.iter().enumerate() {
+            let bit = 1 << i;
+            if (ch as usize) < ASCII_SIZE {
+                masks.ascii[ch as usize] |= bit;
+                continue;
+            }
+            match masks.other.binary_search_by_key(&ch, |&(ch, _)| ch) {
+                Ok(j) => masks.other[j].1 |= bit,
+                Err(j) => masks.other.insert(j, (ch, bit)),
+            }
+        }
+        masks
+    }
+
+    pub fn get(&self, ch: char) -> u64 {
+        if (ch as usize) < ASCII_SIZE { return self.ascii[ch as usize]; }
+        match self.other.binary_search_by_key(&ch, |&(ch, _)| ch) {
+            Ok(j) => self.other[j].1,
+            Err(_) => 0,
+        }
+    }
+}
+
+
+/// Levenshtein distance between a query of the given length and the text,
+/// computed with the bit-parallel algorithm by Myers and Hyyrö.
+pub fn myers_distance<I: Iterator<Item=char>>(masks: &CharMasks, len: usize, text: I) -> usize {
+    if len == 0 { return text.count(); }
+    let last = 1 << (len - 1);
+    let mut pv: u64 = !0;
+    let mut mv: u64 = 0;
+    let mut dist = len;
+
+    for ch in text {
+        let eq = masks.get(ch);
+        let xv = eq | mv;
+        let xh = ((eq & pv).wrapping_add(pv) ^ pv) | eq;
+        let mut ph = mv | !(xh | pv);
+        let mut mh = pv & xh;
+        if ph & last != 0 { dist += 1; }
+        if mh & last != 0 { dist -= 1; }
+        ph = ph << 1 | 1;
+        mh <<= 1;
+        pv = mh | !(xv | ph);
+        mv = ph & xv;
+    }
+
+    dist
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{CharMasks, myers_distance};
+    use crate::slice::Levenshtein;
+
+    #[test]
+    fn masks() {
+        let chars: Vec<char> = "mailbox もしもし".chars().collect();
+        let masks = CharMasks::new(&chars);
+        assert_eq!(masks.get('m'), 0b1);
+        assert_eq!(masks.get('o'), 0b100000);
+        assert_eq!(masks.get('も'), 0b101 << 8);
+        assert_eq!(masks.get('し'), 0b1010 << 8);
+        assert_eq!(masks.get('x'), 0b1000000);
+        assert_eq!(masks.get('z'), 0);
+        assert_eq!(masks.get('ま'), 0);
+    }
+
+    #[test]
+    fn myers() {
+        let lev = Levenshtein::new();
+        let words = ["", "m", "mailbox", "boxmail", "mail", "mialbox", "もしもし", "もしまし", "aaaaaaaaab"];
+        let long: String = "mailbox".chars().cycle().take(64).collect();
+        let longer: String = "malbox".chars().cycle().take(70).collect();
+        let words: Vec<&str> = words.iter().copied().chain(vec![long.as_str(), longer.as_str()]).collect();
+        for s1 in &words {
+            let chars1: Vec<char> = s1.chars().collect();
+            if chars1.len() > 64 { continue; }
+            let masks = CharMasks::new(&chars1);
+            for s2 in &words {
+                let chars2: Vec<char> = s2.chars().collect();
+                assert_eq!(myers_distance(&masks, chars1.len(), s2.chars()), lev.distance(&chars1, &chars2), "{} {}", s1, s2);
+            }
+        }
+    }
+}
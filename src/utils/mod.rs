@@ -1,23 +1,66 @@
 #![macro_use]
 
 mod zip;
+#[cfg(feature = "std")]
 mod buffer;
 
 pub use zip::Zippable;
+#[cfg(feature = "std")]
 pub use buffer::Buffer;
 
-use std::cmp::min;
+use core::cmp::min;
 
 
 macro_rules! min {
     ($x: expr) => ($x);
-    ($x: expr, $($z: expr),+) => (::std::cmp::min($x, min!($($z),*)));
+    ($x: expr, $($z: expr),+) => (::core::cmp::min($x, min!($($z),*)));
 }
 
 
 macro_rules! max {
     ($x: expr) => ($x);
-    ($x: expr, $($z: expr),+) => (::std::cmp::max($x, max!($($z),*)));
+    ($x: expr, $($z: expr),+) => (::core::cmp::max($x, max!($($z),*)));
+}
+
+
+#[cfg(feature = "std")]
+mod bounded;
+
+#[cfg(feature = "std")]
+pub use bounded::BoundedOsa;
+
+#[cfg(feature = "alloc")]
+mod masks;
+
+#[cfg(feature = "alloc")]
+pub use masks::{CharMasks, myers_distance, MAX_MASKED};
+
+#[cfg(feature = "alloc")]
+mod fold;
+
+#[cfg(feature = "alloc")]
+pub use fold::fold_latin;
+
+mod small;
+
+pub use small::{SmallVec, SmallBuffer, STACK_SIZE};
+
+mod grapheme;
+
+pub use grapheme::graphemes;
+
+#[cfg(feature = "alloc")]
+pub use grapheme::GraphemeBuffer;
+
+
+/// Returns bytes of both strings if they are pure ASCII,
+/// so that every byte is exactly one character.
+pub fn ascii_bytes<'a>(str1: &'a str, str2: &'a str) -> Option<(&'a [u8], &'a [u8])> {
+    if str1.is_ascii() && str2.is_ascii() {
+        Some((str1.as_bytes(), str2.as_bytes()))
+    } else {
+        None
+    }
 }
 
 
@@ -41,7 +84,7 @@ pub fn common_affix_sizes<T: Copy + PartialEq>(slice1: &[T], slice2: &[T]) -> (u
 
 #[cfg(test)]
 mod tests {
-    use super::common_affix_sizes;
+    use super::{ascii_bytes, common_affix_sizes};
 
     fn vec(s: &str) -> Vec<char> { s.chars().collect() }
 
@@ -191,4 +234,12 @@ mod tests {
             assert_eq!(common_affix_sizes(&v2, &v1), *expected);
         }
     }
+
+    #[test]
+    fn ascii() {
+        assert_eq!(ascii_bytes("", ""), Some((&b""[..], &b""[..])));
+        assert_eq!(ascii_bytes("mailbox", "box"), Some((&b"mailbox"[..], &b"box"[..])));
+        assert_eq!(ascii_bytes("mailbox", "Äpfel"), None);
+        assert_eq!(ascii_bytes("もしもし", "mailbox"), None);
+    }
 }
@@ -0,0 +1,204 @@
+use core::cell::RefCell;
+use core::mem::MaybeUninit;
+use core::ops::{Deref, DerefMut};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+
+/// Default number of items kept on the stack, enough for most words and names.
+pub const STACK_SIZE: usize = 32;
+
+
+/// A vector keeping up to `N` items inline and spilling to the heap beyond that.
+/// Once spilled, the heap buffer is kept for reuse.
+/// Without the `alloc` feature it panics instead of spilling.
+pub struct SmallVec<T: Copy, const N: usize> {
+    len: usize,
+    stack: [MaybeUninit<T>; N],
+    #[cfg(feature = "alloc")]
+    heap: Vec<T>,
+}
+
+
+impl<T: Copy, const N: usize> SmallVec<T, N> {
+    pub const fn new() -> Self {
+        Self {
+            len: 0,
+            stack: [MaybeUninit::uninit(); N],
+            #[cfg(feature = "alloc")]
+            heap: Vec::new(),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    pub fn push(&mut self, item: T) {
+        if self.len < N {
+            self.stack[self.len] = MaybeUninit::new(item);
+        } else {
+            self.spill(item);
+        }
+        self.len += 1;
+    }
+
+    #[cfg(feature = "alloc")]
+    fn spill(&mut self, item: T) {
+        if self.len == N {
+            self.heap.clear();
+            self.heap.extend(self.stack.iter().map(|x| unsafe { x.assume_init() }));
+        }
+        self.heap.push(item);
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn spill(&mut self, _item: T) {
+        panic!("Input is longer than the stack buffer of {} items", N);
+    }
+
+    pub fn fill<Iter: Iterator<Item=T>>(&mut self, iter: Iter) {
+        self.clear();
+        for item in iter {
+            self.push(item);
+        }
+    }
+
+    pub fn resize(&mut self, len: usize, item: T) {
+        if len <= N {
+            for slot in &mut self.stack[..len] {
+                *slot = MaybeUninit::new(item);
+            }
+        } else {
+            self.spill_resize(len, item);
+        }
+        self.len = len;
+    }
+
+    #[cfg(feature = "alloc")]
+    fn spill_resize(&mut self, len: usize, item: T) {
+        self.heap.clear();
+        self.heap.resize(len, item);
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn spill_resize(&mut self, _len: usize, _item: T) {
+        panic!("Input is longer than the stack buffer of {} items", N);
+    }
+}
+
+
+impl<T: Copy, const N: usize> Deref for SmallVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        #[cfg(feature = "alloc")]
+        if self.len > N { return &self.heap; }
+        unsafe { core::slice::from_raw_parts(self.stack.as_ptr() as *const T, self.len) }
+    }
+}
+
+
+impl<T: Copy, const N: usize> DerefMut for SmallVec<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        #[cfg(feature = "alloc")]
+        if self.len > N { return &mut self.heap; }
+        unsafe { core::slice::from_raw_parts_mut(self.stack.as_mut_ptr() as *mut T, self.len) }
+    }
+}
+
+
+/// Same as `Buffer`, but keeps up to `N` items on the stack
+/// and can be created in a `const` context.
+pub struct SmallBuffer<T: Copy, const N: usize> {
+    cell: RefCell<SmallVec<T, N>>,
+}
+
+
+impl<T: Copy, const N: usize> SmallBuffer<T, N> {
+    pub const fn new() -> Self {
+        Self { cell: RefCell::new(SmallVec::new()) }
+    }
+
+    pub fn store<Iter: Iterator<Item=T>>(&self, iter: Iter) -> &RefCell<SmallVec<T, N>> {
+        self.cell.borrow_mut().fill(iter);
+        &self.cell
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::SmallVec;
+
+    #[test]
+    fn fill_stack() {
+        const EMPTY: SmallVec<char, 4> = SmallVec::new();
+        let mut vec = EMPTY;
+        for s in &["", "f", "foo", "fooo"] {
+            vec.fill(s.chars());
+            assert_eq!(&vec[..], &s.chars().collect::<Vec<_>>()[..]);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn fill_heap() {
+        let mut vec: SmallVec<char, 4> = SmallVec::new();
+        for s in &["foooo", "mailbox", "", "foo", "mailbox"] {
+            vec.fill(s.chars());
+            assert_eq!(&vec[..], &s.chars().collect::<Vec<_>>()[..]);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn fill_zero_sized() {
+        let mut vec: SmallVec<usize, 0> = SmallVec::new();
+        vec.fill(0..0);
+        assert_eq!(&vec[..], &[]);
+        vec.fill(0..3);
+        assert_eq!(&vec[..], &[0, 1, 2]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn resize() {
+        let mut vec: SmallVec<bool, 4> = SmallVec::new();
+        for &len in &[0, 3, 4, 5, 10, 2] {
+            vec.resize(len, true);
+            assert_eq!(&vec[..], &vec![true; len][..]);
+            vec.resize(len, false);
+            assert_eq!(&vec[..], &vec![false; len][..]);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn deref_mut() {
+        let mut vec: SmallVec<usize, 4> = SmallVec::new();
+        for len in 0..10 {
+            vec.fill(0..len);
+            for x in vec.iter_mut() { *x *= 2; }
+            assert_eq!(&vec[..], &(0..len).map(|x| x * 2).collect::<Vec<_>>()[..]);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn store() {
+        use super::SmallBuffer;
+        let buf: SmallBuffer<char, 4> = SmallBuffer::new();
+        assert_eq!(&buf.store("foo".chars()).borrow()[..], &['f', 'o', 'o']);
+        assert_eq!(&buf.store("mailbox".chars()).borrow()[..], &['m', 'a', 'i', 'l', 'b', 'o', 'x']);
+        assert_eq!(&buf.store("ba".chars()).borrow()[..], &['b', 'a']);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(not(feature = "alloc"))]
+    fn no_spill() {
+        let mut vec: SmallVec<char, 4> = SmallVec::new();
+        vec.fill("mailbox".chars());
+    }
+}